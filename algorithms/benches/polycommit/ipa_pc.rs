@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_algorithms::{
+    crypto_hash::PoseidonSponge,
+    fft::DensePolynomial,
+    polycommit::{
+        ipa_pc::InnerProductArgPC,
+        sonic_pc::{Evaluations, LabeledPolynomial, QuerySet},
+    },
+    AlgebraicSponge,
+};
+use snarkvm_curves::bls12_377::{Fq, Fr, G1Affine};
+use snarkvm_utilities::{TestRng, Uniform};
+
+use criterion::Criterion;
+
+#[macro_use]
+extern crate criterion;
+
+type Sponge = PoseidonSponge<Fq, 2, 1>;
+type Ipa = InnerProductArgPC<G1Affine, Sponge>;
+
+fn ipa_pc(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    for degree in [(1 << 10) - 1, (1 << 12) - 1, (1 << 14) - 1] {
+        let pp = Ipa::setup(degree).unwrap();
+        let (ck, vk) = Ipa::trim(&pp, degree).unwrap();
+
+        let polynomial = DensePolynomial::rand(degree, rng);
+        let polynomials = [LabeledPolynomial::new("Test".to_string(), polynomial, None, None)];
+
+        c.bench_function(&format!("IPA commit (degree {degree})"), |b| {
+            b.iter(|| Ipa::commit(&ck, polynomials.iter().map(Into::into), None).unwrap())
+        });
+
+        let (comms, rands) = Ipa::commit(&ck, polynomials.iter().map(Into::into), None).unwrap();
+        let point = Fr::rand(rng);
+        let mut query_set = QuerySet::new();
+        query_set.insert(("Test".to_string(), ("point".to_string(), point)));
+        let mut values = Evaluations::new();
+        values.insert(("Test".to_string(), point), polynomials[0].evaluate(point));
+
+        c.bench_function(&format!("IPA open (degree {degree})"), |b| {
+            b.iter(|| Ipa::batch_open(&ck, &polynomials, &query_set, &rands, &mut Sponge::new(), None).unwrap())
+        });
+
+        let proof = Ipa::batch_open(&ck, &polynomials, &query_set, &rands, &mut Sponge::new(), None).unwrap();
+        c.bench_function(&format!("IPA check (degree {degree})"), |b| {
+            b.iter(|| assert!(Ipa::batch_check(&vk, &comms, &query_set, &values, &proof, &mut Sponge::new()).unwrap()))
+        });
+    }
+}
+
+criterion_group! {
+    name = polycommit;
+    config = Criterion::default().sample_size(10);
+    targets = ipa_pc
+}
+
+criterion_main!(polycommit);
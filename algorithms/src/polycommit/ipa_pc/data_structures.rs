@@ -0,0 +1,210 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::{ConstraintFieldError, Field, ToConstraintField, Zero};
+use snarkvm_utilities::{
+    error,
+    io::{Read, Write},
+    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    FromBytes,
+    ToBytes,
+};
+
+use core::ops::AddAssign;
+use std::io;
+
+/// `UniversalParams` are the transparent public parameters of the inner-product argument,
+/// which are independent group generators with no known discrete logarithm relations.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct UniversalParams<G: AffineCurve> {
+    /// The generators used to commit to the coefficients of a polynomial.
+    pub comm_key: Vec<G>,
+    /// The generator used to commit to the hiding randomness.
+    pub h: G,
+    /// The generator used to commit to the inner product in an evaluation proof.
+    pub s: G,
+}
+
+impl<G: AffineCurve> UniversalParams<G> {
+    /// Returns the maximum degree supported by the parameters.
+    pub fn max_degree(&self) -> usize {
+        self.comm_key.len() - 1
+    }
+}
+
+/// `CommitterKey` is used to commit to, and create evaluation proofs for, a given polynomial.
+/// As the scheme is transparent, the same key is used to verify evaluation proofs.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CommitterKey<G: AffineCurve> {
+    /// The generators used to commit to the coefficients of a polynomial.
+    /// The number of generators is a power of two.
+    pub comm_key: Vec<G>,
+    /// The generator used to commit to the hiding randomness.
+    pub h: G,
+    /// The generator used to commit to the inner product in an evaluation proof.
+    pub s: G,
+    /// The maximum degree supported by the key, which is one less than the number of generators.
+    pub max_degree: usize,
+}
+
+impl<G: AffineCurve> CommitterKey<G> {
+    /// Returns the number of coefficients supported by the key.
+    pub fn supported_size(&self) -> usize {
+        self.comm_key.len()
+    }
+}
+
+/// `VerifierKey` is used to check evaluation proofs for a given commitment.
+pub type VerifierKey<G> = CommitterKey<G>;
+
+/// `Commitment` is a Pedersen vector commitment to the coefficients of a polynomial.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment<G: AffineCurve> {
+    /// The commitment to the polynomial.
+    pub comm: G,
+    /// The commitment to the shifted polynomial `X^{D - d} * p(X)`, where `D` is the maximum degree
+    /// of the committer key. This is `None` if the polynomial does not have a degree bound `d`.
+    pub shifted_comm: Option<G>,
+}
+
+impl<G: AffineCurve + ToConstraintField<F>, F: Field> ToConstraintField<F> for Commitment<G> {
+    fn to_field_elements(&self) -> Result<Vec<F>, ConstraintFieldError> {
+        let mut elements = self.comm.to_field_elements()?;
+        if let Some(shifted_comm) = &self.shifted_comm {
+            elements.extend(shifted_comm.to_field_elements()?);
+        }
+        Ok(elements)
+    }
+}
+
+impl<G: AffineCurve> FromBytes for Commitment<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader).map_err(|_| error("could not deserialize commitment"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for Commitment<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize commitment"))
+    }
+}
+
+/// `Randomness` hides the polynomial inside a commitment. It is output by `InnerProductArgPC::commit`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Randomness<G: AffineCurve> {
+    /// The randomness of the commitment to the polynomial.
+    pub rand: G::ScalarField,
+    /// The randomness of the commitment to the shifted polynomial.
+    pub shifted_rand: Option<G::ScalarField>,
+}
+
+impl<G: AffineCurve> Randomness<G> {
+    /// Returns the randomness of a non-hiding commitment.
+    pub fn empty() -> Self {
+        Self { rand: G::ScalarField::zero(), shifted_rand: None }
+    }
+
+    /// Returns `true` if the corresponding commitment is hiding.
+    pub fn is_hiding(&self) -> bool {
+        !self.rand.is_zero() || self.shifted_rand.map_or(false, |rand| !rand.is_zero())
+    }
+}
+
+impl<'a, G: AffineCurve> AddAssign<(G::ScalarField, &'a Randomness<G>)> for Randomness<G> {
+    #[inline]
+    fn add_assign(&mut self, (f, other): (G::ScalarField, &'a Randomness<G>)) {
+        self.rand += f * other.rand;
+        if let Some(other_shifted_rand) = other.shifted_rand {
+            let shifted_rand = self.shifted_rand.get_or_insert_with(G::ScalarField::zero);
+            *shifted_rand += f * other_shifted_rand;
+        }
+    }
+}
+
+/// `Proof` is an inner-product argument that a committed polynomial evaluates to a claimed value at a point.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<G: AffineCurve> {
+    /// The left commitments of each round of the argument.
+    pub l_vec: Vec<G>,
+    /// The right commitments of each round of the argument.
+    pub r_vec: Vec<G>,
+    /// The final coefficient of the folded polynomial.
+    pub c: G::ScalarField,
+    /// The commitment to the hiding polynomial, which vanishes at the evaluation point.
+    /// This is `None` if the opened polynomial is not hiding.
+    pub hiding_comm: Option<G>,
+    /// The combined randomness of the commitment, which is removed from it before the argument.
+    /// This is `None` if the opened polynomial is not hiding.
+    pub rand: Option<G::ScalarField>,
+}
+
+impl<G: AffineCurve> Proof<G> {
+    /// Returns `true` if the proof is for a hiding polynomial.
+    pub fn is_hiding(&self) -> bool {
+        self.hiding_comm.is_some()
+    }
+}
+
+impl<G: AffineCurve> FromBytes for Proof<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader).map_err(|_| error("could not deserialize IPA proof"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for Proof<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize IPA proof"))
+    }
+}
+
+/// Evaluation proof at a query set, with one proof for each query point.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct BatchProof<G: AffineCurve>(pub(crate) Vec<Proof<G>>);
+
+impl<G: AffineCurve> BatchProof<G> {
+    /// Returns the proofs, one per query point.
+    pub fn proofs(&self) -> &[Proof<G>] {
+        &self.0
+    }
+
+    pub fn is_hiding(&self) -> bool {
+        self.0.iter().any(|proof| proof.is_hiding())
+    }
+}
+
+/// A proof of satisfaction of linear combinations.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct BatchLCProof<G: AffineCurve> {
+    /// Evaluation proof.
+    pub proof: BatchProof<G>,
+}
+
+impl<G: AffineCurve> BatchLCProof<G> {
+    pub fn is_hiding(&self) -> bool {
+        self.proof.is_hiding()
+    }
+}
+
+impl<G: AffineCurve> FromBytes for BatchLCProof<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader).map_err(|_| error("could not deserialize struct"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for BatchLCProof<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize struct"))
+    }
+}
@@ -0,0 +1,854 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    crypto_hash::sha256::sha256,
+    fft::DensePolynomial,
+    msm::variable_base::VariableBase,
+    polycommit::{
+        optional_rng::OptionalRng,
+        sonic_pc::{
+            Evaluations,
+            LabeledCommitment,
+            LabeledPolynomial,
+            LabeledPolynomialWithBasis,
+            LinearCombination,
+            PolynomialWithBasis,
+            QuerySet,
+        },
+        PCError,
+    },
+    AlgebraicSponge,
+};
+use snarkvm_curves::traits::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, ToConstraintField, Zero};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter, Uniform};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use core::{convert::TryInto, marker::PhantomData};
+use itertools::Itertools;
+use rand_core::RngCore;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+mod data_structures;
+pub use data_structures::*;
+
+/// A transparent polynomial commitment scheme based on the inner-product argument of
+/// [[BCCGP16, “Bulletproofs”]][bulletproofs], as adapted to polynomial commitments in [[BCMS20]][pcdas].
+/// The commitment key consists of generators obtained by hashing to the curve, so there is no trusted setup,
+/// and no pairing is required. In exchange, checking an evaluation proof takes time linear in the degree.
+///
+/// Degree bounds are enforced by committing to the shifted polynomial `X^{D - d} * p(X)`,
+/// as in [[CHMMVW20, “Marlin”]][marlin], and the (optional) hiding property follows [[BCMS20]][pcdas],
+/// by opening `p(X) + ξ * q(X)` for a random `q(X)` that vanishes at the evaluation point.
+///
+/// [bulletproofs]: https://eprint.iacr.org/2017/1066
+/// [pcdas]: https://eprint.iacr.org/2020/499
+/// [marlin]: https://eprint.iacr.org/2019/1047
+#[derive(Clone, Debug)]
+pub struct InnerProductArgPC<G: AffineCurve, S> {
+    _phantom: PhantomData<(G, S)>,
+}
+
+impl<G, S> InnerProductArgPC<G, S>
+where
+    G: AffineCurve + ToConstraintField<G::BaseField>,
+    G::BaseField: PrimeField,
+    S: AlgebraicSponge<G::BaseField, 2>,
+{
+    /// The domain separator used to derive the generators of the commitment key.
+    pub const PROTOCOL_NAME: &'static str = "IPA-PC-2023";
+
+    /// Samples the transparent parameters for polynomials of degree up to `max_degree`.
+    /// The number of generators is rounded up to the next power of two.
+    pub fn setup(max_degree: usize) -> Result<UniversalParams<G>, PCError> {
+        if max_degree < 1 {
+            return Err(PCError::DegreeIsZero);
+        }
+        let setup_time = start_timer!(|| format!("Sampling generators for degree {max_degree}"));
+        let supported_size = (max_degree + 1).next_power_of_two();
+        let mut comm_key = cfg_into_iter!(0..supported_size + 2).map(Self::sample_generator).collect::<Vec<_>>();
+        let s = comm_key.pop().unwrap();
+        let h = comm_key.pop().unwrap();
+        end_timer!(setup_time);
+        Ok(UniversalParams { comm_key, h, s })
+    }
+
+    /// Specializes the parameters to polynomials of degree up to `supported_degree`.
+    pub fn trim(
+        pp: &UniversalParams<G>,
+        supported_degree: usize,
+    ) -> Result<(CommitterKey<G>, VerifierKey<G>), PCError> {
+        if supported_degree < 1 {
+            return Err(PCError::DegreeIsZero);
+        }
+        let supported_size = (supported_degree + 1).next_power_of_two();
+        if supported_size > pp.comm_key.len() {
+            return Err(PCError::TrimmingDegreeTooLarge);
+        }
+        let ck = CommitterKey {
+            comm_key: pp.comm_key[..supported_size].to_vec(),
+            h: pp.h,
+            s: pp.s,
+            max_degree: supported_size - 1,
+        };
+        Ok((ck.clone(), ck))
+    }
+
+    /// If for some `i`, `polynomials[i].is_hiding() == false`, then the
+    /// corresponding randomness is `Randomness::empty()`.
+    ///
+    /// If for some `i`, `polynomials[i].degree_bound().is_some()`, then that
+    /// polynomial will have the corresponding degree bound enforced.
+    pub fn commit<'b>(
+        ck: &CommitterKey<G>,
+        polynomials: impl IntoIterator<Item = LabeledPolynomialWithBasis<'b, G::ScalarField>>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<G>>>, Vec<Randomness<G>>), PCError> {
+        let rng = &mut OptionalRng(rng);
+        let commit_time = start_timer!(|| "Committing to polynomials");
+
+        let mut labeled_comms = Vec::new();
+        let mut randomness = Vec::new();
+        for p in polynomials {
+            let label = p.label().to_string();
+            let degree_bound = p.degree_bound();
+            let is_hiding = p.is_hiding();
+            let coeffs = match p.polynomial {
+                PolynomialWithBasis::Lagrange { evaluations } => evaluations.interpolate_by_ref().coeffs,
+                PolynomialWithBasis::Monomial { polynomial, .. } => polynomial.to_dense().into_owned().coeffs,
+            };
+            Self::check_degrees_and_bounds(ck, &label, coeffs.len(), degree_bound)?;
+            if is_hiding && rng.0.is_none() {
+                return Err(PCError::MissingRng);
+            }
+
+            let mut rand = Randomness::empty();
+            if is_hiding {
+                rand.rand = G::ScalarField::rand(rng);
+            }
+            let comm = Self::commit_coefficients(ck, &coeffs, 0, rand.rand);
+            let shifted_comm = degree_bound.map(|degree_bound| {
+                let shifted_rand = match is_hiding {
+                    true => G::ScalarField::rand(rng),
+                    false => G::ScalarField::zero(),
+                };
+                rand.shifted_rand = Some(shifted_rand);
+                Self::commit_coefficients(ck, &coeffs, ck.max_degree - degree_bound, shifted_rand)
+            });
+
+            let mut comms = vec![comm];
+            comms.extend(shifted_comm);
+            let comms = G::Projective::batch_normalization_into_affine(comms);
+            let commitment = Commitment { comm: comms[0], shifted_comm: comms.get(1).copied() };
+            labeled_comms.push(LabeledCommitment::new(label, commitment, degree_bound));
+            randomness.push(rand);
+        }
+
+        end_timer!(commit_time);
+        Ok((labeled_comms, randomness))
+    }
+
+    /// On input a list of labeled polynomials and a query set, `open` outputs a proof of evaluation
+    /// of the polynomials at the points in the query set.
+    pub fn batch_open<'a>(
+        ck: &CommitterKey<G>,
+        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<G::ScalarField>>,
+        query_set: &QuerySet<G::ScalarField>,
+        rands: impl IntoIterator<Item = &'a Randomness<G>>,
+        fs_rng: &mut S,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<BatchProof<G>> {
+        let rng = &mut OptionalRng(rng);
+        let poly_rand: BTreeMap<_, _> =
+            labeled_polynomials.into_iter().zip_eq(rands).map(|(poly, r)| (poly.label(), (poly, r))).collect();
+
+        let open_time = start_timer!(|| format!(
+            "Opening {} polynomials at query set of size {}",
+            poly_rand.len(),
+            query_set.len(),
+        ));
+
+        let mut query_to_labels_map = BTreeMap::new();
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        let mut proofs = Vec::with_capacity(query_to_labels_map.len());
+        for (_point_name, (&point, labels)) in query_to_labels_map.into_iter() {
+            let mut coeffs = vec![G::ScalarField::zero(); ck.supported_size()];
+            let mut rand = G::ScalarField::zero();
+            let mut is_hiding = false;
+            for label in labels {
+                let (polynomial, randomness) =
+                    poly_rand.get(label as &str).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                let polynomial_coeffs = polynomial.polynomial().to_dense();
+                Self::check_degrees_and_bounds(ck, label, polynomial_coeffs.coeffs.len(), polynomial.degree_bound())?;
+                is_hiding |= polynomial.is_hiding();
+
+                // Add the polynomial, and its shifted polynomial if it has a degree bound.
+                let challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+                Self::add_scaled(&mut coeffs, 0, challenge, &polynomial_coeffs.coeffs);
+                rand += challenge * randomness.rand;
+                if let Some(degree_bound) = polynomial.degree_bound() {
+                    let challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+                    let shift = ck.max_degree - degree_bound;
+                    Self::add_scaled(&mut coeffs, shift, challenge, &polynomial_coeffs.coeffs);
+                    rand += challenge * randomness.shifted_rand.unwrap_or_else(G::ScalarField::zero);
+                }
+            }
+
+            let proof_time = start_timer!(|| "Creating proof");
+            proofs.push(Self::open(ck, coeffs, point, rand, is_hiding, fs_rng, rng)?);
+            end_timer!(proof_time);
+        }
+        end_timer!(open_time);
+
+        Ok(BatchProof(proofs))
+    }
+
+    /// Checks that `values` are the true evaluations at `query_set` of the polynomials
+    /// committed in `commitments`.
+    pub fn batch_check<'a>(
+        vk: &VerifierKey<G>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<G>>>,
+        query_set: &QuerySet<G::ScalarField>,
+        values: &Evaluations<G::ScalarField>,
+        proof: &BatchProof<G>,
+        fs_rng: &mut S,
+    ) -> Result<bool> {
+        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label().to_owned(), c)).collect();
+        let batch_check_time = start_timer!(|| format!(
+            "Checking {} commitments at query set of size {}",
+            commitments.len(),
+            query_set.len(),
+        ));
+
+        let mut query_to_labels_map = BTreeMap::new();
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+        ensure!(query_to_labels_map.len() == proof.0.len());
+
+        for ((_point_name, (&point, labels)), p) in query_to_labels_map.into_iter().zip_eq(&proof.0) {
+            let mut bases = Vec::with_capacity(2 * labels.len());
+            let mut scalars = Vec::with_capacity(2 * labels.len());
+            let mut value = G::ScalarField::zero();
+            for label in labels {
+                let commitment =
+                    commitments.get(label).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                let v_i = values
+                    .get(&(label.clone(), point))
+                    .ok_or(PCError::MissingEvaluation { label: label.to_string() })?;
+
+                // Add the commitment, and its shifted commitment if it has a degree bound.
+                let challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+                bases.push(commitment.commitment().comm);
+                scalars.push(challenge);
+                value += challenge * v_i;
+                if let Some(degree_bound) = commitment.degree_bound() {
+                    if degree_bound > vk.max_degree {
+                        bail!(PCError::UnsupportedDegreeBound(degree_bound));
+                    }
+                    let shifted_comm = commitment
+                        .commitment()
+                        .shifted_comm
+                        .ok_or_else(|| anyhow!("Missing the shifted commitment of '{label}'"))?;
+                    let challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+                    let shift = (vk.max_degree - degree_bound) as u64;
+                    bases.push(shifted_comm);
+                    scalars.push(challenge);
+                    value += challenge * v_i * point.pow([shift]);
+                }
+            }
+            let commitment = Self::msm(&bases, &scalars);
+
+            if !Self::check(vk, commitment, point, value, p, fs_rng)? {
+                end_timer!(batch_check_time);
+                return Ok(false);
+            }
+        }
+        end_timer!(batch_check_time);
+        Ok(true)
+    }
+
+    pub fn open_combinations<'a>(
+        ck: &CommitterKey<G>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<G::ScalarField>>,
+        polynomials: impl IntoIterator<Item = LabeledPolynomial<G::ScalarField>>,
+        rands: impl IntoIterator<Item = &'a Randomness<G>>,
+        query_set: &QuerySet<G::ScalarField>,
+        fs_rng: &mut S,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<BatchLCProof<G>> {
+        let label_map =
+            polynomials.into_iter().zip_eq(rands).map(|(p, r)| (p.to_label(), (p, r))).collect::<BTreeMap<_, _>>();
+
+        let mut lc_polynomials = Vec::new();
+        let mut lc_randomness = Vec::new();
+
+        for lc in linear_combinations {
+            let lc_label = lc.label().to_string();
+            let mut poly = DensePolynomial::zero();
+            let mut randomness = Randomness::empty();
+            let mut degree_bound = None;
+            let mut hiding_bound = None;
+
+            let num_polys = lc.len();
+            // We filter out l.is_one() entries because those constants are not committed to and used directly by the verifier.
+            for (coeff, label) in lc.iter().filter(|(_, l)| !l.is_one()) {
+                let label: &String = label.try_into().expect("cannot be one!");
+                let (cur_poly, cur_rand) =
+                    label_map.get(label as &str).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                if let Some(cur_degree_bound) = cur_poly.degree_bound() {
+                    if num_polys != 1 || !coeff.is_one() {
+                        bail!(PCError::EquationHasDegreeBounds(lc_label));
+                    }
+                    degree_bound = Some(cur_degree_bound);
+                }
+                // Some(_) > None, always.
+                hiding_bound = core::cmp::max(hiding_bound, cur_poly.hiding_bound());
+                poly += (*coeff, cur_poly.polynomial());
+                randomness += (*coeff, *cur_rand);
+            }
+
+            lc_polynomials.push(LabeledPolynomial::new(lc_label, poly, degree_bound, hiding_bound));
+            lc_randomness.push(randomness);
+        }
+
+        let proof = Self::batch_open(ck, lc_polynomials.iter(), query_set, lc_randomness.iter(), fs_rng, rng)?;
+
+        Ok(BatchLCProof { proof })
+    }
+
+    /// Checks that `evaluations` are the true evaluations at `query_set` of the linear combinations
+    /// of the polynomials committed in `commitments`.
+    pub fn check_combinations<'a>(
+        vk: &VerifierKey<G>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<G::ScalarField>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<G>>>,
+        query_set: &QuerySet<G::ScalarField>,
+        evaluations: &Evaluations<G::ScalarField>,
+        proof: &BatchLCProof<G>,
+        fs_rng: &mut S,
+    ) -> Result<bool> {
+        let BatchLCProof { proof } = proof;
+        let label_comm_map = commitments.into_iter().map(|c| (c.label(), c)).collect::<BTreeMap<_, _>>();
+
+        let mut lc_commitments = Vec::new();
+        let mut lc_shifted_commitments = Vec::new();
+        let mut lc_info = Vec::new();
+        let mut evaluations = evaluations.clone();
+
+        let lc_processing_time = start_timer!(|| "Combining commitments");
+        for lc in linear_combinations {
+            let lc_label = lc.label().to_string();
+            let num_polys = lc.len();
+
+            let mut degree_bound = None;
+            let mut shifted_comm = None;
+            let mut bases = Vec::with_capacity(num_polys);
+            let mut scalars = Vec::with_capacity(num_polys);
+
+            for (coeff, label) in lc.iter() {
+                if label.is_one() {
+                    for ((label, _), ref mut eval) in evaluations.iter_mut() {
+                        if label == &lc_label {
+                            **eval -= coeff;
+                        }
+                    }
+                } else {
+                    let label: &String = label.try_into().unwrap();
+                    let &cur_comm = label_comm_map
+                        .get(label as &str)
+                        .ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+
+                    if cur_comm.degree_bound().is_some() {
+                        if num_polys != 1 || !coeff.is_one() {
+                            bail!(PCError::EquationHasDegreeBounds(lc_label));
+                        }
+                        degree_bound = cur_comm.degree_bound();
+                        shifted_comm = cur_comm.commitment().shifted_comm;
+                    }
+                    bases.push(cur_comm.commitment().comm);
+                    scalars.push(*coeff);
+                }
+            }
+            lc_commitments.push(Self::msm(&bases, &scalars));
+            lc_shifted_commitments.push(shifted_comm);
+            lc_info.push((lc_label, degree_bound));
+        }
+        end_timer!(lc_processing_time);
+
+        let comms = G::Projective::batch_normalization_into_affine(lc_commitments);
+        ensure!(lc_info.len() == comms.len());
+        let lc_commitments = lc_info
+            .into_iter()
+            .zip_eq(comms)
+            .zip_eq(lc_shifted_commitments)
+            .map(|(((label, d), comm), shifted_comm)| {
+                LabeledCommitment::new(label, Commitment { comm, shifted_comm }, d)
+            })
+            .collect::<Vec<_>>();
+
+        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+    }
+}
+
+impl<G, S> InnerProductArgPC<G, S>
+where
+    G: AffineCurve + ToConstraintField<G::BaseField>,
+    G::BaseField: PrimeField,
+    S: AlgebraicSponge<G::BaseField, 2>,
+{
+    /// Returns the generator of the given index, by hashing the protocol name and the index to the curve.
+    fn sample_generator(index: usize) -> G {
+        let num_bytes = G::prime_subgroup_generator().compressed_size();
+        let mut counter = 0u64;
+        loop {
+            let input = format!("{} generator {index} in {counter}", Self::PROTOCOL_NAME);
+            let bytes = (0u64..)
+                .flat_map(|block| sha256(format!("{input} block {block}").as_bytes()))
+                .take(num_bytes)
+                .collect::<Vec<_>>();
+            if let Some(point) = G::from_random_bytes(&bytes) {
+                let point = point.mul_by_cofactor();
+                if !point.is_zero() {
+                    return point;
+                }
+            }
+            counter += 1;
+        }
+    }
+
+    /// Ensures the polynomial fits in the committer key, and its degree bound (if any) is valid.
+    fn check_degrees_and_bounds(
+        ck: &CommitterKey<G>,
+        label: &str,
+        num_coefficients: usize,
+        degree_bound: Option<usize>,
+    ) -> Result<(), PCError> {
+        if num_coefficients > ck.supported_size() {
+            return Err(PCError::TooManyCoefficients { num_coefficients, num_powers: ck.supported_size() });
+        }
+        if let Some(degree_bound) = degree_bound {
+            let poly_degree = num_coefficients.saturating_sub(1);
+            if degree_bound < poly_degree || degree_bound > ck.max_degree {
+                return Err(PCError::IncorrectDegreeBound {
+                    poly_degree,
+                    degree_bound,
+                    max_degree: ck.max_degree,
+                    label: label.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `Σ scalars[i] * bases[i]`.
+    fn msm(bases: &[G], scalars: &[G::ScalarField]) -> G::Projective {
+        if bases.is_empty() {
+            return G::Projective::zero();
+        }
+        let scalars = cfg_iter!(scalars).map(|scalar| scalar.to_bigint()).collect::<Vec<_>>();
+        VariableBase::msm(bases, &scalars)
+    }
+
+    /// Returns the commitment to the coefficients, placed at the given offset in the committer key.
+    fn commit_coefficients(
+        ck: &CommitterKey<G>,
+        coeffs: &[G::ScalarField],
+        offset: usize,
+        rand: G::ScalarField,
+    ) -> G::Projective {
+        let mut comm = Self::msm(&ck.comm_key[offset..offset + coeffs.len()], coeffs);
+        if !rand.is_zero() {
+            comm += ck.h * rand;
+        }
+        comm
+    }
+
+    /// Adds `scalar * coeffs` to `combined`, starting at the given offset.
+    fn add_scaled(combined: &mut [G::ScalarField], offset: usize, scalar: G::ScalarField, coeffs: &[G::ScalarField]) {
+        combined[offset..].iter_mut().zip(coeffs).for_each(|(combined, coeff)| *combined += scalar * coeff);
+    }
+
+    /// Returns the inner product of the given vectors.
+    fn inner_product(a: &[G::ScalarField], b: &[G::ScalarField]) -> G::ScalarField {
+        a.iter().zip_eq(b).map(|(a, b)| *a * b).sum()
+    }
+
+    /// Squeezes the challenge of a round of the argument, and returns it with its inverse.
+    fn round_challenge(fs_rng: &mut S) -> Result<(G::ScalarField, G::ScalarField)> {
+        let challenge = fs_rng.squeeze_nonnative_field_elements::<G::ScalarField>(1)[0];
+        let inverse = challenge.inverse().ok_or_else(|| anyhow!("The round challenge of the argument is zero"))?;
+        Ok((challenge, inverse))
+    }
+
+    /// Outputs a proof that the polynomial with the given coefficients evaluates to `p(point)` at `point`,
+    /// for the commitment with the given randomness.
+    fn open(
+        ck: &CommitterKey<G>,
+        mut coeffs: Vec<G::ScalarField>,
+        point: G::ScalarField,
+        rand: G::ScalarField,
+        is_hiding: bool,
+        fs_rng: &mut S,
+        rng: &mut OptionalRng<&mut dyn RngCore>,
+    ) -> Result<Proof<G>> {
+        let supported_size = ck.supported_size();
+        ensure!(coeffs.len() == supported_size, "The polynomial does not match the committer key");
+
+        // Add a random polynomial `q(X) = (X - point) * t(X)`, which vanishes at the point, and remove the randomness.
+        let (hiding_comm, rand) = match is_hiding {
+            true => {
+                if rng.0.is_none() {
+                    bail!(PCError::MissingRng);
+                }
+                let mut hiding_coeffs = vec![G::ScalarField::zero(); supported_size];
+                for i in 0..supported_size - 1 {
+                    let t_i = G::ScalarField::rand(rng);
+                    hiding_coeffs[i + 1] += t_i;
+                    hiding_coeffs[i] -= point * t_i;
+                }
+                let hiding_rand = G::ScalarField::rand(rng);
+                let hiding_comm = Self::commit_coefficients(ck, &hiding_coeffs, 0, hiding_rand).to_affine();
+
+                fs_rng.absorb_native_field_elements(&[hiding_comm]);
+                let hiding_challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+                Self::add_scaled(&mut coeffs, 0, hiding_challenge, &hiding_coeffs);
+                (Some(hiding_comm), Some(rand + hiding_challenge * hiding_rand))
+            }
+            false => {
+                ensure!(rand.is_zero(), "The commitment randomness of a non-hiding polynomial must be zero");
+                (None, None)
+            }
+        };
+
+        // Bind the argument to the commitment, the point, and the evaluation.
+        let mut b = Vec::with_capacity(supported_size);
+        let mut power = G::ScalarField::one();
+        for _ in 0..supported_size {
+            b.push(power);
+            power *= point;
+        }
+        let value = Self::inner_product(&coeffs, &b);
+        let comm = Self::commit_coefficients(ck, &coeffs, 0, G::ScalarField::zero()).to_affine();
+        fs_rng.absorb_native_field_elements(&[comm]);
+        fs_rng.absorb_nonnative_field_elements([point, value]);
+        let u = (ck.s * fs_rng.squeeze_nonnative_field_elements::<G::ScalarField>(1)[0]).to_affine();
+
+        // Fold the coefficients, the powers of the point, and the generators in half in each round.
+        let mut a = coeffs;
+        let mut generators = ck.comm_key.clone();
+        let num_rounds = supported_size.trailing_zeros() as usize;
+        let mut l_vec = Vec::with_capacity(num_rounds);
+        let mut r_vec = Vec::with_capacity(num_rounds);
+        while a.len() > 1 {
+            let half = a.len() / 2;
+            let (a_lo, a_hi) = a.split_at(half);
+            let (b_lo, b_hi) = b.split_at(half);
+            let (generators_lo, generators_hi) = generators.split_at(half);
+
+            // Compute `L = <a_lo, G_hi> + <a_lo, b_hi> * U` and `R = <a_hi, G_lo> + <a_hi, b_lo> * U`.
+            let l = Self::msm(generators_hi, a_lo) + u * Self::inner_product(a_lo, b_hi);
+            let r = Self::msm(generators_lo, a_hi) + u * Self::inner_product(a_hi, b_lo);
+            let [l, r]: [G; 2] = G::Projective::batch_normalization_into_affine(vec![l, r]).try_into().unwrap();
+            fs_rng.absorb_native_field_elements(&[l, r]);
+            l_vec.push(l);
+            r_vec.push(r);
+
+            // Compute `a' = a_lo + ξ * a_hi`, `b' = b_lo + ξ^{-1} * b_hi`, and `G' = G_lo + ξ^{-1} * G_hi`.
+            let (challenge, inverse) = Self::round_challenge(fs_rng)?;
+            let a_next = a_lo.iter().zip_eq(a_hi).map(|(lo, hi)| *lo + challenge * hi).collect();
+            let b_next = b_lo.iter().zip_eq(b_hi).map(|(lo, hi)| *lo + inverse * hi).collect();
+            let generators_next = cfg_iter!(generators_lo)
+                .zip_eq(generators_hi)
+                .map(|(lo, hi)| *hi * inverse + lo.to_projective())
+                .collect::<Vec<_>>();
+            a = a_next;
+            b = b_next;
+            generators = G::Projective::batch_normalization_into_affine(generators_next);
+        }
+
+        Ok(Proof { l_vec, r_vec, c: a[0], hiding_comm, rand })
+    }
+
+    /// Checks that the given proof is valid for the commitment, the point, and the evaluation.
+    fn check(
+        vk: &VerifierKey<G>,
+        commitment: G::Projective,
+        point: G::ScalarField,
+        value: G::ScalarField,
+        proof: &Proof<G>,
+        fs_rng: &mut S,
+    ) -> Result<bool> {
+        let check_time = start_timer!(|| "Checking evaluation proof");
+        let supported_size = vk.supported_size();
+        let num_rounds = supported_size.trailing_zeros() as usize;
+        if proof.l_vec.len() != num_rounds || proof.r_vec.len() != num_rounds {
+            end_timer!(check_time);
+            return Ok(false);
+        }
+
+        // Add the hiding polynomial, and remove the randomness.
+        let mut commitment = commitment;
+        match (proof.hiding_comm, proof.rand) {
+            (Some(hiding_comm), Some(rand)) => {
+                fs_rng.absorb_native_field_elements(&[hiding_comm]);
+                let hiding_challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+                commitment += hiding_comm * hiding_challenge;
+                commitment -= vk.h * rand;
+            }
+            (None, None) => {}
+            _ => {
+                end_timer!(check_time);
+                return Ok(false);
+            }
+        }
+
+        // Bind the argument to the commitment, the point, and the evaluation.
+        fs_rng.absorb_native_field_elements(&[commitment.to_affine()]);
+        fs_rng.absorb_nonnative_field_elements([point, value]);
+        let u = vk.s * fs_rng.squeeze_nonnative_field_elements::<G::ScalarField>(1)[0];
+
+        // Fold the commitment, as `C' = C + ξ^{-1} * L + ξ * R` in each round.
+        let mut commitment = commitment + u * value;
+        let mut inverses = Vec::with_capacity(num_rounds);
+        for (l, r) in proof.l_vec.iter().zip_eq(&proof.r_vec) {
+            fs_rng.absorb_native_field_elements(&[*l, *r]);
+            let (challenge, inverse) = Self::round_challenge(fs_rng)?;
+            commitment += *l * inverse + *r * challenge;
+            inverses.push(inverse);
+        }
+
+        // Compute the folded generator `Σ s_i * G_i`, where `s_i` is the product of the inverse challenges
+        // of the rounds in which the index `i` is in the upper half, and the folded power `Σ s_i * point^i`.
+        let mut s = Vec::with_capacity(supported_size);
+        s.push(G::ScalarField::one());
+        for inverse in inverses.iter().rev() {
+            let upper = s.iter().map(|s_i| *s_i * inverse).collect::<Vec<_>>();
+            s.extend(upper);
+        }
+        let generator = Self::msm(&vk.comm_key, &s);
+        let mut b = G::ScalarField::one();
+        let mut power = point;
+        for inverse in inverses.iter().rev() {
+            b *= G::ScalarField::one() + *inverse * power;
+            power.square_in_place();
+        }
+
+        let is_valid = commitment == generator * proof.c + u * (proof.c * b);
+        end_timer!(check_time);
+        Ok(is_valid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_camel_case_types)]
+
+    use super::*;
+    use crate::crypto_hash::PoseidonSponge;
+    use snarkvm_curves::bls12_377::{Fq, Fr, G1Affine};
+    use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
+
+    use rand::Rng;
+
+    type Sponge = PoseidonSponge<Fq, 2, 1>;
+    type IPA_Bls12_377 = InnerProductArgPC<G1Affine, Sponge>;
+
+    const MAX_DEGREE: usize = 63;
+
+    /// Commits to random polynomials, opens them at random points, and returns the result of the check.
+    fn run_test(
+        num_polynomials: usize,
+        num_points: usize,
+        use_degree_bounds: bool,
+        is_hiding: bool,
+        corrupt_value: bool,
+        rng: &mut TestRng,
+    ) -> Result<bool> {
+        let pp = IPA_Bls12_377::setup(MAX_DEGREE)?;
+        let supported_degree = rng.gen_range(1..=MAX_DEGREE);
+        let (ck, vk) = IPA_Bls12_377::trim(&pp, supported_degree)?;
+
+        let mut polynomials = Vec::with_capacity(num_polynomials);
+        for i in 0..num_polynomials {
+            let degree = rng.gen_range(1..=supported_degree);
+            let degree_bound = match use_degree_bounds {
+                true => Some(rng.gen_range(degree..=ck.max_degree)),
+                false => None,
+            };
+            let hiding_bound = is_hiding.then_some(1);
+            let polynomial = DensePolynomial::rand(degree, rng);
+            polynomials.push(LabeledPolynomial::new(format!("Test{i}"), polynomial, degree_bound, hiding_bound));
+        }
+
+        let (comms, rands) = IPA_Bls12_377::commit(&ck, polynomials.iter().map(Into::into), Some(rng))?;
+        assert!(comms.iter().all(|comm| comm.commitment().shifted_comm.is_some() == use_degree_bounds));
+        assert!(rands.iter().all(|rand| rand.is_hiding() == is_hiding));
+
+        let mut query_set = QuerySet::new();
+        let mut values = Evaluations::new();
+        for i in 0..num_points {
+            let point = Fr::rand(rng);
+            for polynomial in polynomials.iter() {
+                query_set.insert((polynomial.to_label(), (format!("{i}"), point)));
+                values.insert((polynomial.to_label(), point), polynomial.evaluate(point));
+            }
+        }
+        if corrupt_value {
+            let value = values.values_mut().next().unwrap();
+            *value += Fr::one();
+        }
+
+        let mut sponge_for_open = Sponge::new();
+        let proof = IPA_Bls12_377::batch_open(&ck, &polynomials, &query_set, &rands, &mut sponge_for_open, Some(rng))?;
+        assert_eq!(proof.is_hiding(), is_hiding);
+        assert_eq!(proof.proofs().len(), num_points);
+
+        // Ensure the proof round-trips through serialization.
+        let proof = BatchLCProof { proof };
+        let proof_bytes = proof.to_bytes_le()?;
+        assert_eq!(proof, BatchLCProof::read_le(&proof_bytes[..])?);
+        let BatchLCProof { proof } = proof;
+
+        let mut sponge_for_check = Sponge::new();
+        IPA_Bls12_377::batch_check(&vk, &comms, &query_set, &values, &proof, &mut sponge_for_check)
+    }
+
+    #[test]
+    fn test_setup_is_deterministic() {
+        let pp_a = IPA_Bls12_377::setup(MAX_DEGREE).unwrap();
+        let pp_b = IPA_Bls12_377::setup(MAX_DEGREE).unwrap();
+        assert_eq!(pp_a.comm_key, pp_b.comm_key);
+        assert_eq!(pp_a.max_degree(), MAX_DEGREE);
+        assert_ne!(pp_a.h, pp_a.s);
+        assert!(!pp_a.comm_key.contains(&pp_a.h));
+
+        let (ck, _) = IPA_Bls12_377::trim(&pp_a, 20).unwrap();
+        assert_eq!(ck.supported_size(), 32);
+        assert_eq!(&ck.comm_key[..], &pp_a.comm_key[..32]);
+        assert!(IPA_Bls12_377::trim(&pp_a, 64).is_err());
+    }
+
+    #[test]
+    fn test_single_poly() {
+        let rng = &mut TestRng::default();
+        assert!(run_test(1, 1, false, false, false, rng).unwrap());
+    }
+
+    #[test]
+    fn test_multiple_polys_multiple_points() {
+        let rng = &mut TestRng::default();
+        assert!(run_test(5, 3, false, false, false, rng).unwrap());
+    }
+
+    #[test]
+    fn test_degree_bounds() {
+        let rng = &mut TestRng::default();
+        assert!(run_test(4, 2, true, false, false, rng).unwrap());
+    }
+
+    #[test]
+    fn test_hiding() {
+        let rng = &mut TestRng::default();
+        assert!(run_test(4, 2, false, true, false, rng).unwrap());
+        assert!(run_test(4, 2, true, true, false, rng).unwrap());
+    }
+
+    #[test]
+    fn test_incorrect_evaluation_fails() {
+        let rng = &mut TestRng::default();
+        assert!(!run_test(3, 2, false, false, true, rng).unwrap());
+        assert!(!run_test(3, 2, true, true, true, rng).unwrap());
+    }
+
+    #[test]
+    fn test_violated_degree_bound_fails() {
+        let rng = &mut TestRng::default();
+        let pp = IPA_Bls12_377::setup(MAX_DEGREE).unwrap();
+        let (ck, _) = IPA_Bls12_377::trim(&pp, MAX_DEGREE).unwrap();
+
+        let polynomial = DensePolynomial::<Fr>::rand(10, rng);
+        let polynomial = LabeledPolynomial::new("Test".to_string(), polynomial, Some(5), None);
+        assert!(IPA_Bls12_377::commit(&ck, [(&polynomial).into()], None).is_err());
+    }
+
+    #[test]
+    fn test_hiding_requires_rng() {
+        let rng = &mut TestRng::default();
+        let pp = IPA_Bls12_377::setup(MAX_DEGREE).unwrap();
+        let (ck, _) = IPA_Bls12_377::trim(&pp, MAX_DEGREE).unwrap();
+
+        let polynomial = DensePolynomial::<Fr>::rand(10, rng);
+        let polynomial = LabeledPolynomial::new("Test".to_string(), polynomial, None, Some(1));
+        assert!(matches!(IPA_Bls12_377::commit(&ck, [(&polynomial).into()], None), Err(PCError::MissingRng)));
+    }
+
+    #[test]
+    fn test_linear_combinations() {
+        let rng = &mut TestRng::default();
+        let pp = IPA_Bls12_377::setup(MAX_DEGREE).unwrap();
+        let (ck, vk) = IPA_Bls12_377::trim(&pp, MAX_DEGREE).unwrap();
+
+        let polynomials = (0..3)
+            .map(|i| {
+                let polynomial = DensePolynomial::rand(rng.gen_range(1..=MAX_DEGREE), rng);
+                LabeledPolynomial::new(format!("Test{i}"), polynomial, None, Some(1))
+            })
+            .collect::<Vec<_>>();
+        let (comms, rands) = IPA_Bls12_377::commit(&ck, polynomials.iter().map(Into::into), Some(rng)).unwrap();
+
+        // Compute `lc = a * Test0 + b * Test1 - Test2 + c`.
+        let (a, b, c) = (Fr::rand(rng), Fr::rand(rng), Fr::rand(rng));
+        let mut lc = LinearCombination::empty("lc");
+        lc.add(a, "Test0").add(b, "Test1").add(-Fr::one(), "Test2").add(c, crate::polycommit::sonic_pc::LCTerm::One);
+
+        let point = Fr::rand(rng);
+        let value = a * polynomials[0].evaluate(point) + b * polynomials[1].evaluate(point)
+            - polynomials[2].evaluate(point)
+            + c;
+        let mut query_set = QuerySet::new();
+        query_set.insert(("lc".to_string(), ("point".to_string(), point)));
+        let mut evaluations = Evaluations::new();
+        evaluations.insert(("lc".to_string(), point), value);
+
+        let proof = IPA_Bls12_377::open_combinations(
+            &ck,
+            [&lc],
+            polynomials.clone(),
+            &rands,
+            &query_set,
+            &mut Sponge::new(),
+            Some(rng),
+        )
+        .unwrap();
+        let result =
+            IPA_Bls12_377::check_combinations(&vk, [&lc], &comms, &query_set, &evaluations, &proof, &mut Sponge::new());
+        assert!(result.unwrap());
+
+        // Ensure an incorrect evaluation of the linear combination is rejected.
+        evaluations.insert(("lc".to_string(), point), value + Fr::one());
+        let result =
+            IPA_Bls12_377::check_combinations(&vk, [&lc], &comms, &query_set, &evaluations, &proof, &mut Sponge::new());
+        assert!(!result.unwrap());
+    }
+}
@@ -33,6 +33,8 @@ pub mod kzg10;
 /// [al]: https://eprint.iacr.org/2019/601
 pub mod sonic_pc;
 
+pub mod ipa_pc;
+
 /// Errors pertaining to query sets.
 pub mod error;
 pub use error::*;
@@ -390,6 +390,11 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
 pub struct BatchProof<E: PairingEngine>(pub(crate) Vec<kzg10::KZGProof<E>>);
 
 impl<E: PairingEngine> BatchProof<E> {
+    /// Returns the KZG proofs, one per query point.
+    pub fn proofs(&self) -> &[kzg10::KZGProof<E>] {
+        &self.0
+    }
+
     pub fn is_hiding(&self) -> bool {
         self.0.iter().any(|c| c.is_hiding())
     }
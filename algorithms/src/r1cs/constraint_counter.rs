@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::r1cs::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, Variable};
+use crate::r1cs::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, LookupTable, Variable};
 use snarkvm_fields::Field;
 
 /// Constraint counter for testing purposes.
@@ -21,6 +21,7 @@ pub struct ConstraintCounter {
     pub num_public_variables: usize,
     pub num_private_variables: usize,
     pub num_constraints: usize,
+    pub num_lookup_tables: usize,
 }
 
 impl<ConstraintF: Field> ConstraintSystem<ConstraintF> for ConstraintCounter {
@@ -60,6 +61,22 @@ impl<ConstraintF: Field> ConstraintSystem<ConstraintF> for ConstraintCounter {
         self.num_constraints += 1;
     }
 
+    fn add_lookup_table(&mut self, _: LookupTable<ConstraintF>) -> usize {
+        self.num_lookup_tables += 1;
+        self.num_lookup_tables - 1
+    }
+
+    fn enforce_lookup<A, AR, LA, LB, LC>(&mut self, _: A, _: LA, _: LB, _: LC, _: usize)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<ConstraintF>) -> LinearCombination<ConstraintF>,
+        LB: FnOnce(LinearCombination<ConstraintF>) -> LinearCombination<ConstraintF>,
+        LC: FnOnce(LinearCombination<ConstraintF>) -> LinearCombination<ConstraintF>,
+    {
+        self.num_constraints += 1;
+    }
+
     fn push_namespace<NR, N>(&mut self, _: N)
     where
         NR: AsRef<str>,
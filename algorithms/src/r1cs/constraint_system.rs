@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::r1cs::{errors::SynthesisError, Index, LinearCombination, Namespace, Variable};
+use crate::r1cs::{errors::SynthesisError, Index, LinearCombination, LookupTable, Namespace, Variable};
 use snarkvm_fields::Field;
 
 use std::marker::PhantomData;
@@ -66,6 +66,21 @@ pub trait ConstraintSystem<F: Field>: Sized {
         LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
         LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>;
 
+    /// Registers a lookup table with the constraint system, and returns its index.
+    /// Tables must be registered before any lookup constraint refers to them.
+    fn add_lookup_table(&mut self, table: LookupTable<F>) -> usize;
+
+    /// Enforce that (`A`, `B`, `C`) is a row of the lookup table at `table_index`.
+    /// The `annotation` function is invoked in testing contexts in order to derive
+    /// a unique name for the constraint in the current namespace.
+    fn enforce_lookup<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC, table_index: usize)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>;
+
     /// Create a new (sub)namespace and enter into it. Not intended
     /// for downstream use; use `namespace` instead.
     fn push_namespace<NR, N>(&mut self, name_fn: N)
@@ -147,6 +162,23 @@ impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for &mut CS {
         (**self).enforce(annotation, a, b, c)
     }
 
+    #[inline]
+    fn add_lookup_table(&mut self, table: LookupTable<F>) -> usize {
+        (**self).add_lookup_table(table)
+    }
+
+    #[inline]
+    fn enforce_lookup<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC, table_index: usize)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        (**self).enforce_lookup(annotation, a, b, c, table_index)
+    }
+
     #[inline]
     fn push_namespace<NR, N>(&mut self, name_fn: N)
     where
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::Field;
+
+use indexmap::IndexSet;
+
+/// A fixed table of `(a, b, c)` rows that lookup constraints can be checked against.
+///
+/// A lookup constraint enforces that the evaluations of its three linear combinations
+/// form one of the rows of the table. The rows are kept in insertion order, which
+/// determines their position when the table is encoded in the index.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LookupTable<F: Field> {
+    /// The distinct rows of the table, in insertion order.
+    rows: IndexSet<[F; 3]>,
+}
+
+impl<F: Field> LookupTable<F> {
+    /// Initializes a new, empty lookup table.
+    pub fn new() -> Self {
+        Self { rows: IndexSet::new() }
+    }
+
+    /// Inserts the given row into the table, ignoring duplicates.
+    pub fn insert(&mut self, row: [F; 3]) {
+        self.rows.insert(row);
+    }
+
+    /// Returns `true` if the table contains the given row.
+    pub fn contains(&self, row: &[F; 3]) -> bool {
+        self.rows.contains(row)
+    }
+
+    /// Returns the position of the given row in the table, if it exists.
+    pub fn index_of(&self, row: &[F; 3]) -> Option<usize> {
+        self.rows.get_index_of(row)
+    }
+
+    /// Returns the number of rows in the table.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns `true` if the table has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns an iterator over the rows of the table, in insertion order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &[F; 3]> {
+        self.rows.iter()
+    }
+}
+
+impl<F: Field> FromIterator<[F; 3]> for LookupTable<F> {
+    fn from_iter<I: IntoIterator<Item = [F; 3]>>(iter: I) -> Self {
+        Self { rows: iter.into_iter().collect() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+
+    #[test]
+    fn test_lookup_table() {
+        let xor = (0u64..4)
+            .flat_map(|a| (0u64..4).map(move |b| [Fr::from(a), Fr::from(b), Fr::from(a ^ b)]))
+            .collect::<LookupTable<Fr>>();
+        assert_eq!(xor.len(), 16);
+        assert!(!xor.is_empty());
+        assert!(xor.contains(&[Fr::one(), Fr::from(2u64), Fr::from(3u64)]));
+        assert!(!xor.contains(&[Fr::one(), Fr::one(), Fr::one()]));
+        assert_eq!(xor.index_of(&[Fr::zero(), Fr::one(), Fr::one()]), Some(1));
+
+        // Duplicate rows are ignored.
+        let mut table = xor.clone();
+        table.insert([Fr::zero(), Fr::zero(), Fr::zero()]);
+        assert_eq!(table, xor);
+        assert!(LookupTable::<Fr>::new().is_empty());
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::r1cs::{errors::SynthesisError, ConstraintSystem, LinearCombination, Variable};
+use crate::r1cs::{errors::SynthesisError, ConstraintSystem, LinearCombination, LookupTable, Variable};
 use snarkvm_fields::Field;
 
 use std::marker::PhantomData;
@@ -61,6 +61,23 @@ impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for Namespace<'_, F,
         self.0.enforce(annotation, a, b, c)
     }
 
+    #[inline]
+    fn add_lookup_table(&mut self, table: LookupTable<F>) -> usize {
+        self.0.add_lookup_table(table)
+    }
+
+    #[inline]
+    fn enforce_lookup<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC, table_index: usize)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.0.enforce_lookup(annotation, a, b, c, table_index)
+    }
+
     // Downstream users who use `namespace` will never interact with these
     // functions and they will never be invoked because the namespace is
     // never a root constraint system.
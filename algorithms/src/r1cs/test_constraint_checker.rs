@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::r1cs::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, Variable};
+use crate::r1cs::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, LookupTable, Variable};
 use snarkvm_fields::Field;
 
 /// Constraint system for testing purposes.
@@ -29,6 +29,8 @@ pub struct TestConstraintChecker<F: Field> {
     segments: Vec<String>,
     // the first unsatisfied constraint
     first_unsatisfied_constraint: Option<String>,
+    // the registered lookup tables
+    lookup_tables: Vec<LookupTable<F>>,
 }
 
 impl<F: Field> Default for TestConstraintChecker<F> {
@@ -40,6 +42,7 @@ impl<F: Field> Default for TestConstraintChecker<F> {
             num_constraints: 0,
             segments: vec![],
             first_unsatisfied_constraint: None,
+            lookup_tables: vec![],
         }
     }
 }
@@ -67,6 +70,31 @@ impl<F: Field> TestConstraintChecker<F> {
     pub fn public_inputs(&self) -> Vec<F> {
         self.public_variables[1..].to_vec()
     }
+
+    fn eval_lc(&self, lc: LinearCombination<F>) -> F {
+        lc.0.into_iter()
+            .map(|(var, coeff)| {
+                let value = match var.get_unchecked() {
+                    Index::Public(index) => self.public_variables[index],
+                    Index::Private(index) => self.private_variables[index],
+                };
+                value * coeff
+            })
+            .sum::<F>()
+    }
+
+    fn mark_unsatisfied<A: FnOnce() -> AR, AR: AsRef<str>>(&mut self, annotation: A) {
+        if self.first_unsatisfied_constraint.is_none() {
+            self.found_unsatisfactory_constraint = true;
+
+            let new = annotation().as_ref().to_string();
+            assert!(!new.contains('/'), "'/' is not allowed in names");
+
+            let mut path = self.segments.clone();
+            path.push(new);
+            self.first_unsatisfied_constraint = Some(path.join("/"));
+        }
+    }
 }
 
 impl<F: Field> ConstraintSystem<F> for TestConstraintChecker<F> {
@@ -108,31 +136,36 @@ impl<F: Field> ConstraintSystem<F> for TestConstraintChecker<F> {
     {
         self.num_constraints += 1;
 
-        let eval_lc = |lc: Vec<(Variable, F)>| -> F {
-            lc.into_iter()
-                .map(|(var, coeff)| {
-                    let value = match var.get_unchecked() {
-                        Index::Public(index) => self.public_variables[index],
-                        Index::Private(index) => self.private_variables[index],
-                    };
-                    value * coeff
-                })
-                .sum::<F>()
-        };
-
-        let a = eval_lc(a(LinearCombination::zero()).0);
-        let b = eval_lc(b(LinearCombination::zero()).0);
-        let c = eval_lc(c(LinearCombination::zero()).0);
-
-        if a * b != c && self.first_unsatisfied_constraint.is_none() {
-            self.found_unsatisfactory_constraint = true;
+        let a = self.eval_lc(a(LinearCombination::zero()));
+        let b = self.eval_lc(b(LinearCombination::zero()));
+        let c = self.eval_lc(c(LinearCombination::zero()));
 
-            let new = annotation().as_ref().to_string();
-            assert!(!new.contains('/'), "'/' is not allowed in names");
+        if a * b != c {
+            self.mark_unsatisfied(annotation);
+        }
+    }
 
-            let mut path = self.segments.clone();
-            path.push(new);
-            self.first_unsatisfied_constraint = Some(path.join("/"));
+    fn add_lookup_table(&mut self, table: LookupTable<F>) -> usize {
+        self.lookup_tables.push(table);
+        self.lookup_tables.len() - 1
+    }
+
+    fn enforce_lookup<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC, table_index: usize)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.num_constraints += 1;
+
+        let a = self.eval_lc(a(LinearCombination::zero()));
+        let b = self.eval_lc(b(LinearCombination::zero()));
+        let c = self.eval_lc(c(LinearCombination::zero()));
+
+        if !self.lookup_tables.get(table_index).map_or(false, |table| table.contains(&[a, b, c])) {
+            self.mark_unsatisfied(annotation);
         }
     }
 
@@ -48,6 +48,20 @@ pub(crate) fn witness_label(circuit_id: CircuitId, poly: &str, i: usize) -> Stri
     label
 }
 
+#[cfg(test)]
+thread_local! {
+    /// Whether the prover skips its lookup checks, so that tests can ensure the verifier rejects invalid lookups.
+    pub(crate) static SKIP_LOOKUP_CHECKS: core::cell::Cell<bool> = core::cell::Cell::new(false);
+}
+
+/// Returns `true` if the prover checks that the lookups hold.
+pub(crate) fn lookup_checks_enabled() -> bool {
+    #[cfg(test)]
+    return !SKIP_LOOKUP_CHECKS.with(|skip| skip.get());
+    #[cfg(not(test))]
+    true
+}
+
 pub(crate) struct NonZeroDomains<F: PrimeField> {
     pub(crate) max_non_zero_domain: Option<EvaluationDomain<F>>,
     pub(crate) domain_a: EvaluationDomain<F>,
@@ -60,6 +74,16 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     /// These correspond to the virtual commitments as noted in the Aleo varuna protocol docs
     pub const LC_WITH_ZERO_EVAL: [&'static str; 3] = ["matrix_sumcheck", "lineval_sumcheck", "rowcheck_zerocheck"];
 
+    /// The linear combinations of the lookup argument that are statically known to evaluate to zero.
+    /// There is one of each for every instance of a circuit with lookups.
+    const LOOKUP_LC_WITH_ZERO_EVAL: [&'static str; 3] =
+        ["_lookup_h_zerocheck_", "_lookup_g_zerocheck_", "_lookup_sumcheck_"];
+
+    /// Returns whether the linear combination with the given label is statically known to evaluate to zero.
+    pub fn lc_has_zero_eval(label: &str) -> bool {
+        Self::LC_WITH_ZERO_EVAL.contains(&label) || Self::LOOKUP_LC_WITH_ZERO_EVAL.iter().any(|lc| label.contains(lc))
+    }
+
     pub fn zk_bound() -> Option<usize> {
         SM::ZK.then_some(1)
     }
@@ -195,7 +219,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             formatted_public_inputs.push(public_inputs_i);
         }
 
-        let verifier::FirstMessage { batch_combiners } = state.first_round_message.as_ref().unwrap();
+        let verifier::FirstMessage { batch_combiners, lookup_challenges } = state.first_round_message.as_ref().unwrap();
         let verifier::SecondMessage { alpha, eta_b, eta_c } = state.second_round_message.unwrap();
         let verifier::ThirdMessage { beta } = state.third_round_message.unwrap();
         let batch_lineval_sum =
@@ -239,7 +263,12 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                     let sum_b_third = third_sums_i[j].sum_b;
                     let sum_c_third = third_sums_i[j].sum_c;
 
-                    rowcheck.add(sum_a_third * sum_b_third - sum_c_third, LCTerm::One);
+                    let rowcheck_at_alpha = sum_a_third * sum_b_third - sum_c_third;
+                    rowcheck.add(rowcheck_at_alpha, LCTerm::One);
+                    // Lookup constraints are checked by the lookup argument instead of the rowcheck.
+                    if circuit_state.has_lookups {
+                        rowcheck.add(-rowcheck_at_alpha, format!("circuit_{id}_lookup_selector"));
+                    }
 
                     circuit_term += (*instance_combiner, &rowcheck);
                 }
@@ -258,6 +287,28 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         linear_combinations.insert("rowcheck_zerocheck".into(), rowcheck_zerocheck);
         end_timer!(rowcheck_time);
 
+        // Lookup zerochecks and sumchecks:
+        if let Some(verifier::LookupChallenges { zeta, shift }) = lookup_challenges {
+            let lookup_time = start_timer!(|| "Lookups");
+            let zetas = [F::one(), *zeta, zeta.square(), zeta.square() * zeta];
+            for (i, (&id, circuit_state)) in state.circuit_specific_states.iter().enumerate() {
+                if !circuit_state.has_lookups {
+                    continue;
+                }
+                let lookup_lcs = Self::construct_lookup_linear_combinations(
+                    evals,
+                    id,
+                    &prover_third_message.sums[i],
+                    circuit_state.constraint_domain.evaluate_vanishing_polynomial(alpha),
+                    alpha,
+                    zetas,
+                    *shift,
+                )?;
+                linear_combinations.extend(lookup_lcs.into_iter().map(|lc| (lc.label.clone(), lc)));
+            }
+            end_timer!(lookup_time);
+        }
+
         // Lineval sumcheck:
         let lineval_time = start_timer!(|| "Lineval");
 
@@ -387,6 +438,65 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         Ok(linear_combinations)
     }
 
+    /// Constructs the linear combinations of the lookup argument of the given circuit at `alpha`.
+    ///
+    /// For each instance, `h` and `g` must interpolate `selector / (shift - f)` and `m / (shift - t)`
+    /// over the constraint domain, where `f` combines the looked-up rows and `t` the table rows,
+    /// and `h - g` must sum to zero over the constraint domain.
+    #[allow(clippy::too_many_arguments, non_snake_case)]
+    fn construct_lookup_linear_combinations<E: EvaluationsProvider<F>>(
+        evals: &E,
+        id: CircuitId,
+        third_sums: &[prover::MatrixSums<F>],
+        v_R_at_alpha: F,
+        alpha: F,
+        zetas: [F; 4],
+        shift: F,
+    ) -> Result<Vec<LinearCombination<F>>> {
+        let label = |poly: &str| format!("circuit_{id}_{poly}");
+        let table_columns = ["table_a", "table_b", "table_c", "table_tag"].map(label);
+        let table = LinearCombination::new(label("lookup_table"), zetas.into_iter().zip_eq(table_columns));
+        let tag = LinearCombination::new(label("lookup_tag"), [(F::one(), label("lookup_tag"))]);
+        let table_at_alpha = evals.get_lc_eval(&table, alpha)?;
+        let tag_at_alpha = evals.get_lc_eval(&tag, alpha)?;
+
+        let mut linear_combinations = Vec::with_capacity(2 + 4 * third_sums.len());
+        for (j, sums) in third_sums.iter().enumerate() {
+            let looked_up_at_alpha = [sums.sum_a, sums.sum_b, sums.sum_c, tag_at_alpha]
+                .into_iter()
+                .zip_eq(zetas)
+                .map(|(f, zeta)| f * zeta)
+                .sum::<F>();
+            let h = witness_label(id, "lookup_h", j);
+            let g = witness_label(id, "lookup_g", j);
+            let h_zerocheck = LinearCombination::new(witness_label(id, "lookup_h_zerocheck", j), [
+                (shift - looked_up_at_alpha, h.clone()),
+                (-F::one(), label("lookup_selector")),
+                (-v_R_at_alpha, witness_label(id, "lookup_qh", j)),
+            ]);
+            let g_zerocheck = LinearCombination::new(witness_label(id, "lookup_g_zerocheck", j), [
+                (shift - table_at_alpha, g.clone()),
+                (-F::one(), witness_label(id, "lookup_m", j)),
+                (-v_R_at_alpha, witness_label(id, "lookup_qg", j)),
+            ]);
+            // `s` is degree-bounded, so it is opened on its own rather than within the sumcheck.
+            let s = witness_label(id, "lookup_s", j);
+            let s = LinearCombination::new(s.clone(), [(F::one(), s)]);
+            let s_at_alpha = evals.get_lc_eval(&s, alpha)?;
+            let sumcheck = LinearCombination::new(witness_label(id, "lookup_sumcheck", j), [
+                (F::one(), h.into()),
+                (-F::one(), g.into()),
+                (-alpha * s_at_alpha, LCTerm::One),
+            ]);
+            debug_assert!(evals.get_lc_eval(&h_zerocheck, alpha)?.is_zero());
+            debug_assert!(evals.get_lc_eval(&g_zerocheck, alpha)?.is_zero());
+            debug_assert!(!lookup_checks_enabled() || evals.get_lc_eval(&sumcheck, alpha)?.is_zero());
+            linear_combinations.extend([h_zerocheck, g_zerocheck, s, sumcheck]);
+        }
+        linear_combinations.extend([table, tag]);
+        Ok(linear_combinations)
+    }
+
     fn construct_g_m_term(
         gamma: F,
         g_m_at_gamma: F,
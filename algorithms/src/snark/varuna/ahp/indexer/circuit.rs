@@ -21,7 +21,7 @@ use crate::{
     },
     polycommit::sonic_pc::LabeledPolynomial,
     snark::varuna::{
-        ahp::matrices::MatrixEvals,
+        ahp::{indexer::LookupEvals, matrices::MatrixEvals},
         matrices::MatrixArithmetization,
         AHPForR1CS,
         CircuitInfo,
@@ -62,6 +62,7 @@ impl CircuitId {
 ///     public input
 /// 2) `{a,b,c}` are the matrices defining the R1CS instance
 /// 3) `{a,b,c}_arith` are structs containing information about the arithmetized matrices
+/// 4) `lookup_evals` are the evaluations of the lookup index polynomials, if the circuit has lookups
 #[derive(Debug)]
 pub struct Circuit<F: PrimeField, SM: SNARKMode> {
     /// Information about the indexed circuit.
@@ -79,6 +80,9 @@ pub struct Circuit<F: PrimeField, SM: SNARKMode> {
     pub b_arith: MatrixEvals<F>,
     pub c_arith: MatrixEvals<F>,
 
+    /// The lookup selector, tags and tables, if the circuit has lookup constraints.
+    pub lookup_evals: Option<LookupEvals<F>>,
+
     pub fft_precomputation: FFTPrecomputation<F>,
     pub ifft_precomputation: IFFTPrecomputation<F>,
     pub(crate) _mode: PhantomData<SM>,
@@ -110,15 +114,25 @@ impl<F: PrimeField, SM: SNARKMode> Circuit<F, SM> {
         a: &Matrix<F>,
         b: &Matrix<F>,
         c: &Matrix<F>,
+        lookup_evals: Option<&LookupEvals<F>>,
     ) -> Result<CircuitId, SerializationError> {
         let mut blake2 = blake2::Blake2s256::new();
         index_info.serialize_uncompressed(&mut blake2)?;
         a.serialize_uncompressed(&mut blake2)?;
         b.serialize_uncompressed(&mut blake2)?;
         c.serialize_uncompressed(&mut blake2)?;
+        // Circuits without lookups keep the identifier they had before lookups were supported.
+        if let Some(lookup_evals) = lookup_evals {
+            lookup_evals.serialize_uncompressed(&mut blake2)?;
+        }
         Ok(CircuitId(blake2.finalize().into()))
     }
 
+    /// Returns `true` if the circuit has lookup constraints.
+    pub fn has_lookups(&self) -> bool {
+        self.lookup_evals.is_some()
+    }
+
     /// The maximum degree required to represent polynomials of this index.
     pub fn max_degree(&self) -> Result<usize> {
         self.index_info.max_degree::<F, SM>()
@@ -146,6 +160,13 @@ impl<F: PrimeField, SM: SNARKMode> Circuit<F, SM> {
         Ok(iters.into_iter().flatten())
     }
 
+    /// Interpolates all of the index polynomials, which are the matrix arithmetizations followed by
+    /// the lookup index polynomials, if any.
+    pub fn interpolate_index_polynomials(&self) -> Result<impl Iterator<Item = LabeledPolynomial<F>> + '_> {
+        let lookup_polynomials = self.lookup_evals.iter().flat_map(|evals| evals.interpolate(&self.id));
+        Ok(self.interpolate_matrix_evals()?.chain(lookup_polynomials))
+    }
+
     /// After indexing, we drop these evaluations to save space in the ProvingKey.
     pub fn prune_row_col_evals(&mut self) {
         self.a_arith.row_col = None;
@@ -163,6 +184,10 @@ impl<F: PrimeField, SM: SNARKMode> CanonicalSerialize for Circuit<F, SM> {
         self.a_arith.serialize_with_mode(&mut writer, compress)?;
         self.b_arith.serialize_with_mode(&mut writer, compress)?;
         self.c_arith.serialize_with_mode(&mut writer, compress)?;
+        // The lookup data is only present for circuits with lookups; see `Self::deserialize_with_lookups`.
+        if let Some(lookup_evals) = &self.lookup_evals {
+            lookup_evals.serialize_with_mode(&mut writer, compress)?;
+        }
         Ok(())
     }
 
@@ -175,6 +200,7 @@ impl<F: PrimeField, SM: SNARKMode> CanonicalSerialize for Circuit<F, SM> {
             .saturating_add(self.a_arith.serialized_size(mode))
             .saturating_add(self.b_arith.serialized_size(mode))
             .saturating_add(self.c_arith.serialized_size(mode))
+            .saturating_add(self.lookup_evals.as_ref().map_or(0, |evals| evals.serialized_size(mode)))
     }
 }
 
@@ -190,7 +216,21 @@ impl<F: PrimeField, SM: SNARKMode> snarkvm_utilities::Valid for Circuit<F, SM> {
 
 impl<F: PrimeField, SM: SNARKMode> CanonicalDeserialize for Circuit<F, SM> {
     fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Self::deserialize_with_lookups(reader, false, compress, validate)
+    }
+}
+
+impl<F: PrimeField, SM: SNARKMode> Circuit<F, SM> {
+    /// Deserializes a circuit, which is followed by its lookup data if `has_lookups` is set.
+    /// The serialization of a circuit does not record whether it has lookups, so that circuits
+    /// without lookups keep their original encoding; the flag is recorded in the verifying key.
+    pub fn deserialize_with_lookups<R: Read>(
         mut reader: R,
+        has_lookups: bool,
         compress: Compress,
         validate: Validate,
     ) -> Result<Self, SerializationError> {
@@ -217,15 +257,28 @@ impl<F: PrimeField, SM: SNARKMode> CanonicalDeserialize for Circuit<F, SM> {
         let a = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
         let b = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
         let c = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
-        let id = Self::hash(&index_info, &a, &b, &c)?;
+        let a_arith = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let b_arith = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let c_arith = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let lookup_evals: Option<LookupEvals<F>> = match has_lookups {
+            true => Some(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?),
+            false => None,
+        };
+        if let Some(lookup_evals) = &lookup_evals {
+            if lookup_evals.domain().size() != constraint_domain_size {
+                return Err(SerializationError::InvalidData);
+            }
+        }
+        let id = Self::hash(&index_info, &a, &b, &c, lookup_evals.as_ref())?;
         Ok(Circuit {
             index_info,
             a,
             b,
             c,
-            a_arith: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            b_arith: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            c_arith: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            a_arith,
+            b_arith,
+            c_arith,
+            lookup_evals,
             fft_precomputation,
             ifft_precomputation,
             _mode: PhantomData,
@@ -12,7 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::r1cs::{errors::SynthesisError, ConstraintSystem as CS, Index as VarIndex, LinearCombination, Variable};
+use crate::r1cs::{
+    errors::SynthesisError,
+    ConstraintSystem as CS,
+    Index as VarIndex,
+    LinearCombination,
+    LookupTable,
+    Variable,
+};
 use snarkvm_fields::Field;
 use snarkvm_utilities::serialize::*;
 
@@ -26,6 +33,10 @@ pub(crate) struct ConstraintSystem<F: Field> {
     pub(crate) num_public_variables: usize,
     pub(crate) num_private_variables: usize,
     pub(crate) num_constraints: usize,
+    /// The registered lookup tables.
+    pub(crate) lookup_tables: Vec<LookupTable<F>>,
+    /// The lookup constraints, as pairs of (constraint index, table index).
+    pub(crate) lookup_constraints: Vec<(usize, usize)>,
 }
 
 impl<F: Field> ConstraintSystem<F> {
@@ -38,6 +49,8 @@ impl<F: Field> ConstraintSystem<F> {
             num_public_variables: 1,
             num_private_variables: 0,
             num_constraints: 0,
+            lookup_tables: Vec::new(),
+            lookup_constraints: Vec::new(),
         }
     }
 
@@ -97,6 +110,23 @@ impl<F: Field> CS<F> for ConstraintSystem<F> {
         self.num_constraints += 1;
     }
 
+    fn add_lookup_table(&mut self, table: LookupTable<F>) -> usize {
+        self.lookup_tables.push(table);
+        self.lookup_tables.len() - 1
+    }
+
+    fn enforce_lookup<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC, table_index: usize)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.lookup_constraints.push((self.num_constraints, table_index));
+        self.enforce(annotation, a, b, c);
+    }
+
     fn push_namespace<NR, N>(&mut self, _: N)
     where
         NR: AsRef<str>,
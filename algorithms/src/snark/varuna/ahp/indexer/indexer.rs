@@ -18,7 +18,7 @@ use crate::{
     r1cs::{errors::SynthesisError, ConstraintSynthesizer},
     snark::varuna::{
         ahp::{
-            indexer::{Circuit, CircuitId, CircuitInfo, ConstraintSystem as IndexerConstraintSystem},
+            indexer::{Circuit, CircuitId, CircuitInfo, ConstraintSystem as IndexerConstraintSystem, LookupEvals},
             AHPForR1CS,
         },
         matrices::{into_matrix_helper, matrix_evals, MatrixEvals},
@@ -60,6 +60,8 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             non_zero_c_domain,
             c_arith,
 
+            lookup_evals,
+
             index_info,
             id,
         } = Self::index_helper(c).map_err(|e| anyhow!("{e:?}"))?;
@@ -84,6 +86,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             a_arith,
             b_arith,
             c_arith,
+            lookup_evals,
             fft_precomputation,
             ifft_precomputation,
             id,
@@ -101,6 +104,17 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         map
     }
 
+    /// Returns the information of the lookup index polynomials of the given circuits,
+    /// which must be the circuits with lookups.
+    pub fn lookup_index_polynomial_info<'a>(
+        circuit_ids: impl Iterator<Item = &'a CircuitId> + 'a,
+    ) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
+        circuit_ids
+            .flat_map(|id| LookupEvals::<F>::labels(id))
+            .map(|label| (label.clone(), PolynomialInfo::new(label, None, None)))
+            .collect()
+    }
+
     pub fn index_polynomial_labels_single<'a>(
         matrix: &str,
         id: &'a CircuitId,
@@ -138,9 +152,20 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             crate::snark::varuna::ahp::matrices::add_randomizing_variables::<_, _>(&mut ics, random_assignments)
         });
 
+        let num_lookup_table_rows = ics.lookup_tables.iter().map(|table| table.len()).sum();
+        crate::snark::varuna::ahp::matrices::pad_constraints_for_lookup_tables(&mut ics, num_lookup_table_rows);
         crate::snark::varuna::ahp::matrices::pad_input_for_indexer_and_prover(&mut ics)?;
 
-        let IndexerConstraintSystem { a, b, c, num_public_variables, num_private_variables, num_constraints } = ics;
+        let IndexerConstraintSystem {
+            a,
+            b,
+            c,
+            num_public_variables,
+            num_private_variables,
+            num_constraints,
+            lookup_tables,
+            lookup_constraints,
+        } = ics;
 
         let a = into_matrix_helper(a, num_public_variables)?;
         let b = into_matrix_helper(b, num_public_variables)?;
@@ -202,7 +227,15 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                 .try_into()
                 .unwrap();
 
-        let id = Circuit::<F, SM>::hash(&index_info, &a, &b, &c)?;
+        let lookup_evals = match lookup_tables.is_empty() {
+            true => {
+                ensure!(lookup_constraints.is_empty(), "Found lookup constraints without lookup tables");
+                None
+            }
+            false => Some(LookupEvals::new(&constraint_domain, &lookup_constraints, &lookup_tables)?),
+        };
+
+        let id = Circuit::<F, SM>::hash(&index_info, &a, &b, &c, lookup_evals.as_ref())?;
 
         let result = Ok(IndexerState {
             constraint_domain,
@@ -220,6 +253,8 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             non_zero_c_domain,
             c_arith,
 
+            lookup_evals,
+
             index_info,
             id,
         });
@@ -236,7 +271,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         mut combiners: impl Iterator<Item = F>,
     ) -> Result<(LinearCombination<F>, F)> {
         let mut lc = LinearCombination::empty("circuit_check");
-        let mut all_evals = Vec::with_capacity(3);
+        let mut all_evals = Vec::with_capacity(12 + state.lookup_evals.as_ref().map_or(0, |_| 6));
         let mut sum = F::zero();
         for (evals, domain, label) in [
             (state.a_arith, state.non_zero_a_domain, "a"),
@@ -247,9 +282,14 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             let lagrange_coefficients_at_point = domain.evaluate_all_lagrange_coefficients(point);
             let evals_at_point = evals.evaluate(&lagrange_coefficients_at_point)?;
             ensure!(labels.len() == evals_at_point.len());
-            all_evals.push(labels.into_iter().zip_eq(evals_at_point.into_iter()));
+            all_evals.extend(labels.into_iter().zip_eq(evals_at_point.into_iter()));
+        }
+        if let Some(lookup_evals) = state.lookup_evals {
+            let lagrange_coefficients_at_point = state.constraint_domain.evaluate_all_lagrange_coefficients(point);
+            let evals_at_point = lookup_evals.evaluate(&lagrange_coefficients_at_point);
+            all_evals.extend(LookupEvals::<F>::labels(id).zip_eq(evals_at_point));
         }
-        let sorted_evals = all_evals.into_iter().flatten().sorted_unstable_by(|(l1, _), (l2, _)| l1.cmp(l2));
+        let sorted_evals = all_evals.into_iter().sorted_unstable_by(|(l1, _), (l2, _)| l1.cmp(l2));
         for (label, eval) in sorted_evals {
             let combiner = combiners.next().ok_or(anyhow!("No combiner left"))?;
             lc.add(combiner, label.as_str());
@@ -276,6 +316,8 @@ pub(crate) struct IndexerState<F: PrimeField> {
     non_zero_c_domain: EvaluationDomain<F>,
     c_arith: MatrixEvals<F>,
 
+    lookup_evals: Option<LookupEvals<F>>,
+
     pub(crate) index_info: CircuitInfo,
     pub(crate) id: CircuitId,
 }
@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    fft::{EvaluationDomain, Evaluations as EvaluationsOnDomain},
+    polycommit::sonic_pc::{LabeledPolynomial, PolynomialLabel},
+    r1cs::LookupTable,
+    snark::varuna::ahp::indexer::CircuitId,
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::serialize::*;
+
+use anyhow::{ensure, Result};
+
+/// The evaluations over the constraint domain of the index polynomials of the lookup argument.
+///
+/// The lookup tables of a circuit are concatenated in order and padded up to the size of the
+/// constraint domain by repeating their first row. Each row carries the index of its table as
+/// a tag, so that a lookup constraint can only be satisfied by a row of the table it refers to.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct LookupEvals<F: PrimeField> {
+    /// Evaluations of the `selector` polynomial, which is one on lookup constraints and zero elsewhere.
+    pub selector: EvaluationsOnDomain<F>,
+    /// Evaluations of the `tag` polynomial, which holds the table index of each lookup constraint.
+    pub tag: EvaluationsOnDomain<F>,
+    /// Evaluations of the first column of the concatenated lookup tables.
+    pub table_a: EvaluationsOnDomain<F>,
+    /// Evaluations of the second column of the concatenated lookup tables.
+    pub table_b: EvaluationsOnDomain<F>,
+    /// Evaluations of the third column of the concatenated lookup tables.
+    pub table_c: EvaluationsOnDomain<F>,
+    /// Evaluations of the table index of each row of the concatenated lookup tables.
+    pub table_tag: EvaluationsOnDomain<F>,
+}
+
+impl<F: PrimeField> LookupEvals<F> {
+    /// The names of the lookup index polynomials, in alphabetical order.
+    pub const POLYNOMIALS: [&'static str; 6] =
+        ["lookup_selector", "lookup_tag", "table_a", "table_b", "table_c", "table_tag"];
+
+    /// Encodes the given lookup constraints and tables over the constraint domain.
+    /// Each lookup constraint is given as a pair of (constraint index, table index).
+    pub(crate) fn new(
+        constraint_domain: &EvaluationDomain<F>,
+        lookup_constraints: &[(usize, usize)],
+        tables: &[LookupTable<F>],
+    ) -> Result<Self> {
+        let size = constraint_domain.size();
+
+        let mut selector = vec![F::zero(); size];
+        let mut tag = vec![F::zero(); size];
+        for &(constraint, table_index) in lookup_constraints {
+            ensure!(constraint < size, "Lookup constraint {constraint} is outside of the constraint domain");
+            ensure!(table_index < tables.len(), "Lookup constraint {constraint} refers to a missing table");
+            selector[constraint] = F::one();
+            tag[constraint] = F::from(table_index as u64);
+        }
+
+        let mut table_a = Vec::with_capacity(size);
+        let mut table_b = Vec::with_capacity(size);
+        let mut table_c = Vec::with_capacity(size);
+        let mut table_tag = Vec::with_capacity(size);
+        for (table_index, table) in tables.iter().enumerate() {
+            for [a, b, c] in table.iter() {
+                table_a.push(*a);
+                table_b.push(*b);
+                table_c.push(*c);
+                table_tag.push(F::from(table_index as u64));
+            }
+        }
+        ensure!(!table_a.is_empty(), "The lookup tables are empty");
+        ensure!(table_a.len() <= size, "The lookup tables do not fit in the constraint domain");
+
+        // Repeating a row does not change the set of rows of the table.
+        for column in [&mut table_a, &mut table_b, &mut table_c, &mut table_tag] {
+            column.resize(size, column[0]);
+        }
+
+        let evals = |evaluations| EvaluationsOnDomain::from_vec_and_domain(evaluations, *constraint_domain);
+        Ok(Self {
+            selector: evals(selector),
+            tag: evals(tag),
+            table_a: evals(table_a),
+            table_b: evals(table_b),
+            table_c: evals(table_c),
+            table_tag: evals(table_tag),
+        })
+    }
+
+    /// Returns the labels of the lookup index polynomials of the given circuit, in alphabetical order.
+    pub fn labels(id: &CircuitId) -> impl ExactSizeIterator<Item = PolynomialLabel> + '_ {
+        Self::POLYNOMIALS.into_iter().map(move |poly| format!("circuit_{id}_{poly}"))
+    }
+
+    /// Iterate over the evaluations, in the alphabetical order of their labels.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &EvaluationsOnDomain<F>> {
+        [&self.selector, &self.tag, &self.table_a, &self.table_b, &self.table_c, &self.table_tag].into_iter()
+    }
+
+    /// Evaluates the lookup index polynomials, in the alphabetical order of their labels.
+    pub(crate) fn evaluate(&self, lagrange_coefficients_at_point: &[F]) -> [F; 6] {
+        [&self.selector, &self.tag, &self.table_a, &self.table_b, &self.table_c, &self.table_tag]
+            .map(|evals| evals.evaluate_with_coeffs(lagrange_coefficients_at_point))
+    }
+
+    /// Interpolates the lookup index polynomials, in the alphabetical order of their labels.
+    pub fn interpolate<'a>(&'a self, id: &'a CircuitId) -> impl ExactSizeIterator<Item = LabeledPolynomial<F>> + 'a {
+        Self::labels(id)
+            .zip(self.iter())
+            .map(|(label, evals)| LabeledPolynomial::new(label, evals.interpolate_by_ref(), None, None))
+    }
+
+    /// Returns the row of the concatenated lookup tables at the given index.
+    pub(crate) fn table_row(&self, index: usize) -> [F; 4] {
+        [
+            self.table_a.evaluations[index],
+            self.table_b.evaluations[index],
+            self.table_c.evaluations[index],
+            self.table_tag.evaluations[index],
+        ]
+    }
+
+    /// Returns the domain over which the evaluations are defined.
+    pub(crate) fn domain(&self) -> EvaluationDomain<F> {
+        self.selector.domain()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+
+    #[test]
+    fn test_lookup_evals() {
+        let domain = EvaluationDomain::<Fr>::new(8).unwrap();
+        let and = (0u64..2).flat_map(|a| (0u64..2).map(move |b| [Fr::from(a), Fr::from(b), Fr::from(a & b)])).collect();
+        let xor = (0u64..2).flat_map(|a| (0u64..2).map(move |b| [Fr::from(a), Fr::from(b), Fr::from(a ^ b)])).collect();
+        let evals = LookupEvals::new(&domain, &[(1, 1), (5, 0)], &[and, xor]).unwrap();
+
+        let selector = [0u64, 1, 0, 0, 0, 1, 0, 0].map(Fr::from);
+        let tag = [0u64, 1, 0, 0, 0, 0, 0, 0].map(Fr::from);
+        assert_eq!(evals.selector.evaluations, selector);
+        assert_eq!(evals.tag.evaluations, tag);
+
+        // The `xor` table starts after the four rows of the `and` table.
+        assert_eq!(evals.table_row(6), [Fr::one(), Fr::zero(), Fr::one(), Fr::one()]);
+        assert_eq!(evals.table_tag.evaluations, [0u64, 0, 0, 0, 1, 1, 1, 1].map(Fr::from));
+
+        // The interpolated polynomials agree with the evaluations.
+        let id = CircuitId([0u8; 32]);
+        for (poly, evals) in evals.interpolate(&id).zip(evals.iter()) {
+            assert_eq!(poly.to_dense().evaluate_over_domain_by_ref(domain).evaluations, evals.evaluations);
+        }
+    }
+
+    #[test]
+    fn test_lookup_evals_padding() {
+        let domain = EvaluationDomain::<Fr>::new(4).unwrap();
+        let table: LookupTable<Fr> = [[Fr::from(7u64), Fr::zero(), Fr::zero()]].into_iter().collect();
+        let evals = LookupEvals::new(&domain, &[(0, 0)], &[table.clone()]).unwrap();
+        assert_eq!(evals.table_a.evaluations, [Fr::from(7u64); 4]);
+
+        // Lookups must refer to an existing table, and the tables must fit in the domain.
+        assert!(LookupEvals::new(&domain, &[(0, 1)], &[table.clone()]).is_err());
+        assert!(LookupEvals::new(&domain, &[(4, 0)], &[table.clone()]).is_err());
+        let large_table = (0u64..5).map(|i| [Fr::from(i), Fr::zero(), Fr::zero()]).collect::<LookupTable<_>>();
+        assert!(LookupEvals::new(&domain, &[(0, 0)], &[large_table]).is_err());
+    }
+}
@@ -18,13 +18,16 @@ mod circuit;
 pub(crate) use circuit::*;
 
 mod circuit_info;
-pub(crate) use circuit_info::*;
+pub use circuit_info::*;
 
 mod constraint_system;
 pub(crate) use constraint_system::*;
 
 mod indexer;
 
+mod lookup;
+pub(crate) use lookup::*;
+
 /// Represents a matrix.
 pub(crate) type Matrix<F> = Vec<Vec<(F, usize)>>;
 
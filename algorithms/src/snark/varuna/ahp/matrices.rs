@@ -80,6 +80,19 @@ pub(crate) fn add_randomizing_variables<F: PrimeField, CS: ConstraintSystem<F>>(
     Ok(())
 }
 
+/// Pads the constraints with empty constraints, so that the constraint domain can hold every lookup table row.
+pub(crate) fn pad_constraints_for_lookup_tables<F: PrimeField, CS: ConstraintSystem<F>>(
+    cs: &mut CS,
+    num_lookup_table_rows: usize,
+) {
+    let num_constraints = cs.num_constraints();
+    if num_lookup_table_rows > num_constraints {
+        for i in 0..(num_lookup_table_rows - num_constraints) {
+            cs.enforce(|| format!("pad_lookup_{i}"), |lc| lc, |lc| lc, |lc| lc);
+        }
+    }
+}
+
 /// Pads the public variables up to the closest power of two.
 pub(crate) fn pad_input_for_indexer_and_prover<F: PrimeField, CS: ConstraintSystem<F>>(cs: &mut CS) -> Result<()> {
     let num_public_variables = cs.num_public_variables();
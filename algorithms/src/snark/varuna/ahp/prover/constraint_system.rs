@@ -12,7 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::r1cs::{errors::SynthesisError, ConstraintSystem as CS, Index as VarIndex, LinearCombination, Variable};
+use crate::r1cs::{
+    errors::SynthesisError,
+    ConstraintSystem as CS,
+    Index as VarIndex,
+    LinearCombination,
+    LookupTable,
+    Variable,
+};
 use snarkvm_fields::Field;
 
 pub(crate) struct ConstraintSystem<F: Field> {
@@ -21,6 +28,8 @@ pub(crate) struct ConstraintSystem<F: Field> {
     pub(crate) num_public_variables: usize,
     pub(crate) num_private_variables: usize,
     pub(crate) num_constraints: usize,
+    pub(crate) num_lookup_tables: usize,
+    pub(crate) num_lookup_table_rows: usize,
 }
 
 impl<F: Field> ConstraintSystem<F> {
@@ -31,6 +40,8 @@ impl<F: Field> ConstraintSystem<F> {
             num_public_variables: 1usize,
             num_private_variables: 0usize,
             num_constraints: 0usize,
+            num_lookup_tables: 0usize,
+            num_lookup_table_rows: 0usize,
         }
     }
 
@@ -93,6 +104,26 @@ impl<F: Field> CS<F> for ConstraintSystem<F> {
         self.num_constraints += 1;
     }
 
+    #[inline]
+    fn add_lookup_table(&mut self, table: LookupTable<F>) -> usize {
+        // The table contents are part of the index, so we only track their dimensions.
+        self.num_lookup_tables += 1;
+        self.num_lookup_table_rows += table.len();
+        self.num_lookup_tables - 1
+    }
+
+    #[inline]
+    fn enforce_lookup<A, AR, LA, LB, LC>(&mut self, _: A, _: LA, _: LB, _: LC, _: usize)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.num_constraints += 1;
+    }
+
     fn push_namespace<NR, N>(&mut self, _: N)
     where
         NR: AsRef<str>,
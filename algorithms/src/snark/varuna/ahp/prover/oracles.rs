@@ -25,6 +25,8 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct FirstOracles<F: PrimeField> {
     pub(in crate::snark::varuna) batches: BTreeMap<CircuitId, Vec<WitnessPoly<F>>>,
+    /// The lookup multiplicity polynomials of the circuits with lookups.
+    pub(in crate::snark::varuna) lookup_multiplicities: BTreeMap<CircuitId, Vec<LabeledPolynomial<F>>>,
     /// The sum-check hiding polynomial.
     pub mask_poly: Option<LabeledPolynomial<F>>,
 }
@@ -32,16 +34,27 @@ pub struct FirstOracles<F: PrimeField> {
 impl<F: PrimeField> FirstOracles<F> {
     /// Iterate over the polynomials output by the prover in the first round.
     pub fn iter(&self) -> impl Iterator<Item = &'_ LabeledPolynomial<F>> {
-        self.batches.values().flat_map(|b| b.iter()).flat_map(|b| b.iter()).chain(self.mask_poly.as_ref())
+        self.batches
+            .values()
+            .flat_map(|b| b.iter())
+            .flat_map(|b| b.iter())
+            .chain(self.lookup_multiplicities.values().flatten())
+            .chain(self.mask_poly.as_ref())
     }
 
     /// Iterate over the polynomials output by the prover in the first round.
     pub fn into_iter(self) -> impl Iterator<Item = LabeledPolynomial<F>> {
-        self.batches.into_values().flat_map(|b| b.into_iter()).map(|b| b.0).chain(self.mask_poly)
+        self.batches
+            .into_values()
+            .flat_map(|b| b.into_iter())
+            .map(|b| b.0)
+            .chain(self.lookup_multiplicities.into_values().flatten())
+            .chain(self.mask_poly)
     }
 
     pub fn matches_info(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> bool {
         self.batches.values().all(|b| b.iter().all(|b| b.matches_info(info)))
+            && self.lookup_multiplicities.values().flatten().all(|p| Some(p.info()) == info.get(p.label()))
             && self.mask_poly.as_ref().map_or(true, |p| Some(p.info()) == info.get(p.label()))
     }
 }
@@ -66,21 +79,51 @@ impl<F: PrimeField> WitnessPoly<F> {
 pub struct SecondOracles<F: PrimeField> {
     /// The polynomial `h` resulting from the first zerocheck.
     pub h_0: LabeledPolynomial<F>,
+    /// The polynomials of the lookup argument of the circuits with lookups.
+    pub(in crate::snark::varuna) lookups: BTreeMap<CircuitId, Vec<LookupPolys<F>>>,
 }
 
 impl<F: PrimeField> SecondOracles<F> {
     /// Iterate over the polynomials output by the prover in the second round.
     pub fn iter(&self) -> impl Iterator<Item = &LabeledPolynomial<F>> {
-        [&self.h_0].into_iter()
+        [&self.h_0].into_iter().chain(self.lookups.values().flatten().flat_map(|l| l.iter()))
     }
 
     /// Iterate over the polynomials output by the prover in the second round.
     pub fn into_iter(self) -> impl Iterator<Item = LabeledPolynomial<F>> {
-        [self.h_0].into_iter()
+        [self.h_0].into_iter().chain(self.lookups.into_values().flatten().flat_map(|l| l.into_iter()))
     }
 
     pub fn matches_info(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> bool {
         Some(self.h_0.info()) == info.get(self.h_0.label())
+            && self.lookups.values().flatten().all(|l| l.iter().all(|p| Some(p.info()) == info.get(p.label())))
+    }
+}
+
+/// The polynomials of the lookup argument for a single instance.
+#[derive(Debug)]
+pub(in crate::snark::varuna) struct LookupPolys<F: PrimeField> {
+    /// The polynomial `h` interpolating the inverses of the looked-up rows.
+    pub(in crate::snark::varuna) h: LabeledPolynomial<F>,
+    /// The polynomial `g` interpolating the multiplicities over the inverses of the table rows.
+    pub(in crate::snark::varuna) g: LabeledPolynomial<F>,
+    /// The polynomial `s` such that `h - g = X * s`.
+    pub(in crate::snark::varuna) s: LabeledPolynomial<F>,
+    /// The quotient of the zerocheck on `h`.
+    pub(in crate::snark::varuna) q_h: LabeledPolynomial<F>,
+    /// The quotient of the zerocheck on `g`.
+    pub(in crate::snark::varuna) q_g: LabeledPolynomial<F>,
+}
+
+impl<F: PrimeField> LookupPolys<F> {
+    /// Iterate over the lookup polynomials.
+    pub fn iter(&self) -> impl Iterator<Item = &LabeledPolynomial<F>> {
+        [&self.h, &self.g, &self.s, &self.q_h, &self.q_g].into_iter()
+    }
+
+    /// Iterate over the lookup polynomials.
+    pub fn into_iter(self) -> impl Iterator<Item = LabeledPolynomial<F>> {
+        [self.h, self.g, self.s, self.q_h, self.q_g].into_iter()
     }
 }
 
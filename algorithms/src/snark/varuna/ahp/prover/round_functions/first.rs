@@ -88,11 +88,16 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             let batches = batches.drain(0..state.batch_size).collect_vec();
             circuit_specific_batches.insert(circuit.id, batches);
         }
+        let lookup_multiplicities = Self::calculate_lookup_multiplicities(&mut state)?;
         let mask_poly = SM::ZK.then(|| Self::calculate_mask_poly(state.max_variable_domain, rng));
-        let oracles = prover::FirstOracles { batches: circuit_specific_batches, mask_poly };
-        assert!(oracles.matches_info(&Self::first_round_polynomial_info(
-            state.circuit_specific_states.iter().map(|(c, s)| (&c.id, &s.batch_size))
-        )));
+        let oracles = prover::FirstOracles { batches: circuit_specific_batches, lookup_multiplicities, mask_poly };
+        let mut polynomial_info = Self::first_round_polynomial_info(
+            state.circuit_specific_states.iter().map(|(c, s)| (&c.id, &s.batch_size)),
+        );
+        polynomial_info.extend(Self::lookup_first_round_polynomial_info(
+            state.circuit_specific_states.iter().filter(|(c, _)| c.has_lookups()).map(|(c, s)| (&c.id, &s.batch_size)),
+        ));
+        assert!(oracles.matches_info(&polynomial_info));
         state.first_round_oracles = Some(oracles);
         end_timer!(round_time);
         Ok(state)
@@ -0,0 +1,254 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    fft::{polynomial::PolyMultiplier, DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain},
+    polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
+    snark::varuna::{
+        ahp::{lookup_checks_enabled, verifier, AHPError, AHPForR1CS, CircuitInfo},
+        prover,
+        witness_label,
+        Circuit,
+        CircuitId,
+        SNARKMode,
+    },
+};
+use snarkvm_fields::{batch_inversion, PrimeField};
+
+use anyhow::{anyhow, ensure, Result};
+use itertools::Itertools;
+use std::collections::BTreeMap;
+
+impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
+    /// Output the number of oracles sent by the prover for the lookup argument.
+    /// Each instance of a circuit with lookups has a multiplicity polynomial in the first round,
+    /// and the polynomials `h`, `g`, `s`, `q_h` and `q_g` in the second round.
+    pub const fn num_lookup_oracles(num_lookup_instances: usize) -> usize {
+        6 * num_lookup_instances
+    }
+
+    /// Output the degree bound of the `s` polynomial of the lookup argument.
+    pub fn lookup_degree_bound(info: &CircuitInfo) -> Result<usize> {
+        let constraint_domain_size =
+            EvaluationDomain::<F>::compute_size_of_domain(info.num_constraints).ok_or(AHPError::PolyTooLarge)?;
+        Ok(constraint_domain_size - 2)
+    }
+
+    /// Output the degree bounds of the lookup oracles in the first round,
+    /// for the given circuits with lookups and their batch sizes.
+    pub fn lookup_first_round_polynomial_info<'a>(
+        circuits: impl Iterator<Item = (&'a CircuitId, &'a usize)>,
+    ) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
+        circuits
+            .flat_map(|(&circuit_id, &batch_size)| {
+                (0..batch_size)
+                    .map(move |i| PolynomialInfo::new(witness_label(circuit_id, "lookup_m", i), None, Self::zk_bound()))
+            })
+            .map(|info| (info.label().into(), info))
+            .collect()
+    }
+
+    /// Output the degree bounds of the lookup oracles in the second round,
+    /// for the given circuits with lookups and their batch sizes.
+    pub fn lookup_second_round_polynomial_info<'a>(
+        circuits: impl Iterator<Item = (CircuitId, usize, &'a CircuitInfo)>,
+    ) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
+        circuits
+            .flat_map(|(circuit_id, batch_size, info)| {
+                let degree_bound = Some(Self::lookup_degree_bound(info).unwrap());
+                (0..batch_size).flat_map(move |i| {
+                    [
+                        PolynomialInfo::new(witness_label(circuit_id, "lookup_h", i), None, Self::zk_bound()),
+                        PolynomialInfo::new(witness_label(circuit_id, "lookup_g", i), None, Self::zk_bound()),
+                        PolynomialInfo::new(witness_label(circuit_id, "lookup_s", i), degree_bound, Self::zk_bound()),
+                        PolynomialInfo::new(witness_label(circuit_id, "lookup_qh", i), None, None),
+                        PolynomialInfo::new(witness_label(circuit_id, "lookup_qg", i), None, None),
+                    ]
+                })
+            })
+            .map(|info| (info.label().into(), info))
+            .collect()
+    }
+
+    /// Computes the multiplicity polynomials of the lookup argument for each circuit with lookups.
+    /// The multiplicity of a table row is the number of lookups of an instance which match it.
+    pub(super) fn calculate_lookup_multiplicities(
+        state: &mut prover::State<F, SM>,
+    ) -> Result<BTreeMap<CircuitId, Vec<LabeledPolynomial<F>>>, AHPError> {
+        let mut multiplicity_polys = BTreeMap::new();
+        for (circuit, circuit_state) in state.circuit_specific_states.iter_mut() {
+            let Some(lookup_evals) = circuit.lookup_evals.as_ref() else {
+                continue;
+            };
+            let constraint_domain = circuit_state.constraint_domain;
+            let z_a = circuit_state.z_a.as_ref().unwrap();
+            let z_b = circuit_state.z_b.as_ref().unwrap();
+            let z_c = circuit_state.z_c.as_ref().unwrap();
+
+            // Padding rows repeat the first row of the tables, so we count each lookup at the first matching row.
+            let mut row_indices = BTreeMap::new();
+            for i in 0..constraint_domain.size() {
+                row_indices.entry(lookup_evals.table_row(i)).or_insert(i);
+            }
+
+            let mut multiplicities = Vec::with_capacity(circuit_state.batch_size);
+            let mut polys = Vec::with_capacity(circuit_state.batch_size);
+            for (j, ((z_a, z_b), z_c)) in z_a.iter().zip_eq(z_b).zip_eq(z_c).enumerate() {
+                let mut m = vec![F::zero(); constraint_domain.size()];
+                for (i, selector) in lookup_evals.selector.evaluations.iter().enumerate() {
+                    if selector.is_zero() {
+                        continue;
+                    }
+                    let row = [z_a[i], z_b[i], z_c[i], lookup_evals.tag.evaluations[i]];
+                    match row_indices.get(&row) {
+                        Some(index) => m[*index] += F::one(),
+                        None if lookup_checks_enabled() => {
+                            return Err(anyhow!("Lookup constraint {i} of {} is not in its table", circuit.id).into());
+                        }
+                        None => continue,
+                    }
+                }
+                let m_poly = EvaluationsOnDomain::from_vec_and_domain(m.clone(), constraint_domain)
+                    .interpolate_with_pc(&circuit.ifft_precomputation);
+                let label = witness_label(circuit.id, "lookup_m", j);
+                polys.push(LabeledPolynomial::new(label, m_poly, None, Self::zk_bound()));
+                multiplicities.push(m);
+            }
+            circuit_state.lookup_multiplicities = Some(multiplicities);
+            multiplicity_polys.insert(circuit.id, polys);
+        }
+        Ok(multiplicity_polys)
+    }
+
+    /// Computes the second round polynomials of the lookup argument for each circuit with lookups.
+    ///
+    /// For the challenges `zeta` and `shift`, each looked-up row `f = z_a + zeta z_b + zeta^2 z_c + zeta^3 tag`
+    /// and each table row `t` is mapped to `1 / (shift - f)` and `m / (shift - t)` respectively.
+    /// The lookups hold if the interpolations `h` and `g` of these values have the same sum over
+    /// the constraint domain, which we prove by showing that `h - g` has a zero constant term.
+    pub(super) fn calculate_lookup_polys(
+        verifier_message: &verifier::FirstMessage<F>,
+        state: &mut prover::State<F, SM>,
+    ) -> Result<BTreeMap<CircuitId, Vec<prover::LookupPolys<F>>>> {
+        let Some(verifier::LookupChallenges { zeta, shift }) = verifier_message.lookup_challenges else {
+            ensure!(state.circuit_specific_states.keys().all(|c| !c.has_lookups()), "Missing lookup challenges");
+            return Ok(BTreeMap::new());
+        };
+        let zetas = [F::one(), zeta, zeta.square(), zeta.square() * zeta];
+
+        let mut lookup_polys = BTreeMap::new();
+        for (circuit, circuit_state) in state.circuit_specific_states.iter_mut() {
+            let Some(lookup_evals) = circuit.lookup_evals.as_ref() else {
+                continue;
+            };
+            let constraint_domain = circuit_state.constraint_domain;
+            let selector = circuit_state.lookup_selector().unwrap();
+            let multiplicities = circuit_state.lookup_multiplicities.take().unwrap();
+            let z_a = circuit_state.z_a.as_ref().unwrap();
+            let z_b = circuit_state.z_b.as_ref().unwrap();
+            let z_c = circuit_state.z_c.as_ref().unwrap();
+
+            let table = (0..constraint_domain.size())
+                .map(|i| lookup_evals.table_row(i).into_iter().zip_eq(zetas).map(|(t, z)| t * z).sum())
+                .collect_vec();
+
+            let mut polys = Vec::with_capacity(circuit_state.batch_size);
+            for (j, (((z_a, z_b), z_c), m)) in z_a.iter().zip_eq(z_b).zip_eq(z_c).zip_eq(multiplicities).enumerate() {
+                let looked_up = (0..constraint_domain.size())
+                    .map(|i| {
+                        let z = |z_m: &[F]| z_m.get(i).copied().unwrap_or_else(F::zero);
+                        let row = [z(z_a), z(z_b), z(z_c), lookup_evals.tag.evaluations[i]];
+                        row.into_iter().zip_eq(zetas).map(|(f, z)| f * z).sum()
+                    })
+                    .collect_vec();
+                polys.push(Self::calculate_lookup_polys_for_instance(
+                    circuit,
+                    j,
+                    shift,
+                    &selector,
+                    &lookup_evals.selector.evaluations,
+                    looked_up,
+                    &table,
+                    m,
+                    constraint_domain,
+                )?);
+            }
+            lookup_polys.insert(circuit.id, polys);
+        }
+        Ok(lookup_polys)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn calculate_lookup_polys_for_instance(
+        circuit: &Circuit<F, SM>,
+        j: usize,
+        shift: F,
+        selector: &DensePolynomial<F>,
+        selector_evals: &[F],
+        looked_up: Vec<F>,
+        table: &[F],
+        multiplicities: Vec<F>,
+        constraint_domain: EvaluationDomain<F>,
+    ) -> Result<prover::LookupPolys<F>> {
+        let interpolate = |evals: Vec<F>| {
+            EvaluationsOnDomain::from_vec_and_domain(evals, constraint_domain)
+                .interpolate_with_pc(&circuit.ifft_precomputation)
+        };
+
+        let mut h_evals = looked_up.iter().map(|f| shift - f).collect_vec();
+        let mut g_evals = table.iter().map(|t| shift - t).collect_vec();
+        ensure!(h_evals.iter().chain(&g_evals).all(|d| !d.is_zero()), "The lookup shift is a root of a denominator");
+        batch_inversion(&mut h_evals);
+        batch_inversion(&mut g_evals);
+        h_evals.iter_mut().zip_eq(selector_evals).for_each(|(h, s)| *h *= s);
+        g_evals.iter_mut().zip_eq(&multiplicities).for_each(|(g, m)| *g *= m);
+        let h = interpolate(h_evals);
+        let g = interpolate(g_evals);
+
+        // `h - g` sums to zero over the constraint domain if and only if its constant term is zero.
+        let h_minus_g = &h - &g;
+        ensure!(
+            !lookup_checks_enabled() || h_minus_g.coeffs.first().map_or(true, |c| c.is_zero()),
+            "The lookups of {} do not hold",
+            circuit.id
+        );
+        let s = DensePolynomial::from_coefficients_slice(h_minus_g.coeffs.get(1..).unwrap_or(&[]));
+
+        // Computes `q` such that `p * (shift - d) - n = q * v_R`.
+        let zerocheck_quotient = |p: &DensePolynomial<F>, d: DensePolynomial<F>, n: &DensePolynomial<F>| {
+            let mut shift_minus_d = -d;
+            shift_minus_d += &DensePolynomial::from_coefficients_vec(vec![shift]);
+            let mut multiplier = PolyMultiplier::new();
+            multiplier.add_polynomial(p.clone(), "p");
+            multiplier.add_polynomial(shift_minus_d, "shift_minus_d");
+            let mut numerator = multiplier.multiply().ok_or_else(|| anyhow!("Failed to multiply polynomials"))?;
+            numerator -= n;
+            let (q, r) = numerator.divide_by_vanishing_poly(constraint_domain)?;
+            ensure!(r.is_zero(), "The lookup zerocheck of {} does not hold", circuit.id);
+            Ok::<_, anyhow::Error>(q)
+        };
+        let q_h = zerocheck_quotient(&h, interpolate(looked_up), selector)?;
+        let q_g = zerocheck_quotient(&g, interpolate(table.to_vec()), &interpolate(multiplicities))?;
+
+        let degree_bound = constraint_domain.size() - 2;
+        let label = |poly: &str| witness_label(circuit.id, poly, j);
+        Ok(prover::LookupPolys {
+            h: LabeledPolynomial::new(label("lookup_h"), h, None, Self::zk_bound()),
+            g: LabeledPolynomial::new(label("lookup_g"), g, None, Self::zk_bound()),
+            s: LabeledPolynomial::new(label("lookup_s"), s, degree_bound, Self::zk_bound()),
+            q_h: LabeledPolynomial::new(label("lookup_qh"), q_h, None, None),
+            q_g: LabeledPolynomial::new(label("lookup_qg"), q_g, None, None),
+        })
+    }
+}
@@ -38,6 +38,7 @@ use rayon::prelude::*;
 mod fifth;
 mod first;
 mod fourth;
+mod lookup;
 mod second;
 mod third;
 
@@ -94,6 +95,11 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                                 rand_assignments,
                             )
                         });
+                        let num_lookup_table_rows = pcs.num_lookup_table_rows;
+                        crate::snark::varuna::ahp::matrices::pad_constraints_for_lookup_tables(
+                            &mut pcs,
+                            num_lookup_table_rows,
+                        );
                         crate::snark::varuna::ahp::matrices::pad_input_for_indexer_and_prover(&mut pcs)?;
 
                         end_timer!(padding_time);
@@ -60,12 +60,22 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
 
         let verifier::FirstMessage { batch_combiners, .. } = verifier_message;
 
+        // The lookup polynomials need the z_m evaluations, which the rowcheck consumes.
+        let lookups = Self::calculate_lookup_polys(verifier_message, &mut state)?;
         let h_0 = Self::calculate_rowcheck_witness(&mut state, batch_combiners)?;
 
         assert!(h_0.degree() <= 2 * max_constraint_domain.size() + 2 * zk_bound.unwrap_or(0) - 2);
 
-        let oracles = prover::SecondOracles { h_0: LabeledPolynomial::new("h_0", h_0, None, None) };
-        assert!(oracles.matches_info(&Self::second_round_polynomial_info()));
+        let oracles = prover::SecondOracles { h_0: LabeledPolynomial::new("h_0", h_0, None, None), lookups };
+        let mut polynomial_info = Self::second_round_polynomial_info();
+        polynomial_info.extend(Self::lookup_second_round_polynomial_info(
+            state
+                .circuit_specific_states
+                .iter()
+                .filter(|(c, _)| c.has_lookups())
+                .map(|(c, s)| (c.id, s.batch_size, &c.index_info)),
+        ));
+        assert!(oracles.matches_info(&polynomial_info));
 
         end_timer!(round_time);
 
@@ -83,6 +93,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             let z_a = circuit_specific_state.z_a.take().unwrap();
             let z_b = circuit_specific_state.z_b.take().unwrap();
             let z_c = circuit_specific_state.z_c.take().unwrap();
+            let lookup_selector = circuit_specific_state.lookup_selector();
 
             let circuit_combiner = batch_combiners[&circuit.id].circuit_combiner;
             let instance_combiners = batch_combiners[&circuit.id].instance_combiners.clone();
@@ -95,6 +106,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             for (j, (instance_combiner, z_a, z_b, z_c)) in
                 itertools::izip!(instance_combiners, z_a, z_b, z_c).enumerate()
             {
+                let lookup_selector = lookup_selector.clone();
                 job_pool.add_job(move || {
                     let mut instance_lhs = DensePolynomial::zero();
                     let za_label = witness_label(circuit.id, "z_a", j);
@@ -110,6 +122,16 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                     let mut rowcheck = multiplier_2.multiply().unwrap();
                     cfg_iter_mut!(rowcheck.coeffs).zip(&z_c.coeffs).for_each(|(ab, c)| *ab -= c);
 
+                    // Lookup constraints are checked by the lookup argument instead of the rowcheck.
+                    if let Some(selector) = lookup_selector {
+                        let mut not_selector = -selector;
+                        not_selector += &DensePolynomial::from_coefficients_vec(vec![F::one()]);
+                        let mut multiplier = PolyMultiplier::new();
+                        multiplier.add_polynomial(rowcheck, "rowcheck");
+                        multiplier.add_polynomial(not_selector, "not_selector");
+                        rowcheck = multiplier.multiply().unwrap();
+                    }
+
                     instance_lhs += &(&rowcheck * instance_combiner);
 
                     let (h_0_i, remainder) = apply_randomized_selector(
@@ -78,7 +78,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
 
         let max_variable_domain = state.max_variable_domain;
 
-        let verifier::FirstMessage { batch_combiners } = verifier_message;
+        let verifier::FirstMessage { batch_combiners, .. } = verifier_message;
         let verifier::SecondMessage { alpha, eta_b, eta_c } = verifier_second_message;
 
         let assignments = Self::calculate_assignments(&mut state)?;
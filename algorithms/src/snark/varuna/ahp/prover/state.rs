@@ -67,6 +67,21 @@ pub struct CircuitSpecificState<F: PrimeField> {
 
     /// Intermediary polynomials of the matrix sumcheck.
     pub(super) lhs_polynomials: Option<[DensePolynomial<F>; 3]>,
+
+    /// The lookup index polynomials, if the circuit has lookups.
+    pub(in crate::snark) lookup_polys: Option<Vec<LabeledPolynomial<F>>>,
+
+    /// The list of lookup multiplicity vectors for each instance in the batch.
+    /// The length of this list must be equal to the batch size.
+    pub(super) lookup_multiplicities: Option<Vec<Vec<F>>>,
+}
+
+impl<F: PrimeField> CircuitSpecificState<F> {
+    /// Returns the lookup selector polynomial, if the circuit has lookups.
+    pub(super) fn lookup_selector(&self) -> Option<DensePolynomial<F>> {
+        // The selector is the first of the lookup index polynomials.
+        self.lookup_polys.as_ref().map(|polys| polys[0].to_dense().into_owned())
+    }
 }
 
 /// State for the AHP prover.
@@ -170,6 +185,8 @@ impl<'a, F: PrimeField, SM: SNARKMode> State<'a, F, SM> {
                     a_polys: None,
                     b_polys: None,
                     lhs_polynomials: None,
+                    lookup_polys: circuit.lookup_evals.as_ref().map(|evals| evals.interpolate(&circuit.id).collect()),
+                    lookup_multiplicities: None,
                 };
                 Ok((circuit, state))
             })
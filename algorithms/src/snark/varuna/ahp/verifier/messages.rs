@@ -31,6 +31,17 @@ pub(crate) struct BatchCombiners<F> {
 pub struct FirstMessage<F: PrimeField> {
     /// Randomizers for combining checks from the batch
     pub(crate) batch_combiners: BTreeMap<CircuitId, BatchCombiners<F>>,
+    /// Challenges for the lookup argument, if any circuit in the batch has lookups
+    pub(crate) lookup_challenges: Option<LookupChallenges<F>>,
+}
+
+/// Challenges of the lookup argument.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct LookupChallenges<F> {
+    /// Randomizer for combining the columns of the looked-up and table rows.
+    pub(crate) zeta: F,
+    /// Shift of the logarithmic derivatives of the looked-up and table rows.
+    pub(crate) shift: F,
 }
 
 /// Second verifier message.
@@ -80,6 +91,10 @@ pub struct QuerySet<F: PrimeField> {
     pub g_b_query: (String, F),
     pub g_c_query: (String, F),
     pub matrix_sumcheck_query: (String, F),
+
+    /// The batch sizes of the circuits with lookups.
+    pub lookup_batch_sizes: BTreeMap<CircuitId, usize>,
+    pub lookup_query: (String, F),
 }
 
 impl<F: PrimeField> QuerySet<F> {
@@ -103,6 +118,14 @@ impl<F: PrimeField> QuerySet<F> {
             g_b_query: ("gamma".into(), gamma),
             g_c_query: ("gamma".into(), gamma),
             matrix_sumcheck_query: ("gamma".into(), gamma),
+
+            lookup_batch_sizes: state
+                .circuit_specific_states
+                .iter()
+                .filter(|(_, s)| s.has_lookups)
+                .map(|(c, s)| (*c, s.batch_size))
+                .collect(),
+            lookup_query: ("alpha".into(), alpha),
         }
     }
 
@@ -115,6 +138,18 @@ impl<F: PrimeField> QuerySet<F> {
             query_set.insert((witness_label(circuit_id, "g_b", 0), self.g_b_query.clone()));
             query_set.insert((witness_label(circuit_id, "g_c", 0), self.g_c_query.clone()));
         }
+        // The lookup zerochecks and sumchecks are virtual oracles which evaluate to zero,
+        // while the lookup table, tag and `s` are needed by the verifier to evaluate the zerochecks and sumchecks.
+        for (&circuit_id, &batch_size) in self.lookup_batch_sizes.iter() {
+            query_set.insert((format!("circuit_{circuit_id}_lookup_table"), self.lookup_query.clone()));
+            query_set.insert((format!("circuit_{circuit_id}_lookup_tag"), self.lookup_query.clone()));
+            for j in 0..batch_size {
+                query_set.insert((witness_label(circuit_id, "lookup_h_zerocheck", j), self.lookup_query.clone()));
+                query_set.insert((witness_label(circuit_id, "lookup_g_zerocheck", j), self.lookup_query.clone()));
+                query_set.insert((witness_label(circuit_id, "lookup_sumcheck", j), self.lookup_query.clone()));
+                query_set.insert((witness_label(circuit_id, "lookup_s", j), self.lookup_query.clone()));
+            }
+        }
         query_set.insert(("g_1".into(), self.g_1_query.clone()));
         query_set.insert(("rowcheck_zerocheck".into(), self.rowcheck_zerocheck_query.clone()));
         query_set.insert(("lineval_sumcheck".into(), self.lineval_sumcheck_query.clone()));
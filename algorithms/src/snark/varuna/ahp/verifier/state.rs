@@ -37,6 +37,9 @@ pub struct CircuitSpecificState<F: PrimeField> {
 
     /// The number of instances being proved in this batch.
     pub(in crate::snark::varuna) batch_size: usize,
+
+    /// Whether the circuit has lookups.
+    pub(in crate::snark::varuna) has_lookups: bool,
 }
 /// State of the AHP verifier.
 #[derive(Debug)]
@@ -19,7 +19,16 @@ use crate::{
     snark::varuna::{
         ahp::{
             indexer::{CircuitId, CircuitInfo},
-            verifier::{BatchCombiners, FirstMessage, FourthMessage, QuerySet, SecondMessage, State, ThirdMessage},
+            verifier::{
+                BatchCombiners,
+                FirstMessage,
+                FourthMessage,
+                LookupChallenges,
+                QuerySet,
+                SecondMessage,
+                State,
+                ThirdMessage,
+            },
             AHPError,
             AHPForR1CS,
         },
@@ -31,13 +40,15 @@ use crate::{
 use anyhow::{ensure, Result};
 use smallvec::SmallVec;
 use snarkvm_fields::PrimeField;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 impl<TargetField: PrimeField, SM: SNARKMode> AHPForR1CS<TargetField, SM> {
     /// Output the first message and next round state.
+    /// `lookups` holds the circuits which have lookups.
     pub fn verifier_first_round<BaseField: PrimeField, R: AlgebraicSponge<BaseField, 2>>(
         batch_sizes: &BTreeMap<CircuitId, usize>,
         circuit_infos: &BTreeMap<CircuitId, &CircuitInfo>,
+        lookups: &BTreeSet<CircuitId>,
         max_constraint_domain: EvaluationDomain<TargetField>,
         max_variable_domain: EvaluationDomain<TargetField>,
         max_non_zero_domain: EvaluationDomain<TargetField>,
@@ -98,11 +109,20 @@ impl<TargetField: PrimeField, SM: SNARKMode> AHPForR1CS<TargetField, SM> {
                 non_zero_b_domain,
                 non_zero_c_domain,
                 batch_size: *batch_size,
+                has_lookups: lookups.contains(circuit_id),
             };
             circuit_specific_states.insert(*circuit_id, circuit_specific_state);
         }
 
-        let message = FirstMessage { batch_combiners };
+        let lookup_challenges = match lookups.is_empty() {
+            true => None,
+            false => {
+                let elems = fs_rng.squeeze_nonnative_field_elements(2);
+                Some(LookupChallenges { zeta: elems[0], shift: elems[1] })
+            }
+        };
+
+        let message = FirstMessage { batch_combiners, lookup_challenges };
 
         let new_state = State {
             circuit_specific_states,
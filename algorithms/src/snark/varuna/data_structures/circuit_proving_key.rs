@@ -50,8 +50,10 @@ impl<E: PairingEngine, SM: SNARKMode> ToBytes for CircuitProvingKey<E, SM> {
 impl<E: PairingEngine, SM: SNARKMode> FromBytes for CircuitProvingKey<E, SM> {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
-        let circuit_verifying_key = CanonicalDeserialize::deserialize_compressed(&mut reader)?;
-        let circuit = CanonicalDeserialize::deserialize_compressed(&mut reader)?;
+        let circuit_verifying_key: CircuitVerifyingKey<E> = CanonicalDeserialize::deserialize_compressed(&mut reader)?;
+        let has_lookups = circuit_verifying_key.has_lookups();
+        let circuit =
+            Arc::new(Circuit::deserialize_with_lookups(&mut reader, has_lookups, Compress::Yes, Validate::Yes)?);
         let committer_key = Arc::new(FromBytes::read_le(&mut reader)?);
 
         Ok(Self { circuit_verifying_key, circuit, committer_key })
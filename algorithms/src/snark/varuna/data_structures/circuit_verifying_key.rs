@@ -57,6 +57,13 @@ impl<E: PairingEngine> CircuitVerifyingKey<E> {
     pub fn iter(&self) -> impl Iterator<Item = &sonic_pc::Commitment<E>> {
         self.circuit_commitments.iter()
     }
+
+    /// Returns whether the circuit has lookups, in which case the verifying key
+    /// also commits to the lookup index polynomials.
+    pub fn has_lookups(&self) -> bool {
+        // The arithmetizations of the matrices `A`, `B` and `C` have four polynomials each.
+        self.circuit_commitments.len() > 12
+    }
 }
 
 impl<E: PairingEngine> FromStr for CircuitVerifyingKey<E> {
@@ -20,7 +20,7 @@ use crate::{
 
 use ahp::prover::{FourthMessage, ThirdMessage};
 use snarkvm_curves::PairingEngine;
-use snarkvm_fields::PrimeField;
+use snarkvm_fields::{PrimeField, Zero};
 use snarkvm_utilities::{
     error,
     io::{self, Read, Write},
@@ -50,6 +50,8 @@ pub struct Commitments<E: PairingEngine> {
     pub g_c_commitments: Vec<sonic_pc::Commitment<E>>,
     /// Commitment to the `h_2` polynomial.
     pub h_2: sonic_pc::Commitment<E>,
+    /// Commitments to the lookup polynomials of each instance of the circuits with lookups.
+    pub lookup_commitments: Vec<LookupCommitments<E>>,
 }
 
 impl<E: PairingEngine> Commitments<E> {
@@ -67,6 +69,7 @@ impl<E: PairingEngine> Commitments<E> {
         serialize_vec_without_len(self.g_b_commitments.iter(), &mut writer, compress)?;
         serialize_vec_without_len(self.g_c_commitments.iter(), &mut writer, compress)?;
         CanonicalSerialize::serialize_with_mode(&self.h_2, &mut writer, compress)?;
+        serialize_vec_without_len(self.lookup_commitments.iter(), &mut writer, compress)?;
         Ok(())
     }
 
@@ -80,10 +83,12 @@ impl<E: PairingEngine> Commitments<E> {
             .saturating_add(serialized_vec_size_without_len(&self.g_b_commitments, compress))
             .saturating_add(serialized_vec_size_without_len(&self.g_c_commitments, compress))
             .saturating_add(CanonicalSerialize::serialized_size(&self.h_2, compress))
+            .saturating_add(serialized_vec_size_without_len(&self.lookup_commitments, compress))
     }
 
     fn deserialize_with_mode<R: snarkvm_utilities::Read>(
         batch_sizes: &[usize],
+        lookups: &[bool],
         mut reader: R,
         compress: Compress,
         validate: Validate,
@@ -92,6 +97,7 @@ impl<E: PairingEngine> Commitments<E> {
         for batch_size in batch_sizes {
             w.extend(deserialize_vec_without_len(&mut reader, compress, validate, *batch_size)?);
         }
+        let num_lookup_instances = num_lookup_instances(batch_sizes, lookups);
         Ok(Commitments {
             witness_commitments: w,
             mask_poly: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
@@ -102,9 +108,16 @@ impl<E: PairingEngine> Commitments<E> {
             g_b_commitments: deserialize_vec_without_len(&mut reader, compress, validate, batch_sizes.len())?,
             g_c_commitments: deserialize_vec_without_len(&mut reader, compress, validate, batch_sizes.len())?,
             h_2: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            lookup_commitments: deserialize_vec_without_len(&mut reader, compress, validate, num_lookup_instances)?,
         })
     }
 }
+
+/// Returns the number of instances of the circuits with lookups.
+fn num_lookup_instances(batch_sizes: &[usize], lookups: &[bool]) -> usize {
+    batch_sizes.iter().zip(lookups).filter(|(_, has_lookups)| **has_lookups).map(|(batch_size, _)| batch_size).sum()
+}
+
 /// Commitments to the `w` polynomials.
 #[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct WitnessCommitments<E: PairingEngine> {
@@ -112,6 +125,23 @@ pub struct WitnessCommitments<E: PairingEngine> {
     pub w: sonic_pc::Commitment<E>,
 }
 
+/// Commitments to the polynomials of the lookup argument of an instance.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct LookupCommitments<E: PairingEngine> {
+    /// Commitment to the multiplicity polynomial `m`, from the first round.
+    pub m: sonic_pc::Commitment<E>,
+    /// Commitment to the `h` polynomial, from the second round.
+    pub h: sonic_pc::Commitment<E>,
+    /// Commitment to the `g` polynomial, from the second round.
+    pub g: sonic_pc::Commitment<E>,
+    /// Commitment to the `s` polynomial, from the second round.
+    pub s: sonic_pc::Commitment<E>,
+    /// Commitment to the quotient of the zerocheck on `h`, from the second round.
+    pub q_h: sonic_pc::Commitment<E>,
+    /// Commitment to the quotient of the zerocheck on `g`, from the second round.
+    pub q_g: sonic_pc::Commitment<E>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Evaluations<F: PrimeField> {
     /// Evaluation of `g_1` at `beta`.
@@ -122,6 +152,19 @@ pub struct Evaluations<F: PrimeField> {
     pub g_b_evals: Vec<F>,
     /// Evaluation of `g_c_i`'s at `gamma`.
     pub g_c_evals: Vec<F>,
+    /// Evaluations of the lookup table, tag and `s` polynomials at `alpha`, for the circuits with lookups.
+    pub lookup_evals: Vec<Option<LookupEvaluations<F>>>,
+}
+
+/// Evaluations of the lookup index polynomials of a circuit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LookupEvaluations<F: PrimeField> {
+    /// Evaluation of the combined lookup table at `alpha`.
+    pub table: F,
+    /// Evaluation of the lookup tag at `alpha`.
+    pub tag: F,
+    /// Evaluations of the `s` polynomial of each instance at `alpha`.
+    pub s: Vec<F>,
 }
 
 impl<F: PrimeField> Evaluations<F> {
@@ -134,6 +177,11 @@ impl<F: PrimeField> Evaluations<F> {
         serialize_vec_without_len(self.g_a_evals.iter(), &mut writer, compress)?;
         serialize_vec_without_len(self.g_b_evals.iter(), &mut writer, compress)?;
         serialize_vec_without_len(self.g_c_evals.iter(), &mut writer, compress)?;
+        for evals in self.lookup_evals.iter().flatten() {
+            CanonicalSerialize::serialize_with_mode(&evals.table, &mut writer, compress)?;
+            CanonicalSerialize::serialize_with_mode(&evals.tag, &mut writer, compress)?;
+            serialize_vec_without_len(evals.s.iter(), &mut writer, compress)?;
+        }
         Ok(())
     }
 
@@ -142,10 +190,22 @@ impl<F: PrimeField> Evaluations<F> {
             .saturating_add(serialized_vec_size_without_len(&self.g_a_evals, compress))
             .saturating_add(serialized_vec_size_without_len(&self.g_b_evals, compress))
             .saturating_add(serialized_vec_size_without_len(&self.g_c_evals, compress))
+            .saturating_add(
+                self.lookup_evals
+                    .iter()
+                    .flatten()
+                    .map(|evals| {
+                        CanonicalSerialize::serialized_size(&evals.table, compress)
+                            .saturating_add(CanonicalSerialize::serialized_size(&evals.tag, compress))
+                            .saturating_add(serialized_vec_size_without_len(&evals.s, compress))
+                    })
+                    .sum(),
+            )
     }
 
     fn deserialize_with_mode<R: snarkvm_utilities::Read>(
         batch_sizes: &[usize],
+        lookups: &[bool],
         mut reader: R,
         compress: Compress,
         validate: Validate,
@@ -155,6 +215,21 @@ impl<F: PrimeField> Evaluations<F> {
             g_a_evals: deserialize_vec_without_len(&mut reader, compress, validate, batch_sizes.len())?,
             g_b_evals: deserialize_vec_without_len(&mut reader, compress, validate, batch_sizes.len())?,
             g_c_evals: deserialize_vec_without_len(&mut reader, compress, validate, batch_sizes.len())?,
+            lookup_evals: lookups
+                .iter()
+                .zip(batch_sizes)
+                .map(|(has_lookups, &batch_size)| {
+                    has_lookups
+                        .then(|| {
+                            Ok(LookupEvaluations {
+                                table: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+                                tag: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+                                s: deserialize_vec_without_len(&mut reader, compress, validate, batch_size)?,
+                            })
+                        })
+                        .transpose()
+                })
+                .collect::<Result<_, snarkvm_utilities::SerializationError>>()?,
         })
     }
 }
@@ -181,7 +256,17 @@ impl<F: PrimeField> Evaluations<F> {
                 g_c_evals.push(*value);
             }
         }
-        Self { g_1_eval: map["g_1"], g_a_evals, g_b_evals, g_c_evals }
+        let lookup_evals = batch_sizes
+            .keys()
+            .map(|id| {
+                let table = map.get(&format!("circuit_{id}_lookup_table"));
+                let tag = map.get(&format!("circuit_{id}_lookup_tag"));
+                let s_label = format!("circuit_{id}_lookup_s_");
+                let s = map.iter().filter(|(label, _)| label.starts_with(&s_label)).map(|(_, s)| *s);
+                table.zip(tag).map(|(&table, &tag)| LookupEvaluations { table, tag, s: s.collect() })
+            })
+            .collect();
+        Self { g_1_eval: map["g_1"], g_a_evals, g_b_evals, g_c_evals, lookup_evals }
     }
 
     pub(crate) fn get(&self, circuit_index: usize, label: &str) -> Option<F> {
@@ -189,6 +274,16 @@ impl<F: PrimeField> Evaluations<F> {
             return Some(self.g_1_eval);
         }
 
+        // The lookup labels are checked first, as they may contain the labels below.
+        if label.ends_with("_lookup_table") {
+            return self.lookup_evals.get(circuit_index)?.as_ref().map(|evals| evals.table);
+        } else if label.ends_with("_lookup_tag") {
+            return self.lookup_evals.get(circuit_index)?.as_ref().map(|evals| evals.tag);
+        } else if let Some((_, instance)) = label.rsplit_once("_lookup_s_") {
+            let instance = instance.parse::<usize>().ok()?;
+            return self.lookup_evals.get(circuit_index)?.as_ref()?.s.get(instance).copied();
+        }
+
         if label.contains("g_a") {
             self.g_a_evals.get(circuit_index).copied()
         } else if label.contains("g_b") {
@@ -206,6 +301,10 @@ impl<F: PrimeField> Evaluations<F> {
         result.extend_from_slice(&self.g_a_evals);
         result.extend_from_slice(&self.g_b_evals);
         result.extend_from_slice(&self.g_c_evals);
+        for evals in self.lookup_evals.iter().flatten() {
+            result.extend([evals.table, evals.tag]);
+            result.extend_from_slice(&evals.s);
+        }
         result
     }
 }
@@ -215,7 +314,12 @@ impl<F: PrimeField> Valid for Evaluations<F> {
         self.g_1_eval.check()?;
         self.g_a_evals.check()?;
         self.g_b_evals.check()?;
-        self.g_c_evals.check()
+        self.g_c_evals.check()?;
+        self.lookup_evals.iter().flatten().try_for_each(|evals| {
+            evals.table.check()?;
+            evals.tag.check()?;
+            evals.s.check()
+        })
     }
 }
 
@@ -259,6 +363,38 @@ impl<E: PairingEngine> Proof<E> {
         &self.batch_sizes
     }
 
+    /// Returns whether each circuit in the proof has lookups.
+    pub fn lookups(&self) -> Vec<bool> {
+        self.evaluations.lookup_evals.iter().map(Option::is_some).collect()
+    }
+
+    /// Returns whether any circuit in the proof has lookups.
+    pub fn has_lookups(&self) -> bool {
+        self.evaluations.lookup_evals.iter().any(Option::is_some)
+    }
+
+    /// Returns an upper bound on the number of bytes in a (compressed) proof for the given number of instances.
+    /// The bound holds for any assignment of the instances to circuits, with or without lookups.
+    pub fn max_serialized_size(num_instances: usize) -> usize {
+        // Determine the size of a batch size, a commitment, a field element, and an evaluation proof.
+        let batch_size = 0u64.compressed_size();
+        let commitment = crate::polycommit::kzg10::KZGCommitment::<E>(Default::default()).compressed_size();
+        let field = E::Fr::zero().compressed_size();
+        let kzg_proof =
+            crate::polycommit::kzg10::KZGProof::<E> { w: Default::default(), random_v: Some(E::Fr::zero()) }
+                .compressed_size();
+
+        // Each instance contributes at most one circuit, which has a batch size,
+        // the `w`, `g_a`, `g_b`, `g_c` and 6 lookup commitments, the 3 `g` and 3 lookup evaluations,
+        // and 3 sums in each of the third and fourth messages.
+        let size_per_instance = batch_size + 10 * commitment + 12 * field;
+        // Every proof has the lengths of the batch sizes and of the evaluation proofs, the mask, `h_0`, `g_1`, `h_1`
+        // and `h_2` commitments, the `g_1` evaluation, and an evaluation proof for each of the 3 query points.
+        let size_of_proof = 2 * batch_size + (1 + commitment) + 4 * commitment + field + 3 * kzg_proof;
+
+        size_of_proof.saturating_add(size_per_instance.saturating_mul(num_instances))
+    }
+
     /// Check that the number of messages is consistent with our batch size
     pub fn check_batch_sizes(&self) -> Result<(), SNARKError> {
         let total_instances = self
@@ -282,6 +418,17 @@ impl<E: PairingEngine> Proof<E> {
                 return Err(SNARKError::BatchSizeMismatch);
             }
         }
+        if self.evaluations.lookup_evals.len() != self.batch_sizes.len() {
+            return Err(SNARKError::BatchSizeMismatch);
+        }
+        for (evals, batch_size) in self.evaluations.lookup_evals.iter().zip(&self.batch_sizes) {
+            if evals.as_ref().is_some_and(|evals| evals.s.len() != *batch_size) {
+                return Err(SNARKError::BatchSizeMismatch);
+            }
+        }
+        if self.commitments.lookup_commitments.len() != num_lookup_instances(&self.batch_sizes, &self.lookups()) {
+            return Err(SNARKError::BatchSizeMismatch);
+        }
         if self.third_msg.sums.len() != self.batch_sizes.len() {
             return Err(SNARKError::BatchSizeMismatch);
         }
@@ -299,7 +446,7 @@ impl<E: PairingEngine> Proof<E> {
 
 impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
     fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
-        let batch_sizes: Vec<u64> = self.batch_sizes.iter().map(|x| u64::try_from(*x)).collect::<Result<_, _>>()?;
+        let batch_sizes = encode_batch_sizes(&self.batch_sizes, &self.lookups())?;
         CanonicalSerialize::serialize_with_mode(&batch_sizes, &mut writer, compress)?;
         Commitments::serialize_with_mode(&self.commitments, &mut writer, compress)?;
         Evaluations::serialize_with_mode(&self.evaluations, &mut writer, compress)?;
@@ -343,9 +490,9 @@ impl<E: PairingEngine> CanonicalDeserialize for Proof<E> {
         validate: Validate,
     ) -> Result<Self, SerializationError> {
         let batch_sizes: Vec<u64> = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
-        let batch_sizes: Vec<usize> = batch_sizes.into_iter().map(|x| x as usize).collect();
-        let commitments = Commitments::deserialize_with_mode(&batch_sizes, &mut reader, compress, validate)?;
-        let evaluations = Evaluations::deserialize_with_mode(&batch_sizes, &mut reader, compress, validate)?;
+        let (batch_sizes, lookups) = decode_batch_sizes(batch_sizes);
+        let commitments = Commitments::deserialize_with_mode(&batch_sizes, &lookups, &mut reader, compress, validate)?;
+        let evaluations = Evaluations::deserialize_with_mode(&batch_sizes, &lookups, &mut reader, compress, validate)?;
         let third_msg_sums = batch_sizes
             .iter()
             .map(|&batch_size| deserialize_vec_without_len(&mut reader, compress, validate, batch_size))
@@ -362,6 +509,30 @@ impl<E: PairingEngine> CanonicalDeserialize for Proof<E> {
     }
 }
 
+/// The flag in a serialized batch size which marks a circuit with lookups.
+/// Proofs without lookups serialize their batch sizes as before.
+const LOOKUP_FLAG: u64 = 1 << 63;
+
+/// Encodes the batch sizes, together with whether each circuit has lookups.
+fn encode_batch_sizes(batch_sizes: &[usize], lookups: &[bool]) -> Result<Vec<u64>, SerializationError> {
+    batch_sizes
+        .iter()
+        .zip(lookups)
+        .map(|(&batch_size, &has_lookups)| {
+            let batch_size = u64::try_from(batch_size)?;
+            match batch_size & LOOKUP_FLAG == 0 {
+                true => Ok(if has_lookups { batch_size | LOOKUP_FLAG } else { batch_size }),
+                false => Err(SerializationError::InvalidData),
+            }
+        })
+        .collect()
+}
+
+/// Decodes the batch sizes, together with whether each circuit has lookups.
+fn decode_batch_sizes(batch_sizes: Vec<u64>) -> (Vec<usize>, Vec<bool>) {
+    batch_sizes.into_iter().map(|x| ((x & !LOOKUP_FLAG) as usize, x & LOOKUP_FLAG != 0)).unzip()
+}
+
 impl<E: PairingEngine> ToBytes for Proof<E> {
     fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
         Self::serialize_compressed(self, &mut w).map_err(|_| error("could not serialize Proof"))
@@ -407,11 +578,24 @@ mod test {
         FromBytes::read_le(buf.as_slice()).unwrap()
     }
 
+    /// Every other circuit has lookups.
+    fn sample_lookups(i: usize) -> Vec<bool> {
+        (0..i).map(|k| k % 2 == 1).collect()
+    }
+
     fn rand_commitments(j: usize, i: usize, test_with_none: bool) -> Commitments<Bls12_377> {
         assert!(i > 0);
         assert!(j > 0);
         let sample_commit = sample_commit();
         let mask_poly = if test_with_none { None } else { Some(sample_commit) };
+        let lookup_commitments = LookupCommitments {
+            m: sample_commit,
+            h: sample_commit,
+            g: sample_commit,
+            s: sample_commit,
+            q_h: sample_commit,
+            q_g: sample_commit,
+        };
         Commitments {
             witness_commitments: vec![WitnessCommitments { w: sample_commit }; i * j],
             mask_poly,
@@ -422,15 +606,26 @@ mod test {
             g_b_commitments: vec![sample_commit; i],
             g_c_commitments: vec![sample_commit; i],
             h_2: sample_commit,
+            lookup_commitments: vec![lookup_commitments; num_lookup_instances(&vec![j; i], &sample_lookups(i))],
         }
     }
 
-    fn rand_evaluations<F: PrimeField>(rng: &mut TestRng, i: usize) -> Evaluations<F> {
+    fn rand_evaluations<F: PrimeField>(rng: &mut TestRng, i: usize, j: usize) -> Evaluations<F> {
         Evaluations {
             g_1_eval: F::rand(rng),
             g_a_evals: (0..i).map(|_| F::rand(rng)).collect(),
             g_b_evals: (0..i).map(|_| F::rand(rng)).collect(),
             g_c_evals: (0..i).map(|_| F::rand(rng)).collect(),
+            lookup_evals: sample_lookups(i)
+                .into_iter()
+                .map(|has_lookups| {
+                    has_lookups.then(|| LookupEvaluations {
+                        table: F::rand(rng),
+                        tag: F::rand(rng),
+                        s: (0..j).map(|_| F::rand(rng)).collect(),
+                    })
+                })
+                .collect(),
         }
     }
 
@@ -455,8 +650,10 @@ mod test {
                     let size = Commitments::serialized_size(&commitments, compress);
                     let mut serialized = vec![0; size];
                     Commitments::serialize_with_mode(&commitments, &mut serialized[..], compress).unwrap();
+                    let lookups = sample_lookups(i);
                     let de =
-                        Commitments::deserialize_with_mode(&batch_sizes, &serialized[..], compress, validate).unwrap();
+                        Commitments::deserialize_with_mode(&batch_sizes, &lookups, &serialized[..], compress, validate)
+                            .unwrap();
                     assert_eq!(commitments, de);
                 }
             }
@@ -469,21 +666,33 @@ mod test {
 
         for i in 1..11 {
             for j in 1..11 {
-                let evaluations: Evaluations<Fr> = rand_evaluations(rng, i);
+                let evaluations: Evaluations<Fr> = rand_evaluations(rng, i, j);
                 let batch_sizes = vec![j; i];
                 let combinations = modes();
                 for (compress, validate) in combinations {
                     let size = Evaluations::serialized_size(&evaluations, compress);
                     let mut serialized = vec![0; size];
                     Evaluations::serialize_with_mode(&evaluations, &mut serialized[..], compress).unwrap();
+                    let lookups = sample_lookups(i);
                     let de =
-                        Evaluations::deserialize_with_mode(&batch_sizes, &serialized[..], compress, validate).unwrap();
+                        Evaluations::deserialize_with_mode(&batch_sizes, &lookups, &serialized[..], compress, validate)
+                            .unwrap();
                     assert_eq!(evaluations, de);
                 }
             }
         }
     }
 
+    #[test]
+    fn test_batch_sizes_encoding() {
+        let batch_sizes = vec![1, 3, 2];
+        let lookups = vec![false, true, false];
+        let encoded = encode_batch_sizes(&batch_sizes, &lookups).unwrap();
+        // Circuits without lookups keep their batch size as is.
+        assert_eq!(encoded, vec![1, 3 | LOOKUP_FLAG, 2]);
+        assert_eq!(decode_batch_sizes(encoded), (batch_sizes, lookups));
+    }
+
     #[test]
     fn test_serializing_proof() {
         let rng = &mut snarkvm_utilities::rand::TestRng::default();
@@ -493,12 +702,14 @@ mod test {
                 let test_with_none = i * j % 2 == 0;
                 let batch_sizes = vec![j; i];
                 let commitments = rand_commitments(j, i, test_with_none);
-                let evaluations: Evaluations<Fr> = rand_evaluations(rng, i);
+                let evaluations: Evaluations<Fr> = rand_evaluations(rng, i, j);
                 let third_msg = ThirdMessage::<Fr> { sums: vec![vec![rand_sums(rng); j]; i] };
                 let fourth_msg = FourthMessage::<Fr> { sums: vec![rand_sums(rng); i] };
                 let pc_proof =
                     sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, test_with_none); j]) };
                 let proof = Proof { batch_sizes, commitments, evaluations, third_msg, fourth_msg, pc_proof };
+                assert_eq!(proof.lookups(), sample_lookups(i));
+                proof.check_batch_sizes().unwrap();
                 let combinations = modes();
                 for (compress, validate) in combinations {
                     let size = Proof::serialized_size(&proof, compress);
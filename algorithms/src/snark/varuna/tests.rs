@@ -122,6 +122,10 @@ mod varuna {
                                 $snark_inst::prove_batch(universal_prover, &fs_parameters, &pks_to_constraints, rng).unwrap();
                             println!("Called prover");
 
+                            // Ensure the size of the proof is within its upper bound.
+                            let max_size = crate::snark::varuna::Proof::<Bls12_377>::max_serialized_size(circuit_batch_size * instance_batch_size);
+                            assert!(proof.to_bytes_le().unwrap().len() <= max_size);
+
                             assert!(
                                 $snark_inst::verify_batch(universal_verifier, &fs_parameters, &vks_to_inputs, &proof).unwrap(),
                                 "Batch verification failed with {instance_batch_size} instances and {circuit_batch_size} circuits for circuits: {constraints:?}"
@@ -689,7 +693,7 @@ mod varuna_test_vectors {
 
         let combiners = verifier::BatchCombiners::<Fr> { circuit_combiner, instance_combiners };
         let batch_combiners = BTreeMap::from_iter([(index_pk.circuit.id, combiners)]);
-        let verifier_first_msg = verifier::FirstMessage::<Fr> { batch_combiners };
+        let verifier_first_msg = verifier::FirstMessage::<Fr> { batch_combiners, lookup_challenges: None };
 
         let (second_oracles, prover_state) =
             AHPForR1CS::<_, MM>::prover_second_round::<_>(&verifier_first_msg, prover_state, rng).unwrap();
@@ -805,3 +809,244 @@ mod varuna_test_vectors {
         test_varuna_with_all_circuits(false);
     }
 }
+
+mod varuna_lookups {
+    use crate::{
+        crypto_hash::PoseidonSponge,
+        r1cs::{ConstraintSynthesizer, ConstraintSystem, LinearCombination, LookupTable, SynthesisError},
+        snark::varuna::{
+            ahp::{AHPForR1CS, SKIP_LOOKUP_CHECKS},
+            test_circuit::TestCircuit,
+            CircuitProvingKey,
+            CircuitVerifyingKey,
+            VarunaHidingMode,
+            VarunaSNARK,
+        },
+        traits::{AlgebraicSponge, SNARK},
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_fields::{Field, One};
+    use snarkvm_utilities::{
+        rand::{TestRng, Uniform},
+        FromBytes,
+        ToBytes,
+    };
+
+    use rand::Rng;
+    use std::collections::BTreeMap;
+
+    type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
+    type FS = PoseidonSponge<Fq, 2, 1>;
+
+    /// A circuit which looks up the XOR of pairs of 2-bit values,
+    /// and exposes the sum of the results as its public input.
+    #[derive(Clone, Debug)]
+    struct XorCircuit {
+        rows: Vec<[u64; 3]>,
+    }
+
+    impl XorCircuit {
+        fn gen_rand(num_lookups: usize, rng: &mut TestRng) -> (Self, Vec<Fr>) {
+            let rows = (0..num_lookups)
+                .map(|_| {
+                    let (a, b) = (rng.gen_range(0..4), rng.gen_range(0..4));
+                    [a, b, a ^ b]
+                })
+                .collect::<Vec<_>>();
+            let sum = rows.iter().map(|[_, _, c]| Fr::from(*c)).sum();
+            (Self { rows }, vec![Fr::one(), sum])
+        }
+    }
+
+    impl<F: Field> ConstraintSynthesizer<F> for XorCircuit {
+        fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+            let table = (0u64..4)
+                .flat_map(|a| (0u64..4).map(move |b| [F::from(a), F::from(b), F::from(a ^ b)]))
+                .collect::<LookupTable<F>>();
+            let table_index = cs.add_lookup_table(table);
+
+            let mut sum = LinearCombination::zero();
+            let mut sum_value = F::zero();
+            for (i, row) in self.rows.iter().enumerate() {
+                let [a, b, c] = row.map(F::from);
+                let a = cs.alloc(|| format!("a_{i}"), || Ok(a))?;
+                let b = cs.alloc(|| format!("b_{i}"), || Ok(b))?;
+                let c_var = cs.alloc(|| format!("c_{i}"), || Ok(c))?;
+                cs.enforce_lookup(|| format!("xor_{i}"), |lc| lc + a, |lc| lc + b, |lc| lc + c_var, table_index);
+
+                sum = sum + c_var;
+                sum_value += c;
+            }
+
+            let sum_input = cs.alloc_input(|| "sum", || Ok(sum_value))?;
+            cs.enforce(|| "sum", |lc| lc + &sum, |lc| lc + CS::one(), |lc| lc + sum_input);
+            Ok(())
+        }
+    }
+
+    /// A circuit with or without lookups, to batch both kinds of circuits in one proof.
+    enum MixedCircuit {
+        Xor(XorCircuit),
+        Test(TestCircuit<Fr>),
+    }
+
+    impl ConstraintSynthesizer<Fr> for MixedCircuit {
+        fn generate_constraints<CS: ConstraintSystem<Fr>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+            match self {
+                Self::Xor(circuit) => circuit.generate_constraints(cs),
+                Self::Test(circuit) => circuit.generate_constraints(cs),
+            }
+        }
+    }
+
+    #[test]
+    fn test_lookup_circuit() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        for num_lookups in [1, 8, 20] {
+            let (circuit, public_inputs) = XorCircuit::gen_rand(num_lookups, rng);
+            let fake_inputs = vec![Fr::one(), public_inputs[1] + Fr::one()];
+
+            let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+            assert!(index_pk.circuit.has_lookups());
+            assert!(index_vk.has_lookups());
+
+            let certificate = VarunaInst::prove_vk(universal_prover, &fs_parameters, &index_vk, &index_pk).unwrap();
+            assert!(
+                VarunaInst::verify_vk(universal_verifier, &fs_parameters, &circuit, &index_vk, &certificate).unwrap()
+            );
+
+            let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+            assert!(proof.has_lookups());
+            let inputs = public_inputs.as_slice();
+            assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, inputs, &proof).unwrap());
+            assert!(!VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, fake_inputs, &proof).unwrap());
+
+            // Ensure the keys and the proof serialize with their lookups.
+            let index_pk_bytes = index_pk.to_bytes_le().unwrap();
+            let candidate_pk = CircuitProvingKey::read_le(&index_pk_bytes[..]).unwrap();
+            assert_eq!(index_pk, candidate_pk);
+            assert!(candidate_pk.circuit.has_lookups());
+            let index_vk_bytes = index_vk.to_bytes_le().unwrap();
+            assert_eq!(index_vk, CircuitVerifyingKey::read_le(&index_vk_bytes[..]).unwrap());
+            let proof_bytes = proof.to_bytes_le().unwrap();
+            let candidate_proof = FromBytes::read_le(&proof_bytes[..]).unwrap();
+            assert_eq!(proof, candidate_proof);
+            assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &candidate_proof)
+                .unwrap());
+        }
+    }
+
+    #[test]
+    fn test_lookup_circuit_fails_with_invalid_lookup() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (mut circuit, _) = XorCircuit::gen_rand(8, rng);
+        let (index_pk, _) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Replace one of the lookups with a row which is not in the table.
+        circuit.rows[3] = [1, 1, 1];
+        assert!(VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).is_err());
+    }
+
+    #[test]
+    fn test_lookup_circuit_verification_fails_with_invalid_lookup() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (mut circuit, _) = XorCircuit::gen_rand(8, rng);
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Replace one of the lookups with a row which is not in the table, and let the prover skip its lookup checks.
+        circuit.rows[3] = [1, 1, 1];
+        let public_inputs = vec![Fr::one(), circuit.rows.iter().map(|[_, _, c]| Fr::from(*c)).sum()];
+        SKIP_LOOKUP_CHECKS.with(|skip| skip.set(true));
+        let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng);
+        SKIP_LOOKUP_CHECKS.with(|skip| skip.set(false));
+
+        // The verifier must reject the proof.
+        let proof = proof.unwrap();
+        assert!(!VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap());
+    }
+
+    #[test]
+    fn test_lookup_circuit_batch() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(300, 100, 600).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        // Batch circuits with and without lookups.
+        let (xor_circuits, xor_inputs): (Vec<_>, Vec<_>) = (0..3)
+            .map(|_| {
+                let (circuit, inputs) = XorCircuit::gen_rand(12, rng);
+                (MixedCircuit::Xor(circuit), inputs)
+            })
+            .unzip();
+        let (test_circuits, test_inputs): (Vec<_>, Vec<_>) = (0..2)
+            .map(|_| {
+                let (circuit, inputs) = TestCircuit::gen_rand(2, 50, 25, rng);
+                (MixedCircuit::Test(circuit), inputs)
+            })
+            .unzip();
+
+        let (xor_pk, xor_vk) = VarunaInst::circuit_setup(&universal_srs, &xor_circuits[0]).unwrap();
+        let (test_pk, test_vk) = VarunaInst::circuit_setup(&universal_srs, &test_circuits[0]).unwrap();
+        assert!(!test_vk.has_lookups());
+
+        let proof = {
+            let mut pks_to_constraints = BTreeMap::new();
+            pks_to_constraints.insert(&xor_pk, &xor_circuits[..]);
+            let proof = VarunaInst::prove_batch(universal_prover, &fs_parameters, &pks_to_constraints, rng).unwrap();
+
+            let mut vks_to_inputs = BTreeMap::new();
+            vks_to_inputs.insert(&xor_vk, &xor_inputs[..]);
+            assert!(VarunaInst::verify_batch(universal_verifier, &fs_parameters, &vks_to_inputs, &proof).unwrap());
+            proof
+        };
+        assert_eq!(vec![true], proof.lookups());
+
+        let mut pks_to_constraints = BTreeMap::new();
+        let mut vks_to_inputs = BTreeMap::new();
+        let mut fake_vks_to_inputs = BTreeMap::new();
+        let fake_inputs = vec![vec![Fr::one(), Fr::rand(rng)]; xor_inputs.len()];
+        pks_to_constraints.insert(&xor_pk, &xor_circuits[..]);
+        vks_to_inputs.insert(&xor_vk, &xor_inputs[..]);
+        fake_vks_to_inputs.insert(&xor_vk, &fake_inputs[..]);
+        pks_to_constraints.insert(&test_pk, &test_circuits[..]);
+        vks_to_inputs.insert(&test_vk, &test_inputs[..]);
+        fake_vks_to_inputs.insert(&test_vk, &test_inputs[..]);
+
+        let proof = VarunaInst::prove_batch(universal_prover, &fs_parameters, &pks_to_constraints, rng).unwrap();
+        assert!(VarunaInst::verify_batch(universal_verifier, &fs_parameters, &vks_to_inputs, &proof).unwrap());
+        assert!(!VarunaInst::verify_batch(universal_verifier, &fs_parameters, &fake_vks_to_inputs, &proof).unwrap());
+
+        let proof_bytes = proof.to_bytes_le().unwrap();
+        let candidate_proof = FromBytes::read_le(&proof_bytes[..]).unwrap();
+        assert_eq!(proof, candidate_proof);
+        // Ensure the size of the proof is within its upper bound.
+        assert!(proof_bytes.len() <= crate::snark::varuna::Proof::<Bls12_377>::max_serialized_size(5));
+        let is_valid =
+            VarunaInst::verify_batch(universal_verifier, &fs_parameters, &vks_to_inputs, &candidate_proof).unwrap();
+        assert!(is_valid);
+    }
+}
@@ -50,7 +50,12 @@ use anyhow::{anyhow, bail, ensure, Result};
 use core::marker::PhantomData;
 use itertools::Itertools;
 use rand::{CryptoRng, Rng};
-use std::{borrow::Borrow, collections::BTreeMap, ops::Deref, sync::Arc};
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, BTreeSet},
+    ops::Deref,
+    sync::Arc,
+};
 
 use crate::srs::UniversalProver;
 #[cfg(not(feature = "std"))]
@@ -85,7 +90,11 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
             universal_srs.download_powers_for(0..indexed_circuit.max_degree()?).map_err(|e| {
                 anyhow!("Failed to download powers for degree {}: {e}", indexed_circuit.max_degree().unwrap())
             })?;
-            let coefficient_support = AHPForR1CS::<E::Fr, SM>::get_degree_bounds(&indexed_circuit.index_info)?;
+            let mut coefficient_support =
+                AHPForR1CS::<E::Fr, SM>::get_degree_bounds(&indexed_circuit.index_info)?.to_vec();
+            if indexed_circuit.has_lookups() {
+                coefficient_support.push(AHPForR1CS::<E::Fr, SM>::lookup_degree_bound(&indexed_circuit.index_info)?);
+            }
 
             // Varuna only needs degree 2 random polynomials.
             let supported_hiding_bound = 1;
@@ -106,7 +115,7 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
             let (mut circuit_commitments, commitment_randomnesses): (_, _) = SonicKZG10::<E, FS>::commit(
                 universal_prover,
                 &ck,
-                indexed_circuit.interpolate_matrix_evals()?.map(Into::into),
+                indexed_circuit.interpolate_index_polynomials()?.map(Into::into),
                 setup_rng,
             )?;
             let empty_randomness = Randomness::<E>::empty();
@@ -250,7 +259,10 @@ where
         let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
 
         let circuit_id = std::iter::once(&verifying_key.id);
-        let circuit_poly_info = AHPForR1CS::<E::Fr, SM>::index_polynomial_info(circuit_id);
+        let mut circuit_poly_info = AHPForR1CS::<E::Fr, SM>::index_polynomial_info(circuit_id.clone());
+        if verifying_key.has_lookups() {
+            circuit_poly_info.extend(AHPForR1CS::<E::Fr, SM>::lookup_index_polynomial_info(circuit_id));
+        }
 
         // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
         let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
@@ -261,12 +273,12 @@ where
         let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
         let committer_key = CommitterUnionKey::union(std::iter::once(proving_key.committer_key.as_ref()));
 
-        let empty_randomness = vec![Randomness::<E>::empty(); 12];
+        let empty_randomness = vec![Randomness::<E>::empty(); verifying_key.circuit_commitments.len()];
         let certificate = SonicKZG10::<E, FS>::open_combinations(
             universal_prover,
             &committer_key,
             &[lc],
-            proving_key.circuit.interpolate_matrix_evals()?,
+            proving_key.circuit.interpolate_index_polynomials()?,
             &empty_randomness,
             &query_set,
             &mut sponge,
@@ -349,6 +361,8 @@ where
             circuits_to_constraints.insert(pk.circuit.deref(), *constraints);
         }
         let prover_state = AHPForR1CS::<_, SM>::init_prover(&circuits_to_constraints, zk_rng)?;
+        let lookups: BTreeSet<_> =
+            keys_to_constraints.keys().filter(|pk| pk.circuit.has_lookups()).map(|pk| pk.circuit.id).collect();
 
         // extract information from the prover key and state to consume in further calculations
         let mut batch_sizes = BTreeMap::new();
@@ -403,6 +417,7 @@ where
         let (verifier_first_message, verifier_state) = AHPForR1CS::<_, SM>::verifier_first_round(
             &batch_sizes,
             &circuit_infos,
+            &lookups,
             prover_state.max_constraint_domain,
             prover_state.max_variable_domain,
             prover_state.max_non_zero_domain,
@@ -487,6 +502,12 @@ where
             prover_state.circuit_specific_states.values_mut().flat_map(|s| s.a_polys.take().unwrap()).collect_vec();
         let index_b_polys =
             prover_state.circuit_specific_states.values_mut().flat_map(|s| s.b_polys.take().unwrap()).collect_vec();
+        let index_lookup_polys = prover_state
+            .circuit_specific_states
+            .values_mut()
+            .flat_map(|s| s.lookup_polys.take())
+            .flatten()
+            .collect_vec();
 
         // --------------------------------------------------------------------
         // Fifth round
@@ -510,15 +531,19 @@ where
         let polynomials: Vec<_> = index_a_polys
             .into_iter()
             .chain(index_b_polys)
+            .chain(index_lookup_polys)
             .chain(first_round_oracles.into_iter())
             .chain(second_oracles.into_iter())
             .chain(third_oracles.into_iter())
             .chain(fourth_oracles.into_iter())
             .chain(fifth_oracles.into_iter())
             .collect();
+        let num_lookup_instances = lookups.iter().map(|id| batch_sizes[id]).sum::<usize>();
         ensure!(
             polynomials.len()
                 == num_unique_circuits * 6 + // numerator and denominator for each matrix sumcheck
+            lookups.len() * 6 + // lookup index polynomials
+            AHPForR1CS::<E::Fr, SM>::num_lookup_oracles(num_lookup_instances) +
             AHPForR1CS::<E::Fr, SM>::num_first_round_oracles(total_instances) +
             AHPForR1CS::<E::Fr, SM>::num_second_round_oracles() +
             AHPForR1CS::<E::Fr, SM>::num_third_round_oracles() +
@@ -527,12 +552,24 @@ where
         );
 
         // Gather commitments in one vector.
-        let witness_comm_len = if SM::ZK { first_commitments.len() - 1 } else { first_commitments.len() };
-        let mask_poly = SM::ZK.then(|| *first_commitments[witness_comm_len].commitment());
+        let witness_comm_len = total_instances;
+        let mask_poly = SM::ZK.then(|| *first_commitments[witness_comm_len + num_lookup_instances].commitment());
         let witness_commitments = first_commitments[..witness_comm_len]
             .iter()
             .map(|c| proof::WitnessCommitments { w: *c.commitment() })
             .collect_vec();
+        let lookup_commitments = first_commitments[witness_comm_len..][..num_lookup_instances]
+            .iter()
+            .zip_eq(second_commitments[1..].chunks_exact(5))
+            .map(|(m, c)| proof::LookupCommitments {
+                m: *m.commitment(),
+                h: *c[0].commitment(),
+                g: *c[1].commitment(),
+                s: *c[2].commitment(),
+                q_h: *c[3].commitment(),
+                q_g: *c[4].commitment(),
+            })
+            .collect_vec();
         let fourth_commitments_chunked = fourth_commitments.chunks_exact(3);
         let (g_a_commitments, g_b_commitments, g_c_commitments) = fourth_commitments_chunked
             .map(|c| (*c[0].commitment(), *c[1].commitment(), *c[2].commitment()))
@@ -549,10 +586,11 @@ where
             g_b_commitments,
             g_c_commitments,
             h_2: *fifth_commitments[0].commitment(),
+            lookup_commitments,
         };
 
         // Gather commitment randomness together.
-        let indexer_randomness = vec![Randomness::<E>::empty(); 6 * num_unique_circuits];
+        let indexer_randomness = vec![Randomness::<E>::empty(); 6 * num_unique_circuits + 6 * lookups.len()];
         let commitment_randomnesses: Vec<Randomness<E>> = indexer_randomness
             .into_iter()
             .chain(first_commitment_randomnesses)
@@ -582,7 +620,7 @@ where
         let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
         let mut evaluations = std::collections::BTreeMap::new();
         for (label, (_, point)) in query_set.to_set() {
-            if !AHPForR1CS::<E::Fr, SM>::LC_WITH_ZERO_EVAL.contains(&label.as_str()) {
+            if !AHPForR1CS::<E::Fr, SM>::lc_has_zero_eval(&label) {
                 let lc = lc_s.get(&label).ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
                 let evaluation = polynomials.get_lc_eval(lc, point)?;
                 evaluations.insert(label, evaluation);
@@ -634,10 +672,19 @@ where
 
         proof.check_batch_sizes()?;
         let batch_sizes_vec = proof.batch_sizes();
+        let lookups_vec = proof.lookups();
         let mut batch_sizes = BTreeMap::new();
+        let mut lookup_batch_sizes = BTreeMap::new();
         for (i, (vk, public_inputs_i)) in keys_to_inputs.iter().enumerate() {
             batch_sizes.insert(vk.id, batch_sizes_vec[i]);
 
+            if lookups_vec[i] != vk.has_lookups() {
+                bail!("The proof does not match the lookups of circuit {}", vk.id);
+            }
+            if lookups_vec[i] {
+                lookup_batch_sizes.insert(vk.id, batch_sizes_vec[i]);
+            }
+
             if public_inputs_i.is_empty() {
                 bail!(SNARKError::EmptyBatch);
             }
@@ -748,6 +795,22 @@ where
             })
             .collect_vec();
 
+        // The lookup commitments are ordered by circuit and then by instance.
+        let lookup_instances = lookup_batch_sizes
+            .iter()
+            .flat_map(|(&circuit_id, &batch_size)| (0..batch_size).map(move |j| (circuit_id, j)))
+            .zip_eq(&comms.lookup_commitments)
+            .collect_vec();
+
+        let lookup_first_round_info =
+            AHPForR1CS::<E::Fr, SM>::lookup_first_round_polynomial_info(lookup_batch_sizes.iter());
+        first_commitments.extend(lookup_instances.iter().map(|((circuit_id, j), comms)| {
+            LabeledCommitment::new_with_info(
+                &lookup_first_round_info[&witness_label(*circuit_id, "lookup_m", *j)],
+                comms.m,
+            )
+        }));
+
         if SM::ZK {
             first_commitments.push(LabeledCommitment::new_with_info(
                 first_round_info.get("mask_poly").ok_or(anyhow!("Missing mask_poly"))?,
@@ -756,7 +819,24 @@ where
         }
 
         let second_round_info = AHPForR1CS::<E::Fr, SM>::second_round_polynomial_info();
-        let second_commitments = [LabeledCommitment::new_with_info(&second_round_info["h_0"], comms.h_0)];
+        let lookup_second_round_info = AHPForR1CS::<E::Fr, SM>::lookup_second_round_polynomial_info(
+            lookup_batch_sizes
+                .iter()
+                .map(|(circuit_id, &batch_size)| (*circuit_id, batch_size, circuit_infos[circuit_id])),
+        );
+        let mut second_commitments = vec![LabeledCommitment::new_with_info(&second_round_info["h_0"], comms.h_0)];
+        second_commitments.extend(lookup_instances.iter().flat_map(|((circuit_id, j), comms)| {
+            [
+                ("lookup_h", comms.h),
+                ("lookup_g", comms.g),
+                ("lookup_s", comms.s),
+                ("lookup_qh", comms.q_h),
+                ("lookup_qg", comms.q_g),
+            ]
+            .map(|(poly, comm)| {
+                LabeledCommitment::new_with_info(&lookup_second_round_info[&witness_label(*circuit_id, poly, *j)], comm)
+            })
+        }));
 
         let third_round_info = AHPForR1CS::<E::Fr, SM>::third_round_polynomial_info(max_variable_domain.size());
         let third_commitments = [
@@ -794,6 +874,7 @@ where
         let (_, verifier_state) = AHPForR1CS::<_, SM>::verifier_first_round(
             &batch_sizes,
             &circuit_infos,
+            &lookup_batch_sizes.keys().copied().collect(),
             max_constraint_domain,
             max_variable_domain,
             max_non_zero_domain,
@@ -844,10 +925,12 @@ where
         // degree bounds because we know the committed index polynomial has the
         // correct degree.
 
+        let mut index_polynomial_info = AHPForR1CS::<E::Fr, SM>::index_polynomial_info(circuit_ids.iter());
+        index_polynomial_info.extend(AHPForR1CS::<E::Fr, SM>::lookup_index_polynomial_info(lookup_batch_sizes.keys()));
         let commitments: Vec<_> = circuit_commitments
             .into_iter()
             .flatten()
-            .zip_eq(AHPForR1CS::<E::Fr, SM>::index_polynomial_info(circuit_ids.iter()).values())
+            .zip_eq(index_polynomial_info.values())
             .map(|(c, info)| LabeledCommitment::new_with_info(info, *c))
             .chain(first_commitments)
             .chain(second_commitments)
@@ -868,7 +951,7 @@ where
         let mut circuit_index: i64 = -1;
 
         for (label, (_point_name, q)) in query_set.to_set() {
-            if AHPForR1CS::<E::Fr, SM>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
+            if AHPForR1CS::<E::Fr, SM>::lc_has_zero_eval(&label) {
                 evaluations.insert((label, q), E::Fr::zero());
             } else {
                 if label != "g_1" {
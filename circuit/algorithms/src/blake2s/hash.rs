@@ -0,0 +1,185 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Hash for Blake2s<E> {
+    type Input = Boolean<E>;
+    type Output = Vec<Boolean<E>>;
+
+    /// Returns the BLAKE2s hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // Resize the input to a multiple of 8, and retrieve the length of the message in bytes.
+        let mut padded_input = input.to_vec();
+        padded_input.resize((input.len() + 7) / 8 * 8, Boolean::constant(false));
+        let num_bytes = (padded_input.len() / 8) as u64;
+
+        // Pad the input with zeros to a positive multiple of the block size.
+        let num_blocks = core::cmp::max(1, (padded_input.len() + BLOCK_SIZE - 1) / BLOCK_SIZE);
+        padded_input.resize(num_blocks * BLOCK_SIZE, Boolean::constant(false));
+
+        // The chaining state `h` is initialized to `IV ⊕ 0x0101kknn`,
+        // where `kk` is the key length and `nn` is the digest length.
+        let mut h = self.iv.clone();
+        h[0] = &h[0] ^ U32::constant(console::U32::new(0x01010000 | DIGEST_SIZE_IN_BYTES));
+
+        // Absorb each block into the chaining state.
+        for (i, block) in padded_input.chunks(BLOCK_SIZE).enumerate() {
+            // Determine if this is the final block.
+            let is_final = i + 1 == num_blocks;
+            // The offset counter `t` is the number of bytes hashed so far, including this block.
+            let t = match is_final {
+                true => num_bytes,
+                false => (i as u64 + 1) * (BLOCK_SIZE as u64 / 8),
+            };
+            h = self.compress(&h, block, t, is_final);
+        }
+
+        // Return the chaining state as little-endian bits.
+        h.iter().flat_map(|word| word.to_bits_le()).collect()
+    }
+}
+
+impl<E: Environment> Blake2s<E> {
+    /// The compression function `F` absorbs the given 512-bit block into the chaining state.
+    fn compress(&self, h: &[U32<E>], block: &[Boolean<E>], t: u64, is_final: bool) -> Vec<U32<E>> {
+        debug_assert_eq!(h.len(), 8, "The chaining state must have 8 words");
+        debug_assert_eq!(block.len(), BLOCK_SIZE, "The block must have {BLOCK_SIZE} bits");
+
+        // Parse the block into 16 message words.
+        let m = block.chunks(32).map(U32::from_bits_le).collect::<Vec<_>>();
+
+        /* Initialize the working vector:
+         *
+         * v[0..7] = h[0..7]
+         * v[8..15] = IV[0..7]
+         * v[12] = v[12] ⊕ (t mod 2^32)
+         * v[13] = v[13] ⊕ (t >> 32)
+         * if the block is final, v[14] = v[14] ⊕ 0xFFFFFFFF
+         */
+        let mut v = h.iter().chain(self.iv.iter()).cloned().collect::<Vec<_>>();
+        v[12] = &v[12] ^ U32::constant(console::U32::new(t as u32));
+        v[13] = &v[13] ^ U32::constant(console::U32::new((t >> 32) as u32));
+        if is_final {
+            v[14] = !&v[14];
+        }
+
+        // Apply the rounds of the mixing function over the columns and diagonals of the working vector.
+        for s in Self::SIGMA.iter() {
+            Self::mix(&mut v, 0, 4, 8, 12, &m[s[0]], &m[s[1]]);
+            Self::mix(&mut v, 1, 5, 9, 13, &m[s[2]], &m[s[3]]);
+            Self::mix(&mut v, 2, 6, 10, 14, &m[s[4]], &m[s[5]]);
+            Self::mix(&mut v, 3, 7, 11, 15, &m[s[6]], &m[s[7]]);
+            Self::mix(&mut v, 0, 5, 10, 15, &m[s[8]], &m[s[9]]);
+            Self::mix(&mut v, 1, 6, 11, 12, &m[s[10]], &m[s[11]]);
+            Self::mix(&mut v, 2, 7, 8, 13, &m[s[12]], &m[s[13]]);
+            Self::mix(&mut v, 3, 4, 9, 14, &m[s[14]], &m[s[15]]);
+        }
+
+        // Compute the next chaining state as `h[i] ⊕ v[i] ⊕ v[i + 8]`.
+        h.iter().enumerate().map(|(i, word)| word ^ &v[i] ^ &v[i + 8]).collect()
+    }
+
+    /// The mixing function `G` mixes the two message words `x` and `y` into the working vector.
+    #[allow(clippy::too_many_arguments)]
+    fn mix(v: &mut [U32<E>], a: usize, b: usize, c: usize, d: usize, x: &U32<E>, y: &U32<E>) {
+        v[a] = v[a].add_wrapped(&v[b]).add_wrapped(x);
+        v[d] = Self::rotate_right(&(&v[d] ^ &v[a]), 16);
+        v[c] = v[c].add_wrapped(&v[d]);
+        v[b] = Self::rotate_right(&(&v[b] ^ &v[c]), 12);
+        v[a] = v[a].add_wrapped(&v[b]).add_wrapped(y);
+        v[d] = Self::rotate_right(&(&v[d] ^ &v[a]), 8);
+        v[c] = v[c].add_wrapped(&v[d]);
+        v[b] = Self::rotate_right(&(&v[b] ^ &v[c]), 7);
+    }
+
+    /// Performs a rotate right operation on the given `u32` value.
+    fn rotate_right(value: &U32<E>, n: usize) -> U32<E> {
+        // Perform the rotation.
+        let mut bits_le = value.to_bits_le();
+        bits_le.rotate_left(n);
+        // Return the rotated value.
+        U32::from_bits_le(&bits_le)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Rng;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    #[test]
+    fn test_blake2s_equivalence() {
+        use console::Hash as H;
+
+        let rng = &mut TestRng::default();
+
+        let native = console::Blake2s;
+        let blake2s = Blake2s::<Circuit>::new();
+
+        let mut input_sizes = vec![0, 1, 2, 7, 8, 9, 16, 32, 64, 256, 511, 512, 513, 1024, 1025];
+        input_sizes.extend((0..5).map(|_| rng.gen_range(1..1024)));
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_inputs in input_sizes.iter().copied() {
+                println!("Checking equivalence for {num_inputs} inputs in {mode} mode");
+
+                // Prepare the preimage.
+                let native_input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+                let input = native_input.iter().map(|v| Boolean::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+                // Compute the console hash.
+                let expected = native.hash(&native_input).expect("Failed to hash console input");
+
+                // Compute the circuit hash.
+                let candidate = blake2s.hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied());
+                // Ensure a constant preimage yields a constant digest.
+                if mode == Mode::Constant {
+                    assert!(candidate.iter().all(|bit| bit.is_constant()));
+                }
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_blake2s_vectors() {
+        let blake2s = Blake2s::<Circuit>::new();
+
+        // The test vectors from RFC 7693, Appendix B, and the empty input.
+        let vectors = [
+            ("", "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9"),
+            ("abc", "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982"),
+        ];
+        for (input, output) in vectors {
+            let input = input
+                .bytes()
+                .flat_map(|byte| (0..8).map(move |i| Boolean::<Circuit>::new(Mode::Private, (byte >> i) & 1 == 1)))
+                .collect::<Vec<_>>();
+            let candidate = blake2s.hash(&input).eject_value();
+            let candidate = candidate
+                .chunks(8)
+                .map(|bits| bits.iter().rev().fold(0u8, |acc, bit| (acc << 1) | *bit as u8))
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>();
+            assert_eq!(output, candidate);
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
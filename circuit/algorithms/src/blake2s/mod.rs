@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+
+#[cfg(test)]
+use snarkvm_utilities::{TestRng, Uniform};
+
+use crate::Hash;
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, U32};
+
+/// The number of rounds in the compression function.
+const NUM_ROUNDS: usize = 10;
+/// The size of a message block in bits.
+const BLOCK_SIZE: usize = 512;
+/// The size of the digest in bytes.
+const DIGEST_SIZE_IN_BYTES: u32 = 32;
+
+/// The BLAKE2s hash function, as specified in RFC 7693, with a 256-bit digest and no key.
+///
+/// The input is zero-padded to a multiple of the block size of 512 bits, where an empty input
+/// is padded to a single block. Each block is absorbed into the 256-bit chaining state `h` with
+/// the compression function `F`, which mixes the block into the working vector `v` over 10 rounds
+/// of the mixing function `G`, and takes the number of bytes hashed so far as the offset counter `t`.
+///
+/// All words are 32-bit little-endian integers, matching the byte encoding of the `ToBits` implementations.
+#[derive(Clone, Debug)]
+pub struct Blake2s<E: Environment> {
+    /// The initialization vector `IV`.
+    iv: Vec<U32<E>>,
+}
+
+impl<E: Environment> Blake2s<E> {
+    /// Initializes a new BLAKE2s hash function.
+    pub fn new() -> Self {
+        Self { iv: Self::IV.into_iter().map(|e| U32::constant(console::U32::new(e))).collect() }
+    }
+}
+
+impl<E: Environment> Default for Blake2s<E> {
+    /// Initializes a new BLAKE2s hash function.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Environment> Blake2s<E> {
+    /// The initialization vector `IV` is the same as the initial hash value of SHA-256.
+    const IV: [u32; 8] =
+        [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+    /// The message word permutations `SIGMA` for each round.
+    const SIGMA: [[usize; 16]; NUM_ROUNDS] = [
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+        [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+        [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+        [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+        [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+        [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+        [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+        [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+        [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+        [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+    ];
+}
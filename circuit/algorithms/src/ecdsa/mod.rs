@@ -0,0 +1,213 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod verify;
+
+#[cfg(all(test, console))]
+use snarkvm_circuit_types::environment::assert_scope;
+
+use crate::helpers::{constant, inverse};
+use snarkvm_circuit_types::{
+    environment::prelude::*,
+    nonnative::{NonNativeParameters, Secp256k1BaseParameters, Secp256k1ScalarParameters},
+    Boolean,
+    Field,
+    NonNativeField,
+    U8,
+};
+
+use core::marker::PhantomData;
+use num_bigint::{BigInt, BigUint};
+
+/// An element of the secp256k1 base field.
+type Base<E> = NonNativeField<E, Secp256k1BaseParameters>;
+/// An element of the secp256k1 scalar field.
+type Scalar<E> = NonNativeField<E, Secp256k1ScalarParameters>;
+/// An affine point on secp256k1, with non-native coordinates.
+type Point<E> = crate::helpers::Point<E, Secp256k1BaseParameters>;
+
+/// The secp256k1 base field modulus, `p = 2^256 - 2^32 - 977`.
+const BASE_MODULUS: &str = "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F";
+/// The secp256k1 scalar field modulus, i.e. the order `n` of the generator.
+const SCALAR_MODULUS: &str = "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141";
+/// The `x`-coordinate of the secp256k1 generator.
+const GENERATOR_X: &str = "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798";
+/// The `y`-coordinate of the secp256k1 generator.
+const GENERATOR_Y: &str = "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8";
+/// A primitive cube root of unity `β` in the base field.
+const BETA: &str = "7AE96A2B657C07106E64479EAC3434E99CF0497512F58995C1396C28719501EE";
+/// A primitive cube root of unity `λ` in the scalar field, such that `λ * (x, y) = (β * x, y)`.
+const LAMBDA: &str = "5363AD4CC05C30E0A5261C028812645A122E22EA20816678DF02967C1B23BD72";
+/// The short basis `(a1, -b1)`, `(a2, a1)` of the lattice of `(k1, k2)` with `k1 + k2 * λ = 0 (mod n)`.
+const GLV_A1: &str = "3086D221A7D46BCDE86C90E49284EB15";
+const GLV_MINUS_B1: &str = "E4437ED6010E88286F547FA90ABFE4C3";
+const GLV_A2: &str = "114CA50F7A8E2F3F657C1108D9D44CFD8";
+
+/// The number of bits in each half of a decomposed scalar, after the GLV decomposition.
+const GLV_BITS: usize = 129;
+/// The number of bits in each window of the fixed-base scalar multiplication.
+const WINDOW_BITS: usize = 4;
+
+/// ECDSA signature verification over the secp256k1 curve `y^2 = x^3 + 7`.
+///
+/// All values are encoded as big-endian bytes, matching `console::ECDSA`:
+///  - a public key is the `x` and `y` coordinates of its point,
+///  - a signature is the `r` and `s` scalars,
+///  - a message is its 32-byte digest.
+///
+/// The curve arithmetic is emulated with `NonNativeField` arithmetic over the secp256k1 base and scalar fields.
+/// The variable-base scalar multiplication uses the GLV endomorphism to halve the number of doublings,
+/// and the fixed-base scalar multiplication uses precomputed windows of multiples of the generator.
+#[derive(Clone, Debug, Default)]
+pub struct ECDSA<E: Environment>(PhantomData<E>);
+
+/// The secp256k1 curve parameters, and the precomputed constants for verification.
+struct Secp256k1 {
+    /// The base field modulus.
+    p: BigUint,
+    /// The scalar field modulus.
+    n: BigUint,
+    /// The generator.
+    g: (BigUint, BigUint),
+    /// The cube root of unity in the base field.
+    beta: BigUint,
+    /// The cube root of unity in the scalar field.
+    lambda: BigUint,
+}
+
+impl Secp256k1 {
+    /// Initializes the curve parameters.
+    fn new() -> Self {
+        Self {
+            p: parse(BASE_MODULUS),
+            n: parse(SCALAR_MODULUS),
+            g: (parse(GENERATOR_X), parse(GENERATOR_Y)),
+            beta: parse(BETA),
+            lambda: parse(LAMBDA),
+        }
+    }
+
+    /// Returns the offset point, which is the point with the smallest positive `x`-coordinate.
+    /// Its discrete logarithm is unknown, which ensures the incomplete additions do not encounter
+    /// exceptional cases, as this would require a known relation between the offset point and the inputs.
+    fn offset(&self) -> (BigUint, BigUint) {
+        let mut x = BigUint::one();
+        loop {
+            let rhs = (&x * &x * &x + 7u32) % &self.p;
+            // As `p = 3 (mod 4)`, the candidate square root is `rhs^((p + 1) / 4)`.
+            let y = rhs.modpow(&((&self.p + 1u32) >> 2u32), &self.p);
+            if (&y * &y) % &self.p == rhs {
+                return (x, y);
+            }
+            x += 1u32;
+        }
+    }
+
+    /// Returns the sum of the given points, where `None` is the point at infinity.
+    fn add(&self, a: &Option<(BigUint, BigUint)>, b: &Option<(BigUint, BigUint)>) -> Option<(BigUint, BigUint)> {
+        let p = &self.p;
+        let ((x1, y1), (x2, y2)) = match (a, b) {
+            (None, _) => return b.clone(),
+            (_, None) => return a.clone(),
+            (Some(a), Some(b)) => (a, b),
+        };
+        let lambda = match x1 == x2 {
+            true if (y1 + y2) % p == BigUint::zero() => return None,
+            true => (BigUint::from(3u32) * x1 * x1) % p * inverse(&((y1 << 1u32) % p), p) % p,
+            false => (y2 + p - y1) % p * inverse(&((x2 + p - x1) % p), p) % p,
+        };
+        let x3 = (&lambda * &lambda + (p << 1u32) - x1 - x2) % p;
+        let y3 = (&lambda * ((x1 + p - &x3) % p) + p - y1) % p;
+        Some((x3, y3))
+    }
+
+    /// Returns the product of the given point and scalar.
+    fn mul(&self, point: &Option<(BigUint, BigUint)>, scalar: &BigUint) -> Option<(BigUint, BigUint)> {
+        let mut output = None;
+        for i in (0..scalar.bits()).rev() {
+            output = self.add(&output, &output);
+            if scalar.bit(i) {
+                output = self.add(&output, point);
+            }
+        }
+        output
+    }
+
+    /// Returns the negation of the given point.
+    fn neg(&self, (x, y): &(BigUint, BigUint)) -> (BigUint, BigUint) {
+        (x.clone(), (&self.p - y) % &self.p)
+    }
+
+    /// Returns the GLV decomposition `(k1, k2)` of the given scalar, such that `k = k1 + k2 * λ (mod n)`,
+    /// where `|k1|` and `|k2|` are less than `2^128`.
+    fn decompose(&self, k: &BigUint) -> (BigInt, BigInt) {
+        let n = BigInt::from(self.n.clone());
+        let k = BigInt::from(k.clone());
+        let (a1, minus_b1, a2) =
+            (BigInt::from(parse(GLV_A1)), BigInt::from(parse(GLV_MINUS_B1)), BigInt::from(parse(GLV_A2)));
+        // Compute `c1 = round(b2 * k / n)` and `c2 = round(-b1 * k / n)`, where `b2 = a1`.
+        let half_n = &n >> 1u32;
+        let c1 = (&a1 * &k + &half_n) / &n;
+        let c2 = (&minus_b1 * &k + &half_n) / &n;
+        // Compute `k1 = k - c1 * a1 - c2 * a2` and `k2 = -c1 * b1 - c2 * b2`.
+        let k1 = &k - &c1 * &a1 - &c2 * &a2;
+        let k2 = &c1 * &minus_b1 - &c2 * &a1;
+        (k1, k2)
+    }
+}
+
+/// Parses the given hexadecimal constant.
+fn parse(hex: &str) -> BigUint {
+    BigUint::parse_bytes(hex.as_bytes(), 16).expect("Invalid secp256k1 constant")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constants() {
+        let curve = Secp256k1::new();
+        let (x, y) = curve.g.clone();
+        // Ensure the generator is on the curve.
+        assert_eq!((&y * &y) % &curve.p, (&x * &x * &x + 7u32) % &curve.p);
+        // Ensure the generator has order `n`.
+        assert_eq!(curve.mul(&Some(curve.g.clone()), &curve.n), None);
+        // Ensure `λ * G = (β * G.x, G.y)`.
+        assert_eq!(curve.mul(&Some(curve.g.clone()), &curve.lambda), Some(((&curve.beta * &x) % &curve.p, y)));
+        // Ensure the offset point is on the curve.
+        let (x, y) = curve.offset();
+        assert_eq!((&y * &y) % &curve.p, (&x * &x * &x + 7u32) % &curve.p);
+    }
+
+    #[test]
+    fn test_decompose() {
+        let curve = Secp256k1::new();
+        let n = BigInt::from(curve.n.clone());
+        let lambda = BigInt::from(curve.lambda.clone());
+
+        let mut rng = snarkvm_utilities::TestRng::default();
+        for _ in 0..100 {
+            let bytes: [u8; 32] = snarkvm_utilities::Uniform::rand(&mut rng);
+            let k = BigUint::from_bytes_be(&bytes) % &curve.n;
+            let (k1, k2) = curve.decompose(&k);
+            // Ensure `k = k1 + k2 * λ (mod n)`.
+            let sum = (k1.clone() + k2.clone() * &lambda - BigInt::from(k)) % &n;
+            assert_eq!(sum, BigInt::zero());
+            // Ensure the halves are less than `2^128`.
+            assert!(k1.magnitude().bits() <= 128);
+            assert!(k2.magnitude().bits() <= 128);
+        }
+    }
+}
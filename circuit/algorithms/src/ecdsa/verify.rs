@@ -0,0 +1,264 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> ECDSA<E> {
+    /// Returns `true` if the signature is valid for the given public key and message digest.
+    ///
+    /// As in `console::ECDSA::verify`, the signature is rejected if `r` or `s` is not a nonzero scalar,
+    /// or if the public key is not on the curve. If `u1 * G + u2 * Q` is the point at infinity,
+    /// which requires knowledge of the secret key, the circuit is unsatisfiable instead.
+    pub fn verify(public_key: &[U8<E>], digest: &[U8<E>], signature: &[U8<E>]) -> Boolean<E> {
+        // Ensure the inputs are of the correct size.
+        if public_key.len() != 64 || digest.len() != 32 || signature.len() != 64 {
+            E::halt("Invalid ECDSA input size: expected a 64-byte public key, 32-byte digest, and 64-byte signature")
+        }
+
+        let curve = Secp256k1::new();
+
+        // Parse the inputs, and check that the public key and signature are canonically encoded.
+        let (x, is_x_canonical) = Self::from_bytes_be::<Secp256k1BaseParameters>(&public_key[..32]);
+        let (y, is_y_canonical) = Self::from_bytes_be::<Secp256k1BaseParameters>(&public_key[32..]);
+        let (r, is_r_canonical) = Self::from_bytes_be::<Secp256k1ScalarParameters>(&signature[..32]);
+        let (s, is_s_canonical) = Self::from_bytes_be::<Secp256k1ScalarParameters>(&signature[32..]);
+        // The digest is reduced modulo `n`.
+        let (z, _) = Self::from_bytes_be::<Secp256k1ScalarParameters>(digest);
+
+        // Check that the public key is on the curve.
+        let seven = constant(&BigUint::from(7u32));
+        let is_on_curve = is_x_canonical & is_y_canonical & y.square().is_equal(&(x.square() * &x + seven));
+        // Check that `r` and `s` are nonzero scalars.
+        let is_r_valid = is_r_canonical & !r.is_equal(&Scalar::zero());
+        let is_s_valid = is_s_canonical & !s.is_equal(&Scalar::zero());
+        let is_valid = is_on_curve & is_r_valid & is_s_valid;
+
+        // If the inputs are invalid, substitute them with valid inputs, so the computation below is well-defined.
+        let q = Point::ternary(&is_valid, &Point { x, y }, &Point::constant(&curve.g));
+        let r = Scalar::ternary(&is_valid, &r, &Scalar::one());
+        let s = Scalar::ternary(&is_valid, &s, &Scalar::one());
+        let z = Scalar::ternary(&is_valid, &z, &Scalar::one());
+
+        // Compute `w = 1 / s`, `u1 = z * w`, and `u2 = r * w` (mod n).
+        let w = s.inverse();
+        let u1 = &z * &w;
+        let u2 = &r * &w;
+
+        // Compute `2^129 * H + u2 * Q + u1 * G + C * G`, where `H` is the offset point, and `C * G` is the offset of the windows.
+        let output = Self::mul_variable_base(&curve, &q, &u2);
+        let output = Self::add_fixed_base(&curve, &output, &u1);
+
+        // Subtract the offsets, to compute `R = u1 * G + u2 * Q`.
+        let num_windows = (256 + WINDOW_BITS - 1) / WINDOW_BITS;
+        let window_offset = ((BigUint::one() << (WINDOW_BITS * num_windows)) - 1u32) / ((1u32 << WINDOW_BITS) - 1);
+        let offset = curve.add(
+            &curve.mul(&Some(curve.offset()), &(BigUint::one() << GLV_BITS)),
+            &curve.mul(&Some(curve.g.clone()), &(window_offset % &curve.n)),
+        );
+        let offset = match offset {
+            Some(offset) => Point::constant(&curve.neg(&offset)),
+            None => E::halt("The ECDSA offset must not be the point at infinity"),
+        };
+        let output = output.add_checked(&offset);
+
+        // Check that `R.x = r (mod n)`, by reducing `R.x` modulo `n`.
+        let x_mod_n = Scalar::from_bits_le_mod_order(&output.x.to_bits_le());
+
+        is_valid & x_mod_n.is_equal(&r)
+    }
+
+    /// Returns the element of the given big-endian bytes, reduced modulo the non-native modulus,
+    /// and `true` if the bytes are the canonical encoding of the element.
+    fn from_bytes_be<P: NonNativeParameters>(bytes: &[U8<E>]) -> (NonNativeField<E, P>, Boolean<E>) {
+        let bits_le = bytes.iter().rev().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+        let element = NonNativeField::<E, P>::from_bits_le_mod_order(&bits_le);
+        // The encoding is canonical if each limb of the element is equal to the corresponding limb of the bits.
+        let is_canonical = bits_le
+            .chunks(NonNativeField::<E, P>::LIMB_BITS)
+            .zip_eq(element.limbs())
+            .fold(Boolean::constant(true), |is_canonical, (bits_le, limb)| {
+                is_canonical & Field::from_bits_le(bits_le).is_equal(limb)
+            });
+        (element, is_canonical)
+    }
+
+    /// Returns `2^129 * H + k * Q`, where `H` is the offset point.
+    ///
+    /// The scalar is decomposed as `k = k1 + k2 * λ (mod n)`, where `λ * Q = φ(Q) = (β * Q.x, Q.y)`.
+    /// Each half is made odd, and written with signed digits `d_i ∈ {-1, 1}`, such that each step of the
+    /// double-and-add adds one of `±(Q + φ(Q))` or `±(Q - φ(Q))`.
+    fn mul_variable_base(curve: &Secp256k1, q: &Point<E>, k: &Scalar<E>) -> Point<E> {
+        let mode = match k.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+
+        // Decompose the scalar, and make each half odd.
+        let (k1, k2) = curve.decompose(k.eject_value().value());
+        let is_k1_even = Boolean::new(mode, !k1.magnitude().bit(0));
+        let is_k2_even = Boolean::new(mode, !k2.magnitude().bit(0));
+        let k1 = k1 + u32::from(is_k1_even.eject_value());
+        let k2 = k2 + u32::from(is_k2_even.eject_value());
+
+        // Write each odd half `k_j` as the signed digits `2 * b_i - 1`, where `b = (k_j + 2^129 - 1) / 2`.
+        let max: BigInt = (BigInt::one() << GLV_BITS) - 1u32;
+        let to_bits = |k: BigInt| {
+            let b = ((k + &max) >> 1u32).to_biguint().unwrap_or_default();
+            (0..GLV_BITS).map(|i| Boolean::new(mode, b.bit(i as u64))).collect::<Vec<_>>()
+        };
+        let bits_1 = to_bits(k1);
+        let bits_2 = to_bits(k2);
+
+        // Ensure `(2 * b1 - (2^129 - 1) - e1) + (2 * b2 - (2^129 - 1) - e2) * λ = k (mod n)`.
+        let lambda = constant(&curve.lambda);
+        let offset = constant(&(max.magnitude() * (&curve.lambda + 1u32)));
+        let (b_1, b_2) = (Scalar::from_bits_le_mod_order(&bits_1), Scalar::from_bits_le_mod_order(&bits_2));
+        let (e_1, e_2) = (
+            Scalar::from_bits_le_mod_order(&[is_k1_even.clone()]),
+            Scalar::from_bits_le_mod_order(&[is_k2_even.clone()]),
+        );
+        Scalar::assert_sum_of_products_is_zero(
+            &[(2, &b_2, &lambda), (-1, &e_2, &lambda)],
+            &[(2, &b_1), (-1, &e_1), (-1, &offset), (-1, k)],
+        );
+
+        // Compute `φ(Q) = (β * Q.x, Q.y)`.
+        let q_1 = q.clone();
+        let q_2 = Point { x: &q.x * &constant(&curve.beta), y: q.y.clone() };
+        let neg_q_1 = q_1.neg();
+        let neg_q_2 = q_2.neg();
+
+        // Compute the table of `Q + φ(Q)`, `Q - φ(Q)`, and their negations.
+        let sum = q_1.add(&q_2);
+        let difference = q_1.add(&neg_q_2);
+        let neg_sum = sum.neg();
+        let neg_difference = difference.neg();
+
+        // Compute `2^129 * H + k1 * Q + k2 * φ(Q)`, with the odd halves.
+        let mut output = Point::constant(&curve.offset());
+        for (bit_1, bit_2) in bits_1.iter().zip(&bits_2).rev() {
+            // Select `d1 * Q + d2 * φ(Q)`, which is `±(Q + φ(Q))` if the digits are equal, and `±(Q - φ(Q))` otherwise.
+            let is_same = !(bit_1 ^ bit_2);
+            let x = Base::ternary(&is_same, &sum.x, &difference.x);
+            let positive_y = Base::ternary(&is_same, &sum.y, &difference.y);
+            let negative_y = Base::ternary(&is_same, &neg_sum.y, &neg_difference.y);
+            let y = Base::ternary(bit_1, &positive_y, &negative_y);
+            // Compute `2 * output + d1 * Q + d2 * φ(Q)`.
+            output = output.double_and_add(&Point { x, y });
+        }
+
+        // Subtract `Q` and `φ(Q)` for each half that was made odd.
+        let output = Point::ternary(&is_k1_even, &output.add(&neg_q_1), &output);
+        Point::ternary(&is_k2_even, &output.add(&neg_q_2), &output)
+    }
+
+    /// Returns `point + k * G + C * G`, where `C = Σ 16^i` is the offset of the windows.
+    ///
+    /// Each 4-bit window `v_i` of the scalar adds the precomputed constant `(v_i + 1) * 16^i * G`,
+    /// so no entry of the table is the point at infinity.
+    fn add_fixed_base(curve: &Secp256k1, point: &Point<E>, k: &Scalar<E>) -> Point<E> {
+        let mut output = point.clone();
+        let mut base = Some(curve.g.clone());
+        for window in k.to_bits_le().chunks(WINDOW_BITS) {
+            // Compute the table of `(v + 1) * 16^i * G`.
+            let mut entry = base.clone();
+            let mut table = Vec::with_capacity(1 << WINDOW_BITS);
+            for _ in 0..(1 << WINDOW_BITS) {
+                match &entry {
+                    Some(point) => table.push(point.clone()),
+                    None => E::halt("The ECDSA window table must not contain the point at infinity"),
+                }
+                entry = curve.add(&entry, &base);
+            }
+            // Add the selected entry of the table.
+            output = output.add(&Point::lookup(window, &table));
+            // Compute the base of the next window.
+            base = curve.mul(&base, &(BigUint::one() << WINDOW_BITS));
+        }
+        output
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    /// Injects the given bytes in the given mode.
+    fn inject(mode: Mode, bytes: &[u8]) -> Vec<U8<Circuit>> {
+        bytes.iter().map(|byte| U8::new(mode, console::U8::new(*byte))).collect()
+    }
+
+    fn check_verify(
+        mode: Mode,
+        public_key: &[u8; 64],
+        digest: &[u8; 32],
+        signature: &[u8; 64],
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        // Compute the expected result.
+        let expected = console::ECDSA::verify(public_key, digest, signature);
+
+        Circuit::scope(format!("ECDSA {mode}"), || {
+            let candidate = ECDSA::verify(&inject(mode, public_key), &inject(mode, digest), &inject(mode, signature));
+            assert_eq!(expected, candidate.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_verify_constant() {
+        let rng = &mut TestRng::default();
+
+        let secret_key = console::ECDSA::sample_secret_key(rng);
+        let public_key = console::ECDSA::to_public_key(&secret_key).unwrap();
+        let digest: [u8; 32] = Uniform::rand(rng);
+        let signature = console::ECDSA::sign(&secret_key, &digest, rng).unwrap();
+        check_verify(Mode::Constant, &public_key, &digest, &signature, 23370, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_verify_private() {
+        let rng = &mut TestRng::default();
+
+        let secret_key = console::ECDSA::sample_secret_key(rng);
+        let public_key = console::ECDSA::to_public_key(&secret_key).unwrap();
+        let digest: [u8; 32] = Uniform::rand(rng);
+        let signature = console::ECDSA::sign(&secret_key, &digest, rng).unwrap();
+
+        // Check a valid signature.
+        check_verify(Mode::Private, &public_key, &digest, &signature, 35462, 0, 1132278, 1139904);
+
+        // Check a signature for a different digest.
+        let mut other_digest = digest;
+        other_digest[0] ^= 1;
+        check_verify(Mode::Private, &public_key, &other_digest, &signature, 35462, 0, 1132278, 1139904);
+
+        // Check a public key that is not on the curve.
+        let mut invalid_public_key = public_key;
+        invalid_public_key[63] ^= 1;
+        check_verify(Mode::Private, &invalid_public_key, &digest, &signature, 35462, 0, 1132278, 1139904);
+
+        // Check a signature with a zero `s`.
+        let mut invalid_signature = signature;
+        invalid_signature[32..].copy_from_slice(&[0u8; 32]);
+        check_verify(Mode::Private, &public_key, &digest, &invalid_signature, 35462, 0, 1132278, 1139904);
+    }
+}
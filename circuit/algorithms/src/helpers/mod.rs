@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod point;
+pub(crate) use point::*;
+
+use snarkvm_circuit_types::{environment::prelude::*, nonnative::NonNativeParameters, Boolean, NonNativeField};
+
+use num_bigint::BigUint;
+
+/// Returns the inverse of `a` modulo the prime `modulus`, i.e. `a^(modulus - 2)`.
+pub(crate) fn inverse(a: &BigUint, modulus: &BigUint) -> BigUint {
+    a.modpow(&(modulus - 2u32), modulus)
+}
+
+/// Returns the non-native field element of the given mode and integer, reduced modulo the non-native modulus.
+pub(crate) fn inject<E: Environment, P: NonNativeParameters>(mode: Mode, value: &BigUint) -> NonNativeField<E, P> {
+    NonNativeField::new(mode, <<NonNativeField<E, P> as Inject>::Primitive>::new(value.clone()))
+}
+
+/// Returns the constant non-native field element of the given integer, reduced modulo the non-native modulus.
+pub(crate) fn constant<E: Environment, P: NonNativeParameters>(value: &BigUint) -> NonNativeField<E, P> {
+    inject(Mode::Constant, value)
+}
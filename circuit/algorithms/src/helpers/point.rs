@@ -0,0 +1,173 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An affine point on a short Weierstrass curve, with coordinates in the non-native field of the parameters `P`.
+///
+/// The additions are incomplete, and assume the inputs are not equal or opposite points.
+/// Each output coordinate is a witness, that is constrained to be the unique valid result.
+/// As the addition formulas do not depend on the curve coefficients, the points are not checked to be on the curve.
+#[derive(Clone)]
+pub(crate) struct Point<E: Environment, P: NonNativeParameters> {
+    /// The `x`-coordinate.
+    pub(crate) x: NonNativeField<E, P>,
+    /// The `y`-coordinate.
+    pub(crate) y: NonNativeField<E, P>,
+}
+
+impl<E: Environment, P: NonNativeParameters> Point<E, P> {
+    /// Initializes a constant point.
+    pub(crate) fn constant((x, y): &(BigUint, BigUint)) -> Self {
+        Self { x: constant(x), y: constant(y) }
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub(crate) fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self {
+            x: NonNativeField::ternary(condition, &first.x, &second.x),
+            y: NonNativeField::ternary(condition, &first.y, &second.y),
+        }
+    }
+
+    /// Returns the constant in the table at the index given by the little-endian bits.
+    ///
+    /// The entries are selected with a tree of ternaries, starting from the least significant bit,
+    /// where the first level is free as its branches are constants.
+    pub(crate) fn lookup(bits_le: &[Boolean<E>], table: &[(BigUint, BigUint)]) -> Self {
+        if table.len() != 1 << bits_le.len() {
+            E::halt("The lookup table must have one entry for each index")
+        }
+        let mut entries = table.iter().map(Self::constant).collect::<Vec<_>>();
+        for bit in bits_le {
+            entries = entries.chunks(2).map(|pair| Self::ternary(bit, &pair[1], &pair[0])).collect();
+        }
+        entries.remove(0)
+    }
+
+    /// Returns the witness mode for the given points.
+    fn mode(points: &[&Self]) -> Mode {
+        match points.iter().all(|point| point.x.is_constant() && point.y.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+
+    /// Returns the negation of the point.
+    pub(crate) fn neg(&self) -> Self {
+        Self { x: self.x.clone(), y: -&self.y }
+    }
+
+    /// Returns `self + other`, enforcing that `self.x != other.x`.
+    pub(crate) fn add_checked(&self, other: &Self) -> Self {
+        // Ensure `(other.x - self.x)` is invertible.
+        let difference = other.x.eject_value() - self.x.eject_value();
+        let inverse = NonNativeField::new(Self::mode(&[self, other]), difference.inverse().unwrap_or_default());
+        let one = NonNativeField::one();
+        NonNativeField::assert_sum_of_products_is_zero(
+            &[(1, &inverse, &other.x), (-1, &inverse, &self.x)],
+            &[(-1, &one)],
+        );
+        // Return the sum.
+        self.add(other)
+    }
+
+    /// Returns `self + other`.
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        let mode = Self::mode(&[self, other]);
+        let (x1, y1) = (self.x.eject_value(), self.y.eject_value());
+        let (x2, y2) = (other.x.eject_value(), other.y.eject_value());
+
+        // Compute `λ = (y2 - y1) / (x2 - x1)`.
+        let lambda = (&y2 - &y1) * (&x2 - &x1).inverse().unwrap_or_default();
+        // Compute `x3 = λ^2 - x1 - x2`.
+        let x3 = &lambda * &lambda - &x1 - &x2;
+        // Compute `y3 = λ * (x1 - x3) - y1`.
+        let y3 = &lambda * &(&x1 - &x3) - &y1;
+
+        let lambda = NonNativeField::new(mode, lambda);
+        let x3 = NonNativeField::new(mode, x3);
+        let y3 = NonNativeField::new(mode, y3);
+
+        // Ensure `λ * (x2 - x1) = y2 - y1`.
+        NonNativeField::assert_sum_of_products_is_zero(
+            &[(1, &lambda, &other.x), (-1, &lambda, &self.x)],
+            &[(-1, &other.y), (1, &self.y)],
+        );
+        // Ensure `x3 = λ^2 - x1 - x2`.
+        NonNativeField::assert_sum_of_products_is_zero(
+            &[(1, &lambda, &lambda)],
+            &[(-1, &self.x), (-1, &other.x), (-1, &x3)],
+        );
+        // Ensure `y3 = λ * (x1 - x3) - y1`.
+        NonNativeField::assert_sum_of_products_is_zero(
+            &[(1, &lambda, &self.x), (-1, &lambda, &x3)],
+            &[(-1, &self.y), (-1, &y3)],
+        );
+
+        Self { x: x3, y: y3 }
+    }
+
+    /// Returns `2 * self + other`, computed as `(self + other) + self`.
+    pub(crate) fn double_and_add(&self, other: &Self) -> Self {
+        let mode = Self::mode(&[self, other]);
+        let (x1, y1) = (self.x.eject_value(), self.y.eject_value());
+        let (x2, y2) = (other.x.eject_value(), other.y.eject_value());
+
+        // Compute `λ1 = (y2 - y1) / (x2 - x1)`.
+        let lambda_1 = (&y2 - &y1) * (&x2 - &x1).inverse().unwrap_or_default();
+        // Compute `x3 = λ1^2 - x1 - x2`.
+        let x3 = &lambda_1 * &lambda_1 - &x1 - &x2;
+        // Compute `λ2 = -λ1 - 2 * y1 / (x3 - x1)`.
+        let lambda_2 = -lambda_1.clone() - (&y1 + &y1) * (&x3 - &x1).inverse().unwrap_or_default();
+        // Compute `x4 = λ2^2 - x3 - x1`.
+        let x4 = &lambda_2 * &lambda_2 - &x3 - &x1;
+        // Compute `y4 = λ2 * (x1 - x4) - y1`.
+        let y4 = &lambda_2 * &(&x1 - &x4) - &y1;
+
+        let lambda_1 = NonNativeField::new(mode, lambda_1);
+        let x3 = NonNativeField::new(mode, x3);
+        let lambda_2 = NonNativeField::new(mode, lambda_2);
+        let x4 = NonNativeField::new(mode, x4);
+        let y4 = NonNativeField::new(mode, y4);
+
+        // Ensure `λ1 * (x2 - x1) = y2 - y1`.
+        NonNativeField::assert_sum_of_products_is_zero(
+            &[(1, &lambda_1, &other.x), (-1, &lambda_1, &self.x)],
+            &[(-1, &other.y), (1, &self.y)],
+        );
+        // Ensure `x3 = λ1^2 - x1 - x2`.
+        NonNativeField::assert_sum_of_products_is_zero(
+            &[(1, &lambda_1, &lambda_1)],
+            &[(-1, &self.x), (-1, &other.x), (-1, &x3)],
+        );
+        // Ensure `(λ1 + λ2) * (x3 - x1) = -2 * y1`.
+        NonNativeField::assert_sum_of_products_is_zero(
+            &[(1, &lambda_1, &x3), (-1, &lambda_1, &self.x), (1, &lambda_2, &x3), (-1, &lambda_2, &self.x)],
+            &[(2, &self.y)],
+        );
+        // Ensure `x4 = λ2^2 - x3 - x1`.
+        NonNativeField::assert_sum_of_products_is_zero(
+            &[(1, &lambda_2, &lambda_2)],
+            &[(-1, &x3), (-1, &self.x), (-1, &x4)],
+        );
+        // Ensure `y4 = λ2 * (x1 - x4) - y1`.
+        NonNativeField::assert_sum_of_products_is_zero(
+            &[(1, &lambda_2, &self.x), (-1, &lambda_2, &x4)],
+            &[(-1, &self.y), (-1, &y4)],
+        );
+
+        Self { x: x4, y: y4 }
+    }
+}
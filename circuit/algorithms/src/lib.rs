@@ -18,9 +18,17 @@
 pub mod bhp;
 pub use bhp::*;
 
+pub mod blake2s;
+pub use blake2s::Blake2s;
+
+pub mod ecdsa;
+pub use ecdsa::ECDSA;
+
 pub mod elligator2;
 pub use elligator2::Elligator2;
 
+mod helpers;
+
 pub mod keccak;
 pub use keccak::*;
 
@@ -30,5 +38,14 @@ pub use pedersen::*;
 pub mod poseidon;
 pub use poseidon::*;
 
+pub mod poseidon2;
+pub use poseidon2::*;
+
+pub mod sha256;
+pub use sha256::Sha256;
+
 pub mod traits;
 pub use traits::*;
+
+pub mod varuna;
+pub use varuna::Varuna;
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> Hash for PoseidonV2<E, RATE> {
+    type Input = Field<E>;
+    type Output = Field<E>;
+
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        self.hash_many(input, 1).swap_remove(0)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "Poseidon2Circuit0";
+    const ITERATIONS: usize = 10;
+    const RATE: usize = 4;
+
+    fn check_hash(mode: Mode, num_inputs: usize, rng: &mut TestRng) -> Result<()> {
+        use console::Hash as H;
+
+        let native = console::PoseidonV2::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = PoseidonV2::<Circuit, RATE>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs)
+                .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(rng))
+                .collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = native.hash(&native_input).expect("Failed to hash native input");
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("Poseidon2 {mode} {i}"), || {
+                let candidate = poseidon.hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                // Ensure a constant preimage yields a constant digest.
+                assert_eq!(mode.is_constant(), candidate.is_constant());
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_constant() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 1..=(2 * RATE) {
+            check_hash(Mode::Constant, num_inputs, &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_public() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 1..=(2 * RATE) {
+            check_hash(Mode::Public, num_inputs, &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_private() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 1..=(2 * RATE) {
+            check_hash(Mode::Private, num_inputs, &mut rng)?;
+        }
+        Ok(())
+    }
+}
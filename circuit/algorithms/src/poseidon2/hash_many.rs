@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> HashMany for PoseidonV2<E, RATE> {
+    type Input = Field<E>;
+    type Output = Field<E>;
+
+    #[inline]
+    fn hash_many(&self, input: &[Self::Input], num_outputs: u16) -> Vec<Self::Output> {
+        // Construct the preimage: [ DOMAIN || LENGTH(INPUT) || [0; RATE-2] || INPUT ].
+        let mut preimage = Vec::with_capacity(RATE + input.len());
+        preimage.push(self.domain.clone());
+        preimage.push(Field::constant(console::Field::from_u128(input.len() as u128)));
+        preimage.resize(RATE, Field::zero()); // Pad up to RATE.
+        preimage.extend_from_slice(input);
+
+        // Initialize the state.
+        let mut state = vec![Field::zero(); RATE + CAPACITY];
+
+        // Absorb the preimage, `RATE` elements at a time, permuting the state between each chunk.
+        for (i, chunk) in preimage.chunks(RATE).enumerate() {
+            if i > 0 {
+                self.permute(&mut state);
+            }
+            for (element, state_element) in chunk.iter().zip(&mut state[CAPACITY..]) {
+                *state_element += element;
+            }
+        }
+
+        // Squeeze the output, `RATE` elements at a time, permuting the state before each chunk.
+        let mut output = Vec::with_capacity(num_outputs as usize);
+        while output.len() < num_outputs as usize {
+            self.permute(&mut state);
+            let num_squeezed = core::cmp::min(RATE, num_outputs as usize - output.len());
+            output.extend_from_slice(&state[CAPACITY..(CAPACITY + num_squeezed)]);
+        }
+        output
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "Poseidon2Circuit0";
+    const ITERATIONS: usize = 10;
+    const RATE: u16 = 4;
+
+    fn check_hash_many(mode: Mode, num_inputs: usize, num_outputs: u16, rng: &mut TestRng) -> Result<()> {
+        use console::HashMany as H;
+
+        let native = console::PoseidonV2::<<Circuit as Environment>::Network, { RATE as usize }>::setup(DOMAIN)?;
+        let poseidon = PoseidonV2::<Circuit, { RATE as usize }>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs)
+                .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(rng))
+                .collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = native.hash_many(&native_input, num_outputs);
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("Poseidon2 {mode} {i} {num_outputs}"), || {
+                let candidate = poseidon.hash_many(&input, num_outputs);
+                assert_eq!(expected.len(), candidate.len());
+                for (expected_element, candidate_element) in expected.iter().zip_eq(&candidate) {
+                    assert_eq!(*expected_element, candidate_element.eject_value());
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_many_constant() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..=(2 * RATE) {
+            for num_outputs in 0..=(2 * RATE) {
+                check_hash_many(Mode::Constant, num_inputs as usize, num_outputs, &mut rng)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_many_public() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..=(2 * RATE) {
+            for num_outputs in 0..=(2 * RATE) {
+                check_hash_many(Mode::Public, num_inputs as usize, num_outputs, &mut rng)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_many_private() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..=(2 * RATE) {
+            for num_outputs in 0..=(2 * RATE) {
+                check_hash_many(Mode::Private, num_inputs as usize, num_outputs, &mut rng)?;
+            }
+        }
+        Ok(())
+    }
+}
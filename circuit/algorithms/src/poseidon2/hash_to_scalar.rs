@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> HashToScalar for PoseidonV2<E, RATE> {
+    type Input = Field<E>;
+    type Scalar = Scalar<E>;
+
+    /// Returns a scalar from hashing the input.
+    /// This method uses truncation (up to data bits) to project onto the scalar field.
+    #[inline]
+    fn hash_to_scalar(&self, input: &[Self::Input]) -> Self::Scalar {
+        // Hash the input to the base field.
+        let output = self.hash(input);
+        // Convert the output to the scalar field,
+        // truncating to the size in data bits (1 bit less than the MODULUS) of the scalar.
+        Scalar::from_field_lossy(&output)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "Poseidon2Circuit0";
+    const ITERATIONS: usize = 10;
+    const RATE: usize = 4;
+
+    fn check_hash_to_scalar(mode: Mode, num_inputs: usize, rng: &mut TestRng) -> Result<()> {
+        use console::HashToScalar as H;
+
+        let native = console::PoseidonV2::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = PoseidonV2::<Circuit, RATE>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash to scalar.
+            let expected = native.hash_to_scalar(&native_input)?;
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("Poseidon2 {mode} {i}"), || {
+                let candidate = poseidon.hash_to_scalar(&input);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_to_scalar() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_inputs in 0..=(2 * RATE) {
+                check_hash_to_scalar(mode, num_inputs, &mut rng)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+mod hash_many;
+mod hash_to_group;
+mod hash_to_scalar;
+
+#[cfg(test)]
+use snarkvm_utilities::{TestRng, Uniform};
+
+use crate::{Elligator2, Hash, HashMany, HashToGroup, HashToScalar};
+use snarkvm_circuit_types::{environment::prelude::*, Field, Group, Scalar};
+
+/// Poseidon2_2 is a cryptographic hash function of input rate 2, using the Poseidon2 permutation.
+pub type Poseidon2_2<E> = PoseidonV2<E, 2>;
+/// Poseidon2_4 is a cryptographic hash function of input rate 4, using the Poseidon2 permutation.
+pub type Poseidon2_4<E> = PoseidonV2<E, 4>;
+/// Poseidon2_8 is a cryptographic hash function of input rate 8, using the Poseidon2 permutation.
+pub type Poseidon2_8<E> = PoseidonV2<E, 8>;
+
+const CAPACITY: usize = 1;
+
+#[derive(Clone)]
+pub struct PoseidonV2<E: Environment, const RATE: usize> {
+    /// The domain separator for the Poseidon2 hash function.
+    domain: Field<E>,
+    /// The number of rounds in a full-round operation.
+    full_rounds: usize,
+    /// The number of rounds in a partial-round operation.
+    partial_rounds: usize,
+    /// The exponent used in S-boxes.
+    alpha: Field<E>,
+    /// The additive round keys for the full rounds.
+    /// They are indexed by `external_ark[round_number][state_element_index]`
+    external_ark: Vec<Vec<Field<E>>>,
+    /// The additive round keys for the partial rounds, which are added to the first state element.
+    internal_ark: Vec<Field<E>>,
+    /// The Maximally Distance Separating (MDS) matrix for the full rounds.
+    external_mds: Vec<Vec<Field<E>>>,
+    /// The diagonal `D` of the matrix `M_I = J + D` for the partial rounds.
+    internal_diagonal: Vec<Field<E>>,
+}
+
+#[cfg(console)]
+impl<E: Environment, const RATE: usize> Inject for PoseidonV2<E, RATE> {
+    type Primitive = console::PoseidonV2<E::Network, RATE>;
+
+    fn new(_mode: Mode, poseidon: Self::Primitive) -> Self {
+        // Initialize the domain separator.
+        let domain = Field::constant(poseidon.domain());
+
+        // Initialize the Poseidon2 parameters.
+        let parameters = poseidon.parameters();
+        let full_rounds = parameters.full_rounds;
+        let partial_rounds = parameters.partial_rounds;
+        let alpha = Field::constant(console::Field::from_u128(parameters.alpha as u128));
+        // Cache the bits for the field element.
+        alpha.to_bits_le();
+        let external_ark = parameters
+            .external_ark
+            .iter()
+            .take(full_rounds)
+            .map(|round| {
+                round.iter().take(RATE + 1).copied().map(|field| Field::constant(console::Field::new(field))).collect()
+            })
+            .collect();
+        let internal_ark = parameters
+            .internal_ark
+            .iter()
+            .take(partial_rounds)
+            .copied()
+            .map(|field| Field::constant(console::Field::new(field)))
+            .collect();
+        let external_mds = parameters
+            .external_mds
+            .iter()
+            .take(RATE + 1)
+            .map(|row| {
+                row.iter().take(RATE + 1).copied().map(|field| Field::constant(console::Field::new(field))).collect()
+            })
+            .collect();
+        let internal_diagonal = parameters
+            .internal_diagonal
+            .iter()
+            .take(RATE + 1)
+            .copied()
+            .map(|field| Field::constant(console::Field::new(field)))
+            .collect();
+
+        Self { domain, full_rounds, partial_rounds, alpha, external_ark, internal_ark, external_mds, internal_diagonal }
+    }
+}
+
+impl<E: Environment, const RATE: usize> PoseidonV2<E, RATE> {
+    /// Apply the Poseidon2 permutation in-place.
+    #[inline]
+    fn permute(&self, state: &mut [Field<E>]) {
+        // Determine the number of full rounds on either side of the partial rounds.
+        let full_rounds_over_2 = self.full_rounds / 2;
+
+        // Apply the external matrix to the initial state.
+        self.apply_external_mds(state);
+        // Apply the first half of the full rounds.
+        for round in 0..full_rounds_over_2 {
+            self.apply_full_round(state, round);
+        }
+        // Apply the partial rounds.
+        for round in 0..self.partial_rounds {
+            self.apply_partial_round(state, round);
+        }
+        // Apply the second half of the full rounds.
+        for round in full_rounds_over_2..self.full_rounds {
+            self.apply_full_round(state, round);
+        }
+    }
+
+    /// Apply a full round, which applies the round keys and the S-box to every state element,
+    /// followed by the external matrix.
+    #[inline]
+    fn apply_full_round(&self, state: &mut [Field<E>], round: usize) {
+        for (element, ark) in state.iter_mut().zip_eq(&self.external_ark[round]) {
+            *element += ark;
+            *element = (&*element).pow(&self.alpha);
+        }
+        self.apply_external_mds(state);
+    }
+
+    /// Apply a partial round, which applies the round key and the S-box to the first state element,
+    /// followed by the internal matrix.
+    #[inline]
+    fn apply_partial_round(&self, state: &mut [Field<E>], round: usize) {
+        state[0] += &self.internal_ark[round];
+        state[0] = (&state[0]).pow(&self.alpha);
+        self.apply_internal_mds(state);
+    }
+
+    /// Apply the external matrix `M_E` in-place.
+    #[inline]
+    fn apply_external_mds(&self, state: &mut [Field<E>]) {
+        let mut new_state = Vec::with_capacity(state.len());
+        for mds_row in self.external_mds.iter() {
+            let mut accumulator = Field::zero();
+            for (element, entry) in state.iter().zip_eq(mds_row) {
+                accumulator += element * entry;
+            }
+            new_state.push(accumulator);
+        }
+        state.clone_from_slice(&new_state);
+    }
+
+    /// Apply the internal matrix `M_I = J + D` in-place, which maps `state[i]` to `sum(state) + D[i] * state[i]`.
+    #[inline]
+    fn apply_internal_mds(&self, state: &mut [Field<E>]) {
+        let mut sum = Field::zero();
+        for element in state.iter() {
+            sum += element;
+        }
+        for (element, diagonal) in state.iter_mut().zip_eq(&self.internal_diagonal) {
+            *element = &sum + &*element * diagonal;
+        }
+    }
+}
@@ -0,0 +1,229 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Hash for Sha256<E> {
+    type Input = Boolean<E>;
+    type Output = Vec<Boolean<E>>;
+
+    /// Returns the SHA-256 hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // The chaining state `H` is initialized to `H(0)`.
+        let mut state = self.initial_state.clone();
+        // Absorb each padded block into the chaining state.
+        for block in Self::pad(input).chunks(BLOCK_SIZE) {
+            state = self.compress(&state, block);
+        }
+        // Return the chaining state as the little-endian bits of its big-endian bytes.
+        state.iter().flat_map(Self::word_to_bits).collect()
+    }
+}
+
+impl<E: Environment> Sha256<E> {
+    /// In SHA-256, `pad` is defined as `pad(M) = M || 0x80 || 0x00…0x00 || len(M)`,
+    /// where `M` is the input data, and `len(M)` is the length of `M` in bits as a 64-bit big-endian integer.
+    /// The padding extends the input data to a multiple of the block size of 512 bits.
+    fn pad(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Resize the input to a multiple of 8.
+        let mut padded_input = input.to_vec();
+        padded_input.resize((input.len() + 7) / 8 * 8, Boolean::constant(false));
+        // Retrieve the length of the message in bits.
+        let length = padded_input.len() as u64;
+
+        // Step 1: Append the "0x80" byte to the message.
+        padded_input.extend((0..8).map(|i| Boolean::constant(i == 7)));
+
+        // Step 2: Append "0" bits until the length of the message is congruent to 448 mod 512.
+        while padded_input.len() % BLOCK_SIZE != BLOCK_SIZE - 64 {
+            padded_input.push(Boolean::constant(false));
+        }
+
+        // Step 3: Append the length of the message as a 64-bit big-endian integer.
+        for byte in length.to_be_bytes() {
+            padded_input.extend((0..8).map(|i| Boolean::constant((byte >> i) & 1 == 1)));
+        }
+        padded_input
+    }
+
+    /// The compression function absorbs the given 512-bit block into the chaining state.
+    fn compress(&self, state: &[U32<E>], block: &[Boolean<E>]) -> Vec<U32<E>> {
+        debug_assert_eq!(state.len(), 8, "The chaining state must have 8 words");
+        debug_assert_eq!(block.len(), BLOCK_SIZE, "The block must have {BLOCK_SIZE} bits");
+
+        /* The message schedule `W`:
+         *
+         * for t = 0 to 15 do
+         *   W[t] = M[t]
+         * end for
+         * for t = 16 to 63 do
+         *   W[t] = σ1(W[t-2]) + W[t-7] + σ0(W[t-15]) + W[t-16]
+         * end for
+         */
+        let mut w = block.chunks(32).map(Self::word_from_bits).collect::<Vec<_>>();
+        for t in 16..NUM_ROUNDS {
+            let sigma_0 = Self::rotate_right(&w[t - 15], 7)
+                ^ Self::rotate_right(&w[t - 15], 18)
+                ^ Self::shift_right(&w[t - 15], 3);
+            let sigma_1 = Self::rotate_right(&w[t - 2], 17)
+                ^ Self::rotate_right(&w[t - 2], 19)
+                ^ Self::shift_right(&w[t - 2], 10);
+            w.push(sigma_1.add_wrapped(&w[t - 7]).add_wrapped(&sigma_0).add_wrapped(&w[t - 16]));
+        }
+
+        // Initialize the working variables with the chaining state.
+        let (mut a, mut b, mut c, mut d) = (state[0].clone(), state[1].clone(), state[2].clone(), state[3].clone());
+        let (mut e, mut f, mut g, mut h) = (state[4].clone(), state[5].clone(), state[6].clone(), state[7].clone());
+
+        /* The rounds:
+         *
+         * for t = 0 to 63 do
+         *   T1 = h + Σ1(e) + Ch(e, f, g) + K[t] + W[t]
+         *   T2 = Σ0(a) + Maj(a, b, c)
+         *   (h, g, f, e, d, c, b, a) = (g, f, e, d + T1, c, b, a, T1 + T2)
+         * end for
+         */
+        for (round_constant, w_t) in self.round_constants.iter().zip(w.iter()) {
+            // Σ1(e) = ROTR(e, 6) ⊕ ROTR(e, 11) ⊕ ROTR(e, 25)
+            let big_sigma_1 = Self::rotate_right(&e, 6) ^ Self::rotate_right(&e, 11) ^ Self::rotate_right(&e, 25);
+            // Ch(e, f, g) = (e ∧ f) ⊕ (¬e ∧ g)
+            let choice = (&e & &f) ^ ((!&e) & &g);
+            // T1 = h + Σ1(e) + Ch(e, f, g) + K[t] + W[t]
+            let t_1 = h.add_wrapped(&big_sigma_1).add_wrapped(&choice).add_wrapped(round_constant).add_wrapped(w_t);
+
+            // Σ0(a) = ROTR(a, 2) ⊕ ROTR(a, 13) ⊕ ROTR(a, 22)
+            let big_sigma_0 = Self::rotate_right(&a, 2) ^ Self::rotate_right(&a, 13) ^ Self::rotate_right(&a, 22);
+            // Maj(a, b, c) = (a ∧ b) ⊕ (a ∧ c) ⊕ (b ∧ c)
+            let majority = (&a & &b) ^ (&a & &c) ^ (&b & &c);
+            // T2 = Σ0(a) + Maj(a, b, c)
+            let t_2 = big_sigma_0.add_wrapped(&majority);
+
+            // Update the working variables.
+            h = g;
+            g = f;
+            f = e;
+            e = d.add_wrapped(&t_1);
+            d = c;
+            c = b;
+            b = a;
+            a = t_1.add_wrapped(&t_2);
+        }
+
+        // Compute the next chaining state.
+        [a, b, c, d, e, f, g, h].iter().zip(state).map(|(variable, word)| word.add_wrapped(variable)).collect()
+    }
+
+    /// Returns the word from the given 32 bits, which are the little-endian bits of its big-endian bytes.
+    fn word_from_bits(bits: &[Boolean<E>]) -> U32<E> {
+        U32::from_bits_le(&bits.chunks(8).rev().flatten().cloned().collect::<Vec<_>>())
+    }
+
+    /// Returns the little-endian bits of the big-endian bytes of the given word.
+    fn word_to_bits(word: &U32<E>) -> Vec<Boolean<E>> {
+        word.to_bits_le().chunks(8).rev().flatten().cloned().collect()
+    }
+
+    /// Performs a rotate right operation on the given `u32` value.
+    fn rotate_right(value: &U32<E>, n: usize) -> U32<E> {
+        // Perform the rotation.
+        let mut bits_le = value.to_bits_le();
+        bits_le.rotate_left(n);
+        // Return the rotated value.
+        U32::from_bits_le(&bits_le)
+    }
+
+    /// Performs a shift right operation on the given `u32` value.
+    fn shift_right(value: &U32<E>, n: usize) -> U32<E> {
+        // Perform the shift.
+        let mut bits_le = value.to_bits_le();
+        bits_le.drain(..n);
+        bits_le.resize(32, Boolean::constant(false));
+        // Return the shifted value.
+        U32::from_bits_le(&bits_le)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Rng;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    #[test]
+    fn test_sha256_equivalence() {
+        use console::Hash as H;
+
+        let rng = &mut TestRng::default();
+
+        let native = console::Sha256;
+        let sha256 = Sha256::<Circuit>::new();
+
+        let mut input_sizes = vec![0, 1, 2, 3, 7, 8, 9, 16, 32, 64, 128, 256, 440, 447, 448, 449, 512, 1024];
+        input_sizes.extend((0..5).map(|_| rng.gen_range(1..1024)));
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_inputs in input_sizes.iter().copied() {
+                println!("Checking equivalence for {num_inputs} inputs in {mode} mode");
+
+                // Prepare the preimage.
+                let native_input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+                let input = native_input.iter().map(|v| Boolean::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+                // Compute the console hash.
+                let expected = native.hash(&native_input).expect("Failed to hash console input");
+
+                // Compute the circuit hash.
+                let candidate = sha256.hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied());
+                // Ensure a constant preimage yields a constant digest.
+                if mode == Mode::Constant {
+                    assert!(candidate.iter().all(|bit| bit.is_constant()));
+                }
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_sha256_vectors() {
+        let sha256 = Sha256::<Circuit>::new();
+
+        // The test vectors from FIPS 180-2, Appendix B.
+        let vectors = [
+            ("", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+            ("abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+            (
+                "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+            ),
+        ];
+        for (input, output) in vectors {
+            let input = input
+                .bytes()
+                .flat_map(|byte| (0..8).map(move |i| Boolean::<Circuit>::new(Mode::Private, (byte >> i) & 1 == 1)))
+                .collect::<Vec<_>>();
+            let candidate = sha256.hash(&input).eject_value();
+            let candidate = candidate
+                .chunks(8)
+                .map(|bits| bits.iter().rev().fold(0u8, |acc, bit| (acc << 1) | *bit as u8))
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>();
+            assert_eq!(output, candidate);
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The output of the in-circuit Varuna verifier, which is a set of points in the first group of the pairing curve,
+/// such that the proof is valid if and only if `e(A, H) * Π_d e(C_d, β^{-d} H) * e(B, βH) = 1`.
+#[derive(Clone)]
+pub struct Accumulator<E: Environment, P: PairingEngine<Fr = E::BaseField>> {
+    /// The point `A`, which is paired with the generator `H` of the second group.
+    pub(super) h: G1<E>,
+    /// The points `C_d` of the commitments with a degree bound `d`, which are paired with `β^{-d} H`.
+    pub(super) shifted: BTreeMap<usize, G1<E>>,
+    /// The point `B`, which is paired with `βH`.
+    pub(super) beta_h: G1<E>,
+    _phantom: PhantomData<P>,
+}
+
+impl<E: Environment, P: PairingEngine<Fr = E::BaseField>> Accumulator<E, P> {
+    /// Initializes a new accumulator.
+    pub(super) fn new(h: G1<E>, shifted: BTreeMap<usize, G1<E>>, beta_h: G1<E>) -> Self {
+        Self { h, shifted, beta_h, _phantom: PhantomData }
+    }
+
+    /// Returns `true` if the pairing check of the accumulator holds, for the given universal verifier.
+    pub fn check(&self, universal_verifier: &UniversalVerifier<P>) -> Result<bool> {
+        let mut g1 = vec![Curve::<E, P>::eject(&self.h)?.prepare()];
+        let mut g2 = vec![universal_verifier.vk.prepared_h.clone()];
+        for (degree_bound, point) in &self.shifted {
+            let Some(shift) = universal_verifier.prepared_negative_powers_of_beta_h.get(degree_bound) else {
+                bail!("The universal verifier does not support the degree bound {degree_bound}")
+            };
+            g1.push(Curve::<E, P>::eject(point)?.prepare());
+            g2.push(shift.clone());
+        }
+        g1.push(Curve::<E, P>::eject(&self.beta_h)?.prepare());
+        g2.push(universal_verifier.vk.prepared_beta_h.clone());
+
+        Ok(P::product_of_pairings(g1.iter().zip_eq(g2.iter())).is_one())
+    }
+}
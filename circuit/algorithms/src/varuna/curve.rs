@@ -0,0 +1,217 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The first group of the pairing curve `y^2 = x^3 + b`, with the constants for in-circuit arithmetic.
+pub(super) struct Curve<E: Environment, P: PairingEngine> {
+    /// The coefficient `b` of the curve.
+    b: BigUint,
+    /// The offset point, which is the subgroup point obtained from the smallest positive `x`-coordinate.
+    /// Its discrete logarithm is unknown, which ensures the incomplete additions do not encounter
+    /// exceptional cases, as this would require a known relation between the offset point and the inputs.
+    offset: P::G1Affine,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Environment, P: PairingEngine<Fr = E::BaseField>> Curve<E, P> {
+    /// Initializes the curve constants.
+    pub(super) fn new() -> Self {
+        // Compute `b = y^2 - x^3` from the generator.
+        let generator = P::G1Affine::prime_subgroup_generator();
+        let (x, y) = (generator.to_x_coordinate(), generator.to_y_coordinate());
+        let b = y.square() - x.square() * x;
+        // Ensure the curve is of the form `y^2 = x^3 + b`, by checking another point.
+        let point = generator.to_projective().double().to_affine();
+        let (x, y) = (point.to_x_coordinate(), point.to_y_coordinate());
+        if y.square() != x.square() * x + b {
+            E::halt("The Varuna verifier requires a pairing curve of the form `y^2 = x^3 + b`")
+        }
+
+        // Compute the offset point.
+        let mut x = P::Fq::one();
+        let offset = loop {
+            if let Some(point) = P::G1Affine::from_x_coordinate(x, true) {
+                let point = point.mul_by_cofactor();
+                if !point.is_zero() {
+                    break point;
+                }
+            }
+            x += P::Fq::one();
+        };
+
+        // Ensure the base field matches the non-native field parameters.
+        if modulus::<P::Fq>() != Bls12_377BaseParameters::modulus() {
+            E::halt("The Varuna verifier only supports the base field of BLS12-377")
+        }
+
+        Self { b: to_biguint(&b), offset, _phantom: PhantomData }
+    }
+
+    /// Returns the coordinates of the given point, which must not be the point at infinity.
+    fn coordinates(point: &P::G1Affine) -> (BigUint, BigUint) {
+        if point.is_zero() {
+            E::halt("The Varuna verifier does not support commitments at the point at infinity")
+        }
+        (to_biguint(&point.to_x_coordinate()), to_biguint(&point.to_y_coordinate()))
+    }
+
+    /// Initializes a point in the given mode, enforcing that it is on the curve if it is not a constant.
+    pub(super) fn new_point(&self, mode: Mode, point: &P::G1Affine) -> G1<E> {
+        let coordinates = Self::coordinates(point);
+        if mode.is_constant() {
+            return G1::constant(&coordinates);
+        }
+
+        // Initialize the coordinates, which ensures they are reduced.
+        let point = G1 { x: helpers::inject(mode, &coordinates.0), y: helpers::inject(mode, &coordinates.1) };
+        // Ensure `y^2 = x^3 + b`.
+        let x_squared = point.x.square();
+        let b = helpers::constant(&self.b);
+        Fq::assert_sum_of_products_is_zero(&[(1, &point.y, &point.y), (-1, &x_squared, &point.x)], &[(-1, &b)]);
+        point
+    }
+
+    /// Returns the point of the given in-circuit point, if it is on the curve and in the prime-order subgroup.
+    pub(super) fn eject(point: &G1<E>) -> Result<P::G1Affine> {
+        let x = P::Fq::from_bytes_le_mod_order(&point.x.eject_value().value().to_bytes_le());
+        let y = P::Fq::from_bytes_le_mod_order(&point.y.eject_value().value().to_bytes_le());
+        let point = match <P::G1Affine as AffineCurve>::pair_from_x_coordinate(x) {
+            Some((first, _)) if first.to_y_coordinate() == y => first,
+            Some((_, second)) if second.to_y_coordinate() == y => second,
+            _ => bail!("The accumulated point is not on the curve"),
+        };
+        ensure!(point.is_in_correct_subgroup_assuming_on_curve(), "The accumulated point is not in the subgroup");
+        Ok(point)
+    }
+
+    /// Returns `Σ k_i * P_i`, for the given points `P_i` in the prime-order subgroup, and scalars `k_i`.
+    ///
+    /// Each scalar is made odd, and written with `n` signed digits `d_j ∈ {-1, 1}`, such that each step of the
+    /// double-and-add adds `±P_i` for every point. The accumulator starts at the offset point `H`,
+    /// and `2^n * H` is subtracted from the output, which must not be the point at infinity.
+    pub(super) fn msm(&self, bases: &[G1<E>], scalars: &[Field<E>]) -> G1<E> {
+        let num_bits = E::BaseField::size_in_bits();
+        if bases.is_empty() || bases.len() != scalars.len() {
+            E::halt("The Varuna multi-scalar multiplication requires one scalar for each point")
+        }
+
+        // Write each scalar `k` as `2 * b - (2^n - 1) - e`, where `e` is `1` if `k` is even, and `b < 2^n`.
+        let max: BigUint = (BigUint::one() << num_bits) - 1u32;
+        let digits = scalars
+            .iter()
+            .map(|scalar| {
+                let mode = if scalar.is_constant() { Mode::Constant } else { Mode::Private };
+                let k = to_biguint(&*scalar.eject_value());
+                let is_even = Boolean::new(mode, !k.bit(0));
+                let b = (k + u32::from(is_even.eject_value()) + &max) >> 1u32;
+                let bits = (0..num_bits).map(|i| Boolean::new(mode, b.bit(i as u64))).collect::<Vec<_>>();
+
+                // Ensure `2 * b - (2^n - 1) - e = k`, over the scalar field.
+                // This holds modulo the subgroup order, which suffices for points in the subgroup.
+                let mut sum = -constant::<E>(E::BaseField::from_bytes_le_mod_order(&max.to_bytes_le()));
+                sum -= Field::from_boolean(&is_even);
+                let mut coefficient = console::Field::<E::Network>::one().double();
+                for bit in &bits {
+                    sum += Field::from_boolean(bit) * Field::constant(coefficient);
+                    coefficient = coefficient.double();
+                }
+                E::assert_eq(&sum, scalar);
+                (is_even, bits)
+            })
+            .collect::<Vec<_>>();
+
+        // Compute `2^n * H + Σ (k_i + e_i) * P_i`.
+        let negations = bases.iter().map(|base| base.neg()).collect::<Vec<_>>();
+        let mut output = G1::constant(&Self::coordinates(&self.offset));
+        for j in (0..num_bits).rev() {
+            for (i, ((base, negation), (_, bits))) in bases.iter().zip_eq(&negations).zip_eq(&digits).enumerate() {
+                let addend = G1 { x: base.x.clone(), y: Fq::ternary(&bits[j], &base.y, &negation.y) };
+                output = match i == 0 {
+                    // Compute `2 * output + d_j * P_0`.
+                    true => output.double_and_add(&addend),
+                    // Compute `output + d_j * P_i`.
+                    false => output.add(&addend),
+                };
+            }
+        }
+
+        // Subtract `P_i` for each scalar that was made odd.
+        for (negation, (is_even, _)) in negations.iter().zip_eq(&digits) {
+            output = G1::ternary(is_even, &output.add(negation), &output);
+        }
+
+        // Subtract the offset.
+        let mut offset = self.offset.to_projective();
+        (0..num_bits).for_each(|_| offset.double_in_place());
+        output.add_checked(&G1::constant(&Self::coordinates(&(-offset.to_affine()))))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr, G1Affine, G1Projective};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    type Curve = super::Curve<Circuit, Bls12_377>;
+
+    fn check_msm(mode: Mode, num_points: usize, rng: &mut TestRng) {
+        let curve = Curve::new();
+
+        let points = (0..num_points).map(|_| G1Affine::rand(rng)).collect::<Vec<_>>();
+        let scalars = (0..num_points).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        // Compute the expected result.
+        let expected =
+            points.iter().zip(&scalars).fold(G1Projective::zero(), |sum, (point, scalar)| sum + *point * *scalar);
+
+        Circuit::scope(format!("MSM {mode}"), || {
+            let bases = points.iter().map(|point| curve.new_point(mode, point)).collect::<Vec<_>>();
+            let scalars =
+                scalars.iter().map(|scalar| Field::new(mode, console::Field::new(*scalar))).collect::<Vec<_>>();
+            let candidate = curve.msm(&bases, &scalars);
+            assert_eq!(expected.to_affine(), Curve::eject(&candidate).unwrap());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_constants() {
+        let curve = Curve::new();
+        // Ensure the offset point is in the subgroup.
+        assert!(curve.offset.is_on_curve());
+        assert!(curve.offset.is_in_correct_subgroup_assuming_on_curve());
+        // Ensure the curve coefficient matches the generator.
+        let generator = G1Affine::prime_subgroup_generator();
+        let (x, y) = (to_biguint(&generator.to_x_coordinate()), to_biguint(&generator.to_y_coordinate()));
+        let q = modulus::<<Bls12_377 as PairingEngine>::Fq>();
+        assert_eq!((&y * &y) % &q, (&x * &x * &x + &curve.b) % &q);
+    }
+
+    #[test]
+    fn test_msm_constant() {
+        let mut rng = TestRng::default();
+        for num_points in 1..4 {
+            check_msm(Mode::Constant, num_points, &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_msm_private() {
+        let mut rng = TestRng::default();
+        check_msm(Mode::Private, 1, &mut rng);
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod accumulator;
+pub use accumulator::*;
+
+mod curve;
+use curve::*;
+
+mod proof;
+pub use proof::*;
+
+mod sponge;
+use sponge::*;
+
+mod verify;
+
+mod verifying_key;
+pub use verifying_key::*;
+
+use crate::helpers;
+use snarkvm_algorithms::{
+    crypto_hash::PoseidonSponge,
+    fft::EvaluationDomain,
+    snark::varuna::{self as native, ahp::indexer::CircuitInfo, CircuitVerifyingKey, SNARKMode, VarunaSNARK},
+    srs::UniversalVerifier,
+    DuplexSpongeMode,
+};
+use snarkvm_circuit_types::{
+    environment::prelude::*,
+    nonnative::{Bls12_377BaseParameters, NonNativeParameters},
+    Boolean,
+    Field,
+    NonNativeField,
+};
+use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::PoseidonParameters;
+use snarkvm_utilities::BigInteger;
+
+use core::marker::PhantomData;
+use num_bigint::BigUint;
+use std::collections::BTreeMap;
+
+/// An element of the base field of the pairing curve.
+type Fq<E> = NonNativeField<E, Bls12_377BaseParameters>;
+/// An affine point on the first group of the pairing curve, with non-native coordinates.
+type G1<E> = crate::helpers::Point<E, Bls12_377BaseParameters>;
+
+/// The Fiat-Shamir sponge of the native Varuna verifier.
+type NativeSponge<P> = PoseidonSponge<<P as PairingEngine>::Fq, 2, 1>;
+
+/// Varuna proof verification, for a proof of a single instance of a single circuit.
+///
+/// The circuit is defined over the scalar field of the pairing curve, so the Fiat-Shamir sponge and the
+/// arithmetic on commitments are emulated with non-native field arithmetic over the base field of the curve.
+/// As a pairing can not be computed efficiently in-circuit, the verifier outputs an [`Accumulator`] of three
+/// (or more) points in the first group, and the final pairing check is deferred to [`Accumulator::check`].
+///
+/// The base field is emulated with the `NonNativeField` parameters of BLS12-377, which is the only pairing curve
+/// whose scalar field is the native field, so any other curve is rejected when the verifier is initialized.
+///
+/// The commitments in the verifying key and the proof are assumed to be in the prime-order subgroup,
+/// and not to be the point at infinity, which holds for honestly generated keys and proofs.
+#[derive(Clone, Debug, Default)]
+pub struct Varuna<E: Environment, P: PairingEngine<Fr = E::BaseField>, SM: SNARKMode>(PhantomData<(E, P, SM)>);
+
+/// Returns the given prime field element as an integer.
+fn to_biguint<F: PrimeField>(value: &F) -> BigUint {
+    value.to_bigint().to_biguint()
+}
+
+/// Returns the modulus of the given prime field.
+fn modulus<F: PrimeField>() -> BigUint {
+    F::modulus().to_biguint()
+}
+
+/// Returns the given scalar field element as a constant.
+fn constant<E: Environment>(value: E::BaseField) -> Field<E> {
+    Field::constant(console::Field::new(value))
+}
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of query points of a Varuna proof, which are `α`, `β`, and `γ`.
+const NUM_QUERY_POINTS: usize = 3;
+
+/// A Varuna proof of a single instance of a single circuit.
+#[derive(Clone)]
+pub struct Proof<E: Environment, P: PairingEngine<Fr = E::BaseField>> {
+    /// The commitment to the witness polynomial.
+    pub(super) w: G1<E>,
+    /// The commitment to the masking polynomial, in zero-knowledge mode.
+    pub(super) mask_poly: Option<G1<E>>,
+    /// The commitment to `h_0`, from the second round.
+    pub(super) h_0: G1<E>,
+    /// The commitment to `g_1`, from the third round.
+    pub(super) g_1: G1<E>,
+    /// The commitment to `h_1`, from the third round.
+    pub(super) h_1: G1<E>,
+    /// The commitments to `g_a`, `g_b`, and `g_c`, from the fourth round.
+    pub(super) g_m: [G1<E>; 3],
+    /// The commitment to `h_2`, from the fifth round.
+    pub(super) h_2: G1<E>,
+    /// The evaluation of `g_1` at `β`.
+    pub(super) g_1_eval: Field<E>,
+    /// The evaluations of `g_a`, `g_b`, and `g_c` at `γ`.
+    pub(super) g_m_evals: [Field<E>; 3],
+    /// The matrix sums of the third round.
+    pub(super) third_sums: [Field<E>; 3],
+    /// The matrix sums of the fourth round.
+    pub(super) fourth_sums: [Field<E>; 3],
+    /// The KZG openings at `α`, `β`, and `γ`, as the witness and the optional hiding evaluation.
+    pub(super) openings: Vec<(G1<E>, Option<Field<E>>)>,
+    _phantom: PhantomData<P>,
+}
+
+impl<E: Environment, P: PairingEngine<Fr = E::BaseField>> Inject for Proof<E, P> {
+    type Primitive = native::Proof<P>;
+
+    /// Initializes a proof from the given native proof, which must be of a single instance of a single circuit.
+    fn new(mode: Mode, proof: Self::Primitive) -> Self {
+        if proof.batch_sizes() != [1] {
+            E::halt("The Varuna verifier only supports a proof of a single instance of a single circuit")
+        }
+        if proof.has_lookups() {
+            E::halt("The Varuna verifier does not support proofs with lookups")
+        }
+        if proof.pc_proof.proof.proofs().len() != NUM_QUERY_POINTS {
+            E::halt("The Varuna proof has an unexpected number of openings")
+        }
+
+        let curve = Curve::<E, P>::new();
+        let point = |point: &P::G1Affine| curve.new_point(mode, point);
+        let field = |value: P::Fr| Field::new(mode, console::Field::new(value));
+
+        let commitments = &proof.commitments;
+        let evaluations = &proof.evaluations;
+        let (third_sums, fourth_sums) = (&proof.third_msg.sums[0][0], &proof.fourth_msg.sums[0]);
+        Self {
+            w: point(&commitments.witness_commitments[0].w.0),
+            mask_poly: commitments.mask_poly.as_ref().map(|mask_poly| point(&mask_poly.0)),
+            h_0: point(&commitments.h_0.0),
+            g_1: point(&commitments.g_1.0),
+            h_1: point(&commitments.h_1.0),
+            g_m: [
+                point(&commitments.g_a_commitments[0].0),
+                point(&commitments.g_b_commitments[0].0),
+                point(&commitments.g_c_commitments[0].0),
+            ],
+            h_2: point(&commitments.h_2.0),
+            g_1_eval: field(evaluations.g_1_eval),
+            g_m_evals: [
+                field(evaluations.g_a_evals[0]),
+                field(evaluations.g_b_evals[0]),
+                field(evaluations.g_c_evals[0]),
+            ],
+            third_sums: [field(third_sums.sum_a), field(third_sums.sum_b), field(third_sums.sum_c)],
+            fourth_sums: [field(fourth_sums.sum_a), field(fourth_sums.sum_b), field(fourth_sums.sum_c)],
+            openings: proof
+                .pc_proof
+                .proof
+                .proofs()
+                .iter()
+                .map(|opening| (point(&opening.w), opening.random_v.map(field)))
+                .collect(),
+            _phantom: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,341 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_algorithms::nonnative_params::{get_params, OptimizationType};
+
+/// The rate of the Fiat-Shamir sponge.
+const RATE: usize = 2;
+/// The capacity of the Fiat-Shamir sponge.
+const CAPACITY: usize = 1;
+/// The number of bits in a short challenge, as in `PoseidonSponge::squeeze_short_nonnative_field_element`.
+const SHORT_CHALLENGE_BITS: usize = 168;
+
+/// The Poseidon sponge of the native Varuna verifier, emulated over the base field of the pairing curve.
+///
+/// The state elements are `NonNativeField` elements, which are always reduced,
+/// so their bits match the bits interpreted by the native sponge.
+pub(super) struct FiatShamir<E: Environment, P: PairingEngine> {
+    /// The number of full rounds.
+    full_rounds: usize,
+    /// The number of partial rounds.
+    partial_rounds: usize,
+    /// The exponent of the S-box.
+    alpha: u64,
+    /// The additive round keys, indexed by round and state element.
+    ark: Vec<Vec<BigUint>>,
+    /// The MDS matrix.
+    mds: Vec<Vec<BigUint>>,
+    /// The state, with the capacity elements first.
+    state: Vec<Fq<E>>,
+    /// The current mode of the sponge.
+    mode: DuplexSpongeMode,
+    _phantom: PhantomData<P>,
+}
+
+impl<E: Environment, P: PairingEngine<Fr = E::BaseField>> FiatShamir<E, P> {
+    /// Initializes a new sponge with the given Poseidon parameters.
+    pub(super) fn new(parameters: &PoseidonParameters<P::Fq, RATE, CAPACITY>) -> Self {
+        let to_biguints = |rows: &[Vec<P::Fq>]| -> Vec<Vec<BigUint>> {
+            rows.iter().map(|row| row.iter().map(to_biguint).collect()).collect()
+        };
+        Self {
+            full_rounds: parameters.full_rounds,
+            partial_rounds: parameters.partial_rounds,
+            alpha: parameters.alpha,
+            ark: to_biguints(&parameters.ark),
+            mds: to_biguints(&parameters.mds),
+            state: vec![Fq::zero(); RATE + CAPACITY],
+            mode: DuplexSpongeMode::Absorbing { next_absorb_index: 0 },
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Absorbs the given bytes, as in `AlgebraicSponge::absorb_bytes`.
+    pub(super) fn absorb_bytes(&mut self, bytes: &[u8]) {
+        let capacity = P::Fq::size_in_bits() - 1;
+        let bits_be = bytes.iter().flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1)).collect::<Vec<_>>();
+        let elements = bits_be
+            .chunks(capacity)
+            .map(|bits_be| {
+                helpers::constant(&bits_be.iter().fold(BigUint::zero(), |value, bit| (value << 1u32) + u32::from(*bit)))
+            })
+            .collect::<Vec<_>>();
+        self.absorb_native(&elements);
+    }
+
+    /// Absorbs the given points, as their coordinates and a (false) infinity flag.
+    pub(super) fn absorb_points(&mut self, points: &[G1<E>]) {
+        let is_infinity = Fq::zero();
+        let elements =
+            points.iter().flat_map(|point| [point.x.clone(), point.y.clone(), is_infinity.clone()]).collect::<Vec<_>>();
+        self.absorb_native(&elements);
+    }
+
+    /// Absorbs the given scalar field elements, as in `PoseidonSponge::push_elements_to_sponge`.
+    ///
+    /// Each element is split into limbs, with the most significant limb first,
+    /// and each pair of limbs is packed into one base field element.
+    pub(super) fn absorb_nonnative(&mut self, elements: &[Field<E>]) {
+        let params = get_params(E::BaseField::size_in_bits(), P::Fq::size_in_bits(), OptimizationType::Weight);
+        let limbs = elements
+            .iter()
+            .flat_map(|element| {
+                let bits_le = element.to_bits_le();
+                let mut limbs = bits_le
+                    .chunks(params.bits_per_limb)
+                    .chain(std::iter::repeat(&[][..]))
+                    .take(params.num_limbs)
+                    .map(|limb| limb.to_vec())
+                    .collect::<Vec<_>>();
+                limbs.reverse();
+                limbs
+            })
+            .collect::<Vec<_>>();
+
+        // As in the native sponge, each limb is assumed to carry 2 bits of overhead,
+        // so a pair of limbs is packed if both fit in the capacity.
+        let shift = params.bits_per_limb + 2;
+        let chunk_size = match 2 * shift < P::Fq::size_in_bits() {
+            true => 2,
+            false => 1,
+        };
+        let elements = limbs
+            .chunks(chunk_size)
+            .map(|limbs| match limbs {
+                // Compute `first * 2^shift + second`.
+                [first, second] => {
+                    let mut bits_le = second.clone();
+                    bits_le.resize(shift, Boolean::constant(false));
+                    bits_le.extend_from_slice(first);
+                    Fq::from_bits_le_mod_order(&bits_le)
+                }
+                _ => Fq::from_bits_le_mod_order(&limbs[0]),
+            })
+            .collect::<Vec<_>>();
+        self.absorb_native(&elements);
+    }
+
+    /// Absorbs the given base field elements.
+    fn absorb_native(&mut self, elements: &[Fq<E>]) {
+        if elements.is_empty() {
+            return;
+        }
+        let mut rate_start = match self.mode {
+            DuplexSpongeMode::Absorbing { next_absorb_index } if next_absorb_index < RATE => next_absorb_index,
+            _ => {
+                self.permute();
+                0
+            }
+        };
+
+        // Absorb the elements into the rate, permuting between each full chunk.
+        let mut elements = elements;
+        loop {
+            let (chunk, rest) = elements.split_at((RATE - rate_start).min(elements.len()));
+            for (i, element) in chunk.iter().enumerate() {
+                self.state[CAPACITY + rate_start + i] += element;
+            }
+            if rest.is_empty() {
+                self.mode = DuplexSpongeMode::Absorbing { next_absorb_index: rate_start + chunk.len() };
+                return;
+            }
+            self.permute();
+            rate_start = 0;
+            elements = rest;
+        }
+    }
+
+    /// Squeezes the given number of base field elements.
+    fn squeeze_native(&mut self, num_elements: usize) -> Vec<Fq<E>> {
+        if num_elements == 0 {
+            return vec![];
+        }
+        let mut rate_start = match self.mode {
+            DuplexSpongeMode::Squeezing { next_squeeze_index } if next_squeeze_index < RATE => next_squeeze_index,
+            _ => {
+                self.permute();
+                0
+            }
+        };
+
+        // Squeeze the elements from the rate, permuting between each full chunk.
+        let mut output = Vec::with_capacity(num_elements);
+        loop {
+            let chunk_size = (RATE - rate_start).min(num_elements - output.len());
+            output.extend_from_slice(&self.state[CAPACITY + rate_start..][..chunk_size]);
+            if output.len() == num_elements {
+                self.mode = DuplexSpongeMode::Squeezing { next_squeeze_index: rate_start + chunk_size };
+                return output;
+            }
+            self.permute();
+            rate_start = 0;
+        }
+    }
+
+    /// Squeezes the given number of scalar field elements.
+    pub(super) fn squeeze_nonnative(&mut self, num_elements: usize) -> Vec<Field<E>> {
+        self.get_fe(num_elements, E::BaseField::size_in_bits() - 1)
+    }
+
+    /// Squeezes a short scalar field element, of `168` bits.
+    pub(super) fn squeeze_short_nonnative(&mut self) -> Field<E> {
+        self.get_fe(1, SHORT_CHALLENGE_BITS).remove(0)
+    }
+
+    /// Squeezes the given number of scalar field elements, each from the given number of big-endian bits.
+    fn get_fe(&mut self, num_elements: usize, num_bits: usize) -> Vec<Field<E>> {
+        self.get_bits(num_elements * num_bits)
+            .chunks(num_bits)
+            .map(|bits_be| Field::from_bits_le(&bits_be.iter().rev().cloned().collect::<Vec<_>>()))
+            .collect()
+    }
+
+    /// Squeezes the given number of big-endian bits, as in `PoseidonSponge::get_bits`.
+    fn get_bits(&mut self, num_bits: usize) -> Vec<Boolean<E>> {
+        let bits_per_element = P::Fq::size_in_bits() - 1;
+        let num_elements = (num_bits + bits_per_element - 1) / bits_per_element;
+
+        let elements = self.squeeze_native(num_elements);
+        let mut bits_be = Vec::with_capacity(num_elements * bits_per_element);
+        for element in &elements {
+            // Take the lower bits in big-endian order, as the native sponge discards the most significant bit.
+            bits_be.extend(element.to_bits_le().into_iter().take(bits_per_element).rev());
+        }
+        bits_be.truncate(num_bits);
+        bits_be
+    }
+
+    /// Returns `x^alpha`.
+    fn pow_alpha(&self, x: &Fq<E>) -> Fq<E> {
+        let mut output: Option<Fq<E>> = None;
+        for i in (0..u64::BITS - self.alpha.leading_zeros()).rev() {
+            output = output.map(|output| output.square());
+            if (self.alpha >> i) & 1 == 1 {
+                output = Some(match output {
+                    Some(output) => output * x,
+                    None => x.clone(),
+                });
+            }
+        }
+        output.unwrap_or_else(Fq::one)
+    }
+
+    /// Returns `Σ c_i * x_i + d`, for the given constants `c_i` and `d`.
+    ///
+    /// The output is witnessed, and checked with a single sum of products, as the MDS matrix is dense.
+    fn linear_combination(&self, coefficients: &[BigUint], elements: &[Fq<E>], constant: &BigUint) -> Fq<E> {
+        let value = coefficients
+            .iter()
+            .zip_eq(elements)
+            .fold(constant.clone(), |sum, (coefficient, element)| sum + coefficient * element.eject_value().value());
+        let mode = match elements.iter().all(|element| element.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+        let output = helpers::inject(mode, &value);
+
+        let coefficients = coefficients.iter().map(helpers::constant).collect::<Vec<Fq<E>>>();
+        let products = coefficients.iter().zip_eq(elements).map(|(c, x)| (1, c, x)).collect::<Vec<_>>();
+        Fq::assert_sum_of_products_is_zero(&products, &[(1, &helpers::constant(constant)), (-1, &output)]);
+        output
+    }
+
+    /// Applies the Poseidon permutation to the state.
+    ///
+    /// The round keys of each round are folded into the MDS matrix of the previous round.
+    fn permute(&mut self) {
+        let full_rounds_over_2 = self.full_rounds / 2;
+        let partial_round_range = full_rounds_over_2..(full_rounds_over_2 + self.partial_rounds);
+
+        // Apply the round keys of the first round.
+        let mut state = self
+            .state
+            .iter()
+            .zip_eq(&self.ark[0])
+            .map(|(element, key)| element + helpers::constant::<E, Bls12_377BaseParameters>(key))
+            .collect::<Vec<_>>();
+        for i in 0..(self.partial_rounds + self.full_rounds) {
+            // Apply the S-box, to every element in full rounds, and to the first element in partial rounds.
+            match partial_round_range.contains(&i) {
+                true => state[0] = self.pow_alpha(&state[0]),
+                false => state = state.iter().map(|element| self.pow_alpha(element)).collect(),
+            }
+            // Apply the MDS matrix, and the round keys of the next round.
+            let zeros = vec![BigUint::zero(); RATE + CAPACITY];
+            let keys = self.ark.get(i + 1).unwrap_or(&zeros);
+            state = self.mds.iter().zip_eq(keys).map(|(row, key)| self.linear_combination(row, &state, key)).collect();
+        }
+        self.state = state;
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::AlgebraicSponge;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr, G1Affine};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    type FiatShamir = super::FiatShamir<Circuit, Bls12_377>;
+    type Curve = super::Curve<Circuit, Bls12_377>;
+
+    fn check_sponge(mode: Mode, num_points: usize, num_scalars: usize, rng: &mut TestRng) {
+        let parameters = NativeSponge::<Bls12_377>::sample_parameters();
+        let points = (0..num_points).map(|_| G1Affine::rand(rng)).collect::<Vec<_>>();
+        let scalars = (0..num_scalars).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+        // Compute the expected challenges.
+        let mut native = NativeSponge::<Bls12_377>::new_with_parameters(&parameters);
+        native.absorb_bytes(b"VARUNA");
+        native.absorb_native_field_elements(&points);
+        native.absorb_nonnative_field_elements(scalars.iter().copied());
+        let expected = native.squeeze_nonnative_field_elements::<Fr>(3);
+        let expected_short = native.squeeze_short_nonnative_field_element::<Fr>();
+
+        Circuit::scope(format!("FiatShamir {mode}"), || {
+            let curve = Curve::new();
+            let mut sponge = FiatShamir::new(&parameters);
+            sponge.absorb_bytes(b"VARUNA");
+            sponge.absorb_points(&points.iter().map(|point| curve.new_point(mode, point)).collect::<Vec<_>>());
+            sponge.absorb_nonnative(
+                &scalars.iter().map(|scalar| Field::new(mode, console::Field::new(*scalar))).collect::<Vec<_>>(),
+            );
+            let candidate = sponge.squeeze_nonnative(3);
+            let candidate_short = sponge.squeeze_short_nonnative();
+            for (expected, candidate) in expected.iter().zip_eq(&candidate) {
+                assert_eq!(*expected, *candidate.eject_value());
+            }
+            assert_eq!(expected_short, *candidate_short.eject_value());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_sponge_constant() {
+        let mut rng = TestRng::default();
+        for (num_points, num_scalars) in [(0, 0), (1, 0), (0, 1), (1, 3), (2, 4), (3, 7)] {
+            check_sponge(Mode::Constant, num_points, num_scalars, &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_sponge_private() {
+        let mut rng = TestRng::default();
+        check_sponge(Mode::Private, 1, 1, &mut rng);
+    }
+}
@@ -0,0 +1,326 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: PairingEngine<Fr = E::BaseField>, SM: SNARKMode> Varuna<E, P, SM> {
+    /// Verifies the given proof for the given public inputs, which include the leading one, and verifying key.
+    ///
+    /// This follows the native `VarunaSNARK::verify` for a single instance, up to the final pairing check
+    /// of the batched KZG openings, which is returned as an accumulator to be checked with [`Accumulator::check`].
+    #[allow(non_snake_case)]
+    pub fn verify(
+        universal_verifier: &UniversalVerifier<P>,
+        fs_parameters: &PoseidonParameters<P::Fq, 2, 1>,
+        verifying_key: &VerifyingKey<E, P>,
+        inputs: &[Field<E>],
+        proof: &Proof<E, P>,
+    ) -> Accumulator<E, P> {
+        let curve = Curve::<E, P>::new();
+
+        // Construct the domains of the circuit.
+        let domain = |size: usize| match EvaluationDomain::<E::BaseField>::new(size) {
+            Some(domain) => domain,
+            None => E::halt("The Varuna circuit is too large for its evaluation domains"),
+        };
+        let circuit_info = &verifying_key.circuit_info;
+        let input_domain = domain(circuit_info.num_public_inputs);
+        let constraint_domain = domain(circuit_info.num_constraints);
+        let variable_domain = domain(circuit_info.num_variables);
+        let non_zero_domains = [
+            domain(circuit_info.num_non_zero_a),
+            domain(circuit_info.num_non_zero_b),
+            domain(circuit_info.num_non_zero_c),
+        ];
+        let max_non_zero_domain = *non_zero_domains.iter().max_by_key(|domain| domain.size).unwrap();
+
+        // Ensure the public inputs are of the expected size, and start with one.
+        if inputs.is_empty() || inputs.len() > input_domain.size() {
+            E::halt("The Varuna public inputs do not match the size of the verifying key")
+        }
+        E::assert_eq(&inputs[0], Field::<E>::one());
+
+        // Ensure the proof matches the zero-knowledge mode.
+        let is_hiding = proof.openings.iter().any(|(_, random_v)| random_v.is_some());
+        if is_hiding != SM::ZK || proof.mask_poly.is_some() != SM::ZK {
+            E::halt("The Varuna proof does not match the zero-knowledge mode")
+        }
+
+        // Initialize the sponge with the public inputs and the verifying key.
+        let mut sponge = FiatShamir::<E, P>::new(fs_parameters);
+        sponge.absorb_bytes(VarunaSNARK::<P, NativeSponge<P>, SM>::PROTOCOL_NAME);
+        sponge.absorb_bytes(&1u64.to_le_bytes());
+        let padded_inputs = inputs
+            .iter()
+            .cloned()
+            .chain(std::iter::repeat(Field::zero()))
+            .take(input_domain.size())
+            .collect::<Vec<_>>();
+        sponge.absorb_nonnative(&padded_inputs);
+        sponge.absorb_points(&verifying_key.circuit_commitments);
+
+        // Returns the evaluation of the vanishing polynomial of the given domain at the given point.
+        let vanishing = |domain: &EvaluationDomain<E::BaseField>, point: &Field<E>| {
+            (0..domain.log_size_of_group).fold(point.clone(), |power, _| power.square()) - Field::one()
+        };
+
+        // First round.
+        let first_commitments = [proof.w.clone()].into_iter().chain(proof.mask_poly.clone()).collect::<Vec<_>>();
+        sponge.absorb_points(&first_commitments);
+
+        // Second round.
+        sponge.absorb_points(&[proof.h_0.clone()]);
+        let challenges = sponge.squeeze_nonnative(3);
+        let (alpha, eta_b, eta_c) = (&challenges[0], &challenges[1], &challenges[2]);
+        let v_R_at_alpha = vanishing(&constraint_domain, alpha);
+        E::assert(!v_R_at_alpha.is_zero());
+
+        // Third round.
+        sponge.absorb_points(&[proof.g_1.clone(), proof.h_1.clone()]);
+        sponge.absorb_nonnative(&proof.third_sums);
+        let beta = &sponge.squeeze_nonnative(1)[0];
+        let v_C_at_beta = vanishing(&variable_domain, beta);
+        E::assert(!v_C_at_beta.is_zero());
+
+        // Fourth round.
+        sponge.absorb_points(&proof.g_m);
+        sponge.absorb_nonnative(&proof.fourth_sums);
+        let challenges = sponge.squeeze_nonnative(2);
+        let deltas = [Field::one(), challenges[0].clone(), challenges[1].clone()];
+
+        // Fifth round.
+        sponge.absorb_points(&[proof.h_2.clone()]);
+        let gamma = &sponge.squeeze_nonnative(1)[0];
+        let v_K_at_gamma = vanishing(&max_non_zero_domain, gamma);
+        E::assert(!v_K_at_gamma.is_zero());
+
+        // Absorb the evaluations.
+        let evaluations =
+            [proof.g_1_eval.clone()].into_iter().chain(proof.g_m_evals.iter().cloned()).collect::<Vec<_>>();
+        sponge.absorb_nonnative(&evaluations);
+
+        // Compute the rowcheck at `α`, as its value and the coefficient of `h_0`.
+        let [sum_a, sum_b, sum_c] = &proof.third_sums;
+        let rowcheck_value = sum_c - sum_a * sum_b;
+        let h_0_coefficient = -&v_R_at_alpha;
+
+        // Compute the lineval sumcheck at `β`, as its value and the coefficients of `w` and `h_1`.
+        // The evaluation of the input polynomial is `x(β) = v_X(β) / |X| * Σ x_i * ω^i / (β - ω^i)`.
+        let v_X_at_beta = vanishing(&input_domain, beta);
+        let lagrange_sum = inputs.iter().zip(input_domain.elements()).fold(Field::zero(), |sum, (input, omega)| {
+            let omega = constant::<E>(omega);
+            sum + input * &omega * (beta - &omega).inverse()
+        });
+        let x_at_beta = lagrange_sum * &v_X_at_beta * constant::<E>(input_domain.size_inv);
+        let fourth_sums = proof
+            .fourth_sums
+            .iter()
+            .zip_eq(&non_zero_domains)
+            .map(|(sum, domain)| sum * constant::<E>(domain.size_as_field_element))
+            .collect::<Vec<_>>();
+        let combined_sum = &fourth_sums[0] + &fourth_sums[1] * eta_b + &fourth_sums[2] * eta_c;
+        let third_sum = sum_a + sum_b * eta_b + sum_c * eta_c;
+        let lineval_value =
+            beta * &proof.g_1_eval + third_sum * constant::<E>(variable_domain.size_inv) - &combined_sum * &x_at_beta;
+        let w_coefficient = &combined_sum * &v_X_at_beta;
+        let h_1_coefficient = -&v_C_at_beta;
+
+        // Compute the matrix sumcheck at `γ`, as its value and the coefficients of the indexed polynomials.
+        let v_rc = &v_R_at_alpha * &v_C_at_beta;
+        let rc = constant::<E>(constraint_domain.size_as_field_element * variable_domain.size_as_field_element);
+        let alpha_beta = alpha * beta;
+        let mut matrix_value = Field::zero();
+        let mut index_coefficients = vec![Field::zero(); verifying_key.circuit_commitments.len()];
+        for (m, (((delta, non_zero_domain), g_m_eval), sum)) in
+            deltas.iter().zip_eq(&non_zero_domains).zip_eq(&proof.g_m_evals).zip_eq(&proof.fourth_sums).enumerate()
+        {
+            // Compute the selector of the domain `K_m` within the largest domain `K`.
+            let scale = match non_zero_domain.size == max_non_zero_domain.size {
+                true => delta.clone(),
+                false => {
+                    let numerator = &v_K_at_gamma * constant::<E>(non_zero_domain.size_as_field_element);
+                    let denominator =
+                        vanishing(non_zero_domain, gamma) * constant::<E>(max_non_zero_domain.size_as_field_element);
+                    delta * numerator / denominator
+                }
+            };
+            let b_scale = &scale * (gamma * g_m_eval + sum) * &rc;
+            matrix_value += &b_scale * &alpha_beta;
+            // The coefficients of `col_m`, `row_m`, `row_col_m`, and `row_col_val_m`.
+            index_coefficients[m] = &b_scale * alpha;
+            index_coefficients[3 + m] = &b_scale * beta;
+            index_coefficients[6 + m] = -&b_scale;
+            index_coefficients[9 + m] = &scale * &v_rc;
+        }
+        let h_2_coefficient = -&v_K_at_gamma;
+
+        // Squeeze the challenges of the batched openings at `α`, `β`, and `γ`.
+        let rowcheck_challenge = sponge.squeeze_short_nonnative();
+        let beta_randomizer = sponge.squeeze_short_nonnative();
+        let g_1_challenge = sponge.squeeze_short_nonnative();
+        let lineval_challenge = sponge.squeeze_short_nonnative();
+        let gamma_randomizer = sponge.squeeze_short_nonnative();
+        let g_m_challenges = [(); 3].map(|_| sponge.squeeze_short_nonnative());
+        let matrix_challenge = sponge.squeeze_short_nonnative();
+
+        // Compute the combined evaluation at each point.
+        let alpha_value = &rowcheck_challenge * rowcheck_value;
+        let beta_value = &g_1_challenge * &proof.g_1_eval + &lineval_challenge * lineval_value;
+        let gamma_value = g_m_challenges
+            .iter()
+            .zip_eq(&proof.g_m_evals)
+            .fold(&matrix_challenge * &matrix_value, |sum, (challenge, eval)| sum + challenge * eval);
+
+        // Compute the point that is paired with `H`, which is the combined commitment without a degree bound,
+        // minus the adjusted witness `Σ r_p * (v_p * G - z_p * W_p + ρ_p * γG)`.
+        let lineval_scale = &beta_randomizer * &lineval_challenge;
+        let matrix_scale = &gamma_randomizer * &matrix_challenge;
+        let mut bases = vec![proof.h_0.clone(), proof.w.clone(), proof.h_1.clone(), proof.h_2.clone()];
+        let mut scalars = vec![
+            &rowcheck_challenge * h_0_coefficient,
+            &lineval_scale * w_coefficient,
+            &lineval_scale * h_1_coefficient,
+            &matrix_scale * h_2_coefficient,
+        ];
+        if let Some(mask_poly) = &proof.mask_poly {
+            bases.push(mask_poly.clone());
+            scalars.push(lineval_scale.clone());
+        }
+        for (commitment, coefficient) in verifying_key.circuit_commitments.iter().zip_eq(&index_coefficients) {
+            bases.push(commitment.clone());
+            scalars.push(&matrix_scale * coefficient);
+        }
+        bases.push(curve.new_point(Mode::Constant, &universal_verifier.vk.g));
+        scalars.push(-(alpha_value + &beta_randomizer * beta_value + &gamma_randomizer * gamma_value));
+
+        let randomizers = [Field::one(), beta_randomizer.clone(), gamma_randomizer.clone()];
+        let points = [alpha, beta, gamma];
+        let mut hiding_scalar = None;
+        for ((witness, random_v), (randomizer, point)) in
+            proof.openings.iter().zip_eq(randomizers.iter().zip_eq(points))
+        {
+            bases.push(witness.clone());
+            scalars.push(randomizer * point);
+            if let Some(random_v) = random_v {
+                hiding_scalar = Some(hiding_scalar.unwrap_or_else(Field::zero) - randomizer * random_v);
+            }
+        }
+        if let Some(hiding_scalar) = hiding_scalar {
+            bases.push(curve.new_point(Mode::Constant, &universal_verifier.vk.gamma_g));
+            scalars.push(hiding_scalar);
+        }
+        let h = curve.msm(&bases, &scalars);
+
+        // Compute the points that are paired with `β^{-d} H`, as the combined commitments with a degree bound `d`.
+        let mut shifted_terms = BTreeMap::<usize, (Vec<G1<E>>, Vec<Field<E>>)>::new();
+        let g_1_term = (variable_domain.size() - 2, &proof.g_1, &beta_randomizer * &g_1_challenge);
+        let g_m_terms = non_zero_domains.iter().zip_eq(&proof.g_m).zip_eq(&g_m_challenges).map(
+            |((non_zero_domain, g_m), challenge)| (non_zero_domain.size() - 2, g_m, &gamma_randomizer * challenge),
+        );
+        for (degree_bound, base, scalar) in [g_1_term].into_iter().chain(g_m_terms) {
+            let (bases, scalars) = shifted_terms.entry(degree_bound).or_default();
+            bases.push(base.clone());
+            scalars.push(scalar);
+        }
+        let shifted = shifted_terms
+            .into_iter()
+            .map(|(degree_bound, (bases, scalars))| (degree_bound, curve.msm(&bases, &scalars)))
+            .collect();
+
+        // Compute the point that is paired with `βH`, which is the negated combined witness `-Σ r_p * W_p`.
+        let witnesses = proof.openings.iter().map(|(witness, _)| witness.clone()).collect::<Vec<_>>();
+        let beta_h = curve.msm(&witnesses, &randomizers.iter().map(|randomizer| -randomizer).collect::<Vec<_>>());
+
+        Accumulator::new(h, shifted, beta_h)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::{
+        snark::varuna::{AHPForR1CS, TestCircuit, VarunaHidingMode, VarunaNonHidingMode},
+        AlgebraicSponge,
+        SNARK,
+    };
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_utilities::TestRng;
+
+    type FS = NativeSponge<Bls12_377>;
+
+    fn check_verify<SM: SNARKMode>(mode: Mode, is_valid: bool) {
+        let rng = &mut TestRng::default();
+
+        // Construct a native proof.
+        let max_degree = AHPForR1CS::<Fr, SM>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaSNARK::<Bls12_377, FS, SM>::universal_setup(max_degree).unwrap();
+        let universal_prover = universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, inputs) = TestCircuit::gen_rand(2, 30, 20, rng);
+        let (proving_key, verifying_key) =
+            VarunaSNARK::<Bls12_377, FS, SM>::circuit_setup(&universal_srs, &circuit).unwrap();
+        let mut proof =
+            VarunaSNARK::<Bls12_377, FS, SM>::prove(&universal_prover, &fs_parameters, &proving_key, &circuit, rng)
+                .unwrap();
+        if !is_valid {
+            proof.evaluations.g_1_eval += Fr::one();
+        }
+        let expected = VarunaSNARK::<Bls12_377, FS, SM>::verify(
+            &universal_verifier,
+            &fs_parameters,
+            &verifying_key,
+            inputs.as_slice(),
+            &proof,
+        )
+        .unwrap();
+        assert_eq!(is_valid, expected);
+
+        Circuit::scope(format!("Varuna {mode}"), || {
+            let verifying_key = VerifyingKey::<Circuit, Bls12_377>::new(mode, verifying_key);
+            let inputs = inputs.iter().map(|input| Field::new(mode, console::Field::new(*input))).collect::<Vec<_>>();
+            let proof = Proof::<Circuit, Bls12_377>::new(mode, proof);
+            let accumulator = Varuna::<Circuit, Bls12_377, SM>::verify(
+                &universal_verifier,
+                &fs_parameters,
+                &verifying_key,
+                &inputs,
+                &proof,
+            );
+            assert_eq!(expected, accumulator.check(&universal_verifier).unwrap());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_verify_constant() {
+        check_verify::<VarunaNonHidingMode>(Mode::Constant, true);
+        check_verify::<VarunaHidingMode>(Mode::Constant, true);
+    }
+
+    #[test]
+    fn test_verify_invalid_proof() {
+        check_verify::<VarunaNonHidingMode>(Mode::Constant, false);
+        check_verify::<VarunaHidingMode>(Mode::Constant, false);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_verify_private() {
+        check_verify::<VarunaHidingMode>(Mode::Private, true);
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of commitments to the indexed polynomials of a circuit.
+const NUM_CIRCUIT_COMMITMENTS: usize = 12;
+
+/// The verifying key of a Varuna circuit.
+#[derive(Clone)]
+pub struct VerifyingKey<E: Environment, P: PairingEngine<Fr = E::BaseField>> {
+    /// The sizes of the circuit, which are constants that determine the domains of the verifier.
+    pub(super) circuit_info: CircuitInfo,
+    /// The commitments to the indexed polynomials, in the order of their labels:
+    /// `col_{a,b,c}`, `row_{a,b,c}`, `row_col_{a,b,c}`, and `row_col_val_{a,b,c}`.
+    pub(super) circuit_commitments: Vec<G1<E>>,
+    _phantom: PhantomData<P>,
+}
+
+impl<E: Environment, P: PairingEngine<Fr = E::BaseField>> Inject for VerifyingKey<E, P> {
+    type Primitive = CircuitVerifyingKey<P>;
+
+    /// Initializes a verifying key from the given native verifying key.
+    /// The circuit sizes are always constants, as they determine the structure of the verifier.
+    fn new(mode: Mode, verifying_key: Self::Primitive) -> Self {
+        if verifying_key.circuit_commitments.len() != NUM_CIRCUIT_COMMITMENTS {
+            E::halt("The Varuna verifying key has an unexpected number of commitments")
+        }
+        let curve = Curve::<E, P>::new();
+        Self {
+            circuit_info: verifying_key.circuit_info,
+            circuit_commitments: verifying_key
+                .circuit_commitments
+                .iter()
+                .map(|commitment| curve.new_point(mode, &commitment.0))
+                .collect(),
+            _phantom: PhantomData,
+        }
+    }
+}
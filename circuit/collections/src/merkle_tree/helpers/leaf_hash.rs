@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::*;
-use snarkvm_circuit_algorithms::{Hash, Poseidon, BHP};
+use snarkvm_circuit_algorithms::{Hash, Poseidon, PoseidonV2, BHP};
 
 /// A trait for a Merkle leaf hash function.
 pub trait LeafHash<E: Environment> {
@@ -54,6 +54,21 @@ impl<E: Environment, const RATE: usize> LeafHash<E> for Poseidon<E, RATE> {
     }
 }
 
+impl<E: Environment, const RATE: usize> LeafHash<E> for PoseidonV2<E, RATE> {
+    type Hash = Field<E>;
+    type Leaf = Vec<Field<E>>;
+
+    /// Returns the hash of the given leaf node.
+    fn hash_leaf(&self, leaf: &Self::Leaf) -> Self::Hash {
+        let mut input = Vec::with_capacity(1 + leaf.len());
+        // Prepend the leaf with a `0field` element.
+        input.push(Self::Hash::zero());
+        input.extend_from_slice(leaf);
+        // Hash the input.
+        Hash::hash(self, &input)
+    }
+}
+
 #[cfg(all(test, console))]
 mod tests {
     use super::*;
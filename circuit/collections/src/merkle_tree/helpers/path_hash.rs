@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::*;
-use snarkvm_circuit_algorithms::{Hash, Poseidon, BHP};
+use snarkvm_circuit_algorithms::{Hash, Poseidon, PoseidonV2, BHP};
 
 /// A trait for a Merkle path hash function.
 pub trait PathHash<E: Environment> {
@@ -55,6 +55,18 @@ impl<E: Environment, const RATE: usize> PathHash<E> for Poseidon<E, RATE> {
     }
 }
 
+impl<E: Environment, const RATE: usize> PathHash<E> for PoseidonV2<E, RATE> {
+    type Hash = Field<E>;
+
+    /// Returns the hash of the given child nodes.
+    fn hash_children(&self, left: &Self::Hash, right: &Self::Hash) -> Self::Hash {
+        // Prepend the nodes with a `1field` byte.
+        let input = &[Self::Hash::one(), left.clone(), right.clone()];
+        // Hash the input.
+        Hash::hash(self, input)
+    }
+}
+
 #[cfg(all(test, console))]
 mod tests {
     use super::*;
@@ -29,6 +29,22 @@ pub struct MerklePath<E: Environment, const DEPTH: u8> {
     siblings: Vec<Field<E>>,
 }
 
+impl<E: Environment, const DEPTH: u8> From<(U64<E>, Vec<Field<E>>)> for MerklePath<E, DEPTH> {
+    /// Initializes a Merkle path from the given leaf index and siblings.
+    fn from((leaf_index, siblings): (U64<E>, Vec<Field<E>>)) -> Self {
+        // Ensure the Merkle path is the correct depth.
+        if siblings.len() != DEPTH as usize {
+            E::halt("Merkle path is not the correct depth")
+        }
+        // Ensure the leaf index is within the tree depth, by constraining its upper bits to zero.
+        for bit in leaf_index.to_bits_le().iter().skip(DEPTH as usize) {
+            E::assert(!bit);
+        }
+        // Return the Merkle path.
+        Self { leaf_index, siblings }
+    }
+}
+
 #[cfg(console)]
 impl<E: Environment, const DEPTH: u8> Inject for MerklePath<E, DEPTH> {
     type Primitive = console::merkle_tree::MerklePath<E::Network, DEPTH>;
@@ -133,4 +149,37 @@ mod tests {
     fn test_new_private() -> Result<()> {
         check_new::<32>(Mode::Private, 0, 0, 96, 64)
     }
+
+    #[test]
+    fn test_from_leaf_index_and_siblings() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Compute the Merkle tree.
+        let leaves = (0..4)
+            .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(&mut rng).to_bits_le())
+            .collect::<Vec<_>>();
+        let merkle_tree =
+            <<Circuit as Environment>::Network as snarkvm_console_network::Network>::merkle_tree_bhp::<8>(&leaves)?;
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            // Compute the Merkle path.
+            let merkle_path = merkle_tree.prove(index, leaf)?;
+
+            // Construct the Merkle path from its leaf index and siblings.
+            let leaf_index = U64::new(Mode::Private, merkle_path.leaf_index());
+            let siblings = merkle_path.siblings().iter().map(|node| Field::new(Mode::Private, *node)).collect();
+            let candidate = MerklePath::<Circuit, 8>::from((leaf_index, siblings));
+            assert_eq!(merkle_path, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+
+        // Ensure a leaf index beyond the tree depth is not satisfied.
+        let leaf_index = U64::new(Mode::Private, console::U64::new(1 << 8));
+        let siblings = (0..8).map(|_| Field::new(Mode::Private, console::Field::zero())).collect();
+        let _candidate = MerklePath::<Circuit, 8>::from((leaf_index, siblings));
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
 }
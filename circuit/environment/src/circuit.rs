@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{helpers::Constraint, Mode, *};
+use crate::{
+    helpers::{Constraint, Lookup},
+    Mode,
+    *,
+};
 
 use core::{
     cell::{Cell, RefCell},
@@ -191,6 +195,76 @@ impl Environment for Circuit {
         })
     }
 
+    /// Registers the lookup table with the given name, and returns its index.
+    /// The table is only constructed if no table with the given name has been registered yet.
+    fn add_lookup_table<S: Into<String>, Fn>(name: S, table: Fn) -> usize
+    where
+        Fn: FnOnce() -> LookupTable<Self::BaseField>,
+    {
+        IN_WITNESS.with(|in_witness| {
+            // Ensure we are not in witness mode.
+            if !in_witness.get() {
+                CIRCUIT.with(|circuit| circuit.borrow_mut().add_lookup_table(name.into(), table))
+            } else {
+                Self::halt("Tried to add a new lookup table in witness mode")
+            }
+        })
+    }
+
+    /// Adds one lookup constraint enforcing that `(A, B, C)` is a row of the lookup table at `table_index`.
+    fn enforce_lookup<Fn, A, B, C>(table_index: usize, lookup: Fn)
+    where
+        Fn: FnOnce() -> (A, B, C),
+        A: Into<LinearCombination<Self::BaseField>>,
+        B: Into<LinearCombination<Self::BaseField>>,
+        C: Into<LinearCombination<Self::BaseField>>,
+    {
+        IN_WITNESS.with(|in_witness| {
+            // Ensure we are not in witness mode.
+            if !in_witness.get() {
+                CIRCUIT.with(|circuit| {
+                    // Ensure that we do not surpass the constraint limit for the circuit.
+                    CONSTRAINT_LIMIT.with(|constraint_limit| {
+                        if let Some(limit) = constraint_limit.get() {
+                            if circuit.borrow().num_constraints() > limit {
+                                Self::halt(format!("Surpassed the constraint limit ({limit})"))
+                            }
+                        }
+                    });
+
+                    let (a, b, c) = lookup();
+                    let (a, b, c) = (a.into(), b.into(), c.into());
+
+                    // Ensure the lookup is not comprised of constants.
+                    match a.is_constant() && b.is_constant() && c.is_constant() {
+                        true => {
+                            // Evaluate the constant lookup.
+                            let row = [a.value(), b.value(), c.value()];
+                            let is_in_table =
+                                circuit.borrow().get_lookup_table(table_index).map_or(false, |t| t.contains(&row));
+                            assert!(
+                                is_in_table,
+                                "Constant lookup failed: ({a}, {b}, {c}) is not in table {table_index}"
+                            );
+                        }
+                        false => {
+                            // Ensure the lookup table exists.
+                            if circuit.borrow().get_lookup_table(table_index).is_none() {
+                                Self::halt(format!("Lookup table {table_index} does not exist"))
+                            }
+                            // Construct the lookup object.
+                            let lookup = Lookup(circuit.borrow().scope(), a, b, c, table_index);
+                            // Append the lookup.
+                            circuit.borrow_mut().enforce_lookup(lookup)
+                        }
+                    }
+                });
+            } else {
+                Self::halt("Tried to add a new lookup in witness mode")
+            }
+        })
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool {
         CIRCUIT.with(|circuit| circuit.borrow().is_satisfied())
@@ -395,4 +469,42 @@ mod tests {
             assert_eq!(0, Circuit::num_constraints_in_scope());
         })
     }
+
+    #[test]
+    fn test_circuit_lookup() {
+        type F = <Circuit as Environment>::BaseField;
+
+        // Construct a lookup table for the XOR of two 2-bit values.
+        let xor = || {
+            (0u64..4)
+                .flat_map(|a| (0u64..4).map(move |b| [F::from(a), F::from(b), F::from(a ^ b)]))
+                .collect::<LookupTable<F>>()
+        };
+        let table_index = Circuit::add_lookup_table("xor_2", xor);
+        // Registering a table with the same name returns the existing index.
+        assert_eq!(table_index, Circuit::add_lookup_table("xor_2", || unreachable!()));
+
+        type ConsoleField = snarkvm_console_types::Field<<Circuit as Environment>::Network>;
+        let new_field = |value: u64| Field::<Circuit>::new(Mode::Private, ConsoleField::from_u64(value));
+
+        Circuit::scope("test_circuit_lookup", || {
+            Circuit::enforce_lookup(table_index, || (new_field(1), new_field(2), new_field(3)));
+            Circuit::enforce_lookup(table_index, || (new_field(3), new_field(3), new_field(0)));
+            assert_eq!(2, Circuit::num_constraints_in_scope());
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+        assert_eq!(2, Circuit::num_constraints());
+        assert!(Circuit::is_satisfied());
+
+        // Lookups with constant terms are checked directly.
+        let new_constant = |value: u64| Field::<Circuit>::new(Mode::Constant, ConsoleField::from_u64(value));
+        Circuit::enforce_lookup(table_index, || (new_constant(2), new_constant(1), new_constant(3)));
+        assert_eq!(2, Circuit::num_constraints());
+
+        // A row outside of the table is not satisfied.
+        Circuit::enforce_lookup(table_index, || (new_field(1), new_field(1), new_field(1)));
+        assert_eq!(3, Circuit::num_constraints());
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
 }
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{witness_mode, Assignment, Inject, LinearCombination, Mode, Variable, R1CS};
+use crate::{witness_mode, Assignment, Inject, LinearCombination, LookupTable, Mode, Variable, R1CS};
 use snarkvm_curves::AffineCurve;
 use snarkvm_fields::traits::*;
 
@@ -67,6 +67,20 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         B: Into<LinearCombination<Self::BaseField>>,
         C: Into<LinearCombination<Self::BaseField>>;
 
+    /// Registers the lookup table with the given name, and returns its index.
+    /// The table is only constructed if no table with the given name has been registered yet.
+    fn add_lookup_table<S: Into<String>, Fn>(name: S, table: Fn) -> usize
+    where
+        Fn: FnOnce() -> LookupTable<Self::BaseField>;
+
+    /// Adds one lookup constraint enforcing that `(A, B, C)` is a row of the lookup table at `table_index`.
+    fn enforce_lookup<Fn, A, B, C>(table_index: usize, lookup: Fn)
+    where
+        Fn: FnOnce() -> (A, B, C),
+        A: Into<LinearCombination<Self::BaseField>>,
+        B: Into<LinearCombination<Self::BaseField>>,
+        C: Into<LinearCombination<Self::BaseField>>;
+
     /// Adds one constraint enforcing that the given boolean is `true`.
     fn assert<Boolean: Into<LinearCombination<Self::BaseField>>>(boolean: Boolean) {
         Self::enforce(|| (boolean, Self::one(), Self::one()))
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::Index;
+use crate::{Index, LookupTable};
 use snarkvm_fields::PrimeField;
 
 use indexmap::IndexMap;
@@ -88,6 +88,8 @@ pub struct Assignment<F: PrimeField> {
     public: Arc<[(Index, F)]>,
     private: Arc<[(Index, F)]>,
     constraints: Arc<[(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>)]>,
+    lookup_tables: Arc<[LookupTable<F>]>,
+    lookups: Arc<[(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>, usize)]>,
 }
 
 impl<F: PrimeField> From<crate::R1CS<F>> for Assignment<F> {
@@ -104,6 +106,11 @@ impl<F: PrimeField> From<crate::R1CS<F>> for Assignment<F> {
                 let (a, b, c) = constraint.to_terms();
                 (a.into(), b.into(), c.into())
             })),
+            lookup_tables: FromIterator::from_iter(r1cs.to_lookup_tables().iter().cloned()),
+            lookups: FromIterator::from_iter(r1cs.to_lookups().iter().map(|lookup| {
+                let (a, b, c) = lookup.to_terms();
+                (a.into(), b.into(), c.into(), lookup.table_index())
+            })),
         }
     }
 }
@@ -124,6 +131,16 @@ impl<F: PrimeField> Assignment<F> {
         &self.constraints
     }
 
+    /// Returns the lookup tables of the assignment.
+    pub const fn lookup_tables(&self) -> &Arc<[LookupTable<F>]> {
+        &self.lookup_tables
+    }
+
+    /// Returns the lookups of the assignment.
+    pub const fn lookups(&self) -> &Arc<[(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>, usize)]> {
+        &self.lookups
+    }
+
     /// Returns the number of public variables in the assignment.
     pub fn num_public(&self) -> u64 {
         self.public.len() as u64
@@ -134,15 +151,17 @@ impl<F: PrimeField> Assignment<F> {
         self.private.len() as u64
     }
 
-    /// Returns the number of constraints in the assignment.
+    /// Returns the number of constraints in the assignment, including lookups.
     pub fn num_constraints(&self) -> u64 {
-        self.constraints.len() as u64
+        (self.constraints.len() + self.lookups.len()) as u64
     }
 
     /// Returns the number of nonzeros in the assignment.
     pub fn num_nonzeros(&self) -> (u64, u64, u64) {
         self.constraints
             .iter()
+            .map(|(a, b, c)| (a, b, c))
+            .chain(self.lookups.iter().map(|(a, b, c, _)| (a, b, c)))
             .map(|(a, b, c)| (a.num_nonzeros(), b.num_nonzeros(), c.num_nonzeros()))
             .fold((0, 0, 0), |(a, b, c), (x, y, z)| (a.saturating_add(x), b.saturating_add(y), c.saturating_add(z)))
     }
@@ -205,54 +224,54 @@ impl<F: PrimeField> snarkvm_algorithms::r1cs::ConstraintSynthesizer<F> for Assig
             assert!(result.is_none(), "Overwrote an existing private variable in the converter");
         }
 
-        // Enforce all of the constraints.
-        for (i, (a, b, c)) in self.constraints.iter().enumerate() {
-            // Converts terms from one linear combination in the first system to the second system.
-            let convert_linear_combination = |lc: &AssignmentLC<F>| -> snarkvm_algorithms::r1cs::LinearCombination<F> {
-                // Initialize a linear combination for the second system.
-                let mut linear_combination = snarkvm_algorithms::r1cs::LinearCombination::<F>::zero();
-
-                // Process every term in the linear combination.
-                for (variable, coefficient) in lc.terms.iter() {
-                    match variable {
-                        AssignmentVariable::Constant(_) => {
-                            unreachable!(
-                                "Failed during constraint translation. The first system by definition cannot have constant variables in the terms"
-                            )
-                        }
-                        AssignmentVariable::Public(index) => {
-                            let gadget = converter.public.get(index).unwrap();
-                            assert_eq!(
-                                snarkvm_algorithms::r1cs::Index::Public(*index as usize),
-                                gadget.get_unchecked(),
-                                "Failed during constraint translation. The public variable in the second system must match the first system (with an off-by-1 for the public case)"
-                            );
-                            linear_combination += (*coefficient, *gadget);
-                        }
-                        AssignmentVariable::Private(index) => {
-                            let gadget = converter.private.get(index).unwrap();
-                            assert_eq!(
-                                snarkvm_algorithms::r1cs::Index::Private(*index as usize),
-                                gadget.get_unchecked(),
-                                "Failed during constraint translation. The private variable in the second system must match the first system"
-                            );
-                            linear_combination += (*coefficient, *gadget);
-                        }
+        // Converts terms from one linear combination in the first system to the second system.
+        let convert_linear_combination = |lc: &AssignmentLC<F>| -> snarkvm_algorithms::r1cs::LinearCombination<F> {
+            // Initialize a linear combination for the second system.
+            let mut linear_combination = snarkvm_algorithms::r1cs::LinearCombination::<F>::zero();
+
+            // Process every term in the linear combination.
+            for (variable, coefficient) in lc.terms.iter() {
+                match variable {
+                    AssignmentVariable::Constant(_) => {
+                        unreachable!(
+                            "Failed during constraint translation. The first system by definition cannot have constant variables in the terms"
+                        )
+                    }
+                    AssignmentVariable::Public(index) => {
+                        let gadget = converter.public.get(index).unwrap();
+                        assert_eq!(
+                            snarkvm_algorithms::r1cs::Index::Public(*index as usize),
+                            gadget.get_unchecked(),
+                            "Failed during constraint translation. The public variable in the second system must match the first system (with an off-by-1 for the public case)"
+                        );
+                        linear_combination += (*coefficient, *gadget);
+                    }
+                    AssignmentVariable::Private(index) => {
+                        let gadget = converter.private.get(index).unwrap();
+                        assert_eq!(
+                            snarkvm_algorithms::r1cs::Index::Private(*index as usize),
+                            gadget.get_unchecked(),
+                            "Failed during constraint translation. The private variable in the second system must match the first system"
+                        );
+                        linear_combination += (*coefficient, *gadget);
                     }
                 }
+            }
 
-                // Finally, add the accumulated constant value to the linear combination.
-                if !lc.constant.is_zero() {
-                    linear_combination += (
-                        lc.constant,
-                        snarkvm_algorithms::r1cs::Variable::new_unchecked(snarkvm_algorithms::r1cs::Index::Public(0)),
-                    );
-                }
+            // Finally, add the accumulated constant value to the linear combination.
+            if !lc.constant.is_zero() {
+                linear_combination += (
+                    lc.constant,
+                    snarkvm_algorithms::r1cs::Variable::new_unchecked(snarkvm_algorithms::r1cs::Index::Public(0)),
+                );
+            }
 
-                // Return the linear combination of the second system.
-                linear_combination
-            };
+            // Return the linear combination of the second system.
+            linear_combination
+        };
 
+        // Enforce all of the constraints.
+        for (i, (a, b, c)) in self.constraints.iter().enumerate() {
             cs.enforce(
                 || format!("Constraint {i}"),
                 |lc| lc + convert_linear_combination(a),
@@ -261,6 +280,22 @@ impl<F: PrimeField> snarkvm_algorithms::r1cs::ConstraintSynthesizer<F> for Assig
             );
         }
 
+        // Register all of the lookup tables.
+        for table in self.lookup_tables.iter() {
+            cs.add_lookup_table(table.clone());
+        }
+
+        // Enforce all of the lookups.
+        for (i, (a, b, c, table_index)) in self.lookups.iter().enumerate() {
+            cs.enforce_lookup(
+                || format!("Lookup {i}"),
+                |lc| lc + convert_linear_combination(a),
+                |lc| lc + convert_linear_combination(b),
+                |lc| lc + convert_linear_combination(c),
+                *table_index,
+            );
+        }
+
         // Ensure the given `cs` matches in size with the first system.
         assert_eq!(self.num_public(), cs.num_public_variables() as u64);
         assert_eq!(self.num_private(), cs.num_private_variables() as u64);
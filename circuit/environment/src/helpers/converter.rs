@@ -106,59 +106,56 @@ impl<F: PrimeField> R1CS<F> {
             }
         }
 
-        // Enforce all of the constraints.
-        for (i, constraint) in self.to_constraints().iter().enumerate() {
-            // Converts terms from one linear combination in the first system to the second system.
-            let convert_linear_combination =
-                |lc: &LinearCombination<F>| -> snarkvm_algorithms::r1cs::LinearCombination<F> {
-                    // Initialize a linear combination for the second system.
-                    let mut linear_combination = snarkvm_algorithms::r1cs::LinearCombination::<F>::zero();
-
-                    // Process every term in the linear combination.
-                    for (variable, coefficient) in lc.to_terms() {
-                        match variable {
-                            Variable::Constant(_) => {
-                                unreachable!(
-                                    "Failed during constraint translation. The first system by definition cannot have constant variables in the terms"
-                                )
-                            }
-                            Variable::Public(index_value) => {
-                                let (index, _value) = index_value.as_ref();
-                                let gadget = converter.public.get(index).unwrap();
-                                assert_eq!(
-                                    snarkvm_algorithms::r1cs::Index::Public((index + 1) as usize),
-                                    gadget.get_unchecked(),
-                                    "Failed during constraint translation. The public variable in the second system must match the first system (with an off-by-1 for the public case)"
-                                );
-                                linear_combination += (*coefficient, *gadget);
-                            }
-                            Variable::Private(index_value) => {
-                                let (index, _value) = index_value.as_ref();
-                                let gadget = converter.private.get(index).unwrap();
-                                assert_eq!(
-                                    snarkvm_algorithms::r1cs::Index::Private(*index as usize),
-                                    gadget.get_unchecked(),
-                                    "Failed during constraint translation. The private variable in the second system must match the first system"
-                                );
-                                linear_combination += (*coefficient, *gadget);
-                            }
-                        }
+        // Converts terms from one linear combination in the first system to the second system.
+        let convert_linear_combination = |lc: &LinearCombination<F>| -> snarkvm_algorithms::r1cs::LinearCombination<F> {
+            // Initialize a linear combination for the second system.
+            let mut linear_combination = snarkvm_algorithms::r1cs::LinearCombination::<F>::zero();
+
+            // Process every term in the linear combination.
+            for (variable, coefficient) in lc.to_terms() {
+                match variable {
+                    Variable::Constant(_) => {
+                        unreachable!(
+                            "Failed during constraint translation. The first system by definition cannot have constant variables in the terms"
+                        )
                     }
-
-                    // Finally, add the accumulated constant value to the linear combination.
-                    if !lc.to_constant().is_zero() {
-                        linear_combination += (
-                            lc.to_constant(),
-                            snarkvm_algorithms::r1cs::Variable::new_unchecked(snarkvm_algorithms::r1cs::Index::Public(
-                                0,
-                            )),
+                    Variable::Public(index_value) => {
+                        let (index, _value) = index_value.as_ref();
+                        let gadget = converter.public.get(index).unwrap();
+                        assert_eq!(
+                            snarkvm_algorithms::r1cs::Index::Public((index + 1) as usize),
+                            gadget.get_unchecked(),
+                            "Failed during constraint translation. The public variable in the second system must match the first system (with an off-by-1 for the public case)"
+                        );
+                        linear_combination += (*coefficient, *gadget);
+                    }
+                    Variable::Private(index_value) => {
+                        let (index, _value) = index_value.as_ref();
+                        let gadget = converter.private.get(index).unwrap();
+                        assert_eq!(
+                            snarkvm_algorithms::r1cs::Index::Private(*index as usize),
+                            gadget.get_unchecked(),
+                            "Failed during constraint translation. The private variable in the second system must match the first system"
                         );
+                        linear_combination += (*coefficient, *gadget);
                     }
+                }
+            }
 
-                    // Return the linear combination of the second system.
-                    linear_combination
-                };
+            // Finally, add the accumulated constant value to the linear combination.
+            if !lc.to_constant().is_zero() {
+                linear_combination += (
+                    lc.to_constant(),
+                    snarkvm_algorithms::r1cs::Variable::new_unchecked(snarkvm_algorithms::r1cs::Index::Public(0)),
+                );
+            }
+
+            // Return the linear combination of the second system.
+            linear_combination
+        };
 
+        // Enforce all of the constraints.
+        for (i, constraint) in self.to_constraints().iter().enumerate() {
             let (a, b, c) = constraint.to_terms();
 
             cs.enforce(
@@ -169,6 +166,24 @@ impl<F: PrimeField> R1CS<F> {
             );
         }
 
+        // Register all of the lookup tables.
+        for table in self.to_lookup_tables() {
+            cs.add_lookup_table(table.clone());
+        }
+
+        // Enforce all of the lookups.
+        for (i, lookup) in self.to_lookups().iter().enumerate() {
+            let (a, b, c) = lookup.to_terms();
+
+            cs.enforce_lookup(
+                || format!("Lookup {i}"),
+                |lc| lc + convert_linear_combination(a),
+                |lc| lc + convert_linear_combination(b),
+                |lc| lc + convert_linear_combination(c),
+                lookup.table_index(),
+            );
+        }
+
         // Ensure the given `cs` matches in size with the first system.
         assert_eq!(self.num_public(), cs.num_public_variables() as u64);
         assert_eq!(self.num_private(), cs.num_private_variables() as u64);
@@ -223,6 +238,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_constraint_converter_with_lookups() {
+        type F = <Circuit as Environment>::BaseField;
+        type ConsoleField = snarkvm_console_types::Field<<Circuit as Environment>::Network>;
+
+        let _candidate_output = create_example_circuit::<Circuit>();
+
+        // Enforce that each value is a byte.
+        let table_index =
+            Circuit::add_lookup_table("u8", || (0u64..256).map(|i| [F::from(i), F::zero(), F::zero()]).collect());
+        for value in [0u64, 17, 255] {
+            let byte = Field::<Circuit>::new(Mode::Private, ConsoleField::from_u64(value));
+            Circuit::enforce_lookup(table_index, || (byte, Circuit::zero(), Circuit::zero()));
+        }
+
+        let mut cs = snarkvm_algorithms::r1cs::TestConstraintSystem::new();
+        Circuit.generate_constraints(&mut cs).unwrap();
+        {
+            use snarkvm_algorithms::r1cs::ConstraintSystem;
+            assert_eq!(Circuit::num_public(), cs.num_public_variables() as u64);
+            assert_eq!(Circuit::num_private(), cs.num_private_variables() as u64);
+            assert_eq!(Circuit::num_constraints(), cs.num_constraints() as u64);
+            assert!(cs.is_satisfied());
+        }
+    }
+
     #[test]
     fn test_varuna() {
         let _candidate_output = create_example_circuit::<Circuit>();
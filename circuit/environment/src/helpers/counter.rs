@@ -21,11 +21,12 @@ use std::{mem, rc::Rc};
 pub(crate) struct Counter<F: PrimeField> {
     scope: Scope,
     constraints: Vec<Rc<Constraint<F>>>,
+    lookups: Vec<Rc<Lookup<F>>>,
     constants: u64,
     public: u64,
     private: u64,
     nonzeros: (u64, u64, u64),
-    parents: Vec<(Scope, Vec<Rc<Constraint<F>>>, u64, u64, u64, (u64, u64, u64))>,
+    parents: Vec<(Scope, Vec<Rc<Constraint<F>>>, Vec<Rc<Lookup<F>>>, u64, u64, u64, (u64, u64, u64))>,
 }
 
 impl<F: PrimeField> Counter<F> {
@@ -45,6 +46,7 @@ impl<F: PrimeField> Counter<F> {
                 self.parents.push((
                     self.scope.clone(),
                     mem::take(&mut self.constraints),
+                    mem::take(&mut self.lookups),
                     self.constants,
                     self.public,
                     self.private,
@@ -74,9 +76,10 @@ impl<F: PrimeField> Counter<F> {
         // Ensure the current scope is the last pushed scope.
         match current_scope == name.into() {
             true => {
-                if let Some((scope, constraints, constants, public, private, nonzeros)) = self.parents.pop() {
+                if let Some((scope, constraints, lookups, constants, public, private, nonzeros)) = self.parents.pop() {
                     self.scope = scope;
                     self.constraints = constraints;
+                    self.lookups = lookups;
                     self.constants = constants;
                     self.public = public;
                     self.private = private;
@@ -101,9 +104,20 @@ impl<F: PrimeField> Counter<F> {
         self.constraints.push(constraint);
     }
 
+    /// Increments the number of constraints by 1, for the given lookup.
+    pub(crate) fn add_lookup(&mut self, lookup: Rc<Lookup<F>>) {
+        let (a_nonzeros, b_nonzeros, c_nonzeros) = lookup.num_nonzeros();
+        self.nonzeros.0 += a_nonzeros;
+        self.nonzeros.1 += b_nonzeros;
+        self.nonzeros.2 += c_nonzeros;
+
+        self.lookups.push(lookup);
+    }
+
     /// Returns `true` if all constraints in the scope are satisfied.
-    pub(crate) fn is_satisfied_in_scope(&self) -> bool {
+    pub(crate) fn is_satisfied_in_scope(&self, tables: &[LookupTable<F>]) -> bool {
         self.constraints.iter().all(|constraint| constraint.is_satisfied())
+            && self.lookups.iter().all(|lookup| lookup.is_satisfied(tables))
     }
 
     /// Returns the current scope.
@@ -143,7 +157,7 @@ impl<F: PrimeField> Counter<F> {
 
     /// Returns the number of constraints in scope.
     pub(crate) fn num_constraints_in_scope(&self) -> u64 {
-        self.constraints.len() as u64
+        (self.constraints.len() + self.lookups.len()) as u64
     }
 
     /// Returns the number of nonzeros in scope.
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exporters and importers for the iden3 `.r1cs` and `.wtns` binary formats, and their JSON forms.
+//!
+//! The wires of an exported circuit are ordered as the constant `1`, followed by the public variables,
+//! followed by the private variables. As the environment does not distinguish private inputs from
+//! intermediate variables, all private variables are exported as internal wires.
+
+mod r1cs_file;
+pub use r1cs_file::*;
+
+mod witness_file;
+pub use witness_file::*;
+
+use crate::prelude::*;
+use console::prelude::anyhow;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{biginteger::BigInteger, FromBytes, ToBytes};
+
+use std::io::Read;
+
+/// Returns the number of bytes used to encode a field element.
+fn field_size_in_bytes<F: PrimeField>() -> usize {
+    F::BigInteger::NUM_LIMBS * 8
+}
+
+/// Writes the given field element in little-endian form.
+fn write_field<F: PrimeField>(field: &F, bytes: &mut Vec<u8>) -> Result<()> {
+    Ok(field.to_bigint().write_le(bytes)?)
+}
+
+/// Reads a field element in little-endian form, ensuring it is less than the modulus.
+fn read_field<F: PrimeField>(reader: &mut &[u8]) -> Result<F> {
+    let bigint = F::BigInteger::read_le(reader)?;
+    F::from_bigint(bigint).ok_or_else(|| anyhow!("Invalid field element: {bigint} exceeds the modulus"))
+}
+
+/// Writes the header of a file with the given magic string, version, and sections.
+fn write_sections(magic: &[u8; 4], version: u32, sections: &[(u32, Vec<u8>)]) -> Vec<u8> {
+    let size = 12 + sections.iter().map(|(_, section)| 12 + section.len()).sum::<usize>();
+    let mut bytes = Vec::with_capacity(size);
+    bytes.extend_from_slice(magic);
+    bytes.extend_from_slice(&version.to_le_bytes());
+    bytes.extend_from_slice(&(sections.len() as u32).to_le_bytes());
+    for (section_type, section) in sections {
+        bytes.extend_from_slice(&section_type.to_le_bytes());
+        bytes.extend_from_slice(&(section.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(section);
+    }
+    bytes
+}
+
+/// Reads the sections of a file with the given magic string and version, in any order.
+fn read_sections<'a>(bytes: &'a [u8], magic: &[u8; 4], version: u32) -> Result<Vec<(u32, &'a [u8])>> {
+    let mut reader = bytes;
+    ensure!(read_bytes::<4>(&mut reader)? == *magic, "Invalid magic string, expected '{}'", magic.escape_ascii());
+    let candidate_version = read_u32(&mut reader)?;
+    ensure!(candidate_version == version, "Unsupported version {candidate_version}, expected {version}");
+
+    let num_sections = read_u32(&mut reader)?;
+    let mut sections = Vec::with_capacity(num_sections.min(16) as usize);
+    for _ in 0..num_sections {
+        let section_type = read_u32(&mut reader)?;
+        let size = usize::try_from(read_u64(&mut reader)?)?;
+        ensure!(size <= reader.len(), "Section {section_type} is truncated");
+        let (section, remaining) = reader.split_at(size);
+        sections.push((section_type, section));
+        reader = remaining;
+    }
+    ensure!(reader.is_empty(), "Found {} trailing bytes", reader.len());
+    Ok(sections)
+}
+
+/// Returns the section of the given type, ensuring it is unique.
+fn find_section<'a>(sections: &[(u32, &'a [u8])], section_type: u32) -> Result<&'a [u8]> {
+    let mut candidates = sections.iter().filter(|(candidate, _)| *candidate == section_type);
+    match (candidates.next(), candidates.next()) {
+        (Some((_, section)), None) => Ok(section),
+        (None, _) => bail!("Missing section {section_type}"),
+        (Some(_), Some(_)) => bail!("Found duplicate sections of type {section_type}"),
+    }
+}
+
+/// Reads the field size and prime of a header, ensuring they match the given field.
+fn read_prime<F: PrimeField>(reader: &mut &[u8]) -> Result<()> {
+    let field_size = read_u32(reader)? as usize;
+    ensure!(field_size == field_size_in_bytes::<F>(), "Unsupported field size of {field_size} bytes");
+    let prime = F::BigInteger::read_le(&mut *reader)?;
+    ensure!(prime == F::modulus(), "Mismatching prime {prime}, expected {}", F::modulus());
+    Ok(())
+}
+
+fn read_bytes<const N: usize>(reader: &mut &[u8]) -> Result<[u8; N]> {
+    let mut bytes = [0u8; N];
+    reader.read_exact(&mut bytes).map_err(|_| anyhow!("Unexpected end of input"))?;
+    Ok(bytes)
+}
+
+fn read_u32(reader: &mut &[u8]) -> Result<u32> {
+    Ok(u32::from_le_bytes(read_bytes(reader)?))
+}
+
+fn read_u64(reader: &mut &[u8]) -> Result<u64> {
+    Ok(u64::from_le_bytes(read_bytes(reader)?))
+}
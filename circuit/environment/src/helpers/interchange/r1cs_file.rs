@@ -0,0 +1,363 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{Assignment, AssignmentLC, AssignmentVariable};
+
+use serde_json::{json, Map, Value};
+
+/// A linear combination, as pairs of wire indices and coefficients.
+pub type R1CSLinearCombination<F> = Vec<(u32, F)>;
+
+/// A constraint system in the iden3 `.r1cs` format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct R1CSFile<F: PrimeField> {
+    /// The number of wires, including the constant `1`.
+    pub num_wires: u32,
+    /// The number of public outputs.
+    pub num_public_outputs: u32,
+    /// The number of public inputs.
+    pub num_public_inputs: u32,
+    /// The number of private inputs.
+    pub num_private_inputs: u32,
+    /// The number of labels.
+    pub num_labels: u64,
+    /// The constraints, as `(A, B, C)` such that `A * B == C`.
+    pub constraints: Vec<(R1CSLinearCombination<F>, R1CSLinearCombination<F>, R1CSLinearCombination<F>)>,
+    /// The label of each wire.
+    pub wire_to_label: Vec<u64>,
+}
+
+impl<F: PrimeField> R1CSFile<F> {
+    /// The magic string of the `.r1cs` format.
+    const MAGIC: &'static [u8; 4] = b"r1cs";
+    /// The version of the `.r1cs` format.
+    const VERSION: u32 = 1;
+    /// The section type of the header.
+    const HEADER: u32 = 1;
+    /// The section type of the constraints.
+    const CONSTRAINTS: u32 = 2;
+    /// The section type of the wire to label map.
+    const WIRE_TO_LABEL: u32 = 3;
+
+    /// Returns `true` if the given witness satisfies all constraints.
+    pub fn is_satisfied(&self, witness: &[F]) -> bool {
+        if witness.len() != self.num_wires as usize || witness.first() != Some(&F::one()) {
+            return false;
+        }
+        let evaluate = |lc: &R1CSLinearCombination<F>| -> F {
+            lc.iter().map(|(wire, coefficient)| witness[*wire as usize] * coefficient).sum()
+        };
+        self.constraints.iter().all(|(a, b, c)| evaluate(a) * evaluate(b) == evaluate(c))
+    }
+
+    /// Returns the constraint system in the `.r1cs` binary format.
+    pub fn to_bytes_le(&self) -> Result<Vec<u8>> {
+        let mut header = Vec::new();
+        header.extend_from_slice(&(field_size_in_bytes::<F>() as u32).to_le_bytes());
+        F::modulus().write_le(&mut header)?;
+        header.extend_from_slice(&self.num_wires.to_le_bytes());
+        header.extend_from_slice(&self.num_public_outputs.to_le_bytes());
+        header.extend_from_slice(&self.num_public_inputs.to_le_bytes());
+        header.extend_from_slice(&self.num_private_inputs.to_le_bytes());
+        header.extend_from_slice(&self.num_labels.to_le_bytes());
+        header.extend_from_slice(&u32::try_from(self.constraints.len())?.to_le_bytes());
+
+        let mut constraints = Vec::new();
+        for (a, b, c) in &self.constraints {
+            for lc in [a, b, c] {
+                constraints.extend_from_slice(&u32::try_from(lc.len())?.to_le_bytes());
+                for (wire, coefficient) in lc {
+                    constraints.extend_from_slice(&wire.to_le_bytes());
+                    write_field(coefficient, &mut constraints)?;
+                }
+            }
+        }
+
+        let wire_to_label = self.wire_to_label.iter().flat_map(|label| label.to_le_bytes()).collect();
+
+        Ok(write_sections(Self::MAGIC, Self::VERSION, &[
+            (Self::HEADER, header),
+            (Self::CONSTRAINTS, constraints),
+            (Self::WIRE_TO_LABEL, wire_to_label),
+        ]))
+    }
+
+    /// Reads a constraint system from the `.r1cs` binary format.
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<Self> {
+        let sections = read_sections(bytes, Self::MAGIC, Self::VERSION)?;
+
+        // Read the header.
+        let mut header = find_section(&sections, Self::HEADER)?;
+        read_prime::<F>(&mut header)?;
+        let num_wires = read_u32(&mut header)?;
+        let num_public_outputs = read_u32(&mut header)?;
+        let num_public_inputs = read_u32(&mut header)?;
+        let num_private_inputs = read_u32(&mut header)?;
+        let num_labels = read_u64(&mut header)?;
+        let num_constraints = read_u32(&mut header)?;
+        ensure!(header.is_empty(), "Found {} trailing bytes in the header", header.len());
+        ensure!(num_wires > 0, "The constraint system must contain the constant wire");
+
+        // Read the constraints.
+        let mut reader = find_section(&sections, Self::CONSTRAINTS)?;
+        let mut read_linear_combination = || -> Result<R1CSLinearCombination<F>> {
+            let num_terms = read_u32(&mut reader)?;
+            (0..num_terms)
+                .map(|_| {
+                    let wire = read_u32(&mut reader)?;
+                    ensure!(wire < num_wires, "Wire {wire} is out of bounds");
+                    Ok((wire, read_field(&mut reader)?))
+                })
+                .collect()
+        };
+        let constraints = (0..num_constraints)
+            .map(|_| Ok((read_linear_combination()?, read_linear_combination()?, read_linear_combination()?)))
+            .collect::<Result<Vec<_>>>()?;
+        ensure!(reader.is_empty(), "Found {} trailing bytes in the constraints", reader.len());
+
+        // Read the wire to label map, if it exists.
+        let wire_to_label = match sections.iter().any(|(section_type, _)| *section_type == Self::WIRE_TO_LABEL) {
+            true => {
+                let mut reader = find_section(&sections, Self::WIRE_TO_LABEL)?;
+                let labels = (0..num_wires).map(|_| read_u64(&mut reader)).collect::<Result<Vec<_>>>()?;
+                ensure!(reader.is_empty(), "Found {} trailing bytes in the wire to label map", reader.len());
+                labels
+            }
+            false => vec![],
+        };
+
+        Ok(Self {
+            num_wires,
+            num_public_outputs,
+            num_public_inputs,
+            num_private_inputs,
+            num_labels,
+            constraints,
+            wire_to_label,
+        })
+    }
+
+    /// Returns the constraint system in the JSON form of `snarkjs`.
+    pub fn to_json(&self) -> Value {
+        let to_json = |lc: &R1CSLinearCombination<F>| -> Value {
+            Value::Object(
+                lc.iter().map(|(wire, coefficient)| (wire.to_string(), json!(coefficient.to_string()))).collect(),
+            )
+        };
+        let constraints =
+            self.constraints.iter().map(|(a, b, c)| json!([to_json(a), to_json(b), to_json(c)])).collect::<Vec<_>>();
+        json!({
+            "n8": field_size_in_bytes::<F>(),
+            "prime": F::modulus().to_string(),
+            "nVars": self.num_wires,
+            "nOutputs": self.num_public_outputs,
+            "nPubInputs": self.num_public_inputs,
+            "nPrvInputs": self.num_private_inputs,
+            "nLabels": self.num_labels,
+            "nConstraints": self.constraints.len(),
+            "constraints": constraints,
+            "map": self.wire_to_label,
+        })
+    }
+
+    /// Reads a constraint system from the JSON form of `snarkjs`.
+    pub fn from_json(json: &Value) -> Result<Self> {
+        let get_u64 = |key: &str| json[key].as_u64().ok_or_else(|| anyhow!("Missing or invalid '{key}'"));
+
+        ensure!(json["prime"].as_str() == Some(&F::modulus().to_string()), "Mismatching prime");
+        let num_wires = u32::try_from(get_u64("nVars")?)?;
+
+        let from_json = |lc: &Value| -> Result<R1CSLinearCombination<F>> {
+            let lc: &Map<String, Value> = lc.as_object().ok_or_else(|| anyhow!("Invalid linear combination"))?;
+            lc.iter()
+                .map(|(wire, coefficient)| {
+                    let wire = wire.parse::<u32>()?;
+                    ensure!(wire < num_wires, "Wire {wire} is out of bounds");
+                    let coefficient = coefficient.as_str().ok_or_else(|| anyhow!("Invalid coefficient"))?;
+                    Ok((wire, F::from_str(coefficient).map_err(|_| anyhow!("Invalid coefficient '{coefficient}'"))?))
+                })
+                .collect()
+        };
+        let constraints = json["constraints"]
+            .as_array()
+            .ok_or_else(|| anyhow!("Missing or invalid 'constraints'"))?
+            .iter()
+            .map(|constraint| match constraint.as_array().map(|lcs| lcs.as_slice()) {
+                Some([a, b, c]) => Ok((from_json(a)?, from_json(b)?, from_json(c)?)),
+                _ => bail!("Invalid constraint"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let wire_to_label = match json.get("map") {
+            Some(map) => map
+                .as_array()
+                .ok_or_else(|| anyhow!("Invalid 'map'"))?
+                .iter()
+                .map(|label| label.as_u64().ok_or_else(|| anyhow!("Invalid label")))
+                .collect::<Result<Vec<_>>>()?,
+            None => vec![],
+        };
+
+        Ok(Self {
+            num_wires,
+            num_public_outputs: u32::try_from(get_u64("nOutputs")?)?,
+            num_public_inputs: u32::try_from(get_u64("nPubInputs")?)?,
+            num_private_inputs: u32::try_from(get_u64("nPrvInputs")?)?,
+            num_labels: get_u64("nLabels")?,
+            constraints,
+            wire_to_label,
+        })
+    }
+}
+
+impl<F: PrimeField> TryFrom<&Assignment<F>> for R1CSFile<F> {
+    type Error = Error;
+
+    /// Converts an assignment into a constraint system in the `.r1cs` format.
+    fn try_from(assignment: &Assignment<F>) -> Result<Self> {
+        // The `.r1cs` format does not support lookups.
+        ensure!(assignment.lookups().is_empty(), "Circuits with lookups cannot be exported to the '.r1cs' format");
+
+        let num_public = u32::try_from(assignment.num_public())?;
+        let num_wires = u32::try_from(assignment.num_public() + assignment.num_private())?;
+
+        // Converts a linear combination into one over wires, where the constant term is on wire 0.
+        let convert = |lc: &AssignmentLC<F>| -> Result<R1CSLinearCombination<F>> {
+            let mut terms = Vec::with_capacity(lc.terms().len() + 1);
+            if !lc.constant().is_zero() {
+                terms.push((0, lc.constant()));
+            }
+            for (variable, coefficient) in lc.terms() {
+                let wire = match variable {
+                    AssignmentVariable::Constant(_) => bail!("Linear combinations cannot have constant variables"),
+                    AssignmentVariable::Public(index) => u32::try_from(*index)?,
+                    AssignmentVariable::Private(index) => num_public + u32::try_from(*index)?,
+                };
+                terms.push((wire, *coefficient));
+            }
+            Ok(terms)
+        };
+        let constraints = assignment
+            .constraints()
+            .iter()
+            .map(|(a, b, c)| Ok((convert(a)?, convert(b)?, convert(c)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            num_wires,
+            num_public_outputs: 0,
+            num_public_inputs: num_public - 1,
+            num_private_inputs: 0,
+            num_labels: num_wires as u64,
+            constraints,
+            wire_to_label: (0..num_wires as u64).collect(),
+        })
+    }
+}
+
+impl<F: PrimeField> Assignment<F> {
+    /// Returns the constraint system of the assignment in the iden3 `.r1cs` format.
+    pub fn to_r1cs_file(&self) -> Result<R1CSFile<F>> {
+        R1CSFile::try_from(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Circuit, Environment, LinearCombination, R1CSWitness};
+
+    type F = <Circuit as Environment>::BaseField;
+
+    /// Computes `x^3 + x + 5 == y`, where `y` is public.
+    fn sample_assignment() -> Assignment<F> {
+        let x = Circuit::new_variable(Mode::Private, F::from(3u64));
+        let y = Circuit::new_variable(Mode::Public, F::from(35u64));
+        let x_squared = Circuit::new_variable(Mode::Private, F::from(9u64));
+        let x_cubed = Circuit::new_variable(Mode::Private, F::from(27u64));
+        Circuit::enforce(|| (&x, &x, &x_squared));
+        Circuit::enforce(|| (&x_squared, &x, &x_cubed));
+        Circuit::assert_eq(LinearCombination::from(&x_cubed) + &x + Circuit::one() * F::from(5u64), y);
+        assert!(Circuit::is_satisfied());
+        Circuit::eject_assignment_and_reset()
+    }
+
+    #[test]
+    fn test_r1cs_file() {
+        let assignment = sample_assignment();
+        let r1cs = assignment.to_r1cs_file().unwrap();
+        assert_eq!(r1cs.num_wires as u64, assignment.num_public() + assignment.num_private());
+        assert_eq!(r1cs.num_public_inputs, 1);
+        assert_eq!(r1cs.constraints.len() as u64, assignment.num_constraints());
+
+        let witness = R1CSWitness::from(&assignment);
+        assert!(r1cs.is_satisfied(&witness.values));
+
+        // Ensure an incorrect witness is rejected.
+        let mut invalid_witness = witness.values.clone();
+        invalid_witness[1] += F::one();
+        assert!(!r1cs.is_satisfied(&invalid_witness));
+    }
+
+    #[test]
+    fn test_r1cs_file_bytes() {
+        let r1cs = sample_assignment().to_r1cs_file().unwrap();
+
+        let bytes = r1cs.to_bytes_le().unwrap();
+        assert_eq!(b"r1cs", &bytes[..4]);
+        assert_eq!(r1cs, R1CSFile::from_bytes_le(&bytes).unwrap());
+
+        // Ensure truncated and extended inputs are rejected.
+        assert!(R1CSFile::<F>::from_bytes_le(&bytes[..bytes.len() - 1]).is_err());
+        assert!(R1CSFile::<F>::from_bytes_le(&[&bytes[..], &[0u8]].concat()).is_err());
+        assert!(R1CSFile::<F>::from_bytes_le(b"wtns").is_err());
+    }
+
+    #[test]
+    fn test_r1cs_file_json() {
+        let r1cs = sample_assignment().to_r1cs_file().unwrap();
+
+        let json = r1cs.to_json();
+        assert_eq!(json["nConstraints"], r1cs.constraints.len());
+        assert_eq!(r1cs, R1CSFile::from_json(&json).unwrap());
+        assert_eq!(r1cs, R1CSFile::from_json(&serde_json::from_str(&json.to_string()).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_witness_round_trip() {
+        let assignment = sample_assignment();
+        let r1cs = R1CSFile::from_bytes_le(&assignment.to_r1cs_file().unwrap().to_bytes_le().unwrap()).unwrap();
+        let witness = assignment.to_r1cs_witness();
+
+        // Ensure the imported witnesses match the exported one, and satisfy the imported constraint system.
+        let from_bytes = R1CSWitness::from_bytes_le(&witness.to_bytes_le().unwrap()).unwrap();
+        let from_json = R1CSWitness::from_json(&witness.to_json()).unwrap();
+        for candidate in [from_bytes, from_json] {
+            assert_eq!(witness, candidate);
+            assert!(r1cs.is_satisfied(&candidate.values));
+        }
+    }
+
+    #[test]
+    fn test_r1cs_file_rejects_lookups() {
+        let table_index = Circuit::add_lookup_table("bit", || {
+            [[F::zero(); 3], [F::one(), F::zero(), F::zero()]].into_iter().collect()
+        });
+        let bit = Circuit::new_variable(Mode::Private, F::one());
+        Circuit::enforce_lookup(table_index, || (bit, Circuit::zero(), Circuit::zero()));
+        let assignment = Circuit::eject_assignment_and_reset();
+        assert!(assignment.to_r1cs_file().is_err());
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Assignment;
+
+use serde_json::{json, Value};
+
+/// A witness in the iden3 `.wtns` format, as the values of all wires.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct R1CSWitness<F: PrimeField> {
+    /// The values of the wires, starting with the constant `1`.
+    pub values: Vec<F>,
+}
+
+impl<F: PrimeField> R1CSWitness<F> {
+    /// The magic string of the `.wtns` format.
+    const MAGIC: &'static [u8; 4] = b"wtns";
+    /// The version of the `.wtns` format.
+    const VERSION: u32 = 2;
+    /// The section type of the header.
+    const HEADER: u32 = 1;
+    /// The section type of the values.
+    const VALUES: u32 = 2;
+
+    /// Returns the witness in the `.wtns` binary format.
+    pub fn to_bytes_le(&self) -> Result<Vec<u8>> {
+        let mut header = Vec::new();
+        header.extend_from_slice(&(field_size_in_bytes::<F>() as u32).to_le_bytes());
+        F::modulus().write_le(&mut header)?;
+        header.extend_from_slice(&u32::try_from(self.values.len())?.to_le_bytes());
+
+        let mut values = Vec::with_capacity(self.values.len() * field_size_in_bytes::<F>());
+        for value in &self.values {
+            write_field(value, &mut values)?;
+        }
+
+        Ok(write_sections(Self::MAGIC, Self::VERSION, &[(Self::HEADER, header), (Self::VALUES, values)]))
+    }
+
+    /// Reads a witness from the `.wtns` binary format.
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<Self> {
+        let sections = read_sections(bytes, Self::MAGIC, Self::VERSION)?;
+
+        // Read the header.
+        let mut header = find_section(&sections, Self::HEADER)?;
+        read_prime::<F>(&mut header)?;
+        let num_values = read_u32(&mut header)?;
+        ensure!(header.is_empty(), "Found {} trailing bytes in the header", header.len());
+
+        // Read the values.
+        let mut reader = find_section(&sections, Self::VALUES)?;
+        let values = (0..num_values).map(|_| read_field(&mut reader)).collect::<Result<Vec<_>>>()?;
+        ensure!(reader.is_empty(), "Found {} trailing bytes in the values", reader.len());
+
+        Ok(Self { values })
+    }
+
+    /// Returns the witness in the JSON form of `snarkjs`, as an array of decimal strings.
+    pub fn to_json(&self) -> Value {
+        json!(self.values.iter().map(|value| value.to_string()).collect::<Vec<_>>())
+    }
+
+    /// Reads a witness from the JSON form of `snarkjs`.
+    pub fn from_json(json: &Value) -> Result<Self> {
+        let values = json
+            .as_array()
+            .ok_or_else(|| anyhow!("The witness must be an array"))?
+            .iter()
+            .map(|value| {
+                let value = value.as_str().ok_or_else(|| anyhow!("Invalid witness value"))?;
+                F::from_str(value).map_err(|_| anyhow!("Invalid witness value '{value}'"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { values })
+    }
+}
+
+impl<F: PrimeField> From<&Assignment<F>> for R1CSWitness<F> {
+    /// Converts an assignment into the values of its wires.
+    fn from(assignment: &Assignment<F>) -> Self {
+        let public = assignment.public_inputs().iter().map(|(_, value)| *value);
+        let private = assignment.private_inputs().iter().map(|(_, value)| *value);
+        Self { values: public.chain(private).collect() }
+    }
+}
+
+impl<F: PrimeField> Assignment<F> {
+    /// Returns the witness of the assignment in the iden3 `.wtns` format.
+    pub fn to_r1cs_witness(&self) -> R1CSWitness<F> {
+        R1CSWitness::from(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Circuit, Environment};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    type F = <Circuit as Environment>::BaseField;
+
+    fn sample_witness(num_values: usize) -> R1CSWitness<F> {
+        let rng = &mut TestRng::default();
+        let values = std::iter::once(F::one()).chain((1..num_values).map(|_| F::rand(rng))).collect();
+        R1CSWitness { values }
+    }
+
+    #[test]
+    fn test_witness_bytes() {
+        for num_values in [1, 2, 100] {
+            let witness = sample_witness(num_values);
+            let bytes = witness.to_bytes_le().unwrap();
+            assert_eq!(b"wtns", &bytes[..4]);
+            assert_eq!(witness, R1CSWitness::from_bytes_le(&bytes).unwrap());
+            assert!(R1CSWitness::<F>::from_bytes_le(&bytes[..bytes.len() - 1]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_witness_json() {
+        let witness = sample_witness(100);
+        let json = witness.to_json();
+        assert_eq!(witness, R1CSWitness::from_json(&json).unwrap());
+        assert!(R1CSWitness::<F>::from_json(&json!(["not a field element"])).is_err());
+    }
+
+    #[test]
+    fn test_witness_from_assignment() {
+        let (one, two, three) = (F::one(), F::from(2u64), F::from(3u64));
+        Circuit::new_variable(Mode::Private, three);
+        Circuit::new_variable(Mode::Public, two);
+        let witness = Circuit::eject_assignment_and_reset().to_r1cs_witness();
+        assert_eq!(witness.values, vec![one, two, three]);
+    }
+}
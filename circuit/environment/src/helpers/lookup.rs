@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{prelude::*, *};
+use snarkvm_fields::PrimeField;
+
+pub use snarkvm_algorithms::r1cs::LookupTable;
+
+/// A lookup constraint, enforcing that `(A, B, C)` is a row of the lookup table at the given index.
+#[derive(Clone, Debug)]
+pub struct Lookup<F: PrimeField>(
+    pub(crate) Scope,
+    pub(crate) LinearCombination<F>,
+    pub(crate) LinearCombination<F>,
+    pub(crate) LinearCombination<F>,
+    pub(crate) usize,
+);
+
+impl<F: PrimeField> Lookup<F> {
+    /// Returns the number of non-zero terms required by this lookup.
+    pub(crate) fn num_nonzeros(&self) -> (u64, u64, u64) {
+        let (a, b, c) = (&self.1, &self.2, &self.3);
+        (a.num_nonzeros(), b.num_nonzeros(), c.num_nonzeros())
+    }
+
+    /// Returns `true` if the lookup is satisfied by the given tables.
+    pub(crate) fn is_satisfied(&self, tables: &[LookupTable<F>]) -> bool {
+        let (scope, a, b, c, table_index) = (&self.0, &self.1, &self.2, &self.3, self.4);
+        let row = [a.value(), b.value(), c.value()];
+
+        match tables.get(table_index).map_or(false, |table| table.contains(&row)) {
+            true => true,
+            false => {
+                let [a, b, c] = row;
+                eprintln!("Failed lookup at {scope}:\n\t({a}, {b}, {c}) is not in table {table_index}");
+                false
+            }
+        }
+    }
+
+    /// Returns a reference to the terms `(a, b, c)`.
+    pub fn to_terms(&self) -> (&LinearCombination<F>, &LinearCombination<F>, &LinearCombination<F>) {
+        (&self.1, &self.2, &self.3)
+    }
+
+    /// Returns the index of the lookup table.
+    pub const fn table_index(&self) -> usize {
+        self.4
+    }
+}
+
+impl<F: PrimeField> Display for Lookup<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (scope, a, b, c, table_index) = (&self.0, &self.1, &self.2, &self.3, self.4);
+        write!(f, "Lookup {scope}:\n\t({}, {}, {}) in table {table_index}\n", a.value(), b.value(), c.value())
+    }
+}
@@ -29,9 +29,15 @@ pub use count::*;
 pub(super) mod counter;
 pub(super) use counter::*;
 
+pub mod interchange;
+pub use interchange::*;
+
 pub mod linear_combination;
 pub use linear_combination::*;
 
+pub mod lookup;
+pub use lookup::*;
+
 mod mode;
 pub use mode::*;
 
@@ -13,11 +13,12 @@
 // limitations under the License.
 
 use crate::{
-    helpers::{Constraint, Counter},
+    helpers::{Constraint, Counter, Lookup, LookupTable},
     prelude::*,
 };
 use snarkvm_fields::PrimeField;
 
+use indexmap::IndexMap;
 use std::rc::Rc;
 
 pub type Scope = String;
@@ -28,6 +29,9 @@ pub struct R1CS<F: PrimeField> {
     public: Vec<Variable<F>>,
     private: Vec<Variable<F>>,
     constraints: Vec<Rc<Constraint<F>>>,
+    lookup_tables: Vec<LookupTable<F>>,
+    lookup_table_indices: IndexMap<String, usize>,
+    lookups: Vec<Rc<Lookup<F>>>,
     counter: Counter<F>,
     nonzeros: (u64, u64, u64),
 }
@@ -40,6 +44,9 @@ impl<F: PrimeField> R1CS<F> {
             public: vec![Variable::Public(Rc::new((0u64, F::one())))],
             private: Default::default(),
             constraints: Default::default(),
+            lookup_tables: Default::default(),
+            lookup_table_indices: Default::default(),
+            lookups: Default::default(),
             counter: Default::default(),
             nonzeros: (0, 0, 0),
         }
@@ -91,6 +98,37 @@ impl<F: PrimeField> R1CS<F> {
         self.counter.add_constraint(constraint);
     }
 
+    /// Registers the lookup table with the given name, and returns its index.
+    /// If a table with the given name is already registered, its index is returned instead.
+    pub(crate) fn add_lookup_table<Fn: FnOnce() -> LookupTable<F>>(&mut self, name: String, table: Fn) -> usize {
+        match self.lookup_table_indices.get(&name) {
+            Some(index) => *index,
+            None => {
+                let index = self.lookup_tables.len();
+                self.lookup_tables.push(table());
+                self.lookup_table_indices.insert(name, index);
+                index
+            }
+        }
+    }
+
+    /// Returns the lookup table at the given index, if it exists.
+    pub(crate) fn get_lookup_table(&self, index: usize) -> Option<&LookupTable<F>> {
+        self.lookup_tables.get(index)
+    }
+
+    /// Adds one lookup constraint enforcing that `(A, B, C)` is a row of the lookup table.
+    pub(crate) fn enforce_lookup(&mut self, lookup: Lookup<F>) {
+        let (a_nonzeros, b_nonzeros, c_nonzeros) = lookup.num_nonzeros();
+        self.nonzeros.0 += a_nonzeros;
+        self.nonzeros.1 += b_nonzeros;
+        self.nonzeros.2 += c_nonzeros;
+
+        let lookup = Rc::new(lookup);
+        self.lookups.push(Rc::clone(&lookup));
+        self.counter.add_lookup(lookup);
+    }
+
     /// Returns `true` if all of the constraints are satisfied.
     ///
     /// In addition, when in debug mode, this function also checks that
@@ -102,31 +140,40 @@ impl<F: PrimeField> R1CS<F> {
             return false;
         }
 
+        // Ensure all lookups are satisfied.
+        let lookups_satisfied = self.lookups.iter().all(|lookup| lookup.is_satisfied(&self.lookup_tables));
+        if !lookups_satisfied {
+            return false;
+        }
+
         // In debug mode, ensure all constraints use variables corresponding to the declared variables.
         #[cfg(not(debug_assertions))]
         return true;
         #[cfg(debug_assertions)]
-        self.constraints.iter().all(|constraint| {
-            let (a, b, c) = constraint.to_terms();
-            [a, b, c].into_iter().all(|lc| {
-                lc.to_terms().iter().all(|(variable, _)| match variable {
-                    Variable::Constant(_value) => false, // terms should not contain Constants
-                    Variable::Private(private) => {
-                        let (index, value) = private.as_ref();
-                        self.private.get(*index as usize).map_or_else(|| false, |v| v.value() == *value)
-                    }
-                    Variable::Public(public) => {
-                        let (index, value) = public.as_ref();
-                        self.public.get(*index as usize).map_or_else(|| false, |v| v.value() == *value)
-                    }
+        self.constraints
+            .iter()
+            .map(|constraint| constraint.to_terms())
+            .chain(self.lookups.iter().map(|lookup| lookup.to_terms()))
+            .all(|(a, b, c)| {
+                [a, b, c].into_iter().all(|lc| {
+                    lc.to_terms().iter().all(|(variable, _)| match variable {
+                        Variable::Constant(_value) => false, // terms should not contain Constants
+                        Variable::Private(private) => {
+                            let (index, value) = private.as_ref();
+                            self.private.get(*index as usize).map_or_else(|| false, |v| v.value() == *value)
+                        }
+                        Variable::Public(public) => {
+                            let (index, value) = public.as_ref();
+                            self.public.get(*index as usize).map_or_else(|| false, |v| v.value() == *value)
+                        }
+                    })
                 })
             })
-        })
     }
 
     /// Returns `true` if all constraints in the current scope are satisfied.
     pub(crate) fn is_satisfied_in_scope(&self) -> bool {
-        self.counter.is_satisfied_in_scope()
+        self.counter.is_satisfied_in_scope(&self.lookup_tables)
     }
 
     /// Returns the current scope.
@@ -149,9 +196,14 @@ impl<F: PrimeField> R1CS<F> {
         self.private.len() as u64
     }
 
-    /// Returns the number of constraints in the constraint system.
+    /// Returns the number of constraints in the constraint system, including lookups.
     pub fn num_constraints(&self) -> u64 {
-        self.constraints.len() as u64
+        (self.constraints.len() + self.lookups.len()) as u64
+    }
+
+    /// Returns the number of lookups in the constraint system.
+    pub fn num_lookups(&self) -> u64 {
+        self.lookups.len() as u64
     }
 
     /// Returns the number of nonzeros in the constraint system.
@@ -198,6 +250,16 @@ impl<F: PrimeField> R1CS<F> {
     pub fn to_constraints(&self) -> &Vec<Rc<Constraint<F>>> {
         &self.constraints
     }
+
+    /// Returns the lookup tables in the constraint system.
+    pub fn to_lookup_tables(&self) -> &Vec<LookupTable<F>> {
+        &self.lookup_tables
+    }
+
+    /// Returns the lookups in the constraint system.
+    pub fn to_lookups(&self) -> &Vec<Rc<Lookup<F>>> {
+        &self.lookups
+    }
 }
 
 impl<F: PrimeField> Display for R1CS<F> {
@@ -206,6 +268,9 @@ impl<F: PrimeField> Display for R1CS<F> {
         for constraint in self.to_constraints() {
             output += &constraint.to_string();
         }
+        for lookup in self.to_lookups() {
+            output += &lookup.to_string();
+        }
         output += "\n";
 
         write!(f, "{output}")
@@ -49,6 +49,7 @@ pub mod prelude {
         Count,
         Environment,
         LinearCombination,
+        LookupTable,
         Mode,
         OutputMode,
         Variable,
@@ -59,6 +60,8 @@ pub mod prelude {
             ensure,
             fmt,
             has_duplicates,
+            i256,
+            u256,
             Debug,
             Display,
             Error,
@@ -17,6 +17,7 @@ pub use console::traits::{
     arithmetic::*,
     bitwise::*,
     from_bits::{SizeInBits, SizeInDataBits},
+    string::*,
 };
 
 use crate::BooleanTrait;
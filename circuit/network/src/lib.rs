@@ -85,6 +85,9 @@ pub trait Aleo: Environment {
     /// Returns the BHP hash with an input hasher of 1024-bits.
     fn hash_bhp1024(input: &[Boolean<Self>]) -> Field<Self>;
 
+    /// Returns the BLAKE2s hash with a 256-bit output.
+    fn hash_blake2s(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
+
     /// Returns the Keccak hash with a 256-bit output.
     fn hash_keccak256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
 
@@ -109,6 +112,18 @@ pub trait Aleo: Environment {
     /// Returns the Poseidon hash with an input rate of 8.
     fn hash_psd8(input: &[Field<Self>]) -> Field<Self>;
 
+    /// Returns the Poseidon2 hash with an input rate of 2.
+    fn hash_poseidon2_2(input: &[Field<Self>]) -> Field<Self>;
+
+    /// Returns the Poseidon2 hash with an input rate of 4.
+    fn hash_poseidon2_4(input: &[Field<Self>]) -> Field<Self>;
+
+    /// Returns the Poseidon2 hash with an input rate of 8.
+    fn hash_poseidon2_8(input: &[Field<Self>]) -> Field<Self>;
+
+    /// Returns the SHA-2 hash with a 256-bit output.
+    fn hash_sha256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
 
@@ -154,6 +169,15 @@ pub trait Aleo: Environment {
     /// Returns the Poseidon hash with an input rate of 8 on the affine curve.
     fn hash_to_group_psd8(input: &[Field<Self>]) -> Group<Self>;
 
+    /// Returns the Poseidon2 hash with an input rate of 2 on the affine curve.
+    fn hash_to_group_poseidon2_2(input: &[Field<Self>]) -> Group<Self>;
+
+    /// Returns the Poseidon2 hash with an input rate of 4 on the affine curve.
+    fn hash_to_group_poseidon2_4(input: &[Field<Self>]) -> Group<Self>;
+
+    /// Returns the Poseidon2 hash with an input rate of 8 on the affine curve.
+    fn hash_to_group_poseidon2_8(input: &[Field<Self>]) -> Group<Self>;
+
     /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
     fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Scalar<Self>;
 
@@ -178,4 +202,12 @@ pub trait Aleo: Environment {
         root: &Field<Self>,
         leaf: &Vec<Field<Self>>,
     ) -> Boolean<Self>;
+
+    /// Returns `true` if the given Poseidon2 Merkle path is valid for the given root and leaf.
+    #[allow(clippy::ptr_arg)]
+    fn verify_merkle_path_poseidon2<const DEPTH: u8>(
+        path: &MerklePath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaf: &Vec<Field<Self>>,
+    ) -> Boolean<Self>;
 }
@@ -14,6 +14,7 @@
 
 use crate::Aleo;
 use snarkvm_circuit_algorithms::{
+    Blake2s,
     Commit,
     CommitUncompressed,
     Hash,
@@ -27,8 +28,12 @@ use snarkvm_circuit_algorithms::{
     Pedersen128,
     Pedersen64,
     Poseidon2,
+    Poseidon2_2,
+    Poseidon2_4,
+    Poseidon2_8,
     Poseidon4,
     Poseidon8,
+    Sha256,
     Sha3_256,
     Sha3_384,
     Sha3_512,
@@ -70,6 +75,9 @@ thread_local! {
     /// The BHP hash function, which can take an input of up to 1024 bits.
     static BHP_1024: BHP1024<AleoV0> = BHP1024::<AleoV0>::constant(console::BHP_1024.clone());
 
+    /// The BLAKE2s hash function, which outputs 256 bits.
+    static BLAKE2S: Blake2s<AleoV0> = Blake2s::<AleoV0>::new();
+
     /// The Keccak hash function, which outputs 256 bits.
     static KECCAK_256: Keccak256<AleoV0> = Keccak256::<AleoV0>::new();
     /// The Keccak hash function, which outputs 384 bits.
@@ -89,6 +97,16 @@ thread_local! {
     /// The Poseidon hash function, using a rate of 8.
     static POSEIDON_8: Poseidon8<AleoV0> = Poseidon8::<AleoV0>::constant(console::POSEIDON_8.clone());
 
+    /// The Poseidon2 hash function, using a rate of 2.
+    static POSEIDON2_2: Poseidon2_2<AleoV0> = Poseidon2_2::<AleoV0>::constant(console::POSEIDON2_2.clone());
+    /// The Poseidon2 hash function, using a rate of 4.
+    static POSEIDON2_4: Poseidon2_4<AleoV0> = Poseidon2_4::<AleoV0>::constant(console::POSEIDON2_4.clone());
+    /// The Poseidon2 hash function, using a rate of 8.
+    static POSEIDON2_8: Poseidon2_8<AleoV0> = Poseidon2_8::<AleoV0>::constant(console::POSEIDON2_8.clone());
+
+    /// The SHA-2 hash function, which outputs 256 bits.
+    static SHA256: Sha256<AleoV0> = Sha256::<AleoV0>::new();
+
     /// The SHA-3 hash function, which outputs 256 bits.
     static SHA3_256: Sha3_256<AleoV0> = Sha3_256::<AleoV0>::new();
     /// The SHA-3 hash function, which outputs 384 bits.
@@ -207,6 +225,11 @@ impl Aleo for AleoV0 {
         BHP_1024.with(|bhp| bhp.hash(input))
     }
 
+    /// Returns the BLAKE2s hash with a 256-bit output.
+    fn hash_blake2s(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
+        BLAKE2S.with(|blake2s| blake2s.hash(input))
+    }
+
     /// Returns the Keccak hash with a 256-bit output.
     fn hash_keccak256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
         KECCAK_256.with(|keccak| keccak.hash(input))
@@ -247,6 +270,26 @@ impl Aleo for AleoV0 {
         POSEIDON_8.with(|poseidon| poseidon.hash(input))
     }
 
+    /// Returns the Poseidon2 hash with an input rate of 2.
+    fn hash_poseidon2_2(input: &[Field<Self>]) -> Field<Self> {
+        POSEIDON2_2.with(|poseidon| poseidon.hash(input))
+    }
+
+    /// Returns the Poseidon2 hash with an input rate of 4.
+    fn hash_poseidon2_4(input: &[Field<Self>]) -> Field<Self> {
+        POSEIDON2_4.with(|poseidon| poseidon.hash(input))
+    }
+
+    /// Returns the Poseidon2 hash with an input rate of 8.
+    fn hash_poseidon2_8(input: &[Field<Self>]) -> Field<Self> {
+        POSEIDON2_8.with(|poseidon| poseidon.hash(input))
+    }
+
+    /// Returns the SHA-2 hash with a 256-bit output.
+    fn hash_sha256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
+        SHA256.with(|sha256| sha256.hash(input))
+    }
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
         SHA3_256.with(|sha3| sha3.hash(input))
@@ -322,6 +365,21 @@ impl Aleo for AleoV0 {
         POSEIDON_8.with(|poseidon| poseidon.hash_to_group(input))
     }
 
+    /// Returns the Poseidon2 hash with an input rate of 2 on the affine curve.
+    fn hash_to_group_poseidon2_2(input: &[Field<Self>]) -> Group<Self> {
+        POSEIDON2_2.with(|poseidon| poseidon.hash_to_group(input))
+    }
+
+    /// Returns the Poseidon2 hash with an input rate of 4 on the affine curve.
+    fn hash_to_group_poseidon2_4(input: &[Field<Self>]) -> Group<Self> {
+        POSEIDON2_4.with(|poseidon| poseidon.hash_to_group(input))
+    }
+
+    /// Returns the Poseidon2 hash with an input rate of 8 on the affine curve.
+    fn hash_to_group_poseidon2_8(input: &[Field<Self>]) -> Group<Self> {
+        POSEIDON2_8.with(|poseidon| poseidon.hash_to_group(input))
+    }
+
     /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
     fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Scalar<Self> {
         POSEIDON_2.with(|poseidon| poseidon.hash_to_scalar(input))
@@ -354,6 +412,15 @@ impl Aleo for AleoV0 {
     ) -> Boolean<Self> {
         POSEIDON_4.with(|psd4| POSEIDON_2.with(|psd2| path.verify(psd4, psd2, root, leaf)))
     }
+
+    /// Returns `true` if the given Poseidon2 Merkle path is valid for the given root and leaf.
+    fn verify_merkle_path_poseidon2<const DEPTH: u8>(
+        path: &MerklePath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaf: &Vec<Field<Self>>,
+    ) -> Boolean<Self> {
+        POSEIDON2_4.with(|psd4| POSEIDON2_2.with(|psd2| path.verify(psd4, psd2, root, leaf)))
+    }
 }
 
 impl Environment for AleoV0 {
@@ -401,6 +468,26 @@ impl Environment for AleoV0 {
         E::enforce(constraint)
     }
 
+    /// Registers the lookup table with the given name, and returns its index.
+    /// The table is only constructed if no table with the given name has been registered yet.
+    fn add_lookup_table<S: Into<String>, Fn>(name: S, table: Fn) -> usize
+    where
+        Fn: FnOnce() -> LookupTable<Self::BaseField>,
+    {
+        E::add_lookup_table(name, table)
+    }
+
+    /// Adds one lookup constraint enforcing that `(A, B, C)` is a row of the lookup table at `table_index`.
+    fn enforce_lookup<Fn, A, B, C>(table_index: usize, lookup: Fn)
+    where
+        Fn: FnOnce() -> (A, B, C),
+        A: Into<LinearCombination<Self::BaseField>>,
+        B: Into<LinearCombination<Self::BaseField>>,
+        C: Into<LinearCombination<Self::BaseField>>,
+    {
+        E::enforce_lookup(table_index, lookup)
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool {
         E::is_satisfied()
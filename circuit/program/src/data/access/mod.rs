@@ -41,6 +41,9 @@ impl<A: Aleo> Inject for Access<A> {
         match plaintext {
             Self::Primitive::Member(identifier) => Self::Member(Identifier::new(_m, identifier)),
             Self::Primitive::Index(index) => Self::Index(U32::new(_m, index)),
+            Self::Primitive::Register(locator) => {
+                A::halt(format!("Register access 'r{locator}' must be resolved into an index before injection"))
+            }
         }
     }
 }
@@ -71,10 +74,14 @@ impl<A: Aleo> Parser for Access<A> {
     /// Parses a UTF-8 string into an access.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
-        // Parse the identifier from the string.
-        let (string, access) = console::Access::parse(string)?;
-
-        Ok((string, Access::constant(access)))
+        // Parse the access from the string.
+        // Note: A register access is only resolved into an index at runtime, so it is rejected here.
+        map_res(console::Access::parse, |access| match access {
+            console::Access::Register(locator) => {
+                Err(Error::msg(format!("Register access 'r{locator}' must be resolved into an index before parsing")))
+            }
+            access => Ok(Access::constant(access)),
+        })(string)
     }
 }
 
@@ -120,3 +127,25 @@ impl<A: Aleo> PartialEq for Access<A> {
         self.eject_value() == other.eject_value()
     }
 }
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        // Ensure a member access is parsed.
+        let access = Access::<Circuit>::from_str(".owner")?;
+        assert_eq!(access.eject_value(), console::Access::<CurrentNetwork>::from_str(".owner")?);
+        // Ensure an index access is parsed.
+        let access = Access::<Circuit>::from_str("[1u32]")?;
+        assert_eq!(access.eject_value(), console::Access::<CurrentNetwork>::from_str("[1u32]")?);
+        // Ensure a register access is rejected.
+        assert!(Access::<Circuit>::parse("[r0]").is_err());
+        assert!(Access::<Circuit>::from_str("[r0]").is_err());
+        Ok(())
+    }
+}
@@ -76,11 +76,13 @@ impl<A: Aleo> Literal<A> {
             Self::I32(integer) => cast_integer_to_type(integer, to_type),
             Self::I64(integer) => cast_integer_to_type(integer, to_type),
             Self::I128(integer) => cast_integer_to_type(integer, to_type),
+            Self::I256(integer) => cast_integer_to_type(integer, to_type),
             Self::U8(integer) => cast_integer_to_type(integer, to_type),
             Self::U16(integer) => cast_integer_to_type(integer, to_type),
             Self::U32(integer) => cast_integer_to_type(integer, to_type),
             Self::U64(integer) => cast_integer_to_type(integer, to_type),
             Self::U128(integer) => cast_integer_to_type(integer, to_type),
+            Self::U256(integer) => cast_integer_to_type(integer, to_type),
             Self::Scalar(scalar) => cast_scalar_to_type(scalar, to_type),
             Self::Signature(..) => bail!("Cannot cast a signature literal to another type."),
             Self::String(..) => bail!("Cannot cast a string literal to another type."),
@@ -101,11 +103,13 @@ macro_rules! impl_cast_body {
             LiteralType::I32 => Ok(Literal::I32($input.$cast())),
             LiteralType::I64 => Ok(Literal::I64($input.$cast())),
             LiteralType::I128 => Ok(Literal::I128($input.$cast())),
+            LiteralType::I256 => Ok(Literal::I256($input.$cast())),
             LiteralType::U8 => Ok(Literal::U8($input.$cast())),
             LiteralType::U16 => Ok(Literal::U16($input.$cast())),
             LiteralType::U32 => Ok(Literal::U32($input.$cast())),
             LiteralType::U64 => Ok(Literal::U64($input.$cast())),
             LiteralType::U128 => Ok(Literal::U128($input.$cast())),
+            LiteralType::U256 => Ok(Literal::U256($input.$cast())),
             LiteralType::Scalar => Ok(Literal::Scalar($input.$cast())),
             LiteralType::Signature => {
                 bail!(concat!("Cannot cast a ", stringify!($type_name), " literal to a signature type."))
@@ -39,11 +39,14 @@ impl<E: Environment, I: IntegerType> CastLossy<Boolean<E>> for Integer<E, I> {
 }
 
 impl<E: Environment, I: IntegerType> CastLossy<Field<E>> for Integer<E, I> {
-    /// Casts an `Integer` to a `Field`.
-    /// This is safe because casting from an integer to a field is **always** lossless.
+    /// Casts an `Integer` to a `Field`, with lossy truncation.
+    /// This operation is lossless, except for integers that are wider than the data capacity
+    /// of the base field (i.e. `u256`, `i256`), which are truncated to `BaseField::size_in_data_bits` bits.
     #[inline]
     fn cast_lossy(&self) -> Field<E> {
-        self.to_field()
+        let mut bits_le = self.to_bits_le();
+        bits_le.truncate(E::BaseField::size_in_data_bits());
+        Field::from_bits_le(&bits_le)
     }
 }
 
@@ -78,8 +81,9 @@ impl<E: Environment, I0: IntegerType, I1: IntegerType> CastLossy<Integer<E, I1>>
 }
 
 impl<E: Environment, I: IntegerType> CastLossy<Scalar<E>> for Integer<E, I> {
-    /// Casts an `Integer` to a `Scalar`.
-    /// This is safe because casting from an integer to a scalar is **always** lossless.
+    /// Casts an `Integer` to a `Scalar`, with lossy truncation.
+    /// This operation is lossless, except for integers that are wider than the data capacity
+    /// of the scalar field (i.e. `u256`, `i256`), which are truncated to `ScalarField::size_in_data_bits` bits.
     #[inline]
     fn cast_lossy(&self) -> Scalar<E> {
         self.to_scalar()
@@ -35,6 +35,7 @@ use snarkvm_circuit_types::prelude::{
     Inject,
     IntegerType,
     One,
+    PrimeField,
     Result,
     Scalar,
     Ternary,
@@ -79,11 +80,13 @@ impl<A: Aleo> Literal<A> {
             Self::I32(integer) => cast_lossy_integer_to_type(integer, to_type),
             Self::I64(integer) => cast_lossy_integer_to_type(integer, to_type),
             Self::I128(integer) => cast_lossy_integer_to_type(integer, to_type),
+            Self::I256(integer) => cast_lossy_integer_to_type(integer, to_type),
             Self::U8(integer) => cast_lossy_integer_to_type(integer, to_type),
             Self::U16(integer) => cast_lossy_integer_to_type(integer, to_type),
             Self::U32(integer) => cast_lossy_integer_to_type(integer, to_type),
             Self::U64(integer) => cast_lossy_integer_to_type(integer, to_type),
             Self::U128(integer) => cast_lossy_integer_to_type(integer, to_type),
+            Self::U256(integer) => cast_lossy_integer_to_type(integer, to_type),
             Self::Scalar(scalar) => cast_lossy_scalar_to_type(scalar, to_type),
             Self::Signature(..) => bail!("Cannot cast a signature literal to another type."),
             Self::String(..) => bail!("Cannot cast a string literal to another type."),
@@ -104,11 +107,13 @@ macro_rules! impl_cast_body {
             LiteralType::I32 => Ok(Literal::I32($input.$cast_lossy())),
             LiteralType::I64 => Ok(Literal::I64($input.$cast_lossy())),
             LiteralType::I128 => Ok(Literal::I128($input.$cast_lossy())),
+            LiteralType::I256 => Ok(Literal::I256($input.$cast_lossy())),
             LiteralType::U8 => Ok(Literal::U8($input.$cast_lossy())),
             LiteralType::U16 => Ok(Literal::U16($input.$cast_lossy())),
             LiteralType::U32 => Ok(Literal::U32($input.$cast_lossy())),
             LiteralType::U64 => Ok(Literal::U64($input.$cast_lossy())),
             LiteralType::U128 => Ok(Literal::U128($input.$cast_lossy())),
+            LiteralType::U256 => Ok(Literal::U256($input.$cast_lossy())),
             LiteralType::Scalar => Ok(Literal::Scalar($input.$cast_lossy())),
             LiteralType::Signature => {
                 bail!(concat!("Cannot cast (lossy) a ", stringify!($type_name), " literal to a signature type."))
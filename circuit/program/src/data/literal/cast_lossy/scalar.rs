@@ -64,14 +64,11 @@ impl<E: Environment, I: IntegerType> CastLossy<Integer<E, I>> for Scalar<E> {
     /// Casts a `Scalar` to an `Integer`, with lossy truncation.
     #[inline]
     fn cast_lossy(&self) -> Integer<E, I> {
-        // Note: We are reconstituting the integer from the scalar field.
-        // This is safe as the number of bits in the integer is less than the scalar field modulus,
-        // and thus will always fit within a single scalar field element.
-        debug_assert!(I::BITS < <console::Scalar<E::Network> as console::SizeInBits>::size_in_bits() as u64);
-
-        // Truncate the field to the size of the integer domain.
-        // Slicing here is safe as the base field is larger than the integer domain.
-        Integer::<E, I>::from_bits_le(&self.to_bits_le()[..usize::try_from(I::BITS).unwrap()])
+        // Truncate the scalar to the size of the integer domain.
+        // If the integer is wider than the scalar field (i.e. `u256`, `i256`), the bits are zero-extended instead.
+        let mut bits_le = self.to_bits_le();
+        bits_le.truncate(usize::try_from(I::BITS).unwrap());
+        Integer::<E, I>::from_bits_le(&bits_le)
     }
 }
 
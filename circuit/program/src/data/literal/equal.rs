@@ -29,11 +29,13 @@ impl<A: Aleo> Equal<Self> for Literal<A> {
             (Self::I32(a), Self::I32(b)) => a.is_equal(b),
             (Self::I64(a), Self::I64(b)) => a.is_equal(b),
             (Self::I128(a), Self::I128(b)) => a.is_equal(b),
+            (Self::I256(a), Self::I256(b)) => a.is_equal(b),
             (Self::U8(a), Self::U8(b)) => a.is_equal(b),
             (Self::U16(a), Self::U16(b)) => a.is_equal(b),
             (Self::U32(a), Self::U32(b)) => a.is_equal(b),
             (Self::U64(a), Self::U64(b)) => a.is_equal(b),
             (Self::U128(a), Self::U128(b)) => a.is_equal(b),
+            (Self::U256(a), Self::U256(b)) => a.is_equal(b),
             (Self::Scalar(a), Self::Scalar(b)) => a.is_equal(b),
             (Self::Signature(a), Self::Signature(b)) => a.is_equal(b),
             (Self::String(a), Self::String(b)) => a.is_equal(b),
@@ -53,11 +55,13 @@ impl<A: Aleo> Equal<Self> for Literal<A> {
             (Self::I32(a), Self::I32(b)) => a.is_not_equal(b),
             (Self::I64(a), Self::I64(b)) => a.is_not_equal(b),
             (Self::I128(a), Self::I128(b)) => a.is_not_equal(b),
+            (Self::I256(a), Self::I256(b)) => a.is_not_equal(b),
             (Self::U8(a), Self::U8(b)) => a.is_not_equal(b),
             (Self::U16(a), Self::U16(b)) => a.is_not_equal(b),
             (Self::U32(a), Self::U32(b)) => a.is_not_equal(b),
             (Self::U64(a), Self::U64(b)) => a.is_not_equal(b),
             (Self::U128(a), Self::U128(b)) => a.is_not_equal(b),
+            (Self::U256(a), Self::U256(b)) => a.is_not_equal(b),
             (Self::Scalar(a), Self::Scalar(b)) => a.is_not_equal(b),
             (Self::Signature(a), Self::Signature(b)) => a.is_not_equal(b),
             (Self::String(a), Self::String(b)) => a.is_not_equal(b),
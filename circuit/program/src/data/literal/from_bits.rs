@@ -36,7 +36,9 @@ impl<A: Aleo> Literal<A> {
             14 => Literal::Scalar(Scalar::from_bits_le(literal)),
             15 => Literal::Signature(Box::new(Signature::from_bits_le(literal))),
             16 => Literal::String(StringType::from_bits_le(literal)),
-            17.. => A::halt(format!("Failed to initialize literal variant {} from bits (LE)", variant.eject_value())),
+            17 => Literal::I256(I256::from_bits_le(literal)),
+            18 => Literal::U256(U256::from_bits_le(literal)),
+            19.. => A::halt(format!("Failed to initialize literal variant {} from bits (LE)", variant.eject_value())),
         }
     }
 
@@ -61,7 +63,9 @@ impl<A: Aleo> Literal<A> {
             14 => Literal::Scalar(Scalar::from_bits_be(literal)),
             15 => Literal::Signature(Box::new(Signature::from_bits_be(literal))),
             16 => Literal::String(StringType::from_bits_be(literal)),
-            17.. => A::halt(format!("Failed to initialize literal variant {} from bits (BE))", variant.eject_value())),
+            17 => Literal::I256(I256::from_bits_be(literal)),
+            18 => Literal::U256(U256::from_bits_be(literal)),
+            19.. => A::halt(format!("Failed to initialize literal variant {} from bits (BE))", variant.eject_value())),
         }
     }
 }
@@ -114,6 +118,8 @@ mod tests {
             check_serialization(Literal::<Circuit>::I64(I64::new(mode, Uniform::rand(rng))));
             // I128
             check_serialization(Literal::<Circuit>::I128(I128::new(mode, Uniform::rand(rng))));
+            // I256
+            check_serialization(Literal::<Circuit>::I256(I256::new(mode, Uniform::rand(rng))));
             // U8
             check_serialization(Literal::<Circuit>::U8(U8::new(mode, Uniform::rand(rng))));
             // U16
@@ -124,6 +130,8 @@ mod tests {
             check_serialization(Literal::<Circuit>::U64(U64::new(mode, Uniform::rand(rng))));
             // U128
             check_serialization(Literal::<Circuit>::U128(U128::new(mode, Uniform::rand(rng))));
+            // U256
+            check_serialization(Literal::<Circuit>::U256(U256::new(mode, Uniform::rand(rng))));
             // Scalar
             check_serialization(Literal::<Circuit>::Scalar(Scalar::new(mode, Uniform::rand(rng))));
             // Signature
@@ -20,6 +20,7 @@ mod cast_lossy;
 mod equal;
 mod from_bits;
 mod size_in_bits;
+mod ternary;
 mod to_bits;
 mod to_fields;
 mod to_type;
@@ -69,6 +70,10 @@ pub enum Literal<A: Aleo> {
     Signature(Box<Signature<A>>),
     /// The string type.
     String(StringType<A>),
+    /// The 256-bit signed integer type.
+    I256(I256<A>),
+    /// The 256-bit unsigned integer type.
+    U256(U256<A>),
 }
 
 #[cfg(console)]
@@ -87,11 +92,13 @@ impl<A: Aleo> Inject for Literal<A> {
             Self::Primitive::I32(i32) => Self::I32(I32::new(mode, i32)),
             Self::Primitive::I64(i64) => Self::I64(I64::new(mode, i64)),
             Self::Primitive::I128(i128) => Self::I128(I128::new(mode, i128)),
+            Self::Primitive::I256(i256) => Self::I256(I256::new(mode, i256)),
             Self::Primitive::U8(u8) => Self::U8(U8::new(mode, u8)),
             Self::Primitive::U16(u16) => Self::U16(U16::new(mode, u16)),
             Self::Primitive::U32(u32) => Self::U32(U32::new(mode, u32)),
             Self::Primitive::U64(u64) => Self::U64(U64::new(mode, u64)),
             Self::Primitive::U128(u128) => Self::U128(U128::new(mode, u128)),
+            Self::Primitive::U256(u256) => Self::U256(U256::new(mode, u256)),
             Self::Primitive::Scalar(scalar) => Self::Scalar(Scalar::new(mode, scalar)),
             Self::Primitive::Signature(signature) => Self::Signature(Box::new(Signature::new(mode, *signature))),
             Self::Primitive::String(string) => Self::String(StringType::new(mode, string)),
@@ -115,11 +122,13 @@ impl<A: Aleo> Eject for Literal<A> {
             Self::I32(literal) => literal.eject_mode(),
             Self::I64(literal) => literal.eject_mode(),
             Self::I128(literal) => literal.eject_mode(),
+            Self::I256(literal) => literal.eject_mode(),
             Self::U8(literal) => literal.eject_mode(),
             Self::U16(literal) => literal.eject_mode(),
             Self::U32(literal) => literal.eject_mode(),
             Self::U64(literal) => literal.eject_mode(),
             Self::U128(literal) => literal.eject_mode(),
+            Self::U256(literal) => literal.eject_mode(),
             Self::Scalar(literal) => literal.eject_mode(),
             Self::Signature(literal) => literal.eject_mode(),
             Self::String(literal) => literal.eject_mode(),
@@ -138,11 +147,13 @@ impl<A: Aleo> Eject for Literal<A> {
             Self::I32(literal) => Self::Primitive::I32(literal.eject_value()),
             Self::I64(literal) => Self::Primitive::I64(literal.eject_value()),
             Self::I128(literal) => Self::Primitive::I128(literal.eject_value()),
+            Self::I256(literal) => Self::Primitive::I256(literal.eject_value()),
             Self::U8(literal) => Self::Primitive::U8(literal.eject_value()),
             Self::U16(literal) => Self::Primitive::U16(literal.eject_value()),
             Self::U32(literal) => Self::Primitive::U32(literal.eject_value()),
             Self::U64(literal) => Self::Primitive::U64(literal.eject_value()),
             Self::U128(literal) => Self::Primitive::U128(literal.eject_value()),
+            Self::U256(literal) => Self::Primitive::U256(literal.eject_value()),
             Self::Scalar(literal) => Self::Primitive::Scalar(literal.eject_value()),
             Self::Signature(literal) => Self::Primitive::Signature(Box::new(literal.eject_value())),
             Self::String(literal) => Self::Primitive::String(literal.eject_value()),
@@ -165,11 +176,13 @@ impl<A: Aleo> Parser for Literal<A> {
             map(I32::parse, |literal| Self::I32(literal)),
             map(I64::parse, |literal| Self::I64(literal)),
             map(I128::parse, |literal| Self::I128(literal)),
+            map(I256::parse, |literal| Self::I256(literal)),
             map(U8::parse, |literal| Self::U8(literal)),
             map(U16::parse, |literal| Self::U16(literal)),
             map(U32::parse, |literal| Self::U32(literal)),
             map(U64::parse, |literal| Self::U64(literal)),
             map(U128::parse, |literal| Self::U128(literal)),
+            map(U256::parse, |literal| Self::U256(literal)),
             map(Scalar::parse, |literal| Self::Scalar(literal)),
             map(Signature::parse, |literal| Self::Signature(Box::new(literal))),
             map(StringType::parse, |literal| Self::String(literal)),
@@ -210,11 +223,13 @@ impl<A: Aleo> Literal<A> {
             Self::I32(..) => I32::<A>::type_name(),
             Self::I64(..) => I64::<A>::type_name(),
             Self::I128(..) => I128::<A>::type_name(),
+            Self::I256(..) => I256::<A>::type_name(),
             Self::U8(..) => U8::<A>::type_name(),
             Self::U16(..) => U16::<A>::type_name(),
             Self::U32(..) => U32::<A>::type_name(),
             Self::U64(..) => U64::<A>::type_name(),
             Self::U128(..) => U128::<A>::type_name(),
+            Self::U256(..) => U256::<A>::type_name(),
             Self::Scalar(..) => Scalar::<A>::type_name(),
             Self::Signature(..) => Signature::<A>::type_name(),
             Self::String(..) => StringType::<A>::type_name(),
@@ -242,11 +257,13 @@ impl<A: Aleo> Display for Literal<A> {
             Self::I32(literal) => Display::fmt(literal, f),
             Self::I64(literal) => Display::fmt(literal, f),
             Self::I128(literal) => Display::fmt(literal, f),
+            Self::I256(literal) => Display::fmt(literal, f),
             Self::U8(literal) => Display::fmt(literal, f),
             Self::U16(literal) => Display::fmt(literal, f),
             Self::U32(literal) => Display::fmt(literal, f),
             Self::U64(literal) => Display::fmt(literal, f),
             Self::U128(literal) => Display::fmt(literal, f),
+            Self::U256(literal) => Display::fmt(literal, f),
             Self::Scalar(literal) => Display::fmt(literal, f),
             Self::Signature(literal) => Display::fmt(literal, f),
             Self::String(literal) => Display::fmt(literal, f),
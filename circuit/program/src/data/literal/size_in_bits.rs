@@ -27,11 +27,13 @@ impl<A: Aleo> Literal<A> {
             Self::I32(..) => console::I32::<A::Network>::size_in_bits() as u16,
             Self::I64(..) => console::I64::<A::Network>::size_in_bits() as u16,
             Self::I128(..) => console::I128::<A::Network>::size_in_bits() as u16,
+            Self::I256(..) => console::I256::<A::Network>::size_in_bits() as u16,
             Self::U8(..) => console::U8::<A::Network>::size_in_bits() as u16,
             Self::U16(..) => console::U16::<A::Network>::size_in_bits() as u16,
             Self::U32(..) => console::U32::<A::Network>::size_in_bits() as u16,
             Self::U64(..) => console::U64::<A::Network>::size_in_bits() as u16,
             Self::U128(..) => console::U128::<A::Network>::size_in_bits() as u16,
+            Self::U256(..) => console::U256::<A::Network>::size_in_bits() as u16,
             Self::Scalar(..) => console::Scalar::<A::Network>::size_in_bits() as u16,
             Self::Signature(..) => console::Signature::<A::Network>::size_in_bits() as u16,
             Self::String(string) => string.to_bits_le().len() as u16,
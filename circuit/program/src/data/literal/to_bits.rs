@@ -43,11 +43,13 @@ impl<A: Aleo> ToBits for &Literal<A> {
             Literal::I32(literal) => literal.write_bits_le(vec),
             Literal::I64(literal) => literal.write_bits_le(vec),
             Literal::I128(literal) => literal.write_bits_le(vec),
+            Literal::I256(literal) => literal.write_bits_le(vec),
             Literal::U8(literal) => literal.write_bits_le(vec),
             Literal::U16(literal) => literal.write_bits_le(vec),
             Literal::U32(literal) => literal.write_bits_le(vec),
             Literal::U64(literal) => literal.write_bits_le(vec),
             Literal::U128(literal) => literal.write_bits_le(vec),
+            Literal::U256(literal) => literal.write_bits_le(vec),
             Literal::Scalar(literal) => literal.write_bits_le(vec),
             Literal::Signature(literal) => literal.write_bits_le(vec),
             Literal::String(literal) => literal.write_bits_le(vec),
@@ -66,11 +68,13 @@ impl<A: Aleo> ToBits for &Literal<A> {
             Literal::I32(literal) => literal.write_bits_be(vec),
             Literal::I64(literal) => literal.write_bits_be(vec),
             Literal::I128(literal) => literal.write_bits_be(vec),
+            Literal::I256(literal) => literal.write_bits_be(vec),
             Literal::U8(literal) => literal.write_bits_be(vec),
             Literal::U16(literal) => literal.write_bits_be(vec),
             Literal::U32(literal) => literal.write_bits_be(vec),
             Literal::U64(literal) => literal.write_bits_be(vec),
             Literal::U128(literal) => literal.write_bits_be(vec),
+            Literal::U256(literal) => literal.write_bits_be(vec),
             Literal::Scalar(literal) => literal.write_bits_be(vec),
             Literal::Signature(literal) => literal.write_bits_be(vec),
             Literal::String(literal) => literal.write_bits_be(vec),
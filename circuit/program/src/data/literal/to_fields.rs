@@ -38,11 +38,13 @@ impl<A: Aleo> ToFields for &Literal<A> {
             Literal::I32(literal) => vec![literal.to_field()],
             Literal::I64(literal) => vec![literal.to_field()],
             Literal::I128(literal) => vec![literal.to_field()],
+            Literal::I256(literal) => literal.to_fields(),
             Literal::U8(literal) => vec![literal.to_field()],
             Literal::U16(literal) => vec![literal.to_field()],
             Literal::U32(literal) => vec![literal.to_field()],
             Literal::U64(literal) => vec![literal.to_field()],
             Literal::U128(literal) => vec![literal.to_field()],
+            Literal::U256(literal) => literal.to_fields(),
             Literal::Scalar(literal) => vec![literal.to_field()],
             Literal::Signature(literal) => literal.to_fields(),
             Literal::String(literal) => literal.to_fields(),
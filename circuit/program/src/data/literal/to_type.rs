@@ -28,11 +28,13 @@ impl<A: Aleo> Literal<A> {
             Self::I32(..) => console::LiteralType::I32,
             Self::I64(..) => console::LiteralType::I64,
             Self::I128(..) => console::LiteralType::I128,
+            Self::I256(..) => console::LiteralType::I256,
             Self::U8(..) => console::LiteralType::U8,
             Self::U16(..) => console::LiteralType::U16,
             Self::U32(..) => console::LiteralType::U32,
             Self::U64(..) => console::LiteralType::U64,
             Self::U128(..) => console::LiteralType::U128,
+            Self::U256(..) => console::LiteralType::U256,
             Self::Scalar(..) => console::LiteralType::Scalar,
             Self::Signature(..) => console::LiteralType::Signature,
             Self::String(..) => console::LiteralType::String,
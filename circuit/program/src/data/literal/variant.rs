@@ -35,6 +35,8 @@ impl<A: Aleo> Literal<A> {
             Self::Scalar(..) => console::U8::new(14),
             Self::Signature(..) => console::U8::new(15),
             Self::String(..) => console::U8::new(16),
+            Self::I256(..) => console::U8::new(17),
+            Self::U256(..) => console::U8::new(18),
         })
     }
 }
@@ -43,7 +43,12 @@ impl<A: Aleo> Equal<Self> for Plaintext<A> {
                 }
                 false => Boolean::constant(false),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::constant(false),
+            (Self::Optional(is_some_a, a, _), Self::Optional(is_some_b, b, _)) => {
+                is_some_a.is_equal(is_some_b) & a.is_equal(b)
+            }
+            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) | (Self::Optional(..), _) => {
+                Boolean::constant(false)
+            }
         }
     }
 
@@ -73,7 +78,12 @@ impl<A: Aleo> Equal<Self> for Plaintext<A> {
                 }
                 false => Boolean::constant(true),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::constant(true),
+            (Self::Optional(is_some_a, a, _), Self::Optional(is_some_b, b, _)) => {
+                is_some_a.is_not_equal(is_some_b) | a.is_not_equal(b)
+            }
+            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) | (Self::Optional(..), _) => {
+                Boolean::constant(true)
+            }
         }
     }
 }
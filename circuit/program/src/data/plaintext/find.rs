@@ -25,13 +25,14 @@ impl<A: Aleo> Plaintext<A> {
         match self {
             // Halts if the value is not a struct or an array.
             Self::Literal(..) => A::halt("A literal is not a struct or an array"),
+            Self::Optional(..) => A::halt("An optional is not a struct or an array"),
             // Retrieve the value of the member (from the value).
             Self::Struct(..) | Self::Array(..) => {
                 // Initialize the plaintext starting from the top-level.
                 let mut plaintext = self;
 
                 // Iterate through the path to retrieve the value.
-                for access in path.iter() {
+                for (i, access) in path.iter().enumerate() {
                     let access = access.clone().into();
                     match (plaintext, &access) {
                         (Self::Struct(members, ..), Access::Member(identifier)) => {
@@ -42,11 +43,8 @@ impl<A: Aleo> Plaintext<A> {
                                 None => bail!("Failed to locate member '{identifier}'"),
                             }
                         }
-                        (Self::Array(array, ..), Access::Index(index)) => {
-                            let index = match index.eject_mode() {
-                                Mode::Constant => index.eject_value(),
-                                _ => bail!("'{index}' must be a constant"),
-                            };
+                        (Self::Array(array, ..), Access::Index(index)) if index.eject_mode().is_constant() => {
+                            let index = index.eject_value();
                             match array.get(*index as usize) {
                                 // Retrieve the element and update `plaintext` for the next iteration.
                                 Some(element) => plaintext = element,
@@ -54,6 +52,15 @@ impl<A: Aleo> Plaintext<A> {
                                 None => bail!("Failed to locate element '{index}'"),
                             }
                         }
+                        (Self::Array(array, ..), Access::Index(index)) => {
+                            // Select the element at the index.
+                            let element = Self::select(array, index)?;
+                            // Retrieve the value from the remainder of the path.
+                            return match i + 1 == path.len() {
+                                true => Ok(element),
+                                false => element.find(&path[i + 1..]),
+                            };
+                        }
                         _ => bail!("Invalid access `{access}``"),
                     }
                 }
@@ -63,4 +70,57 @@ impl<A: Aleo> Plaintext<A> {
             }
         }
     }
+
+    /// Returns the element of the array at the given (non-constant) index.
+    ///
+    /// The element is selected with a multiplexer over all elements, so the cost is linear
+    /// in the number of elements, and the size of each element.
+    fn select(array: &[Plaintext<A>], index: &U32<A>) -> Result<Plaintext<A>> {
+        // Retrieve the last element of the array.
+        let Some((last, rest)) = array.split_last() else { bail!("Attempted to index into an empty array") };
+        // Ensure the index is within bounds.
+        A::assert(index.is_less_than(&U32::constant(console::U32::new(u32::try_from(array.len())?))));
+        // Select the element at the index, defaulting to the last element.
+        Ok(rest.iter().enumerate().rev().fold(last.clone(), |selected, (i, element)| {
+            // Note: `i` is less than `array.len()`, which fits in a `u32`.
+            let is_index = index.is_equal(&U32::constant(console::U32::new(i as u32)));
+            Plaintext::ternary(&is_index, element, &selected)
+        }))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    #[test]
+    fn test_find_with_private_index() -> Result<()> {
+        let value =
+            console::Plaintext::from_str("[{ a: 1u8, b: [2u8, 3u8] }, { a: 4u8, b: [5u8, 6u8] }, { a: 7u8, b: [8u8, 9u8] }]")?;
+        let plaintext = Plaintext::<Circuit>::new(Mode::Private, value.clone());
+        let a = Access::Member(Identifier::from_str("a")?);
+        let b = Access::Member(Identifier::from_str("b")?);
+
+        for i in 0..3u32 {
+            let index = Access::Index(U32::new(Mode::Private, console::U32::new(i)));
+            // Select a member of the element.
+            let candidate = plaintext.find(&[index.clone(), a.clone()])?;
+            assert_eq!(candidate.eject_value(), console::Plaintext::from_str(&format!("{}u8", 3 * i + 1))?);
+            // Select an element of a member of the element, with a private index.
+            let inner = Access::Index(U32::new(Mode::Private, console::U32::new(1)));
+            let candidate = plaintext.find(&[index, b.clone(), inner])?;
+            assert_eq!(candidate.eject_value(), console::Plaintext::from_str(&format!("{}u8", 3 * i + 3))?);
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure an out-of-bounds index is not satisfied.
+        let plaintext = Plaintext::<Circuit>::new(Mode::Private, value);
+        let index = Access::Index(U32::new(Mode::Private, console::U32::new(3)));
+        let _candidate = plaintext.find(&[index, a])?;
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
 }
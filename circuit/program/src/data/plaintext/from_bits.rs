@@ -86,6 +86,22 @@ impl<A: Aleo> FromBits for Plaintext<A> {
             // Cache the plaintext bits, and return the array.
             Self::Array(elements, OnceCell::with_value(bits_le.to_vec()))
         }
+        // Optional
+        else if variant == [true, true] {
+            let is_some = next_bits(1)[0].clone();
+            let literal_variant = U8::from_bits_le(next_bits(8));
+            let literal_size = U16::from_bits_le(next_bits(16)).eject_value();
+            let literal = Literal::from_bits_le(&literal_variant, next_bits(*literal_size as usize));
+
+            // Ensure an empty optional holds the zero literal.
+            match console::Literal::zero(literal.to_type()) {
+                Ok(zero) => A::assert(&is_some | &literal.is_equal(&Literal::constant(zero))),
+                Err(error) => A::halt(error.to_string()),
+            }
+
+            // Cache the plaintext bits, and return the optional.
+            Self::Optional(is_some, literal, OnceCell::with_value(bits_le.to_vec()))
+        }
         // Unknown variant.
         else {
             A::halt("Unknown plaintext variant.")
@@ -161,6 +177,22 @@ impl<A: Aleo> FromBits for Plaintext<A> {
             // Cache the plaintext bits, and return the array.
             Self::Array(elements, OnceCell::with_value(bits_be.to_vec()))
         }
+        // Optional
+        else if variant == [true, true] {
+            let is_some = next_bits(1)[0].clone();
+            let literal_variant = U8::from_bits_be(next_bits(8));
+            let literal_size = U16::from_bits_be(next_bits(16)).eject_value();
+            let literal = Literal::from_bits_be(&literal_variant, next_bits(*literal_size as usize));
+
+            // Ensure an empty optional holds the zero literal.
+            match console::Literal::zero(literal.to_type()) {
+                Ok(zero) => A::assert(&is_some | &literal.is_equal(&Literal::constant(zero))),
+                Err(error) => A::halt(error.to_string()),
+            }
+
+            // Cache the plaintext bits, and return the optional.
+            Self::Optional(is_some, literal, OnceCell::with_value(bits_be.to_vec()))
+        }
         // Unknown variant.
         else {
             A::halt("Unknown plaintext variant.")
@@ -22,6 +22,7 @@ mod from_bits;
 mod from_fields;
 mod num_randomizers;
 mod size_in_fields;
+mod ternary;
 mod to_bits;
 mod to_fields;
 
@@ -37,6 +38,8 @@ pub enum Plaintext<A: Aleo> {
     Struct(IndexMap<Identifier<A>, Plaintext<A>>, OnceCell<Vec<Boolean<A>>>),
     /// A plaintext array.
     Array(Vec<Plaintext<A>>, OnceCell<Vec<Boolean<A>>>),
+    /// A plaintext optional, with a flag indicating if the literal is present.
+    Optional(Boolean<A>, Literal<A>, OnceCell<Vec<Boolean<A>>>),
 }
 
 #[cfg(console)]
@@ -49,6 +52,9 @@ impl<A: Aleo> Inject for Plaintext<A> {
             Self::Primitive::Literal(literal, _) => Self::Literal(Literal::new(mode, literal), Default::default()),
             Self::Primitive::Struct(struct_, _) => Self::Struct(Inject::new(mode, struct_), Default::default()),
             Self::Primitive::Array(array, _) => Self::Array(Inject::new(mode, array), Default::default()),
+            Self::Primitive::Optional(is_some, literal, _) => {
+                Self::Optional(Boolean::new(mode, *is_some), Literal::new(mode, literal), Default::default())
+            }
         }
     }
 }
@@ -67,6 +73,7 @@ impl<A: Aleo> Eject for Plaintext<A> {
                 .collect::<Vec<_>>()
                 .eject_mode(),
             Self::Array(array, _) => array.iter().map(Eject::eject_mode).collect::<Vec<_>>().eject_mode(),
+            Self::Optional(is_some, literal, _) => Mode::combine(is_some.eject_mode(), [literal.eject_mode()]),
         }
     }
 
@@ -80,6 +87,11 @@ impl<A: Aleo> Eject for Plaintext<A> {
             Self::Array(array, _) => {
                 console::Plaintext::Array(array.iter().map(Eject::eject_value).collect(), Default::default())
             }
+            Self::Optional(is_some, literal, _) => console::Plaintext::Optional(
+                console::Boolean::new(is_some.eject_value()),
+                literal.eject_value(),
+                Default::default(),
+            ),
         }
     }
 }
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> Ternary for Plaintext<A> {
+    type Boolean = Boolean<A>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    /// Note: The plaintexts must be of the same type.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        match (first, second) {
+            (Self::Literal(a, _), Self::Literal(b, _)) => {
+                Self::Literal(Literal::ternary(condition, a, b), Default::default())
+            }
+            (Self::Struct(a, _), Self::Struct(b, _)) if a.len() == b.len() => {
+                // Recursively select each member.
+                let members = a.iter().zip_eq(b.iter()).map(|((name_a, plaintext_a), (name_b, plaintext_b))| {
+                    // Ensure the member names match.
+                    if name_a.eject_value() != name_b.eject_value() {
+                        A::halt("Cannot select between structs with different members")
+                    }
+                    (name_a.clone(), Self::ternary(condition, plaintext_a, plaintext_b))
+                });
+                Self::Struct(members.collect(), Default::default())
+            }
+            (Self::Array(a, _), Self::Array(b, _)) if a.len() == b.len() => {
+                // Recursively select each element.
+                let elements = a.iter().zip_eq(b.iter()).map(|(a, b)| Self::ternary(condition, a, b));
+                Self::Array(elements.collect(), Default::default())
+            }
+            (Self::Optional(is_some_a, a, _), Self::Optional(is_some_b, b, _)) => Self::Optional(
+                Boolean::ternary(condition, is_some_a, is_some_b),
+                Literal::ternary(condition, a, b),
+                Default::default(),
+            ),
+            _ => A::halt("Cannot select between plaintexts of different types"),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    fn sample_plaintext(mode: Mode, value: &str) -> Plaintext<Circuit> {
+        Plaintext::new(mode, console::Plaintext::from_str(value).unwrap())
+    }
+
+    fn check_ternary(mode_a: Mode, mode_b: Mode) {
+        let a = sample_plaintext(mode_a, "{ a: [1u8, 2u8], b: 3field, c: \"foo\" }");
+        let b = sample_plaintext(mode_b, "{ a: [4u8, 5u8], b: 6field, c: \"bar\" }");
+
+        for flag in [true, false] {
+            let condition = Boolean::<Circuit>::new(Mode::Private, flag);
+
+            let candidate = Plaintext::ternary(&condition, &a, &b);
+            let expected = if flag { a.eject_value() } else { b.eject_value() };
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_ternary() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_ternary(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ternary_mismatched_members() {
+        let a = sample_plaintext(Mode::Private, "{ a: 1u8 }");
+        let b = sample_plaintext(Mode::Private, "{ b: 1u8 }");
+        let condition = Boolean::<Circuit>::new(Mode::Private, true);
+        let result = std::panic::catch_unwind(|| Plaintext::ternary(&condition, &a, &b));
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+}
@@ -64,6 +64,19 @@ impl<A: Aleo> ToBits for Plaintext<A> {
                 // Extend the vector with the bits of the array.
                 vec.extend_from_slice(bits);
             }
+            Self::Optional(is_some, literal, bits_le) => {
+                // Compute the bits of the optional.
+                let bits = bits_le.get_or_init(|| {
+                    let mut bits_le = vec![Boolean::constant(true), Boolean::constant(true)]; // Variant bit.
+                    bits_le.push(is_some.clone());
+                    literal.variant().write_bits_le(&mut bits_le);
+                    literal.size_in_bits().write_bits_le(&mut bits_le);
+                    literal.write_bits_le(&mut bits_le);
+                    bits_le
+                });
+                // Extend the vector with the bits of the optional.
+                vec.extend_from_slice(bits);
+            }
         }
     }
 
@@ -114,6 +127,19 @@ impl<A: Aleo> ToBits for Plaintext<A> {
                 // Extend the vector with the bits of the array.
                 vec.extend_from_slice(bits)
             }
+            Self::Optional(is_some, literal, bits_be) => {
+                // Compute the bits of the optional.
+                let bits = bits_be.get_or_init(|| {
+                    let mut bits_be = vec![Boolean::constant(true), Boolean::constant(true)]; // Variant bit.
+                    bits_be.push(is_some.clone());
+                    literal.variant().write_bits_be(&mut bits_be);
+                    literal.size_in_bits().write_bits_be(&mut bits_be);
+                    literal.write_bits_be(&mut bits_be);
+                    bits_be
+                });
+                // Extend the vector with the bits of the optional.
+                vec.extend_from_slice(bits)
+            }
         }
     }
 }
@@ -118,6 +118,38 @@ impl<A: Aleo> Request<A> {
         tvk: &Field<A>,
         tcm: &Field<A>,
         signature: Option<&Signature<A>>,
+    ) -> (Boolean<A>, Option<Vec<Field<A>>>) {
+        // Compute the function ID.
+        let function_id = compute_function_id(network_id, program_id, function_name);
+        // Check the input IDs.
+        Self::check_input_ids_with_function_id::<CREATE_MESSAGE>(
+            &function_id,
+            Some(program_id),
+            input_ids,
+            inputs,
+            input_types,
+            signer,
+            sk_tag,
+            tvk,
+            tcm,
+            signature,
+        )
+    }
+
+    /// Returns `true` if the inputs match their input IDs, given the function ID.
+    /// If the program ID is not provided (i.e. for a dynamic call), then record inputs are rejected.
+    /// Note: This method does **not** perform signature checks.
+    pub fn check_input_ids_with_function_id<const CREATE_MESSAGE: bool>(
+        function_id: &Field<A>,
+        program_id: Option<&ProgramID<A>>,
+        input_ids: &[InputID<A>],
+        inputs: &[Value<A>],
+        input_types: &[console::ValueType<A::Network>],
+        signer: &Address<A>,
+        sk_tag: &Field<A>,
+        tvk: &Field<A>,
+        tcm: &Field<A>,
+        signature: Option<&Signature<A>>,
     ) -> (Boolean<A>, Option<Vec<Field<A>>>) {
         // Ensure the signature response matches the `CREATE_MESSAGE` flag.
         match CREATE_MESSAGE {
@@ -125,9 +157,6 @@ impl<A: Aleo> Request<A> {
             false => assert!(signature.is_none()),
         }
 
-        // Compute the function ID.
-        let function_id = compute_function_id(network_id, program_id, function_name);
-
         // Initialize a vector for a message.
         let mut message = Vec::new();
 
@@ -224,6 +253,11 @@ impl<A: Aleo> Request<A> {
                             // Ensure the input is a record.
                             _ => A::halt(format!("Expected a record input at input {index}")),
                         };
+                        // Retrieve the program ID.
+                        let program_id = match program_id {
+                            Some(program_id) => program_id,
+                            None => A::halt("Expected a plaintext input, found a record input in a dynamic call"),
+                        };
                         // Compute the record commitment.
                         let candidate_commitment = record.to_commitment(program_id, &record_name);
                         // Compute the `candidate_serial_number` from `gamma`.
@@ -28,7 +28,29 @@ impl<A: Aleo> Response<A> {
     ) -> Vec<Value<A>> {
         // Compute the function ID.
         let function_id = compute_function_id(network_id, program_id, function_name);
+        // Process the outputs.
+        Self::process_outputs_from_callback_with_function_id(
+            &function_id,
+            Some(program_id),
+            num_inputs,
+            tvk,
+            tcm,
+            outputs,
+            output_types,
+        )
+    }
 
+    /// Returns the injected circuit outputs, given the function ID, number of inputs, tvk, tcm, outputs, and types.
+    /// If the program ID is not provided (i.e. for a dynamic call), then record outputs are rejected.
+    pub fn process_outputs_from_callback_with_function_id(
+        function_id: &Field<A>,
+        program_id: Option<&ProgramID<A>>,
+        num_inputs: usize,
+        tvk: &Field<A>,
+        tcm: &Field<A>,
+        outputs: Vec<console::Value<A::Network>>,        // Note: Console type
+        output_types: &[console::ValueType<A::Network>], // Note: Console type
+    ) -> Vec<Value<A>> {
         match outputs
             .iter()
             .zip_eq(output_types)
@@ -118,6 +140,11 @@ impl<A: Aleo> Response<A> {
                             Value::Plaintext(..) => A::halt("Expected a record output, found a plaintext output"),
                             Value::Future(..) => A::halt("Expected a record output, found a future output"),
                         };
+                        // Retrieve the program ID.
+                        let program_id = match program_id {
+                            Some(program_id) => program_id,
+                            None => A::halt("Expected a plaintext output, found a record output in a dynamic call"),
+                        };
                         // Compute the record commitment.
                         let commitment = record.to_commitment(program_id, &Identifier::constant(*record_name));
 
@@ -19,5 +19,6 @@ pub mod from_boolean;
 pub mod one;
 pub mod to_bits;
 pub mod to_lower_bits;
+pub mod to_lower_bytes;
 pub mod to_upper_bits;
 pub mod zero;
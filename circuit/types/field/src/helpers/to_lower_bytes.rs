@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    ///
+    /// Outputs the lower `k` bytes of an `n`-bit field element in little-endian representation.
+    /// Enforces that the upper `n - 8k` bits are zero.
+    ///
+    /// Each byte is range checked with a single lookup into a table of all bytes,
+    /// which requires `k + 1` constraints, instead of the `8k + 1` constraints of `to_lower_bits_le`.
+    ///
+    pub fn to_lower_bytes_le(&self, k: usize) -> Vec<Field<E>> {
+        // Ensure the bytes do not overflow the field.
+        if 8 * k > E::BaseField::size_in_data_bits() {
+            E::halt(format!(
+                "Attempted to extract {k} bytes from a {}-bit base field element",
+                E::BaseField::size_in_data_bits()
+            ))
+        }
+
+        // Construct a vector of field elements comprising the bytes of the field value.
+        let bytes: Vec<Field<E>> = witness!(|self| {
+            self.to_bits_le()
+                .chunks(8)
+                .take(k)
+                .map(|byte| console::Field::from_bits_le(byte).unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        // Register the table of all bytes, if there are any non-constant bytes to check.
+        let table_index = match self.is_constant() {
+            true => None,
+            false => Some(E::add_lookup_table("u8", || {
+                (0u64..256).map(|byte| [E::BaseField::from(byte), E::BaseField::zero(), E::BaseField::zero()]).collect()
+            })),
+        };
+
+        // Reconstruct the bytes as a linear combination representing the original field value.
+        let mut accumulator = Field::zero();
+        let mut coefficient = Field::one();
+        for byte in &bytes {
+            // Ensure the byte is in the range [0, 256).
+            match table_index {
+                Some(table_index) => E::enforce_lookup(table_index, || (byte, E::zero(), E::zero())),
+                None => debug_assert!(byte.eject_value() < console::Field::<E::Network>::from_u16(256)),
+            }
+
+            accumulator += byte * &coefficient;
+            for _ in 0..8 {
+                coefficient = coefficient.double();
+            }
+        }
+
+        // Ensure value * 1 == (256^k * B_k + ... + 256^0 * B_0)
+        // and ensures that B_n, ..., B_{n-k} are all equal to zero.
+        E::assert_eq(self, accumulator);
+
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_to_lower_k_bytes_le<I: IntegerType + Unsigned>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random unsigned integer.
+            let value: I = Uniform::rand(&mut rng);
+            let expected = value.to_bits_le();
+            let num_bytes = I::BITS as usize / 8;
+
+            // Construct the unsigned integer as a field element.
+            let candidate = Field::<Circuit>::new(mode, console::Field::from_bits_le(&expected).unwrap());
+
+            Circuit::scope(&format!("{mode} {i}"), || {
+                let candidate = candidate.to_lower_bytes_le(num_bytes);
+                assert_eq!(num_bytes, candidate.len());
+                for (i, (expected_byte, candidate_byte)) in expected.chunks(8).zip_eq(candidate.iter()).enumerate() {
+                    assert_eq!(
+                        console::Field::from_bits_le(expected_byte).unwrap(),
+                        candidate_byte.eject_value(),
+                        "LSB+{i}"
+                    );
+                }
+                match mode {
+                    Mode::Constant => assert_scope!(num_bytes as u64, 0, 0, 0),
+                    _ => assert_scope!(0, 0, num_bytes as u64, num_bytes as u64 + 1),
+                }
+            });
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_to_lower_bytes_savings() {
+        // Sample a random u64 as a field element.
+        let value = console::Field::from_u64(Uniform::rand(&mut TestRng::default()));
+        let candidate = Field::<Circuit>::new(Mode::Private, value);
+
+        // Extracting the bits requires one boolean constraint per bit.
+        Circuit::scope("to_lower_bits_le", || {
+            candidate.to_lower_bits_le(64);
+            assert_scope!(0, 0, 64, 65);
+        });
+        // Extracting the bytes requires one lookup per byte.
+        Circuit::scope("to_lower_bytes_le", || {
+            candidate.to_lower_bytes_le(8);
+            assert_scope!(0, 0, 8, 9);
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_to_lower_bytes_fails() {
+        // A value of 2^16 does not fit in two bytes.
+        let candidate = Field::<Circuit>::new(Mode::Private, console::Field::from_u32(1 << 16));
+        candidate.to_lower_bytes_le(2);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_to_8_bits_constant() {
+        check_to_lower_k_bytes_le::<u8>(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_8_bits_public() {
+        check_to_lower_k_bytes_le::<u8>(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_8_bits_private() {
+        check_to_lower_k_bytes_le::<u8>(Mode::Private);
+    }
+
+    #[test]
+    fn test_to_64_bits_constant() {
+        check_to_lower_k_bytes_le::<u64>(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_64_bits_public() {
+        check_to_lower_k_bytes_le::<u64>(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_64_bits_private() {
+        check_to_lower_k_bytes_le::<u64>(Mode::Private);
+    }
+
+    #[test]
+    fn test_to_128_bits_private() {
+        check_to_lower_k_bytes_le::<u128>(Mode::Private);
+    }
+}
@@ -40,7 +40,10 @@ impl<E: Environment, I: IntegerType> Metrics<dyn AbsWrapped<Output = Integer<E,
         match I::is_signed() {
             true => match case {
                 Mode::Constant => Count::is(2 * I::BITS, 0, 0, 0),
-                _ => Count::is(I::BITS, 0, (2 * I::BITS) + 1, (2 * I::BITS) + 2),
+                _ => {
+                    let num_limbs = Self::num_add_limbs();
+                    Count::is(I::BITS, 0, (2 * I::BITS) + num_limbs, (2 * I::BITS) + (2 * num_limbs))
+                }
             },
             false => Count::is(0, 0, 0, 0),
         }
@@ -126,12 +129,14 @@ mod tests {
     test_integer_unary!(run_test, i32, equals);
     test_integer_unary!(run_test, i64, equals);
     test_integer_unary!(run_test, i128, equals);
+    test_integer_unary!(run_test, i256, equals);
 
     test_integer_unary!(run_test, u8, equals);
     test_integer_unary!(run_test, u16, equals);
     test_integer_unary!(run_test, u32, equals);
     test_integer_unary!(run_test, u64, equals);
     test_integer_unary!(run_test, u128, equals);
+    test_integer_unary!(run_test, u256, equals);
 
     test_integer_unary!(#[ignore], run_exhaustive_test, u8, equals, exhaustive);
     test_integer_unary!(#[ignore], run_exhaustive_test, i8, equals, exhaustive);
@@ -73,6 +73,24 @@ impl<E: Environment, I: IntegerType> AddChecked<Self> for Integer<E, I> {
                 Some(value) => Integer::constant(console::Integer::new(value)),
                 None => E::halt("Integer overflow on addition of two constants"),
             }
+        } else if I::BITS >= E::BaseField::size_in_data_bits() as u64 {
+            // Add the integers over limbs, as the sum does not fit within a single field element.
+            let (sum, carry) = self.add_with_carry(other, &Boolean::constant(false));
+
+            match I::is_signed() {
+                // For signed addition, overflow and underflow conditions are:
+                //   - a > 0 && b > 0 && a + b < 0 (Overflow)
+                //   - a < 0 && b < 0 && a + b > 0 (Underflow)
+                true => {
+                    let is_same_sign = self.msb().is_equal(other.msb());
+                    let is_overflow = is_same_sign & sum.msb().is_not_equal(self.msb());
+                    E::assert_eq(is_overflow, E::zero());
+                }
+                // For unsigned addition, ensure the carry bit is zero.
+                false => E::assert_eq(carry, E::zero()),
+            }
+
+            sum
         } else if I::is_signed() {
             // Instead of adding the bits of `self` and `other` directly, the integers are
             // converted into a field elements, and summed, before converting back to integers.
@@ -130,16 +148,21 @@ impl<E: Environment, I: IntegerType> Metrics<dyn AddChecked<Integer<E, I>, Outpu
     type Case = (Mode, Mode);
 
     fn count(case: &Self::Case) -> Count {
+        // Retrieve the number of private variables and constraints used to sum the integers.
+        let num_limbs = Self::num_add_limbs();
+        let (num_private, num_constraints) = (I::BITS + num_limbs, I::BITS + 2 * num_limbs);
+
         match I::is_signed() {
             true => match (case.0, case.1) {
                 (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
-                (Mode::Constant, _) => Count::is(0, 0, I::BITS + 2, I::BITS + 4),
-                (_, Mode::Constant) => Count::is(0, 0, I::BITS + 3, I::BITS + 5),
-                (_, _) => Count::is(0, 0, I::BITS + 4, I::BITS + 6),
+                (Mode::Constant, _) => Count::is(0, 0, num_private + 1, num_constraints + 2),
+                (_, Mode::Constant) => Count::is(0, 0, num_private + 2, num_constraints + 3),
+                (_, _) => Count::is(0, 0, num_private + 3, num_constraints + 4),
             },
-            false => match (case.0, case.1) {
-                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
-                (_, _) => Count::is(0, 0, I::BITS, I::BITS + 1),
+            false => match (case.0, case.1, num_limbs) {
+                (Mode::Constant, Mode::Constant, _) => Count::is(I::BITS, 0, 0, 0),
+                (_, _, 1) => Count::is(0, 0, I::BITS, I::BITS + 1),
+                (_, _, _) => Count::is(0, 0, num_private, num_constraints + 1),
             },
         }
     }
@@ -241,12 +264,14 @@ mod tests {
     test_integer_binary!(run_test, i32, plus);
     test_integer_binary!(run_test, i64, plus);
     test_integer_binary!(run_test, i128, plus);
+    test_integer_binary!(run_test, i256, plus);
 
     test_integer_binary!(run_test, u8, plus);
     test_integer_binary!(run_test, u16, plus);
     test_integer_binary!(run_test, u32, plus);
     test_integer_binary!(run_test, u64, plus);
     test_integer_binary!(run_test, u128, plus);
+    test_integer_binary!(run_test, u256, plus);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, plus, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, plus, exhaustive);
@@ -23,6 +23,10 @@ impl<E: Environment, I: IntegerType> AddWrapped<Self> for Integer<E, I> {
         if self.is_constant() && other.is_constant() {
             // Compute the sum and return the new constant.
             witness!(|self, other| console::Integer::new(self.wrapping_add(&other)))
+        } else if I::BITS >= E::BaseField::size_in_data_bits() as u64 {
+            // Add the integers over limbs, as the sum does not fit within a single field element.
+            // Drop the carry bit as the operation is wrapped addition.
+            self.add_with_carry(other, &Boolean::constant(false)).0
         } else {
             // Instead of adding the bits of `self` and `other` directly, the integers are
             // converted into a field elements, and summed, before converting back to integers.
@@ -40,13 +44,51 @@ impl<E: Environment, I: IntegerType> AddWrapped<Self> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// The number of bits in each limb of `add_with_carry`.
+    const LIMB_BITS: usize = 128;
+
+    /// Adds `self`, `other`, and the `carry` bit, returning the sum (without the carry bit) and the carry bit.
+    /// The sum is computed over 128-bit limbs, so that each partial sum fits within a single field element.
+    /// This method is used for integers that are too large to be summed in a single field element.
+    pub(super) fn add_with_carry(&self, other: &Integer<E, I>, carry: &Boolean<E>) -> (Integer<E, I>, Boolean<E>) {
+        let mut bits_le = Vec::with_capacity(I::BITS as usize);
+        let mut carry = carry.clone();
+
+        for (this, that) in self.bits_le.chunks(Self::LIMB_BITS).zip_eq(other.bits_le.chunks(Self::LIMB_BITS)) {
+            // Sum the limbs and the carry bit from the previous limb.
+            let sum = Field::from_bits_le(this) + Field::from_bits_le(that) + Field::from_boolean(&carry);
+            // Extract the bits of the limb from the field element, with a carry bit.
+            let mut limb_bits_le = sum.to_lower_bits_le(this.len() + 1);
+            carry = match limb_bits_le.pop() {
+                Some(carry) => carry,
+                // Note: `E::halt` should never be invoked as `this.len() + 1` is greater than zero.
+                None => E::halt("Malformed sum detected during integer addition"),
+            };
+            bits_le.extend(limb_bits_le);
+        }
+
+        // Return the sum and the carry bit.
+        (Integer { bits_le, phantom: Default::default() }, carry)
+    }
+
+    /// Returns the number of limbs used by `add_with_carry`, or one if the integer is summed in a single field element.
+    pub(super) fn num_add_limbs() -> u64 {
+        match I::BITS >= E::BaseField::size_in_data_bits() as u64 {
+            true => (I::BITS + Self::LIMB_BITS as u64 - 1) / Self::LIMB_BITS as u64,
+            false => 1,
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Metrics<dyn AddWrapped<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
     type Case = (Mode, Mode);
 
     fn count(case: &Self::Case) -> Count {
+        let num_limbs = Self::num_add_limbs();
         match (case.0, case.1) {
             (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
-            (_, _) => Count::is(0, 0, I::BITS + 1, I::BITS + 2),
+            (_, _) => Count::is(0, 0, I::BITS + num_limbs, I::BITS + 2 * num_limbs),
         }
     }
 }
@@ -136,12 +178,14 @@ mod tests {
     test_integer_binary!(run_test, i32, plus);
     test_integer_binary!(run_test, i64, plus);
     test_integer_binary!(run_test, i128, plus);
+    test_integer_binary!(run_test, i256, plus);
 
     test_integer_binary!(run_test, u8, plus);
     test_integer_binary!(run_test, u16, plus);
     test_integer_binary!(run_test, u32, plus);
     test_integer_binary!(run_test, u64, plus);
     test_integer_binary!(run_test, u128, plus);
+    test_integer_binary!(run_test, u256, plus);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, plus, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, plus, exhaustive);
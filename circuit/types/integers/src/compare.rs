@@ -23,6 +23,21 @@ impl<E: Environment, I: IntegerType> Compare<Self> for Integer<E, I> {
         if self.is_constant() && other.is_constant() {
             // Compute the comparison and return the new constant.
             witness!(|self, other| self < other)
+        } else if I::BITS >= E::BaseField::size_in_data_bits() as u64 {
+            // Compute `self - other` over limbs, as the difference does not fit within a single field element.
+            // The carry bit of `self - other` is set if and only if there is no borrow.
+            let (_, carry) = self.add_with_carry(&!other, &Boolean::constant(true));
+            match I::is_signed() {
+                // If sign(a) != sign(b), then a < b, if a is negative and b is positive.
+                // If sign(a) == sign(b), then a < b, if `self - other` borrows.
+                true => {
+                    let same_sign = self.msb().is_equal(other.msb());
+                    let self_is_negative_and_other_is_positive = self.msb() & !other.msb();
+                    Self::Output::ternary(&same_sign, &!carry, &self_is_negative_and_other_is_positive)
+                }
+                // If `self - other` borrows, then a < b, otherwise a >= b.
+                false => !carry,
+            }
         } else if I::is_signed() {
             // Compute the less than operation via a sign and overflow check.
             // If sign(a) != sign(b), then a < b, if a is negative and b is positive.
@@ -71,15 +86,25 @@ impl<E: Environment, I: IntegerType> Metrics<dyn Compare<Integer<E, I>, Output =
     type Case = (Mode, Mode);
 
     fn count(case: &Self::Case) -> Count {
+        // Retrieve the number of constants, private variables, and constraints used to subtract the integers.
+        let num_limbs = Self::num_add_limbs();
+        let num_constants = match I::BITS >= E::BaseField::size_in_data_bits() as u64 {
+            true => 0,
+            false => I::BITS,
+        };
+        let (num_private, num_constraints) = (I::BITS + num_limbs, I::BITS + 2 * num_limbs);
+
         match I::is_signed() {
             true => match (case.0, case.1) {
                 (Mode::Constant, Mode::Constant) => Count::is(1, 0, 0, 0),
-                (Mode::Constant, _) | (_, Mode::Constant) => Count::is(I::BITS, 0, I::BITS + 2, I::BITS + 3),
-                (_, _) => Count::is(I::BITS, 0, I::BITS + 4, I::BITS + 5),
+                (Mode::Constant, _) | (_, Mode::Constant) => {
+                    Count::is(num_constants, 0, num_private + 1, num_constraints + 1)
+                }
+                (_, _) => Count::is(num_constants, 0, num_private + 3, num_constraints + 3),
             },
             false => match (case.0, case.1) {
                 (Mode::Constant, Mode::Constant) => Count::is(1, 0, 0, 0),
-                (_, _) => Count::is(I::BITS, 0, I::BITS + 1, I::BITS + 2),
+                (_, _) => Count::is(num_constants, 0, num_private, num_constraints),
             },
         }
     }
@@ -188,12 +213,14 @@ mod tests {
     test_integer_binary!(run_test, i32, compare_with);
     test_integer_binary!(run_test, i64, compare_with);
     test_integer_binary!(run_test, i128, compare_with);
+    test_integer_binary!(run_test, i256, compare_with);
 
     test_integer_binary!(run_test, u8, compare_with);
     test_integer_binary!(run_test, u16, compare_with);
     test_integer_binary!(run_test, u32, compare_with);
     test_integer_binary!(run_test, u64, compare_with);
     test_integer_binary!(run_test, u128, compare_with);
+    test_integer_binary!(run_test, u256, compare_with);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, bitand, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, bitand, exhaustive);
@@ -128,21 +128,26 @@ impl<E: Environment, I: IntegerType> Metrics<dyn DivChecked<Integer<E, I>, Outpu
     type Case = (Mode, Mode);
 
     fn count(case: &Self::Case) -> Count {
+        let data_bits = E::BaseField::size_in_data_bits() as u64;
         match (case.0, case.1) {
             (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
             (Mode::Constant, _) | (_, Mode::Constant) => {
-                match (I::is_signed(), 2 * I::BITS < E::BaseField::size_in_data_bits() as u64) {
-                    (true, true) => Count::less_than(7 * I::BITS + 1, 0, (9 * I::BITS) + 11, (9 * I::BITS) + 18),
-                    (true, false) => Count::less_than(7 * I::BITS + 1, 0, 1486, 1497),
-                    (false, true) => Count::less_than(I::BITS + 1, 0, (3 * I::BITS) + 2, (3 * I::BITS) + 5),
-                    (false, false) => Count::less_than(I::BITS + 1, 0, 709, 716),
+                match (I::is_signed(), 2 * I::BITS < data_bits, I::BITS < data_bits) {
+                    (true, true, _) => Count::less_than(7 * I::BITS + 1, 0, (9 * I::BITS) + 11, (9 * I::BITS) + 18),
+                    (true, false, true) => Count::less_than(7 * I::BITS + 1, 0, 1486, 1497),
+                    (true, false, false) => Count::less_than(6 * I::BITS + 1, 0, 2606, 2623),
+                    (false, true, _) => Count::less_than(I::BITS + 1, 0, (3 * I::BITS) + 2, (3 * I::BITS) + 5),
+                    (false, false, true) => Count::less_than(I::BITS + 1, 0, 709, 716),
+                    (false, false, false) => Count::less_than(I::BITS + 1, 0, 1573, 1585),
                 }
             }
-            (_, _) => match (I::is_signed(), 2 * I::BITS < E::BaseField::size_in_data_bits() as u64) {
-                (true, true) => Count::is(6 * I::BITS, 0, (9 * I::BITS) + 11, (9 * I::BITS) + 18),
-                (true, false) => Count::is(6 * I::BITS, 0, 1486, 1497),
-                (false, true) => Count::is(I::BITS, 0, (3 * I::BITS) + 2, (3 * I::BITS) + 5),
-                (false, false) => Count::is(I::BITS, 0, 709, 716),
+            (_, _) => match (I::is_signed(), 2 * I::BITS < data_bits, I::BITS < data_bits) {
+                (true, true, _) => Count::is(6 * I::BITS, 0, (9 * I::BITS) + 11, (9 * I::BITS) + 18),
+                (true, false, true) => Count::is(6 * I::BITS, 0, 1486, 1497),
+                (true, false, false) => Count::is(5 * I::BITS, 0, 3127, 3146),
+                (false, true, _) => Count::is(I::BITS, 0, (3 * I::BITS) + 2, (3 * I::BITS) + 5),
+                (false, false, true) => Count::is(I::BITS, 0, 709, 716),
+                (false, false, false) => Count::is(0, 0, 1573, 1585),
             },
         }
     }
@@ -268,12 +273,14 @@ mod tests {
     test_integer_binary!(run_test, i32, div);
     test_integer_binary!(run_test, i64, div);
     test_integer_binary!(run_test, i128, div);
+    test_integer_binary!(run_test, i256, div);
 
     test_integer_binary!(run_test, u8, div);
     test_integer_binary!(run_test, u16, div);
     test_integer_binary!(run_test, u32, div);
     test_integer_binary!(run_test, u64, div);
     test_integer_binary!(run_test, u128, div);
+    test_integer_binary!(run_test, u256, div);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, div, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, div, exhaustive);
@@ -70,9 +70,13 @@ impl<E: Environment, I: IntegerType> Integer<E, I> {
         if 2 * I::BITS < E::BaseField::size_in_data_bits() as u64 {
             // Ensure that Euclidean division holds for these values in the base field.
             E::assert_eq(self.to_field(), quotient.to_field() * other.to_field() + remainder.to_field());
-        } else {
+        } else if I::BITS < E::BaseField::size_in_data_bits() as u64 {
             // Ensure that Euclidean division holds for these values as integers.
             E::assert_eq(self, quotient.mul_checked(other).add_checked(&remainder));
+        } else {
+            // Ensure that Euclidean division holds for these values as integers.
+            // Note that the integers are compared bitwise, as they may exceed the field modulus.
+            E::assert(self.is_equal(&quotient.mul_checked(other).add_checked(&remainder)));
         }
 
         // Ensure that the remainder is less than the divisor.
@@ -88,21 +92,26 @@ impl<E: Environment, I: IntegerType> Metrics<dyn DivWrapped<Integer<E, I>, Outpu
     type Case = (Mode, Mode);
 
     fn count(case: &Self::Case) -> Count {
+        let data_bits = E::BaseField::size_in_data_bits() as u64;
         match (case.0, case.1) {
             (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
             (Mode::Constant, _) | (_, Mode::Constant) => {
-                match (I::is_signed(), 2 * I::BITS < E::BaseField::size_in_data_bits() as u64) {
-                    (true, true) => Count::less_than(5 * I::BITS + 1, 0, (9 * I::BITS) + 6, (9 * I::BITS) + 12),
-                    (true, false) => Count::less_than(6 * I::BITS + 1, 0, 1481, 1491),
-                    (false, true) => Count::less_than(2 * I::BITS + 1, 0, (3 * I::BITS) + 2, (3 * I::BITS) + 5),
-                    (false, false) => Count::less_than(2 * I::BITS + 1, 0, 839, 839),
+                match (I::is_signed(), 2 * I::BITS < data_bits, I::BITS < data_bits) {
+                    (true, true, _) => Count::less_than(5 * I::BITS + 1, 0, (9 * I::BITS) + 6, (9 * I::BITS) + 12),
+                    (true, false, true) => Count::less_than(6 * I::BITS + 1, 0, 1481, 1491),
+                    (true, false, false) => Count::less_than(4 * I::BITS + 1, 0, 2601, 2617),
+                    (false, true, _) => Count::less_than(2 * I::BITS + 1, 0, (3 * I::BITS) + 2, (3 * I::BITS) + 5),
+                    (false, false, true) => Count::less_than(2 * I::BITS + 1, 0, 839, 839),
+                    (false, false, false) => Count::less_than(2 * I::BITS + 1, 0, 1573, 1585),
                 }
             }
-            (_, _) => match (I::is_signed(), 2 * I::BITS < E::BaseField::size_in_data_bits() as u64) {
-                (true, true) => Count::is(4 * I::BITS, 0, (9 * I::BITS) + 6, (9 * I::BITS) + 12),
-                (true, false) => Count::is(4 * I::BITS, 0, 1481, 1491),
-                (false, true) => Count::is(I::BITS, 0, (3 * I::BITS) + 2, (3 * I::BITS) + 5),
-                (false, false) => Count::less_than(2 * I::BITS, 0, 839, 839),
+            (_, _) => match (I::is_signed(), 2 * I::BITS < data_bits, I::BITS < data_bits) {
+                (true, true, _) => Count::is(4 * I::BITS, 0, (9 * I::BITS) + 6, (9 * I::BITS) + 12),
+                (true, false, true) => Count::is(4 * I::BITS, 0, 1481, 1491),
+                (true, false, false) => Count::is(3 * I::BITS, 0, 3116, 3134),
+                (false, true, _) => Count::is(I::BITS, 0, (3 * I::BITS) + 2, (3 * I::BITS) + 5),
+                (false, false, true) => Count::less_than(2 * I::BITS, 0, 839, 839),
+                (false, false, false) => Count::is(0, 0, 1573, 1585),
             },
         }
     }
@@ -220,12 +229,14 @@ mod tests {
     test_integer_binary!(run_test, i32, div);
     test_integer_binary!(run_test, i64, div);
     test_integer_binary!(run_test, i128, div);
+    test_integer_binary!(run_test, i256, div);
 
     test_integer_binary!(run_test, u8, div);
     test_integer_binary!(run_test, u16, div);
     test_integer_binary!(run_test, u32, div);
     test_integer_binary!(run_test, u64, div);
     test_integer_binary!(run_test, u128, div);
+    test_integer_binary!(run_test, u256, div);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, div, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, div, exhaustive);
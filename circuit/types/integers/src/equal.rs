@@ -29,12 +29,22 @@ impl<E: Environment, I: IntegerType> Equal<Self> for Integer<E, I> {
                 .zip_eq(other.bits_le.iter())
                 .map(|(this, that)| this.is_equal(that))
                 .fold(Boolean::constant(true), |a, b| a & b),
-            false => {
+            false => match I::BITS >= E::BaseField::size_in_data_bits() as u64 {
+                // Instead of comparing the bits of `self` and `other` directly, the integers are
+                // packed into field elements, and checked if each pair is equivalent as field elements.
+                true => {
+                    let size_in_data_bits = E::BaseField::size_in_data_bits();
+                    self.bits_le
+                        .chunks(size_in_data_bits)
+                        .zip_eq(other.bits_le.chunks(size_in_data_bits))
+                        .map(|(this, that)| Field::from_bits_le(this).is_equal(&Field::from_bits_le(that)))
+                        .fold(Boolean::constant(true), |a, b| a & b)
+                }
                 // Instead of comparing the bits of `self` and `other` directly, the integers are
                 // converted into a field elements, and checked if they are equivalent as field elements.
-                // Note: This is safe as the field is larger than the maximum integer type supported.
-                self.to_field().is_equal(&other.to_field())
-            }
+                // Note: This is safe as the field is larger than the integer.
+                false => self.to_field().is_equal(&other.to_field()),
+            },
         }
     }
 
@@ -55,7 +65,15 @@ impl<E: Environment, I: IntegerType> Metrics<dyn Equal<Integer<E, I>, Output = B
     fn count(case: &Self::Case) -> Count {
         match case.0.is_constant() && case.1.is_constant() {
             true => Count::is(0, 0, 0, 0),
-            false => Count::is(0, 0, 2, 2),
+            false => match I::BITS >= E::BaseField::size_in_data_bits() as u64 {
+                // Each pair of field elements is compared, and the results are combined with an AND.
+                true => {
+                    let size_in_data_bits = E::BaseField::size_in_data_bits() as u64;
+                    let num_chunks = (I::BITS + size_in_data_bits - 1) / size_in_data_bits;
+                    Count::is(0, 0, 3 * num_chunks - 1, 3 * num_chunks - 1)
+                }
+                false => Count::is(0, 0, 2, 2),
+            },
         }
     }
 }
@@ -132,12 +150,14 @@ mod tests {
     test_integer_binary!(run_test, i32, equals);
     test_integer_binary!(run_test, i64, equals);
     test_integer_binary!(run_test, i128, equals);
+    test_integer_binary!(run_test, i256, equals);
 
     test_integer_binary!(run_test, u8, equals);
     test_integer_binary!(run_test, u16, equals);
     test_integer_binary!(run_test, u32, equals);
     test_integer_binary!(run_test, u64, equals);
     test_integer_binary!(run_test, u128, equals);
+    test_integer_binary!(run_test, u256, equals);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, equals, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, equals, exhaustive);
@@ -23,13 +23,17 @@ impl<E: Environment, I: IntegerType> FromField for Integer<E, I> {
     ///   1. If the field element is larger than the integer domain, then the operation will fail.
     ///   2. If the field element is smaller than the integer domain, then the operation will succeed.
     fn from_field(field: Self::Field) -> Self {
-        // Note: We are reconstituting the integer from the base field.
-        // This is safe as the number of bits in the integer is less than the base field modulus,
-        // and thus will always fit within a single base field element.
-        debug_assert!(I::BITS < E::BaseField::size_in_bits() as u64);
-
-        // Extract the integer bits from the field element, **without** a carry bit.
-        let bits_le = field.to_lower_bits_le(I::BITS as usize);
+        let bits_le = match I::BITS < E::BaseField::size_in_bits() as u64 {
+            // Extract the integer bits from the field element, **without** a carry bit.
+            true => field.to_lower_bits_le(I::BITS as usize),
+            // If the integer is wider than the base field, zero-extend the bits to the size of the integer.
+            // Note: This is safe as every base field element fits within the integer domain.
+            false => {
+                let mut bits_le = field.to_bits_le();
+                bits_le.resize(I::BITS as usize, Boolean::constant(false));
+                bits_le
+            }
+        };
 
         // Return the integer.
         Integer { bits_le, phantom: Default::default() }
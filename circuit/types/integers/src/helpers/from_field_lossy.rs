@@ -20,14 +20,11 @@ impl<E: Environment, I: IntegerType> Integer<E, I> {
     /// This method is commonly-used by hash-to-integer algorithms,
     /// where the hash output does not need to preserve the full base field.
     pub fn from_field_lossy(field: &Field<E>) -> Self {
-        // Note: We are reconstituting the integer from the base field.
-        // This is safe as the number of bits in the integer is less than the base field modulus,
-        // and thus will always fit within a single base field element.
-        debug_assert!(I::BITS < E::BaseField::size_in_bits() as u64);
-
         // Truncate the field to the size in bits of the integer.
-        // Slicing here is safe as the base field is larger than the integer domain.
-        Self { bits_le: field.to_bits_le()[..I::BITS as usize].to_vec(), phantom: Default::default() }
+        // If the integer is wider than the base field (i.e. `u256`, `i256`), the bits are zero-extended instead.
+        let mut bits_le = field.to_bits_le();
+        bits_le.resize(I::BITS as usize, Boolean::constant(false));
+        Self { bits_le, phantom: Default::default() }
     }
 }
 
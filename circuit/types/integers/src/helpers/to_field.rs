@@ -18,12 +18,13 @@ impl<E: Environment, I: IntegerType> ToField for Integer<E, I> {
     type Field = Field<E>;
 
     /// Casts an integer into a base field.
+    ///
+    /// This method enforces that the integer is less than the base field modulus,
+    /// which is only a constraint for integers wider than the base field (i.e. `u256`, `i256`).
     fn to_field(&self) -> Self::Field {
         // Note: We are reconstituting the integer as a base field.
-        // This is safe as the number of bits in the integer is less than the base field modulus,
-        // and thus will always fit within a single base field element.
-        debug_assert!(I::BITS < E::BaseField::size_in_bits() as u64);
-
+        // For integers narrower than the base field, this will always fit within a single base field element.
+        // Otherwise, `Field::from_bits_le` enforces the integer is less than the base field modulus.
         // Reconstruct the bits as a linear combination representing the original field value.
         Field::from_bits_le(&self.bits_le)
     }
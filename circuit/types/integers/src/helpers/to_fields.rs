@@ -18,8 +18,14 @@ impl<E: Environment, I: IntegerType> ToFields for Integer<E, I> {
     type Field = Field<E>;
 
     /// Casts an integer into a list of base fields.
+    ///
+    /// Integers that are wider than the data capacity of the base field (i.e. `u256`, `i256`)
+    /// are packed, in little-endian order, into `BaseField::size_in_data_bits` bits per field element.
     fn to_fields(&self) -> Vec<Self::Field> {
-        vec![self.to_field()]
+        match I::BITS < E::BaseField::size_in_data_bits() as u64 {
+            true => vec![self.to_field()],
+            false => self.bits_le.chunks(E::BaseField::size_in_data_bits()).map(Field::from_bits_le).collect(),
+        }
     }
 }
 
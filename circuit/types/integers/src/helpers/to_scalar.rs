@@ -16,14 +16,17 @@ use super::*;
 
 impl<E: Environment, I: IntegerType> Integer<E, I> {
     /// Casts an integer into a scalar.
+    ///
+    /// Integers that are wider than the data capacity of the scalar field (i.e. `u256`, `i256`)
+    /// are truncated to `ScalarField::size_in_data_bits` bits.
     pub fn to_scalar(&self) -> Scalar<E> {
         // Note: We are reconstituting the integer as a scalar field.
-        // This is safe as the number of bits in the integer is less than the scalar field modulus,
+        // This is safe as the bits are truncated to be less than the scalar field modulus,
         // and thus will always fit within a single scalar field element.
-        debug_assert!(I::BITS < E::ScalarField::size_in_bits() as u64);
+        let num_bits = self.bits_le.len().min(E::ScalarField::size_in_data_bits());
 
         // Reconstruct the bits as a linear combination representing the original value.
-        Scalar::from_bits_le(&self.bits_le)
+        Scalar::from_bits_le(&self.bits_le[..num_bits])
     }
 }
 
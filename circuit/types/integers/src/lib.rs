@@ -51,12 +51,14 @@ pub type I16<E> = Integer<E, i16>;
 pub type I32<E> = Integer<E, i32>;
 pub type I64<E> = Integer<E, i64>;
 pub type I128<E> = Integer<E, i128>;
+pub type I256<E> = Integer<E, i256>;
 
 pub type U8<E> = Integer<E, u8>;
 pub type U16<E> = Integer<E, u16>;
 pub type U32<E> = Integer<E, u32>;
 pub type U64<E> = Integer<E, u64>;
 pub type U128<E> = Integer<E, u128>;
+pub type U256<E> = Integer<E, u256>;
 
 #[cfg(test)]
 use snarkvm_circuit_environment::{
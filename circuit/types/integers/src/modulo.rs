@@ -131,12 +131,14 @@ mod tests {
     test_integer_binary!(run_test, i32, modulo);
     test_integer_binary!(run_test, i64, modulo);
     test_integer_binary!(run_test, i128, modulo);
+    test_integer_binary!(run_test, i256, modulo);
 
     test_integer_binary!(run_test, u8, modulo);
     test_integer_binary!(run_test, u16, modulo);
     test_integer_binary!(run_test, u32, modulo);
     test_integer_binary!(run_test, u64, modulo);
     test_integer_binary!(run_test, u128, modulo);
+    test_integer_binary!(run_test, u256, modulo);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, modulo, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, modulo, exhaustive);
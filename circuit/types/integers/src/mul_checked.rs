@@ -148,8 +148,17 @@ impl<E: Environment, I: IntegerType> Integer<E, I> {
 
             // Return the product of `self` and `other`.
             product
-        } else {
-            E::halt(format!("Multiplication of integers of size {} is not supported", I::BITS))
+        }
+        // Case 3 - 1.5 integers do not fit in 1 field element (u256, i256).
+        else {
+            // Use schoolbook multiplication to compute the product of `self` and `other`.
+            let (product, overflow) = Self::schoolbook_multiply(this, that);
+
+            // Check that the overflow is zero.
+            E::assert_eq(overflow, E::zero());
+
+            // Return the product of `self` and `other`.
+            product
         }
     }
 }
@@ -204,6 +213,46 @@ impl<E: Environment, I: IntegerType> Integer<E, I> {
         // Return the product of `self` and `other`, along with the carry bits.
         (Integer::from_bits_le(bits_le), carry.to_vec(), z_2)
     }
+
+    /// Multiply the integer bits of `this` and `that`, using schoolbook multiplication over 64-bit limbs.
+    ///
+    /// The output is the product of `this` and `that`, and the overflow as a field element.
+    /// The overflow is the sum of the carry and the partial products that exceed `I::BITS`,
+    /// which are all non-negative, and thus the overflow is zero if and only if the product does not overflow.
+    /// This function is used for integers where 1.5 * I::BITS does not fit in 1 field element.
+    #[inline]
+    pub(super) fn schoolbook_multiply(this: &Integer<E, I>, that: &Integer<E, I>) -> (Integer<E, I>, Field<E>) {
+        const LIMB_BITS: usize = 64;
+
+        // Decompose the integers into 64-bit limbs, in little-endian order.
+        let x = this.bits_le.chunks(LIMB_BITS).map(Field::from_bits_le).collect::<Vec<_>>();
+        let y = that.bits_le.chunks(LIMB_BITS).map(Field::from_bits_le).collect::<Vec<_>>();
+        let num_limbs = x.len();
+
+        // Each column is the sum of at most `num_limbs` partial products of 2 * LIMB_BITS bits, and the carry.
+        // Thus, each column fits in 2 * LIMB_BITS + log2(num_limbs) + 1 bits.
+        let column_size = 2 * LIMB_BITS + num_limbs.next_power_of_two().trailing_zeros() as usize + 1;
+
+        let mut bits_le = Vec::with_capacity(I::BITS as usize);
+        let mut carry = Field::zero();
+        for k in 0..num_limbs {
+            // Sum the partial products of the column, and the carry from the previous column.
+            let column = (0..=k).fold(carry, |column, i| column + &x[i] * &y[k - i]);
+            // Split the column into the limb of the product and the carry.
+            let column_bits_le = column.to_lower_bits_le(column_size);
+            let (limb_bits_le, carry_bits_le) = column_bits_le.split_at(LIMB_BITS);
+            bits_le.extend_from_slice(limb_bits_le);
+            carry = Field::from_bits_le(carry_bits_le);
+        }
+
+        // Sum the carry and the partial products that exceed `I::BITS`.
+        let overflow = x.iter().enumerate().skip(1).fold(carry, |overflow, (i, x_i)| {
+            y[num_limbs - i..].iter().fold(overflow, |overflow, y_j| overflow + x_i * y_j)
+        });
+
+        // Return the product of `self` and `other`, along with the overflow.
+        (Integer { bits_le, phantom: Default::default() }, overflow)
+    }
 }
 
 impl<E: Environment, I: IntegerType> Metrics<dyn MulChecked<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
@@ -245,8 +294,23 @@ impl<E: Environment, I: IntegerType> Metrics<dyn MulChecked<Integer<E, I>, Outpu
                     (_, _) => Count::is(0, 0, 196, 199),
                 },
             }
-        } else {
-            E::halt(format!("Multiplication of integers of size {} is not supported", I::BITS))
+        }
+        // Case 3 - 1.5 integers do not fit in 1 field element (u256, i256).
+        else {
+            match I::is_signed() {
+                // Signed case
+                true => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (Mode::Constant, _) | (_, Mode::Constant) => Count::less_than(1804, 0, 1810, 1821),
+                    (_, _) => Count::is(3 * I::BITS, 0, 2341, 2354),
+                },
+                // Unsigned case
+                false => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (Mode::Constant, _) | (_, Mode::Constant) => Count::less_than(524, 0, 524, 529),
+                    (_, _) => Count::is(0, 0, 540, 545),
+                },
+            }
         }
     }
 }
@@ -394,12 +458,14 @@ mod tests {
     test_integer_binary!(run_test, i32, times);
     test_integer_binary!(run_test, i64, times);
     test_integer_binary!(run_test, i128, times);
+    test_integer_binary!(run_test, i256, times);
 
     test_integer_binary!(run_test, u8, times);
     test_integer_binary!(run_test, u16, times);
     test_integer_binary!(run_test, u32, times);
     test_integer_binary!(run_test, u64, times);
     test_integer_binary!(run_test, u128, times);
+    test_integer_binary!(run_test, u256, times);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, times, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, times, exhaustive);
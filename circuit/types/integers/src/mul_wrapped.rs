@@ -23,6 +23,10 @@ impl<E: Environment, I: IntegerType> MulWrapped<Self> for Integer<E, I> {
         if self.is_constant() && other.is_constant() {
             // Compute the product and return the new constant.
             witness!(|self, other| console::Integer::new(self.wrapping_mul(&other)))
+        } else if (I::BITS + I::BITS / 2) >= (E::BaseField::size_in_bits() - 1) as u64 {
+            // Use schoolbook multiplication, as 1.5 integers do not fit in 1 field element.
+            // Ignore the overflow as the operation is wrapped multiplication.
+            Self::schoolbook_multiply(self, other).0
         } else {
             // Perform multiplication by decomposing it into operations on its upper and lower bits.
             // See this page for reference: https://en.wikipedia.org/wiki/Karatsuba_algorithm.
@@ -61,15 +65,27 @@ impl<E: Environment, I: IntegerType> Metrics<dyn MulWrapped<Integer<E, I>, Outpu
     type Case = (Mode, Mode, bool, bool);
 
     fn count(case: &Self::Case) -> Count {
-        match (case.0, case.1, case.2, case.3) {
-            (Mode::Constant, Mode::Constant, _, _) => Count::is(I::BITS, 0, 0, 0),
-            (Mode::Constant, _, true, _) | (_, Mode::Constant, _, true) => {
-                Count::is(I::BITS + (I::BITS / 2) + 1, 0, 0, 0)
+        // Case 1 - 1.5 integers do not fit in 1 field element (u256, i256).
+        if (I::BITS + I::BITS / 2) >= (E::BaseField::size_in_bits() - 1) as u64 {
+            match (case.0, case.1, case.2, case.3) {
+                (Mode::Constant, Mode::Constant, _, _) => Count::is(I::BITS, 0, 0, 0),
+                (Mode::Constant, _, true, _) | (_, Mode::Constant, _, true) => Count::is(524, 0, 0, 0),
+                (Mode::Constant, _, false, _) | (_, Mode::Constant, _, false) => Count::less_than(393, 0, 524, 528),
+                (_, _, _, _) => Count::is(0, 0, 540, 544),
             }
-            (Mode::Constant, _, false, _) | (_, Mode::Constant, _, false) => {
-                Count::is(0, 0, I::BITS + (I::BITS / 2) + 1, I::BITS + (I::BITS / 2) + 2)
+        }
+        // Case 2 - 1.5 integers fit in 1 field element (u8, u16, u32, u64, u128, i8, i16, i32, i64, i128).
+        else {
+            match (case.0, case.1, case.2, case.3) {
+                (Mode::Constant, Mode::Constant, _, _) => Count::is(I::BITS, 0, 0, 0),
+                (Mode::Constant, _, true, _) | (_, Mode::Constant, _, true) => {
+                    Count::is(I::BITS + (I::BITS / 2) + 1, 0, 0, 0)
+                }
+                (Mode::Constant, _, false, _) | (_, Mode::Constant, _, false) => {
+                    Count::is(0, 0, I::BITS + (I::BITS / 2) + 1, I::BITS + (I::BITS / 2) + 2)
+                }
+                (_, _, _, _) => Count::is(0, 0, I::BITS + (I::BITS / 2) + 4, I::BITS + (I::BITS / 2) + 5),
             }
-            (_, _, _, _) => Count::is(0, 0, I::BITS + (I::BITS / 2) + 4, I::BITS + (I::BITS / 2) + 5),
         }
     }
 }
@@ -210,12 +226,14 @@ mod tests {
     test_integer_binary!(run_test, i32, times);
     test_integer_binary!(run_test, i64, times);
     test_integer_binary!(run_test, i128, times);
+    test_integer_binary!(run_test, i256, times);
 
     test_integer_binary!(run_test, u8, times);
     test_integer_binary!(run_test, u16, times);
     test_integer_binary!(run_test, u32, times);
     test_integer_binary!(run_test, u64, times);
     test_integer_binary!(run_test, u128, times);
+    test_integer_binary!(run_test, u256, times);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, times, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, times, exhaustive);
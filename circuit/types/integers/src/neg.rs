@@ -45,7 +45,10 @@ impl<E: Environment, I: IntegerType> Metrics<dyn Neg<Output = Integer<E, I>>> fo
             false => E::halt("Unsigned integers cannot be negated"),
             true => match case {
                 Mode::Constant => Count::is(2 * I::BITS, 0, 0, 0),
-                _ => Count::is(I::BITS, 0, I::BITS + 2, I::BITS + 4),
+                _ => {
+                    let num_limbs = Self::num_add_limbs();
+                    Count::is(I::BITS, 0, I::BITS + num_limbs + 1, I::BITS + 2 * num_limbs + 2)
+                }
             },
         }
     }
@@ -135,12 +138,14 @@ mod tests {
     test_integer_unary!(run_test, i32, neg);
     test_integer_unary!(run_test, i64, neg);
     test_integer_unary!(run_test, i128, neg);
+    test_integer_unary!(run_test, i256, neg);
 
     test_integer_unary!(assert_unsigned_neg_halts, u8, neg);
     test_integer_unary!(assert_unsigned_neg_halts, u16, neg);
     test_integer_unary!(assert_unsigned_neg_halts, u32, neg);
     test_integer_unary!(assert_unsigned_neg_halts, u64, neg);
     test_integer_unary!(assert_unsigned_neg_halts, u128, neg);
+    test_integer_unary!(assert_unsigned_neg_halts, u256, neg);
 
     test_integer_unary!(#[ignore], assert_unsigned_neg_halts, u8, neg, exhaustive);
     test_integer_unary!(#[ignore], run_exhaustive_test, i8, neg, exhaustive);
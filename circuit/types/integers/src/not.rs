@@ -111,12 +111,14 @@ mod tests {
     test_integer_unary!(run_test, i32, not);
     test_integer_unary!(run_test, i64, not);
     test_integer_unary!(run_test, i128, not);
+    test_integer_unary!(run_test, i256, not);
 
     test_integer_unary!(run_test, u8, not);
     test_integer_unary!(run_test, u16, not);
     test_integer_unary!(run_test, u32, not);
     test_integer_unary!(run_test, u64, not);
     test_integer_unary!(run_test, u128, not);
+    test_integer_unary!(run_test, u256, not);
 
     test_integer_unary!(#[ignore], run_exhaustive_test, u8, not, exhaustive);
     test_integer_unary!(#[ignore], run_exhaustive_test, i8, not, exhaustive);
@@ -183,12 +183,14 @@ mod tests {
     test_integer_binary!(run_test, i32, bitor);
     test_integer_binary!(run_test, i64, bitor);
     test_integer_binary!(run_test, i128, bitor);
+    test_integer_binary!(run_test, i256, bitor);
 
     test_integer_binary!(run_test, u8, bitor);
     test_integer_binary!(run_test, u16, bitor);
     test_integer_binary!(run_test, u32, bitor);
     test_integer_binary!(run_test, u64, bitor);
     test_integer_binary!(run_test, u128, bitor);
+    test_integer_binary!(run_test, u256, bitor);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, bitor, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, bitor, exhaustive);
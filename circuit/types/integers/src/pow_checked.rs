@@ -130,8 +130,16 @@ impl<E: Environment, I: IntegerType> Integer<E, I> {
 
             // Return the product of `self` and `other` and the overflow flag.
             (product, flag)
-        } else {
-            E::halt(format!("Multiplication of integers of size {} is not supported", I::BITS))
+        }
+        // Case 3 - 1.5 integers do not fit in 1 field element (u256, i256).
+        else {
+            // Use schoolbook multiplication to compute the product of `self` and `other` and the overflow.
+            let (product, overflow) = Self::schoolbook_multiply(this, that);
+            // Compute whether the overflow is zero.
+            let flag = overflow.is_not_equal(&Field::zero());
+
+            // Return the product of `self` and `other` and the overflow flag.
+            (product, flag)
         }
     }
 }
@@ -298,6 +306,9 @@ mod tests {
     test_integer_binary!(run_test, i128, u8, pow);
     test_integer_binary!(run_test, i128, u16, pow);
     test_integer_binary!(run_test, i128, u32, pow);
+    test_integer_binary!(run_test, i256, u8, pow);
+    test_integer_binary!(run_test, i256, u16, pow);
+    test_integer_binary!(run_test, i256, u32, pow);
 
     test_integer_binary!(run_test, u8, u8, pow);
     test_integer_binary!(run_test, u8, u16, pow);
@@ -318,6 +329,9 @@ mod tests {
     test_integer_binary!(run_test, u128, u8, pow);
     test_integer_binary!(run_test, u128, u16, pow);
     test_integer_binary!(run_test, u128, u32, pow);
+    test_integer_binary!(run_test, u256, u8, pow);
+    test_integer_binary!(run_test, u256, u16, pow);
+    test_integer_binary!(run_test, u256, u32, pow);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, u8, pow, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, u8, pow, exhaustive);
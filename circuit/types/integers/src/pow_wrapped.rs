@@ -185,6 +185,9 @@ mod tests {
     test_integer_binary!(run_test, i128, u8, pow);
     test_integer_binary!(run_test, i128, u16, pow);
     test_integer_binary!(run_test, i128, u32, pow);
+    test_integer_binary!(run_test, i256, u8, pow);
+    test_integer_binary!(run_test, i256, u16, pow);
+    test_integer_binary!(run_test, i256, u32, pow);
 
     test_integer_binary!(run_test, u8, u8, pow);
     test_integer_binary!(run_test, u8, u16, pow);
@@ -205,6 +208,9 @@ mod tests {
     test_integer_binary!(run_test, u128, u8, pow);
     test_integer_binary!(run_test, u128, u16, pow);
     test_integer_binary!(run_test, u128, u32, pow);
+    test_integer_binary!(run_test, u256, u8, pow);
+    test_integer_binary!(run_test, u256, u16, pow);
+    test_integer_binary!(run_test, u256, u32, pow);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, u8, pow, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, u8, pow, exhaustive);
@@ -266,12 +266,14 @@ mod tests {
     test_integer_binary!(run_test, i32, rem);
     test_integer_binary!(run_test, i64, rem);
     test_integer_binary!(run_test, i128, rem);
+    test_integer_binary!(run_test, i256, rem);
 
     test_integer_binary!(run_test, u8, rem);
     test_integer_binary!(run_test, u16, rem);
     test_integer_binary!(run_test, u32, rem);
     test_integer_binary!(run_test, u64, rem);
     test_integer_binary!(run_test, u128, rem);
+    test_integer_binary!(run_test, u256, rem);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, rem, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, rem, exhaustive);
@@ -51,21 +51,26 @@ impl<E: Environment, I: IntegerType> Metrics<dyn RemWrapped<Integer<E, I>, Outpu
     type Case = (Mode, Mode);
 
     fn count(case: &Self::Case) -> Count {
+        let data_bits = E::BaseField::size_in_data_bits() as u64;
         match (case.0, case.1) {
             (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
             (Mode::Constant, _) | (_, Mode::Constant) => {
-                match (I::is_signed(), 2 * I::BITS < E::BaseField::size_in_data_bits() as u64) {
-                    (true, true) => Count::less_than(5 * I::BITS + 1, 0, (9 * I::BITS) + 5, (9 * I::BITS) + 11),
-                    (true, false) => Count::less_than(6 * I::BITS + 1, 0, 1480, 1490),
-                    (false, true) => Count::less_than(2 * I::BITS + 1, 0, (3 * I::BITS) + 2, (3 * I::BITS) + 5),
-                    (false, false) => Count::less_than(2 * I::BITS + 1, 0, 839, 1039),
+                match (I::is_signed(), 2 * I::BITS < data_bits, I::BITS < data_bits) {
+                    (true, true, _) => Count::less_than(5 * I::BITS + 1, 0, (9 * I::BITS) + 5, (9 * I::BITS) + 11),
+                    (true, false, true) => Count::less_than(6 * I::BITS + 1, 0, 1480, 1490),
+                    (true, false, false) => Count::less_than(4 * I::BITS + 1, 0, 2585, 2601),
+                    (false, true, _) => Count::less_than(2 * I::BITS + 1, 0, (3 * I::BITS) + 2, (3 * I::BITS) + 5),
+                    (false, false, true) => Count::less_than(2 * I::BITS + 1, 0, 839, 1039),
+                    (false, false, false) => Count::less_than(2 * I::BITS + 1, 0, 1573, 1585),
                 }
             }
-            (_, _) => match (I::is_signed(), 2 * I::BITS < E::BaseField::size_in_data_bits() as u64) {
-                (true, true) => Count::is(4 * I::BITS, 0, (9 * I::BITS) + 5, (9 * I::BITS) + 11),
-                (true, false) => Count::is(4 * I::BITS, 0, 1480, 1490),
-                (false, true) => Count::is(I::BITS, 0, (3 * I::BITS) + 2, (3 * I::BITS) + 5),
-                (false, false) => Count::less_than(2 * I::BITS, 0, 839, 1039),
+            (_, _) => match (I::is_signed(), 2 * I::BITS < data_bits, I::BITS < data_bits) {
+                (true, true, _) => Count::is(4 * I::BITS, 0, (9 * I::BITS) + 5, (9 * I::BITS) + 11),
+                (true, false, true) => Count::is(4 * I::BITS, 0, 1480, 1490),
+                (true, false, false) => Count::is(3 * I::BITS, 0, 3115, 3133),
+                (false, true, _) => Count::is(I::BITS, 0, (3 * I::BITS) + 2, (3 * I::BITS) + 5),
+                (false, false, true) => Count::less_than(2 * I::BITS, 0, 839, 1039),
+                (false, false, false) => Count::is(0, 0, 1573, 1585),
             },
         }
     }
@@ -183,12 +188,14 @@ mod tests {
     test_integer_binary!(run_test, i32, rem);
     test_integer_binary!(run_test, i64, rem);
     test_integer_binary!(run_test, i128, rem);
+    test_integer_binary!(run_test, i256, rem);
 
     test_integer_binary!(run_test, u8, rem);
     test_integer_binary!(run_test, u16, rem);
     test_integer_binary!(run_test, u32, rem);
     test_integer_binary!(run_test, u64, rem);
     test_integer_binary!(run_test, u128, rem);
+    test_integer_binary!(run_test, u256, rem);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, rem, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, rem, exhaustive);
@@ -178,8 +178,8 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn ShlChecked<Intege
     type Case = (Mode, Mode, bool, bool);
 
     fn count(case: &Self::Case) -> Count {
-        // A quick hack that matches `(u8 -> 0, u16 -> 1, u32 -> 2, u64 -> 3, u128 -> 4)`.
-        let index = |num_bits: u64| match [8, 16, 32, 64, 128].iter().position(|&bits| bits == num_bits) {
+        // A quick hack that matches `(u8 -> 0, u16 -> 1, u32 -> 2, u64 -> 3, u128 -> 4, u256 -> 5)`.
+        let index = |num_bits: u64| match [8, 16, 32, 64, 128, 256].iter().position(|&bits| bits == num_bits) {
             Some(index) => index as u64,
             None => E::halt(format!("Integer of {num_bits} bits is not supported")),
         };
@@ -244,7 +244,7 @@ mod tests {
                 (_, Mode::Constant) => {
                     // If `second` >= I::BITS, then the invocation to `pow_checked` will halt.
                     // Otherwise, the invocation to `mul_checked` will not be satisfied.
-                    if *second >= M::try_from(I::BITS).unwrap_or_default() {
+                    if M::try_from(I::BITS).is_ok_and(|bits| *second >= bits) {
                         check_operation_halts(&a, &b, Integer::shl_checked);
                     } else {
                         Circuit::scope(name, || {
@@ -332,6 +332,9 @@ mod tests {
     test_integer_binary!(run_test, i128, u8, shl);
     test_integer_binary!(run_test, i128, u16, shl);
     test_integer_binary!(run_test, i128, u32, shl);
+    test_integer_binary!(run_test, i256, u8, shl);
+    test_integer_binary!(run_test, i256, u16, shl);
+    test_integer_binary!(run_test, i256, u32, shl);
 
     test_integer_binary!(run_test, u8, u8, shl);
     test_integer_binary!(run_test, u8, u16, shl);
@@ -352,6 +355,9 @@ mod tests {
     test_integer_binary!(run_test, u128, u8, shl);
     test_integer_binary!(run_test, u128, u16, shl);
     test_integer_binary!(run_test, u128, u32, shl);
+    test_integer_binary!(run_test, u256, u8, shl);
+    test_integer_binary!(run_test, u256, u16, shl);
+    test_integer_binary!(run_test, u256, u32, shl);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, u8, shl, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, u8, shl, exhaustive);
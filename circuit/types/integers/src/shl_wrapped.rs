@@ -63,6 +63,20 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShlWrapped<Integer<E, M>> for
                 let bits_le = result.to_lower_bits_le(2 * I::BITS as usize);
                 // Initialize the integer, ignoring the carry bits.
                 Self { bits_le: bits_le[..I::BITS as usize].to_vec(), phantom: Default::default() }
+            } else if I::BITS >= E::BaseField::size_in_data_bits() as u64 {
+                // Shift the bits directly, since 2^{rhs} may exceed the field modulus.
+                let mut bits_le = self.bits_le.clone();
+                for (i, bit) in rhs.bits_le[..first_upper_bit_index].iter().enumerate() {
+                    // In each iteration, shift the bits left by 2^i, if the bit is set.
+                    let shift = 1 << i;
+                    bits_le = (0..I::BITS as usize)
+                        .map(|j| match j < shift {
+                            true => Boolean::ternary(bit, &Boolean::constant(false), &bits_le[j]),
+                            false => Boolean::ternary(bit, &bits_le[j - shift], &bits_le[j]),
+                        })
+                        .collect();
+                }
+                Self { bits_le, phantom: Default::default() }
             } else {
                 // Calculate the value of the shift directly in the field.
                 // Since 2^{rhs} < Integer::MAX, we know that the operation will not overflow Integer::MAX or the field modulus.
@@ -87,42 +101,47 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn ShlWrapped<Intege
     type Case = (Mode, Mode, bool, bool);
 
     fn count(case: &Self::Case) -> Count {
-        // A quick hack that matches `(u8 -> 0, u16 -> 1, u32 -> 2, u64 -> 3, u128 -> 4)`.
-        let index = |num_bits: u64| match [8, 16, 32, 64, 128].iter().position(|&bits| bits == num_bits) {
+        // A quick hack that matches `(u8 -> 0, u16 -> 1, u32 -> 2, u64 -> 3, u128 -> 4, u256 -> 5)`.
+        let index = |num_bits: u64| match [8, 16, 32, 64, 128, 256].iter().position(|&bits| bits == num_bits) {
             Some(index) => index as u64,
             None => E::halt(format!("Integer of {num_bits} bits is not supported")),
         };
 
+        let data_bits = E::BaseField::size_in_data_bits() as u64;
         match (case.0, case.1) {
             (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
             (_, Mode::Constant) => Count::less_than(2 * I::BITS + 3, 0, 0, 0),
-            (Mode::Constant, _) => match 2 * I::BITS < E::BaseField::size_in_data_bits() as u64 {
-                true => Count::less_than(
+            (Mode::Constant, _) => match (2 * I::BITS < data_bits, I::BITS < data_bits) {
+                (true, _) => Count::less_than(
                     (2 * I::BITS) + index(I::BITS) + 3,
                     0,
                     (2 * I::BITS) + index(I::BITS) + 2,
                     (2 * I::BITS) + index(I::BITS) + 3,
                 ),
-                false => Count::is(
+                (false, true) => Count::is(
                     0,
                     0,
                     (2 * I::BITS) + (I::BITS / 2) + (2 * index(I::BITS)) + 5,
                     (2 * I::BITS) + (I::BITS / 2) + (2 * index(I::BITS)) + 7,
                 ),
+                (false, false) => {
+                    Count::less_than(0, 0, I::BITS * (index(I::BITS) + 3), I::BITS * (index(I::BITS) + 3))
+                }
             },
-            (_, _) => match 2 * I::BITS < E::BaseField::size_in_data_bits() as u64 {
-                true => Count::is(
+            (_, _) => match (2 * I::BITS < data_bits, I::BITS < data_bits) {
+                (true, _) => Count::is(
                     3 + index(I::BITS),
                     0,
                     (2 * I::BITS) + index(I::BITS) + 3,
                     (2 * I::BITS) + index(I::BITS) + 4,
                 ),
-                false => Count::is(
+                (false, true) => Count::is(
                     0,
                     0,
                     (2 * I::BITS) + (I::BITS / 2) + (2 * index(I::BITS)) + 8,
                     (2 * I::BITS) + (I::BITS / 2) + (2 * index(I::BITS)) + 10,
                 ),
+                (false, false) => Count::is(0, 0, I::BITS * (index(I::BITS) + 3), I::BITS * (index(I::BITS) + 3)),
             },
         }
     }
@@ -229,6 +248,9 @@ mod tests {
     test_integer_binary!(run_test, i128, u8, shl);
     test_integer_binary!(run_test, i128, u16, shl);
     test_integer_binary!(run_test, i128, u32, shl);
+    test_integer_binary!(run_test, i256, u8, shl);
+    test_integer_binary!(run_test, i256, u16, shl);
+    test_integer_binary!(run_test, i256, u32, shl);
 
     test_integer_binary!(run_test, u8, u8, shl);
     test_integer_binary!(run_test, u8, u16, shl);
@@ -249,6 +271,9 @@ mod tests {
     test_integer_binary!(run_test, u128, u8, shl);
     test_integer_binary!(run_test, u128, u16, shl);
     test_integer_binary!(run_test, u128, u32, shl);
+    test_integer_binary!(run_test, u256, u8, shl);
+    test_integer_binary!(run_test, u256, u16, shl);
+    test_integer_binary!(run_test, u256, u32, shl);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, u8, shl, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, u8, shl, exhaustive);
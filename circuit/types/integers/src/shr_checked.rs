@@ -75,7 +75,7 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShrChecked<Integer<E, M>> for
             }
         } else {
             // Determine the index where the first upper bit of the RHS must be zero.
-            // There is at least one trailing zero, as I::BITS = 8, 16, 32, 64, or 128.
+            // There is at least one trailing zero, as I::BITS = 8, 16, 32, 64, 128, or 256.
             let trailing_zeros_index = I::BITS.trailing_zeros() as usize;
 
             // Check that the upper bits of the RHS are nonzero.
@@ -109,8 +109,8 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn ShrChecked<Intege
     type Case = (Mode, Mode);
 
     fn count(case: &Self::Case) -> Count {
-        // A quick hack that matches `(u8 -> 0, u16 -> 1, u32 -> 2, u64 -> 3, u128 -> 4)`.
-        let index = |num_bits: u64| match [8, 16, 32, 64, 128].iter().position(|&bits| bits == num_bits) {
+        // A quick hack that matches `(u8 -> 0, u16 -> 1, u32 -> 2, u64 -> 3, u128 -> 4, u256 -> 5)`.
+        let index = |num_bits: u64| match [8, 16, 32, 64, 128, 256].iter().position(|&bits| bits == num_bits) {
             Some(index) => index as u64,
             None => E::halt(format!("Integer of {num_bits} bits is not supported")),
         };
@@ -232,6 +232,9 @@ mod tests {
     test_integer_binary!(run_test, i128, u8, shr);
     test_integer_binary!(run_test, i128, u16, shr);
     test_integer_binary!(run_test, i128, u32, shr);
+    test_integer_binary!(run_test, i256, u8, shr);
+    test_integer_binary!(run_test, i256, u16, shr);
+    test_integer_binary!(run_test, i256, u32, shr);
 
     test_integer_binary!(run_test, u8, u8, shr);
     test_integer_binary!(run_test, u8, u16, shr);
@@ -252,6 +255,9 @@ mod tests {
     test_integer_binary!(run_test, u128, u8, shr);
     test_integer_binary!(run_test, u128, u16, shr);
     test_integer_binary!(run_test, u128, u32, shr);
+    test_integer_binary!(run_test, u256, u8, shr);
+    test_integer_binary!(run_test, u256, u16, shr);
+    test_integer_binary!(run_test, u256, u32, shr);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, u8, shr, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, u8, shr, exhaustive);
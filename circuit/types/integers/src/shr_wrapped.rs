@@ -109,6 +109,25 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShrWrapped<Integer<E, M>> for
                     // Initialize the integer, ignoring the carry bits.
                     Self { bits_le, phantom: Default::default() }
                 }
+            } else if I::BITS >= E::BaseField::size_in_data_bits() as u64 {
+                // Shift the bits directly, since 2^{rhs} may exceed the field modulus.
+                // Sign-extend `self` if it is signed, and zero-extend it otherwise.
+                let fill = match I::is_signed() {
+                    true => self.msb().clone(),
+                    false => Boolean::constant(false),
+                };
+                let mut bits_le = self.bits_le.clone();
+                for (i, bit) in rhs.bits_le[..first_upper_bit_index].iter().enumerate() {
+                    // In each iteration, shift the bits right by 2^i, if the bit is set.
+                    let shift = 1 << i;
+                    bits_le = (0..I::BITS as usize)
+                        .map(|j| match bits_le.get(j + shift) {
+                            Some(shifted) => Boolean::ternary(bit, shifted, &bits_le[j]),
+                            None => Boolean::ternary(bit, &fill, &bits_le[j]),
+                        })
+                        .collect();
+                }
+                Self { bits_le, phantom: Default::default() }
             } else {
                 // Calculate the value of the shift directly in the field.
                 // Since 2^{rhs} < Integer::MAX, we know that the operation will not overflow Integer::MAX or the field modulus.
@@ -162,28 +181,31 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn ShrWrapped<Intege
 
     #[rustfmt::skip]
     fn count(case: &Self::Case) -> Count {
-        // A quick hack that matches `(u8 -> 0, u16 -> 1, u32 -> 2, u64 -> 3, u128 -> 4)`.
-        let index = |num_bits: u64| match [8, 16, 32, 64, 128].iter().position(|&bits| bits == num_bits) {
+        // A quick hack that matches `(u8 -> 0, u16 -> 1, u32 -> 2, u64 -> 3, u128 -> 4, u256 -> 5)`.
+        let index = |num_bits: u64| match [8, 16, 32, 64, 128, 256].iter().position(|&bits| bits == num_bits) {
             Some(index) => index as u64,
             None => E::halt(format!("Integer of {num_bits} bits is not supported")),
         };
 
+        let data_bits = E::BaseField::size_in_data_bits() as u64;
         match (case.0, case.1) {
             (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
             (_, Mode::Constant) => Count::is(0, 0, 0, 0),
             (Mode::Constant, _) => {
-                match (I::is_signed(), 2 * I::BITS < E::BaseField::size_in_data_bits() as u64) {
-                    (true, true) => Count::less_than((2 * I::BITS) + index(I::BITS) + 6, 0, (2 * I::BITS) + index(I::BITS) + 3, (2 * I::BITS) + index(I::BITS) + 4),
-                    (true, false) => Count::less_than(5 * I::BITS, 0, 1622, 1633),
-                    (false, true) => Count::less_than((2 * I::BITS) + index(I::BITS) + 3, 0, (2 * I::BITS) + index(I::BITS) + 3, (2 * I::BITS) + index(I::BITS) + 4),
-                    (false, false) => Count::less_than(I::BITS, 0, 849, 857),
+                match (I::is_signed(), 2 * I::BITS < data_bits, I::BITS < data_bits) {
+                    (true, true, _) => Count::less_than((2 * I::BITS) + index(I::BITS) + 6, 0, (2 * I::BITS) + index(I::BITS) + 3, (2 * I::BITS) + index(I::BITS) + 4),
+                    (true, false, true) => Count::less_than(5 * I::BITS, 0, 1622, 1633),
+                    (false, true, _) => Count::less_than((2 * I::BITS) + index(I::BITS) + 3, 0, (2 * I::BITS) + index(I::BITS) + 3, (2 * I::BITS) + index(I::BITS) + 4),
+                    (false, false, true) => Count::less_than(I::BITS, 0, 849, 857),
+                    (_, false, false) => Count::less_than(0, 0, I::BITS * (index(I::BITS) + 3), I::BITS * (index(I::BITS) + 3)),
                 }
             }
-            (_, _) => match (I::is_signed(), 2 * I::BITS < E::BaseField::size_in_data_bits() as u64) {
-                (true, true) => Count::is(6 + 2 * index(I::BITS), 0, (2 * I::BITS) + index(I::BITS) + 3, (2 * I::BITS) + index(I::BITS) + 4),
-                (true, false) => Count::is(4 * I::BITS, 0, 1622, 1633),
-                (false, true) => Count::is(3 + index(I::BITS), 0, (2 * I::BITS) + index(I::BITS) + 3, (2 * I::BITS) + index(I::BITS) + 4),
-                (false, false) => Count::is(I::BITS, 0, 849, 857),
+            (_, _) => match (I::is_signed(), 2 * I::BITS < data_bits, I::BITS < data_bits) {
+                (true, true, _) => Count::is(6 + 2 * index(I::BITS), 0, (2 * I::BITS) + index(I::BITS) + 3, (2 * I::BITS) + index(I::BITS) + 4),
+                (true, false, true) => Count::is(4 * I::BITS, 0, 1622, 1633),
+                (false, true, _) => Count::is(3 + index(I::BITS), 0, (2 * I::BITS) + index(I::BITS) + 3, (2 * I::BITS) + index(I::BITS) + 4),
+                (false, false, true) => Count::is(I::BITS, 0, 849, 857),
+                (_, false, false) => Count::is(0, 0, I::BITS * (index(I::BITS) + 3), I::BITS * (index(I::BITS) + 3)),
             },
         }
     }
@@ -283,6 +305,9 @@ mod tests {
     test_integer_binary!(run_test, i128, u8, shr);
     test_integer_binary!(run_test, i128, u16, shr);
     test_integer_binary!(run_test, i128, u32, shr);
+    test_integer_binary!(run_test, i256, u8, shr);
+    test_integer_binary!(run_test, i256, u16, shr);
+    test_integer_binary!(run_test, i256, u32, shr);
 
     test_integer_binary!(run_test, u8, u8, shr);
     test_integer_binary!(run_test, u8, u16, shr);
@@ -303,6 +328,9 @@ mod tests {
     test_integer_binary!(run_test, u128, u8, shr);
     test_integer_binary!(run_test, u128, u16, shr);
     test_integer_binary!(run_test, u128, u32, shr);
+    test_integer_binary!(run_test, u256, u8, shr);
+    test_integer_binary!(run_test, u256, u16, shr);
+    test_integer_binary!(run_test, u256, u32, shr);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, u8, shr, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, u8, shr, exhaustive);
@@ -77,13 +77,19 @@ impl<E: Environment, I: IntegerType> SubChecked<Self> for Integer<E, I> {
             // Instead of subtracting the bits of `self` and `other` directly, the integers are
             // converted into a field elements, and subtracted, before converting back to integers.
             // Note: This is safe as the field is larger than the maximum integer type supported.
-            let difference = self.to_field() + (!other).to_field() + Field::one();
-
-            // Extract the integer bits from the field element, with a carry bit.
-            let (difference, carry) = match difference.to_lower_bits_le(I::BITS as usize + 1).split_last() {
-                Some((carry, bits_le)) => (Integer::from_bits_le(bits_le), carry.clone()),
-                // Note: `E::halt` should never be invoked as `I::BITS as usize + 1` is greater than zero.
-                None => E::halt("Malformed difference detected during integer subtraction"),
+            let (difference, carry) = match I::BITS >= E::BaseField::size_in_data_bits() as u64 {
+                // Subtract the integers over limbs, as the difference does not fit within a single field element.
+                true => self.add_with_carry(&!other, &Boolean::constant(true)),
+                false => {
+                    let difference = self.to_field() + (!other).to_field() + Field::one();
+
+                    // Extract the integer bits from the field element, with a carry bit.
+                    match difference.to_lower_bits_le(I::BITS as usize + 1).split_last() {
+                        Some((carry, bits_le)) => (Integer::from_bits_le(bits_le), carry.clone()),
+                        // Note: `E::halt` should never be invoked as `I::BITS as usize + 1` is greater than zero.
+                        None => E::halt("Malformed difference detected during integer subtraction"),
+                    }
+                }
             };
 
             // Check for underflow.
@@ -128,16 +134,20 @@ impl<E: Environment, I: IntegerType> Metrics<dyn SubChecked<Integer<E, I>, Outpu
     type Case = (Mode, Mode);
 
     fn count(case: &Self::Case) -> Count {
+        // Retrieve the number of private variables and constraints used to subtract the integers.
+        let num_limbs = Self::num_add_limbs();
+        let (num_private, num_constraints) = (I::BITS + num_limbs, I::BITS + 2 * num_limbs);
+
         match I::is_signed() {
             true => match (case.0, case.1) {
                 (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
-                (Mode::Constant, _) => Count::is(0, 0, I::BITS + 3, I::BITS + 5),
-                (_, Mode::Constant) => Count::is(0, 0, I::BITS + 2, I::BITS + 4),
-                (_, _) => Count::is(0, 0, I::BITS + 4, I::BITS + 6),
+                (Mode::Constant, _) => Count::is(0, 0, num_private + 2, num_constraints + 3),
+                (_, Mode::Constant) => Count::is(0, 0, num_private + 1, num_constraints + 2),
+                (_, _) => Count::is(0, 0, num_private + 3, num_constraints + 4),
             },
             false => match (case.0, case.1) {
                 (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
-                (_, _) => Count::is(0, 0, I::BITS + 1, I::BITS + 3),
+                (_, _) => Count::is(0, 0, num_private, num_constraints + 1),
             },
         }
     }
@@ -233,12 +243,14 @@ mod tests {
     test_integer_binary!(run_test, i32, minus);
     test_integer_binary!(run_test, i64, minus);
     test_integer_binary!(run_test, i128, minus);
+    test_integer_binary!(run_test, i256, minus);
 
     test_integer_binary!(run_test, u8, minus);
     test_integer_binary!(run_test, u16, minus);
     test_integer_binary!(run_test, u32, minus);
     test_integer_binary!(run_test, u64, minus);
     test_integer_binary!(run_test, u128, minus);
+    test_integer_binary!(run_test, u256, minus);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, minus, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, minus, exhaustive);
@@ -23,6 +23,10 @@ impl<E: Environment, I: IntegerType> SubWrapped<Self> for Integer<E, I> {
         if self.is_constant() && other.is_constant() {
             // Compute the difference and return the new constant.
             witness!(|self, other| console::Integer::new(self.wrapping_sub(&other)))
+        } else if I::BITS >= E::BaseField::size_in_data_bits() as u64 {
+            // Subtract the integers over limbs, as the difference does not fit within a single field element.
+            // Drop the carry bit as the operation is wrapped subtraction.
+            self.add_with_carry(&!other, &Boolean::constant(true)).0
         } else {
             // Instead of subtracting the bits of `self` and `other` directly, the integers are
             // converted into field elements to perform the operation, before converting back to integers.
@@ -44,9 +48,10 @@ impl<E: Environment, I: IntegerType> Metrics<dyn SubWrapped<Integer<E, I>, Outpu
     type Case = (Mode, Mode);
 
     fn count(case: &Self::Case) -> Count {
+        let num_limbs = Self::num_add_limbs();
         match (case.0, case.1) {
             (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
-            (_, _) => Count::is(0, 0, I::BITS + 1, I::BITS + 2),
+            (_, _) => Count::is(0, 0, I::BITS + num_limbs, I::BITS + 2 * num_limbs),
         }
     }
 }
@@ -131,12 +136,14 @@ mod tests {
     test_integer_binary!(run_test, i32, minus);
     test_integer_binary!(run_test, i64, minus);
     test_integer_binary!(run_test, i128, minus);
+    test_integer_binary!(run_test, i256, minus);
 
     test_integer_binary!(run_test, u8, minus);
     test_integer_binary!(run_test, u16, minus);
     test_integer_binary!(run_test, u32, minus);
     test_integer_binary!(run_test, u64, minus);
     test_integer_binary!(run_test, u128, minus);
+    test_integer_binary!(run_test, u256, minus);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, minus, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, minus, exhaustive);
@@ -113,10 +113,12 @@ mod tests {
     test_integer_ternary!(run_test, i32, if, then, else);
     test_integer_ternary!(run_test, i64, if, then, else);
     test_integer_ternary!(run_test, i128, if, then, else);
+    test_integer_ternary!(run_test, i256, if, then, else);
 
     test_integer_ternary!(run_test, u8, if, then, else);
     test_integer_ternary!(run_test, u16, if, then, else);
     test_integer_ternary!(run_test, u32, if, then, else);
     test_integer_ternary!(run_test, u64, if, then, else);
     test_integer_ternary!(run_test, u128, if, then, else);
+    test_integer_ternary!(run_test, u256, if, then, else);
 }
@@ -177,12 +177,14 @@ mod tests {
     test_integer_binary!(run_test, i32, bitxor);
     test_integer_binary!(run_test, i64, bitxor);
     test_integer_binary!(run_test, i128, bitxor);
+    test_integer_binary!(run_test, i256, bitxor);
 
     test_integer_binary!(run_test, u8, bitxor);
     test_integer_binary!(run_test, u16, bitxor);
     test_integer_binary!(run_test, u32, bitxor);
     test_integer_binary!(run_test, u64, bitxor);
     test_integer_binary!(run_test, u128, bitxor);
+    test_integer_binary!(run_test, u256, bitxor);
 
     test_integer_binary!(#[ignore], run_exhaustive_test, u8, bitxor, exhaustive);
     test_integer_binary!(#[ignore], run_exhaustive_test, i8, bitxor, exhaustive);
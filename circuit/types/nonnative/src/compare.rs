@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> Compare<NonNativeField<E, P>> for NonNativeField<E, P> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is less than `other`.
+    ///
+    /// This method computes `self - other` limb by limb with a borrow chain,
+    /// where each borrow is witnessed and each limb of the difference is range-checked.
+    /// The final borrow is `true` if and only if `self < other`.
+    fn is_less_than(&self, other: &Self) -> Self::Output {
+        // If both operands are constant, the result is also constant.
+        if self.is_constant() && other.is_constant() {
+            return Boolean::constant(self.eject_value() < other.eject_value());
+        }
+
+        let self_limbs = self.eject_value().to_limbs_le(Self::NUM_LIMBS);
+        let other_limbs = other.eject_value().to_limbs_le(Self::NUM_LIMBS);
+        let radix = Self::new_native(Mode::Constant, E::BaseField::from(1u128 << Self::LIMB_BITS));
+
+        let mut borrow = Boolean::constant(false);
+        for (k, (a, b)) in self.limbs.iter().zip_eq(&other.limbs).enumerate() {
+            // Witness the outgoing borrow, which is `true` if `a - b - borrow` is negative.
+            let difference = i128::from(self_limbs[k]) - i128::from(other_limbs[k]) - i128::from(borrow.eject_value());
+            let next_borrow = Boolean::new(Mode::Private, difference < 0);
+            // Ensure `a - b - borrow + next_borrow * 2^LIMB_BITS` is in `[0, 2^LIMB_BITS)`.
+            let difference = a - b - Field::from_boolean(&borrow) + Field::from_boolean(&next_borrow) * &radix;
+            difference.to_lower_bits_le(Self::LIMB_BITS);
+            borrow = next_borrow;
+        }
+        borrow
+    }
+
+    /// Returns `true` if `self` is greater than `other`.
+    fn is_greater_than(&self, other: &Self) -> Self::Output {
+        other.is_less_than(self)
+    }
+
+    /// Returns `true` if `self` is less than or equal to `other`.
+    fn is_less_than_or_equal(&self, other: &Self) -> Self::Output {
+        !other.is_less_than(self)
+    }
+
+    /// Returns `true` if `self` is greater than or equal to `other`.
+    fn is_greater_than_or_equal(&self, other: &Self) -> Self::Output {
+        !self.is_less_than(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_is_less_than(mode_a: Mode, mode_b: Mode, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Secp256k1Base::rand(&mut rng);
+            // Exercise both equal and distinct operands.
+            let second = if i % 10 == 0 { first.clone() } else { Secp256k1Base::rand(&mut rng) };
+            let expected = first < second;
+
+            let a = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(mode_a, first);
+            let b = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(mode_b, second);
+
+            Circuit::scope(format!("{mode_a} < {mode_b} {i}"), || {
+                let candidate = a.is_less_than(&b);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(<=4096, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_constant_is_less_than_constant() {
+        check_is_less_than(Mode::Constant, Mode::Constant, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_constant_is_less_than_private() {
+        check_is_less_than(Mode::Constant, Mode::Private, 0, 260, 264);
+    }
+
+    #[test]
+    fn test_public_is_less_than_constant() {
+        check_is_less_than(Mode::Public, Mode::Constant, 0, 260, 264);
+    }
+
+    #[test]
+    fn test_private_is_less_than_private() {
+        check_is_less_than(Mode::Private, Mode::Private, 0, 260, 264);
+    }
+
+    #[test]
+    fn test_comparisons() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let first = Secp256k1Base::rand(&mut rng);
+            let second = Secp256k1Base::rand(&mut rng);
+
+            let a = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(Mode::Private, first.clone());
+            let b = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(Mode::Private, second.clone());
+
+            assert_eq!(first > second, a.is_greater_than(&b).eject_value());
+            assert_eq!(first <= second, a.is_less_than_or_equal(&b).eject_value());
+            assert_eq!(first >= second, a.is_greater_than_or_equal(&b).eject_value());
+            assert!(a.is_less_than_or_equal(&a).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
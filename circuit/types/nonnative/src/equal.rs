@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> Equal<Self> for NonNativeField<E, P> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` and `other` are equal.
+    ///
+    /// As both elements are canonical, this method compares the limbs pairwise.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        self.limbs
+            .iter()
+            .zip_eq(&other.limbs)
+            .fold(Boolean::constant(true), |is_equal, (a, b)| is_equal & a.is_equal(b))
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        !self.is_equal(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_is_equal(mode_a: Mode, mode_b: Mode, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Secp256k1Base::rand(&mut rng);
+            // Exercise both equal and distinct operands.
+            let second = if i % 2 == 0 { first.clone() } else { Secp256k1Base::rand(&mut rng) };
+            let expected = first == second;
+
+            let a = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(mode_a, first);
+            let b = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(mode_b, second);
+
+            Circuit::scope(format!("{mode_a} == {mode_b} {i}"), || {
+                let candidate = a.is_equal(&b);
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(!expected, a.is_not_equal(&b).eject_value());
+                assert_scope!(<=4096, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_constant_is_equal_constant() {
+        check_is_equal(Mode::Constant, Mode::Constant, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_private_is_equal_private() {
+        check_is_equal(Mode::Private, Mode::Private, 0, 22, 22);
+    }
+}
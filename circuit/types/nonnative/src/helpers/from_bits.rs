@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> NonNativeField<E, P> {
+    /// Initializes a new non-native field element from a list of little-endian bits,
+    /// reducing the integer they represent modulo the non-native modulus.
+    pub fn from_bits_le_mod_order(bits_le: &[Boolean<E>]) -> Self {
+        // If all of the bits are constant, the result is also constant.
+        if bits_le.iter().all(|bit| bit.is_constant()) {
+            return Self::constant(console::NonNativeField::from_bits_le_mod_order(&bits_le.eject_value()));
+        }
+
+        // Witness the reduced element.
+        let output = Self::new(Mode::Private, console::NonNativeField::from_bits_le_mod_order(&bits_le.eject_value()));
+
+        // Pack the bits into limbs, which is free as each limb is a linear combination of the bits.
+        let mut columns = bits_le.chunks(Self::LIMB_BITS).map(Field::from_bits_le).collect::<Vec<_>>();
+        if columns.len() < Self::NUM_LIMBS {
+            columns.resize(Self::NUM_LIMBS, Field::zero());
+        }
+        for (column, limb) in columns.iter_mut().zip(&output.limbs) {
+            *column -= limb;
+        }
+
+        // Ensure `bits_le - output` is a multiple of the modulus.
+        // As the modulus is at least `2^(SIZE_IN_BITS - 1)`, the quotient is less than `2^(len - SIZE_IN_BITS + 1)`.
+        Self::enforce_multiple_of_modulus(columns, (bits_le.len() + 1).saturating_sub(P::SIZE_IN_BITS));
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits_le_mod_order(
+        mode: Mode,
+        num_bits: usize,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let bits = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            let expected = Secp256k1Base::from_bits_le_mod_order(&bits);
+            let candidate: Vec<Boolean<Circuit>> = Inject::new(mode, bits);
+
+            Circuit::scope(&format!("{mode} {i}"), || {
+                let candidate = NonNativeField::<Circuit, Secp256k1BaseParameters>::from_bits_le_mod_order(&candidate);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(<=4096, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_mod_order_constant() {
+        check_from_bits_le_mod_order(Mode::Constant, 512, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_from_bits_le_mod_order_private() {
+        check_from_bits_le_mod_order(Mode::Private, 512, 0, 1281, 1290);
+    }
+
+    #[test]
+    fn test_from_bits_le_mod_order_unreduced() {
+        // Ensure a single element of bits that exceeds the modulus is reduced.
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let bits = (0..Secp256k1Base::SIZE_IN_BITS).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            let expected = Secp256k1Base::from_bits_le_mod_order(&bits);
+            let candidate: Vec<Boolean<Circuit>> = Inject::new(Mode::Private, bits);
+            let candidate = NonNativeField::<Circuit, Secp256k1BaseParameters>::from_bits_le_mod_order(&candidate);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod from_bits;
+mod one;
+mod reduce;
+mod sum_of_products;
+mod to_bits;
+mod zero;
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> NonNativeField<E, P> {
+    /// Returns a new native field element of the given mode and value.
+    pub(crate) fn new_native(mode: Mode, value: E::BaseField) -> Field<E> {
+        Field::from(LinearCombination::from(E::new_variable(mode, value)))
+    }
+
+    /// Returns the given native field element as an unsigned integer.
+    pub(crate) fn to_biguint(value: &E::BaseField) -> BigUint {
+        value.to_bigint().to_biguint()
+    }
+
+    /// Returns the given native field element as a signed integer,
+    /// where elements greater than half of the native modulus are interpreted as negative.
+    pub(crate) fn to_signed(value: &E::BaseField) -> BigInt {
+        let value = Self::to_biguint(value);
+        let modulus = E::BaseField::modulus().to_biguint();
+        match value > (&modulus >> 1u32) {
+            true => BigInt::from_biguint(Sign::Minus, modulus - value),
+            false => BigInt::from_biguint(Sign::Plus, value),
+        }
+    }
+
+    /// Returns the native field element of the given signed integer.
+    pub(crate) fn from_signed(value: &BigInt) -> E::BaseField {
+        let magnitude = E::BaseField::from_bytes_le_mod_order(&value.magnitude().to_bytes_le());
+        match value.sign() {
+            Sign::Minus => -magnitude,
+            _ => magnitude,
+        }
+    }
+
+    /// Enforces that each limb is in `[0, 2^LIMB_BITS)`, and that the limbs represent an integer less than the modulus.
+    pub(crate) fn enforce_canonical(limbs: &[Field<E>]) {
+        // Decompose each limb into bits, which enforces the limb is in range.
+        let bits_le = limbs.iter().flat_map(|limb| limb.to_lower_bits_le(Self::LIMB_BITS)).collect::<Vec<_>>();
+        // Ensure the limbs represent an integer that is at most `modulus - 1`.
+        let modulus_minus_one = P::modulus() - 1u32;
+        let modulus_minus_one_bits_le = (0..bits_le.len()).map(|i| modulus_minus_one.bit(i as u64)).collect::<Vec<_>>();
+        Boolean::assert_less_than_or_equal_constant(&bits_le, &modulus_minus_one_bits_le);
+    }
+}
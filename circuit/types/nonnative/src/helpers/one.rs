@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> One for NonNativeField<E, P> {
+    type Boolean = Boolean<E>;
+
+    /// Returns the `1` element of the non-native field.
+    fn one() -> Self {
+        Self::constant(console::NonNativeField::one())
+    }
+
+    /// Returns `true` if `self` is one.
+    fn is_one(&self) -> Self::Boolean {
+        self.is_equal(&Self::one())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    type CurrentField = NonNativeField<Circuit, Secp256k1BaseParameters>;
+
+    #[test]
+    fn test_one() {
+        let one = console::NonNativeField::<Secp256k1BaseParameters>::one();
+
+        Circuit::scope("One", || {
+            let candidate = CurrentField::one();
+            assert_eq!(one, candidate.eject_value());
+            assert_scope!(<=4, 0, 0, 0);
+        });
+    }
+
+    #[test]
+    fn test_is_one() {
+        let candidate = CurrentField::one();
+        assert!(candidate.is_one().eject_value());
+
+        let candidate = CurrentField::new(Mode::Private, console::NonNativeField::zero());
+        assert!(!candidate.is_one().eject_value());
+        Circuit::reset();
+    }
+}
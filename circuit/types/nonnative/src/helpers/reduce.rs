@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> NonNativeField<E, P> {
+    /// The number of bits in each carry of the carry chain.
+    const CARRY_BITS: usize = 71;
+    /// The maximum number of bits in the magnitude of each column of the carry chain.
+    pub(super) const MAX_COLUMN_BITS: usize = Self::LIMB_BITS + Self::CARRY_BITS - 2;
+
+    /// Enforces that the signed integer `Σ_k columns[k] * 2^(LIMB_BITS * k)` is equal to `quotient * modulus`,
+    /// for some witnessed quotient in `[0, 2^num_quotient_bits)`.
+    ///
+    /// Each column is a linear combination of limb products, and its magnitude, including the subtracted
+    /// `quotient * modulus`, must be less than `2^MAX_COLUMN_BITS`, which holds for the product of two elements
+    /// of up to `8` limbs.
+    /// This ensures every carry is less than `2^(CARRY_BITS - 1)` in magnitude.
+    ///
+    /// The quotient is witnessed in limbs, and `quotient * modulus` is subtracted from the columns.
+    /// The resulting columns are then enforced to be zero by propagating a signed carry from each column
+    /// to the next, where every carry is range-checked to `CARRY_BITS` bits and the final column must be zero.
+    pub(crate) fn enforce_multiple_of_modulus(mut columns: Vec<Field<E>>, num_quotient_bits: usize) {
+        // Ensure the columns are within the supported bounds.
+        if Self::NUM_LIMBS > 8 {
+            E::halt(format!("Non-native fields of more than 8 limbs are not supported (found {})", Self::NUM_LIMBS))
+        }
+
+        // Compute the quotient as an integer.
+        let modulus = P::modulus();
+        let value = columns.iter().rev().fold(BigInt::default(), |accumulator, column| {
+            (accumulator << Self::LIMB_BITS) + Self::to_signed(&column.eject_value())
+        });
+        // If the value is not a (bounded) multiple of the modulus, the quotient is set to zero,
+        // which causes the constraints below to be unsatisfied.
+        let quotient = match value.to_biguint() {
+            Some(value) if (&value % &modulus) == BigUint::default() => match value / &modulus {
+                quotient if quotient.bits() <= num_quotient_bits as u64 => quotient,
+                _ => BigUint::default(),
+            },
+            _ => BigUint::default(),
+        };
+
+        // Witness the quotient in limbs, and ensure each limb is in range.
+        let quotient_digits = quotient.to_u64_digits();
+        let quotient_limbs = (0..(num_quotient_bits + Self::LIMB_BITS - 1) / Self::LIMB_BITS)
+            .map(|i| {
+                let limb = Self::new_native(
+                    Mode::Private,
+                    E::BaseField::from(quotient_digits.get(i).copied().unwrap_or_default()),
+                );
+                limb.to_lower_bits_le(core::cmp::min(Self::LIMB_BITS, num_quotient_bits - i * Self::LIMB_BITS));
+                limb
+            })
+            .collect::<Vec<_>>();
+
+        // Subtract `quotient * modulus` from the columns. As the modulus is a constant, this is free.
+        if !quotient_limbs.is_empty() {
+            let num_columns = core::cmp::max(columns.len(), quotient_limbs.len() + P::MODULUS.len() - 1);
+            columns.resize(num_columns, Field::zero());
+        }
+        for (i, quotient_limb) in quotient_limbs.iter().enumerate() {
+            for (j, modulus_limb) in P::MODULUS.iter().enumerate() {
+                columns[i + j] -= quotient_limb * Self::new_native(Mode::Constant, E::BaseField::from(*modulus_limb));
+            }
+        }
+
+        // Propagate the carries, and ensure each column is a multiple of `2^LIMB_BITS`.
+        let radix = Self::new_native(Mode::Constant, E::BaseField::from(1u128 << Self::LIMB_BITS));
+        let offset = BigInt::from(1u8) << (Self::CARRY_BITS - 1);
+        let offset_field = Self::new_native(Mode::Constant, Self::from_signed(&offset));
+
+        let mut carry = Field::zero();
+        if let Some((last, rest)) = columns.split_last() {
+            for column in rest {
+                let total = column + &carry;
+                let carry_value = Self::to_signed(&total.eject_value()) >> Self::LIMB_BITS;
+                carry = match total.is_constant() {
+                    true => Self::new_native(Mode::Constant, Self::from_signed(&carry_value)),
+                    false => {
+                        // Witness the carry, shifted by the offset so that it is non-negative.
+                        let shifted = Self::new_native(Mode::Private, Self::from_signed(&(carry_value + &offset)));
+                        // Ensure the shifted carry is in `[0, 2^CARRY_BITS)`.
+                        shifted.to_lower_bits_le(Self::CARRY_BITS);
+                        shifted - &offset_field
+                    }
+                };
+                // Ensure `column + carry_in == carry_out * 2^LIMB_BITS`.
+                E::assert_eq(&total, &carry * &radix);
+            }
+            // Ensure the final column (with its carry) is zero.
+            E::assert_eq(last + &carry, E::zero());
+        }
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> NonNativeField<E, P> {
+    /// Enforces that `Σ c_i * a_i * b_i + Σ d_j * x_j` is zero modulo the non-native modulus,
+    /// for the given products `(c_i, a_i, b_i)` and terms `(d_j, x_j)`, with integer coefficients.
+    ///
+    /// This method checks the whole relation with a single carry chain, which is cheaper than
+    /// witnessing each product separately. To ensure the reduced integer is nonnegative,
+    /// the smallest multiple of the modulus that exceeds the negative terms is added to it.
+    pub fn assert_sum_of_products_is_zero(products: &[(i64, &Self, &Self)], terms: &[(i64, &Self)]) {
+        let modulus = P::modulus();
+        let max = &modulus - 1u32;
+
+        // Compute the bounds of the positive and negative parts of the sum.
+        let (mut positive, mut negative) = (BigUint::default(), BigUint::default());
+        let bounds =
+            products.iter().map(|(c, ..)| (*c, &max * &max)).chain(terms.iter().map(|(c, _)| (*c, max.clone())));
+        for (coefficient, bound) in bounds {
+            match coefficient < 0 {
+                true => negative += bound * coefficient.unsigned_abs(),
+                false => positive += bound * coefficient.unsigned_abs(),
+            }
+        }
+        // Compute the offset, and the number of bits in the quotient of the offset sum.
+        let offset = (&negative + &max) / &modulus * &modulus;
+        let num_quotient_bits = ((positive + &offset) / &modulus).bits() as usize;
+        let offset_limbs = offset.to_u64_digits();
+
+        // Compute the columns of the sum, and their bounds.
+        let coefficient = |c: i64| Self::new_native(Mode::Constant, Self::from_signed(&BigInt::from(c)));
+        let limb_max = BigUint::from(u64::MAX);
+        let num_columns = core::cmp::max(2 * Self::NUM_LIMBS - 1, offset_limbs.len());
+        let mut columns = vec![Field::zero(); num_columns];
+        let mut column_bounds = vec![BigUint::default(); num_columns];
+        for (c, a, b) in products {
+            let constant = coefficient(*c);
+            for (i, a) in a.limbs.iter().enumerate() {
+                for (j, b) in b.limbs.iter().enumerate() {
+                    columns[i + j] += &constant * (a * b);
+                    column_bounds[i + j] += &limb_max * &limb_max * c.unsigned_abs();
+                }
+            }
+        }
+        for (c, x) in terms {
+            let constant = coefficient(*c);
+            for (i, x) in x.limbs.iter().enumerate() {
+                columns[i] += &constant * x;
+                column_bounds[i] += &limb_max * c.unsigned_abs();
+            }
+        }
+        for (i, limb) in offset_limbs.iter().enumerate() {
+            columns[i] += Self::new_native(Mode::Constant, E::BaseField::from(*limb));
+            column_bounds[i] += *limb;
+        }
+
+        // If the sum is constant, check it directly.
+        if columns.iter().all(|column| column.is_constant()) {
+            let value = columns.iter().rev().fold(BigInt::default(), |accumulator, column| {
+                (accumulator << Self::LIMB_BITS) + Self::to_signed(&column.eject_value())
+            });
+            if value % BigInt::from(modulus) != BigInt::default() {
+                E::halt("The constant sum of products is not zero modulo the non-native modulus")
+            }
+            return;
+        }
+
+        // Ensure the columns, with the subtracted multiple of the modulus, are within the supported bounds.
+        let num_quotient_limbs = (num_quotient_bits + Self::LIMB_BITS - 1) / Self::LIMB_BITS;
+        column_bounds
+            .resize(core::cmp::max(num_columns, num_quotient_limbs + P::MODULUS.len() - 1), BigUint::default());
+        for i in 0..num_quotient_limbs {
+            for (j, modulus_limb) in P::MODULUS.iter().enumerate() {
+                column_bounds[i + j] += &limb_max * *modulus_limb;
+            }
+        }
+        if column_bounds.iter().any(|bound| bound.bits() as usize > Self::MAX_COLUMN_BITS) {
+            E::halt("The sum of products exceeds the capacity of the non-native carry chain")
+        }
+
+        // Ensure the sum is a multiple of the modulus.
+        Self::enforce_multiple_of_modulus(columns, num_quotient_bits);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_assert_sum_of_products_is_zero(mode: Mode, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let (a, b, c, d) = (
+                Secp256k1Base::rand(&mut rng),
+                Secp256k1Base::rand(&mut rng),
+                Secp256k1Base::rand(&mut rng),
+                Secp256k1Base::rand(&mut rng),
+            );
+            // Compute `x = 2 * a * b - c * d`.
+            let x = (&a * &b) + (&a * &b) - (&c * &d);
+
+            let [a, b, c, d, x] =
+                [a, b, c, d, x].map(|value| NonNativeField::<Circuit, Secp256k1BaseParameters>::new(mode, value));
+            Circuit::scope(format!("{mode} {i}"), || {
+                // Ensure `2 * a * b - c * d - x = 0`.
+                NonNativeField::assert_sum_of_products_is_zero(&[(2, &a, &b), (-1, &c, &d)], &[(-1, &x)]);
+                assert_scope!(<=1024, num_public, num_private, num_constraints);
+            });
+            assert!(Circuit::is_satisfied());
+
+            // Ensure the relation fails for a different term.
+            if !mode.is_constant() {
+                NonNativeField::assert_sum_of_products_is_zero(&[(2, &a, &b), (-1, &c, &d)], &[(-1, &a)]);
+                assert!(!Circuit::is_satisfied());
+            }
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_assert_sum_of_products_is_zero_constant() {
+        check_assert_sum_of_products_is_zero(Mode::Constant, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_assert_sum_of_products_is_zero_private() {
+        check_assert_sum_of_products_is_zero(Mode::Private, 0, 799, 807);
+    }
+}
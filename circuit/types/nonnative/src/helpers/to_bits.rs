@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> ToBits for NonNativeField<E, P> {
+    type Boolean = Boolean<E>;
+
+    /// Outputs the little-endian bit representation of `self`, of length `P::SIZE_IN_BITS`.
+    fn write_bits_le(&self, vec: &mut Vec<Self::Boolean>) {
+        (&self).write_bits_le(vec);
+    }
+
+    /// Outputs the big-endian bit representation of `self`, of length `P::SIZE_IN_BITS`.
+    fn write_bits_be(&self, vec: &mut Vec<Self::Boolean>) {
+        (&self).write_bits_be(vec);
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> ToBits for &NonNativeField<E, P> {
+    type Boolean = Boolean<E>;
+
+    /// Outputs the little-endian bit representation of `self`, of length `P::SIZE_IN_BITS`.
+    fn write_bits_le(&self, vec: &mut Vec<Self::Boolean>) {
+        // As the element is canonical, the bits of the limbs are the unique bit representation of the element.
+        for (i, limb) in self.limbs.iter().enumerate() {
+            let num_bits = core::cmp::min(
+                NonNativeField::<E, P>::LIMB_BITS,
+                P::SIZE_IN_BITS - i * NonNativeField::<E, P>::LIMB_BITS,
+            );
+            vec.extend(limb.to_lower_bits_le(num_bits));
+        }
+    }
+
+    /// Outputs the big-endian bit representation of `self`, of length `P::SIZE_IN_BITS`.
+    fn write_bits_be(&self, vec: &mut Vec<Self::Boolean>) {
+        let initial_len = vec.len();
+        self.write_bits_le(vec);
+        vec[initial_len..].reverse();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_to_bits_le(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let expected = Secp256k1Base::rand(&mut rng);
+            let candidate = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(mode, expected.clone());
+
+            Circuit::scope(&format!("{mode} {i}"), || {
+                let candidate = candidate.to_bits_le();
+                assert_eq!(Secp256k1Base::SIZE_IN_BITS, candidate.len());
+                assert_eq!(expected.to_bits_le(), candidate.eject_value());
+                assert_scope!(<=num_constants, num_public, num_private, num_constraints);
+            });
+
+            // Ensure the big-endian bits are the reverse of the little-endian bits.
+            let candidate_be = candidate.to_bits_be();
+            assert_eq!(expected.to_bits_be(), candidate_be.eject_value());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_to_bits_le_constant() {
+        check_to_bits_le(Mode::Constant, 1024, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_to_bits_le_public() {
+        check_to_bits_le(Mode::Public, 1024, 0, 256, 260);
+    }
+
+    #[test]
+    fn test_to_bits_le_private() {
+        check_to_bits_le(Mode::Private, 1024, 0, 256, 260);
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> Zero for NonNativeField<E, P> {
+    type Boolean = Boolean<E>;
+
+    /// Returns the `0` element of the non-native field.
+    fn zero() -> Self {
+        Self::constant(console::NonNativeField::zero())
+    }
+
+    /// Returns `true` if `self` is zero.
+    fn is_zero(&self) -> Self::Boolean {
+        self.is_equal(&Self::zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    type CurrentField = NonNativeField<Circuit, Secp256k1BaseParameters>;
+
+    #[test]
+    fn test_zero() {
+        let zero = console::NonNativeField::<Secp256k1BaseParameters>::zero();
+
+        Circuit::scope("Zero", || {
+            let candidate = CurrentField::zero();
+            assert_eq!(zero, candidate.eject_value());
+            assert_scope!(<=4, 0, 0, 0);
+        });
+    }
+
+    #[test]
+    fn test_is_zero() {
+        let candidate = CurrentField::zero();
+        assert!(candidate.is_zero().eject_value());
+
+        let candidate = CurrentField::new(Mode::Private, console::NonNativeField::from_u64(1));
+        assert!(!candidate.is_zero().eject_value());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> Inverse for NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    /// Returns the multiplicative inverse of `self`.
+    ///
+    /// This method witnesses the inverse, and enforces `self * inverse - 1` is a multiple of the modulus.
+    /// If `self` is zero, the constraints are unsatisfiable.
+    fn inverse(&self) -> Self::Output {
+        // If the operand is constant, the result is also constant.
+        if self.is_constant() {
+            return match self.eject_value().inverse() {
+                Ok(inverse) => Self::constant(inverse),
+                Err(error) => E::halt(format!("Failed to invert a constant non-native field element: {error}")),
+            };
+        }
+
+        // Witness the inverse. If `self` is zero, the inverse is set to zero, which does not satisfy the constraints.
+        let inverse = Self::new(Mode::Private, self.eject_value().inverse().unwrap_or_default());
+
+        // Compute the columns of the schoolbook product `self * inverse`, and subtract one from them.
+        let mut columns = vec![Field::zero(); 2 * Self::NUM_LIMBS - 1];
+        for (i, a) in self.limbs.iter().enumerate() {
+            for (j, b) in inverse.limbs.iter().enumerate() {
+                columns[i + j] += a * b;
+            }
+        }
+        columns[0] -= Field::one();
+        // Ensure `self * inverse - 1` is a multiple of the modulus, with a quotient less than the modulus.
+        Self::enforce_multiple_of_modulus(columns, P::SIZE_IN_BITS);
+
+        inverse
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Div<NonNativeField<E, P>> for NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn div(self, other: NonNativeField<E, P>) -> Self::Output {
+        self / &other
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Div<&NonNativeField<E, P>> for NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn div(self, other: &NonNativeField<E, P>) -> Self::Output {
+        let mut result = self;
+        result /= other;
+        result
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Div<NonNativeField<E, P>> for &NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn div(self, other: NonNativeField<E, P>) -> Self::Output {
+        self / &other
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Div<&NonNativeField<E, P>> for &NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn div(self, other: &NonNativeField<E, P>) -> Self::Output {
+        let mut result = self.clone();
+        result /= other;
+        result
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> DivAssign<NonNativeField<E, P>> for NonNativeField<E, P> {
+    fn div_assign(&mut self, other: NonNativeField<E, P>) {
+        *self /= &other;
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> DivAssign<&NonNativeField<E, P>> for NonNativeField<E, P> {
+    /// Divides `self` by `other`, by multiplying `self` with the inverse of `other`.
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, other: &NonNativeField<E, P>) {
+        *self *= other.inverse();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_inverse(mode: Mode, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Secp256k1Base::rand(&mut rng);
+            let expected = value.inverse().unwrap();
+            let candidate = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(mode, value);
+
+            Circuit::scope(format!("Inverse {mode} {i}"), || {
+                let candidate = candidate.inverse();
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(<=4096, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_inverse_constant() {
+        check_inverse(Mode::Constant, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_inverse_public() {
+        check_inverse(Mode::Public, 0, 1223, 1231);
+    }
+
+    #[test]
+    fn test_inverse_private() {
+        check_inverse(Mode::Private, 0, 1223, 1231);
+    }
+
+    #[test]
+    fn test_inverse_zero_fails() {
+        let zero = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(Mode::Private, Secp256k1Base::zero());
+        let _candidate = zero.inverse();
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_div() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let first = Secp256k1Base::rand(&mut rng);
+            let second = Secp256k1Base::rand(&mut rng);
+            let expected = first.clone() / second.clone();
+
+            let a = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(Mode::Private, first);
+            let b = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(Mode::Public, second);
+            assert_eq!(expected, (a / b).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
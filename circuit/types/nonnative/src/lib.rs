@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![forbid(unsafe_code)]
+#![allow(clippy::too_many_arguments)]
+#![cfg_attr(test, allow(clippy::assertions_on_result_states))]
+
+mod helpers;
+
+pub mod add;
+pub mod compare;
+pub mod equal;
+pub mod inverse;
+pub mod mul;
+pub mod neg;
+pub mod sub;
+pub mod ternary;
+
+#[cfg(test)]
+use console::{Secp256k1Base, TestRng, Uniform};
+#[cfg(test)]
+use snarkvm_circuit_environment::assert_scope;
+
+pub use console::{
+    Bls12_377BaseParameters,
+    Bn254BaseParameters,
+    Bn254ScalarParameters,
+    Ed25519BaseParameters,
+    Ed25519ScalarParameters,
+    NonNativeParameters,
+    Secp256k1BaseParameters,
+    Secp256k1ScalarParameters,
+};
+
+use snarkvm_circuit_environment::prelude::*;
+use snarkvm_circuit_types_boolean::Boolean;
+use snarkvm_circuit_types_field::Field;
+
+use core::marker::PhantomData;
+use num_bigint::{BigInt, BigUint, Sign};
+use snarkvm_utilities::biginteger::BigInteger as _;
+
+/// An element of a prime field whose modulus differs from the native field, i.e. a *non-native* field.
+///
+/// The element is represented by `NUM_LIMBS` little-endian limbs of `LIMB_BITS` bits each,
+/// and is always in canonical form, i.e. the integer it represents is less than the modulus.
+#[derive(Clone)]
+pub struct NonNativeField<E: Environment, P: NonNativeParameters> {
+    /// The little-endian limbs of the element, each in `[0, 2^LIMB_BITS)`.
+    limbs: Vec<Field<E>>,
+    /// The parameters of the field.
+    _parameters: PhantomData<P>,
+}
+
+impl<E: Environment, P: NonNativeParameters> NonNativeField<E, P> {
+    /// The number of bits in each limb.
+    pub const LIMB_BITS: usize = 64;
+    /// The number of limbs in each element.
+    pub const NUM_LIMBS: usize = (P::SIZE_IN_BITS + Self::LIMB_BITS - 1) / Self::LIMB_BITS;
+    /// The field size in bits.
+    pub const SIZE_IN_BITS: usize = P::SIZE_IN_BITS;
+
+    /// Returns the little-endian limbs of the element.
+    pub fn limbs(&self) -> &[Field<E>] {
+        &self.limbs
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, P: NonNativeParameters> Inject for NonNativeField<E, P> {
+    type Primitive = console::NonNativeField<P>;
+
+    /// Initializes a non-native field circuit from a console non-native field.
+    /// If the mode is not constant, this method enforces that each limb is in range,
+    /// and that the element is less than the modulus.
+    fn new(mode: Mode, value: Self::Primitive) -> Self {
+        let limbs = value
+            .to_limbs_le(Self::NUM_LIMBS)
+            .into_iter()
+            .map(|limb| Self::new_native(mode, E::BaseField::from(limb)))
+            .collect::<Vec<_>>();
+        // Ensure the element is in canonical form.
+        if !mode.is_constant() {
+            Self::enforce_canonical(&limbs);
+        }
+        Self { limbs, _parameters: PhantomData }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, P: NonNativeParameters> Eject for NonNativeField<E, P> {
+    type Primitive = console::NonNativeField<P>;
+
+    /// Ejects the mode of the non-native field circuit.
+    fn eject_mode(&self) -> Mode {
+        self.limbs.eject_mode()
+    }
+
+    /// Ejects the non-native field circuit as a console non-native field.
+    fn eject_value(&self) -> Self::Primitive {
+        console::NonNativeField::new(self.limbs.iter().rev().fold(BigUint::default(), |accumulator, limb| {
+            (accumulator << Self::LIMB_BITS) + Self::to_biguint(&limb.eject_value())
+        }))
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, P: NonNativeParameters> TypeName for NonNativeField<E, P> {
+    /// Returns the type name of the circuit as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        console::NonNativeField::<P>::type_name()
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, P: NonNativeParameters> Debug for NonNativeField<E, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, P: NonNativeParameters> Display for NonNativeField<E, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.eject_value(), self.eject_mode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_new(mode: Mode, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let expected = Secp256k1Base::rand(&mut rng);
+
+            Circuit::scope(format!("New {mode} {i}"), || {
+                let candidate = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(mode, expected.clone());
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(mode, candidate.eject_mode());
+                assert_scope!(<=1024, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_new_constant() {
+        check_new(Mode::Constant, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_new_public() {
+        check_new(Mode::Public, 4, 511, 516);
+    }
+
+    #[test]
+    fn test_new_private() {
+        check_new(Mode::Private, 0, 515, 516);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let expected = Secp256k1Base::rand(&mut rng);
+            let candidate = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(mode, expected.clone());
+            assert_eq!(format!("{expected}.{mode}"), format!("{candidate}"));
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_new_fails_on_non_canonical_limbs() {
+        // Construct limbs that represent the modulus itself, which is not canonical.
+        let limbs = Secp256k1BaseParameters::MODULUS
+            .iter()
+            .map(|limb| {
+                NonNativeField::<Circuit, Secp256k1BaseParameters>::new_native(
+                    Mode::Private,
+                    <Circuit as Environment>::BaseField::from(*limb),
+                )
+            })
+            .collect::<Vec<_>>();
+        NonNativeField::<Circuit, Secp256k1BaseParameters>::enforce_canonical(&limbs);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
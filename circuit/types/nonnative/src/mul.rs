@@ -0,0 +1,186 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> Mul<NonNativeField<E, P>> for NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn mul(self, other: NonNativeField<E, P>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Mul<&NonNativeField<E, P>> for NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn mul(self, other: &NonNativeField<E, P>) -> Self::Output {
+        let mut result = self;
+        result *= other;
+        result
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Mul<NonNativeField<E, P>> for &NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn mul(self, other: NonNativeField<E, P>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Mul<&NonNativeField<E, P>> for &NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn mul(self, other: &NonNativeField<E, P>) -> Self::Output {
+        let mut result = self.clone();
+        result *= other;
+        result
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> MulAssign<NonNativeField<E, P>> for NonNativeField<E, P> {
+    fn mul_assign(&mut self, other: NonNativeField<E, P>) {
+        *self *= &other;
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> MulAssign<&NonNativeField<E, P>> for NonNativeField<E, P> {
+    /// Multiplies `self` by `other`.
+    ///
+    /// This method witnesses the product, and enforces `self * other - product` is a multiple of the modulus,
+    /// using one native multiplication per pair of (non-constant) limbs.
+    fn mul_assign(&mut self, other: &NonNativeField<E, P>) {
+        let product = self.eject_value() * other.eject_value();
+        // If both operands are constant, the result is also constant.
+        if self.is_constant() && other.is_constant() {
+            *self = Self::constant(product);
+            return;
+        }
+
+        // Witness the product.
+        let product = Self::new(Mode::Private, product);
+
+        // Compute the columns of the schoolbook product `self * other`, and subtract the product from them.
+        let mut columns = vec![Field::zero(); 2 * Self::NUM_LIMBS - 1];
+        for (i, a) in self.limbs.iter().enumerate() {
+            for (j, b) in other.limbs.iter().enumerate() {
+                columns[i + j] += a * b;
+            }
+        }
+        for (column, limb) in columns.iter_mut().zip(&product.limbs) {
+            *column -= limb;
+        }
+        // Ensure `self * other - product` is a multiple of the modulus, with a quotient less than the modulus.
+        Self::enforce_multiple_of_modulus(columns, P::SIZE_IN_BITS);
+
+        *self = product;
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Square for NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    /// Returns `self * self`.
+    fn square(&self) -> Self::Output {
+        self * self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_mul(mode_a: Mode, mode_b: Mode, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Secp256k1Base::rand(&mut rng);
+            let second = Secp256k1Base::rand(&mut rng);
+            let expected = first.clone() * second.clone();
+
+            let a = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(mode_a, first);
+            let b = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(mode_b, second);
+
+            Circuit::scope(format!("{mode_a} * {mode_b} {i}"), || {
+                let candidate = &a * &b;
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(mode_a.is_constant() && mode_b.is_constant(), candidate.is_constant());
+                assert_scope!(<=4096, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_constant_mul_constant() {
+        check_mul(Mode::Constant, Mode::Constant, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_constant_mul_public() {
+        check_mul(Mode::Constant, Mode::Public, 0, 1207, 1215);
+    }
+
+    #[test]
+    fn test_public_mul_constant() {
+        check_mul(Mode::Public, Mode::Constant, 0, 1207, 1215);
+    }
+
+    #[test]
+    fn test_private_mul_constant() {
+        check_mul(Mode::Private, Mode::Constant, 0, 1207, 1215);
+    }
+
+    #[test]
+    fn test_public_mul_private() {
+        check_mul(Mode::Public, Mode::Private, 0, 1223, 1231);
+    }
+
+    #[test]
+    fn test_private_mul_private() {
+        check_mul(Mode::Private, Mode::Private, 0, 1223, 1231);
+    }
+
+    #[test]
+    fn test_square() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let value = Secp256k1Base::rand(&mut rng);
+            let candidate = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(Mode::Private, value.clone());
+            assert_eq!(value.square(), candidate.square().eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_mul_ed25519() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let first = console::Ed25519Base::rand(&mut rng);
+            let second = console::Ed25519Base::rand(&mut rng);
+            let a = NonNativeField::<Circuit, console::Ed25519BaseParameters>::new(Mode::Private, first.clone());
+            let b = NonNativeField::<Circuit, console::Ed25519BaseParameters>::new(Mode::Public, second.clone());
+            assert_eq!(first * second, (a * b).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
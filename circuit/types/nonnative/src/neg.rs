@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> Neg for NonNativeField<E, P> {
+    type Output = Self;
+
+    /// Performs the unary `-` operation.
+    fn neg(self) -> Self::Output {
+        (&self).neg()
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Neg for &NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    /// Performs the unary `-` operation.
+    ///
+    /// This method witnesses the negation, and enforces `self + negation` is either `0` or the modulus.
+    fn neg(self) -> Self::Output {
+        let negation = -self.eject_value();
+        // If the operand is constant, the result is also constant.
+        if self.is_constant() {
+            return NonNativeField::<E, P>::constant(negation);
+        }
+
+        // Witness the negation.
+        let negation = NonNativeField::<E, P>::new(Mode::Private, negation);
+        // Ensure `self + negation` is a multiple of the modulus, with a quotient of either `0` or `1`.
+        let columns = self.limbs.iter().zip_eq(&negation.limbs).map(|(a, b)| a + b);
+        NonNativeField::<E, P>::enforce_multiple_of_modulus(columns.collect(), 1);
+
+        negation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_neg(mode: Mode, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Secp256k1Base::rand(&mut rng);
+            let expected = -value.clone();
+            let candidate = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(mode, value);
+
+            Circuit::scope(format!("Neg {mode} {i}"), || {
+                let candidate = -&candidate;
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(<=4096, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_neg_constant() {
+        check_neg(Mode::Constant, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_neg_public() {
+        check_neg(Mode::Public, 0, 733, 738);
+    }
+
+    #[test]
+    fn test_neg_private() {
+        check_neg(Mode::Private, 0, 733, 738);
+    }
+
+    #[test]
+    fn test_neg_zero() {
+        let zero = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(Mode::Private, Secp256k1Base::zero());
+        assert_eq!(Secp256k1Base::zero(), (-zero).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> Sub<NonNativeField<E, P>> for NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn sub(self, other: NonNativeField<E, P>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Sub<&NonNativeField<E, P>> for NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn sub(self, other: &NonNativeField<E, P>) -> Self::Output {
+        let mut result = self;
+        result -= other;
+        result
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Sub<NonNativeField<E, P>> for &NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn sub(self, other: NonNativeField<E, P>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Sub<&NonNativeField<E, P>> for &NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn sub(self, other: &NonNativeField<E, P>) -> Self::Output {
+        let mut result = self.clone();
+        result -= other;
+        result
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> SubAssign<NonNativeField<E, P>> for NonNativeField<E, P> {
+    fn sub_assign(&mut self, other: NonNativeField<E, P>) {
+        *self -= &other;
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> SubAssign<&NonNativeField<E, P>> for NonNativeField<E, P> {
+    /// Subtracts `other` from `self`.
+    ///
+    /// This method witnesses the difference, and enforces `difference + other - self` is either `0` or the modulus.
+    fn sub_assign(&mut self, other: &NonNativeField<E, P>) {
+        let difference = self.eject_value() - other.eject_value();
+        // If both operands are constant, the result is also constant.
+        if self.is_constant() && other.is_constant() {
+            *self = Self::constant(difference);
+            return;
+        }
+
+        // Witness the difference.
+        let difference = Self::new(Mode::Private, difference);
+        // Ensure `difference + other - self` is a multiple of the modulus, with a quotient of either `0` or `1`.
+        let columns = difference.limbs.iter().zip_eq(&other.limbs).zip_eq(&self.limbs).map(|((a, b), c)| a + b - c);
+        Self::enforce_multiple_of_modulus(columns.collect(), 1);
+
+        *self = difference;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_sub(mode_a: Mode, mode_b: Mode, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Secp256k1Base::rand(&mut rng);
+            let second = Secp256k1Base::rand(&mut rng);
+            let expected = first.clone() - second.clone();
+
+            let a = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(mode_a, first);
+            let b = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(mode_b, second);
+
+            Circuit::scope(format!("{mode_a} - {mode_b} {i}"), || {
+                let candidate = &a - &b;
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(mode_a.is_constant() && mode_b.is_constant(), candidate.is_constant());
+                assert_scope!(<=4096, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_constant_sub_constant() {
+        check_sub(Mode::Constant, Mode::Constant, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_constant_sub_public() {
+        check_sub(Mode::Constant, Mode::Public, 0, 733, 738);
+    }
+
+    #[test]
+    fn test_public_sub_constant() {
+        check_sub(Mode::Public, Mode::Constant, 0, 733, 738);
+    }
+
+    #[test]
+    fn test_private_sub_constant() {
+        check_sub(Mode::Private, Mode::Constant, 0, 733, 738);
+    }
+
+    #[test]
+    fn test_public_sub_private() {
+        check_sub(Mode::Public, Mode::Private, 0, 733, 738);
+    }
+
+    #[test]
+    fn test_private_sub_private() {
+        check_sub(Mode::Private, Mode::Private, 0, 733, 738);
+    }
+
+    #[test]
+    fn test_sub_wraps_around_modulus() {
+        // Ensure `0 - 1` is reduced to `p - 1`.
+        let a = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(Mode::Private, Secp256k1Base::zero());
+        let b = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(Mode::Private, Secp256k1Base::one());
+        assert_eq!(-Secp256k1Base::one(), (a - b).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
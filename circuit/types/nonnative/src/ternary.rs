@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> Ternary for NonNativeField<E, P> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        let limbs = first.limbs.iter().zip_eq(&second.limbs).map(|(a, b)| Field::ternary(condition, a, b)).collect();
+        Self { limbs, _parameters: PhantomData }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_ternary(mode: Mode, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let flag = bool::rand(&mut rng);
+            let first = Secp256k1Base::rand(&mut rng);
+            let second = Secp256k1Base::rand(&mut rng);
+            let expected = if flag { first.clone() } else { second.clone() };
+
+            let condition = Boolean::<Circuit>::new(mode, flag);
+            let a = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(mode, first);
+            let b = NonNativeField::<Circuit, Secp256k1BaseParameters>::new(mode, second);
+
+            Circuit::scope(format!("Ternary {mode} {i}"), || {
+                let candidate = NonNativeField::ternary(&condition, &a, &b);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(<=4096, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_ternary_constant() {
+        check_ternary(Mode::Constant, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_ternary_private() {
+        check_ternary(Mode::Private, 0, 4, 4);
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> Concat<Self> for StringType<E> {
+    type Output = Self;
+
+    /// Returns the concatenation of `self` and `other`.
+    fn concat(&self, other: &Self) -> Self::Output {
+        // Ensure the concatenated string is within the allowed capacity.
+        let num_bytes = self.bytes.len() + other.bytes.len();
+        if num_bytes > E::MAX_STRING_BYTES as usize {
+            E::halt(format!("Attempted to concatenate into a string of size {num_bytes}"))
+        }
+
+        StringType {
+            mode: Mode::combine(self.mode, [other.mode]),
+            bytes: self.bytes.iter().chain(&other.bytes).cloned().collect(),
+            size_in_bytes: &self.size_in_bytes + &other.size_in_bytes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn sample_string(mode: Mode, rng: &mut TestRng) -> StringType<Circuit> {
+        // Sample a random string. Take 1/8th to ensure the concatenation fits for all code points.
+        let given = rng.next_string(Circuit::MAX_STRING_BYTES / 8, true);
+        StringType::<Circuit>::new(mode, console::StringType::new(&given))
+    }
+
+    fn check_concat(
+        mode_a: Mode,
+        mode_b: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut rng = TestRng::default();
+
+        // Sample two strings.
+        let string_a = sample_string(mode_a, &mut rng);
+        let string_b = sample_string(mode_b, &mut rng);
+        let expected = string_a.eject_value().concat(&string_b.eject_value());
+
+        Circuit::scope(format!("{mode_a} {mode_b}"), || {
+            let candidate = string_a.concat(&string_b);
+            assert_eq!(expected, candidate.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+
+            // Ensure the size of the concatenated string is correct.
+            assert_eq!(*expected.length(), *candidate.length().eject_value());
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_concat_constant() {
+        check_concat(Mode::Constant, Mode::Constant, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_concat_public() {
+        check_concat(Mode::Constant, Mode::Public, 0, 0, 0, 0);
+        check_concat(Mode::Public, Mode::Constant, 0, 0, 0, 0);
+        check_concat(Mode::Public, Mode::Public, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_concat_private() {
+        check_concat(Mode::Constant, Mode::Private, 0, 0, 0, 0);
+        check_concat(Mode::Private, Mode::Constant, 0, 0, 0, 0);
+        check_concat(Mode::Private, Mode::Private, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_concat_exceeds_capacity_halts() {
+        let half = "a".repeat(Circuit::MAX_STRING_BYTES as usize / 2 + 1);
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new(&half));
+
+        let result = std::panic::catch_unwind(|| string.concat(&string));
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+}
@@ -15,5 +15,6 @@
 use super::*;
 
 pub mod from_bits;
+pub mod padded_bytes;
 pub mod to_bits;
 pub mod to_fields;
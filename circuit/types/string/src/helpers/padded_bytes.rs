@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the bytes of the string, padded with trailing zeros to `num_bytes`.
+    pub fn to_padded_bytes(&self, num_bytes: usize) -> Vec<U8<E>> {
+        // Ensure the string fits within the given number of bytes.
+        if self.bytes.len() > num_bytes {
+            E::halt(format!("Cannot fit a string of {} bytes into {num_bytes} bytes", self.bytes.len()))
+        }
+        // Pad the bytes with trailing zeros.
+        let mut bytes = self.bytes.clone();
+        bytes.resize(num_bytes, U8::zero());
+        bytes
+    }
+
+    /// Initializes a new string from the given bytes, stripping any trailing zeros.
+    pub fn from_padded_bytes(bytes: &[U8<E>]) -> Self {
+        // Determine the number of bytes, excluding the trailing zeros.
+        let num_bytes = bytes.iter().rposition(|byte| *byte.eject_value() != 0).map_or(0, |index| index + 1);
+        // Ensure the string is within the allowed capacity.
+        if num_bytes > E::MAX_STRING_BYTES as usize {
+            E::halt(format!("Attempted to allocate a string of size {num_bytes}"))
+        }
+
+        // Ensure the padding consists of zeros.
+        for byte in &bytes[num_bytes..] {
+            E::assert(byte.is_zero());
+        }
+        // Ensure the last byte of the string is nonzero, so that the size of the string is unique.
+        if let Some(byte) = bytes[..num_bytes].last() {
+            E::assert(!byte.is_zero());
+        }
+
+        // Return the string.
+        Self::from_bits_le(&(&bytes[..num_bytes]).to_bits_le())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_padded_bytes(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random string. Take 1/8th to ensure we fit for all code points.
+            let expected = console::StringType::new(&rng.next_string(Circuit::MAX_STRING_BYTES / 8, true));
+            let num_bytes = expected.len() + 8;
+            let string = StringType::<Circuit>::new(mode, expected.clone());
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let bytes = string.to_padded_bytes(num_bytes);
+                assert_eq!(expected.to_padded_bytes(num_bytes).unwrap(), bytes.eject_value());
+
+                let candidate = StringType::from_padded_bytes(&bytes);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_padded_bytes_constant() {
+        check_padded_bytes(Mode::Constant, 81, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_padded_bytes_public() {
+        check_padded_bytes(Mode::Public, 81, 0, 3, 4);
+    }
+
+    #[test]
+    fn test_padded_bytes_private() {
+        check_padded_bytes(Mode::Private, 81, 0, 3, 4);
+    }
+
+    #[test]
+    fn test_to_padded_bytes_halts() {
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new("hello"));
+        assert!(std::panic::catch_unwind(|| string.to_padded_bytes(4)).is_err());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_padded_bytes_empty() {
+        let bytes = vec![U8::<Circuit>::new(Mode::Private, console::U8::new(0)); 8];
+        let candidate = StringType::from_padded_bytes(&bytes);
+        assert_eq!("", &*candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> Length for StringType<E> {
+    type Output = U32<E>;
+
+    /// Returns the length of the string in bytes.
+    fn length(&self) -> Self::Output {
+        U32::from_field(self.size_in_bytes.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_length(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..10 {
+            // Sample a random string. Take 1/4th to ensure we fit for all code points.
+            let given = rng.next_string(Circuit::MAX_STRING_BYTES / 4, true);
+            let string = StringType::<Circuit>::new(mode, console::StringType::new(&given));
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = string.length();
+                assert_eq!(given.len() as u32, *candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_length_constant() {
+        check_length(Mode::Constant, 32, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_length_public() {
+        check_length(Mode::Public, 0, 0, 32, 33);
+    }
+
+    #[test]
+    fn test_length_private() {
+        check_length(Mode::Private, 0, 0, 32, 33);
+    }
+}
@@ -15,8 +15,11 @@
 #![forbid(unsafe_code)]
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
+mod concat;
 mod equal;
 mod helpers;
+mod length;
+mod slice;
 
 #[cfg(test)]
 use console::TestRng;
@@ -26,7 +29,7 @@ use snarkvm_circuit_environment::assert_scope;
 use snarkvm_circuit_environment::prelude::*;
 use snarkvm_circuit_types_boolean::Boolean;
 use snarkvm_circuit_types_field::Field;
-use snarkvm_circuit_types_integers::U8;
+use snarkvm_circuit_types_integers::{U32, U8};
 
 #[derive(Clone)]
 pub struct StringType<E: Environment> {
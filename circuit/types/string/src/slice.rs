@@ -0,0 +1,227 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> Slice<U32<E>> for StringType<E> {
+    type Output = Self;
+
+    /// Returns the substring from byte index `start` (inclusive) to byte index `end` (exclusive).
+    /// The range must lie on UTF-8 character boundaries.
+    fn slice(&self, start: &U32<E>, end: &U32<E>) -> Self::Output {
+        // Retrieve the number of bytes in the string.
+        let num_bytes = self.bytes.len();
+        // Determine the mode of the substring.
+        let mode = Mode::combine(self.mode, [start.eject_mode(), end.eject_mode()]);
+
+        // If the range is constant, select the bytes directly.
+        if start.is_constant() && end.is_constant() {
+            let (start_index, end_index) = (*start.eject_value() as usize, *end.eject_value() as usize);
+            // Ensure the range is within bounds.
+            if start_index > end_index || end_index > num_bytes {
+                E::halt(format!("Attempted to slice a string of size {num_bytes} at {start_index}..{end_index}"))
+            }
+            // Ensure the range lies on character boundaries.
+            // Note: The index `num_bytes` is always a character boundary.
+            for byte in [self.bytes.get(start_index), self.bytes.get(end_index)].into_iter().flatten() {
+                E::assert(!is_continuation_byte(byte));
+            }
+            return StringType {
+                mode,
+                bytes: self.bytes[start_index..end_index].to_vec(),
+                size_in_bytes: end.to_field() - start.to_field(),
+            };
+        }
+
+        // Ensure `start <= end <= num_bytes`.
+        E::assert(start.is_less_than_or_equal(end));
+        E::assert(end.is_less_than_or_equal(&U32::constant(console::U32::new(num_bytes as u32))));
+
+        // Compute a one-hot encoding of each index over `0..=num_bytes`.
+        let one_hot = |index: &U32<E>| -> Vec<Boolean<E>> {
+            (0..=num_bytes).map(|i| index.is_equal(&U32::constant(console::U32::new(i as u32)))).collect()
+        };
+        let start_selectors = one_hot(start);
+        let end_selectors = one_hot(end);
+
+        // Ensure the range lies on character boundaries.
+        // Note: The index `num_bytes` is always a character boundary.
+        for (index, byte) in self.bytes.iter().enumerate() {
+            let is_continuation = is_continuation_byte(byte);
+            E::assert(!(&start_selectors[index] & &is_continuation));
+            E::assert(!(&end_selectors[index] & &is_continuation));
+        }
+
+        // Select the bytes from `start` onwards, padded with zeros up to `num_bytes`.
+        // Note: The padded bytes are computed over every offset, so that the number of constraints
+        // depends only on the size of the string, and not on the range.
+        let fields: Vec<Field<E>> = self.bytes.iter().map(|byte| byte.to_field()).collect();
+        let padded_bytes: Vec<U8<E>> = (0..num_bytes)
+            .map(|offset| {
+                let field = start_selectors
+                    .iter()
+                    .zip(&fields[offset..])
+                    .fold(Field::zero(), |sum, (selector, byte)| sum + Field::from_boolean(selector) * byte);
+                U8::from_field(field)
+            })
+            .collect();
+
+        // Determine the number of bytes in the substring.
+        // Note: If the range is invalid, the constraints above are unsatisfiable.
+        let num_sliced_bytes =
+            (*end.eject_value() as usize).saturating_sub(*start.eject_value() as usize).min(num_bytes);
+        // Truncate the padded bytes to the length of the substring.
+        let bytes = padded_bytes.into_iter().take(num_sliced_bytes).collect();
+
+        StringType { mode, bytes, size_in_bytes: end.to_field() - start.to_field() }
+    }
+}
+
+/// Returns `true` if the given byte is a UTF-8 continuation byte, i.e. of the form `0b10xxxxxx`.
+fn is_continuation_byte<E: Environment>(byte: &U8<E>) -> Boolean<E> {
+    let bits_le = byte.to_bits_le();
+    &bits_le[7] & !&bits_le[6]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use rand::Rng;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_slice(
+        mode: Mode,
+        mode_index: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random ASCII string, so that every index is a character boundary.
+            let given = rng.next_string(Circuit::MAX_STRING_BYTES / 16, true).replace(|c: char| !c.is_ascii(), "a");
+            let string = StringType::<Circuit>::new(mode, console::StringType::new(&given));
+
+            // Sample a valid range.
+            let end_index = rng.gen_range(0..=given.len());
+            let start_index = rng.gen_range(0..=end_index);
+            let start = U32::new(mode_index, console::U32::new(start_index as u32));
+            let end = U32::new(mode_index, console::U32::new(end_index as u32));
+
+            Circuit::scope(format!("{mode} {mode_index} {i}"), || {
+                let candidate = string.slice(&start, &end);
+                assert_eq!(&given[start_index..end_index], &*candidate.eject_value());
+                assert_scope!(<=num_constants, <=num_public, <=num_private, <=num_constraints);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    fn check_slice_out_of_bounds(mode: Mode) {
+        let string = StringType::<Circuit>::new(mode, console::StringType::new("hello"));
+        let start = U32::new(Mode::Private, console::U32::new(3));
+        let end = U32::new(Mode::Private, console::U32::new(6));
+
+        let _candidate = string.slice(&start, &end);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    fn check_slice_not_on_boundary(mode: Mode, mode_index: Mode) {
+        // Note: The character 'é' is encoded in two bytes, at byte indices 1..3.
+        let string = StringType::<Circuit>::new(mode, console::StringType::new("hé!"));
+
+        // Ensure a range on character boundaries is satisfied.
+        let start = U32::new(mode_index, console::U32::new(1));
+        let end = U32::new(mode_index, console::U32::new(3));
+        assert_eq!("é", &*string.slice(&start, &end).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure a range that splits a character is unsatisfied, at either end.
+        for (start, end) in [(2, 3), (1, 2)] {
+            let start = U32::new(mode_index, console::U32::new(start));
+            let end = U32::new(mode_index, console::U32::new(end));
+            let _candidate = string.slice(&start, &end);
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_slice_constant() {
+        check_slice(Mode::Constant, Mode::Constant, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_slice_public() {
+        check_slice(Mode::Public, Mode::Constant, 0, 0, 2, 4);
+        check_slice(Mode::Public, Mode::Public, 1200, 0, 450, 500);
+    }
+
+    #[test]
+    fn test_slice_private() {
+        check_slice(Mode::Private, Mode::Constant, 0, 0, 2, 4);
+        check_slice(Mode::Private, Mode::Private, 1200, 0, 450, 500);
+    }
+
+    #[test]
+    fn test_slice_out_of_bounds() {
+        check_slice_out_of_bounds(Mode::Constant);
+        check_slice_out_of_bounds(Mode::Private);
+
+        // Ensure a constant range out of bounds halts.
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new("hello"));
+        let start = U32::constant(console::U32::new(3));
+        let end = U32::constant(console::U32::new(6));
+        assert!(std::panic::catch_unwind(|| string.slice(&start, &end)).is_err());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_slice_not_on_boundary() {
+        check_slice_not_on_boundary(Mode::Private, Mode::Constant);
+        check_slice_not_on_boundary(Mode::Private, Mode::Private);
+        check_slice_not_on_boundary(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_slice_is_independent_of_range() {
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new("hello world"));
+
+        // Ensure the number of constraints is the same for every range.
+        let mut counts = Vec::new();
+        for (start, end) in [(0, 0), (0, 11), (3, 7), (11, 11)] {
+            let start = U32::new(Mode::Private, console::U32::new(start));
+            let end = U32::new(Mode::Private, console::U32::new(end));
+            Circuit::scope("slice", || {
+                let _candidate = string.slice(&start, &end);
+                counts.push((
+                    Circuit::num_constants_in_scope(),
+                    Circuit::num_public_in_scope(),
+                    Circuit::num_private_in_scope(),
+                    Circuit::num_constraints_in_scope(),
+                ));
+            });
+            assert!(Circuit::is_satisfied());
+        }
+        assert!(counts.windows(2).all(|pair| pair[0] == pair[1]));
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_console_algorithms::{Poseidon2, Poseidon2_2, Poseidon2_4, Poseidon2_8, Poseidon4, Poseidon8};
+use snarkvm_console_types::prelude::*;
+use snarkvm_utilities::{TestRng, Uniform};
+
+use criterion::Criterion;
+type F = Field<Console>;
+
+/// Benchmarks Poseidon and Poseidon2 of the same rate on identical inputs.
+macro_rules! bench_poseidon_vs_poseidon2 {
+    ($c:expr, $poseidon:ident, $poseidon2:ident, $rate:expr) => {{
+        let rng = &mut TestRng::default();
+        let poseidon = $poseidon::<Console>::setup(concat!("Poseidon", $rate)).unwrap();
+        let poseidon2 = $poseidon2::<Console>::setup(concat!("Poseidon2_", $rate)).unwrap();
+
+        let input = [F::rand(rng), F::rand(rng), F::rand(rng), F::rand(rng)];
+        let mut group = $c.benchmark_group(concat!("Rate ", $rate, " Hash 4 -> 1"));
+        group.bench_function("Poseidon", |b| b.iter(|| poseidon.hash(&input)));
+        group.bench_function("Poseidon2", |b| b.iter(|| poseidon2.hash(&input)));
+        group.finish();
+
+        let input: Vec<_> = (0..10).map(|_| F::rand(rng)).collect();
+        let mut group = $c.benchmark_group(concat!("Rate ", $rate, " Hash 10 -> 1"));
+        group.bench_function("Poseidon", |b| b.iter(|| poseidon.hash(&input)));
+        group.bench_function("Poseidon2", |b| b.iter(|| poseidon2.hash(&input)));
+        group.finish();
+
+        let mut group = $c.benchmark_group(concat!("Rate ", $rate, " Hash 10 -> 8"));
+        group.bench_function("Poseidon", |b| b.iter(|| poseidon.hash_many(&input, 8)));
+        group.bench_function("Poseidon2", |b| b.iter(|| poseidon2.hash_many(&input, 8)));
+        group.finish();
+    }};
+}
+
+fn rate2(c: &mut Criterion) {
+    bench_poseidon_vs_poseidon2!(c, Poseidon2, Poseidon2_2, "2");
+}
+
+fn rate4(c: &mut Criterion) {
+    bench_poseidon_vs_poseidon2!(c, Poseidon4, Poseidon2_4, "4");
+}
+
+fn rate8(c: &mut Criterion) {
+    bench_poseidon_vs_poseidon2!(c, Poseidon8, Poseidon2_8, "8");
+}
+
+criterion_group! {
+    name = sponge;
+    config = Criterion::default().sample_size(50);
+    targets = rate2, rate4, rate8,
+}
+
+criterion_main!(sponge);
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_utilities::{bits_from_bytes_le, bytes_from_bits_le};
+
+impl Hash for Blake2s {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the BLAKE2s hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        Ok(bits_from_bytes_le(&blake2s_native(&bytes_from_bits_le(input))).collect())
+    }
+}
+
+/// Computes the BLAKE2s hash of the given preimage as bytes.
+fn blake2s_native(preimage: &[u8]) -> [u8; 32] {
+    let digest = blake2s_simd::Params::new().hash_length(32).hash(preimage);
+
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(digest.as_bytes());
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct Case {
+        hash: String,
+        #[serde(rename = "in")]
+        input: String,
+        key: String,
+        #[serde(rename = "out")]
+        output: String,
+    }
+
+    #[test]
+    fn test_blake2s_equivalence() {
+        let rng = &mut TestRng::default();
+
+        let mut input_sizes = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 16, 32, 64, 128, 256, 511, 512, 513, 1024];
+        input_sizes.extend((0..100).map(|_| rng.gen_range(1..1024)));
+
+        for num_inputs in input_sizes {
+            println!("Checking equivalence for {num_inputs} inputs");
+
+            // Prepare the preimage.
+            let input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+
+            // Compute the native hash.
+            let expected = blake2s_native(&bytes_from_bits_le(&input));
+            let expected = bits_from_bytes_le(&expected).collect::<Vec<_>>();
+
+            // Compute the console hash.
+            let candidate = Blake2s.hash(&input).unwrap();
+            assert_eq!(expected, candidate);
+        }
+    }
+
+    #[test]
+    fn test_blake2s_vectors() {
+        // The test vector from RFC 7693, Appendix B.
+        let input = bits_from_bytes_le(b"abc").collect::<Vec<_>>();
+        let candidate = bytes_from_bits_le(&Blake2s.hash(&input).unwrap());
+        assert_eq!("508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982", hex::encode(candidate));
+
+        // Run the unkeyed test vector cases from the BLAKE2 reference implementation.
+        let vectors: Vec<Case> = serde_json::from_str(include_str!("../blake2xs/resources/blake2-kat.json")).unwrap();
+        for case in vectors.iter().filter(|v| &v.hash == "blake2s" && v.key.is_empty()) {
+            let input = bits_from_bytes_le(&hex::decode(case.input.as_bytes()).unwrap()).collect::<Vec<_>>();
+            let candidate = bytes_from_bits_le(&Blake2s.hash(&input).unwrap());
+            assert_eq!(case.output, hex::encode(candidate));
+        }
+    }
+}
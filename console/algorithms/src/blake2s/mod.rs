@@ -0,0 +1,31 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+
+#[cfg(test)]
+use snarkvm_utilities::Uniform;
+
+use crate::Hash;
+use snarkvm_console_types::environment::prelude::*;
+
+/// The BLAKE2s hash function with a 256-bit output, as specified in RFC 7693.
+///
+/// The input bits are packed into bytes in little-endian bit order, where the final byte is padded with zeros.
+/// The message is absorbed in 512-bit blocks, where the final block is padded with zeros and flagged as final,
+/// and the hash is unkeyed and unpersonalized (i.e. `kk = 0` and `nn = 32`).
+///
+/// The 256-bit chaining state is returned as the little-endian bits of its little-endian bytes.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Blake2s;
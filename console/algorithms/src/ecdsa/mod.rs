@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod sign;
+mod verify;
+
+use snarkvm_console_types::environment::prelude::*;
+
+use num_bigint::BigUint;
+
+/// The secp256k1 base field modulus, `p = 2^256 - 2^32 - 977`.
+const BASE_MODULUS: &str = "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F";
+/// The secp256k1 scalar field modulus, i.e. the order `n` of the generator.
+const SCALAR_MODULUS: &str = "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141";
+/// The `x`-coordinate of the secp256k1 generator.
+const GENERATOR_X: &str = "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798";
+/// The `y`-coordinate of the secp256k1 generator.
+const GENERATOR_Y: &str = "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8";
+
+/// The ECDSA signature scheme over the secp256k1 curve `y^2 = x^3 + 7`, as used by Ethereum and Bitcoin.
+///
+/// All values are encoded as big-endian bytes:
+///  - a public key is the `x` and `y` coordinates of its point (i.e. an uncompressed SEC1 key without the `0x04` prefix),
+///  - a signature is the `r` and `s` scalars (i.e. an Ethereum signature without the recovery byte `v`),
+///  - a message is its 32-byte digest (e.g. the Keccak-256 hash of the message).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ECDSA;
+
+impl ECDSA {
+    /// The number of bytes in a message digest.
+    pub const DIGEST_SIZE_IN_BYTES: usize = 32;
+    /// The number of bytes in a public key.
+    pub const PUBLIC_KEY_SIZE_IN_BYTES: usize = 64;
+    /// The number of bytes in a secret key.
+    pub const SECRET_KEY_SIZE_IN_BYTES: usize = 32;
+    /// The number of bytes in a signature.
+    pub const SIGNATURE_SIZE_IN_BYTES: usize = 64;
+
+    /// Returns the public key for the given secret key.
+    pub fn to_public_key(
+        secret_key: &[u8; Self::SECRET_KEY_SIZE_IN_BYTES],
+    ) -> Result<[u8; Self::PUBLIC_KEY_SIZE_IN_BYTES]> {
+        let curve = Secp256k1::new();
+        // Parse the secret key, and ensure it is a nonzero scalar.
+        let secret_key = BigUint::from_bytes_be(secret_key);
+        ensure!(!secret_key.is_zero() && secret_key < curve.n, "The ECDSA secret key must be a nonzero scalar");
+        // Compute the public key.
+        match curve.mul(&curve.g, &secret_key) {
+            Some(public_key) => Ok(encode_pair(&public_key)),
+            None => bail!("The ECDSA public key must not be the point at infinity"),
+        }
+    }
+}
+
+/// An affine point on secp256k1, where `None` is the point at infinity.
+type Point = Option<(BigUint, BigUint)>;
+
+/// The secp256k1 curve parameters.
+struct Secp256k1 {
+    /// The base field modulus.
+    p: BigUint,
+    /// The scalar field modulus.
+    n: BigUint,
+    /// The generator.
+    g: Point,
+}
+
+impl Secp256k1 {
+    /// Initializes the curve parameters.
+    fn new() -> Self {
+        let parse = |hex: &str| BigUint::parse_bytes(hex.as_bytes(), 16).expect("Invalid secp256k1 constant");
+        Self { p: parse(BASE_MODULUS), n: parse(SCALAR_MODULUS), g: Some((parse(GENERATOR_X), parse(GENERATOR_Y))) }
+    }
+
+    /// Returns `true` if `(x, y)` is a point on the curve.
+    fn is_on_curve(&self, x: &BigUint, y: &BigUint) -> bool {
+        x < &self.p && y < &self.p && (y * y) % &self.p == (x * x * x + 7u32) % &self.p
+    }
+
+    /// Returns the sum of the given points.
+    fn add(&self, a: &Point, b: &Point) -> Point {
+        let p = &self.p;
+        let (x1, y1) = match a {
+            Some(a) => a,
+            None => return b.clone(),
+        };
+        let (x2, y2) = match b {
+            Some(b) => b,
+            None => return a.clone(),
+        };
+        let lambda = if x1 == x2 {
+            // If the points are inverses of each other, return the point at infinity.
+            if (y1 + y2) % p == BigUint::zero() {
+                return None;
+            }
+            // Otherwise, double the point, with `lambda = 3 * x^2 / (2 * y)`.
+            (BigUint::from(3u32) * x1 * x1) % p * inverse(&((y1 << 1u32) % p), p) % p
+        } else {
+            // Otherwise, add the points, with `lambda = (y2 - y1) / (x2 - x1)`.
+            (y2 + p - y1) % p * inverse(&((x2 + p - x1) % p), p) % p
+        };
+        let x3 = (&lambda * &lambda + (p << 1u32) - x1 - x2) % p;
+        let y3 = (&lambda * ((x1 + p - &x3) % p) + p - y1) % p;
+        Some((x3, y3))
+    }
+
+    /// Returns the product of the given point and scalar.
+    fn mul(&self, point: &Point, scalar: &BigUint) -> Point {
+        let (x, y) = match point {
+            Some(point) => point,
+            None => return None,
+        };
+        // Compute the product in Jacobian coordinates `(X, Y, Z)`, where `Z = 0` is the point at infinity.
+        let mut output = (BigUint::one(), BigUint::one(), BigUint::zero());
+        for i in (0..scalar.bits()).rev() {
+            output = self.double_jacobian(&output);
+            if scalar.bit(i) {
+                output = self.add_jacobian(&output, x, y);
+            }
+        }
+        // Convert the product to affine coordinates.
+        let (x, y, z) = output;
+        if z.is_zero() {
+            return None;
+        }
+        let z_inverse = inverse(&z, &self.p);
+        let z_inverse_squared = (&z_inverse * &z_inverse) % &self.p;
+        Some(((x * &z_inverse_squared) % &self.p, (y * z_inverse_squared * z_inverse) % &self.p))
+    }
+
+    /// Returns the double of the given point in Jacobian coordinates.
+    fn double_jacobian(&self, (x, y, z): &(BigUint, BigUint, BigUint)) -> (BigUint, BigUint, BigUint) {
+        let p = &self.p;
+        if z.is_zero() || y.is_zero() {
+            return (BigUint::one(), BigUint::one(), BigUint::zero());
+        }
+        let a = (x * x) % p;
+        let b = (y * y) % p;
+        let c = (&b * &b) % p;
+        let d = (((x + &b) * (x + &b) + (p << 1u32) - &a - &c) << 1u32) % p;
+        let e = (&a * 3u32) % p;
+        let f = (&e * &e) % p;
+        let x3 = (f + (p << 1u32) - (&d << 1u32)) % p;
+        let y3 = (e * ((d + p - &x3) % p) + (p << 3u32) - (c << 3u32)) % p;
+        let z3 = ((y * z) << 1u32) % p;
+        (x3, y3, z3)
+    }
+
+    /// Returns the sum of the given point in Jacobian coordinates and the affine point `(x2, y2)`.
+    fn add_jacobian(
+        &self,
+        (x1, y1, z1): &(BigUint, BigUint, BigUint),
+        x2: &BigUint,
+        y2: &BigUint,
+    ) -> (BigUint, BigUint, BigUint) {
+        let p = &self.p;
+        if z1.is_zero() {
+            return (x2.clone(), y2.clone(), BigUint::one());
+        }
+        let z1_squared = (z1 * z1) % p;
+        let u2 = (x2 * &z1_squared) % p;
+        let s2 = (y2 * z1_squared * z1) % p;
+        let h = (u2 + p - x1) % p;
+        let r = (s2 + p - y1) % p;
+        if h.is_zero() {
+            // If the points are equal, double the point. Otherwise, they are inverses of each other.
+            return match r.is_zero() {
+                true => self.double_jacobian(&(x1.clone(), y1.clone(), z1.clone())),
+                false => (BigUint::one(), BigUint::one(), BigUint::zero()),
+            };
+        }
+        let h_squared = (&h * &h) % p;
+        let h_cubed = (&h * &h_squared) % p;
+        let v = (x1 * h_squared) % p;
+        let x3 = (&r * &r + (p << 1u32) - &h_cubed - (&v << 1u32)) % p;
+        let y3 = (r * ((v + p - &x3) % p) + p - (y1 * h_cubed) % p) % p;
+        let z3 = (z1 * h) % p;
+        (x3, y3, z3)
+    }
+}
+
+/// Returns the inverse of `a` modulo the prime `modulus`, i.e. `a^(modulus - 2)`.
+fn inverse(a: &BigUint, modulus: &BigUint) -> BigUint {
+    a.modpow(&(modulus - 2u32), modulus)
+}
+
+/// Returns the given integer as 32 big-endian bytes.
+fn encode(value: &BigUint) -> [u8; 32] {
+    let bytes = value.to_bytes_be();
+    let mut output = [0u8; 32];
+    output[32 - bytes.len()..].copy_from_slice(&bytes);
+    output
+}
+
+/// Returns the given pair of integers as 64 big-endian bytes.
+fn encode_pair((a, b): &(BigUint, BigUint)) -> [u8; 64] {
+    let mut output = [0u8; 64];
+    output[..32].copy_from_slice(&encode(a));
+    output[32..].copy_from_slice(&encode(b));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generator() {
+        let curve = Secp256k1::new();
+        let (x, y) = curve.g.clone().unwrap();
+        // Ensure the generator is on the curve.
+        assert!(curve.is_on_curve(&x, &y));
+        // Ensure the generator has order `n`.
+        assert_eq!(curve.mul(&curve.g, &curve.n), None);
+    }
+
+    #[test]
+    fn test_to_public_key() {
+        let mut secret_key = [0u8; 32];
+
+        // Ensure the public key of `1` is the generator.
+        secret_key[31] = 1;
+        let public_key = ECDSA::to_public_key(&secret_key).unwrap();
+        assert_eq!(public_key, encode_pair(&Secp256k1::new().g.unwrap()));
+
+        // Ensure the public key of `2` matches the known value of `2 * G`.
+        secret_key[31] = 2;
+        let public_key = ECDSA::to_public_key(&secret_key).unwrap();
+        let expected = "C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5\
+                        1AE168FEA63DC339A3C58419466CEAEEF7F632653266D0E1236431A950CFE52A";
+        assert_eq!(hex::encode_upper(public_key), expected);
+
+        // Ensure zero and the scalar modulus are rejected.
+        assert!(ECDSA::to_public_key(&[0u8; 32]).is_err());
+        assert!(ECDSA::to_public_key(&encode(&Secp256k1::new().n)).is_err());
+    }
+}
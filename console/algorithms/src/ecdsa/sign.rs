@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl ECDSA {
+    /// Samples a random secret key.
+    pub fn sample_secret_key<R: Rng + CryptoRng>(rng: &mut R) -> [u8; Self::SECRET_KEY_SIZE_IN_BYTES] {
+        let curve = Secp256k1::new();
+        loop {
+            let secret_key: [u8; Self::SECRET_KEY_SIZE_IN_BYTES] = rng.gen();
+            // Ensure the secret key is a nonzero scalar.
+            let scalar = BigUint::from_bytes_be(&secret_key);
+            if !scalar.is_zero() && scalar < curve.n {
+                return secret_key;
+            }
+        }
+    }
+
+    /// Returns a signature for the given message digest, using the given secret key.
+    /// The signature is normalized to the low-`s` form, as required by Ethereum.
+    pub fn sign<R: Rng + CryptoRng>(
+        secret_key: &[u8; Self::SECRET_KEY_SIZE_IN_BYTES],
+        digest: &[u8; Self::DIGEST_SIZE_IN_BYTES],
+        rng: &mut R,
+    ) -> Result<[u8; Self::SIGNATURE_SIZE_IN_BYTES]> {
+        let curve = Secp256k1::new();
+        // Parse the secret key, and ensure it is a nonzero scalar.
+        let secret_key = BigUint::from_bytes_be(secret_key);
+        ensure!(!secret_key.is_zero() && secret_key < curve.n, "The ECDSA secret key must be a nonzero scalar");
+        // Parse the digest as a scalar.
+        let z = BigUint::from_bytes_be(digest) % &curve.n;
+
+        loop {
+            // Sample a random nonce `k`.
+            let k = BigUint::from_bytes_be(&Self::sample_secret_key(rng));
+            // Compute `r = (k * G).x (mod n)`.
+            let r = match curve.mul(&curve.g, &k) {
+                Some((x, _)) => x % &curve.n,
+                None => continue,
+            };
+            // Compute `s = (z + r * secret_key) / k (mod n)`.
+            let s = (&z + &r * &secret_key) % &curve.n * inverse(&k, &curve.n) % &curve.n;
+            // Ensure `r` and `s` are nonzero.
+            if r.is_zero() || s.is_zero() {
+                continue;
+            }
+            // Normalize `s` to the low-`s` form.
+            let s = match s > (&curve.n >> 1u32) {
+                true => &curve.n - s,
+                false => s,
+            };
+            return Ok(encode_pair(&(r, s)));
+        }
+    }
+}
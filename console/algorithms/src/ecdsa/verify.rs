@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl ECDSA {
+    /// Returns `true` if the signature is valid for the given public key and message digest.
+    ///
+    /// The signature is rejected if `r` or `s` is not a nonzero scalar, or if the public key is not on the curve.
+    /// Both high-`s` and low-`s` signatures are accepted.
+    pub fn verify(
+        public_key: &[u8; Self::PUBLIC_KEY_SIZE_IN_BYTES],
+        digest: &[u8; Self::DIGEST_SIZE_IN_BYTES],
+        signature: &[u8; Self::SIGNATURE_SIZE_IN_BYTES],
+    ) -> bool {
+        let curve = Secp256k1::new();
+
+        // Parse the public key, and ensure it is on the curve.
+        let (x, y) = (BigUint::from_bytes_be(&public_key[..32]), BigUint::from_bytes_be(&public_key[32..]));
+        if !curve.is_on_curve(&x, &y) {
+            return false;
+        }
+        // Parse the signature, and ensure `r` and `s` are nonzero scalars.
+        let (r, s) = (BigUint::from_bytes_be(&signature[..32]), BigUint::from_bytes_be(&signature[32..]));
+        if r.is_zero() || s.is_zero() || r >= curve.n || s >= curve.n {
+            return false;
+        }
+        // Parse the digest as a scalar.
+        let z = BigUint::from_bytes_be(digest) % &curve.n;
+
+        // Compute `u1 = z / s` and `u2 = r / s`.
+        let s_inverse = inverse(&s, &curve.n);
+        let u1 = (&z * &s_inverse) % &curve.n;
+        let u2 = (&r * &s_inverse) % &curve.n;
+
+        // Compute `R = u1 * G + u2 * Q`, and check that `R.x = r (mod n)`.
+        match curve.add(&curve.mul(&curve.g, &u1), &curve.mul(&Some((x, y)), &u2)) {
+            Some((x, _)) => x % &curve.n == r,
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_sign_and_verify() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a secret key and a message digest.
+            let secret_key = ECDSA::sample_secret_key(rng);
+            let digest: [u8; 32] = Uniform::rand(rng);
+
+            // Sign the digest, and ensure the signature verifies.
+            let public_key = ECDSA::to_public_key(&secret_key).unwrap();
+            let signature = ECDSA::sign(&secret_key, &digest, rng).unwrap();
+            assert!(ECDSA::verify(&public_key, &digest, &signature));
+
+            // Ensure the high-`s` form of the signature verifies.
+            let curve = Secp256k1::new();
+            let mut high_s = signature;
+            high_s[32..].copy_from_slice(&encode(&(&curve.n - BigUint::from_bytes_be(&signature[32..]))));
+            assert!(ECDSA::verify(&public_key, &digest, &high_s));
+
+            // Ensure the signature does not verify for a different digest.
+            let mut other_digest = digest;
+            other_digest[0] ^= 1;
+            assert!(!ECDSA::verify(&public_key, &other_digest, &signature));
+
+            // Ensure the signature does not verify for a different public key.
+            let other_public_key = ECDSA::to_public_key(&ECDSA::sample_secret_key(rng)).unwrap();
+            assert!(!ECDSA::verify(&other_public_key, &digest, &signature));
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_invalid_encodings() {
+        let rng = &mut TestRng::default();
+
+        let secret_key = ECDSA::sample_secret_key(rng);
+        let digest: [u8; 32] = Uniform::rand(rng);
+        let public_key = ECDSA::to_public_key(&secret_key).unwrap();
+        let signature = ECDSA::sign(&secret_key, &digest, rng).unwrap();
+        let curve = Secp256k1::new();
+
+        // Ensure a public key that is not on the curve is rejected.
+        let mut invalid_public_key = public_key;
+        invalid_public_key[63] ^= 1;
+        assert!(!ECDSA::verify(&invalid_public_key, &digest, &signature));
+
+        // Ensure a zero `r` or `s` is rejected.
+        let mut invalid_signature = signature;
+        invalid_signature[..32].copy_from_slice(&[0u8; 32]);
+        assert!(!ECDSA::verify(&public_key, &digest, &invalid_signature));
+        let mut invalid_signature = signature;
+        invalid_signature[32..].copy_from_slice(&[0u8; 32]);
+        assert!(!ECDSA::verify(&public_key, &digest, &invalid_signature));
+
+        // Ensure an `s` equal to the scalar modulus is rejected.
+        let mut invalid_signature = signature;
+        invalid_signature[32..].copy_from_slice(&encode(&curve.n));
+        assert!(!ECDSA::verify(&public_key, &digest, &invalid_signature));
+    }
+}
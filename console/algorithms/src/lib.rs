@@ -22,9 +22,15 @@ pub use snarkvm_console_types::prelude::*;
 pub mod bhp;
 pub use bhp::{BHP, BHP1024, BHP256, BHP512, BHP768};
 
+mod blake2s;
+pub use blake2s::Blake2s;
+
 mod blake2xs;
 pub use blake2xs::Blake2Xs;
 
+mod ecdsa;
+pub use ecdsa::ECDSA;
+
 mod elligator2;
 pub use elligator2::Elligator2;
 
@@ -36,3 +42,9 @@ pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
 
 mod poseidon;
 pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8};
+
+mod poseidon2;
+pub use poseidon2::{Poseidon2_2, Poseidon2_4, Poseidon2_8, PoseidonV2};
+
+mod sha256;
+pub use sha256::Sha256;
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> HashMany for PoseidonV2<E, RATE> {
+    type Input = Field<E>;
+    type Output = Field<E>;
+
+    /// Returns the cryptographic hash for a list of field elements as input,
+    /// and returns the specified number of field elements as output.
+    #[inline]
+    fn hash_many(&self, input: &[Self::Input], num_outputs: u16) -> Vec<Self::Output> {
+        // Construct the preimage: [ DOMAIN || LENGTH(INPUT) || [0; RATE-2] || INPUT ].
+        let mut preimage = Vec::with_capacity(RATE + input.len());
+        preimage.push(self.domain);
+        preimage.push(Field::<E>::from_u128(input.len() as u128));
+        preimage.resize(RATE, Field::<E>::zero()); // Pad up to RATE.
+        preimage.extend_from_slice(input);
+
+        // Initialize the state.
+        let mut state = vec![Field::<E>::zero(); RATE + CAPACITY];
+
+        // Absorb the preimage, `RATE` elements at a time, permuting the state between each chunk.
+        for (i, chunk) in preimage.chunks(RATE).enumerate() {
+            if i > 0 {
+                self.permute(&mut state);
+            }
+            for (element, state_element) in chunk.iter().zip(&mut state[CAPACITY..]) {
+                *state_element += element;
+            }
+        }
+
+        // Squeeze the output, `RATE` elements at a time, permuting the state before each chunk.
+        let mut output = Vec::with_capacity(num_outputs as usize);
+        while output.len() < num_outputs as usize {
+            self.permute(&mut state);
+            let num_squeezed = core::cmp::min(RATE, num_outputs as usize - output.len());
+            output.extend_from_slice(&state[CAPACITY..(CAPACITY + num_squeezed)]);
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 100;
+
+    macro_rules! check_hash_many {
+        ($poseidon:ident, $rate:expr) => {{
+            // Initialize Poseidon2.
+            let poseidon = $poseidon::<CurrentEnvironment>::setup("HashManyTest")?;
+
+            let mut rng = TestRng::default();
+
+            for _ in 0..ITERATIONS {
+                for num_inputs in 0..(2 * $rate) {
+                    // Sample random field elements.
+                    let inputs = (0..num_inputs).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+
+                    // Ensure the hash is the first output of hash many.
+                    let outputs = poseidon.hash_many(&inputs, 2 * $rate + 1);
+                    assert_eq!(outputs.len(), 2 * $rate + 1);
+                    assert_eq!(poseidon.hash(&inputs)?, outputs[0]);
+
+                    // Ensure a shorter output is a prefix of a longer output.
+                    for num_outputs in 0..(2 * $rate + 1) {
+                        assert_eq!(poseidon.hash_many(&inputs, num_outputs as u16), outputs[..num_outputs]);
+                    }
+
+                    // Ensure appending an element changes the hash.
+                    let mut extended = inputs.clone();
+                    extended.push(Field::zero());
+                    assert_ne!(poseidon.hash(&extended)?, outputs[0]);
+                }
+            }
+            Ok(())
+        }};
+    }
+
+    #[test]
+    fn test_poseidon2_2_hash_many() -> Result<()> {
+        check_hash_many!(Poseidon2_2, 2)
+    }
+
+    #[test]
+    fn test_poseidon2_4_hash_many() -> Result<()> {
+        check_hash_many!(Poseidon2_4, 4)
+    }
+
+    #[test]
+    fn test_poseidon2_8_hash_many() -> Result<()> {
+        check_hash_many!(Poseidon2_8, 8)
+    }
+
+    #[test]
+    fn test_poseidon2_differs_from_poseidon() -> Result<()> {
+        let poseidon = crate::Poseidon4::<CurrentEnvironment>::setup("HashManyTest")?;
+        let poseidon2 = Poseidon2_4::<CurrentEnvironment>::setup("HashManyTest")?;
+
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..8 {
+            let inputs = (0..num_inputs).map(|_| Uniform::rand(&mut rng)).collect::<Vec<Field<CurrentEnvironment>>>();
+            assert_ne!(poseidon.hash(&inputs)?, poseidon2.hash(&inputs)?);
+        }
+        Ok(())
+    }
+}
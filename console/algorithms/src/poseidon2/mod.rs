@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+mod hash_many;
+mod hash_to_group;
+mod hash_to_scalar;
+
+use crate::Elligator2;
+use snarkvm_console_types::prelude::*;
+use snarkvm_fields::{Poseidon2Parameters, PoseidonDefaultField};
+
+use std::sync::Arc;
+
+const CAPACITY: usize = 1;
+
+/// Poseidon2_2 is a cryptographic hash function of input rate 2, using the Poseidon2 permutation.
+pub type Poseidon2_2<E> = PoseidonV2<E, 2>;
+/// Poseidon2_4 is a cryptographic hash function of input rate 4, using the Poseidon2 permutation.
+pub type Poseidon2_4<E> = PoseidonV2<E, 4>;
+/// Poseidon2_8 is a cryptographic hash function of input rate 8, using the Poseidon2 permutation.
+pub type Poseidon2_8<E> = PoseidonV2<E, 8>;
+
+/// The Poseidon2 hash function, as described in the [Poseidon2 paper](https://eprint.iacr.org/2023/323).
+///
+/// Poseidon2 keeps the sponge construction and round structure of Poseidon, but replaces the
+/// dense MDS matrix in the partial rounds with the matrix `M_I = J + D`, where `J` is the all-ones
+/// matrix and `D` is a diagonal matrix, and applies the external matrix `M_E` before the first round.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PoseidonV2<E: Environment, const RATE: usize> {
+    /// The domain separator for the Poseidon2 hash function.
+    domain: Field<E>,
+    /// The Poseidon2 parameters for hashing.
+    parameters: Arc<Poseidon2Parameters<E::Field, RATE, CAPACITY>>,
+}
+
+impl<E: Environment, const RATE: usize> PoseidonV2<E, RATE> {
+    /// Initializes a new instance of Poseidon2.
+    pub fn setup(domain: &str) -> Result<Self> {
+        // Ensure the given domain is within the allowed size in bits.
+        let num_bits = domain.len().saturating_mul(8);
+        let max_bits = Field::<E>::size_in_data_bits();
+        ensure!(num_bits <= max_bits, "Domain cannot exceed {max_bits} bits, found {num_bits} bits");
+
+        Ok(Self {
+            domain: Field::<E>::new_domain_separator(domain),
+            parameters: Arc::new(E::Field::default_poseidon2_parameters::<RATE>()?),
+        })
+    }
+
+    /// Returns the domain separator for the hash function.
+    pub fn domain(&self) -> Field<E> {
+        self.domain
+    }
+
+    /// Returns the Poseidon2 parameters for hashing.
+    pub fn parameters(&self) -> &Arc<Poseidon2Parameters<E::Field, RATE, CAPACITY>> {
+        &self.parameters
+    }
+}
+
+impl<E: Environment, const RATE: usize> PoseidonV2<E, RATE> {
+    /// Apply the Poseidon2 permutation in-place.
+    fn permute(&self, state: &mut [Field<E>]) {
+        // Determine the number of full rounds on either side of the partial rounds.
+        let full_rounds_over_2 = self.parameters.full_rounds / 2;
+
+        // Apply the external matrix to the initial state.
+        self.apply_external_mds(state);
+        // Apply the first half of the full rounds.
+        for round in 0..full_rounds_over_2 {
+            self.apply_full_round(state, round);
+        }
+        // Apply the partial rounds.
+        for round in 0..self.parameters.partial_rounds {
+            self.apply_partial_round(state, round);
+        }
+        // Apply the second half of the full rounds.
+        for round in full_rounds_over_2..self.parameters.full_rounds {
+            self.apply_full_round(state, round);
+        }
+    }
+
+    /// Apply a full round, which applies the round keys and the S-box to every state element,
+    /// followed by the external matrix.
+    fn apply_full_round(&self, state: &mut [Field<E>], round: usize) {
+        for (element, ark) in state.iter_mut().zip_eq(&self.parameters.external_ark[round]) {
+            *element += Field::<E>::new(*ark);
+            let e = element.deref_mut();
+            *e = e.pow([self.parameters.alpha]);
+        }
+        self.apply_external_mds(state);
+    }
+
+    /// Apply a partial round, which applies the round key and the S-box to the first state element,
+    /// followed by the internal matrix.
+    fn apply_partial_round(&self, state: &mut [Field<E>], round: usize) {
+        state[0] += Field::<E>::new(self.parameters.internal_ark[round]);
+        let e = state[0].deref_mut();
+        *e = e.pow([self.parameters.alpha]);
+        self.apply_internal_mds(state);
+    }
+
+    /// Apply the external matrix `M_E` in-place.
+    fn apply_external_mds(&self, state: &mut [Field<E>]) {
+        let new_state = self
+            .parameters
+            .external_mds
+            .iter()
+            .map(|mds_row| Field::new(E::Field::sum_of_products(state.iter().map(|e| e.deref()), mds_row.iter())))
+            .collect::<Vec<_>>();
+        state.copy_from_slice(&new_state);
+    }
+
+    /// Apply the internal matrix `M_I = J + D` in-place, which maps `state[i]` to `sum(state) + D[i] * state[i]`.
+    fn apply_internal_mds(&self, state: &mut [Field<E>]) {
+        let sum = state.iter().fold(Field::<E>::zero(), |sum, element| sum + element);
+        for (element, diagonal) in state.iter_mut().zip_eq(&self.parameters.internal_diagonal) {
+            *element = sum + Field::new(*diagonal) * *element;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+    use snarkvm_curves::edwards_bls12::Fq;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_parameters() {
+        fn single_rate_test<const RATE: usize>() {
+            let parameters = Fq::default_poseidon2_parameters::<RATE>().unwrap();
+            let poseidon = Fq::default_poseidon_parameters::<RATE>().unwrap();
+            // Ensure the number of rounds and the S-box match those of Poseidon.
+            assert_eq!(parameters.full_rounds, poseidon.full_rounds);
+            assert_eq!(parameters.partial_rounds, poseidon.partial_rounds);
+            assert_eq!(parameters.alpha, poseidon.alpha);
+            // Ensure the round keys and matrices have the expected dimensions.
+            assert_eq!(parameters.external_ark.len(), parameters.full_rounds);
+            assert!(parameters.external_ark.iter().all(|round| round.len() == RATE + CAPACITY));
+            assert_eq!(parameters.internal_ark.len(), parameters.partial_rounds);
+            assert_eq!(parameters.external_mds.len(), RATE + CAPACITY);
+            assert!(parameters.external_mds.iter().all(|row| row.len() == RATE + CAPACITY));
+            assert_eq!(parameters.internal_diagonal.len(), RATE + CAPACITY);
+            // Ensure the parameters are deterministic.
+            assert_eq!(parameters, Fq::default_poseidon2_parameters::<RATE>().unwrap());
+        }
+        single_rate_test::<2>();
+        single_rate_test::<3>();
+        single_rate_test::<4>();
+        single_rate_test::<5>();
+        single_rate_test::<6>();
+        single_rate_test::<7>();
+        single_rate_test::<8>();
+    }
+
+    #[test]
+    fn test_permutation_is_injective() -> Result<()> {
+        let mut rng = TestRng::default();
+        let poseidon = Poseidon2_4::<CurrentEnvironment>::setup("Poseidon2PermutationTest")?;
+
+        for _ in 0..100 {
+            // Sample two distinct states.
+            let first = (0..5).map(|_| Uniform::rand(&mut rng)).collect::<Vec<Field<CurrentEnvironment>>>();
+            let mut second = first.clone();
+            second[4] += Field::one();
+
+            // Ensure the permuted states are distinct.
+            let (mut first_state, mut second_state) = (first.clone(), second.clone());
+            poseidon.permute(&mut first_state);
+            poseidon.permute(&mut second_state);
+            assert_ne!(first_state, second_state);
+            assert_ne!(first, first_state);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_internal_mds() -> Result<()> {
+        let mut rng = TestRng::default();
+        let poseidon = Poseidon2_2::<CurrentEnvironment>::setup("Poseidon2MatrixTest")?;
+
+        // Sample a random state.
+        let state = (0..3).map(|_| Uniform::rand(&mut rng)).collect::<Vec<Field<CurrentEnvironment>>>();
+
+        // Compute the expected output as the dense product with `J + D`.
+        let expected = (0..3)
+            .map(|i| {
+                (0..3).fold(Field::zero(), |acc, j| {
+                    let entry = match i == j {
+                        true => Field::one() + Field::new(poseidon.parameters.internal_diagonal[i]),
+                        false => Field::one(),
+                    };
+                    acc + entry * state[j]
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Compute the candidate output.
+        let mut candidate = state;
+        poseidon.apply_internal_mds(&mut candidate);
+        assert_eq!(expected, candidate);
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_utilities::{bits_from_bytes_le, bytes_from_bits_le};
+
+impl Hash for Sha256 {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the SHA-256 hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        Ok(bits_from_bytes_le(&sha256_native(&bytes_from_bits_le(input))).collect())
+    }
+}
+
+/// Computes the SHA-256 hash of the given preimage as bytes.
+fn sha256_native(preimage: &[u8]) -> [u8; 32] {
+    let mut sha256 = NativeSha256::new();
+    sha256.update(preimage);
+
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&sha256.finalize());
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+
+    #[test]
+    fn test_sha256_equivalence() {
+        let rng = &mut TestRng::default();
+
+        let mut input_sizes = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 16, 32, 64, 128, 256, 440, 447, 448, 512, 1024];
+        input_sizes.extend((0..100).map(|_| rng.gen_range(1..1024)));
+
+        for num_inputs in input_sizes {
+            println!("Checking equivalence for {num_inputs} inputs");
+
+            // Prepare the preimage.
+            let input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+
+            // Compute the native hash.
+            let expected = sha256_native(&bytes_from_bits_le(&input));
+            let expected = bits_from_bytes_le(&expected).collect::<Vec<_>>();
+
+            // Compute the console hash.
+            let candidate = Sha256.hash(&input).unwrap();
+            assert_eq!(expected, candidate);
+        }
+    }
+
+    #[test]
+    fn test_sha256_vectors() {
+        // The test vectors from FIPS 180-2, Appendix B.
+        let vectors = [
+            ("", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+            ("abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+            (
+                "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+            ),
+            (
+                "abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+                "cf5b16a778af8380036ce59e7b0492370b249b11e8f07a51afac45037afee9d1",
+            ),
+        ];
+        for (input, output) in vectors {
+            let input = bits_from_bytes_le(input.as_bytes()).collect::<Vec<_>>();
+            let candidate = bytes_from_bits_le(&Sha256.hash(&input).unwrap());
+            assert_eq!(output, hex::encode(candidate));
+        }
+
+        // The test vector of one million repetitions of 'a'.
+        let input = bits_from_bytes_le(&[b'a'; 1_000_000]).collect::<Vec<_>>();
+        let candidate = bytes_from_bits_le(&Sha256.hash(&input).unwrap());
+        assert_eq!("cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0", hex::encode(candidate));
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+
+#[cfg(test)]
+use snarkvm_utilities::Uniform;
+
+use crate::Hash;
+use snarkvm_console_types::environment::prelude::*;
+
+use sha2::{Digest, Sha256 as NativeSha256};
+
+/// The SHA-256 hash function, as specified in FIPS 180-4.
+///
+/// The input bits are packed into bytes in little-endian bit order, where the final byte is padded with zeros.
+/// The message is then padded as `pad(M) = M || 0x80 || 0x00…0x00 || len(M)`, where `len(M)` is the
+/// length of `M` in bits as a 64-bit big-endian integer, such that the padded message is a multiple of 512 bits.
+///
+/// Each 512-bit block is absorbed into the 256-bit chaining state with the SHA-256 compression function,
+/// and the final chaining state is returned as the little-endian bits of its big-endian bytes.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Sha256;
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use snarkvm_console_algorithms::{Poseidon, BHP};
+use snarkvm_console_algorithms::{Poseidon, PoseidonV2, BHP};
 use snarkvm_console_types::prelude::*;
 
 #[cfg(not(feature = "serial"))]
@@ -65,3 +65,18 @@ impl<E: Environment, const RATE: usize> LeafHash for Poseidon<E, RATE> {
         Hash::hash(self, &input)
     }
 }
+
+impl<E: Environment, const RATE: usize> LeafHash for PoseidonV2<E, RATE> {
+    type Hash = Field<E>;
+    type Leaf = Vec<Self::Hash>;
+
+    /// Returns the hash of the given leaf node.
+    fn hash_leaf(&self, leaf: &Self::Leaf) -> Result<Self::Hash> {
+        let mut input = Vec::with_capacity(1 + leaf.len());
+        // Prepend the leaf with a `0field` element.
+        input.push(Self::Hash::zero());
+        input.extend(leaf);
+        // Hash the input.
+        Hash::hash(self, &input)
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use snarkvm_console_algorithms::{Poseidon, BHP};
+use snarkvm_console_algorithms::{Poseidon, PoseidonV2, BHP};
 use snarkvm_console_types::prelude::*;
 
 #[cfg(not(feature = "serial"))]
@@ -66,3 +66,15 @@ impl<E: Environment, const RATE: usize> PathHash for Poseidon<E, RATE> {
         Hash::hash(self, input)
     }
 }
+
+impl<E: Environment, const RATE: usize> PathHash for PoseidonV2<E, RATE> {
+    type Hash = Field<E>;
+
+    /// Returns the hash of the given child nodes.
+    fn hash_children(&self, left: &Self::Hash, right: &Self::Hash) -> Result<Self::Hash> {
+        // Prepend the nodes with a `1field` byte.
+        let input = &[Self::Hash::one(), *left, *right];
+        // Hash the input.
+        Hash::hash(self, input)
+    }
+}
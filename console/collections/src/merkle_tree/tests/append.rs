@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::*;
-use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_algorithms::{Poseidon, PoseidonV2, BHP1024, BHP512};
 use snarkvm_console_types::prelude::Console;
 
 type CurrentEnvironment = Console;
@@ -469,6 +469,53 @@ fn test_merkle_tree_poseidon() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_merkle_tree_poseidon2() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = PoseidonV2<CurrentEnvironment, 4>;
+        type PH = PoseidonV2<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 0..ITERATIONS {
+            for j in 0..ITERATIONS {
+                // Determine the leaves and additional leaves.
+                let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+                let num_additional_leaves = core::cmp::min(2u128.pow(DEPTH as u32) - num_leaves, j);
+
+                // Check the Merkle tree.
+                check_merkle_tree::<CurrentEnvironment, LH, PH, DEPTH>(
+                    &leaf_hasher,
+                    &path_hasher,
+                    &(0..num_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>(),
+                    &(0..num_additional_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>(),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Spot check important depths.
+    assert!(run_test::<1>(&mut rng).is_ok());
+    assert!(run_test::<2>(&mut rng).is_ok());
+    assert!(run_test::<3>(&mut rng).is_ok());
+    assert!(run_test::<4>(&mut rng).is_ok());
+    assert!(run_test::<5>(&mut rng).is_ok());
+    assert!(run_test::<6>(&mut rng).is_ok());
+    assert!(run_test::<7>(&mut rng).is_ok());
+    assert!(run_test::<8>(&mut rng).is_ok());
+    assert!(run_test::<9>(&mut rng).is_ok());
+    assert!(run_test::<10>(&mut rng).is_ok());
+    assert!(run_test::<32>(&mut rng).is_ok());
+    assert!(run_test::<64>(&mut rng).is_ok());
+    Ok(())
+}
+
 #[test]
 fn test_merkle_tree_depth_2_bhp() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
@@ -0,0 +1,698 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A 256-bit signed integer, stored in two's complement form.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct i256(u256);
+
+impl i256 {
+    /// The size of this integer type in bits.
+    pub const BITS: u32 = 256;
+    /// The largest value that can be represented by this integer type.
+    pub const MAX: Self = Self(u256::from_halves(u128::MAX, u128::MAX >> 1));
+    /// The smallest value that can be represented by this integer type.
+    pub const MIN: Self = Self(u256::from_halves(0, 1 << 127));
+    /// The value `-1`.
+    pub const NEG_ONE: Self = Self(u256::MAX);
+    /// The value `1`.
+    pub const ONE: Self = Self(u256::ONE);
+    /// The value `0`.
+    pub const ZERO: Self = Self(u256::ZERO);
+
+    /// Reinterprets the given bits as a signed integer.
+    pub const fn from_bits(bits: u256) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the lower 128 bits of `self`.
+    pub const fn low(self) -> u128 {
+        self.0.low()
+    }
+
+    /// Reinterprets the bits of `self` as an unsigned integer.
+    pub const fn as_u256(self) -> u256 {
+        self.0
+    }
+
+    /// Returns `true` if `self` is zero.
+    pub const fn is_zero(self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns `true` if `self` is negative.
+    pub const fn is_negative(self) -> bool {
+        self.0.high() >> 127 == 1
+    }
+
+    /// Returns the absolute value of `self` as an unsigned integer.
+    pub const fn unsigned_abs(self) -> u256 {
+        match self.is_negative() {
+            true => self.0.wrapping_neg(),
+            false => self.0,
+        }
+    }
+
+    /// Returns the number of ones in the binary representation of `self`.
+    pub const fn count_ones(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Returns the number of leading zeros in the binary representation of `self`.
+    pub const fn leading_zeros(self) -> u32 {
+        self.0.leading_zeros()
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of `self`.
+    pub const fn trailing_zeros(self) -> u32 {
+        self.0.trailing_zeros()
+    }
+
+    /// Returns the signed integer with the given sign and magnitude, or `None` if it is out of range.
+    const fn from_sign_and_magnitude(is_negative: bool, magnitude: u256) -> Option<Self> {
+        match is_negative {
+            // The magnitude of a negative integer is at most `2^255`.
+            true => match magnitude.high() > 1 << 127 || (magnitude.high() == 1 << 127 && magnitude.low() != 0) {
+                true => None,
+                false => Some(Self(magnitude.wrapping_neg())),
+            },
+            // The magnitude of a non-negative integer is at most `2^255 - 1`.
+            false => match magnitude.high() >> 127 == 1 {
+                true => None,
+                false => Some(Self(magnitude)),
+            },
+        }
+    }
+
+    /// Calculates `self + rhs`, returning the sum and whether an overflow occurred.
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let sum = Self(self.0.wrapping_add(rhs.0));
+        // An overflow occurs if both operands have the same sign, and the sum has a different sign.
+        let overflow = self.is_negative() == rhs.is_negative() && sum.is_negative() != self.is_negative();
+        (sum, overflow)
+    }
+
+    /// Calculates `self - rhs`, returning the difference and whether an overflow occurred.
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let difference = Self(self.0.wrapping_sub(rhs.0));
+        // An overflow occurs if the operands have different signs, and the difference has the sign of `rhs`.
+        let overflow = self.is_negative() != rhs.is_negative() && difference.is_negative() != self.is_negative();
+        (difference, overflow)
+    }
+
+    /// Returns `self + rhs`, or `None` if an overflow occurred.
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (sum, false) => Some(sum),
+            (_, true) => None,
+        }
+    }
+
+    /// Returns `self - rhs`, or `None` if an overflow occurred.
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (difference, false) => Some(difference),
+            (_, true) => None,
+        }
+    }
+
+    /// Returns `self * rhs`, or `None` if an overflow occurred.
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.unsigned_abs().checked_mul(rhs.unsigned_abs()) {
+            Some(magnitude) => Self::from_sign_and_magnitude(self.is_negative() != rhs.is_negative(), magnitude),
+            None => None,
+        }
+    }
+
+    /// Returns `self / rhs`, rounding towards zero, or `None` if `rhs` is zero or the division overflows.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::NEG_ONE) {
+            return None;
+        }
+        let quotient = self.unsigned_abs().div_rem(rhs.unsigned_abs()).0;
+        Self::from_sign_and_magnitude(self.is_negative() != rhs.is_negative(), quotient)
+    }
+
+    /// Returns `self % rhs`, or `None` if `rhs` is zero or the division overflows.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::NEG_ONE) {
+            return None;
+        }
+        // The remainder has the same sign as the dividend.
+        let remainder = self.unsigned_abs().div_rem(rhs.unsigned_abs()).1;
+        Self::from_sign_and_magnitude(self.is_negative(), remainder)
+    }
+
+    /// Returns `-self`, or `None` if `self` is the minimum value.
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.0.high() == 1 << 127 && self.0.low() == 0 {
+            true => None,
+            false => Some(Self(self.0.wrapping_neg())),
+        }
+    }
+
+    /// Returns the absolute value of `self`, or `None` if `self` is the minimum value.
+    pub const fn checked_abs(self) -> Option<Self> {
+        match self.is_negative() {
+            true => self.checked_neg(),
+            false => Some(self),
+        }
+    }
+
+    /// Returns `self << rhs`, or `None` if `rhs` is at least the number of bits.
+    pub const fn checked_shl(self, rhs: u32) -> Option<Self> {
+        match rhs < Self::BITS {
+            true => Some(self.wrapping_shl(rhs)),
+            false => None,
+        }
+    }
+
+    /// Returns `self >> rhs`, or `None` if `rhs` is at least the number of bits.
+    pub const fn checked_shr(self, rhs: u32) -> Option<Self> {
+        match rhs < Self::BITS {
+            true => Some(self.wrapping_shr(rhs)),
+            false => None,
+        }
+    }
+
+    /// Returns `self ^ exp`, or `None` if an overflow occurred.
+    pub const fn checked_pow(self, exp: u32) -> Option<Self> {
+        // The result is negative if the base is negative and the exponent is odd.
+        match self.unsigned_abs().checked_pow(exp) {
+            Some(magnitude) => Self::from_sign_and_magnitude(self.is_negative() && exp & 1 == 1, magnitude),
+            None => None,
+        }
+    }
+
+    /// Returns `self + rhs`, wrapping around at the boundary of the type.
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_add(rhs.0))
+    }
+
+    /// Returns `self - rhs`, wrapping around at the boundary of the type.
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_sub(rhs.0))
+    }
+
+    /// Returns `self * rhs`, wrapping around at the boundary of the type.
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_mul(rhs.0))
+    }
+
+    /// Returns `self / rhs`, wrapping around at the boundary of the type.
+    ///
+    /// # Panics
+    /// This method panics if `rhs` is zero.
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+        // Note: `MIN / -1` wraps around to `MIN`.
+        self.checked_div(rhs).unwrap_or(Self::MIN)
+    }
+
+    /// Returns `self % rhs`, wrapping around at the boundary of the type.
+    ///
+    /// # Panics
+    /// This method panics if `rhs` is zero.
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        assert!(!rhs.is_zero(), "attempt to calculate the remainder with a divisor of zero");
+        // Note: `MIN % -1` wraps around to `0`.
+        self.checked_rem(rhs).unwrap_or(Self::ZERO)
+    }
+
+    /// Returns `-self`, wrapping around at the boundary of the type.
+    pub const fn wrapping_neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+
+    /// Returns the absolute value of `self`, wrapping around at the boundary of the type.
+    pub const fn wrapping_abs(self) -> Self {
+        Self(self.unsigned_abs())
+    }
+
+    /// Returns `self ^ exp`, wrapping around at the boundary of the type.
+    pub const fn wrapping_pow(self, exp: u32) -> Self {
+        Self(self.0.wrapping_pow(exp))
+    }
+
+    /// Returns `self << (rhs % 256)`.
+    pub const fn wrapping_shl(self, rhs: u32) -> Self {
+        Self(self.0.wrapping_shl(rhs))
+    }
+
+    /// Returns `self >> (rhs % 256)`, filling the upper bits with the sign bit.
+    pub const fn wrapping_shr(self, rhs: u32) -> Self {
+        let shift = rhs % Self::BITS;
+        let shifted = self.0.wrapping_shr(shift);
+        match self.is_negative() && shift > 0 {
+            // Fill the upper `shift` bits with ones.
+            true => Self(u256::from_halves(
+                shifted.low() | (u256::MAX.wrapping_shl(Self::BITS - shift)).low(),
+                shifted.high() | (u256::MAX.wrapping_shl(Self::BITS - shift)).high(),
+            )),
+            false => Self(shifted),
+        }
+    }
+
+    /// Returns `self >> (rhs % 256)`, filling the upper bits with the sign bit.
+    const fn arithmetic_shr(self, rhs: u32) -> Self {
+        self.wrapping_shr(rhs)
+    }
+
+    /// Returns `self >> (rhs % 256)`, filling the upper bits with zeros.
+    const fn logical_shr(self, rhs: u32) -> Self {
+        Self(self.0.wrapping_shr(rhs))
+    }
+
+    /// Returns `self + rhs`, saturating at the numeric bounds.
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(sum) => sum,
+            None if rhs.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Returns `self - rhs`, saturating at the numeric bounds.
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(difference) => difference,
+            None if rhs.is_negative() => Self::MAX,
+            None => Self::MIN,
+        }
+    }
+
+    /// Returns the memory representation of `self` as a byte array in little-endian order.
+    pub fn to_le_bytes(self) -> [u8; 32] {
+        self.0.to_le_bytes()
+    }
+
+    /// Returns the memory representation of `self` as a byte array in big-endian order.
+    pub fn to_be_bytes(self) -> [u8; 32] {
+        self.0.to_be_bytes()
+    }
+
+    /// Initializes an integer from its memory representation as a byte array in little-endian order.
+    pub fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        Self(u256::from_le_bytes(bytes))
+    }
+
+    /// Initializes an integer from its memory representation as a byte array in big-endian order.
+    pub fn from_be_bytes(bytes: [u8; 32]) -> Self {
+        Self(u256::from_be_bytes(bytes))
+    }
+
+    /// Parses an integer from a string in the given radix, with an optional leading `+` or `-`.
+    pub fn from_str_radix(string: &str, radix: u32) -> Result<Self, ParseIntError> {
+        // Parse the magnitude, and then apply the sign.
+        match string.strip_prefix('-') {
+            Some(digits) => {
+                let magnitude = match digits.starts_with('+') {
+                    true => return Err(invalid_digit_error()),
+                    false => u256::from_str_radix(digits, radix).map_err(|error| match error.kind() {
+                        IntErrorKind::PosOverflow => negative_overflow_error(),
+                        _ if digits.is_empty() => invalid_digit_error(),
+                        _ => error,
+                    })?,
+                };
+                Self::from_sign_and_magnitude(true, magnitude).ok_or_else(negative_overflow_error)
+            }
+            None => {
+                let magnitude = u256::from_str_radix(string, radix)?;
+                Self::from_sign_and_magnitude(false, magnitude).ok_or_else(positive_overflow_error)
+            }
+        }
+    }
+}
+
+impl Ord for i256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Flip the sign bits, so that the unsigned order matches the signed order.
+        let sign = u256::from_halves(0, 1 << 127);
+        (self.0 ^ sign).cmp(&(other.0 ^ sign))
+    }
+}
+
+impl PartialOrd for i256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<i8> for i256 {
+    fn from(value: i8) -> Self {
+        Self::from(value as i128)
+    }
+}
+
+impl From<i16> for i256 {
+    fn from(value: i16) -> Self {
+        Self::from(value as i128)
+    }
+}
+
+impl From<i32> for i256 {
+    fn from(value: i32) -> Self {
+        Self::from(value as i128)
+    }
+}
+
+impl From<i64> for i256 {
+    fn from(value: i64) -> Self {
+        Self::from(value as i128)
+    }
+}
+
+impl From<i128> for i256 {
+    fn from(value: i128) -> Self {
+        // Sign-extend the value into the upper half.
+        let hi = match value < 0 {
+            true => u128::MAX,
+            false => 0,
+        };
+        Self(u256::from_halves(value as u128, hi))
+    }
+}
+
+impl From<u8> for i256 {
+    fn from(value: u8) -> Self {
+        Self(u256::from(value))
+    }
+}
+
+impl From<u16> for i256 {
+    fn from(value: u16) -> Self {
+        Self(u256::from(value))
+    }
+}
+
+impl From<u32> for i256 {
+    fn from(value: u32) -> Self {
+        Self(u256::from(value))
+    }
+}
+
+impl From<u64> for i256 {
+    fn from(value: u64) -> Self {
+        Self(u256::from(value))
+    }
+}
+
+impl From<u128> for i256 {
+    fn from(value: u128) -> Self {
+        Self(u256::from(value))
+    }
+}
+
+impl TryFrom<u256> for i256 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: u256) -> Result<Self, Self::Error> {
+        match value.as_i256() {
+            value if value.is_negative() => Err(try_from_int_error()),
+            value => Ok(value),
+        }
+    }
+}
+
+impl AsPrimitive<i256> for i256 {
+    fn as_(self) -> i256 {
+        self
+    }
+}
+
+impl AsPrimitive<u256> for i256 {
+    fn as_(self) -> u256 {
+        self.as_u256()
+    }
+}
+
+impl Not for i256 {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self(!self.0)
+    }
+}
+
+impl BitAnd for i256 {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for i256 {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitXor for i256 {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl Neg for i256 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
+
+impl Display for i256 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad_integral(!self.is_negative(), "", &self.unsigned_abs().to_string())
+    }
+}
+
+impl Binary for i256 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // As with the primitive integers, the two's complement representation is formatted.
+        Binary::fmt(&self.0, f)
+    }
+}
+
+impl Debug for i256 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl FromStr for i256 {
+    type Err = ParseIntError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(string, 10)
+    }
+}
+
+impl ToBits for i256 {
+    /// Returns `self` as a boolean array in little-endian order.
+    fn write_bits_le(&self, vec: &mut Vec<bool>) {
+        self.0.write_bits_le(vec);
+    }
+
+    /// Returns `self` as a boolean array in big-endian order.
+    fn write_bits_be(&self, vec: &mut Vec<bool>) {
+        self.0.write_bits_be(vec);
+    }
+
+    fn num_bits() -> Option<usize> {
+        Some(Self::BITS as usize)
+    }
+}
+
+impl FromBits for i256 {
+    /// Reads `Self` from a boolean array in little-endian order.
+    fn from_bits_le(bits: &[bool]) -> anyhow::Result<Self> {
+        Ok(Self(u256::from_bits_le(bits)?))
+    }
+
+    /// Reads `Self` from a boolean array in big-endian order.
+    fn from_bits_be(bits: &[bool]) -> anyhow::Result<Self> {
+        Ok(Self(u256::from_bits_be(bits)?))
+    }
+}
+
+impl ToBytes for i256 {
+    /// Writes `self` into `writer` as little-endian bytes.
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write_le(writer)
+    }
+}
+
+impl FromBytes for i256 {
+    /// Reads `Self` from `reader` as little-endian bytes.
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Self(u256::read_le(reader)?))
+    }
+}
+
+impl Distribution<i256> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> i256 {
+        i256(rng.gen())
+    }
+}
+
+impl ToPrimitive for i256 {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_i128().and_then(|value| value.to_i64())
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|value| value.to_u64())
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        // The value fits in 128 bits if the upper half is the sign extension of the lower half.
+        let lo = self.0.low() as i128;
+        let sign_extension = match lo < 0 {
+            true => u128::MAX,
+            false => 0,
+        };
+        match self.0.high() == sign_extension {
+            true => Some(lo),
+            false => None,
+        }
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        match self.is_negative() {
+            true => None,
+            false => self.0.to_u128(),
+        }
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        let magnitude = self.unsigned_abs().to_f64()?;
+        match self.is_negative() {
+            true => Some(-magnitude),
+            false => Some(magnitude),
+        }
+    }
+}
+
+impl num_traits::NumCast for i256 {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        match n.to_i128() {
+            Some(value) => Some(From::from(value)),
+            None => n.to_u128().map(|value| Self(From::from(value))),
+        }
+    }
+}
+
+impl_integer_traits!(i256);
+impl_primitive_conversions!(i256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: usize = 1000;
+
+    /// Samples a random integer, which is randomly shrunk to exercise the small-value paths.
+    fn sample(rng: &mut TestRng) -> i256 {
+        let value: i256 = rng.gen();
+        match rng.gen_range(0..4) {
+            0 => i256::from(rng.gen::<i64>()),
+            1 => i256::from(rng.gen::<i128>()),
+            _ => value,
+        }
+    }
+
+    #[test]
+    fn test_display_and_parse() {
+        assert_eq!(
+            i256::MAX.to_string(),
+            "57896044618658097711785492504343953926634992332820282019728792003956564819967"
+        );
+        assert_eq!(
+            i256::MIN.to_string(),
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+        );
+        assert_eq!(i256::NEG_ONE.to_string(), "-1");
+        assert_eq!(i256::from(i128::MIN).to_string(), i128::MIN.to_string());
+
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            let value = sample(&mut rng);
+            assert_eq!(value, i256::from_str(&value.to_string()).unwrap());
+        }
+
+        assert!(i256::from_str("").is_err());
+        assert!(i256::from_str("-").is_err());
+        assert!(
+            i256::from_str("57896044618658097711785492504343953926634992332820282019728792003956564819968").is_err()
+        );
+        assert!(
+            i256::from_str("-57896044618658097711785492504343953926634992332820282019728792003956564819969").is_err()
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            let a = sample(&mut rng);
+            let b = sample(&mut rng);
+
+            // Check the additive identity and the ordering.
+            assert_eq!(a.wrapping_add(b).wrapping_sub(b), a);
+            if let (Some(x), Some(y)) = (a.to_i128(), b.to_i128()) {
+                assert_eq!(a.cmp(&b), x.cmp(&y));
+            }
+            // Check the division identity.
+            if let Some(quotient) = a.checked_div(b) {
+                let remainder = a.checked_rem(b).unwrap();
+                assert_eq!(quotient.checked_mul(b).and_then(|value| value.checked_add(remainder)), Some(a));
+                assert!(remainder.is_zero() || remainder.is_negative() == a.is_negative());
+            }
+        }
+
+        // Check the overflow cases.
+        assert_eq!(i256::MAX.checked_add(i256::ONE), None);
+        assert_eq!(i256::MIN.checked_sub(i256::ONE), None);
+        assert_eq!(i256::MIN.checked_neg(), None);
+        assert_eq!(i256::MIN.checked_abs(), None);
+        assert_eq!(i256::MIN.checked_div(i256::NEG_ONE), None);
+        assert_eq!(i256::MIN.wrapping_div(i256::NEG_ONE), i256::MIN);
+        assert_eq!(i256::MIN.checked_mul(i256::ONE), Some(i256::MIN));
+        assert_eq!(i256::MIN.checked_mul(i256::NEG_ONE), None);
+        assert_eq!(i256::from(-2i8).checked_pow(255), Some(i256::MIN));
+        assert_eq!(i256::from(2i8).checked_pow(255), None);
+        assert_eq!(i256::from(-8i8).wrapping_shr(2), i256::from(-2i8));
+        assert_eq!(i256::NEG_ONE.wrapping_shr(255), i256::NEG_ONE);
+        assert!(i256::MIN < i256::NEG_ONE && i256::NEG_ONE < i256::ZERO && i256::ZERO < i256::MAX);
+    }
+
+    #[test]
+    fn test_bits_and_bytes() {
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            let value = sample(&mut rng);
+            assert_eq!(value, i256::from_bits_le(&value.to_bits_le()).unwrap());
+            assert_eq!(value, i256::from_bits_be(&value.to_bits_be()).unwrap());
+            assert_eq!(value, i256::from_bytes_le(&value.to_bytes_le().unwrap()).unwrap());
+        }
+    }
+}
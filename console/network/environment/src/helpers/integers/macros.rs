@@ -0,0 +1,362 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Implements the operator and `num_traits` traits for a 256-bit integer,
+/// in terms of the inherent `checked_*`, `wrapping_*`, and `saturating_*` methods of the type.
+///
+/// As with the primitive integers, the operators panic on overflow, division by zero, and oversized shifts.
+macro_rules! impl_integer_traits {
+    ($t:ident) => {
+        impl Add for $t {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                self.checked_add(rhs).expect("attempt to add with overflow")
+            }
+        }
+
+        impl Sub for $t {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                self.checked_sub(rhs).expect("attempt to subtract with overflow")
+            }
+        }
+
+        impl Mul for $t {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                self.checked_mul(rhs).expect("attempt to multiply with overflow")
+            }
+        }
+
+        impl Div for $t {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                assert!(!rhs.is_zero(), "attempt to divide by zero");
+                self.checked_div(rhs).expect("attempt to divide with overflow")
+            }
+        }
+
+        impl Rem for $t {
+            type Output = Self;
+
+            fn rem(self, rhs: Self) -> Self::Output {
+                assert!(!rhs.is_zero(), "attempt to calculate the remainder with a divisor of zero");
+                self.checked_rem(rhs).expect("attempt to calculate the remainder with overflow")
+            }
+        }
+
+        impl Shl<u32> for $t {
+            type Output = Self;
+
+            fn shl(self, rhs: u32) -> Self::Output {
+                self.checked_shl(rhs).expect("attempt to shift left with overflow")
+            }
+        }
+
+        impl Shl<usize> for $t {
+            type Output = Self;
+
+            fn shl(self, rhs: usize) -> Self::Output {
+                let rhs = u32::try_from(rhs).expect("attempt to shift left with overflow");
+                self << rhs
+            }
+        }
+
+        impl Shr<u32> for $t {
+            type Output = Self;
+
+            fn shr(self, rhs: u32) -> Self::Output {
+                self.checked_shr(rhs).expect("attempt to shift right with overflow")
+            }
+        }
+
+        impl Shr<usize> for $t {
+            type Output = Self;
+
+            fn shr(self, rhs: usize) -> Self::Output {
+                let rhs = u32::try_from(rhs).expect("attempt to shift right with overflow");
+                self >> rhs
+            }
+        }
+
+        impl num_traits::Zero for $t {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            fn is_zero(&self) -> bool {
+                $t::is_zero(*self)
+            }
+        }
+
+        impl num_traits::One for $t {
+            fn one() -> Self {
+                Self::ONE
+            }
+        }
+
+        impl num_traits::Bounded for $t {
+            fn min_value() -> Self {
+                Self::MIN
+            }
+
+            fn max_value() -> Self {
+                Self::MAX
+            }
+        }
+
+        impl num_traits::Num for $t {
+            type FromStrRadixErr = ParseIntError;
+
+            fn from_str_radix(string: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                $t::from_str_radix(string, radix)
+            }
+        }
+
+        impl num_traits::CheckedAdd for $t {
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                $t::checked_add(*self, *v)
+            }
+        }
+
+        impl num_traits::CheckedSub for $t {
+            fn checked_sub(&self, v: &Self) -> Option<Self> {
+                $t::checked_sub(*self, *v)
+            }
+        }
+
+        impl num_traits::CheckedMul for $t {
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                $t::checked_mul(*self, *v)
+            }
+        }
+
+        impl num_traits::CheckedDiv for $t {
+            fn checked_div(&self, v: &Self) -> Option<Self> {
+                $t::checked_div(*self, *v)
+            }
+        }
+
+        impl num_traits::CheckedRem for $t {
+            fn checked_rem(&self, v: &Self) -> Option<Self> {
+                $t::checked_rem(*self, *v)
+            }
+        }
+
+        impl num_traits::CheckedNeg for $t {
+            fn checked_neg(&self) -> Option<Self> {
+                $t::checked_neg(*self)
+            }
+        }
+
+        impl num_traits::CheckedShr for $t {
+            fn checked_shr(&self, rhs: u32) -> Option<Self> {
+                $t::checked_shr(*self, rhs)
+            }
+        }
+
+        impl num_traits::WrappingAdd for $t {
+            fn wrapping_add(&self, v: &Self) -> Self {
+                $t::wrapping_add(*self, *v)
+            }
+        }
+
+        impl num_traits::WrappingSub for $t {
+            fn wrapping_sub(&self, v: &Self) -> Self {
+                $t::wrapping_sub(*self, *v)
+            }
+        }
+
+        impl num_traits::WrappingMul for $t {
+            fn wrapping_mul(&self, v: &Self) -> Self {
+                $t::wrapping_mul(*self, *v)
+            }
+        }
+
+        impl num_traits::WrappingNeg for $t {
+            fn wrapping_neg(&self) -> Self {
+                $t::wrapping_neg(*self)
+            }
+        }
+
+        impl num_traits::WrappingShl for $t {
+            fn wrapping_shl(&self, rhs: u32) -> Self {
+                $t::wrapping_shl(*self, rhs)
+            }
+        }
+
+        impl num_traits::WrappingShr for $t {
+            fn wrapping_shr(&self, rhs: u32) -> Self {
+                $t::wrapping_shr(*self, rhs)
+            }
+        }
+
+        impl num_traits::Saturating for $t {
+            fn saturating_add(self, v: Self) -> Self {
+                $t::saturating_add(self, v)
+            }
+
+            fn saturating_sub(self, v: Self) -> Self {
+                $t::saturating_sub(self, v)
+            }
+        }
+
+        impl num_traits::PrimInt for $t {
+            fn count_ones(self) -> u32 {
+                $t::count_ones(self)
+            }
+
+            fn count_zeros(self) -> u32 {
+                Self::BITS - $t::count_ones(self)
+            }
+
+            fn leading_zeros(self) -> u32 {
+                $t::leading_zeros(self)
+            }
+
+            fn trailing_zeros(self) -> u32 {
+                $t::trailing_zeros(self)
+            }
+
+            fn rotate_left(self, n: u32) -> Self {
+                let n = n % Self::BITS;
+                match n {
+                    0 => self,
+                    n => self.wrapping_shl(n) | self.logical_shr(Self::BITS - n),
+                }
+            }
+
+            fn rotate_right(self, n: u32) -> Self {
+                let n = n % Self::BITS;
+                match n {
+                    0 => self,
+                    n => self.logical_shr(n) | self.wrapping_shl(Self::BITS - n),
+                }
+            }
+
+            fn signed_shl(self, n: u32) -> Self {
+                self << n
+            }
+
+            fn signed_shr(self, n: u32) -> Self {
+                assert!(n < Self::BITS, "attempt to shift right with overflow");
+                self.arithmetic_shr(n)
+            }
+
+            fn unsigned_shl(self, n: u32) -> Self {
+                self << n
+            }
+
+            fn unsigned_shr(self, n: u32) -> Self {
+                assert!(n < Self::BITS, "attempt to shift right with overflow");
+                self.logical_shr(n)
+            }
+
+            fn swap_bytes(self) -> Self {
+                Self::from_be_bytes(self.to_le_bytes())
+            }
+
+            fn from_be(x: Self) -> Self {
+                x.to_be()
+            }
+
+            fn from_le(x: Self) -> Self {
+                x.to_le()
+            }
+
+            fn to_be(self) -> Self {
+                match cfg!(target_endian = "big") {
+                    true => self,
+                    false => self.swap_bytes(),
+                }
+            }
+
+            fn to_le(self) -> Self {
+                match cfg!(target_endian = "little") {
+                    true => self,
+                    false => self.swap_bytes(),
+                }
+            }
+
+            fn pow(self, exp: u32) -> Self {
+                self.checked_pow(exp).expect("attempt to multiply with overflow")
+            }
+        }
+    };
+}
+
+/// Implements the checked (`TryFrom`) and wrapping (`AsPrimitive`) conversions
+/// between a 256-bit integer and each of the primitive integers.
+macro_rules! impl_primitive_conversions {
+    ($t:ident) => {
+        impl_primitive_conversions!($t, unsigned: u8, u16, u32, u64, u128);
+        impl_primitive_conversions!($t, signed: i8, i16, i32, i64, i128);
+    };
+    ($t:ident, unsigned: $($p:ty),+) => {
+        $(
+            impl TryFrom<$t> for $p {
+                type Error = TryFromIntError;
+
+                fn try_from(value: $t) -> Result<Self, Self::Error> {
+                    match value.to_u128() {
+                        Some(value) => <$p>::try_from(value).map_err(|_| try_from_int_error()),
+                        None => Err(try_from_int_error()),
+                    }
+                }
+            }
+
+            impl AsPrimitive<$p> for $t {
+                fn as_(self) -> $p {
+                    self.low() as $p
+                }
+            }
+
+            impl AsPrimitive<$t> for $p {
+                fn as_(self) -> $t {
+                    <$t>::from(self as u128)
+                }
+            }
+        )+
+    };
+    ($t:ident, signed: $($p:ty),+) => {
+        $(
+            impl TryFrom<$t> for $p {
+                type Error = TryFromIntError;
+
+                fn try_from(value: $t) -> Result<Self, Self::Error> {
+                    match value.to_i128() {
+                        Some(value) => <$p>::try_from(value).map_err(|_| try_from_int_error()),
+                        None => Err(try_from_int_error()),
+                    }
+                }
+            }
+
+            impl AsPrimitive<$p> for $t {
+                fn as_(self) -> $p {
+                    self.low() as $p
+                }
+            }
+
+            impl AsPrimitive<$t> for $p {
+                fn as_(self) -> $t {
+                    i256::from(self as i128).as_()
+                }
+            }
+        )+
+    };
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+mod macros;
+
+mod int256;
+pub use int256::i256;
+
+mod uint256;
+pub use uint256::u256;
+
+use snarkvm_utilities::{
+    FromBits,
+    FromBytes,
+    ToBits,
+    ToBytes,
+    io::{Read, Result as IoResult, Write},
+};
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Binary, Debug, Display, Formatter},
+    num::{IntErrorKind, ParseIntError, TryFromIntError},
+    ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub},
+    str::FromStr,
+};
+use num_traits::{AsPrimitive, ToPrimitive};
+use rand::{
+    Rng,
+    distributions::{Distribution, Standard},
+};
+
+#[cfg(test)]
+use snarkvm_utilities::TestRng;
+
+/// Returns the error for parsing an empty string.
+fn empty_error() -> ParseIntError {
+    "".parse::<u8>().unwrap_err()
+}
+
+/// Returns the error for parsing a string with an invalid digit.
+fn invalid_digit_error() -> ParseIntError {
+    "-".parse::<u8>().unwrap_err()
+}
+
+/// Returns the error for parsing a string that is too large for the integer type.
+fn positive_overflow_error() -> ParseIntError {
+    "256".parse::<u8>().unwrap_err()
+}
+
+/// Returns the error for parsing a string that is too small for the integer type.
+fn negative_overflow_error() -> ParseIntError {
+    "-129".parse::<i8>().unwrap_err()
+}
+
+/// Returns the error for a conversion that is out of range for the integer type.
+fn try_from_int_error() -> TryFromIntError {
+    u8::try_from(u16::MAX).unwrap_err()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(empty_error().kind(), &IntErrorKind::Empty);
+        assert_eq!(invalid_digit_error().kind(), &IntErrorKind::InvalidDigit);
+        assert_eq!(positive_overflow_error().kind(), &IntErrorKind::PosOverflow);
+        assert_eq!(negative_overflow_error().kind(), &IntErrorKind::NegOverflow);
+    }
+
+    #[test]
+    fn test_conversions() {
+        // Check the checked conversions.
+        assert_eq!(u8::try_from(u256::from(255u8)), Ok(255));
+        assert!(u8::try_from(u256::from(256u16)).is_err());
+        assert!(u256::try_from(-1i8).is_err());
+        assert_eq!(i8::try_from(i256::from(-128i8)), Ok(-128));
+        assert!(i8::try_from(i256::from(128u8)).is_err());
+        assert!(i256::try_from(u256::MAX).is_err());
+        assert!(u256::try_from(i256::NEG_ONE).is_err());
+        assert_eq!(u256::try_from(i256::MAX), Ok(u256::MAX >> 1u32));
+
+        // Check the wrapping conversions.
+        assert_eq!(AsPrimitive::<u8>::as_(u256::MAX), u8::MAX);
+        assert_eq!(AsPrimitive::<i128>::as_(i256::NEG_ONE), -1);
+        assert_eq!(AsPrimitive::<u256>::as_(-1i8), u256::MAX);
+        assert_eq!(AsPrimitive::<i256>::as_(u128::MAX).to_u128(), Some(u128::MAX));
+        assert_eq!(AsPrimitive::<i256>::as_(u256::MAX), i256::NEG_ONE);
+    }
+
+    #[test]
+    fn test_binary() {
+        assert_eq!(format!("{:b}", u256::from(5u8)), "101");
+        assert_eq!(format!("{:#b}", u256::from_halves(0, 1)), format!("0b1{}", "0".repeat(128)));
+        assert_eq!(format!("{:b}", i256::NEG_ONE), "1".repeat(256));
+    }
+}
@@ -0,0 +1,762 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A 256-bit unsigned integer, stored as two 128-bit halves.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct u256 {
+    /// The lower 128 bits.
+    lo: u128,
+    /// The upper 128 bits.
+    hi: u128,
+}
+
+impl u256 {
+    /// The size of this integer type in bits.
+    pub const BITS: u32 = 256;
+    /// The largest value that can be represented by this integer type.
+    pub const MAX: Self = Self { lo: u128::MAX, hi: u128::MAX };
+    /// The smallest value that can be represented by this integer type.
+    pub const MIN: Self = Self { lo: 0, hi: 0 };
+    /// The value `1`.
+    pub const ONE: Self = Self { lo: 1, hi: 0 };
+    /// The value `0`.
+    pub const ZERO: Self = Self { lo: 0, hi: 0 };
+
+    /// Initializes a new integer from its lower and upper 128 bits.
+    pub const fn from_halves(lo: u128, hi: u128) -> Self {
+        Self { lo, hi }
+    }
+
+    /// Returns the lower 128 bits.
+    pub const fn low(self) -> u128 {
+        self.lo
+    }
+
+    /// Returns the upper 128 bits.
+    pub const fn high(self) -> u128 {
+        self.hi
+    }
+
+    /// Returns `true` if `self` is zero.
+    pub const fn is_zero(self) -> bool {
+        self.lo == 0 && self.hi == 0
+    }
+
+    /// Returns the number of ones in the binary representation of `self`.
+    pub const fn count_ones(self) -> u32 {
+        self.lo.count_ones() + self.hi.count_ones()
+    }
+
+    /// Returns the number of leading zeros in the binary representation of `self`.
+    pub const fn leading_zeros(self) -> u32 {
+        match self.hi {
+            0 => 128 + self.lo.leading_zeros(),
+            hi => hi.leading_zeros(),
+        }
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of `self`.
+    pub const fn trailing_zeros(self) -> u32 {
+        match self.lo {
+            0 => 128 + self.hi.trailing_zeros(),
+            lo => lo.trailing_zeros(),
+        }
+    }
+
+    /// Calculates `self + rhs`, returning the sum and whether an overflow occurred.
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (lo, carry) = self.lo.overflowing_add(rhs.lo);
+        let (hi, overflow_0) = self.hi.overflowing_add(rhs.hi);
+        let (hi, overflow_1) = hi.overflowing_add(carry as u128);
+        (Self { lo, hi }, overflow_0 | overflow_1)
+    }
+
+    /// Calculates `self - rhs`, returning the difference and whether an underflow occurred.
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (lo, borrow) = self.lo.overflowing_sub(rhs.lo);
+        let (hi, underflow_0) = self.hi.overflowing_sub(rhs.hi);
+        let (hi, underflow_1) = hi.overflowing_sub(borrow as u128);
+        (Self { lo, hi }, underflow_0 | underflow_1)
+    }
+
+    /// Calculates `self * rhs`, returning the product and whether an overflow occurred.
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        // Compute the partial products of the halves.
+        let (lo, hi) = widening_mul(self.lo, rhs.lo);
+        let (lo_hi, lo_hi_carry) = widening_mul(self.lo, rhs.hi);
+        let (hi_lo, hi_lo_carry) = widening_mul(self.hi, rhs.lo);
+        // Sum the partial products into the upper half.
+        let (hi, overflow_0) = hi.overflowing_add(lo_hi);
+        let (hi, overflow_1) = hi.overflowing_add(hi_lo);
+        // The product overflows if any partial product exceeds 256 bits.
+        let overflow = (self.hi != 0 && rhs.hi != 0) || lo_hi_carry != 0 || hi_lo_carry != 0;
+        (Self { lo, hi }, overflow | overflow_0 | overflow_1)
+    }
+
+    /// Returns `self + rhs`, or `None` if an overflow occurred.
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (sum, false) => Some(sum),
+            (_, true) => None,
+        }
+    }
+
+    /// Returns `self - rhs`, or `None` if an underflow occurred.
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (difference, false) => Some(difference),
+            (_, true) => None,
+        }
+    }
+
+    /// Returns `self * rhs`, or `None` if an overflow occurred.
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (product, false) => Some(product),
+            (_, true) => None,
+        }
+    }
+
+    /// Returns `self / rhs`, or `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        match rhs.is_zero() {
+            true => None,
+            false => Some(self.div_rem(rhs).0),
+        }
+    }
+
+    /// Returns `self % rhs`, or `None` if `rhs` is zero.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        match rhs.is_zero() {
+            true => None,
+            false => Some(self.div_rem(rhs).1),
+        }
+    }
+
+    /// Returns `-self`, or `None` unless `self` is zero.
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.is_zero() {
+            true => Some(self),
+            false => None,
+        }
+    }
+
+    /// Returns `self << rhs`, or `None` if `rhs` is at least the number of bits.
+    pub const fn checked_shl(self, rhs: u32) -> Option<Self> {
+        match rhs < Self::BITS {
+            true => Some(self.wrapping_shl(rhs)),
+            false => None,
+        }
+    }
+
+    /// Returns `self >> rhs`, or `None` if `rhs` is at least the number of bits.
+    pub const fn checked_shr(self, rhs: u32) -> Option<Self> {
+        match rhs < Self::BITS {
+            true => Some(self.wrapping_shr(rhs)),
+            false => None,
+        }
+    }
+
+    /// Returns `self ^ exp`, or `None` if an overflow occurred.
+    pub const fn checked_pow(self, mut exp: u32) -> Option<Self> {
+        let mut base = self;
+        let mut result = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = match result.checked_mul(base) {
+                    Some(result) => result,
+                    None => return None,
+                };
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = match base.checked_mul(base) {
+                    Some(base) => base,
+                    None => return None,
+                };
+            }
+        }
+        Some(result)
+    }
+
+    /// Returns `self + rhs`, wrapping around at the boundary of the type.
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Returns `self - rhs`, wrapping around at the boundary of the type.
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Returns `self * rhs`, wrapping around at the boundary of the type.
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+
+    /// Returns `self / rhs`. Unsigned division never wraps.
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        self.div_rem(rhs).0
+    }
+
+    /// Returns `self % rhs`. Unsigned remainder never wraps.
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        self.div_rem(rhs).1
+    }
+
+    /// Returns `-self`, wrapping around at the boundary of the type.
+    pub const fn wrapping_neg(self) -> Self {
+        Self::ZERO.wrapping_sub(self)
+    }
+
+    /// Returns `self ^ exp`, wrapping around at the boundary of the type.
+    pub const fn wrapping_pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut result = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.wrapping_mul(base);
+            }
+            exp >>= 1;
+            base = base.wrapping_mul(base);
+        }
+        result
+    }
+
+    /// Returns `self << (rhs % 256)`.
+    pub const fn wrapping_shl(self, rhs: u32) -> Self {
+        let shift = rhs % Self::BITS;
+        match shift {
+            0 => self,
+            1..=127 => Self { lo: self.lo << shift, hi: (self.hi << shift) | (self.lo >> (128 - shift)) },
+            _ => Self { lo: 0, hi: self.lo << (shift - 128) },
+        }
+    }
+
+    /// Returns `self >> (rhs % 256)`, filling the upper bits with zeros.
+    pub const fn wrapping_shr(self, rhs: u32) -> Self {
+        let shift = rhs % Self::BITS;
+        match shift {
+            0 => self,
+            1..=127 => Self { lo: (self.lo >> shift) | (self.hi << (128 - shift)), hi: self.hi >> shift },
+            _ => Self { lo: self.hi >> (shift - 128), hi: 0 },
+        }
+    }
+
+    /// Returns `self >> (rhs % 256)`, filling the upper bits with the most significant bit.
+    const fn arithmetic_shr(self, rhs: u32) -> Self {
+        self.as_i256().wrapping_shr(rhs).as_u256()
+    }
+
+    /// Returns `self >> (rhs % 256)`, filling the upper bits with zeros.
+    const fn logical_shr(self, rhs: u32) -> Self {
+        self.wrapping_shr(rhs)
+    }
+
+    /// Returns `self + rhs`, saturating at the numeric bounds.
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(sum) => sum,
+            None => Self::MAX,
+        }
+    }
+
+    /// Returns `self - rhs`, saturating at the numeric bounds.
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(difference) => difference,
+            None => Self::MIN,
+        }
+    }
+
+    /// Returns the quotient and remainder of `self / rhs`.
+    ///
+    /// # Panics
+    /// This method panics if `rhs` is zero.
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+        // If both operands fit in 128 bits, use the native division.
+        if self.hi == 0 && rhs.hi == 0 {
+            return (Self::from(self.lo / rhs.lo), Self::from(self.lo % rhs.lo));
+        }
+        // If the divisor is larger than the dividend, the quotient is zero.
+        if self < rhs {
+            return (Self::ZERO, self);
+        }
+        // Otherwise, perform binary long division, starting from the most significant aligned bit.
+        let shift = rhs.leading_zeros() - self.leading_zeros();
+        let mut divisor = rhs.wrapping_shl(shift);
+        let mut quotient = Self::ZERO;
+        let mut remainder = self;
+        for i in (0..=shift).rev() {
+            if remainder >= divisor {
+                remainder = remainder.wrapping_sub(divisor);
+                quotient = quotient | Self::ONE.wrapping_shl(i);
+            }
+            divisor = divisor.wrapping_shr(1);
+        }
+        (quotient, remainder)
+    }
+
+    /// Returns the quotient and remainder of `self / rhs`, for a 64-bit divisor.
+    fn div_rem_u64(self, rhs: u64) -> (Self, u64) {
+        let rhs = rhs as u128;
+        // Divide each 64-bit limb, from the most significant to the least significant.
+        let limbs = [self.hi >> 64, self.hi & LOWER_64, self.lo >> 64, self.lo & LOWER_64];
+        let mut quotient = [0u128; 4];
+        let mut remainder = 0u128;
+        for (limb, quotient) in limbs.iter().zip(quotient.iter_mut()) {
+            let current = (remainder << 64) | limb;
+            *quotient = current / rhs;
+            remainder = current % rhs;
+        }
+        let quotient = Self { lo: (quotient[2] << 64) | quotient[3], hi: (quotient[0] << 64) | quotient[1] };
+        (quotient, remainder as u64)
+    }
+
+    /// Returns the memory representation of `self` as a byte array in little-endian order.
+    pub fn to_le_bytes(self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes[..16].copy_from_slice(&self.lo.to_le_bytes());
+        bytes[16..].copy_from_slice(&self.hi.to_le_bytes());
+        bytes
+    }
+
+    /// Returns the memory representation of `self` as a byte array in big-endian order.
+    pub fn to_be_bytes(self) -> [u8; 32] {
+        let mut bytes = self.to_le_bytes();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Initializes an integer from its memory representation as a byte array in little-endian order.
+    pub fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        let mut lo = [0u8; 16];
+        let mut hi = [0u8; 16];
+        lo.copy_from_slice(&bytes[..16]);
+        hi.copy_from_slice(&bytes[16..]);
+        Self { lo: u128::from_le_bytes(lo), hi: u128::from_le_bytes(hi) }
+    }
+
+    /// Initializes an integer from its memory representation as a byte array in big-endian order.
+    pub fn from_be_bytes(mut bytes: [u8; 32]) -> Self {
+        bytes.reverse();
+        Self::from_le_bytes(bytes)
+    }
+
+    /// Parses an integer from a string in the given radix, with an optional leading `+`.
+    pub fn from_str_radix(string: &str, radix: u32) -> Result<Self, ParseIntError> {
+        assert!((2..=36).contains(&radix), "from_str_radix: radix must lie in the range `[2, 36]`");
+        // Strip the optional sign.
+        let digits = match string.strip_prefix('+') {
+            Some(digits) if !digits.is_empty() => digits,
+            _ if string.is_empty() => return Err(empty_error()),
+            Some(_) => return Err(invalid_digit_error()),
+            None => string,
+        };
+        // Accumulate the digits, from the most significant to the least significant.
+        let radix_u256 = Self::from(radix);
+        digits.chars().try_fold(Self::ZERO, |value, character| {
+            let digit = character.to_digit(radix).ok_or_else(invalid_digit_error)?;
+            value
+                .checked_mul(radix_u256)
+                .and_then(|value| value.checked_add(Self::from(digit)))
+                .ok_or_else(positive_overflow_error)
+        })
+    }
+
+    /// Reinterprets the bits of `self` as a signed integer.
+    pub const fn as_i256(self) -> i256 {
+        i256::from_bits(self)
+    }
+}
+
+/// The mask for the lower 64 bits of a `u128`.
+const LOWER_64: u128 = u64::MAX as u128;
+
+/// Returns the full 256-bit product of two 128-bit integers, as `(lo, hi)`.
+const fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    let (a_0, a_1) = (a & LOWER_64, a >> 64);
+    let (b_0, b_1) = (b & LOWER_64, b >> 64);
+    // Compute the 64-bit partial products, which each fit in 128 bits.
+    let p_00 = a_0 * b_0;
+    let p_01 = a_0 * b_1;
+    let p_10 = a_1 * b_0;
+    let p_11 = a_1 * b_1;
+    // Sum the middle terms, which fits in 128 bits as each term is less than 2^64.
+    let middle = (p_00 >> 64) + (p_01 & LOWER_64) + (p_10 & LOWER_64);
+    let lo = (p_00 & LOWER_64) | (middle << 64);
+    let hi = p_11 + (p_01 >> 64) + (p_10 >> 64) + (middle >> 64);
+    (lo, hi)
+}
+
+impl Ord for u256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.hi, self.lo).cmp(&(other.hi, other.lo))
+    }
+}
+
+impl PartialOrd for u256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<u8> for u256 {
+    fn from(value: u8) -> Self {
+        Self::from(value as u128)
+    }
+}
+
+impl From<u16> for u256 {
+    fn from(value: u16) -> Self {
+        Self::from(value as u128)
+    }
+}
+
+impl From<u32> for u256 {
+    fn from(value: u32) -> Self {
+        Self::from(value as u128)
+    }
+}
+
+impl From<u64> for u256 {
+    fn from(value: u64) -> Self {
+        Self::from(value as u128)
+    }
+}
+
+impl TryFrom<i8> for u256 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        Self::try_from(value as i128)
+    }
+}
+
+impl TryFrom<i16> for u256 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        Self::try_from(value as i128)
+    }
+}
+
+impl TryFrom<i32> for u256 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        Self::try_from(value as i128)
+    }
+}
+
+impl TryFrom<i64> for u256 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        Self::try_from(value as i128)
+    }
+}
+
+impl TryFrom<i128> for u256 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: i128) -> Result<Self, Self::Error> {
+        match u128::try_from(value) {
+            Ok(value) => Ok(Self::from(value)),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl TryFrom<i256> for u256 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: i256) -> Result<Self, Self::Error> {
+        match value.is_negative() {
+            true => Err(try_from_int_error()),
+            false => Ok(value.as_u256()),
+        }
+    }
+}
+
+impl AsPrimitive<u256> for u256 {
+    fn as_(self) -> u256 {
+        self
+    }
+}
+
+impl AsPrimitive<i256> for u256 {
+    fn as_(self) -> i256 {
+        self.as_i256()
+    }
+}
+
+impl From<u128> for u256 {
+    fn from(value: u128) -> Self {
+        Self { lo: value, hi: 0 }
+    }
+}
+
+impl Not for u256 {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self { lo: !self.lo, hi: !self.hi }
+    }
+}
+
+impl BitAnd for u256 {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self { lo: self.lo & rhs.lo, hi: self.hi & rhs.hi }
+    }
+}
+
+impl BitOr for u256 {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self { lo: self.lo | rhs.lo, hi: self.hi | rhs.hi }
+    }
+}
+
+impl BitXor for u256 {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self { lo: self.lo ^ rhs.lo, hi: self.hi ^ rhs.hi }
+    }
+}
+
+impl Display for u256 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Divide the integer into chunks of 19 decimal digits, the largest power of ten in a `u64`.
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut chunks = Vec::new();
+        let mut value = *self;
+        while value.hi != 0 || value.lo >= CHUNK as u128 {
+            let (quotient, remainder) = value.div_rem_u64(CHUNK);
+            chunks.push(remainder);
+            value = quotient;
+        }
+        // Write the most significant chunk, followed by the zero-padded remaining chunks.
+        let mut string = value.lo.to_string();
+        for chunk in chunks.iter().rev() {
+            string.push_str(&format!("{chunk:019}"));
+        }
+        f.pad_integral(true, "", &string)
+    }
+}
+
+impl Binary for u256 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let string = match self.hi {
+            0 => format!("{:b}", self.lo),
+            hi => format!("{hi:b}{:0128b}", self.lo),
+        };
+        f.pad_integral(true, "0b", &string)
+    }
+}
+
+impl Debug for u256 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl FromStr for u256 {
+    type Err = ParseIntError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(string, 10)
+    }
+}
+
+impl ToBits for u256 {
+    /// Returns `self` as a boolean array in little-endian order.
+    fn write_bits_le(&self, vec: &mut Vec<bool>) {
+        self.lo.write_bits_le(vec);
+        self.hi.write_bits_le(vec);
+    }
+
+    /// Returns `self` as a boolean array in big-endian order.
+    fn write_bits_be(&self, vec: &mut Vec<bool>) {
+        self.hi.write_bits_be(vec);
+        self.lo.write_bits_be(vec);
+    }
+
+    fn num_bits() -> Option<usize> {
+        Some(Self::BITS as usize)
+    }
+}
+
+impl FromBits for u256 {
+    /// Reads `Self` from a boolean array in little-endian order.
+    fn from_bits_le(bits: &[bool]) -> anyhow::Result<Self> {
+        // Note: `u128::from_bits_le` ensures that any bits beyond the upper half are zero.
+        let (lo, hi) = bits.split_at(bits.len().min(128));
+        Ok(Self { lo: u128::from_bits_le(lo)?, hi: u128::from_bits_le(hi)? })
+    }
+
+    /// Reads `Self` from a boolean array in big-endian order.
+    fn from_bits_be(bits: &[bool]) -> anyhow::Result<Self> {
+        Self::from_bits_le(&bits.iter().rev().copied().collect::<Vec<_>>())
+    }
+}
+
+impl ToBytes for u256 {
+    /// Writes `self` into `writer` as little-endian bytes.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.lo.write_le(&mut writer)?;
+        self.hi.write_le(&mut writer)
+    }
+}
+
+impl FromBytes for u256 {
+    /// Reads `Self` from `reader` as little-endian bytes.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let lo = u128::read_le(&mut reader)?;
+        let hi = u128::read_le(&mut reader)?;
+        Ok(Self { lo, hi })
+    }
+}
+
+impl Distribution<u256> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u256 {
+        u256 { lo: rng.gen(), hi: rng.gen() }
+    }
+}
+
+impl ToPrimitive for u256 {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_u128().and_then(|value| value.to_i64())
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|value| value.to_u64())
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.to_u128().and_then(|value| value.to_i128())
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        match self.hi {
+            0 => Some(self.lo),
+            _ => None,
+        }
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.hi as f64 * 2f64.powi(128) + self.lo as f64)
+    }
+}
+
+impl num_traits::NumCast for u256 {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        n.to_u128().map(From::from)
+    }
+}
+
+impl_integer_traits!(u256);
+impl_primitive_conversions!(u256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: usize = 1000;
+
+    /// Samples a random integer, which is randomly shrunk to exercise the carry and small-value paths.
+    fn sample(rng: &mut TestRng) -> u256 {
+        let (lo, hi): (u128, u128) = (rng.gen(), rng.gen());
+        match rng.gen_range(0..4) {
+            0 => u256::from(lo),
+            1 => u256::from(lo >> 64),
+            _ => u256::from_halves(lo, hi),
+        }
+    }
+
+    #[test]
+    fn test_display_and_parse() {
+        assert_eq!(
+            u256::MAX.to_string(),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(u256::ZERO.to_string(), "0");
+        assert_eq!(u256::from(u128::MAX).to_string(), u128::MAX.to_string());
+
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            let value = sample(&mut rng);
+            assert_eq!(value, u256::from_str(&value.to_string()).unwrap());
+        }
+
+        assert!(u256::from_str("").is_err());
+        assert!(u256::from_str("-1").is_err());
+        assert!(
+            u256::from_str("115792089237316195423570985008687907853269984665640564039457584007913129639936").is_err()
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            let a = sample(&mut rng);
+            let b = sample(&mut rng);
+
+            // Check the additive and multiplicative identities.
+            assert_eq!(a.wrapping_add(b).wrapping_sub(b), a);
+            if let Some(product) = a.checked_mul(b) {
+                if !b.is_zero() {
+                    assert_eq!(product.div_rem(b), (a, u256::ZERO));
+                }
+            }
+            if !b.is_zero() {
+                let (quotient, remainder) = a.div_rem(b);
+                assert!(remainder < b);
+                assert_eq!(quotient.checked_mul(b).and_then(|value| value.checked_add(remainder)), Some(a));
+            }
+            // Check the shifts.
+            let shift = rng.gen_range(0..256);
+            assert_eq!(a.wrapping_shl(shift).wrapping_shr(shift), a & (u256::MAX.wrapping_shr(shift)));
+        }
+
+        // Check the overflow cases.
+        assert_eq!(u256::MAX.checked_add(u256::ONE), None);
+        assert_eq!(u256::ZERO.checked_sub(u256::ONE), None);
+        assert_eq!(u256::from_halves(0, 1).checked_mul(u256::from_halves(0, 1)), None);
+        assert_eq!(u256::from(u128::MAX).checked_mul(u256::from(u128::MAX)), Some(u256::from_halves(1, u128::MAX - 1)));
+        assert_eq!(u256::from(2u8).checked_pow(255), Some(u256::from_halves(0, 1 << 127)));
+        assert_eq!(u256::from(2u8).checked_pow(256), None);
+    }
+
+    #[test]
+    fn test_bits_and_bytes() {
+        let mut rng = TestRng::default();
+        for _ in 0..ITERATIONS {
+            let value = sample(&mut rng);
+            assert_eq!(value, u256::from_bits_le(&value.to_bits_le()).unwrap());
+            assert_eq!(value, u256::from_bits_be(&value.to_bits_be()).unwrap());
+            assert_eq!(value, u256::from_bytes_le(&value.to_bytes_le().unwrap()).unwrap());
+            assert_eq!(value, u256::from_le_bytes(value.to_le_bytes()));
+        }
+    }
+}
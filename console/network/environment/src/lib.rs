@@ -36,6 +36,7 @@ pub mod prelude {
             from_field::*,
             parse::*,
             parse_string::*,
+            string::*,
             to_bits_le,
             to_field::*,
             type_name::*,
@@ -35,6 +35,9 @@ pub use parse::*;
 pub mod parse_string;
 pub use parse_string::string_parser;
 
+pub mod string;
+pub use string::*;
+
 pub mod to_field;
 pub use to_field::*;
 
@@ -0,0 +1,37 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Binary operator for concatenating two values.
+pub trait Concat<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the concatenation of `self` and `other`.
+    fn concat(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Unary operator for retrieving the length of a value.
+pub trait Length {
+    type Output;
+
+    /// Returns the length of `self`.
+    fn length(&self) -> Self::Output;
+}
+
+/// Ternary operator for retrieving the sub-value of `self` from `start` (inclusive) to `end` (exclusive).
+pub trait Slice<Index: ?Sized> {
+    type Output;
+
+    /// Returns the sub-value of `self` from `start` (inclusive) to `end` (exclusive).
+    fn slice(&self, start: &Index, end: &Index) -> Self::Output;
+}
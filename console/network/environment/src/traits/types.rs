@@ -303,6 +303,7 @@ pub trait IntegerCore<I: integer_type::IntegerType>:
 }
 
 pub mod integer_type {
+    use crate::{i256, u256};
     use snarkvm_utilities::{FromBits, FromBytes, ToBits, ToBytes, Uniform};
 
     use core::{
@@ -373,12 +374,14 @@ pub mod integer_type {
     impl IntegerType for i32 {}
     impl IntegerType for i64 {}
     impl IntegerType for i128 {}
+    impl IntegerType for i256 {}
 
     impl IntegerType for u8 {}
     impl IntegerType for u16 {}
     impl IntegerType for u32 {}
     impl IntegerType for u64 {}
     impl IntegerType for u128 {}
+    impl IntegerType for u256 {}
 
     macro_rules! binary_impl {
         ($trait_name:ident, $t:ty, $method:ident, $arg1: ident, $argname:ident, $arg2: ident, $rt:ty, $body:expr) => {
@@ -400,11 +403,13 @@ pub mod integer_type {
     binary_impl!(CheckedPow, u32, checked_pow, self, v, u32, Option<u32>, u32::checked_pow(*self, *v));
     binary_impl!(CheckedPow, u64, checked_pow, self, v, u32, Option<u64>, u64::checked_pow(*self, *v));
     binary_impl!(CheckedPow, u128, checked_pow, self, v, u32, Option<u128>, u128::checked_pow(*self, *v));
+    binary_impl!(CheckedPow, u256, checked_pow, self, v, u32, Option<u256>, u256::checked_pow(*self, *v));
     binary_impl!(CheckedPow, i8, checked_pow, self, v, u32, Option<i8>, i8::checked_pow(*self, *v));
     binary_impl!(CheckedPow, i16, checked_pow, self, v, u32, Option<i16>, i16::checked_pow(*self, *v));
     binary_impl!(CheckedPow, i32, checked_pow, self, v, u32, Option<i32>, i32::checked_pow(*self, *v));
     binary_impl!(CheckedPow, i64, checked_pow, self, v, u32, Option<i64>, i64::checked_pow(*self, *v));
     binary_impl!(CheckedPow, i128, checked_pow, self, v, u32, Option<i128>, i128::checked_pow(*self, *v));
+    binary_impl!(CheckedPow, i256, checked_pow, self, v, u32, Option<i256>, i256::checked_pow(*self, *v));
 
     pub trait CheckedShl: Sized {
         fn checked_shl(&self, v: &u32) -> Option<Self>;
@@ -421,6 +426,8 @@ pub mod integer_type {
     #[rustfmt::skip]
     binary_impl!(CheckedShl, u128, checked_shl, self, v, u32, Option<u128>, u128::checked_pow(2u128, *v).and_then(|x| u128::checked_mul(*self, x)));
     #[rustfmt::skip]
+    binary_impl!(CheckedShl, u256, checked_shl, self, v, u32, Option<u256>, u256::checked_pow(u256::from(2u8), *v).and_then(|x| u256::checked_mul(*self, x)));
+    #[rustfmt::skip]
     binary_impl!(CheckedShl, i8, checked_shl, self, v, u32, Option<i8>, u8::checked_pow(2u8, *v).and_then(|x| i8::checked_mul(if (x as i8) == i8::MIN { self.wrapping_neg() } else { *self }, x as i8)));
     #[rustfmt::skip]
     binary_impl!(CheckedShl, i16, checked_shl, self, v, u32, Option<i16>, u16::checked_pow(2u16, *v).and_then(|x| i16::checked_mul(if (x as i16) == i16::MIN { self.wrapping_neg() } else { *self }, x as i16)));
@@ -430,6 +437,8 @@ pub mod integer_type {
     binary_impl!(CheckedShl, i64, checked_shl, self, v, u32, Option<i64>, u64::checked_pow(2u64, *v).and_then(|x| i64::checked_mul(if (x as i64) == i64::MIN { self.wrapping_neg() } else { *self }, x as i64)));
     #[rustfmt::skip]
     binary_impl!(CheckedShl, i128, checked_shl, self, v, u32, Option<i128>, u128::checked_pow(2u128, *v).and_then(|x| i128::checked_mul(if (x as i128) == i128::MIN { self.wrapping_neg() } else { *self }, x as i128)));
+    #[rustfmt::skip]
+    binary_impl!(CheckedShl, i256, checked_shl, self, v, u32, Option<i256>, u256::checked_pow(u256::from(2u8), *v).and_then(|x| i256::checked_mul(if x.as_i256() == i256::MIN { self.wrapping_neg() } else { *self }, x.as_i256())));
 
     pub trait Modulo: Sized + Rem<Self, Output = Self> {
         fn modulo(&self, v: &Self) -> Self;
@@ -440,6 +449,7 @@ pub mod integer_type {
     binary_impl!(Modulo, u32, modulo, self, v, Self, u32, u32::wrapping_rem(*self, *v));
     binary_impl!(Modulo, u64, modulo, self, v, Self, u64, u64::wrapping_rem(*self, *v));
     binary_impl!(Modulo, u128, modulo, self, v, Self, u128, u128::wrapping_rem(*self, *v));
+    binary_impl!(Modulo, u256, modulo, self, v, Self, u256, u256::wrapping_rem(*self, *v));
     #[rustfmt::skip]
     binary_impl!(Modulo, i8, modulo, self, _v, Self, i8, panic!("modulo is not implemented for i8"));
     #[rustfmt::skip]
@@ -450,6 +460,8 @@ pub mod integer_type {
     binary_impl!(Modulo, i64, modulo, self, _v, Self, i64, panic!("modulo is not implemented for i64"));
     #[rustfmt::skip]
     binary_impl!(Modulo, i128, modulo, self, _v, Self, i128, panic!("modulo is not implemented for i128"));
+    #[rustfmt::skip]
+    binary_impl!(Modulo, i256, modulo, self, _v, Self, i256, panic!("modulo is not implemented for i256"));
 
     pub trait WrappingDiv: Sized + Div<Self, Output = Self> {
         fn wrapping_div(&self, v: &Self) -> Self;
@@ -460,11 +472,13 @@ pub mod integer_type {
     binary_impl!(WrappingDiv, u32, wrapping_div, self, v, Self, u32, u32::wrapping_div(*self, *v));
     binary_impl!(WrappingDiv, u64, wrapping_div, self, v, Self, u64, u64::wrapping_div(*self, *v));
     binary_impl!(WrappingDiv, u128, wrapping_div, self, v, Self, u128, u128::wrapping_div(*self, *v));
+    binary_impl!(WrappingDiv, u256, wrapping_div, self, v, Self, u256, u256::wrapping_div(*self, *v));
     binary_impl!(WrappingDiv, i8, wrapping_div, self, v, Self, i8, i8::wrapping_div(*self, *v));
     binary_impl!(WrappingDiv, i16, wrapping_div, self, v, Self, i16, i16::wrapping_div(*self, *v));
     binary_impl!(WrappingDiv, i32, wrapping_div, self, v, Self, i32, i32::wrapping_div(*self, *v));
     binary_impl!(WrappingDiv, i64, wrapping_div, self, v, Self, i64, i64::wrapping_div(*self, *v));
     binary_impl!(WrappingDiv, i128, wrapping_div, self, v, Self, i128, i128::wrapping_div(*self, *v));
+    binary_impl!(WrappingDiv, i256, wrapping_div, self, v, Self, i256, i256::wrapping_div(*self, *v));
 
     pub trait WrappingRem: Sized + Rem<Self, Output = Self> {
         fn wrapping_rem(&self, v: &Self) -> Self;
@@ -475,11 +489,13 @@ pub mod integer_type {
     binary_impl!(WrappingRem, u32, wrapping_rem, self, v, Self, u32, u32::wrapping_rem(*self, *v));
     binary_impl!(WrappingRem, u64, wrapping_rem, self, v, Self, u64, u64::wrapping_rem(*self, *v));
     binary_impl!(WrappingRem, u128, wrapping_rem, self, v, Self, u128, u128::wrapping_rem(*self, *v));
+    binary_impl!(WrappingRem, u256, wrapping_rem, self, v, Self, u256, u256::wrapping_rem(*self, *v));
     binary_impl!(WrappingRem, i8, wrapping_rem, self, v, Self, i8, i8::wrapping_rem(*self, *v));
     binary_impl!(WrappingRem, i16, wrapping_rem, self, v, Self, i16, i16::wrapping_rem(*self, *v));
     binary_impl!(WrappingRem, i32, wrapping_rem, self, v, Self, i32, i32::wrapping_rem(*self, *v));
     binary_impl!(WrappingRem, i64, wrapping_rem, self, v, Self, i64, i64::wrapping_rem(*self, *v));
     binary_impl!(WrappingRem, i128, wrapping_rem, self, v, Self, i128, i128::wrapping_rem(*self, *v));
+    binary_impl!(WrappingRem, i256, wrapping_rem, self, v, Self, i256, i256::wrapping_rem(*self, *v));
 
     pub trait WrappingPow: Sized {
         fn wrapping_pow(&self, v: &u32) -> Self;
@@ -490,11 +506,13 @@ pub mod integer_type {
     binary_impl!(WrappingPow, u32, wrapping_pow, self, v, u32, u32, u32::wrapping_pow(*self, *v));
     binary_impl!(WrappingPow, u64, wrapping_pow, self, v, u32, u64, u64::wrapping_pow(*self, *v));
     binary_impl!(WrappingPow, u128, wrapping_pow, self, v, u32, u128, u128::wrapping_pow(*self, *v));
+    binary_impl!(WrappingPow, u256, wrapping_pow, self, v, u32, u256, u256::wrapping_pow(*self, *v));
     binary_impl!(WrappingPow, i8, wrapping_pow, self, v, u32, i8, i8::wrapping_pow(*self, *v));
     binary_impl!(WrappingPow, i16, wrapping_pow, self, v, u32, i16, i16::wrapping_pow(*self, *v));
     binary_impl!(WrappingPow, i32, wrapping_pow, self, v, u32, i32, i32::wrapping_pow(*self, *v));
     binary_impl!(WrappingPow, i64, wrapping_pow, self, v, u32, i64, i64::wrapping_pow(*self, *v));
     binary_impl!(WrappingPow, i128, wrapping_pow, self, v, u32, i128, i128::wrapping_pow(*self, *v));
+    binary_impl!(WrappingPow, i256, wrapping_pow, self, v, u32, i256, i256::wrapping_pow(*self, *v));
 
     macro_rules! unary_impl {
         ($trait_name:ident, $t:ty, $method:ident, $arg: ident, $rt:ty, $body:expr) => {
@@ -516,11 +534,13 @@ pub mod integer_type {
     unary_impl!(CheckedAbs, u32, checked_abs, self, Option<u32>, Some(*self));
     unary_impl!(CheckedAbs, u64, checked_abs, self, Option<u64>, Some(*self));
     unary_impl!(CheckedAbs, u128, checked_abs, self, Option<u128>, Some(*self));
+    unary_impl!(CheckedAbs, u256, checked_abs, self, Option<u256>, Some(*self));
     unary_impl!(CheckedAbs, i8, checked_abs, self, Option<i8>, i8::checked_abs(*self));
     unary_impl!(CheckedAbs, i16, checked_abs, self, Option<i16>, i16::checked_abs(*self));
     unary_impl!(CheckedAbs, i32, checked_abs, self, Option<i32>, i32::checked_abs(*self));
     unary_impl!(CheckedAbs, i64, checked_abs, self, Option<i64>, i64::checked_abs(*self));
     unary_impl!(CheckedAbs, i128, checked_abs, self, Option<i128>, i128::checked_abs(*self));
+    unary_impl!(CheckedAbs, i256, checked_abs, self, Option<i256>, i256::checked_abs(*self));
 
     pub trait WrappingAbs: Sized {
         fn wrapping_abs(&self) -> Self;
@@ -531,11 +551,13 @@ pub mod integer_type {
     unary_impl!(WrappingAbs, u32, wrapping_abs, self, u32, *self);
     unary_impl!(WrappingAbs, u64, wrapping_abs, self, u64, *self);
     unary_impl!(WrappingAbs, u128, wrapping_abs, self, u128, *self);
+    unary_impl!(WrappingAbs, u256, wrapping_abs, self, u256, *self);
     unary_impl!(WrappingAbs, i8, wrapping_abs, self, i8, i8::wrapping_abs(*self));
     unary_impl!(WrappingAbs, i16, wrapping_abs, self, i16, i16::wrapping_abs(*self));
     unary_impl!(WrappingAbs, i32, wrapping_abs, self, i32, i32::wrapping_abs(*self));
     unary_impl!(WrappingAbs, i64, wrapping_abs, self, i64, i64::wrapping_abs(*self));
     unary_impl!(WrappingAbs, i128, wrapping_abs, self, i128, i128::wrapping_abs(*self));
+    unary_impl!(WrappingAbs, i256, wrapping_abs, self, i256, i256::wrapping_abs(*self));
 
     /// Properties common to all integer types.
     pub trait IntegerProperties: PrimInt + Debug + Display {
@@ -559,6 +581,9 @@ pub mod integer_type {
 
     macro_rules! integer_properties_impl {
         ($t:ty, $dual:ty, $is_signed:expr) => {
+            integer_properties_impl!($t, $dual, $is_signed, value => value as $dual);
+        };
+        ($t:ty, $dual:ty, $is_signed:expr, $value:ident => $into_dual:expr) => {
             impl IntegerProperties for $t {
                 type Dual = $dual;
 
@@ -573,12 +598,13 @@ pub mod integer_type {
 
                 #[inline]
                 fn type_name() -> &'static str {
-                    std::any::type_name::<$t>()
+                    stringify!($t)
                 }
 
                 #[inline]
                 fn into_dual(self) -> Self::Dual {
-                    self as $dual
+                    let $value = self;
+                    $into_dual
                 }
             }
         };
@@ -589,11 +615,13 @@ pub mod integer_type {
     integer_properties_impl!(u32, i32, false);
     integer_properties_impl!(u64, i64, false);
     integer_properties_impl!(u128, i128, false);
+    integer_properties_impl!(u256, i256, false, value => value.as_i256());
     integer_properties_impl!(i8, u8, true);
     integer_properties_impl!(i16, u16, true);
     integer_properties_impl!(i32, u32, true);
     integer_properties_impl!(i64, u64, true);
     integer_properties_impl!(i128, u128, true);
+    integer_properties_impl!(i256, u256, true, value => value.as_u256());
 }
 
 /// Trait pattern to prevent abuse of Magnitude.
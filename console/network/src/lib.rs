@@ -39,7 +39,7 @@ use snarkvm_algorithms::{
     srs::{UniversalProver, UniversalVerifier},
     AlgebraicSponge,
 };
-use snarkvm_console_algorithms::{Poseidon2, Poseidon4, BHP1024, BHP512};
+use snarkvm_console_algorithms::{Poseidon2, Poseidon2_2, Poseidon2_4, Poseidon4, BHP1024, BHP512};
 use snarkvm_console_collections::merkle_tree::{MerklePath, MerkleTree};
 use snarkvm_console_types::{Field, Group, Scalar};
 use snarkvm_curves::PairingEngine;
@@ -52,6 +52,8 @@ use std::sync::Arc;
 pub type BHPMerkleTree<N, const DEPTH: u8> = MerkleTree<N, BHP1024<N>, BHP512<N>, DEPTH>;
 /// A helper type for the Poseidon Merkle tree.
 pub type PoseidonMerkleTree<N, const DEPTH: u8> = MerkleTree<N, Poseidon4<N>, Poseidon2<N>, DEPTH>;
+/// A helper type for the Poseidon2 Merkle tree.
+pub type Poseidon2MerkleTree<N, const DEPTH: u8> = MerkleTree<N, Poseidon2_4<N>, Poseidon2_2<N>, DEPTH>;
 
 /// Helper types for the Varuna parameters.
 type Fq<N> = <<N as Environment>::PairingCurve as PairingEngine>::Fq;
@@ -265,6 +267,9 @@ pub trait Network:
     /// Returns the BHP hash with an input hasher of 1024-bits.
     fn hash_bhp1024(input: &[bool]) -> Result<Field<Self>>;
 
+    /// Returns the BLAKE2s hash with a 256-bit output.
+    fn hash_blake2s(input: &[bool]) -> Result<Vec<bool>>;
+
     /// Returns the Keccak hash with a 256-bit output.
     fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>>;
 
@@ -289,6 +294,18 @@ pub trait Network:
     /// Returns the Poseidon hash with an input rate of 8.
     fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>>;
 
+    /// Returns the Poseidon2 hash with an input rate of 2.
+    fn hash_poseidon2_2(input: &[Field<Self>]) -> Result<Field<Self>>;
+
+    /// Returns the Poseidon2 hash with an input rate of 4.
+    fn hash_poseidon2_4(input: &[Field<Self>]) -> Result<Field<Self>>;
+
+    /// Returns the Poseidon2 hash with an input rate of 8.
+    fn hash_poseidon2_8(input: &[Field<Self>]) -> Result<Field<Self>>;
+
+    /// Returns the SHA-2 hash with a 256-bit output.
+    fn hash_sha256(input: &[bool]) -> Result<Vec<bool>>;
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>>;
 
@@ -334,6 +351,15 @@ pub trait Network:
     /// Returns the Poseidon hash with an input rate of 8 on the affine curve.
     fn hash_to_group_psd8(input: &[Field<Self>]) -> Result<Group<Self>>;
 
+    /// Returns the Poseidon2 hash with an input rate of 2 on the affine curve.
+    fn hash_to_group_poseidon2_2(input: &[Field<Self>]) -> Result<Group<Self>>;
+
+    /// Returns the Poseidon2 hash with an input rate of 4 on the affine curve.
+    fn hash_to_group_poseidon2_4(input: &[Field<Self>]) -> Result<Group<Self>>;
+
+    /// Returns the Poseidon2 hash with an input rate of 8 on the affine curve.
+    fn hash_to_group_poseidon2_8(input: &[Field<Self>]) -> Result<Group<Self>>;
+
     /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
     fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>>;
 
@@ -349,6 +375,9 @@ pub trait Network:
     /// Returns a Merkle tree with a Poseidon leaf hasher with input rate of 4 and a Poseidon path hasher with input rate of 2.
     fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<PoseidonMerkleTree<Self, DEPTH>>;
 
+    /// Returns a Merkle tree with a Poseidon2 leaf hasher with input rate of 4 and a Poseidon2 path hasher with input rate of 2.
+    fn merkle_tree_poseidon2<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<Poseidon2MerkleTree<Self, DEPTH>>;
+
     /// Returns `true` if the given Merkle path is valid for the given root and leaf.
     #[allow(clippy::ptr_arg)]
     fn verify_merkle_path_bhp<const DEPTH: u8>(
@@ -364,4 +393,12 @@ pub trait Network:
         root: &Field<Self>,
         leaf: &Vec<Field<Self>>,
     ) -> bool;
+
+    /// Returns `true` if the given Poseidon2 Merkle path is valid for the given root and leaf.
+    #[allow(clippy::ptr_arg)]
+    fn verify_merkle_path_poseidon2<const DEPTH: u8>(
+        path: &MerklePath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaf: &Vec<Field<Self>>,
+    ) -> bool;
 }
@@ -15,14 +15,19 @@
 use super::*;
 use snarkvm_console_algorithms::{
     Blake2Xs,
+    Blake2s,
     Keccak256,
     Keccak384,
     Keccak512,
     Pedersen128,
     Pedersen64,
     Poseidon2,
+    Poseidon2_2,
+    Poseidon2_4,
+    Poseidon2_8,
     Poseidon4,
     Poseidon8,
+    Sha256,
     Sha3_256,
     Sha3_384,
     Sha3_512,
@@ -67,6 +72,13 @@ lazy_static! {
     /// The Poseidon hash function, using a rate of 8.
     pub static ref POSEIDON_8: Poseidon8<MainnetV0> = Poseidon8::<MainnetV0>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
 
+    /// The Poseidon2 hash function, using a rate of 2.
+    pub static ref POSEIDON2_2: Poseidon2_2<MainnetV0> = Poseidon2_2::<MainnetV0>::setup("AleoPoseidon2_2").expect("Failed to setup Poseidon2_2");
+    /// The Poseidon2 hash function, using a rate of 4.
+    pub static ref POSEIDON2_4: Poseidon2_4<MainnetV0> = Poseidon2_4::<MainnetV0>::setup("AleoPoseidon2_4").expect("Failed to setup Poseidon2_4");
+    /// The Poseidon2 hash function, using a rate of 8.
+    pub static ref POSEIDON2_8: Poseidon2_8<MainnetV0> = Poseidon2_8::<MainnetV0>::setup("AleoPoseidon2_8").expect("Failed to setup Poseidon2_8");
+
     pub static ref CREDITS_PROVING_KEYS: IndexMap<String, Arc<VarunaProvingKey<Console>>> = {
         let mut map = IndexMap::new();
         snarkvm_parameters::insert_credit_keys!(map, VarunaProvingKey<Console>, Prover);
@@ -322,6 +334,11 @@ impl Network for MainnetV0 {
         BHP_1024.hash(input)
     }
 
+    /// Returns the BLAKE2s hash with a 256-bit output.
+    fn hash_blake2s(input: &[bool]) -> Result<Vec<bool>> {
+        Blake2s.hash(input)
+    }
+
     /// Returns the Keccak hash with a 256-bit output.
     fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>> {
         Keccak256::default().hash(input)
@@ -362,6 +379,26 @@ impl Network for MainnetV0 {
         POSEIDON_8.hash(input)
     }
 
+    /// Returns the Poseidon2 hash with an input rate of 2.
+    fn hash_poseidon2_2(input: &[Field<Self>]) -> Result<Field<Self>> {
+        POSEIDON2_2.hash(input)
+    }
+
+    /// Returns the Poseidon2 hash with an input rate of 4.
+    fn hash_poseidon2_4(input: &[Field<Self>]) -> Result<Field<Self>> {
+        POSEIDON2_4.hash(input)
+    }
+
+    /// Returns the Poseidon2 hash with an input rate of 8.
+    fn hash_poseidon2_8(input: &[Field<Self>]) -> Result<Field<Self>> {
+        POSEIDON2_8.hash(input)
+    }
+
+    /// Returns the SHA-2 hash with a 256-bit output.
+    fn hash_sha256(input: &[bool]) -> Result<Vec<bool>> {
+        Sha256.hash(input)
+    }
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>> {
         Sha3_256::default().hash(input)
@@ -437,6 +474,21 @@ impl Network for MainnetV0 {
         POSEIDON_8.hash_to_group(input)
     }
 
+    /// Returns the Poseidon2 hash with an input rate of 2 on the affine curve.
+    fn hash_to_group_poseidon2_2(input: &[Field<Self>]) -> Result<Group<Self>> {
+        POSEIDON2_2.hash_to_group(input)
+    }
+
+    /// Returns the Poseidon2 hash with an input rate of 4 on the affine curve.
+    fn hash_to_group_poseidon2_4(input: &[Field<Self>]) -> Result<Group<Self>> {
+        POSEIDON2_4.hash_to_group(input)
+    }
+
+    /// Returns the Poseidon2 hash with an input rate of 8 on the affine curve.
+    fn hash_to_group_poseidon2_8(input: &[Field<Self>]) -> Result<Group<Self>> {
+        POSEIDON2_8.hash_to_group(input)
+    }
+
     /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
     fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>> {
         POSEIDON_2.hash_to_scalar(input)
@@ -462,6 +514,11 @@ impl Network for MainnetV0 {
         MerkleTree::new(&*POSEIDON_4, &*POSEIDON_2, leaves)
     }
 
+    /// Returns a Merkle tree with a Poseidon2 leaf hasher with input rate of 4 and a Poseidon2 path hasher with input rate of 2.
+    fn merkle_tree_poseidon2<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<Poseidon2MerkleTree<Self, DEPTH>> {
+        MerkleTree::new(&*POSEIDON2_4, &*POSEIDON2_2, leaves)
+    }
+
     /// Returns `true` if the given Merkle path is valid for the given root and leaf.
     fn verify_merkle_path_bhp<const DEPTH: u8>(
         path: &MerklePath<Self, DEPTH>,
@@ -479,6 +536,15 @@ impl Network for MainnetV0 {
     ) -> bool {
         path.verify(&*POSEIDON_4, &*POSEIDON_2, root, leaf)
     }
+
+    /// Returns `true` if the given Poseidon2 Merkle path is valid for the given root and leaf.
+    fn verify_merkle_path_poseidon2<const DEPTH: u8>(
+        path: &MerklePath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaf: &Vec<Field<Self>>,
+    ) -> bool {
+        path.verify(&*POSEIDON2_4, &*POSEIDON2_2, root, leaf)
+    }
 }
 
 #[cfg(test)]
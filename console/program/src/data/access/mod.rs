@@ -26,6 +26,8 @@ pub enum Access<N: Network> {
     Member(Identifier<N>),
     /// Access an element of an array.
     Index(U32<N>),
+    /// Access an element of an array, at the index stored in the register with the given locator.
+    Register(u64),
 }
 
 impl<N: Network> From<Identifier<N>> for Access<N> {
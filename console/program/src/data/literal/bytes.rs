@@ -36,7 +36,9 @@ impl<N: Network> FromBytes for Literal<N> {
             14 => Self::Scalar(Scalar::read_le(&mut reader)?),
             15 => Self::Signature(Box::new(Signature::read_le(&mut reader)?)),
             16 => Self::String(StringType::read_le(&mut reader)?),
-            17.. => return Err(error(format!("Failed to decode literal variant {index}"))),
+            17 => Self::I256(I256::read_le(&mut reader)?),
+            18 => Self::U256(U256::read_le(&mut reader)?),
+            19.. => return Err(error(format!("Failed to decode literal variant {index}"))),
         };
         Ok(literal)
     }
@@ -115,6 +117,14 @@ impl<N: Network> ToBytes for Literal<N> {
                 (16 as Size).write_le(&mut writer)?;
                 primitive.write_le(&mut writer)
             }
+            Self::I256(primitive) => {
+                (17 as Size).write_le(&mut writer)?;
+                primitive.write_le(&mut writer)
+            }
+            Self::U256(primitive) => {
+                (18 as Size).write_le(&mut writer)?;
+                primitive.write_le(&mut writer)
+            }
         }
     }
 }
@@ -160,6 +170,8 @@ mod tests {
             check_bytes(Literal::<CurrentNetwork>::I64(I64::new(Uniform::rand(rng))))?;
             // I128
             check_bytes(Literal::<CurrentNetwork>::I128(I128::new(Uniform::rand(rng))))?;
+            // I256
+            check_bytes(Literal::<CurrentNetwork>::I256(I256::new(Uniform::rand(rng))))?;
             // U8
             check_bytes(Literal::<CurrentNetwork>::U8(U8::new(Uniform::rand(rng))))?;
             // U16
@@ -170,6 +182,8 @@ mod tests {
             check_bytes(Literal::<CurrentNetwork>::U64(U64::new(Uniform::rand(rng))))?;
             // U128
             check_bytes(Literal::<CurrentNetwork>::U128(U128::new(Uniform::rand(rng))))?;
+            // U256
+            check_bytes(Literal::<CurrentNetwork>::U256(U256::new(Uniform::rand(rng))))?;
             // Scalar
             check_bytes(Literal::<CurrentNetwork>::Scalar(Uniform::rand(rng)))?;
             // Signature
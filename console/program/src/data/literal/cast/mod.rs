@@ -52,11 +52,13 @@ impl<N: Network> Literal<N> {
             Self::I32(integer) => cast_integer_to_type(integer, to_type),
             Self::I64(integer) => cast_integer_to_type(integer, to_type),
             Self::I128(integer) => cast_integer_to_type(integer, to_type),
+            Self::I256(integer) => cast_integer_to_type(integer, to_type),
             Self::U8(integer) => cast_integer_to_type(integer, to_type),
             Self::U16(integer) => cast_integer_to_type(integer, to_type),
             Self::U32(integer) => cast_integer_to_type(integer, to_type),
             Self::U64(integer) => cast_integer_to_type(integer, to_type),
             Self::U128(integer) => cast_integer_to_type(integer, to_type),
+            Self::U256(integer) => cast_integer_to_type(integer, to_type),
             Self::Scalar(scalar) => cast_scalar_to_type(scalar, to_type),
             Self::Signature(..) => bail!("Cannot cast a signature literal to another type."),
             Self::String(..) => bail!("Cannot cast a string literal to another type."),
@@ -77,11 +79,13 @@ macro_rules! impl_cast_body {
             LiteralType::I32 => Ok(Literal::I32($input.$cast()?)),
             LiteralType::I64 => Ok(Literal::I64($input.$cast()?)),
             LiteralType::I128 => Ok(Literal::I128($input.$cast()?)),
+            LiteralType::I256 => Ok(Literal::I256($input.$cast()?)),
             LiteralType::U8 => Ok(Literal::U8($input.$cast()?)),
             LiteralType::U16 => Ok(Literal::U16($input.$cast()?)),
             LiteralType::U32 => Ok(Literal::U32($input.$cast()?)),
             LiteralType::U64 => Ok(Literal::U64($input.$cast()?)),
             LiteralType::U128 => Ok(Literal::U128($input.$cast()?)),
+            LiteralType::U256 => Ok(Literal::U256($input.$cast()?)),
             LiteralType::Scalar => Ok(Literal::Scalar($input.$cast()?)),
             LiteralType::Signature => {
                 bail!(concat!("Cannot cast a ", stringify!($type_name), " literal to a signature type."))
@@ -123,11 +127,13 @@ where
     i32: TryFrom<I>,
     i64: TryFrom<I>,
     i128: TryFrom<I>,
+    i256: TryFrom<I>,
     u8: TryFrom<I>,
     u16: TryFrom<I>,
     u32: TryFrom<I>,
     u64: TryFrom<I>,
     u128: TryFrom<I>,
+    u256: TryFrom<I>,
 {
     impl_cast_body!(integer, cast, input, to_type)
 }
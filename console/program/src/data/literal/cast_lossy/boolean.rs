@@ -212,4 +212,14 @@ mod tests {
     fn test_boolean_to_u128() {
         check_boolean_to_integer!(U128<CurrentEnvironment>);
     }
+
+    #[test]
+    fn test_boolean_to_i256() {
+        check_boolean_to_integer!(I256<CurrentEnvironment>);
+    }
+
+    #[test]
+    fn test_boolean_to_u256() {
+        check_boolean_to_integer!(U256<CurrentEnvironment>);
+    }
 }
@@ -278,4 +278,14 @@ mod tests {
     fn test_field_to_u128() {
         check_field_to_integer!(U128<CurrentEnvironment>);
     }
+
+    #[test]
+    fn test_field_to_i256() {
+        check_field_to_integer!(I256<CurrentEnvironment>);
+    }
+
+    #[test]
+    fn test_field_to_u256() {
+        check_field_to_integer!(U256<CurrentEnvironment>);
+    }
 }
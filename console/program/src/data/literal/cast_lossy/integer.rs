@@ -39,11 +39,14 @@ impl<E: Environment, I: IntegerType> CastLossy<Boolean<E>> for Integer<E, I> {
 }
 
 impl<E: Environment, I: IntegerType> CastLossy<Field<E>> for Integer<E, I> {
-    /// Casts an `Integer` to a `Field`.
-    /// This is safe because casting from an integer to a field is **always** lossless.
+    /// Casts an `Integer` to a `Field`, with lossy truncation.
+    /// This operation is lossless, except for integers that are wider than the data capacity
+    /// of the base field (i.e. `u256`, `i256`), which are truncated to `Field::size_in_data_bits` bits.
     #[inline]
     fn cast_lossy(&self) -> Field<E> {
-        let result = self.to_field();
+        let mut bits_le = self.to_bits_le();
+        bits_le.truncate(Field::<E>::size_in_data_bits());
+        let result = Field::from_bits_le(&bits_le);
         debug_assert!(result.is_ok(), "Casting an integer to field cannot fail");
         result.unwrap()
     }
@@ -71,8 +74,9 @@ impl<E: Environment, I0: IntegerType + AsPrimitive<I1>, I1: IntegerType> CastLos
 }
 
 impl<E: Environment, I: IntegerType> CastLossy<Scalar<E>> for Integer<E, I> {
-    /// Casts an `Integer` to a `Scalar`.
-    /// This is safe because casting from an integer to a scalar is **always** lossless.
+    /// Casts an `Integer` to a `Scalar`, with lossy truncation.
+    /// This operation is lossless, except for integers that are wider than the data capacity
+    /// of the scalar field (i.e. `u256`, `i256`), which are truncated to `Scalar::size_in_data_bits` bits.
     #[inline]
     fn cast_lossy(&self) -> Scalar<E> {
         self.to_scalar()
@@ -120,11 +124,13 @@ mod tests {
         check_integer_to_address!(i32);
         check_integer_to_address!(i64);
         check_integer_to_address!(i128);
+        check_integer_to_address!(i256);
         check_integer_to_address!(u8);
         check_integer_to_address!(u16);
         check_integer_to_address!(u32);
         check_integer_to_address!(u64);
         check_integer_to_address!(u128);
+        check_integer_to_address!(u256);
     }
 
     #[test]
@@ -158,11 +164,13 @@ mod tests {
         check_integer_to_boolean!(i32);
         check_integer_to_boolean!(i64);
         check_integer_to_boolean!(i128);
+        check_integer_to_boolean!(i256);
         check_integer_to_boolean!(u8);
         check_integer_to_boolean!(u16);
         check_integer_to_boolean!(u32);
         check_integer_to_boolean!(u64);
         check_integer_to_boolean!(u128);
+        check_integer_to_boolean!(u256);
     }
 
     #[test]
@@ -176,8 +184,10 @@ mod tests {
                     let integer = Integer::<CurrentEnvironment, $type>::rand(rng);
                     // Perform the operation.
                     let candidate: Field<CurrentEnvironment> = integer.cast_lossy();
-                    // Compare the result against the field representation of the integer.
-                    let expected = integer.to_field().unwrap();
+                    // Compare the result against the field representation of the integer, truncated to the data bits.
+                    let data_bits = Field::<CurrentEnvironment>::size_in_data_bits();
+                    let expected =
+                        Field::from_bits_le(&integer.to_bits_le()[..data_bits.min(<$type>::BITS as usize)]).unwrap();
                     assert_eq!(expected, candidate);
                 }
             };
@@ -188,11 +198,13 @@ mod tests {
         check_integer_to_field!(i32);
         check_integer_to_field!(i64);
         check_integer_to_field!(i128);
+        check_integer_to_field!(i256);
         check_integer_to_field!(u8);
         check_integer_to_field!(u16);
         check_integer_to_field!(u32);
         check_integer_to_field!(u64);
         check_integer_to_field!(u128);
+        check_integer_to_field!(u256);
     }
 
     #[test]
@@ -215,7 +227,8 @@ mod tests {
                     // Perform the operation.
                     let candidate: Group<CurrentEnvironment> = integer.cast_lossy();
                     // Compare the result against the group representation of the integer.
-                    let expected: Group<CurrentEnvironment> = integer.to_field().unwrap().cast_lossy();
+                    let field: Field<CurrentEnvironment> = integer.cast_lossy();
+                    let expected: Group<CurrentEnvironment> = field.cast_lossy();
                     assert_eq!(expected, candidate);
                 }
             };
@@ -226,11 +239,13 @@ mod tests {
         check_integer_to_group!(i32);
         check_integer_to_group!(i64);
         check_integer_to_group!(i128);
+        check_integer_to_group!(i256);
         check_integer_to_group!(u8);
         check_integer_to_group!(u16);
         check_integer_to_group!(u32);
         check_integer_to_group!(u64);
         check_integer_to_group!(u128);
+        check_integer_to_group!(u256);
     }
 
     #[test]
@@ -256,11 +271,13 @@ mod tests {
         check_integer_to_scalar!(i32);
         check_integer_to_scalar!(i64);
         check_integer_to_scalar!(i128);
+        check_integer_to_scalar!(i256);
         check_integer_to_scalar!(u8);
         check_integer_to_scalar!(u16);
         check_integer_to_scalar!(u32);
         check_integer_to_scalar!(u64);
         check_integer_to_scalar!(u128);
+        check_integer_to_scalar!(u256);
     }
 
     #[test]
@@ -310,11 +327,13 @@ mod tests {
             check_integer_to_integer!(i8, i32);
             check_integer_to_integer!(i8, i64);
             check_integer_to_integer!(i8, i128);
+            check_integer_to_integer!(i8, i256);
             check_integer_to_integer!(i8, u8);
             check_integer_to_integer!(i8, u16);
             check_integer_to_integer!(i8, u32);
             check_integer_to_integer!(i8, u64);
             check_integer_to_integer!(i8, u128);
+            check_integer_to_integer!(i8, u256);
         }
         {
             check_integer_to_integer!(i16, i8);
@@ -322,11 +341,13 @@ mod tests {
             check_integer_to_integer!(i16, i32);
             check_integer_to_integer!(i16, i64);
             check_integer_to_integer!(i16, i128);
+            check_integer_to_integer!(i16, i256);
             check_integer_to_integer!(i16, u8);
             check_integer_to_integer!(i16, u16);
             check_integer_to_integer!(i16, u32);
             check_integer_to_integer!(i16, u64);
             check_integer_to_integer!(i16, u128);
+            check_integer_to_integer!(i16, u256);
         }
         {
             check_integer_to_integer!(i32, i8);
@@ -334,11 +355,13 @@ mod tests {
             check_integer_to_integer!(i32, i32);
             check_integer_to_integer!(i32, i64);
             check_integer_to_integer!(i32, i128);
+            check_integer_to_integer!(i32, i256);
             check_integer_to_integer!(i32, u8);
             check_integer_to_integer!(i32, u16);
             check_integer_to_integer!(i32, u32);
             check_integer_to_integer!(i32, u64);
             check_integer_to_integer!(i32, u128);
+            check_integer_to_integer!(i32, u256);
         }
         {
             check_integer_to_integer!(i64, i8);
@@ -346,11 +369,13 @@ mod tests {
             check_integer_to_integer!(i64, i32);
             check_integer_to_integer!(i64, i64);
             check_integer_to_integer!(i64, i128);
+            check_integer_to_integer!(i64, i256);
             check_integer_to_integer!(i64, u8);
             check_integer_to_integer!(i64, u16);
             check_integer_to_integer!(i64, u32);
             check_integer_to_integer!(i64, u64);
             check_integer_to_integer!(i64, u128);
+            check_integer_to_integer!(i64, u256);
         }
         {
             check_integer_to_integer!(i128, i8);
@@ -358,11 +383,13 @@ mod tests {
             check_integer_to_integer!(i128, i32);
             check_integer_to_integer!(i128, i64);
             check_integer_to_integer!(i128, i128);
+            check_integer_to_integer!(i128, i256);
             check_integer_to_integer!(i128, u8);
             check_integer_to_integer!(i128, u16);
             check_integer_to_integer!(i128, u32);
             check_integer_to_integer!(i128, u64);
             check_integer_to_integer!(i128, u128);
+            check_integer_to_integer!(i128, u256);
         }
         {
             check_integer_to_integer!(u8, i8);
@@ -370,11 +397,13 @@ mod tests {
             check_integer_to_integer!(u8, i32);
             check_integer_to_integer!(u8, i64);
             check_integer_to_integer!(u8, i128);
+            check_integer_to_integer!(u8, i256);
             check_integer_to_integer!(u8, u8);
             check_integer_to_integer!(u8, u16);
             check_integer_to_integer!(u8, u32);
             check_integer_to_integer!(u8, u64);
             check_integer_to_integer!(u8, u128);
+            check_integer_to_integer!(u8, u256);
         }
         {
             check_integer_to_integer!(u16, i8);
@@ -382,11 +411,13 @@ mod tests {
             check_integer_to_integer!(u16, i32);
             check_integer_to_integer!(u16, i64);
             check_integer_to_integer!(u16, i128);
+            check_integer_to_integer!(u16, i256);
             check_integer_to_integer!(u16, u8);
             check_integer_to_integer!(u16, u16);
             check_integer_to_integer!(u16, u32);
             check_integer_to_integer!(u16, u64);
             check_integer_to_integer!(u16, u128);
+            check_integer_to_integer!(u16, u256);
         }
         {
             check_integer_to_integer!(u32, i8);
@@ -394,11 +425,13 @@ mod tests {
             check_integer_to_integer!(u32, i32);
             check_integer_to_integer!(u32, i64);
             check_integer_to_integer!(u32, i128);
+            check_integer_to_integer!(u32, i256);
             check_integer_to_integer!(u32, u8);
             check_integer_to_integer!(u32, u16);
             check_integer_to_integer!(u32, u32);
             check_integer_to_integer!(u32, u64);
             check_integer_to_integer!(u32, u128);
+            check_integer_to_integer!(u32, u256);
         }
         {
             check_integer_to_integer!(u64, i8);
@@ -406,11 +439,41 @@ mod tests {
             check_integer_to_integer!(u64, i32);
             check_integer_to_integer!(u64, i64);
             check_integer_to_integer!(u64, i128);
+            check_integer_to_integer!(u64, i256);
             check_integer_to_integer!(u64, u8);
             check_integer_to_integer!(u64, u16);
             check_integer_to_integer!(u64, u32);
             check_integer_to_integer!(u64, u64);
             check_integer_to_integer!(u64, u128);
+            check_integer_to_integer!(u64, u256);
+        }
+        {
+            check_integer_to_integer!(i256, i8);
+            check_integer_to_integer!(i256, i16);
+            check_integer_to_integer!(i256, i32);
+            check_integer_to_integer!(i256, i64);
+            check_integer_to_integer!(i256, i128);
+            check_integer_to_integer!(i256, i256);
+            check_integer_to_integer!(i256, u8);
+            check_integer_to_integer!(i256, u16);
+            check_integer_to_integer!(i256, u32);
+            check_integer_to_integer!(i256, u64);
+            check_integer_to_integer!(i256, u128);
+            check_integer_to_integer!(i256, u256);
+        }
+        {
+            check_integer_to_integer!(u256, i8);
+            check_integer_to_integer!(u256, i16);
+            check_integer_to_integer!(u256, i32);
+            check_integer_to_integer!(u256, i64);
+            check_integer_to_integer!(u256, i128);
+            check_integer_to_integer!(u256, i256);
+            check_integer_to_integer!(u256, u8);
+            check_integer_to_integer!(u256, u16);
+            check_integer_to_integer!(u256, u32);
+            check_integer_to_integer!(u256, u64);
+            check_integer_to_integer!(u256, u128);
+            check_integer_to_integer!(u256, u256);
         }
     }
 }
@@ -66,14 +66,11 @@ impl<E: Environment, I: IntegerType> CastLossy<Integer<E, I>> for Scalar<E> {
     /// Casts a `Scalar` to an `Integer`, with lossy truncation.
     #[inline]
     fn cast_lossy(&self) -> Integer<E, I> {
-        // Note: We are reconstituting the integer from the scalar field.
-        // This is safe as the number of bits in the integer is less than the scalar field modulus,
-        // and thus will always fit within a single scalar field element.
-        debug_assert!(I::BITS < Scalar::<E>::size_in_bits() as u64);
-
-        // Truncate the field to the size of the integer domain.
-        // Slicing here is safe as the base field is larger than the integer domain.
-        let result = Integer::<E, I>::from_bits_le(&self.to_bits_le()[..usize::try_from(I::BITS).unwrap()]);
+        // Truncate the scalar to the size of the integer domain.
+        // If the integer is wider than the scalar field (i.e. `u256`, `i256`), the bits are zero-extended instead.
+        let mut bits_le = self.to_bits_le();
+        bits_le.truncate(usize::try_from(I::BITS).unwrap());
+        let result = Integer::<E, I>::from_bits_le(&bits_le);
         debug_assert!(result.is_ok(), "A lossy integer should always be able to be constructed from scalar bits");
         result.unwrap()
     }
@@ -211,10 +208,9 @@ mod tests {
                 // Perform the operation.
                 let candidate: Integer<CurrentEnvironment, $type> = scalar.cast_lossy();
                 // Compare the result against the least significant bits of the scalar.
-                let expected = Integer::<CurrentEnvironment, $type>::from_bits_le(
-                    &scalar.to_bits_le()[..usize::try_from(<$type>::BITS).unwrap()],
-                )
-                .unwrap();
+                let mut bits_le = scalar.to_bits_le();
+                bits_le.truncate(usize::try_from(<$type>::BITS).unwrap());
+                let expected = Integer::<CurrentEnvironment, $type>::from_bits_le(&bits_le).unwrap();
                 assert_eq!(expected, candidate);
             }
         };
@@ -269,4 +265,14 @@ mod tests {
     fn test_scalar_to_u128() {
         check_scalar_to_integer!(u128);
     }
+
+    #[test]
+    fn test_scalar_to_i256() {
+        check_scalar_to_integer!(i256);
+    }
+
+    #[test]
+    fn test_scalar_to_u256() {
+        check_scalar_to_integer!(u256);
+    }
 }
@@ -35,11 +35,13 @@ impl<N: Network> core::hash::Hash for Literal<N> {
             Self::I32(a) => a.hash(state),
             Self::I64(a) => a.hash(state),
             Self::I128(a) => a.hash(state),
+            Self::I256(a) => a.hash(state),
             Self::U8(a) => a.hash(state),
             Self::U16(a) => a.hash(state),
             Self::U32(a) => a.hash(state),
             Self::U64(a) => a.hash(state),
             Self::U128(a) => a.hash(state),
+            Self::U256(a) => a.hash(state),
             Self::Scalar(a) => a.hash(state),
             Self::Signature(a) => a.hash(state),
             Self::String(a) => a.hash(state),
@@ -62,11 +64,13 @@ impl<N: Network> Equal for Literal<N> {
             (Self::I32(a), Self::I32(b)) => a.is_equal(b),
             (Self::I64(a), Self::I64(b)) => a.is_equal(b),
             (Self::I128(a), Self::I128(b)) => a.is_equal(b),
+            (Self::I256(a), Self::I256(b)) => a.is_equal(b),
             (Self::U8(a), Self::U8(b)) => a.is_equal(b),
             (Self::U16(a), Self::U16(b)) => a.is_equal(b),
             (Self::U32(a), Self::U32(b)) => a.is_equal(b),
             (Self::U64(a), Self::U64(b)) => a.is_equal(b),
             (Self::U128(a), Self::U128(b)) => a.is_equal(b),
+            (Self::U256(a), Self::U256(b)) => a.is_equal(b),
             (Self::Scalar(a), Self::Scalar(b)) => a.is_equal(b),
             (Self::Signature(a), Self::Signature(b)) => a.is_equal(b),
             (Self::String(a), Self::String(b)) => a.is_equal(b),
@@ -86,11 +90,13 @@ impl<N: Network> Equal for Literal<N> {
             (Self::I32(a), Self::I32(b)) => a.is_not_equal(b),
             (Self::I64(a), Self::I64(b)) => a.is_not_equal(b),
             (Self::I128(a), Self::I128(b)) => a.is_not_equal(b),
+            (Self::I256(a), Self::I256(b)) => a.is_not_equal(b),
             (Self::U8(a), Self::U8(b)) => a.is_not_equal(b),
             (Self::U16(a), Self::U16(b)) => a.is_not_equal(b),
             (Self::U32(a), Self::U32(b)) => a.is_not_equal(b),
             (Self::U64(a), Self::U64(b)) => a.is_not_equal(b),
             (Self::U128(a), Self::U128(b)) => a.is_not_equal(b),
+            (Self::U256(a), Self::U256(b)) => a.is_not_equal(b),
             (Self::Scalar(a), Self::Scalar(b)) => a.is_not_equal(b),
             (Self::Signature(a), Self::Signature(b)) => a.is_not_equal(b),
             (Self::String(a), Self::String(b)) => a.is_not_equal(b),
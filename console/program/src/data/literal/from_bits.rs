@@ -48,7 +48,9 @@ impl<N: Network> Literal<N> {
                     false => bail!("String literal exceeds maximum length of {} bytes.", N::MAX_STRING_BYTES),
                 }
             }
-            17.. => bail!("Failed to initialize literal variant {} from bits (LE)", variant),
+            17 => Literal::I256(I256::from_bits_le(literal)?),
+            18 => Literal::U256(U256::from_bits_le(literal)?),
+            19.. => bail!("Failed to initialize literal variant {} from bits (LE)", variant),
         };
         Ok(literal)
     }
@@ -86,7 +88,9 @@ impl<N: Network> Literal<N> {
                     false => bail!("String literal exceeds maximum length of {} bytes.", N::MAX_STRING_BYTES),
                 }
             }
-            17.. => bail!("Failed to initialize literal variant {} from bits (BE)", variant),
+            17 => Literal::I256(I256::from_bits_be(literal)?),
+            18 => Literal::U256(U256::from_bits_be(literal)?),
+            19.. => bail!("Failed to initialize literal variant {} from bits (BE)", variant),
         };
         Ok(literal)
     }
@@ -133,6 +137,8 @@ mod tests {
             check_serialization(Literal::<CurrentNetwork>::I64(I64::new(Uniform::rand(rng))))?;
             // I128
             check_serialization(Literal::<CurrentNetwork>::I128(I128::new(Uniform::rand(rng))))?;
+            // I256
+            check_serialization(Literal::<CurrentNetwork>::I256(I256::new(Uniform::rand(rng))))?;
             // U8
             check_serialization(Literal::<CurrentNetwork>::U8(U8::new(Uniform::rand(rng))))?;
             // U16
@@ -143,6 +149,8 @@ mod tests {
             check_serialization(Literal::<CurrentNetwork>::U64(U64::new(Uniform::rand(rng))))?;
             // U128
             check_serialization(Literal::<CurrentNetwork>::U128(U128::new(Uniform::rand(rng))))?;
+            // U256
+            check_serialization(Literal::<CurrentNetwork>::U256(U256::new(Uniform::rand(rng))))?;
             // Scalar
             check_serialization(Literal::<CurrentNetwork>::Scalar(Uniform::rand(rng)))?;
             // Signature
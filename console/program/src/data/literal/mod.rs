@@ -28,6 +28,7 @@ mod size_in_bytes;
 mod to_bits;
 mod to_type;
 mod variant;
+mod zero;
 
 use crate::{LiteralType, ProgramID};
 use snarkvm_console_account::{ComputeKey, PrivateKey, Signature};
@@ -71,4 +72,8 @@ pub enum Literal<N: Network> {
     Signature(Box<Signature<N>>),
     /// The string type.
     String(StringType<N>),
+    /// The 256-bit signed integer type.
+    I256(I256<N>),
+    /// The 256-bit unsigned integer type.
+    U256(U256<N>),
 }
@@ -28,11 +28,13 @@ impl<N: Network> Parser for Literal<N> {
             map(I32::<N>::parse, |literal| Self::I32(literal)),
             map(I64::<N>::parse, |literal| Self::I64(literal)),
             map(I128::<N>::parse, |literal| Self::I128(literal)),
+            map(I256::<N>::parse, |literal| Self::I256(literal)),
             map(U8::<N>::parse, |literal| Self::U8(literal)),
             map(U16::<N>::parse, |literal| Self::U16(literal)),
             map(U32::<N>::parse, |literal| Self::U32(literal)),
             map(U64::<N>::parse, |literal| Self::U64(literal)),
             map(U128::<N>::parse, |literal| Self::U128(literal)),
+            map(U256::<N>::parse, |literal| Self::U256(literal)),
             map(Scalar::<N>::parse, |literal| Self::Scalar(literal)),
             map(Signature::<N>::parse, |literal| Self::Signature(Box::new(literal))),
             map(StringType::<N>::parse, |literal| Self::String(literal)),
@@ -78,11 +80,13 @@ impl<N: Network> Display for Literal<N> {
             Self::I32(literal) => Display::fmt(literal, f),
             Self::I64(literal) => Display::fmt(literal, f),
             Self::I128(literal) => Display::fmt(literal, f),
+            Self::I256(literal) => Display::fmt(literal, f),
             Self::U8(literal) => Display::fmt(literal, f),
             Self::U16(literal) => Display::fmt(literal, f),
             Self::U32(literal) => Display::fmt(literal, f),
             Self::U64(literal) => Display::fmt(literal, f),
             Self::U128(literal) => Display::fmt(literal, f),
+            Self::U256(literal) => Display::fmt(literal, f),
             Self::Scalar(literal) => Display::fmt(literal, f),
             Self::Signature(literal) => Display::fmt(literal, f),
             Self::String(literal) => Display::fmt(literal, f),
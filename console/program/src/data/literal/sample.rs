@@ -27,11 +27,13 @@ impl<N: Network> Literal<N> {
             LiteralType::I32 => Literal::I32(I32::rand(rng)),
             LiteralType::I64 => Literal::I64(I64::rand(rng)),
             LiteralType::I128 => Literal::I128(I128::rand(rng)),
+            LiteralType::I256 => Literal::I256(I256::rand(rng)),
             LiteralType::U8 => Literal::U8(U8::rand(rng)),
             LiteralType::U16 => Literal::U16(U16::rand(rng)),
             LiteralType::U32 => Literal::U32(U32::rand(rng)),
             LiteralType::U64 => Literal::U64(U64::rand(rng)),
             LiteralType::U128 => Literal::U128(U128::rand(rng)),
+            LiteralType::U256 => Literal::U256(U256::rand(rng)),
             LiteralType::Scalar => Literal::Scalar(Scalar::rand(rng)),
             LiteralType::Signature => Literal::Signature(Box::new(Signature::from((
                 Scalar::rand(rng),
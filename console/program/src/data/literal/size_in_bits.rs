@@ -27,11 +27,13 @@ impl<N: Network> Literal<N> {
             Self::I32(..) => I32::<N>::size_in_bits(),
             Self::I64(..) => I64::<N>::size_in_bits(),
             Self::I128(..) => I128::<N>::size_in_bits(),
+            Self::I256(..) => I256::<N>::size_in_bits(),
             Self::U8(..) => U8::<N>::size_in_bits(),
             Self::U16(..) => U16::<N>::size_in_bits(),
             Self::U32(..) => U32::<N>::size_in_bits(),
             Self::U64(..) => U64::<N>::size_in_bits(),
             Self::U128(..) => U128::<N>::size_in_bits(),
+            Self::U256(..) => U256::<N>::size_in_bits(),
             Self::Scalar(..) => Scalar::<N>::size_in_bits(),
             Self::Signature(..) => Signature::<N>::size_in_bits(),
             Self::String(string) => match string.len().checked_mul(8) {
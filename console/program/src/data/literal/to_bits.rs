@@ -39,11 +39,13 @@ impl<N: Network> ToBits for &Literal<N> {
             Literal::I32(literal) => literal.write_bits_le(vec),
             Literal::I64(literal) => literal.write_bits_le(vec),
             Literal::I128(literal) => literal.write_bits_le(vec),
+            Literal::I256(literal) => literal.write_bits_le(vec),
             Literal::U8(literal) => literal.write_bits_le(vec),
             Literal::U16(literal) => literal.write_bits_le(vec),
             Literal::U32(literal) => literal.write_bits_le(vec),
             Literal::U64(literal) => literal.write_bits_le(vec),
             Literal::U128(literal) => literal.write_bits_le(vec),
+            Literal::U256(literal) => literal.write_bits_le(vec),
             Literal::Scalar(literal) => literal.write_bits_le(vec),
             Literal::Signature(literal) => literal.write_bits_le(vec),
             Literal::String(literal) => literal.as_bytes().write_bits_le(vec),
@@ -62,11 +64,13 @@ impl<N: Network> ToBits for &Literal<N> {
             Literal::I32(literal) => literal.write_bits_be(vec),
             Literal::I64(literal) => literal.write_bits_be(vec),
             Literal::I128(literal) => literal.write_bits_be(vec),
+            Literal::I256(literal) => literal.write_bits_be(vec),
             Literal::U8(literal) => literal.write_bits_be(vec),
             Literal::U16(literal) => literal.write_bits_be(vec),
             Literal::U32(literal) => literal.write_bits_be(vec),
             Literal::U64(literal) => literal.write_bits_be(vec),
             Literal::U128(literal) => literal.write_bits_be(vec),
+            Literal::U256(literal) => literal.write_bits_be(vec),
             Literal::Scalar(literal) => literal.write_bits_be(vec),
             Literal::Signature(literal) => literal.write_bits_be(vec),
             Literal::String(literal) => literal.as_bytes().write_bits_be(vec),
@@ -27,11 +27,13 @@ impl<N: Network> Literal<N> {
             Self::I32(..) => LiteralType::I32,
             Self::I64(..) => LiteralType::I64,
             Self::I128(..) => LiteralType::I128,
+            Self::I256(..) => LiteralType::I256,
             Self::U8(..) => LiteralType::U8,
             Self::U16(..) => LiteralType::U16,
             Self::U32(..) => LiteralType::U32,
             Self::U64(..) => LiteralType::U64,
             Self::U128(..) => LiteralType::U128,
+            Self::U256(..) => LiteralType::U256,
             Self::Scalar(..) => LiteralType::Scalar,
             Self::Signature(..) => LiteralType::Signature,
             Self::String(..) => LiteralType::String,
@@ -35,6 +35,8 @@ impl<N: Network> Literal<N> {
             Self::Scalar(..) => 14,
             Self::Signature(..) => 15,
             Self::String(..) => 16,
+            Self::I256(..) => 17,
+            Self::U256(..) => 18,
         }
     }
 }
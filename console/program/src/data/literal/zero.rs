@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Literal<N> {
+    /// Returns the zero literal of the given literal type.
+    /// This is the value held by an empty optional of the given type.
+    pub fn zero(literal_type: LiteralType) -> Result<Self> {
+        Ok(match literal_type {
+            LiteralType::Address => Literal::Address(Address::zero()),
+            LiteralType::Boolean => Literal::Boolean(Boolean::new(false)),
+            LiteralType::Field => Literal::Field(Field::zero()),
+            LiteralType::Group => Literal::Group(Group::zero()),
+            LiteralType::I8 => Literal::I8(I8::zero()),
+            LiteralType::I16 => Literal::I16(I16::zero()),
+            LiteralType::I32 => Literal::I32(I32::zero()),
+            LiteralType::I64 => Literal::I64(I64::zero()),
+            LiteralType::I128 => Literal::I128(I128::zero()),
+            LiteralType::I256 => Literal::I256(I256::zero()),
+            LiteralType::U8 => Literal::U8(U8::zero()),
+            LiteralType::U16 => Literal::U16(U16::zero()),
+            LiteralType::U32 => Literal::U32(U32::zero()),
+            LiteralType::U64 => Literal::U64(U64::zero()),
+            LiteralType::U128 => Literal::U128(U128::zero()),
+            LiteralType::U256 => Literal::U256(U256::zero()),
+            LiteralType::Scalar => Literal::Scalar(Scalar::zero()),
+            LiteralType::Signature => bail!("A signature does not have a zero value"),
+            LiteralType::String => Literal::String(StringType::new("")),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_zero() -> Result<()> {
+        assert_eq!(Literal::<CurrentNetwork>::zero(LiteralType::U64)?.to_string(), "0u64");
+        assert_eq!(Literal::<CurrentNetwork>::zero(LiteralType::Boolean)?.to_string(), "false");
+        assert_eq!(Literal::<CurrentNetwork>::zero(LiteralType::String)?.to_string(), "\"\"");
+        assert_eq!(Literal::<CurrentNetwork>::zero(LiteralType::I256)?.to_type(), LiteralType::I256);
+        assert!(Literal::<CurrentNetwork>::zero(LiteralType::Signature).is_err());
+        Ok(())
+    }
+}
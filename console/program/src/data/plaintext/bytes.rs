@@ -65,7 +65,19 @@ impl<N: Network> FromBytes for Plaintext<N> {
                 // Return the array.
                 Self::Array(elements, Default::default())
             }
-            3.. => return Err(error(format!("Failed to decode plaintext variant {index}"))),
+            3 => {
+                let is_some = bool::read_le(&mut reader)?;
+                let literal = Literal::read_le(&mut reader)?;
+                let optional = match is_some {
+                    true => Self::some(literal),
+                    false => match literal == Literal::zero(literal.to_type()).map_err(error)? {
+                        true => Self::none(literal.to_type()),
+                        false => return Err(error("Failed to decode plaintext: Empty optional is not zero")),
+                    },
+                };
+                optional.map_err(error)?
+            }
+            4.. => return Err(error(format!("Failed to decode plaintext variant {index}"))),
         };
         Ok(plaintext)
     }
@@ -116,6 +128,11 @@ impl<N: Network> ToBytes for Plaintext<N> {
                 }
                 Ok(())
             }
+            Self::Optional(is_some, literal, ..) => {
+                3u8.write_le(&mut writer)?;
+                is_some.write_le(&mut writer)?;
+                literal.write_le(&mut writer)
+            }
         }
     }
 }
@@ -182,6 +199,11 @@ mod tests {
                 Literal::<CurrentNetwork>::I128(I128::new(Uniform::rand(rng))),
                 Default::default(),
             ))?;
+            // I256
+            check_bytes(Plaintext::Literal(
+                Literal::<CurrentNetwork>::I256(I256::new(Uniform::rand(rng))),
+                Default::default(),
+            ))?;
             // U8
             check_bytes(Plaintext::Literal(
                 Literal::<CurrentNetwork>::U8(U8::new(Uniform::rand(rng))),
@@ -207,6 +229,11 @@ mod tests {
                 Literal::<CurrentNetwork>::U128(U128::new(Uniform::rand(rng))),
                 Default::default(),
             ))?;
+            // U256
+            check_bytes(Plaintext::Literal(
+                Literal::<CurrentNetwork>::U256(U256::new(Uniform::rand(rng))),
+                Default::default(),
+            ))?;
             // Scalar
             check_bytes(Plaintext::Literal(Literal::<CurrentNetwork>::Scalar(Uniform::rand(rng)), Default::default()))?;
             // String
@@ -232,6 +259,15 @@ mod tests {
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Plaintext::read_le(&expected_bytes[..])?);
 
+        // Check the optionals manually.
+        check_bytes(Plaintext::<CurrentNetwork>::from_str("some(5u64)")?)?;
+        check_bytes(Plaintext::<CurrentNetwork>::from_str("none(u64)")?)?;
+
+        // Ensure an empty optional with a non-zero value is rejected.
+        let mut bytes = Plaintext::<CurrentNetwork>::from_str("some(5u64)")?.to_bytes_le()?;
+        bytes[1] = 0;
+        assert!(Plaintext::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
         Ok(())
     }
 }
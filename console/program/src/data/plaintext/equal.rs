@@ -48,7 +48,12 @@ impl<N: Network> Equal<Self> for Plaintext<N> {
                 }
                 false => Boolean::new(false),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::new(false),
+            (Self::Optional(is_some_a, a, _), Self::Optional(is_some_b, b, _)) => {
+                is_some_a.is_equal(is_some_b) & a.is_equal(b)
+            }
+            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) | (Self::Optional(..), _) => {
+                Boolean::new(false)
+            }
         }
     }
 
@@ -76,7 +81,12 @@ impl<N: Network> Equal<Self> for Plaintext<N> {
                 }
                 false => Boolean::new(true),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::new(true),
+            (Self::Optional(is_some_a, a, _), Self::Optional(is_some_b, b, _)) => {
+                is_some_a.is_not_equal(is_some_b) | a.is_not_equal(b)
+            }
+            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) | (Self::Optional(..), _) => {
+                Boolean::new(true)
+            }
         }
     }
 }
@@ -22,7 +22,7 @@ impl<N: Network> Plaintext<N> {
 
         match self {
             // Halts if the value is not a struct.
-            Self::Literal(..) => bail!("'{self}' is not a struct"),
+            Self::Literal(..) | Self::Optional(..) => bail!("'{self}' is not a struct"),
             // Retrieve the value of the member (from the value).
             Self::Struct(..) | Self::Array(..) => {
                 // Initialize the plaintext starting from the top-level.
@@ -90,6 +90,21 @@ impl<N: Network> FromBits for Plaintext<N> {
             // Cache the plaintext bits, and return the array.
             Ok(Self::Array(elements, OnceCell::with_value(bits_le.to_vec())))
         }
+        // Optional
+        else if variant == [true, true] {
+            let is_some = next_bits(1)?[0];
+            let literal_variant = u8::from_bits_le(next_bits(8)?)?;
+            let literal_size = u16::from_bits_le(next_bits(16)?)?;
+            let literal = Literal::from_bits_le(literal_variant, next_bits(literal_size as usize)?)?;
+            ensure!(!matches!(literal, Literal::Signature(..)), "An optional cannot contain a signature");
+            // Ensure an empty optional holds the zero literal.
+            if !is_some {
+                ensure!(literal == Literal::zero(literal.to_type())?, "An empty optional must hold the zero literal");
+            }
+
+            // Cache the plaintext bits, and return the optional.
+            Ok(Self::Optional(Boolean::new(is_some), literal, OnceCell::with_value(bits_le.to_vec())))
+        }
         // Unknown variant.
         else {
             bail!("Unknown plaintext variant - {variant:?}");
@@ -171,6 +186,21 @@ impl<N: Network> FromBits for Plaintext<N> {
             // Cache the plaintext bits, and return the array.
             Ok(Self::Array(elements, OnceCell::with_value(bits_be.to_vec())))
         }
+        // Optional
+        else if variant == [true, true] {
+            let is_some = next_bits(1)?[0];
+            let literal_variant = u8::from_bits_be(next_bits(8)?)?;
+            let literal_size = u16::from_bits_be(next_bits(16)?)?;
+            let literal = Literal::from_bits_be(literal_variant, next_bits(literal_size as usize)?)?;
+            ensure!(!matches!(literal, Literal::Signature(..)), "An optional cannot contain a signature");
+            // Ensure an empty optional holds the zero literal.
+            if !is_some {
+                ensure!(literal == Literal::zero(literal.to_type())?, "An empty optional must hold the zero literal");
+            }
+
+            // Cache the plaintext bits, and return the optional.
+            Ok(Self::Optional(Boolean::new(is_some), literal, OnceCell::with_value(bits_be.to_vec())))
+        }
         // Unknown variant.
         else {
             bail!("Unknown plaintext variant - {variant:?}");
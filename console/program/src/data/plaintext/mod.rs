@@ -25,7 +25,7 @@ mod size_in_fields;
 mod to_bits;
 mod to_fields;
 
-use crate::{Access, Ciphertext, Identifier, Literal};
+use crate::{Access, Ciphertext, Identifier, Literal, LiteralType};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
@@ -40,6 +40,23 @@ pub enum Plaintext<N: Network> {
     Struct(IndexMap<Identifier<N>, Plaintext<N>>, OnceCell<Vec<bool>>),
     /// An array.
     Array(Vec<Plaintext<N>>, OnceCell<Vec<bool>>),
+    /// An optional literal, with a flag indicating if the value is present.
+    /// An empty optional holds the zero literal of its type.
+    /// Note: The payload is restricted to literals; see `PlaintextType::Optional`.
+    Optional(Boolean<N>, Literal<N>, OnceCell<Vec<bool>>),
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Returns a new optional plaintext containing the given literal.
+    pub fn some(literal: Literal<N>) -> Result<Self> {
+        ensure!(!matches!(literal, Literal::Signature(..)), "An optional cannot contain a signature");
+        Ok(Self::Optional(Boolean::new(true), literal, OnceCell::new()))
+    }
+
+    /// Returns a new empty optional plaintext of the given literal type.
+    pub fn none(literal_type: LiteralType) -> Result<Self> {
+        Ok(Self::Optional(Boolean::new(false), Literal::zero(literal_type)?, OnceCell::new()))
+    }
 }
 
 impl<N: Network> From<Literal<N>> for Plaintext<N> {
@@ -208,6 +225,12 @@ mod tests {
             OnceCell::new(),
         ));
 
+        // Test optionals.
+        run_test(Plaintext::<CurrentNetwork>::from_str("some(5u64)")?);
+        run_test(Plaintext::<CurrentNetwork>::from_str("none(u64)")?);
+        run_test(Plaintext::<CurrentNetwork>::from_str("{ a: some(true), b: none(field) }")?);
+        run_test(Plaintext::<CurrentNetwork>::from_str("[ some(1u8), none(u8) ]")?);
+
         Ok(())
     }
 }
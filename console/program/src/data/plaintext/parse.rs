@@ -78,6 +78,35 @@ impl<N: Network> Parser for Plaintext<N> {
             Ok((string, Plaintext::Array(members, Default::default())))
         }
 
+        /// Parses a plaintext as an optional: `some(literal)` or `none(literal_type)`.
+        fn parse_optional<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+            /// Parses a present optional: `some(literal)`.
+            fn parse_some<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+                // Parse the "some(" from the string.
+                let (string, _) = tag("some(")(string)?;
+                // Parse the literal.
+                let (string, plaintext) = map_res(Literal::parse, Plaintext::some)(string)?;
+                // Parse the ")" from the string.
+                let (string, _) = tag(")")(string)?;
+                // Output the plaintext.
+                Ok((string, plaintext))
+            }
+
+            /// Parses an empty optional: `none(literal_type)`.
+            fn parse_none<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+                // Parse the "none(" from the string.
+                let (string, _) = tag("none(")(string)?;
+                // Parse the literal type.
+                let (string, plaintext) = map_res(LiteralType::parse, Plaintext::none)(string)?;
+                // Parse the ")" from the string.
+                let (string, _) = tag(")")(string)?;
+                // Output the plaintext.
+                Ok((string, plaintext))
+            }
+
+            alt((parse_some, parse_none))(string)
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the plaintext (order matters).
@@ -88,6 +117,8 @@ impl<N: Network> Parser for Plaintext<N> {
             parse_struct,
             // Parse a plaintext array.
             parse_array,
+            // Parse a plaintext optional.
+            parse_optional,
         ))(string)
     }
 }
@@ -149,7 +180,7 @@ impl<N: Network> Plaintext<N> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{name}: {literal},", "", indent = (depth + 1) * INDENT),
                         },
-                        Self::Struct(..) | Self::Array(..) => {
+                        Self::Struct(..) | Self::Array(..) | Self::Optional(..) => {
                             // Print the member name.
                             write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -182,7 +213,7 @@ impl<N: Network> Plaintext<N> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{literal},", "", indent = (depth + 1) * INDENT),
                         },
-                        Self::Struct(..) | Self::Array(..) => {
+                        Self::Struct(..) | Self::Array(..) | Self::Optional(..) => {
                             // Print a newline.
                             write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -198,6 +229,11 @@ impl<N: Network> Plaintext<N> {
                     }
                 })
             }
+            // Prints the optional, i.e. some(5u8) or none(u8)
+            Self::Optional(is_some, literal, ..) => match **is_some {
+                true => write!(f, "some({literal})"),
+                false => write!(f, "none({})", literal.to_type()),
+            },
         }
     }
 }
@@ -209,6 +245,29 @@ mod tests {
 
     type CurrentNetwork = MainnetV0;
 
+    #[test]
+    fn test_parse_optional() -> Result<()> {
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("some(5u8)")?;
+        assert_eq!("some(5u8)", candidate.to_string());
+        assert_eq!("", remainder);
+
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("none(address)")?;
+        assert_eq!("none(address)", candidate.to_string());
+        assert_eq!("", remainder);
+
+        let expected = r"{
+  foo: some(5u8),
+  bar: none(field)
+}";
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("{ foo: some(5u8), bar: none(field) }")?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        assert!(Plaintext::<CurrentNetwork>::parse("none(signature)").is_err());
+        assert!(Plaintext::<CurrentNetwork>::parse("some(u8)").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_parse_literal() -> Result<()> {
         // Sanity check.
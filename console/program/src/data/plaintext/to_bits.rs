@@ -85,6 +85,21 @@ impl<N: Network> ToBits for Plaintext<N> {
                 // Extend the vector with the bits.
                 vec.extend_from_slice(bits)
             }
+            Self::Optional(is_some, literal, bits_le) => {
+                // Compute the bits.
+                // Note: This is the last of the 2-bit plaintext variants, so a new plaintext variant
+                // must introduce a new encoding, rather than a new variant prefix.
+                let bits = bits_le.get_or_init(|| {
+                    let mut bits_le = vec![true, true]; // Variant bits.
+                    bits_le.push(**is_some);
+                    literal.variant().write_bits_le(&mut bits_le);
+                    literal.size_in_bits().write_bits_le(&mut bits_le);
+                    literal.write_bits_le(&mut bits_le);
+                    bits_le
+                });
+                // Extend the vector with the bits.
+                vec.extend_from_slice(bits)
+            }
         }
     }
 
@@ -159,6 +174,19 @@ impl<N: Network> ToBits for Plaintext<N> {
                 // Extend the vector with the bits.
                 vec.extend_from_slice(bits)
             }
+            Self::Optional(is_some, literal, bits_be) => {
+                // Compute the bits.
+                let bits = bits_be.get_or_init(|| {
+                    let mut bits_be = vec![true, true]; // Variant bits.
+                    bits_be.push(**is_some);
+                    literal.variant().write_bits_be(&mut bits_be);
+                    literal.size_in_bits().write_bits_be(&mut bits_be);
+                    literal.write_bits_be(&mut bits_be);
+                    bits_be
+                });
+                // Extend the vector with the bits.
+                vec.extend_from_slice(bits)
+            }
         }
     }
 }
@@ -46,6 +46,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
                 parse_struct,
                 // Parse an array.
                 parse_array,
+                // Parse an optional.
+                parse_optional,
             ))(string)?;
             // Parse the whitespace from the string.
             let (string, _) = Sanitizer::parse_whitespaces(string)?;
@@ -62,6 +64,23 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             ))(string)
         }
 
+        /// Parses an entry as an optional: `some(literal).visibility` or `none(literal_type).visibility`.
+        fn parse_optional<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
+            // Parse the optional from the string.
+            let (string, plaintext) = map_res(Plaintext::parse, |plaintext| match plaintext {
+                Plaintext::Optional(..) => Ok(plaintext),
+                _ => Err(error("Expected an optional")),
+            })(string)?;
+            // Parse the visibility from the string.
+            let (string, mode) = alt((
+                map(tag(".constant"), |_| Mode::Constant),
+                map(tag(".public"), |_| Mode::Public),
+                map(tag(".private"), |_| Mode::Private),
+            ))(string)?;
+            // Output the plaintext and visibility.
+            Ok((string, (plaintext, mode)))
+        }
+
         /// Parses an entry as a struct: `{ identifier_0: plaintext_0.visibility, ..., identifier_n: plaintext_n.visibility }`.
         /// Observe the `visibility` is the same for all members of the plaintext value.
         fn parse_struct<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
@@ -111,7 +130,7 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             let (string, (elements, mode)) = map_res(
                 separated_list1(
                     pair(Sanitizer::parse_whitespaces, pair(tag(","), Sanitizer::parse_whitespaces)),
-                    alt((parse_literal, parse_struct, parse_array)),
+                    alt((parse_literal, parse_struct, parse_array, parse_optional)),
                 ),
                 |members: Vec<(Plaintext<N>, Mode)>| {
                     // Ensure the members all have the same visibility.
@@ -146,6 +165,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             parse_struct,
             // Parse an array.
             parse_array,
+            // Parse an optional.
+            parse_optional,
         ))(string)?;
 
         // Return the entry.
@@ -205,6 +226,8 @@ impl<N: Network> Entry<N, Plaintext<N>> {
             Plaintext::Literal(literal, ..) => {
                 write!(f, "{:indent$}{literal}.{visibility}", "", indent = depth * INDENT)
             }
+            // Prints the optional, i.e. some(10field).public
+            Plaintext::Optional(..) => write!(f, "{plaintext}.{visibility}"),
             // Prints the struct, i.e. { first: 10i64.private, second: 198u64.private }
             Plaintext::Struct(struct_, ..) => {
                 // Print the opening brace.
@@ -223,7 +246,7 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{name}: {literal}.{visibility},", "", indent = (depth + 1) * INDENT),
                         },
-                        Plaintext::Struct(..) | Plaintext::Array(..) => {
+                        Plaintext::Struct(..) | Plaintext::Array(..) | Plaintext::Optional(..) => {
                             // Print the member name.
                             write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -261,7 +284,7 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{literal}.{visibility},", "", indent = (depth + 1) * INDENT),
                         },
-                        Plaintext::Struct(..) | Plaintext::Array(..) => {
+                        Plaintext::Struct(..) | Plaintext::Array(..) | Plaintext::Optional(..) => {
                             // Print a new line.
                             write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -388,6 +411,20 @@ mod tests {
         assert_eq!(expected, candidate.to_string());
         assert_eq!("", remainder);
 
+        // Test a struct with optionals.
+        let expected = r"{
+  foo: some(5u8).private,
+  bar: [
+    none(field).private,
+    some(1field).private
+  ]
+}";
+        let (remainder, candidate) = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse(
+            "{ foo: some(5u8).private, bar: [ none(field).private, some(1field).private ] }",
+        )?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
         Ok(())
     }
 }
@@ -385,6 +385,12 @@ mod tests {
             );
         }
 
+        // Register::Access with Access::Register
+        assert_eq!(
+            ("", Register::<CurrentNetwork>::Access(0, vec![Access::Register(1), Access::Index(U32::new(0))])),
+            Register::parse("r0[r1][0u32]").unwrap()
+        );
+
         // Register::Access with Access::Index
         assert_eq!(
             ("", Register::<CurrentNetwork>::Access(0, vec![Access::Index(U32::new(0))])),
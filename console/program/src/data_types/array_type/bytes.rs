@@ -22,7 +22,11 @@ impl<N: Network> FromBytes for ArrayType<N> {
         let element_type = match variant {
             0 => PlaintextType::Literal(LiteralType::read_le(&mut reader)?),
             1 => PlaintextType::Struct(Identifier::read_le(&mut reader)?),
-            2.. => return Err(error(format!("Failed to deserialize element type {variant}"))),
+            2 => match LiteralType::read_le(&mut reader)? {
+                LiteralType::Signature => return Err(error("An optional type cannot contain a signature")),
+                literal_type => PlaintextType::Optional(literal_type),
+            },
+            3.. => return Err(error(format!("Failed to deserialize element type {variant}"))),
         };
 
         // Read the number of dimensions of the array.
@@ -57,7 +61,7 @@ impl<N: Network> ToBytes for ArrayType<N> {
         // Note that the lengths are in the order of the outermost dimension to the innermost dimension.
         for _ in 1..N::MAX_DATA_DEPTH {
             element_type = match element_type {
-                PlaintextType::Literal(_) | PlaintextType::Struct(_) => break,
+                PlaintextType::Literal(_) | PlaintextType::Struct(_) | PlaintextType::Optional(_) => break,
                 PlaintextType::Array(array_type) => {
                     lengths.push(*array_type.length());
                     array_type.next_element_type().clone()
@@ -80,6 +84,10 @@ impl<N: Network> ToBytes for ArrayType<N> {
                 1u8.write_le(&mut writer)?;
                 identifier.write_le(&mut writer)?;
             }
+            PlaintextType::Optional(literal_type) => {
+                2u8.write_le(&mut writer)?;
+                literal_type.write_le(&mut writer)?;
+            }
             PlaintextType::Array(_) => {
                 // This is technically unreachable by definition, however we return an error
                 // out of an abundance of caution.
@@ -132,6 +132,13 @@ mod tests {
         assert_eq!(array.length(), &U32::new(32));
         assert!(!array.is_empty());
 
+        // Test array type with optional elements.
+        let array = ArrayType::<CurrentNetwork>::from_str("[u64?; 4u32]")?;
+        assert_eq!(array, ArrayType::<CurrentNetwork>::new(PlaintextType::from_str("u64?")?, vec![U32::new(4)])?);
+        assert_eq!(array, ArrayType::<CurrentNetwork>::from_bytes_le(&array.to_bytes_le()?)?);
+        assert_eq!(array.next_element_type(), &PlaintextType::Optional(LiteralType::U64));
+        assert_eq!(array.to_string(), "[u64?; 4u32]");
+
         // Test multi-dimensional array types.
         let array = ArrayType::<CurrentNetwork>::from_str("[[field; 2u32]; 3u32]")?;
         assert_eq!(
@@ -21,7 +21,14 @@ impl<N: Network> Parser for ArrayType<N> {
     fn parse(string: &str) -> ParserResult<Self> {
         // A helper function to parse the innermost element type.
         fn parse_inner_element_type<N: Network>(string: &str) -> ParserResult<PlaintextType<N>> {
-            alt((map(LiteralType::parse, PlaintextType::from), map(Identifier::parse, PlaintextType::from)))(string)
+            alt((
+                map_res(terminated(LiteralType::parse, tag("?")), |literal_type| match literal_type {
+                    LiteralType::Signature => Err(error("An optional type cannot contain a signature")),
+                    _ => Ok(PlaintextType::Optional(literal_type)),
+                }),
+                map(LiteralType::parse, PlaintextType::from),
+                map(Identifier::parse, PlaintextType::from),
+            ))(string)
         }
 
         // A helper function to parse the length of each dimension.
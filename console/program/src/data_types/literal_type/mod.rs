@@ -62,6 +62,10 @@ pub enum LiteralType {
     Signature,
     /// The string type.
     String,
+    /// The 256-bit signed integer type.
+    I256,
+    /// The 256-bit unsigned integer type.
+    U256,
 }
 
 impl LiteralType {
@@ -77,11 +81,13 @@ impl LiteralType {
             Self::I32 => "i32",
             Self::I64 => "i64",
             Self::I128 => "i128",
+            Self::I256 => "i256",
             Self::U8 => "u8",
             Self::U16 => "u16",
             Self::U32 => "u32",
             Self::U64 => "u64",
             Self::U128 => "u128",
+            Self::U256 => "u256",
             Self::Scalar => "scalar",
             Self::Signature => "signature",
             Self::String => "string",
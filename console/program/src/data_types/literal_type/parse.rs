@@ -29,11 +29,13 @@ impl Parser for LiteralType {
             map(tag("i32"), |_| Self::I32),
             map(tag("i64"), |_| Self::I64),
             map(tag("i128"), |_| Self::I128),
+            map(tag("i256"), |_| Self::I256),
             map(tag("u8"), |_| Self::U8),
             map(tag("u16"), |_| Self::U16),
             map(tag("u32"), |_| Self::U32),
             map(tag("u64"), |_| Self::U64),
             map(tag("u128"), |_| Self::U128),
+            map(tag("u256"), |_| Self::U256),
             map(tag("scalar"), |_| Self::Scalar),
             map(tag("signature"), |_| Self::Signature),
             map(tag("string"), |_| Self::String),
@@ -49,11 +49,13 @@ mod tests {
         "i32",
         "i64",
         "i128",
+        "i256",
         "u8",
         "u16",
         "u32",
         "u64",
         "u128",
+        "u256",
         "scalar",
         "signature",
         "string",
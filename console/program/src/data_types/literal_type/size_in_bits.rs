@@ -29,11 +29,13 @@ impl LiteralType {
             Self::I32 => I32::<N>::size_in_bits(),
             Self::I64 => I64::<N>::size_in_bits(),
             Self::I128 => I128::<N>::size_in_bits(),
+            Self::I256 => I256::<N>::size_in_bits(),
             Self::U8 => U8::<N>::size_in_bits(),
             Self::U16 => U16::<N>::size_in_bits(),
             Self::U32 => U32::<N>::size_in_bits(),
             Self::U64 => U64::<N>::size_in_bits(),
             Self::U128 => U128::<N>::size_in_bits(),
+            Self::U256 => U256::<N>::size_in_bits(),
             Self::Scalar => Scalar::<N>::size_in_bits(),
             Self::Signature => Signature::<N>::size_in_bits(),
             Self::String => N::MAX_STRING_BYTES.saturating_mul(8) as usize,
@@ -22,7 +22,11 @@ impl<N: Network> FromBytes for PlaintextType<N> {
             0 => Ok(Self::Literal(LiteralType::read_le(&mut reader)?)),
             1 => Ok(Self::Struct(Identifier::read_le(&mut reader)?)),
             2 => Ok(Self::Array(ArrayType::read_le(&mut reader)?)),
-            3.. => Err(error(format!("Failed to deserialize annotation variant {variant}"))),
+            3 => match LiteralType::read_le(&mut reader)? {
+                LiteralType::Signature => Err(error("An optional type cannot contain a signature")),
+                literal_type => Ok(Self::Optional(literal_type)),
+            },
+            4.. => Err(error(format!("Failed to deserialize annotation variant {variant}"))),
         }
     }
 }
@@ -43,6 +47,10 @@ impl<N: Network> ToBytes for PlaintextType<N> {
                 2u8.write_le(&mut writer)?;
                 array_type.write_le(&mut writer)
             }
+            Self::Optional(literal_type) => {
+                3u8.write_le(&mut writer)?;
+                literal_type.write_le(&mut writer)
+            }
         }
     }
 }
@@ -19,7 +19,7 @@ mod serialize;
 use crate::{ArrayType, Identifier, LiteralType};
 use snarkvm_console_network::prelude::*;
 
-/// A `PlaintextType` defines the type parameter for a literal, struct, or array.
+/// A `PlaintextType` defines the type parameter for a literal, struct, array, or optional.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum PlaintextType<N: Network> {
     /// A literal type contains its type name.
@@ -31,6 +31,13 @@ pub enum PlaintextType<N: Network> {
     /// An array type contains its element type and length.
     /// The format of the type is `[<element_type>; <length>]`.
     Array(ArrayType<N>),
+    /// An optional type contains the literal type of its value, if present.
+    /// The format of the type is `<type_name>?`.
+    ///
+    /// Note: An optional only wraps a literal (other than a signature), so that an empty and a present
+    /// optional of the same type have the same size. Optional structs, arrays, and nested optionals are
+    /// not supported, and are expressed with an explicit boolean member instead.
+    Optional(LiteralType),
 }
 
 impl<N: Network> From<LiteralType> for PlaintextType<N> {
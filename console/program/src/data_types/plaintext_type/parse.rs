@@ -21,6 +21,10 @@ impl<N: Network> Parser for PlaintextType<N> {
         // Parse to determine the plaintext type (order matters).
         alt((
             map(ArrayType::parse, |type_| Self::Array(type_)),
+            map_res(terminated(LiteralType::parse, tag("?")), |type_| match type_ {
+                LiteralType::Signature => Err(error("An optional type cannot contain a signature")),
+                _ => Ok(Self::Optional(type_)),
+            }),
             map(Identifier::parse, |identifier| Self::Struct(identifier)),
             map(LiteralType::parse, |type_| Self::Literal(type_)),
         ))(string)
@@ -61,6 +65,8 @@ impl<N: Network> Display for PlaintextType<N> {
             Self::Struct(struct_) => Display::fmt(struct_, f),
             // Prints the array type, i.e. [field; 2u32]
             Self::Array(array) => Display::fmt(array, f),
+            // Prints the optional type, i.e. field?
+            Self::Optional(literal) => write!(f, "{literal}?"),
         }
     }
 }
@@ -94,6 +100,12 @@ mod tests {
             PlaintextType::parse("[field; 1u32]"),
             Ok(("", PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[field; 1u32]")?)))
         );
+        assert_eq!(PlaintextType::parse("u64?"), Ok(("", PlaintextType::<CurrentNetwork>::Optional(LiteralType::U64))));
+        assert_eq!(
+            PlaintextType::parse("address?"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Optional(LiteralType::Address)))
+        );
+        assert_eq!(PlaintextType::<CurrentNetwork>::from_str("u64?")?.to_string(), "u64?");
         Ok(())
     }
 
@@ -141,6 +153,12 @@ mod tests {
             PlaintextType::<CurrentNetwork>::parse("[field; 1u32].private")
         );
 
+        // An optional must contain a literal type.
+        assert!(PlaintextType::<CurrentNetwork>::from_str("signature?").is_err());
+        assert!(PlaintextType::<CurrentNetwork>::from_str("foo?").is_err());
+        assert!(PlaintextType::<CurrentNetwork>::from_str("[field; 1u32]?").is_err());
+        assert!(PlaintextType::<CurrentNetwork>::from_str("u64??").is_err());
+
         // Must be non-empty.
         assert!(PlaintextType::<CurrentNetwork>::parse("").is_err());
         assert!(PlaintextType::<CurrentNetwork>::parse("{}").is_err());
@@ -184,6 +202,7 @@ mod tests {
             PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[field; 8u32]")?).to_string(),
             "[field; 8u32]"
         );
+        assert_eq!(PlaintextType::<CurrentNetwork>::Optional(LiteralType::U8).to_string(), "u8?");
         Ok(())
     }
 }
@@ -67,12 +67,14 @@ mod tests {
         check_bytes::<u32>(&mut rng)?;
         check_bytes::<u64>(&mut rng)?;
         check_bytes::<u128>(&mut rng)?;
+        check_bytes::<u256>(&mut rng)?;
 
         check_bytes::<i8>(&mut rng)?;
         check_bytes::<i16>(&mut rng)?;
         check_bytes::<i32>(&mut rng)?;
         check_bytes::<i64>(&mut rng)?;
         check_bytes::<i128>(&mut rng)?;
+        check_bytes::<i256>(&mut rng)?;
 
         Ok(())
     }
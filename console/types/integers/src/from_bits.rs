@@ -88,12 +88,14 @@ mod tests {
         check_from_bits_le::<u32>(&mut rng)?;
         check_from_bits_le::<u64>(&mut rng)?;
         check_from_bits_le::<u128>(&mut rng)?;
+        check_from_bits_le::<u256>(&mut rng)?;
 
         check_from_bits_le::<i8>(&mut rng)?;
         check_from_bits_le::<i16>(&mut rng)?;
         check_from_bits_le::<i32>(&mut rng)?;
         check_from_bits_le::<i64>(&mut rng)?;
         check_from_bits_le::<i128>(&mut rng)?;
+        check_from_bits_le::<i256>(&mut rng)?;
 
         Ok(())
     }
@@ -107,12 +109,14 @@ mod tests {
         check_from_bits_be::<u32>(&mut rng)?;
         check_from_bits_be::<u64>(&mut rng)?;
         check_from_bits_be::<u128>(&mut rng)?;
+        check_from_bits_be::<u256>(&mut rng)?;
 
         check_from_bits_be::<i8>(&mut rng)?;
         check_from_bits_be::<i16>(&mut rng)?;
         check_from_bits_be::<i32>(&mut rng)?;
         check_from_bits_be::<i64>(&mut rng)?;
         check_from_bits_be::<i128>(&mut rng)?;
+        check_from_bits_be::<i256>(&mut rng)?;
 
         Ok(())
     }
@@ -23,13 +23,14 @@ impl<E: Environment, I: IntegerType> FromField for Integer<E, I> {
     ///   1. If the field element is larger than the integer domain, then the operation will fail.
     ///   2. If the field element is smaller than the integer domain, then the operation will succeed.
     fn from_field(field: &Self::Field) -> Result<Self> {
-        // Note: We are reconstituting the integer from the base field.
-        // This is safe as the number of bits in the integer is less than the base field modulus,
-        // and thus will always fit within a single base field element.
-        debug_assert!(I::BITS < Field::<E>::size_in_bits() as u64);
-
         // Convert the field element into bits.
-        let bits_le = field.to_bits_le();
+        let mut bits_le = field.to_bits_le();
+
+        // If the integer is wider than the base field, zero-extend the bits to the size of the integer.
+        // Note: This is safe as every base field element fits within the integer domain.
+        if bits_le.len() < Self::size_in_bits() {
+            bits_le.resize(Self::size_in_bits(), false);
+        }
 
         // Extract the integer bits from the field element, **without** a carry bit.
         let (bits_le, zero_bits) = bits_le.split_at(Self::size_in_bits());
@@ -71,6 +72,8 @@ mod tests {
                 "u32" | "i32" => Field::<CurrentEnvironment>::from_u32(u32::MAX),
                 "u64" | "i64" => Field::<CurrentEnvironment>::from_u64(u64::MAX),
                 "u128" | "i128" => Field::<CurrentEnvironment>::from_u128(u128::MAX),
+                // Note: Every base field element fits within the 256-bit integer domain.
+                "u256" | "i256" => -Field::<CurrentEnvironment>::one(),
                 _ => panic!("Unsupported integer type."),
             };
             // Filter for field elements that exceed the integer domain.
@@ -144,4 +147,36 @@ mod tests {
         type I = i128;
         check_from_field::<I>()
     }
+
+    fn check_from_field_wide<I: IntegerType>() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random field.
+            let expected = Field::<CurrentEnvironment>::rand(&mut rng);
+
+            // Perform the operation.
+            let candidate = Integer::<CurrentEnvironment, I>::from_field(&expected)?;
+
+            // Ensure the field bits are zero-extended into the integer.
+            let candidate_bits_le = candidate.to_bits_le();
+            let (lower_bits_le, upper_bits_le) =
+                candidate_bits_le.split_at(Field::<CurrentEnvironment>::size_in_bits());
+            assert_eq!(expected.to_bits_le(), lower_bits_le);
+            assert!(upper_bits_le.iter().all(|bit| !bit));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_u256_from_field() -> Result<()> {
+        type I = u256;
+        check_from_field_wide::<I>()
+    }
+
+    #[test]
+    fn test_i256_from_field() -> Result<()> {
+        type I = i256;
+        check_from_field_wide::<I>()
+    }
 }
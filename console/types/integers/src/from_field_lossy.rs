@@ -20,14 +20,11 @@ impl<E: Environment, I: IntegerType> Integer<E, I> {
     /// This method is commonly-used by hash-to-integer algorithms,
     /// where the hash output does not need to preserve the full base field.
     pub fn from_field_lossy(field: &Field<E>) -> Self {
-        // Note: We are reconstituting the integer from the base field.
-        // This is safe as the number of bits in the integer is less than the base field modulus,
-        // and thus will always fit within a single base field element.
-        debug_assert!(I::BITS < Field::<E>::size_in_bits() as u64);
-
         // Truncate the field to the size of the integer domain.
-        // Slicing here is safe as the base field is larger than the integer domain.
-        let result = Self::from_bits_le(&field.to_bits_le()[..usize::try_from(I::BITS).unwrap()]);
+        // If the integer is wider than the base field, the bits are zero-extended instead.
+        let mut bits_le = field.to_bits_le();
+        bits_le.resize(Self::size_in_bits(), false);
+        let result = Self::from_bits_le(&bits_le);
         debug_assert!(result.is_ok(), "A lossy integer should always be able to be constructed from field bits");
         result.unwrap()
     }
@@ -120,4 +117,32 @@ mod tests {
         type I = i128;
         check_from_field_lossy::<I>()
     }
+
+    fn check_from_field_lossy_wide<I: IntegerType>() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random field.
+            let expected = Field::<CurrentEnvironment>::rand(&mut rng);
+
+            // Perform the operation.
+            let candidate = Integer::<CurrentEnvironment, I>::from_field_lossy(&expected);
+
+            // Ensure the operation is lossless, as the integer is wider than the base field.
+            assert_eq!(Integer::<CurrentEnvironment, I>::from_field(&expected)?, candidate);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_u256_from_field_lossy() -> Result<()> {
+        type I = u256;
+        check_from_field_lossy_wide::<I>()
+    }
+
+    #[test]
+    fn test_i256_from_field_lossy() -> Result<()> {
+        type I = i256;
+        check_from_field_lossy_wide::<I>()
+    }
 }
@@ -19,10 +19,35 @@ impl<E: Environment, I: IntegerType> FromFields for Integer<E, I> {
 
     /// Initializes a new integer by recovering the **x-coordinate** of an affine group from a field element.
     fn from_fields(fields: &[Self::Field]) -> Result<Self> {
-        // Ensure there is exactly one field element in the vector.
-        ensure!(fields.len() == 1, "Integer must be recovered from a single field element");
-        // Recover the integer from the field element.
-        Self::from_field(&fields[0])
+        // Retrieve the data size of the base field.
+        let size_in_data_bits = Field::<E>::size_in_data_bits();
+
+        if I::BITS < size_in_data_bits as u64 {
+            // Ensure there is exactly one field element in the vector.
+            ensure!(fields.len() == 1, "Integer must be recovered from a single field element");
+            // Recover the integer from the field element.
+            Self::from_field(&fields[0])
+        } else {
+            // Ensure the number of field elements matches the packed size of the integer.
+            let num_fields = (Self::size_in_bits() + size_in_data_bits - 1) / size_in_data_bits;
+            ensure!(fields.len() == num_fields, "Integer must be recovered from {num_fields} field elements");
+
+            // Unpack the bits of the integer from the field elements.
+            let mut bits_le = Vec::with_capacity(num_fields * size_in_data_bits);
+            for field in fields {
+                let field_bits = field.to_bits_le();
+                let (data_bits, zero_bits) = field_bits.split_at(size_in_data_bits);
+                ensure!(zero_bits.iter().all(|&bit| !bit), "Failed to unpack integer: upper bits are not zero");
+                bits_le.extend_from_slice(data_bits);
+            }
+
+            // Ensure the unused upper bits are all zero.
+            let (bits_le, zero_bits) = bits_le.split_at(Self::size_in_bits());
+            ensure!(zero_bits.iter().all(|&bit| !bit), "Failed to unpack integer: upper bits are not zero");
+
+            // Recover the integer from the bits.
+            Self::from_bits_le(bits_le)
+        }
     }
 }
 
@@ -108,4 +133,16 @@ mod tests {
         type I = i128;
         check_from_fields::<I>()
     }
+
+    #[test]
+    fn test_u256_from_fields() -> Result<()> {
+        type I = u256;
+        check_from_fields::<I>()
+    }
+
+    #[test]
+    fn test_i256_from_fields() -> Result<()> {
+        type I = i256;
+        check_from_fields::<I>()
+    }
 }
@@ -47,12 +47,14 @@ pub type I16<E> = Integer<E, i16>;
 pub type I32<E> = Integer<E, i32>;
 pub type I64<E> = Integer<E, i64>;
 pub type I128<E> = Integer<E, i128>;
+pub type I256<E> = Integer<E, i256>;
 
 pub type U8<E> = Integer<E, u8>;
 pub type U16<E> = Integer<E, u16>;
 pub type U32<E> = Integer<E, u32>;
 pub type U64<E> = Integer<E, u64>;
 pub type U128<E> = Integer<E, u128>;
+pub type U256<E> = Integer<E, u256>;
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Integer<E: Environment, I: IntegerType> {
@@ -71,7 +73,9 @@ impl<E: Environment, I: IntegerType> Visibility for Integer<E, I> {
 
     /// Returns the number of field elements to encode `self`.
     fn size_in_fields(&self) -> Result<u16> {
-        Ok(1)
+        // Note: The integer is packed into field elements of `Field::size_in_data_bits` bits each.
+        let size_in_data_bits = Field::<E>::size_in_data_bits() as u64;
+        Ok(u16::try_from((I::BITS + size_in_data_bits - 1) / size_in_data_bits)?)
     }
 }
 
@@ -113,12 +113,14 @@ mod tests {
         check_display::<CurrentEnvironment, u32>(&mut rng);
         check_display::<CurrentEnvironment, u64>(&mut rng);
         check_display::<CurrentEnvironment, u128>(&mut rng);
+        check_display::<CurrentEnvironment, u256>(&mut rng);
 
         check_display::<CurrentEnvironment, i8>(&mut rng);
         check_display::<CurrentEnvironment, i16>(&mut rng);
         check_display::<CurrentEnvironment, i32>(&mut rng);
         check_display::<CurrentEnvironment, i64>(&mut rng);
         check_display::<CurrentEnvironment, i128>(&mut rng);
+        check_display::<CurrentEnvironment, i256>(&mut rng);
     }
 
     #[test]
@@ -54,9 +54,11 @@ mod tests {
         check_random::<u32>(&mut rng);
         check_random::<u64>(&mut rng);
         check_random::<u128>(&mut rng);
+        check_random::<u256>(&mut rng);
 
         check_random::<i32>(&mut rng);
         check_random::<i64>(&mut rng);
         check_random::<i128>(&mut rng);
+        check_random::<i256>(&mut rng);
     }
 }
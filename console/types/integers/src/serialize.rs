@@ -85,12 +85,14 @@ mod tests {
         check_serde_json::<u32>(&mut rng)?;
         check_serde_json::<u64>(&mut rng)?;
         check_serde_json::<u128>(&mut rng)?;
+        check_serde_json::<u256>(&mut rng)?;
 
         check_serde_json::<i8>(&mut rng)?;
         check_serde_json::<i16>(&mut rng)?;
         check_serde_json::<i32>(&mut rng)?;
         check_serde_json::<i64>(&mut rng)?;
         check_serde_json::<i128>(&mut rng)?;
+        check_serde_json::<i256>(&mut rng)?;
         Ok(())
     }
 
@@ -103,12 +105,14 @@ mod tests {
         check_bincode::<u32>(&mut rng)?;
         check_bincode::<u64>(&mut rng)?;
         check_bincode::<u128>(&mut rng)?;
+        check_bincode::<u256>(&mut rng)?;
 
         check_bincode::<i8>(&mut rng)?;
         check_bincode::<i16>(&mut rng)?;
         check_bincode::<i32>(&mut rng)?;
         check_bincode::<i64>(&mut rng)?;
         check_bincode::<i128>(&mut rng)?;
+        check_bincode::<i256>(&mut rng)?;
         Ok(())
     }
 }
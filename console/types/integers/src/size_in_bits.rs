@@ -18,7 +18,7 @@ impl<E: Environment, I: IntegerType> SizeInBits for Integer<E, I> {
     /// Returns the integer size in bits.
     #[inline]
     fn size_in_bits() -> usize {
-        // Note: `unwrap()` is safe as the integer is at most 128 bits.
+        // Note: `unwrap()` is safe as the integer is at most 256 bits.
         usize::try_from(I::BITS).unwrap()
     }
 }
@@ -18,12 +18,13 @@ impl<E: Environment, I: IntegerType> ToField for Integer<E, I> {
     type Field = Field<E>;
 
     /// Converts an integer into a field element.
+    ///
+    /// This method fails if the integer is larger than the base field modulus,
+    /// which is only possible for integers wider than the base field (i.e. `u256`, `i256`).
     fn to_field(&self) -> Result<Self::Field> {
         // Note: We are reconstituting the integer as a base field.
-        // This is safe as the number of bits in the integer is less than the base field modulus,
-        // and thus will always fit within a single base field element.
-        debug_assert!(I::BITS < Field::<E>::size_in_bits() as u64);
-
+        // For integers narrower than the base field, this will always fit within a single base field element.
+        // Otherwise, `Field::from_bits_le` ensures the integer is less than the base field modulus.
         // Reconstruct the bits as a linear combination representing the original field value.
         Field::from_bits_le(&self.to_bits_le())
     }
@@ -126,4 +127,34 @@ mod tests {
         type I = i128;
         check_to_field::<I>()
     }
+
+    fn check_to_field_wide<I: IntegerType>() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random field, and recover it as an integer.
+            let expected = Field::<CurrentEnvironment>::rand(&mut rng);
+            let integer = Integer::<CurrentEnvironment, I>::from_field(&expected)?;
+
+            // Perform the operation.
+            let candidate = integer.to_field()?;
+            assert_eq!(expected, candidate);
+        }
+
+        // Ensure integers that exceed the base field modulus fail.
+        assert!(Integer::<CurrentEnvironment, I>::MAX.to_field().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_u256_to_field() -> Result<()> {
+        type I = u256;
+        check_to_field_wide::<I>()
+    }
+
+    #[test]
+    fn test_i256_to_field() -> Result<()> {
+        type I = i256;
+        check_to_field_wide::<I>()
+    }
 }
@@ -18,8 +18,14 @@ impl<E: Environment, I: IntegerType> ToFields for Integer<E, I> {
     type Field = Field<E>;
 
     /// Returns the integer as field elements.
+    ///
+    /// Integers that are wider than the data capacity of the base field (i.e. `u256`, `i256`)
+    /// are packed, in little-endian order, into `Field::size_in_data_bits` bits per field element.
     fn to_fields(&self) -> Result<Vec<Self::Field>> {
-        Ok(vec![self.to_field()?])
+        match I::BITS < Field::<E>::size_in_data_bits() as u64 {
+            true => Ok(vec![self.to_field()?]),
+            false => self.to_bits_le().chunks(Field::<E>::size_in_data_bits()).map(Field::from_bits_le).collect(),
+        }
     }
 }
 
@@ -120,4 +126,39 @@ mod tests {
         type I = i128;
         check_to_fields::<I>()
     }
+
+    fn check_to_fields_wide<I: IntegerType>() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random integer.
+            let expected = Integer::<CurrentEnvironment, I>::rand(&mut rng);
+
+            // Perform the operation.
+            let candidate = expected.to_fields()?;
+            assert_eq!(usize::from(expected.size_in_fields()?), candidate.len());
+
+            // Ensure the integer bits are packed into the data bits of each field element.
+            let size_in_data_bits = Field::<CurrentEnvironment>::size_in_data_bits();
+            let expected_bits = expected.to_bits_le();
+            for (expected_bits, field) in expected_bits.chunks(size_in_data_bits).zip_eq(&candidate) {
+                let candidate_bits_le = field.to_bits_le();
+                assert_eq!(expected_bits, &candidate_bits_le[..expected_bits.len()]);
+                assert!(candidate_bits_le[expected_bits.len()..].iter().all(|bit| !bit));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_u256_to_fields() -> Result<()> {
+        type I = u256;
+        check_to_fields_wide::<I>()
+    }
+
+    #[test]
+    fn test_i256_to_fields() -> Result<()> {
+        type I = i256;
+        check_to_fields_wide::<I>()
+    }
 }
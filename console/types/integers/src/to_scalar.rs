@@ -16,14 +16,18 @@ use super::*;
 
 impl<E: Environment, I: IntegerType> Integer<E, I> {
     /// Converts an integer into a scalar.
+    ///
+    /// Integers that are wider than the data capacity of the scalar field (i.e. `u256`, `i256`)
+    /// are truncated to `Scalar::size_in_data_bits` bits.
     pub fn to_scalar(&self) -> Scalar<E> {
         // Note: We are reconstituting the integer as a scalar field.
-        // This is safe as the number of bits in the integer is less than the scalar field modulus,
+        // This is safe as the bits are truncated to be less than the scalar field modulus,
         // and thus will always fit within a single scalar field element.
-        debug_assert!(I::BITS < Scalar::<E>::size_in_bits() as u64);
+        let mut bits_le = self.to_bits_le();
+        bits_le.truncate(Scalar::<E>::size_in_data_bits());
 
         // Reconstruct the bits as a linear combination representing the original value.
-        let result = Scalar::from_bits_le(&self.to_bits_le());
+        let result = Scalar::from_bits_le(&bits_le);
         debug_assert!(result.is_ok(), "An integer should always be able to be constructed from scalar bits");
         result.unwrap()
     }
@@ -126,4 +130,35 @@ mod tests {
         type I = i128;
         check_to_scalar::<I>()
     }
+
+    fn check_to_scalar_wide<I: IntegerType>() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random integer.
+            let expected = Integer::<CurrentEnvironment, I>::rand(&mut rng);
+
+            // Perform the operation.
+            let candidate = expected.to_scalar();
+
+            // Ensure the integer is truncated to the data bits of the scalar field.
+            let size_in_data_bits = Scalar::<CurrentEnvironment>::size_in_data_bits();
+            let candidate_bits_le = candidate.to_bits_le();
+            assert_eq!(&expected.to_bits_le()[..size_in_data_bits], &candidate_bits_le[..size_in_data_bits]);
+            assert!(candidate_bits_le[size_in_data_bits..].iter().all(|bit| !bit));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_u256_to_scalar() -> Result<()> {
+        type I = u256;
+        check_to_scalar_wide::<I>()
+    }
+
+    #[test]
+    fn test_i256_to_scalar() -> Result<()> {
+        type I = i256;
+        check_to_scalar_wide::<I>()
+    }
 }
@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Implements a binary operator, and its assigning variant, for owned and borrowed operands.
+macro_rules! impl_binary_operator {
+    ($trait_:ident, $method:ident, $assign_trait:ident, $assign_method:ident, |$a:ident, $b:ident| $operation:expr) => {
+        impl<P: NonNativeParameters> $trait_<&NonNativeField<P>> for &NonNativeField<P> {
+            type Output = NonNativeField<P>;
+
+            #[inline]
+            fn $method(self, other: &NonNativeField<P>) -> Self::Output {
+                let ($a, $b) = (self, other);
+                $operation
+            }
+        }
+
+        impl<P: NonNativeParameters> $trait_<NonNativeField<P>> for NonNativeField<P> {
+            type Output = NonNativeField<P>;
+
+            #[inline]
+            fn $method(self, other: NonNativeField<P>) -> Self::Output {
+                (&self).$method(&other)
+            }
+        }
+
+        impl<P: NonNativeParameters> $trait_<&NonNativeField<P>> for NonNativeField<P> {
+            type Output = NonNativeField<P>;
+
+            #[inline]
+            fn $method(self, other: &NonNativeField<P>) -> Self::Output {
+                (&self).$method(other)
+            }
+        }
+
+        impl<P: NonNativeParameters> $assign_trait<NonNativeField<P>> for NonNativeField<P> {
+            #[inline]
+            fn $assign_method(&mut self, other: NonNativeField<P>) {
+                *self = (&*self).$method(&other);
+            }
+        }
+
+        impl<P: NonNativeParameters> $assign_trait<&NonNativeField<P>> for NonNativeField<P> {
+            #[inline]
+            fn $assign_method(&mut self, other: &NonNativeField<P>) {
+                *self = (&*self).$method(other);
+            }
+        }
+    };
+}
+
+impl_binary_operator!(Add, add, AddAssign, add_assign, |a, b| NonNativeField::new(&a.value + &b.value));
+impl_binary_operator!(Sub, sub, SubAssign, sub_assign, |a, b| NonNativeField::new(&a.value + P::modulus() - &b.value));
+impl_binary_operator!(Mul, mul, MulAssign, mul_assign, |a, b| NonNativeField::new(&a.value * &b.value));
+impl_binary_operator!(Div, div, DivAssign, div_assign, |a, b| match b.inverse() {
+    Ok(inverse) => Mul::mul(a, &inverse),
+    Err(error) => panic!("{error}"),
+});
+
+impl<P: NonNativeParameters> Neg for NonNativeField<P> {
+    type Output = NonNativeField<P>;
+
+    /// Returns the `negation` of `self`.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        NonNativeField::zero() - self
+    }
+}
+
+impl<P: NonNativeParameters> NonNativeField<P> {
+    /// Returns the `double` of `self`.
+    #[inline]
+    pub fn double(&self) -> Self {
+        self + self
+    }
+
+    /// Returns the `square` of `self`.
+    #[inline]
+    pub fn square(&self) -> Self {
+        self * self
+    }
+
+    /// Returns the `inverse` of `self`.
+    #[inline]
+    pub fn inverse(&self) -> Result<Self> {
+        ensure!(!self.is_zero(), "Failed to invert a non-native field element: {self}");
+        // As the modulus is prime, the inverse is `self^(p - 2)`, by Fermat's little theorem.
+        let modulus = P::modulus();
+        Ok(Self::new(self.value.modpow(&(&modulus - 2u32), &modulus)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentField = Secp256k1Base;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_add_sub() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let a: CurrentField = Uniform::rand(&mut rng);
+            let b: CurrentField = Uniform::rand(&mut rng);
+
+            let sum = &a + &b;
+            assert_eq!(sum.value(), &((a.value() + b.value()) % Secp256k1BaseParameters::modulus()));
+            assert_eq!(a, &sum - &b);
+            assert_eq!(b, &sum - &a);
+            assert_eq!(CurrentField::zero(), &a + &(-a.clone()));
+            assert_eq!(a.double(), &a + &a);
+        }
+    }
+
+    #[test]
+    fn test_mul_inverse() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let a: CurrentField = Uniform::rand(&mut rng);
+            let b: CurrentField = Uniform::rand(&mut rng);
+
+            let product = &a * &b;
+            assert_eq!(product.value(), &((a.value() * b.value()) % Secp256k1BaseParameters::modulus()));
+            assert_eq!(a.square(), &a * &a);
+            assert_eq!(CurrentField::one(), &a * &a.inverse().unwrap());
+            assert_eq!(a, &product / &b);
+        }
+
+        // Ensure the inverse of zero fails.
+        assert!(CurrentField::zero().inverse().is_err());
+    }
+
+    #[test]
+    fn test_wraparound() {
+        let minus_one = -CurrentField::one();
+        assert_eq!(minus_one.value(), &(Secp256k1BaseParameters::modulus() - 1u32));
+        assert_eq!(CurrentField::zero(), &minus_one + &CurrentField::one());
+        assert_eq!(CurrentField::one(), &minus_one * &minus_one);
+    }
+}
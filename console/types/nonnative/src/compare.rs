@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<P: NonNativeParameters> PartialOrd for NonNativeField<P> {
+    /// Compares the canonical representatives of `self` and `other`.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: NonNativeParameters> Ord for NonNativeField<P> {
+    /// Compares the canonical representatives of `self` and `other`.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentField = Bn254Scalar;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_compare() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let a: CurrentField = Uniform::rand(&mut rng);
+            let b: CurrentField = Uniform::rand(&mut rng);
+            assert_eq!(a.value().cmp(b.value()), a.cmp(&b));
+        }
+        assert!(CurrentField::zero() < CurrentField::one());
+        assert!(-CurrentField::one() > CurrentField::one());
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<P: NonNativeParameters> FromBits for NonNativeField<P> {
+    /// Initializes a new field element from a list of little-endian bits.
+    ///   - If `bits_le` is longer than the field size in bits, the excess bits must be zero.
+    ///   - The value must be less than the modulus.
+    fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        // Ensure the excess bits are zero.
+        if let Some(excess) = bits_le.get(P::SIZE_IN_BITS..) {
+            ensure!(excess.iter().all(|bit| !bit), "The excess bits of a non-native field element must be zero");
+        }
+        // Ensure the value is less than the modulus.
+        let value = Self::biguint_from_bits_le(bits_le);
+        ensure!(value < P::modulus(), "The non-native field element must be less than the modulus");
+        Ok(Self::new(value))
+    }
+
+    /// Initializes a new field element from a list of big-endian bits *without* leading zeros.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        let mut bits_le = bits_be.to_vec();
+        bits_le.reverse();
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+impl<P: NonNativeParameters> NonNativeField<P> {
+    /// Initializes a new field element from a list of little-endian bits of any length, reduced modulo the modulus.
+    pub fn from_bits_le_mod_order(bits_le: &[bool]) -> Self {
+        Self::new(Self::biguint_from_bits_le(bits_le))
+    }
+
+    /// Returns the integer of the given little-endian bits.
+    fn biguint_from_bits_le(bits_le: &[bool]) -> BigUint {
+        let mut value = BigUint::from(0u64);
+        for (i, bit) in bits_le.iter().enumerate() {
+            value.set_bit(i as u64, *bit);
+        }
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentField = Bn254Base;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_from_bits() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let field: CurrentField = Uniform::rand(&mut rng);
+
+            // Ensure the bits round-trip, with or without excess zeros.
+            let mut bits_le = field.to_bits_le();
+            assert_eq!(field, CurrentField::from_bits_le(&bits_le).unwrap());
+            bits_le.extend([false; 8]);
+            assert_eq!(field, CurrentField::from_bits_le(&bits_le).unwrap());
+
+            // Ensure excess ones are rejected, and reduced in the modular variant.
+            bits_le.push(true);
+            assert!(CurrentField::from_bits_le(&bits_le).is_err());
+            let expected = CurrentField::new(field.value() + (BigUint::from(1u64) << (bits_le.len() - 1)));
+            assert_eq!(expected, CurrentField::from_bits_le_mod_order(&bits_le));
+        }
+
+        // Ensure the modulus itself is rejected.
+        let modulus = Bn254BaseParameters::modulus();
+        let bits_le = (0..modulus.bits()).map(|i| modulus.bit(i)).collect::<Vec<_>>();
+        assert!(CurrentField::from_bits_le(&bits_le).is_err());
+        assert!(CurrentField::from_bits_le_mod_order(&bits_le).is_zero());
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(test, allow(clippy::assertions_on_result_states))]
+#![warn(clippy::cast_possible_truncation)]
+
+mod arithmetic;
+mod compare;
+mod from_bits;
+mod one;
+mod parameters;
+mod parse;
+mod random;
+mod size_in_bits;
+mod to_bits;
+mod zero;
+
+pub use parameters::*;
+
+pub use num_bigint::BigUint;
+pub use snarkvm_console_network_environment::prelude::*;
+
+use core::marker::PhantomData;
+
+/// An element of a prime field whose modulus differs from the native field, i.e. a *non-native* field.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct NonNativeField<P: NonNativeParameters> {
+    /// The canonical representative of the element, in `[0, P::modulus())`.
+    value: BigUint,
+    /// The parameters of the field.
+    _parameters: PhantomData<P>,
+}
+
+impl<P: NonNativeParameters> NonNativeField<P> {
+    /// The field size in bits.
+    pub const SIZE_IN_BITS: usize = P::SIZE_IN_BITS;
+
+    /// Initializes a new field element from the given integer, reduced modulo the field modulus.
+    pub fn new(value: BigUint) -> Self {
+        Self { value: value % P::modulus(), _parameters: PhantomData }
+    }
+
+    /// Initializes a new field element from a `u64`.
+    pub fn from_u64(value: u64) -> Self {
+        Self::new(BigUint::from(value))
+    }
+
+    /// Initializes a new field element from the given little-endian bytes, reduced modulo the field modulus.
+    pub fn from_bytes_le_mod_order(bytes: &[u8]) -> Self {
+        Self::new(BigUint::from_bytes_le(bytes))
+    }
+
+    /// Returns the canonical representative of the field element.
+    pub const fn value(&self) -> &BigUint {
+        &self.value
+    }
+
+    /// Returns the little-endian `u64` limbs of the field element, padded with zeros to `num_limbs` limbs.
+    pub fn to_limbs_le(&self, num_limbs: usize) -> Vec<u64> {
+        let mut limbs = self.value.to_u64_digits();
+        limbs.resize(num_limbs, 0);
+        limbs
+    }
+}
+
+impl<P: NonNativeParameters> Default for NonNativeField<P> {
+    /// Returns the default field element.
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: NonNativeParameters> TypeName for NonNativeField<P> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        P::TYPE_NAME
+    }
+}
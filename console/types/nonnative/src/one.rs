@@ -0,0 +1,36 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<P: NonNativeParameters> One for NonNativeField<P> {
+    /// Returns the `1` element of the field.
+    fn one() -> Self {
+        Self::new(BigUint::from(1u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentField = Ed25519Base;
+
+    #[test]
+    fn test_one() {
+        let one = CurrentField::one();
+        assert!(one.is_one());
+        assert_eq!(vec![1u64, 0, 0, 0], one.to_limbs_le(4));
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The parameters of a non-native field.
+pub trait NonNativeParameters:
+    'static + Copy + Clone + Debug + PartialEq + Eq + core::hash::Hash + Send + Sync
+{
+    /// The type name of the field, used as the literal suffix (i.e. `7secp256k1_base`).
+    const TYPE_NAME: &'static str;
+    /// The modulus of the field, as little-endian `u64` limbs.
+    const MODULUS: &'static [u64];
+    /// The number of bits in the modulus.
+    const SIZE_IN_BITS: usize;
+
+    /// Returns the modulus of the field.
+    fn modulus() -> BigUint {
+        BigUint::from_bytes_le(&Self::MODULUS.iter().flat_map(|limb| limb.to_le_bytes()).collect::<Vec<_>>())
+    }
+}
+
+/// Declares the parameters of a non-native field, and a type alias for the field.
+macro_rules! non_native_parameters {
+    ($(#[$doc:meta])* $parameters:ident, $field:ident, $type_name:expr, $size_in_bits:expr, $modulus:expr) => {
+        $(#[$doc])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub struct $parameters;
+
+        impl NonNativeParameters for $parameters {
+            const MODULUS: &'static [u64] = &$modulus;
+            const SIZE_IN_BITS: usize = $size_in_bits;
+            const TYPE_NAME: &'static str = $type_name;
+        }
+
+        $(#[$doc])*
+        pub type $field = NonNativeField<$parameters>;
+    };
+}
+
+non_native_parameters!(
+    /// The base field of secp256k1, with modulus `2^256 - 2^32 - 977`.
+    Secp256k1BaseParameters,
+    Secp256k1Base,
+    "secp256k1_base",
+    256,
+    [0xfffffffefffffc2f, 0xffffffffffffffff, 0xffffffffffffffff, 0xffffffffffffffff]
+);
+
+non_native_parameters!(
+    /// The scalar field of secp256k1.
+    Secp256k1ScalarParameters,
+    Secp256k1Scalar,
+    "secp256k1_scalar",
+    256,
+    [0xbfd25e8cd0364141, 0xbaaedce6af48a03b, 0xfffffffffffffffe, 0xffffffffffffffff]
+);
+
+non_native_parameters!(
+    /// The base field of ed25519, with modulus `2^255 - 19`.
+    Ed25519BaseParameters,
+    Ed25519Base,
+    "ed25519_base",
+    255,
+    [0xffffffffffffffed, 0xffffffffffffffff, 0xffffffffffffffff, 0x7fffffffffffffff]
+);
+
+non_native_parameters!(
+    /// The scalar field of ed25519, with modulus `2^252 + 27742317777372353535851937790883648493`.
+    Ed25519ScalarParameters,
+    Ed25519Scalar,
+    "ed25519_scalar",
+    253,
+    [0x5812631a5cf5d3ed, 0x14def9dea2f79cd6, 0x0000000000000000, 0x1000000000000000]
+);
+
+non_native_parameters!(
+    /// The base field of BN254.
+    Bn254BaseParameters,
+    Bn254Base,
+    "bn254_base",
+    254,
+    [0x3c208c16d87cfd47, 0x97816a916871ca8d, 0xb85045b68181585d, 0x30644e72e131a029]
+);
+
+non_native_parameters!(
+    /// The scalar field of BN254.
+    Bn254ScalarParameters,
+    Bn254Scalar,
+    "bn254_scalar",
+    254,
+    [0x43e1f593f0000001, 0x2833e84879b97091, 0xb85045b68181585d, 0x30644e72e131a029]
+);
+
+non_native_parameters!(
+    /// The base field of BLS12-377.
+    Bls12_377BaseParameters,
+    Bls12_377Base,
+    "bls12_377_base",
+    377,
+    [
+        0x8508c00000000001,
+        0x170b5d4430000000,
+        0x1ef3622fba094800,
+        0x1a22d9f300f5138f,
+        0xc63b05c06ca1493b,
+        0x01ae3a4617c510ea
+    ]
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_parameters<P: NonNativeParameters>(expected: &str) {
+        let modulus = P::modulus();
+        assert_eq!(expected, modulus.to_string());
+        assert_eq!(P::SIZE_IN_BITS as u64, modulus.bits());
+        assert_eq!(P::MODULUS, modulus.to_u64_digits().as_slice());
+    }
+
+    #[test]
+    fn test_parameters() {
+        check_parameters::<Secp256k1BaseParameters>(
+            "115792089237316195423570985008687907853269984665640564039457584007908834671663",
+        );
+        check_parameters::<Secp256k1ScalarParameters>(
+            "115792089237316195423570985008687907852837564279074904382605163141518161494337",
+        );
+        check_parameters::<Ed25519BaseParameters>(
+            "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+        );
+        check_parameters::<Ed25519ScalarParameters>(
+            "7237005577332262213973186563042994240857116359379907606001950938285454250989",
+        );
+        check_parameters::<Bn254BaseParameters>(
+            "21888242871839275222246405745257275088696311157297823662689037894645226208583",
+        );
+        check_parameters::<Bn254ScalarParameters>(
+            "21888242871839275222246405745257275088548364400416034343698204186575808495617",
+        );
+        check_parameters::<Bls12_377BaseParameters>(
+            "258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458177",
+        );
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<P: NonNativeParameters> Parser for NonNativeField<P> {
+    /// Parses a string into a non-native field element.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the optional negative sign '-' from the string.
+        let (string, negation) = map(opt(tag("-")), |neg: Option<&str>| neg.is_some())(string)?;
+        // Parse the digits from the string.
+        let (string, primitive) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
+        // Parse the value from the string.
+        let (string, value): (&str, BigUint) =
+            map_res(tag(Self::type_name()), |_| primitive.replace('_', "").parse())(string)?;
+        // Negate the value if the negative sign was present.
+        let value = match negation {
+            true => -Self::new(value),
+            false => Self::new(value),
+        };
+
+        Ok((string, value))
+    }
+}
+
+impl<P: NonNativeParameters> FromStr for NonNativeField<P> {
+    type Err = Error;
+
+    /// Parses a string into a non-native field element.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<P: NonNativeParameters> Debug for NonNativeField<P> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<P: NonNativeParameters> Display for NonNativeField<P> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.value, Self::type_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentField = Secp256k1Base;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        // Ensure empty value fails.
+        assert!(CurrentField::parse(CurrentField::type_name()).is_err());
+        assert!(CurrentField::parse("").is_err());
+
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random value.
+            let field: CurrentField = Uniform::rand(&mut rng);
+
+            let expected = format!("{field}");
+            let (remainder, candidate) = CurrentField::parse(&expected).unwrap();
+            assert_eq!(expected, candidate.to_string());
+            assert_eq!("", remainder);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("7secp256k1_base", CurrentField::from_u64(7).to_string());
+        assert_eq!(-CurrentField::from_u64(7), CurrentField::from_str("-7secp256k1_base").unwrap());
+        // Ensure values are reduced modulo the modulus.
+        let modulus = Secp256k1BaseParameters::modulus();
+        assert_eq!(CurrentField::one(), CurrentField::from_str(&format!("{}secp256k1_base", modulus + 1u32)).unwrap());
+        // Ensure the type suffix is checked.
+        assert!(CurrentField::from_str("7bn254_base").is_err());
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<P: NonNativeParameters> Distribution<NonNativeField<P>> for Standard {
+    /// Samples a random field element, by reducing twice as many random bits as in the modulus.
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NonNativeField<P> {
+        let bytes = (0..2 * ((P::SIZE_IN_BITS + 7) / 8)).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+        NonNativeField::from_bytes_le_mod_order(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    type CurrentField = Secp256k1Scalar;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_random() {
+        // Initialize a set to store all seen random elements.
+        let mut set = HashSet::with_capacity(ITERATIONS);
+
+        let mut rng = TestRng::default();
+
+        // Note: This test technically has a `(1 + 2 + ... + ITERATIONS) / MODULUS` probability of being flaky.
+        for _ in 0..ITERATIONS {
+            // Sample a random value.
+            let field: CurrentField = Uniform::rand(&mut rng);
+            assert!(field.value() < &Secp256k1ScalarParameters::modulus());
+            assert!(!set.contains(&field));
+
+            // Add the new random value to the set.
+            set.insert(field);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<P: NonNativeParameters> ToBits for NonNativeField<P> {
+    /// Outputs the little-endian bit representation of `self`, padded to the field size in bits.
+    fn write_bits_le(&self, vec: &mut Vec<bool>) {
+        vec.extend((0..P::SIZE_IN_BITS as u64).map(|i| self.value.bit(i)));
+    }
+
+    /// Outputs the big-endian bit representation of `self`, padded to the field size in bits.
+    fn write_bits_be(&self, vec: &mut Vec<bool>) {
+        vec.extend((0..P::SIZE_IN_BITS as u64).rev().map(|i| self.value.bit(i)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentField = Ed25519Scalar;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_to_bits() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let field: CurrentField = Uniform::rand(&mut rng);
+
+            let bits_le = field.to_bits_le();
+            assert_eq!(CurrentField::size_in_bits(), bits_le.len());
+            assert_eq!(field, CurrentField::from_bits_le(&bits_le).unwrap());
+
+            let mut bits_be = field.to_bits_be();
+            bits_be.reverse();
+            assert_eq!(bits_le, bits_be);
+        }
+    }
+}
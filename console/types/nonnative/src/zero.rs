@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<P: NonNativeParameters> Zero for NonNativeField<P> {
+    /// Returns the `0` element of the field.
+    fn zero() -> Self {
+        Self::new(BigUint::from(0u64))
+    }
+
+    /// Returns `true` if the element is zero.
+    fn is_zero(&self) -> bool {
+        self.value == BigUint::from(0u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentField = Ed25519Base;
+
+    #[test]
+    fn test_zero() {
+        let zero = CurrentField::zero();
+        assert!(zero.is_zero());
+        assert_eq!(vec![0u64; 4], zero.to_limbs_le(4));
+        assert!(!CurrentField::one().is_zero());
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> Concat for StringType<E> {
+    type Output = Self;
+
+    /// Returns the concatenation of `self` and `other`.
+    fn concat(&self, other: &Self) -> Self::Output {
+        // Note: This halts if the concatenated string exceeds the allowed capacity.
+        StringType::new(&format!("{}{}", self.string, other.string))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_concat() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let a: StringType<CurrentEnvironment> = Uniform::rand(&mut rng);
+            let b: StringType<CurrentEnvironment> = Uniform::rand(&mut rng);
+
+            let candidate = a.concat(&b);
+            assert_eq!(format!("{}{}", &*a, &*b), *candidate);
+            assert_eq!(a.len() + b.len(), candidate.len());
+        }
+    }
+
+    #[test]
+    fn test_concat_exceeds_capacity_halts() {
+        let half = "a".repeat(CurrentEnvironment::MAX_STRING_BYTES as usize / 2 + 1);
+        let string = StringType::<CurrentEnvironment>::new(&half);
+
+        let result = std::panic::catch_unwind(|| string.concat(&string));
+        assert!(result.is_err());
+    }
+}
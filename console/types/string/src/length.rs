@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> Length for StringType<E> {
+    type Output = U32<E>;
+
+    /// Returns the length of the string in bytes.
+    fn length(&self) -> Self::Output {
+        U32::new(u32::try_from(self.string.len()).unwrap_or_else(|error| E::halt(error.to_string())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_length() {
+        let empty = StringType::<CurrentEnvironment>::new("");
+        assert_eq!(0, *empty.length());
+
+        let ascii = StringType::<CurrentEnvironment>::new("hello world");
+        assert_eq!(11, *ascii.length());
+
+        // Note: The length is measured in bytes, not characters.
+        let unicode = StringType::<CurrentEnvironment>::new("héllo");
+        assert_eq!(6, *unicode.length());
+    }
+}
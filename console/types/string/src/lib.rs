@@ -17,14 +17,18 @@
 
 mod bitwise;
 mod bytes;
+mod concat;
+mod length;
+mod padded_bytes;
 mod parse;
 mod random;
 mod serialize;
+mod slice;
 
 pub use snarkvm_console_network_environment::prelude::*;
 pub use snarkvm_console_types_boolean::Boolean;
 pub use snarkvm_console_types_field::Field;
-pub use snarkvm_console_types_integers::Integer;
+pub use snarkvm_console_types_integers::{Integer, U32, U8};
 
 use core::marker::PhantomData;
 
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the bytes of the string, padded with trailing zeros to `num_bytes`.
+    pub fn to_padded_bytes(&self, num_bytes: usize) -> Result<Vec<U8<E>>> {
+        // Ensure the string fits within the given number of bytes.
+        ensure!(self.len() <= num_bytes, "Cannot fit a string of {} bytes into {num_bytes} bytes", self.len());
+        // Pad the bytes with trailing zeros.
+        let mut bytes: Vec<_> = self.as_bytes().iter().map(|byte| U8::new(*byte)).collect();
+        bytes.resize(num_bytes, U8::zero());
+        Ok(bytes)
+    }
+
+    /// Initializes a new string from the given bytes, stripping any trailing zeros.
+    pub fn from_padded_bytes(bytes: &[U8<E>]) -> Result<Self> {
+        // Determine the number of bytes, excluding the trailing zeros.
+        let num_bytes = bytes.iter().rposition(|byte| **byte != 0).map_or(0, |index| index + 1);
+        // Ensure the string is within the allowed capacity.
+        ensure!(num_bytes <= E::MAX_STRING_BYTES as usize, "Attempted to allocate a string of size {num_bytes}");
+        // Ensure the bytes are valid UTF-8.
+        let string = String::from_utf8(bytes[..num_bytes].iter().map(|byte| **byte).collect())?;
+        Ok(Self::new(&string))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_padded_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let expected: StringType<CurrentEnvironment> = Uniform::rand(&mut rng);
+
+            // Ensure the string is padded to the requested size, and recovered without the padding.
+            for num_bytes in [expected.len(), expected.len() + 1, expected.len() + 32] {
+                let bytes = expected.to_padded_bytes(num_bytes)?;
+                assert_eq!(num_bytes, bytes.len());
+                assert_eq!(expected, StringType::from_padded_bytes(&bytes)?);
+            }
+
+            // Ensure the string does not fit in fewer bytes.
+            assert!(expected.to_padded_bytes(expected.len() - 1).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_padded_bytes_invalid_utf8() {
+        let bytes = [U8::<CurrentEnvironment>::new(0xc3), U8::new(0x28), U8::new(0)];
+        assert!(StringType::from_padded_bytes(&bytes).is_err());
+    }
+}
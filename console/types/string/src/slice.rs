@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> Slice<U32<E>> for StringType<E> {
+    type Output = Self;
+
+    /// Returns the substring from byte index `start` (inclusive) to byte index `end` (exclusive).
+    /// This method halts if the range is out of bounds or does not lie on character boundaries.
+    fn slice(&self, start: &U32<E>, end: &U32<E>) -> Self::Output {
+        let (start, end) = (**start as usize, **end as usize);
+        match start <= end {
+            true => match self.string.get(start..end) {
+                Some(substring) => StringType::new(substring),
+                None => E::halt(format!("Attempted to slice a string of size {} at {start}..{end}", self.len())),
+            },
+            false => E::halt(format!("Attempted to slice a string with an invalid range {start}..{end}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_slice() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let string: StringType<CurrentEnvironment> = Uniform::rand(&mut rng);
+
+            // Sample a valid range. Note: Random strings are alphanumeric, so every index is a character boundary.
+            let end = rng.gen_range(0..=*string.length());
+            let start = rng.gen_range(0..=end);
+
+            let candidate = string.slice(&U32::new(start), &U32::new(end));
+            assert_eq!(&string[start as usize..end as usize], &*candidate);
+        }
+    }
+
+    #[test]
+    fn test_slice_halts() {
+        let string = StringType::<CurrentEnvironment>::new("héllo");
+
+        // Ensure a range past the end halts.
+        assert!(std::panic::catch_unwind(|| string.slice(&U32::new(0), &U32::new(7))).is_err());
+        // Ensure a reversed range halts.
+        assert!(std::panic::catch_unwind(|| string.slice(&U32::new(3), &U32::new(2))).is_err());
+        // Ensure a range that splits a character halts.
+        assert!(std::panic::catch_unwind(|| string.slice(&U32::new(0), &U32::new(2))).is_err());
+        // Ensure a range on character boundaries succeeds.
+        assert_eq!("é", &*string.slice(&U32::new(1), &U32::new(3)));
+    }
+}
@@ -34,6 +34,27 @@ pub struct PoseidonParameters<F: PrimeField, const RATE: usize, const CAPACITY:
     pub mds: Vec<Vec<F>>,
 }
 
+/// Parameters used by the Poseidon2 permutation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Poseidon2Parameters<F: PrimeField, const RATE: usize, const CAPACITY: usize> {
+    /// number of rounds in a full-round operation
+    pub full_rounds: usize,
+    /// number of rounds in a partial-round operation
+    pub partial_rounds: usize,
+    /// Exponent used in S-boxes
+    pub alpha: u64,
+    /// Additive round keys for the full rounds, which are added to every state element.
+    /// They are indexed by `external_ark[round_num][state_element_index]`
+    pub external_ark: Vec<Vec<F>>,
+    /// Additive round keys for the partial rounds, which are added to the first state element.
+    /// They are indexed by `internal_ark[round_num]`
+    pub internal_ark: Vec<F>,
+    /// Maximally Distance Separating matrix, applied in the full rounds and before the first round.
+    pub external_mds: Vec<Vec<F>>,
+    /// Diagonal `D` of the matrix `M_I = J + D` applied in the partial rounds, where `J` is the all-ones matrix.
+    pub internal_diagonal: Vec<F>,
+}
+
 /// A field with Poseidon parameters associated
 pub trait PoseidonDefaultField {
     /// Obtain the default Poseidon parameters for this rate and for this prime field,
@@ -107,6 +128,95 @@ pub trait PoseidonDefaultField {
             None => bail!("No Poseidon parameters were found for this rate"),
         }
     }
+
+    /// Obtain the default Poseidon2 parameters for this rate and for this prime field.
+    ///
+    /// The number of rounds and the S-box exponent are those of the Poseidon parameters for this rate,
+    /// while the round keys and matrices are sampled from the Poseidon Grain LFSR, following the
+    /// [Poseidon2 paper](https://eprint.iacr.org/2023/323).
+    fn default_poseidon2_parameters<const RATE: usize>() -> Result<Poseidon2Parameters<Self, RATE, 1>>
+    where
+        Self: PrimeField,
+    {
+        /// Internal function that samples the round keys and matrices from the Poseidon Grain LFSR.
+        #[allow(clippy::type_complexity)]
+        fn find_poseidon2_ark_and_matrices<F: PrimeField, const RATE: usize>(
+            full_rounds: u64,
+            partial_rounds: u64,
+        ) -> Result<(Vec<Vec<F>>, Vec<F>, Vec<Vec<F>>, Vec<F>)> {
+            let lfsr_time = start_timer!(|| "LFSR Init");
+            let mut lfsr =
+                PoseidonGrainLFSR::new(false, F::size_in_bits() as u64, (RATE + 1) as u64, full_rounds, partial_rounds);
+            end_timer!(lfsr_time);
+
+            let ark_time = start_timer!(|| "Constructing ARK");
+            let mut external_ark = Vec::with_capacity(full_rounds as usize);
+            for _ in 0..full_rounds {
+                external_ark.push(lfsr.get_field_elements_rejection_sampling(RATE + 1)?);
+            }
+            let internal_ark = lfsr.get_field_elements_rejection_sampling(partial_rounds as usize)?;
+            end_timer!(ark_time);
+
+            // The external matrix is the Cauchy matrix `1 / (x[i] + y[j])`, which is MDS if
+            // there is no duplication among the elements in x or y, and there is no i and j such that x[i] + y[j] = p.
+            let xs = lfsr.get_field_elements_mod_p::<F>(RATE + 1)?;
+            let ys = lfsr.get_field_elements_mod_p::<F>(RATE + 1)?;
+            for i in 0..(RATE + 1) {
+                for j in 0..(RATE + 1) {
+                    if (i != j && (xs[i] == xs[j] || ys[i] == ys[j])) || (xs[i] + ys[j]).is_zero() {
+                        bail!("The sampled Poseidon2 external matrix is not MDS")
+                    }
+                }
+            }
+
+            let mds_time = start_timer!(|| "Construct MDS");
+            let mut mds_flattened = vec![F::zero(); (RATE + 1) * (RATE + 1)];
+            for (x, mds_row_i) in xs.iter().zip_eq(mds_flattened.chunks_mut(RATE + 1)) {
+                for (y, e) in ys.iter().zip_eq(mds_row_i) {
+                    *e = *x + y;
+                }
+            }
+            serial_batch_inversion_and_mul(&mut mds_flattened, &F::one());
+            let external_mds = mds_flattened.chunks(RATE + 1).map(|row| row.to_vec()).collect();
+            end_timer!(mds_time);
+
+            // The internal matrix `J + D` is invertible if every `D[i]` is nonzero and `1 + sum(1 / D[i])` is nonzero,
+            // as its determinant is `prod(D[i]) * (1 + sum(1 / D[i]))`.
+            let internal_diagonal = lfsr.get_field_elements_mod_p::<F>(RATE + 1)?;
+            let mut determinant_factor = F::one();
+            for element in internal_diagonal.iter() {
+                match element.inverse() {
+                    Some(inverse) => determinant_factor += inverse,
+                    None => bail!("The sampled Poseidon2 internal matrix is not invertible"),
+                }
+            }
+            if determinant_factor.is_zero() {
+                bail!("The sampled Poseidon2 internal matrix is not invertible")
+            }
+
+            Ok((external_ark, internal_ark, external_mds, internal_diagonal))
+        }
+
+        match Self::Parameters::PARAMS_OPT_FOR_CONSTRAINTS.iter().find(|entry| entry.rate == RATE) {
+            Some(entry) => {
+                let (external_ark, internal_ark, external_mds, internal_diagonal) =
+                    find_poseidon2_ark_and_matrices::<Self, RATE>(
+                        entry.full_rounds as u64,
+                        entry.partial_rounds as u64,
+                    )?;
+                Ok(Poseidon2Parameters {
+                    full_rounds: entry.full_rounds,
+                    partial_rounds: entry.partial_rounds,
+                    alpha: entry.alpha as u64,
+                    external_ark,
+                    internal_ark,
+                    external_mds,
+                    internal_diagonal,
+                })
+            }
+            None => bail!("No Poseidon2 parameters were found for this rate"),
+        }
+    }
 }
 
 /// A trait for default Poseidon parameters associated with a prime field
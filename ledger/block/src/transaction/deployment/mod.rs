@@ -56,10 +56,11 @@ impl<N: Network> Deployment<N> {
     pub fn check_is_ordered(&self) -> Result<()> {
         let program_id = self.program.id();
 
-        // Ensure the edition matches.
+        // Ensure the edition is at least the network edition.
+        // Note: An edition after the network edition is an upgrade of an existing program.
         ensure!(
-            self.edition == N::EDITION,
-            "Deployed the wrong edition (expected '{}', found '{}').",
+            self.edition >= N::EDITION,
+            "Deployed the wrong edition (expected at least '{}', found '{}').",
             N::EDITION,
             self.edition
         );
@@ -43,7 +43,7 @@ impl<N: Network> FromBytes for ConfirmedTransaction<N> {
                 let finalize =
                     (0..num_finalize).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
                 // Return the confirmed transaction.
-                Self::accepted_execute(index, transaction, finalize).map_err(error)
+                Self::accepted_execute(index, transaction, finalize, vec![]).map_err(error)
             }
             2 => {
                 // Read the index.
@@ -75,7 +75,28 @@ impl<N: Network> FromBytes for ConfirmedTransaction<N> {
                 // Return the confirmed transaction.
                 Self::rejected_execute(index, transaction, rejected, finalize).map_err(error)
             }
-            4.. => Err(error(format!("Failed to decode confirmed transaction variant {variant}"))),
+            4 => {
+                // Read the index.
+                let index = u32::read_le(&mut reader)?;
+                // Read the transaction.
+                let transaction = Transaction::<N>::read_le(&mut reader)?;
+                // Read the number of finalize operations.
+                let num_finalize = NumFinalizeSize::read_le(&mut reader)?;
+                // Read the finalize operations.
+                let finalize =
+                    (0..num_finalize).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                // Read the number of events.
+                let num_events = NumEventsSize::read_le(&mut reader)?;
+                // Ensure there are events, as accepted executions without events use variant 1.
+                if num_events == 0 {
+                    return Err(error("Failed to decode confirmed transaction variant 4 without events"));
+                }
+                // Read the events.
+                let events = (0..num_events).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                // Return the confirmed transaction.
+                Self::accepted_execute(index, transaction, finalize, events).map_err(error)
+            }
+            5.. => Err(error(format!("Failed to decode confirmed transaction variant {variant}"))),
         }
     }
 }
@@ -96,9 +117,13 @@ impl<N: Network> ToBytes for ConfirmedTransaction<N> {
                 // Write the finalize operations.
                 finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))
             }
-            Self::AcceptedExecute(index, transaction, finalize) => {
+            Self::AcceptedExecute(index, transaction, finalize, events) => {
                 // Write the variant.
-                1u8.write_le(&mut writer)?;
+                // Note: Variant 1 is preserved for accepted executions without events.
+                match events.is_empty() {
+                    true => 1u8.write_le(&mut writer)?,
+                    false => 4u8.write_le(&mut writer)?,
+                }
                 // Write the index.
                 index.write_le(&mut writer)?;
                 // Write the transaction.
@@ -106,7 +131,15 @@ impl<N: Network> ToBytes for ConfirmedTransaction<N> {
                 // Write the number of finalize operations.
                 NumFinalizeSize::try_from(finalize.len()).map_err(error)?.write_le(&mut writer)?;
                 // Write the finalize operations.
-                finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))
+                finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))?;
+                // Write the events, if there are any.
+                if !events.is_empty() {
+                    // Write the number of events.
+                    NumEventsSize::try_from(events.len()).map_err(error)?.write_le(&mut writer)?;
+                    // Write the events.
+                    events.iter().try_for_each(|event| event.write_le(&mut writer))?;
+                }
+                Ok(())
             }
             Self::RejectedDeploy(index, transaction, rejected, finalize) => {
                 // Write the variant.
@@ -152,4 +185,20 @@ mod tests {
             assert_eq!(expected, ConfirmedTransaction::read_le(&expected_bytes[..]).unwrap());
         }
     }
+
+    #[test]
+    fn test_bytes_rejects_events_variant_without_events() {
+        for expected in crate::transactions::confirmed::test_helpers::sample_confirmed_transactions() {
+            // Select the accepted executions without events.
+            if !matches!(&expected, ConfirmedTransaction::AcceptedExecute(_, _, _, events) if events.is_empty()) {
+                continue;
+            }
+            // Encode the accepted execution as variant 4, with zero events.
+            let mut bytes = expected.to_bytes_le().unwrap();
+            bytes[0] = 4;
+            bytes.extend(0u16.to_bytes_le().unwrap());
+            // Ensure the encoding is rejected.
+            assert!(ConfirmedTransaction::<console::network::MainnetV0>::read_le(&bytes[..]).is_err());
+        }
+    }
 }
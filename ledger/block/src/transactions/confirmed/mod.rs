@@ -18,17 +18,18 @@ mod string;
 
 use crate::{rejected::Rejected, Transaction};
 use console::{network::prelude::*, program::FINALIZE_ID_DEPTH, types::Field};
-use synthesizer_program::FinalizeOperation;
+use synthesizer_program::{Event, FinalizeOperation};
 
 pub type NumFinalizeSize = u16;
+pub type NumEventsSize = u16;
 
 /// The confirmed transaction.
 #[derive(Clone, PartialEq, Eq)]
 pub enum ConfirmedTransaction<N: Network> {
     /// The accepted deploy transaction is composed of `(index, deploy_transaction, finalize_operations)`.
     AcceptedDeploy(u32, Transaction<N>, Vec<FinalizeOperation<N>>),
-    /// The accepted execute transaction is composed of `(index, execute_transaction, finalize_operations)`.
-    AcceptedExecute(u32, Transaction<N>, Vec<FinalizeOperation<N>>),
+    /// The accepted execute transaction is composed of `(index, execute_transaction, finalize_operations, events)`.
+    AcceptedExecute(u32, Transaction<N>, Vec<FinalizeOperation<N>>, Vec<Event<N>>),
     /// The rejected deploy transaction is composed of `(index, fee_transaction, rejected_deployment, finalize_operations)`.
     RejectedDeploy(u32, Transaction<N>, Rejected<N>, Vec<FinalizeOperation<N>>),
     /// The rejected execute transaction is composed of `(index, fee_transaction, rejected_execution, finalize_operations)`.
@@ -97,6 +98,7 @@ impl<N: Network> ConfirmedTransaction<N> {
         index: u32,
         transaction: Transaction<N>,
         finalize_operations: Vec<FinalizeOperation<N>>,
+        events: Vec<Event<N>>,
     ) -> Result<Self> {
         // Ensure the finalize operations contain the correct types.
         for operation in finalize_operations.iter() {
@@ -112,9 +114,25 @@ impl<N: Network> ConfirmedTransaction<N> {
                 }
             }
         }
+        // Ensure the number of events is within bounds.
+        ensure!(
+            NumEventsSize::try_from(events.len()).is_ok(),
+            "Transaction '{}' (execute) contains too many events",
+            transaction.id()
+        );
+        // Ensure each event was emitted by a program in the transaction.
+        for event in events.iter() {
+            if !transaction.transitions().any(|transition| transition.program_id() == event.program_id()) {
+                bail!(
+                    "Transaction '{}' (execute) contains an event from an unknown program '{}'",
+                    transaction.id(),
+                    event.program_id()
+                )
+            }
+        }
         // Ensure the transaction is an execute transaction.
         match transaction.is_execute() {
-            true => Ok(Self::AcceptedExecute(index, transaction, finalize_operations)),
+            true => Ok(Self::AcceptedExecute(index, transaction, finalize_operations, events)),
             false => bail!("Transaction '{}' is not an execute transaction", transaction.id()),
         }
     }
@@ -225,7 +243,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub const fn transaction(&self) -> &Transaction<N> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => transaction,
-            Self::AcceptedExecute(_, transaction, _) => transaction,
+            Self::AcceptedExecute(_, transaction, _, _) => transaction,
             Self::RejectedDeploy(_, transaction, _, _) => transaction,
             Self::RejectedExecute(_, transaction, _, _) => transaction,
         }
@@ -235,7 +253,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub fn into_transaction(self) -> Transaction<N> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => transaction,
-            Self::AcceptedExecute(_, transaction, _) => transaction,
+            Self::AcceptedExecute(_, transaction, _, _) => transaction,
             Self::RejectedDeploy(_, transaction, _, _) => transaction,
             Self::RejectedExecute(_, transaction, _, _) => transaction,
         }
@@ -245,7 +263,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub fn num_finalize(&self) -> usize {
         match self {
             Self::AcceptedDeploy(_, _, finalize) => finalize.len(),
-            Self::AcceptedExecute(_, _, finalize) => finalize.len(),
+            Self::AcceptedExecute(_, _, finalize, _) => finalize.len(),
             Self::RejectedDeploy(_, _, _, finalize) => finalize.len(),
             Self::RejectedExecute(_, _, _, finalize) => finalize.len(),
         }
@@ -255,12 +273,20 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub const fn finalize_operations(&self) -> &Vec<FinalizeOperation<N>> {
         match self {
             Self::AcceptedDeploy(_, _, finalize) => finalize,
-            Self::AcceptedExecute(_, _, finalize) => finalize,
+            Self::AcceptedExecute(_, _, finalize, _) => finalize,
             Self::RejectedDeploy(_, _, _, finalize) => finalize,
             Self::RejectedExecute(_, _, _, finalize) => finalize,
         }
     }
 
+    /// Returns the events emitted by the confirmed transaction.
+    pub fn events(&self) -> &[Event<N>] {
+        match self {
+            Self::AcceptedExecute(_, _, _, events) => events,
+            Self::AcceptedDeploy(..) | Self::RejectedDeploy(..) | Self::RejectedExecute(..) => &[],
+        }
+    }
+
     /// Returns the finalize ID, by computing the root of a (small) Merkle tree comprised of
     /// the ordered finalize operations for the transaction.
     pub fn to_finalize_id(&self) -> Result<Field<N>> {
@@ -295,7 +321,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub fn to_unconfirmed_transaction_id(&self) -> Result<N::TransactionID> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => Ok(transaction.id()),
-            Self::AcceptedExecute(_, transaction, _) => Ok(transaction.id()),
+            Self::AcceptedExecute(_, transaction, _, _) => Ok(transaction.id()),
             Self::RejectedDeploy(_, fee_transaction, rejected, _)
             | Self::RejectedExecute(_, fee_transaction, rejected, _) => {
                 Ok(rejected.to_unconfirmed_id(&fee_transaction.fee_transition())?.into())
@@ -309,7 +335,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub fn to_unconfirmed_transaction(&self) -> Result<Transaction<N>> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => Ok(transaction.clone()),
-            Self::AcceptedExecute(_, transaction, _) => Ok(transaction.clone()),
+            Self::AcceptedExecute(_, transaction, _, _) => Ok(transaction.clone()),
             Self::RejectedDeploy(_, fee_transaction, rejected, _) => Transaction::from_deployment(
                 rejected
                     .program_owner()
@@ -338,7 +364,7 @@ impl<N: Network> Deref for ConfirmedTransaction<N> {
 #[cfg(test)]
 pub mod test_helpers {
     use super::*;
-    use console::network::MainnetV0;
+    use console::{network::MainnetV0, program::Plaintext};
 
     type CurrentNetwork = MainnetV0;
 
@@ -373,7 +399,27 @@ pub mod test_helpers {
         // Sample an execute transaction.
         let tx = crate::transaction::test_helpers::sample_execution_transaction_with_fee(is_fee_private, rng);
         // Return the confirmed transaction.
-        ConfirmedTransaction::accepted_execute(index, tx, vec![]).unwrap()
+        ConfirmedTransaction::accepted_execute(index, tx, vec![], vec![]).unwrap()
+    }
+
+    /// Samples an accepted execute transaction with events at the given index.
+    pub(crate) fn sample_accepted_execute_with_events(
+        index: u32,
+        is_fee_private: bool,
+        rng: &mut TestRng,
+    ) -> ConfirmedTransaction<CurrentNetwork> {
+        // Sample an execute transaction.
+        let tx = crate::transaction::test_helpers::sample_execution_transaction_with_fee(is_fee_private, rng);
+        // Retrieve the program ID and function name of the first transition.
+        let transition = tx.transitions().next().unwrap();
+        let (program_id, function_name) = (*transition.program_id(), *transition.function_name());
+        // Construct the events.
+        let events = vec![
+            Event::new(program_id, function_name, Plaintext::from_str("5u64").unwrap()),
+            Event::new(program_id, function_name, Plaintext::from_str("{ a: 1u8, b: true }").unwrap()),
+        ];
+        // Return the confirmed transaction.
+        ConfirmedTransaction::accepted_execute(index, tx, vec![], events).unwrap()
     }
 
     /// Samples a rejected deploy transaction at the given index.
@@ -423,6 +469,8 @@ pub mod test_helpers {
             sample_accepted_deploy(0, false, rng),
             sample_accepted_execute(1, true, rng),
             sample_accepted_execute(1, false, rng),
+            sample_accepted_execute_with_events(1, true, rng),
+            sample_accepted_execute_with_events(1, false, rng),
             sample_rejected_deploy(2, true, rng),
             sample_rejected_deploy(2, false, rng),
             sample_rejected_execute(3, true, rng),
@@ -459,20 +507,35 @@ mod test {
             FinalizeOperation::UpdateKeyValue(Uniform::rand(rng), Uniform::rand(rng), Uniform::rand(rng)),
             FinalizeOperation::RemoveKeyValue(Uniform::rand(rng), Uniform::rand(rng)),
         ];
-        let confirmed = ConfirmedTransaction::accepted_execute(index, tx.clone(), finalize_operations.clone()).unwrap();
+        let confirmed =
+            ConfirmedTransaction::accepted_execute(index, tx.clone(), finalize_operations.clone(), vec![]).unwrap();
 
         assert_eq!(confirmed.index(), index);
         assert_eq!(confirmed.transaction(), &tx);
         assert_eq!(confirmed.num_finalize(), finalize_operations.len());
         assert_eq!(confirmed.finalize_operations(), &finalize_operations);
+        assert!(confirmed.events().is_empty());
 
         // Attempt to create an `AcceptedExecution` with invalid `FinalizeOperation`s.
         let finalize_operations = vec![FinalizeOperation::InitializeMapping(Uniform::rand(rng))];
-        let confirmed = ConfirmedTransaction::accepted_execute(index, tx.clone(), finalize_operations);
+        let confirmed = ConfirmedTransaction::accepted_execute(index, tx.clone(), finalize_operations, vec![]);
         assert!(confirmed.is_err());
 
         let finalize_operations = vec![FinalizeOperation::RemoveMapping(Uniform::rand(rng))];
-        let confirmed = ConfirmedTransaction::accepted_execute(index, tx, finalize_operations);
+        let confirmed = ConfirmedTransaction::accepted_execute(index, tx.clone(), finalize_operations, vec![]);
+        assert!(confirmed.is_err());
+
+        // Create an `AcceptedExecution` with events.
+        let transition = tx.transitions().next().unwrap();
+        let payload = console::program::Plaintext::from_str("5u64").unwrap();
+        let events = vec![Event::new(*transition.program_id(), *transition.function_name(), payload.clone())];
+        let confirmed = ConfirmedTransaction::accepted_execute(index, tx.clone(), vec![], events.clone()).unwrap();
+        assert_eq!(confirmed.events(), &events[..]);
+
+        // Attempt to create an `AcceptedExecution` with an event from a program outside the transaction.
+        let program_id = console::program::ProgramID::from_str("unknown.aleo").unwrap();
+        let events = vec![Event::new(program_id, *transition.function_name(), payload)];
+        let confirmed = ConfirmedTransaction::accepted_execute(index, tx, vec![], events);
         assert!(confirmed.is_err());
     }
 
@@ -18,22 +18,39 @@ impl<N: Network> FromBytes for Rejected<N> {
     /// Reads the rejected transaction from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let variant = u8::read_le(&mut reader)?;
-        match variant {
-            0 => {
+        let rejected = match variant {
+            0 | 2 => {
                 // Read the program owner.
                 let program_owner = ProgramOwner::read_le(&mut reader)?;
                 // Read the deployment.
                 let deployment = Deployment::read_le(&mut reader)?;
                 // Return the rejected deployment.
-                Ok(Self::new_deployment(program_owner, deployment))
+                Self::new_deployment(program_owner, deployment)
             }
-            1 => {
+            1 | 3 => {
                 // Read the execution.
                 let execution = Execution::read_le(&mut reader)?;
                 // Return the rejected execution.
-                Ok(Self::new_execution(execution))
+                Self::new_execution(execution)
+            }
+            4.. => return Err(error(format!("Failed to decode rejected transaction variant {variant}"))),
+        };
+        match variant {
+            // Read the reason.
+            2 | 3 => {
+                // Read the number of bytes in the reason.
+                let num_bytes = u16::read_le(&mut reader)? as usize;
+                // Ensure the reason does not exceed the maximum number of bytes.
+                if num_bytes > Self::MAX_REASON_BYTES {
+                    return Err(error(format!("Rejection reason exceeds {} bytes", Self::MAX_REASON_BYTES)));
+                }
+                // Read the reason.
+                let mut bytes = vec![0u8; num_bytes];
+                reader.read_exact(&mut bytes)?;
+                let reason = String::from_utf8(bytes).map_err(|e| error(format!("Invalid rejection reason: {e}")))?;
+                Ok(rejected.with_reason(&reason))
             }
-            2.. => Err(error(format!("Failed to decode rejected transaction variant {variant}"))),
+            _ => Ok(rejected),
         }
     }
 }
@@ -41,22 +58,36 @@ impl<N: Network> FromBytes for Rejected<N> {
 impl<N: Network> ToBytes for Rejected<N> {
     /// Writes the rejected transaction to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Note: A rejection with a reason is written with the variant offset by 2, followed by the reason.
+        let offset = if self.reason().is_some() { 2u8 } else { 0u8 };
         match self {
-            Self::Deployment(program_owner, deployment) => {
+            Self::Deployment(program_owner, deployment, _) => {
                 // Write the variant.
-                0u8.write_le(&mut writer)?;
+                offset.write_le(&mut writer)?;
                 // Write the program owner.
                 program_owner.write_le(&mut writer)?;
                 // Write the deployment.
-                deployment.write_le(&mut writer)
+                deployment.write_le(&mut writer)?;
             }
-            Self::Execution(execution) => {
+            Self::Execution(execution, _) => {
                 // Write the variant.
-                1u8.write_le(&mut writer)?;
+                (1u8 + offset).write_le(&mut writer)?;
                 // Write the execution.
-                execution.write_le(&mut writer)
+                execution.write_le(&mut writer)?;
+            }
+        }
+        // Write the reason, if there is one.
+        if let Some(reason) = self.reason() {
+            // Ensure the reason does not exceed the maximum number of bytes.
+            if reason.len() > Self::MAX_REASON_BYTES {
+                return Err(error(format!("Rejection reason exceeds {} bytes", Self::MAX_REASON_BYTES)));
             }
+            // Write the number of bytes in the reason.
+            (reason.len() as u16).write_le(&mut writer)?;
+            // Write the reason.
+            reason.as_bytes().write_le(&mut writer)?;
         }
+        Ok(())
     }
 }
 
@@ -20,22 +20,41 @@ use super::*;
 
 use crate::{Deployment, Execution, Fee};
 
-/// A wrapper around the rejected deployment or execution.
+/// A wrapper around the rejected deployment or execution, with the reason for the rejection (if any).
 #[derive(Clone, PartialEq, Eq)]
 pub enum Rejected<N: Network> {
-    Deployment(ProgramOwner<N>, Box<Deployment<N>>),
-    Execution(Execution<N>),
+    Deployment(ProgramOwner<N>, Box<Deployment<N>>, Option<String>),
+    Execution(Execution<N>, Option<String>),
 }
 
 impl<N: Network> Rejected<N> {
+    /// The maximum number of bytes in the reason for a rejection.
+    pub const MAX_REASON_BYTES: usize = 256;
+
     /// Initializes a rejected deployment.
     pub fn new_deployment(program_owner: ProgramOwner<N>, deployment: Deployment<N>) -> Self {
-        Self::Deployment(program_owner, Box::new(deployment))
+        Self::Deployment(program_owner, Box::new(deployment), None)
     }
 
     /// Initializes a rejected execution.
     pub fn new_execution(execution: Execution<N>) -> Self {
-        Self::Execution(execution)
+        Self::Execution(execution, None)
+    }
+
+    /// Returns the rejected transaction with the given reason for the rejection.
+    /// If the reason exceeds `MAX_REASON_BYTES`, it is truncated at the last character boundary within the limit.
+    pub fn with_reason(mut self, reason: &str) -> Self {
+        // Truncate the reason to the last character boundary within the limit.
+        let mut length = reason.len().min(Self::MAX_REASON_BYTES);
+        while !reason.is_char_boundary(length) {
+            length -= 1;
+        }
+        let reason = Some(reason[..length].to_string());
+        // Set the reason.
+        match &mut self {
+            Self::Deployment(_, _, current) | Self::Execution(_, current) => *current = reason,
+        }
+        self
     }
 
     /// Returns true if the rejected transaction is a deployment.
@@ -48,35 +67,42 @@ impl<N: Network> Rejected<N> {
         matches!(self, Self::Execution(..))
     }
 
+    /// Returns the reason for the rejection, if one was recorded.
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            Self::Deployment(_, _, reason) | Self::Execution(_, reason) => reason.as_deref(),
+        }
+    }
+
     /// Returns the program owner of the rejected deployment.
     pub fn program_owner(&self) -> Option<&ProgramOwner<N>> {
         match self {
-            Self::Deployment(program_owner, _) => Some(program_owner),
-            Self::Execution(_) => None,
+            Self::Deployment(program_owner, ..) => Some(program_owner),
+            Self::Execution(..) => None,
         }
     }
 
     /// Returns the rejected deployment.
     pub fn deployment(&self) -> Option<&Deployment<N>> {
         match self {
-            Self::Deployment(_, deployment) => Some(deployment),
-            Self::Execution(_) => None,
+            Self::Deployment(_, deployment, _) => Some(deployment),
+            Self::Execution(..) => None,
         }
     }
 
     /// Returns the rejected execution.
     pub fn execution(&self) -> Option<&Execution<N>> {
         match self {
-            Self::Deployment(_, _) => None,
-            Self::Execution(execution) => Some(execution),
+            Self::Deployment(..) => None,
+            Self::Execution(execution, _) => Some(execution),
         }
     }
 
     /// Returns the rejected ID.
     pub fn to_id(&self) -> Result<Field<N>> {
         match self {
-            Self::Deployment(_, deployment) => deployment.to_deployment_id(),
-            Self::Execution(execution) => execution.to_execution_id(),
+            Self::Deployment(_, deployment, _) => deployment.to_deployment_id(),
+            Self::Execution(execution, _) => execution.to_execution_id(),
         }
     }
 
@@ -85,8 +111,8 @@ impl<N: Network> Rejected<N> {
     /// changing the original transaction ID.
     pub fn to_unconfirmed_id(&self, fee: &Option<Fee<N>>) -> Result<Field<N>> {
         match self {
-            Self::Deployment(_, deployment) => Ok(*Transaction::deployment_tree(deployment, fee.as_ref())?.root()),
-            Self::Execution(execution) => Ok(*Transaction::execution_tree(execution, fee)?.root()),
+            Self::Deployment(_, deployment, _) => Ok(*Transaction::deployment_tree(deployment, fee.as_ref())?.root()),
+            Self::Execution(execution, _) => Ok(*Transaction::execution_tree(execution, fee)?.root()),
         }
     }
 }
@@ -137,6 +163,8 @@ pub mod test_helpers {
             sample_rejected_deployment(false, rng),
             sample_rejected_execution(true, rng),
             sample_rejected_execution(false, rng),
+            sample_rejected_deployment(true, rng).with_reason("Program already exists"),
+            sample_rejected_execution(true, rng).with_reason("'finalize' exceeded the budget"),
         ]
     }
 }
@@ -19,17 +19,23 @@ impl<N: Network> Serialize for Rejected<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => match self {
-                Self::Deployment(program_owner, deployment) => {
-                    let mut object = serializer.serialize_struct("Rejected", 3)?;
+                Self::Deployment(program_owner, deployment, reason) => {
+                    let mut object = serializer.serialize_struct("Rejected", 3 + reason.is_some() as usize)?;
                     object.serialize_field("type", "deployment")?;
                     object.serialize_field("program_owner", program_owner)?;
                     object.serialize_field("deployment", deployment)?;
+                    if let Some(reason) = reason {
+                        object.serialize_field("reason", reason)?;
+                    }
                     object.end()
                 }
-                Self::Execution(execution) => {
-                    let mut object = serializer.serialize_struct("Rejected", 2)?;
+                Self::Execution(execution, reason) => {
+                    let mut object = serializer.serialize_struct("Rejected", 2 + reason.is_some() as usize)?;
                     object.serialize_field("type", "execution")?;
                     object.serialize_field("execution", execution)?;
+                    if let Some(reason) = reason {
+                        object.serialize_field("reason", reason)?;
+                    }
                     object.end()
                 }
             },
@@ -46,11 +52,17 @@ impl<'de, N: Network> Deserialize<'de> for Rejected<N> {
                 // Parse the rejected transaction from a string into a value.
                 let mut object = serde_json::Value::deserialize(deserializer)?;
 
+                // Parse the reason, if there is one.
+                let reason: Option<String> = match object.get("reason") {
+                    Some(_) => Some(DeserializeExt::take_from_value::<D>(&mut object, "reason")?),
+                    None => None,
+                };
+
                 // Parse the type.
                 let type_ = object.get("type").and_then(|t| t.as_str());
 
                 // Recover the rejected transaction.
-                match type_ {
+                let rejected = match type_ {
                     Some("deployment") => {
                         // Parse the program owner.
                         let program_owner: ProgramOwner<N> =
@@ -59,15 +71,21 @@ impl<'de, N: Network> Deserialize<'de> for Rejected<N> {
                         let deployment: Deployment<N> =
                             DeserializeExt::take_from_value::<D>(&mut object, "deployment")?;
                         // Return the rejected deployment.
-                        Ok(Self::new_deployment(program_owner, deployment))
+                        Self::new_deployment(program_owner, deployment)
                     }
                     Some("execution") => {
                         // Parse the execution.
                         let execution: Execution<N> = DeserializeExt::take_from_value::<D>(&mut object, "execution")?;
                         // Return the rejected execution.
-                        Ok(Self::new_execution(execution))
+                        Self::new_execution(execution)
                     }
-                    _ => Err(de::Error::custom("Invalid rejected transaction type")),
+                    _ => return Err(de::Error::custom("Invalid rejected transaction type")),
+                };
+
+                // Return the rejected transaction, with the reason (if any).
+                match reason {
+                    Some(reason) => Ok(rejected.with_reason(&reason)),
+                    None => Ok(rejected),
                 }
             }
             false => {
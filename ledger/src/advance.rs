@@ -306,6 +306,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             next_round,
             next_height,
+            next_timestamp,
             next_cumulative_weight,
             next_cumulative_proof_target,
             previous_block.hash(),
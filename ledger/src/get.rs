@@ -182,6 +182,45 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
     }
 
+    /// Returns the events emitted by the transaction for the given transaction ID.
+    pub fn get_events(&self, transaction_id: N::TransactionID) -> Result<Vec<Event<N>>> {
+        self.vm.block_store().get_events(&transaction_id)
+    }
+
+    /// Returns the events emitted by the given program in the given block range, along with
+    /// the block height and transaction ID of each event.
+    /// The range is inclusive of the start and exclusive of the end, and spans at most `MAX_EVENTS_BLOCK_RANGE` blocks.
+    #[allow(clippy::type_complexity)]
+    pub fn get_events_for_program(
+        &self,
+        program_id: &ProgramID<N>,
+        heights: Range<u32>,
+    ) -> Result<Vec<(u32, N::TransactionID, Event<N>)>> {
+        // Ensure the block range is within the limit, as each block in the range is scanned.
+        ensure!(
+            heights.len() <= MAX_EVENTS_BLOCK_RANGE as usize,
+            "Cannot scan more than {MAX_EVENTS_BLOCK_RANGE} blocks for events (requested {})",
+            heights.len()
+        );
+        // Initialize a list for the events.
+        let mut events = Vec::new();
+        for height in heights {
+            // Retrieve the block transactions.
+            let transactions = self.get_transactions(height)?;
+            // Collect the events emitted by the program.
+            for confirmed in transactions.iter() {
+                events.extend(
+                    confirmed
+                        .events()
+                        .iter()
+                        .filter(|event| event.program_id() == program_id)
+                        .map(|event| (height, confirmed.id(), event.clone())),
+                );
+            }
+        }
+        Ok(events)
+    }
+
     /// Returns the unconfirmed transaction for the given `transaction ID`.
     pub fn get_unconfirmed_transaction(&self, transaction_id: &N::TransactionID) -> Result<Transaction<N>> {
         // Retrieve the unconfirmed transaction.
@@ -58,7 +58,7 @@ use ledger_narwhal::{BatchCertificate, Subdag, Transmission, TransmissionID};
 use ledger_query::Query;
 use ledger_store::{ConsensusStorage, ConsensusStore};
 use synthesizer::{
-    program::{FinalizeGlobalState, Program},
+    program::{Event, FinalizeGlobalState, Program},
     vm::VM,
 };
 
@@ -79,6 +79,9 @@ use rayon::prelude::*;
 
 pub type RecordMap<N> = IndexMap<Field<N>, Record<N, Plaintext<N>>>;
 
+/// The maximum number of blocks that may be scanned for the events of a program in a single request.
+pub const MAX_EVENTS_BLOCK_RANGE: u32 = 100;
+
 #[derive(Copy, Clone, Debug)]
 pub enum RecordsFilter<N: Network> {
     /// Returns all records associated with the account.
@@ -17,6 +17,7 @@ use crate::{
     test_helpers::{CurrentLedger, CurrentNetwork},
     Ledger,
     RecordsFilter,
+    MAX_EVENTS_BLOCK_RANGE,
 };
 use aleo_std::StorageMode;
 use console::{
@@ -85,6 +86,20 @@ fn test_state_path() {
     let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
 }
 
+#[test]
+fn test_get_events_for_program_block_range() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let ledger = crate::test_helpers::sample_ledger(PrivateKey::<CurrentNetwork>::new(rng).unwrap(), rng);
+    let program_id = ProgramID::from_str("credits.aleo").unwrap();
+
+    // Ensure a block range within the limit is scanned.
+    assert!(ledger.get_events_for_program(&program_id, 0..1).unwrap().is_empty());
+    // Ensure a block range beyond the limit is rejected.
+    assert!(ledger.get_events_for_program(&program_id, 0..MAX_EVENTS_BLOCK_RANGE + 1).is_err());
+}
+
 #[test]
 fn test_insufficient_private_fees() {
     let rng = &mut TestRng::default();
@@ -43,7 +43,7 @@ use ledger_block::{
 };
 use ledger_coinbase::{ProverSolution, PuzzleCommitment};
 use ledger_narwhal_batch_certificate::BatchCertificate;
-use synthesizer_program::{FinalizeOperation, Program};
+use synthesizer_program::{Event, FinalizeOperation, Program};
 
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
@@ -57,23 +57,23 @@ use rayon::prelude::*;
 #[allow(clippy::type_complexity)]
 fn to_confirmed_tuple<N: Network>(
     confirmed: ConfirmedTransaction<N>,
-) -> Result<(ConfirmedTxType<N>, Transaction<N>, Vec<FinalizeOperation<N>>)> {
+) -> Result<(ConfirmedTxType<N>, Transaction<N>, Vec<FinalizeOperation<N>>, Vec<Event<N>>)> {
     match confirmed {
         ConfirmedTransaction::AcceptedDeploy(index, tx, finalize_operations) => {
             // Return the confirmed tuple.
-            Ok((ConfirmedTxType::AcceptedDeploy(index), tx, finalize_operations))
+            Ok((ConfirmedTxType::AcceptedDeploy(index), tx, finalize_operations, vec![]))
         }
-        ConfirmedTransaction::AcceptedExecute(index, tx, finalize_operations) => {
+        ConfirmedTransaction::AcceptedExecute(index, tx, finalize_operations, events) => {
             // Return the confirmed tuple.
-            Ok((ConfirmedTxType::AcceptedExecute(index), tx, finalize_operations))
+            Ok((ConfirmedTxType::AcceptedExecute(index), tx, finalize_operations, events))
         }
         ConfirmedTransaction::RejectedDeploy(index, tx, rejected, finalize_operations) => {
             // Return the confirmed tuple.
-            Ok((ConfirmedTxType::RejectedDeploy(index, rejected), tx, finalize_operations))
+            Ok((ConfirmedTxType::RejectedDeploy(index, rejected), tx, finalize_operations, vec![]))
         }
         ConfirmedTransaction::RejectedExecute(index, tx, rejected, finalize_operations) => {
             // Return the confirmed tuple.
-            Ok((ConfirmedTxType::RejectedExecute(index, rejected), tx, finalize_operations))
+            Ok((ConfirmedTxType::RejectedExecute(index, rejected), tx, finalize_operations, vec![]))
         }
     }
 }
@@ -82,6 +82,7 @@ fn to_confirmed_transaction<N: Network>(
     confirmed_type: ConfirmedTxType<N>,
     transaction: Transaction<N>,
     finalize_operations: Vec<FinalizeOperation<N>>,
+    events: Vec<Event<N>>,
 ) -> Result<ConfirmedTransaction<N>> {
     match confirmed_type {
         ConfirmedTxType::AcceptedDeploy(index) => {
@@ -90,7 +91,7 @@ fn to_confirmed_transaction<N: Network>(
         }
         ConfirmedTxType::AcceptedExecute(index) => {
             // Return the confirmed transaction.
-            ConfirmedTransaction::accepted_execute(index, transaction, finalize_operations)
+            ConfirmedTransaction::accepted_execute(index, transaction, finalize_operations, events)
         }
         ConfirmedTxType::RejectedDeploy(index, rejected) => {
             // Return the confirmed transaction.
@@ -139,6 +140,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type ConfirmedTransactionsMap: for<'a> Map<'a, N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     /// The rejected deployment or execution map.
     type RejectedDeploymentOrExecutionMap: for<'a> Map<'a, Field<N>, Rejected<N>>;
+    /// The mapping of `transaction ID` to `[event]`.
+    type EventsMap: for<'a> Map<'a, N::TransactionID, Vec<Event<N>>>;
     /// The transaction storage.
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
@@ -181,6 +184,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn confirmed_transactions_map(&self) -> &Self::ConfirmedTransactionsMap;
     /// Returns the rejected deployment or execution map.
     fn rejected_deployment_or_execution_map(&self) -> &Self::RejectedDeploymentOrExecutionMap;
+    /// Returns the events map.
+    fn events_map(&self) -> &Self::EventsMap;
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
 
@@ -213,6 +218,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().start_atomic();
         self.confirmed_transactions_map().start_atomic();
         self.rejected_deployment_or_execution_map().start_atomic();
+        self.events_map().start_atomic();
         self.transaction_store().start_atomic();
     }
 
@@ -235,6 +241,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.rejected_or_aborted_transaction_id_map().is_atomic_in_progress()
             || self.confirmed_transactions_map().is_atomic_in_progress()
             || self.rejected_deployment_or_execution_map().is_atomic_in_progress()
+            || self.events_map().is_atomic_in_progress()
             || self.transaction_store().is_atomic_in_progress()
     }
 
@@ -257,6 +264,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_checkpoint();
         self.confirmed_transactions_map().atomic_checkpoint();
         self.rejected_deployment_or_execution_map().atomic_checkpoint();
+        self.events_map().atomic_checkpoint();
         self.transaction_store().atomic_checkpoint();
     }
 
@@ -279,6 +287,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().clear_latest_checkpoint();
         self.confirmed_transactions_map().clear_latest_checkpoint();
         self.rejected_deployment_or_execution_map().clear_latest_checkpoint();
+        self.events_map().clear_latest_checkpoint();
         self.transaction_store().clear_latest_checkpoint();
     }
 
@@ -301,6 +310,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_rewind();
         self.confirmed_transactions_map().atomic_rewind();
         self.rejected_deployment_or_execution_map().atomic_rewind();
+        self.events_map().atomic_rewind();
         self.transaction_store().atomic_rewind();
     }
 
@@ -323,6 +333,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().abort_atomic();
         self.confirmed_transactions_map().abort_atomic();
         self.rejected_deployment_or_execution_map().abort_atomic();
+        self.events_map().abort_atomic();
         self.transaction_store().abort_atomic();
     }
 
@@ -345,6 +356,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().finish_atomic()?;
         self.confirmed_transactions_map().finish_atomic()?;
         self.rejected_deployment_or_execution_map().finish_atomic()?;
+        self.events_map().finish_atomic()?;
         self.transaction_store().finish_atomic()
     }
 
@@ -443,10 +455,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             }
 
             // Store the confirmed transactions.
-            for (confirmed_type, transaction, finalize_operations) in confirmed {
+            for (confirmed_type, transaction, finalize_operations, events) in confirmed {
                 // Store the block hash and confirmed transaction data.
                 self.confirmed_transactions_map()
                     .insert(transaction.id(), (block.hash(), confirmed_type.clone(), finalize_operations))?;
+                // Store the events, if there are any.
+                if !events.is_empty() {
+                    self.events_map().insert(transaction.id(), events)?;
+                }
                 // Store the rejected deployment or execution.
                 if let ConfirmedTxType::RejectedDeploy(_, rejected) | ConfirmedTxType::RejectedExecute(_, rejected) =
                     confirmed_type
@@ -582,6 +598,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             for transaction_id in transaction_ids.iter() {
                 // Remove the reverse transaction ID.
                 self.confirmed_transactions_map().remove(transaction_id)?;
+                // Remove the events.
+                self.events_map().remove(transaction_id)?;
                 // Remove the transaction.
                 self.transaction_store().remove(transaction_id)?;
             }
@@ -918,8 +936,18 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 Some(confirmed_attributes) => cow_to_cloned!(confirmed_attributes),
                 None => bail!("Missing confirmed transaction '{transaction_id}' in block storage"),
             };
+        // Retrieve the events.
+        let events = self.get_events(&transaction.id())?;
         // Construct the confirmed transaction.
-        to_confirmed_transaction(confirmed_type, transaction, finalize_operations).map(Some)
+        to_confirmed_transaction(confirmed_type, transaction, finalize_operations, events).map(Some)
+    }
+
+    /// Returns the events emitted by the transaction for the given `transaction ID`.
+    fn get_events(&self, transaction_id: &N::TransactionID) -> Result<Vec<Event<N>>> {
+        match self.events_map().get_confirmed(transaction_id)? {
+            Some(events) => Ok(cow_to_cloned!(events)),
+            None => Ok(Vec::new()),
+        }
     }
 
     /// Returns the unconfirmed transaction for the given `transaction ID`.
@@ -1283,6 +1311,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_unconfirmed_transaction(transaction_id)
     }
 
+    /// Returns the events emitted by the transaction for the given `transaction ID`.
+    pub fn get_events(&self, transaction_id: &N::TransactionID) -> Result<Vec<Event<N>>> {
+        self.storage.get_events(transaction_id)
+    }
+
     /// Returns the block for the given `block hash`.
     pub fn get_block(&self, block_hash: &N::BlockHash) -> Result<Option<Block<N>>> {
         self.storage.get_block(block_hash)
@@ -84,6 +84,7 @@ pub enum BlockMap {
     RejectedOrAbortedTransactionID = DataID::BlockRejectedOrAbortedTransactionIDMap as u16,
     ConfirmedTransactions = DataID::BlockConfirmedTransactionsMap as u16,
     RejectedDeploymentOrExecution = DataID::BlockRejectedDeploymentOrExecutionMap as u16,
+    Events = DataID::BlockEventsMap as u16,
 }
 
 /// The RocksDB map prefix for committee-related entries.
@@ -290,6 +291,8 @@ enum DataID {
     // Program
     ProgramIDMap,
     KeyValueMap,
+    // Block (continued)
+    BlockEventsMap,
 
     // Testing
     #[cfg(test)]
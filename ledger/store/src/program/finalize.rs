@@ -604,6 +604,11 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         self.storage.contains_mapping_confirmed(program_id, mapping_name)
     }
 
+    /// Returns `true` if the given `program ID` and `mapping name` exist, including uncommitted mappings.
+    fn contains_mapping_speculative(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<bool> {
+        self.storage.contains_mapping_speculative(program_id, mapping_name)
+    }
+
     /// Returns `true` if the given `program ID`, `mapping name`, and `key` exist.
     fn contains_key_speculative(
         &self,
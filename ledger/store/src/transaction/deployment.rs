@@ -218,10 +218,15 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the program ID for transaction '{transaction_id}'"),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.get_edition_for_transaction_id(&program_id, transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to locate the edition for program '{program_id}'"),
         };
+        // Ensure the edition is the latest edition of the program.
+        ensure!(
+            self.get_edition(&program_id)? == Some(edition),
+            "Cannot remove edition {edition} of program '{program_id}', as it is not the latest edition"
+        );
         // Retrieve the program.
         let program = match self.program_map().get_confirmed(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
@@ -231,8 +236,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Remove the program ID.
             self.id_map().remove(transaction_id)?;
-            // Remove the edition.
-            self.edition_map().remove(&program_id)?;
+            // Revert the edition to the previous edition, or remove it if this is the first edition.
+            match edition > N::EDITION {
+                true => self.edition_map().insert(program_id, edition - 1)?,
+                false => self.edition_map().remove(&program_id)?,
+            }
 
             // Remove the reverse program ID.
             self.reverse_id_map().remove(&(program_id, edition))?;
@@ -309,6 +317,28 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the edition of the given `program ID` that was deployed in the given `transaction ID`.
+    fn get_edition_for_transaction_id(
+        &self,
+        program_id: &ProgramID<N>,
+        transaction_id: &N::TransactionID,
+    ) -> Result<Option<u16>> {
+        // Retrieve the latest edition.
+        let latest_edition = match self.get_edition(program_id)? {
+            Some(edition) => edition,
+            None => return Ok(None),
+        };
+        // Search the editions, from the latest to the earliest, for the transaction ID.
+        for edition in (N::EDITION..=latest_edition).rev() {
+            if let Some(candidate) = self.reverse_id_map().get_confirmed(&(*program_id, edition))? {
+                if cow_to_copied!(candidate) == *transaction_id {
+                    return Ok(Some(edition));
+                }
+            }
+        }
+        Ok(None)
+    }
+
     /// Returns the program for the given `program ID`.
     fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         // Check if the program ID is for 'credits.aleo'.
@@ -390,7 +420,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => return Ok(None),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.get_edition_for_transaction_id(&program_id, transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to get the edition for program '{program_id}'"),
         };
@@ -437,8 +467,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             return Ok(None);
         }
 
-        // TODO (raychu86): Consider program upgrades and edition changes.
-        // Retrieve the edition.
+        // Retrieve the latest edition.
         let edition = match self.get_edition(program_id)? {
             Some(edition) => edition,
             None => return Ok(None),
@@ -465,8 +494,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         };
 
         // Retrieve the owner.
-        let owner = match self.get_owner(deployment.program_id())? {
-            Some(owner) => owner,
+        let owner = match self.owner_map().get_confirmed(&(*deployment.program_id(), deployment.edition()))? {
+            Some(owner) => cow_to_copied!(owner),
             None => bail!("Failed to get the owner for transaction '{transaction_id}'"),
         };
 
@@ -570,6 +599,15 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_edition(program_id)
     }
 
+    /// Returns the edition of the given `program ID` that was deployed in the given `transaction ID`.
+    pub fn get_edition_for_transaction_id(
+        &self,
+        program_id: &ProgramID<N>,
+        transaction_id: &N::TransactionID,
+    ) -> Result<Option<u16>> {
+        self.storage.get_edition_for_transaction_id(program_id, transaction_id)
+    }
+
     /// Returns the program ID for the given `transaction ID`.
     pub fn get_program_id(&self, transaction_id: &N::TransactionID) -> Result<Option<ProgramID<N>>> {
         self.storage.get_program_id(transaction_id)
@@ -602,6 +640,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     pub fn get_fee(&self, transaction_id: &N::TransactionID) -> Result<Option<Fee<N>>> {
         self.storage.get_fee(transaction_id)
     }
+
+    /// Returns the owner of the latest edition for the given `program ID`.
+    pub fn get_owner(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramOwner<N>>> {
+        self.storage.get_owner(program_id)
+    }
 }
 
 impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
@@ -369,7 +369,9 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
                 let program_id = self.storage.deployment_store().get_program_id(transaction_id)?;
                 // Return the edition.
                 match program_id {
-                    Some(program_id) => self.storage.deployment_store().get_edition(&program_id),
+                    Some(program_id) => {
+                        self.storage.deployment_store().get_edition_for_transaction_id(&program_id, transaction_id)
+                    }
                     None => bail!("Failed to get the program ID for deployment transaction '{transaction_id}'"),
                 }
             }
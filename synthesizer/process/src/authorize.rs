@@ -25,8 +25,10 @@ impl<N: Network> Process<N> {
         inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
         rng: &mut R,
     ) -> Result<Authorization<N>> {
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
         // Authorize the call.
-        self.get_stack(program_id)?.authorize::<A, R>(private_key, function_name, inputs, rng)
+        stack.authorize::<A, R>(private_key, function_name, inputs, self.dynamic_stacks(stack), rng)
     }
 
     /// Authorizes the fee given the credits record, the fee amount (in microcredits),
@@ -64,8 +66,11 @@ impl<N: Network> Process<N> {
         .into_iter();
         lap!(timer, "Construct the inputs");
 
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
         // Authorize the call.
-        let authorization = self.get_stack(program_id)?.authorize::<A, R>(private_key, function_name, inputs, rng)?;
+        let authorization =
+            stack.authorize::<A, R>(private_key, function_name, inputs, self.dynamic_stacks(stack), rng)?;
         finish!(timer, "Compute the authorization");
 
         // Return the authorization.
@@ -98,8 +103,11 @@ impl<N: Network> Process<N> {
         .into_iter();
         lap!(timer, "Construct the inputs");
 
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
         // Authorize the call.
-        let authorization = self.get_stack(program_id)?.authorize::<A, R>(private_key, function_name, inputs, rng)?;
+        let authorization =
+            stack.authorize::<A, R>(private_key, function_name, inputs, self.dynamic_stacks(stack), rng)?;
         finish!(timer, "Compute the authorization");
 
         // Return the authorization.
@@ -12,17 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Process, Stack, StackProgramTypes};
+use crate::{FinalizeRegisters, FinalizeTypes, Process, Stack, StackProgramTypes};
 
 use console::{
     prelude::*,
-    program::{FinalizeType, Identifier, LiteralType, PlaintextType},
+    program::{FinalizeType, Identifier, LiteralType, Plaintext, PlaintextType, Value},
+};
+use ledger_block::{Deployment, Execution, Fee};
+use synthesizer_program::{
+    CastType,
+    Command,
+    FinalizeRegistersState,
+    Instruction,
+    Operand,
+    RegistersLoad,
+    StackProgram,
 };
-use ledger_block::{Deployment, Execution};
-use synthesizer_program::{CastType, Command, Finalize, Instruction, Operand, StackProgram};
 
-/// Returns the *minimum* cost in microcredits to publish the given deployment (total cost, (storage cost, synthesis cost, namespace cost)).
-pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (u64, u64, u64))> {
+/// Returns the *minimum* cost in microcredits to publish the given deployment (total cost, (storage cost, synthesis cost, constructor cost, namespace cost)).
+pub fn deployment_cost<N: Network>(
+    process: &Process<N>,
+    deployment: &Deployment<N>,
+) -> Result<(u64, (u64, u64, u64, u64))> {
     // Determine the number of bytes in the deployment.
     let size_in_bytes = deployment.size_in_bytes()?;
     // Retrieve the program ID.
@@ -40,6 +51,13 @@ pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (
     // Compute the synthesis cost in microcredits.
     let synthesis_cost = num_combined_constraints * N::SYNTHESIS_FEE_MULTIPLIER;
 
+    // Compute the constructor cost in microcredits.
+    // Note: The constructor only runs for the first edition of a program, so an upgrade does not pay for it.
+    let constructor_cost = match deployment.program().constructor().is_some() && !process.contains_program(program_id) {
+        true => constructor_cost_in_microcredits(&process.compute_deployment_stack(deployment)?)?,
+        false => 0,
+    };
+
     // Compute the namespace cost in credits: 10^(10 - num_characters).
     let namespace_cost = 10u64
         .checked_pow(10u32.saturating_sub(num_characters))
@@ -49,10 +67,11 @@ pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (
     // Compute the total cost in microcredits.
     let total_cost = storage_cost
         .checked_add(synthesis_cost)
+        .and_then(|x| x.checked_add(constructor_cost))
         .and_then(|x| x.checked_add(namespace_cost))
         .ok_or(anyhow!("The total cost computation overflowed for a deployment"))?;
 
-    Ok((total_cost, (storage_cost, synthesis_cost, namespace_cost)))
+    Ok((total_cost, (storage_cost, synthesis_cost, constructor_cost, namespace_cost)))
 }
 
 /// Returns the *minimum* cost in microcredits to publish the given execution (total cost, (storage cost, finalize cost)).
@@ -74,97 +93,104 @@ pub fn execution_cost<N: Network>(process: &Process<N>, execution: &Execution<N>
     Ok((total_cost, (storage_cost, finalize_cost)))
 }
 
-/// Returns the minimum number of microcredits required to run the finalize.
-pub fn cost_in_microcredits<N: Network>(stack: &Stack<N>, function_name: &Identifier<N>) -> Result<u64> {
-    /// A helper function to determine the plaintext type in bytes.
-    fn plaintext_size_in_bytes<N: Network>(stack: &Stack<N>, plaintext_type: &PlaintextType<N>) -> Result<u64> {
-        match plaintext_type {
-            PlaintextType::Literal(literal_type) => Ok(literal_type.size_in_bytes::<N>() as u64),
-            PlaintextType::Struct(struct_name) => {
-                // Retrieve the struct from the stack.
-                let struct_ = stack.program().get_struct(struct_name)?;
-                // Retrieve the size of the struct name.
-                let size_of_name = struct_.name().to_bytes_le()?.len() as u64;
-                // Retrieve the size of all the members of the struct.
-                let size_of_members = struct_.members().iter().try_fold(0u64, |acc, (_, member_type)| {
-                    acc.checked_add(plaintext_size_in_bytes(stack, member_type)?).ok_or(anyhow!(
-                        "Overflowed while computing the size of the struct '{}/{struct_name}' - {member_type}",
-                        stack.program_id()
-                    ))
-                })?;
-                // Return the size of the struct.
-                Ok(size_of_name.saturating_add(size_of_members))
-            }
-            PlaintextType::Array(array_type) => {
-                // Retrieve the number of elements in the array.
-                let num_elements = **array_type.length() as u64;
-                // Compute the size of an array element.
-                let size_of_element = plaintext_size_in_bytes(stack, array_type.next_element_type())?;
-                // Return the size of the array.
-                Ok(num_elements.saturating_mul(size_of_element))
-            }
-        }
-    }
+// Finalize costs for compute heavy operations, derived as:
+// `BASE_COST + (PER_BYTE_COST * SIZE_IN_BYTES)`.
 
-    /// A helper function to compute the following: base_cost + (byte_multiplier * size_of_operands).
-    fn cost_in_size<'a, N: Network>(
-        stack: &Stack<N>,
-        finalize: &Finalize<N>,
-        operands: impl IntoIterator<Item = &'a Operand<N>>,
-        byte_multiplier: u64,
-        base_cost: u64,
-    ) -> Result<u64> {
-        // Retrieve the finalize types.
-        let finalize_types = stack.get_finalize_types(finalize.name())?;
-        // Compute the size of the operands.
-        let size_of_operands = operands.into_iter().try_fold(0u64, |acc, operand| {
-            // Determine the size of the operand.
-            let operand_size = match finalize_types.get_type_from_operand(stack, operand)? {
-                FinalizeType::Plaintext(plaintext_type) => plaintext_size_in_bytes(stack, &plaintext_type)?,
-                FinalizeType::Future(future) => {
-                    bail!("Future '{future}' is not a valid operand in the finalize scope");
-                }
-            };
-            // Safely add the size to the accumulator.
-            acc.checked_add(operand_size).ok_or(anyhow!(
-                "Overflowed while computing the size of the operand '{operand}' in '{}/{}' (finalize)",
-                stack.program_id(),
-                finalize.name()
-            ))
-        })?;
-        // Return the cost.
-        Ok(base_cost.saturating_add(byte_multiplier.saturating_mul(size_of_operands)))
-    }
+const CAST_BASE_COST: u64 = 500;
+const CAST_PER_BYTE_COST: u64 = 30;
 
-    // Finalize costs for compute heavy operations, derived as:
-    // `BASE_COST + (PER_BYTE_COST * SIZE_IN_BYTES)`.
+const ECDSA_VERIFY_COST: u64 = 250_000;
 
-    const CAST_BASE_COST: u64 = 500;
-    const CAST_PER_BYTE_COST: u64 = 30;
+const EMIT_BASE_COST: u64 = 10_000;
+const EMIT_PER_BYTE_COST: u64 = 100;
 
-    const HASH_BASE_COST: u64 = 10_000;
-    const HASH_PER_BYTE_COST: u64 = 30;
+const HASH_BASE_COST: u64 = 10_000;
+const HASH_PER_BYTE_COST: u64 = 30;
 
-    const HASH_BHP_BASE_COST: u64 = 50_000;
-    const HASH_BHP_PER_BYTE_COST: u64 = 300;
+const HASH_BHP_BASE_COST: u64 = 50_000;
+const HASH_BHP_PER_BYTE_COST: u64 = 300;
 
-    const HASH_PSD_BASE_COST: u64 = 40_000;
-    const HASH_PSD_PER_BYTE_COST: u64 = 75;
+const HASH_PSD_BASE_COST: u64 = 40_000;
+const HASH_PSD_PER_BYTE_COST: u64 = 75;
 
-    const MAPPING_BASE_COST: u64 = 10_000;
-    const MAPPING_PER_BYTE_COST: u64 = 10;
+const MAPPING_BASE_COST: u64 = 10_000;
+const MAPPING_PER_BYTE_COST: u64 = 10;
 
-    const SET_BASE_COST: u64 = 10_000;
-    const SET_PER_BYTE_COST: u64 = 100;
+const SET_BASE_COST: u64 = 10_000;
+const SET_PER_BYTE_COST: u64 = 100;
 
-    // Retrieve the finalize logic.
-    let Some(finalize) = stack.get_function_ref(function_name)?.finalize_logic() else {
-        // Return a finalize cost of 0, if the function does not have a finalize scope.
-        return Ok(0);
-    };
+const STRING_BASE_COST: u64 = 500;
+const STRING_PER_BYTE_COST: u64 = 30;
 
-    // Measure the cost of each command.
-    let cost = |command: &Command<N>| match command {
+/// A helper function to determine the plaintext type in bytes.
+fn plaintext_size_in_bytes<N: Network>(stack: &Stack<N>, plaintext_type: &PlaintextType<N>) -> Result<u64> {
+    match plaintext_type {
+        PlaintextType::Literal(literal_type) => Ok(literal_type.size_in_bytes::<N>() as u64),
+        PlaintextType::Struct(struct_name) => {
+            // Retrieve the struct from the stack.
+            let struct_ = stack.program().get_struct(struct_name)?;
+            // Retrieve the size of the struct name.
+            let size_of_name = struct_.name().to_bytes_le()?.len() as u64;
+            // Retrieve the size of all the members of the struct.
+            let size_of_members = struct_.members().iter().try_fold(0u64, |acc, (_, member_type)| {
+                acc.checked_add(plaintext_size_in_bytes(stack, member_type)?).ok_or(anyhow!(
+                    "Overflowed while computing the size of the struct '{}/{struct_name}' - {member_type}",
+                    stack.program_id()
+                ))
+            })?;
+            // Return the size of the struct.
+            Ok(size_of_name.saturating_add(size_of_members))
+        }
+        PlaintextType::Array(array_type) => {
+            // Retrieve the number of elements in the array.
+            let num_elements = **array_type.length() as u64;
+            // Compute the size of an array element.
+            let size_of_element = plaintext_size_in_bytes(stack, array_type.next_element_type())?;
+            // Return the size of the array.
+            Ok(num_elements.saturating_mul(size_of_element))
+        }
+        // An optional is sized as its literal, plus one byte for the flag.
+        PlaintextType::Optional(literal_type) => Ok((literal_type.size_in_bytes::<N>() as u64).saturating_add(1)),
+    }
+}
+
+/// A helper function to compute the following: base_cost + (byte_multiplier * size_of_operands).
+fn cost_in_size<'a, N: Network>(
+    stack: &Stack<N>,
+    finalize_types: &FinalizeTypes<N>,
+    scope: &Identifier<N>,
+    operands: impl IntoIterator<Item = &'a Operand<N>>,
+    byte_multiplier: u64,
+    base_cost: u64,
+) -> Result<u64> {
+    // Compute the size of the operands.
+    let size_of_operands = operands.into_iter().try_fold(0u64, |acc, operand| {
+        // Determine the size of the operand.
+        let operand_size = match finalize_types.get_type_from_operand(stack, operand)? {
+            FinalizeType::Plaintext(plaintext_type) => plaintext_size_in_bytes(stack, &plaintext_type)?,
+            FinalizeType::Future(future) => {
+                bail!("Future '{future}' is not a valid operand in the finalize scope");
+            }
+        };
+        // Safely add the size to the accumulator.
+        acc.checked_add(operand_size).ok_or(anyhow!(
+            "Overflowed while computing the size of the operand '{operand}' in '{}/{}' (finalize)",
+            stack.program_id(),
+            scope
+        ))
+    })?;
+    // Return the cost.
+    Ok(base_cost.saturating_add(byte_multiplier.saturating_mul(size_of_operands)))
+}
+
+/// A helper function to compute the cost of a command, given the finalize types of its scope.
+fn cost_of_command<N: Network>(
+    stack: &Stack<N>,
+    finalize_types: &FinalizeTypes<N>,
+    scope: &Identifier<N>,
+    command: &Command<N>,
+) -> Result<u64> {
+    match command {
         Command::Instruction(Instruction::Abs(_)) => Ok(500),
         Command::Instruction(Instruction::AbsWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::Add(_)) => Ok(500),
@@ -174,6 +200,7 @@ pub fn cost_in_microcredits<N: Network>(stack: &Stack<N>, function_name: &Identi
         Command::Instruction(Instruction::AssertNeq(_)) => Ok(500),
         Command::Instruction(Instruction::Async(_)) => bail!("'async' is not supported in finalize"),
         Command::Instruction(Instruction::Call(_)) => bail!("'call' is not supported in finalize"),
+        Command::Instruction(Instruction::CallDynamic(_)) => bail!("'call.dynamic' is not supported in finalize"),
         Command::Instruction(Instruction::Cast(cast)) => match cast.cast_type() {
             CastType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
             CastType::Plaintext(plaintext_type) => Ok(plaintext_size_in_bytes(stack, plaintext_type)?
@@ -195,85 +222,113 @@ pub fn cost_in_microcredits<N: Network>(stack: &Stack<N>, function_name: &Identi
             | CastType::ExternalRecord(_) => Ok(500),
         },
         Command::Instruction(Instruction::CommitBHP256(commit)) => {
-            cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
         }
         Command::Instruction(Instruction::CommitBHP512(commit)) => {
-            cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
         }
         Command::Instruction(Instruction::CommitBHP768(commit)) => {
-            cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
         }
         Command::Instruction(Instruction::CommitBHP1024(commit)) => {
-            cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
         }
         Command::Instruction(Instruction::CommitPED64(commit)) => {
-            cost_in_size(stack, finalize, commit.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, commit.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
         }
         Command::Instruction(Instruction::CommitPED128(commit)) => {
-            cost_in_size(stack, finalize, commit.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, commit.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+        }
+        Command::Instruction(Instruction::Concat(concat)) => {
+            cost_in_size(stack, finalize_types, scope, concat.operands(), STRING_PER_BYTE_COST, STRING_BASE_COST)
+        }
+        Command::Instruction(Instruction::DeserializeBits(deserialize)) => {
+            Ok(plaintext_size_in_bytes(stack, deserialize.destination_type())?
+                .saturating_mul(CAST_PER_BYTE_COST)
+                .saturating_add(CAST_BASE_COST))
+        }
+        Command::Instruction(Instruction::DeserializeBytes(deserialize)) => {
+            Ok(plaintext_size_in_bytes(stack, deserialize.destination_type())?
+                .saturating_mul(CAST_PER_BYTE_COST)
+                .saturating_add(CAST_BASE_COST))
         }
         Command::Instruction(Instruction::Div(div)) => {
             // Ensure `div` has exactly two operands.
             ensure!(div.operands().len() == 2, "'div' must contain exactly 2 operands");
-            // Retrieve the finalize types.
-            let finalize_types = stack.get_finalize_types(finalize.name())?;
             // Retrieve the price by the operand type.
             match finalize_types.get_type_from_operand(stack, &div.operands()[0])? {
                 FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Field)) => Ok(1_500),
                 FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
                 FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'div' does not support arrays"),
                 FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'div' does not support structs"),
+                FinalizeType::Plaintext(PlaintextType::Optional(_)) => bail!("'div' does not support optionals"),
                 FinalizeType::Future(_) => bail!("'div' does not support futures"),
             }
         }
         Command::Instruction(Instruction::DivWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::Double(_)) => Ok(500),
+        Command::Instruction(Instruction::ECDSAVerify(_)) => Ok(ECDSA_VERIFY_COST),
         Command::Instruction(Instruction::GreaterThan(_)) => Ok(500),
         Command::Instruction(Instruction::GreaterThanOrEqual(_)) => Ok(500),
         Command::Instruction(Instruction::HashBHP256(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
         }
         Command::Instruction(Instruction::HashBHP512(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
         }
         Command::Instruction(Instruction::HashBHP768(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
         }
         Command::Instruction(Instruction::HashBHP1024(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+        }
+        Command::Instruction(Instruction::HashBlake2s(hash)) => {
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
         }
         Command::Instruction(Instruction::HashKeccak256(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
         }
         Command::Instruction(Instruction::HashKeccak384(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
         }
         Command::Instruction(Instruction::HashKeccak512(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
         }
         Command::Instruction(Instruction::HashPED64(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
         }
         Command::Instruction(Instruction::HashPED128(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
         }
         Command::Instruction(Instruction::HashPSD2(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
         }
         Command::Instruction(Instruction::HashPSD4(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
         }
         Command::Instruction(Instruction::HashPSD8(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
+        Command::Instruction(Instruction::HashPoseidon2_2(hash)) => {
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
+        Command::Instruction(Instruction::HashPoseidon2_4(hash)) => {
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
+        Command::Instruction(Instruction::HashPoseidon2_8(hash)) => {
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
+        Command::Instruction(Instruction::HashSha256(hash)) => {
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
         }
         Command::Instruction(Instruction::HashSha3_256(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
         }
         Command::Instruction(Instruction::HashSha3_384(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
         }
         Command::Instruction(Instruction::HashSha3_512(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
         }
         Command::Instruction(Instruction::HashManyPSD2(_)) => {
             bail!("`hash_many.psd2` is not supported in finalize")
@@ -287,14 +342,19 @@ pub fn cost_in_microcredits<N: Network>(stack: &Stack<N>, function_name: &Identi
         Command::Instruction(Instruction::Inv(_)) => Ok(2_500),
         Command::Instruction(Instruction::IsEq(_)) => Ok(500),
         Command::Instruction(Instruction::IsNeq(_)) => Ok(500),
+        Command::Instruction(Instruction::Len(_)) => Ok(500),
         Command::Instruction(Instruction::LessThan(_)) => Ok(500),
         Command::Instruction(Instruction::LessThanOrEqual(_)) => Ok(500),
+        Command::Instruction(Instruction::MerkleVerifyBHP(merkle)) => {
+            cost_in_size(stack, finalize_types, scope, merkle.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+        }
+        Command::Instruction(Instruction::MerkleVerifyPSD(merkle)) => {
+            cost_in_size(stack, finalize_types, scope, merkle.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
         Command::Instruction(Instruction::Modulo(_)) => Ok(500),
         Command::Instruction(Instruction::Mul(mul)) => {
             // Ensure `mul` has exactly two operands.
             ensure!(mul.operands().len() == 2, "'mul' must contain exactly 2 operands");
-            // Retrieve the finalize types.
-            let finalize_types = stack.get_finalize_types(finalize.name())?;
             // Retrieve the price by operand type.
             match finalize_types.get_type_from_operand(stack, &mul.operands()[0])? {
                 FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Group)) => Ok(10_000),
@@ -302,6 +362,7 @@ pub fn cost_in_microcredits<N: Network>(stack: &Stack<N>, function_name: &Identi
                 FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
                 FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'mul' does not support arrays"),
                 FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'mul' does not support structs"),
+                FinalizeType::Plaintext(PlaintextType::Optional(_)) => bail!("'mul' does not support optionals"),
                 FinalizeType::Future(_) => bail!("'mul' does not support futures"),
             }
         }
@@ -310,27 +371,62 @@ pub fn cost_in_microcredits<N: Network>(stack: &Stack<N>, function_name: &Identi
         Command::Instruction(Instruction::Neg(_)) => Ok(500),
         Command::Instruction(Instruction::Nor(_)) => Ok(500),
         Command::Instruction(Instruction::Not(_)) => Ok(500),
+        Command::Instruction(Instruction::OptionIsSome(_)) => Ok(500),
+        Command::Instruction(Instruction::OptionNone(_)) => Ok(500),
+        Command::Instruction(Instruction::OptionSome(_)) => Ok(500),
+        Command::Instruction(Instruction::OptionUnwrap(_)) => Ok(500),
         Command::Instruction(Instruction::Or(_)) => Ok(500),
         Command::Instruction(Instruction::Pow(pow)) => {
             // Ensure `pow` has at least one operand.
             ensure!(!pow.operands().is_empty(), "'pow' must contain at least 1 operand");
-            // Retrieve the finalize types.
-            let finalize_types = stack.get_finalize_types(finalize.name())?;
             // Retrieve the price by operand type.
             match finalize_types.get_type_from_operand(stack, &pow.operands()[0])? {
                 FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Field)) => Ok(1_500),
                 FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
                 FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'pow' does not support arrays"),
                 FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'pow' does not support structs"),
+                FinalizeType::Plaintext(PlaintextType::Optional(_)) => bail!("'pow' does not support optionals"),
                 FinalizeType::Future(_) => bail!("'pow' does not support futures"),
             }
         }
         Command::Instruction(Instruction::PowWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::Rem(_)) => Ok(500),
         Command::Instruction(Instruction::RemWrapped(_)) => Ok(500),
-        Command::Instruction(Instruction::SignVerify(sign)) => {
-            cost_in_size(stack, finalize, sign.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        Command::Instruction(Instruction::Repeat(repeat)) => {
+            // Retrieve the repeated closure.
+            let closure = stack.program().get_closure(repeat.closure())?;
+            // Retrieve the finalize types of the closure.
+            let closure_types = FinalizeTypes::from_closure(stack, &closure)?;
+            // Compute the cost of a single iteration.
+            let iteration_cost = closure.instructions().iter().try_fold(0u64, |acc, instruction| {
+                let command = Command::Instruction(instruction.clone());
+                let cost = cost_of_command(stack, &closure_types, closure.name(), &command)?;
+                acc.checked_add(cost).ok_or(anyhow!("Finalize cost overflowed"))
+            })?;
+            // Return the cost of all iterations.
+            iteration_cost.checked_mul(repeat.iterations() as u64).ok_or(anyhow!("Finalize cost overflowed"))
+        }
+        Command::Instruction(Instruction::SerializeBits(serialize)) => {
+            Ok(plaintext_size_in_bytes(stack, serialize.destination_type())?
+                .saturating_mul(CAST_PER_BYTE_COST)
+                .saturating_add(CAST_BASE_COST))
         }
+        Command::Instruction(Instruction::SerializeBytes(serialize)) => {
+            Ok(plaintext_size_in_bytes(stack, serialize.destination_type())?
+                .saturating_mul(CAST_PER_BYTE_COST)
+                .saturating_add(CAST_BASE_COST))
+        }
+        Command::Instruction(Instruction::SignVerify(sign)) => {
+            cost_in_size(stack, finalize_types, scope, sign.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
+        }
+        Command::Instruction(Instruction::Slice(slice)) => cost_in_size(
+            stack,
+            finalize_types,
+            scope,
+            slice.operands().iter().take(1),
+            STRING_PER_BYTE_COST,
+            STRING_BASE_COST,
+        ),
         Command::Instruction(Instruction::Shl(_)) => Ok(500),
         Command::Instruction(Instruction::ShlWrapped(_)) => Ok(500),
         Command::Instruction(Instruction::Shr(_)) => Ok(500),
@@ -343,21 +439,38 @@ pub fn cost_in_microcredits<N: Network>(stack: &Stack<N>, function_name: &Identi
         Command::Instruction(Instruction::Xor(_)) => Ok(500),
         Command::Await(_) => Ok(500),
         Command::Contains(command) => {
-            cost_in_size(stack, finalize, [command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, [command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST)
         }
         Command::Get(command) => {
-            cost_in_size(stack, finalize, [command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, [command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST)
         }
         Command::GetOrUse(command) => {
-            cost_in_size(stack, finalize, [command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST)
+            cost_in_size(stack, finalize_types, scope, [command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST)
         }
         Command::RandChaCha(_) => Ok(25_000),
         Command::Remove(_) => Ok(MAPPING_BASE_COST),
-        Command::Set(command) => {
-            cost_in_size(stack, finalize, [command.key(), command.value()], SET_PER_BYTE_COST, SET_BASE_COST)
-        }
+        Command::Set(command) => cost_in_size(
+            stack,
+            finalize_types,
+            scope,
+            [command.key(), command.value()],
+            SET_PER_BYTE_COST,
+            SET_BASE_COST,
+        ),
         Command::BranchEq(_) | Command::BranchNeq(_) => Ok(500),
         Command::Position(_) => Ok(100),
+        Command::Emit(command) => {
+            cost_in_size(stack, finalize_types, scope, [command.operand()], EMIT_PER_BYTE_COST, EMIT_BASE_COST)
+        }
+    }
+}
+
+/// Returns the minimum number of microcredits required to run the finalize.
+pub fn cost_in_microcredits<N: Network>(stack: &Stack<N>, function_name: &Identifier<N>) -> Result<u64> {
+    // Retrieve the finalize logic.
+    let Some(finalize) = stack.get_function_ref(function_name)?.finalize_logic() else {
+        // Return a finalize cost of 0, if the function does not have a finalize scope.
+        return Ok(0);
     };
 
     // Get the cost of finalizing all futures.
@@ -373,8 +486,194 @@ pub fn cost_in_microcredits<N: Network>(stack: &Stack<N>, function_name: &Identi
         }
     }
 
+    // Add the cost of the commands in the finalize scope.
+    future_cost
+        .checked_add(cost_in_microcredits_without_futures(stack, function_name)?)
+        .ok_or(anyhow!("Finalize cost overflowed"))
+}
+
+/// Returns the minimum number of microcredits required to run the commands in the finalize scope
+/// of the given function, **excluding** the cost of finalizing the futures it awaits.
+pub fn cost_in_microcredits_without_futures<N: Network>(
+    stack: &Stack<N>,
+    function_name: &Identifier<N>,
+) -> Result<u64> {
+    // Retrieve the finalize logic.
+    let Some(finalize) = stack.get_function_ref(function_name)?.finalize_logic() else {
+        // Return a finalize cost of 0, if the function does not have a finalize scope.
+        return Ok(0);
+    };
+
+    // Retrieve the finalize types.
+    let finalize_types = stack.get_finalize_types(finalize.name())?;
+    // Measure the cost of each command.
+    let cost = |command: &Command<N>| cost_of_command(stack, finalize_types, finalize.name(), command);
+
     // Aggregate the cost of all commands in the program.
-    finalize.commands().iter().map(cost).try_fold(future_cost, |acc, res| {
+    finalize.commands().iter().map(cost).try_fold(0u64, |acc, res| {
         res.and_then(|x| acc.checked_add(x).ok_or(anyhow!("Finalize cost overflowed")))
     })
 }
+
+/// Returns the minimum number of microcredits required to run the constructor of the given stack.
+/// If the program does not have a constructor, the cost is 0.
+pub fn constructor_cost_in_microcredits<N: Network>(stack: &Stack<N>) -> Result<u64> {
+    // Retrieve the constructor logic.
+    let Some(constructor) = stack.program().constructor() else {
+        // Return a cost of 0, if the program does not have a constructor.
+        return Ok(0);
+    };
+    let finalize = constructor.finalize_logic();
+
+    // Retrieve the finalize types.
+    let finalize_types = stack.get_finalize_types(finalize.name())?;
+    // Measure the cost of each command.
+    let cost = |command: &Command<N>| cost_of_command(stack, finalize_types, finalize.name(), command);
+
+    // Aggregate the cost of all commands in the constructor.
+    finalize.commands().iter().map(cost).try_fold(0u64, |acc, res| {
+        res.and_then(|x| acc.checked_add(x).ok_or(anyhow!("Constructor cost overflowed")))
+    })
+}
+
+/// Returns the number of microcredits available to run the constructor of the given deployment.
+/// The budget is the base fee, less the cost to publish the deployment, excluding the constructor cost.
+pub fn constructor_budget<N: Network>(process: &Process<N>, deployment: &Deployment<N>, fee: &Fee<N>) -> Result<u64> {
+    // Compute the cost to publish the deployment.
+    let (total_cost, (_, _, constructor_cost, _)) = deployment_cost(process, deployment)?;
+    // Deduct the cost, excluding the constructor cost, from the base fee.
+    Ok(fee.base_amount()?.saturating_sub(total_cost.saturating_sub(constructor_cost)))
+}
+
+/// Returns the number of microcredits available to run the finalize of the given execution.
+/// The budget is the base fee, less the storage cost of the execution. Without a fee, the finalize is unmetered.
+///
+/// Note: The base fee covers at least the minimum finalize cost, which excludes the size of the values read from
+/// storage. As the metered cost includes these reads, a finalize that reads large values may exceed its budget.
+pub fn finalize_budget<N: Network>(execution: &Execution<N>, fee: Option<&Fee<N>>) -> Result<u64> {
+    match fee {
+        // Deduct the storage cost from the base fee.
+        Some(fee) => Ok(fee.base_amount()?.saturating_sub(execution.size_in_bytes()?)),
+        // If there is no fee, there is no budget to enforce.
+        None => Ok(u64::MAX),
+    }
+}
+
+/// Returns the cost in microcredits to run the given command, with the given finalize registers.
+/// Unlike `cost_in_microcredits`, storage reads and writes are weighted by the size of their keys and values,
+/// instead of the size of their declared types.
+pub fn cost_of_finalize_command<N: Network>(
+    stack: &Stack<N>,
+    registers: &FinalizeRegisters<N>,
+    command: &Command<N>,
+) -> Result<u64> {
+    // Retrieve the finalize scope.
+    let scope = registers.function_name();
+    // Retrieve the finalize types.
+    let finalize_types = stack.get_finalize_types(scope)?;
+    // Measure the cost of a storage command by the size of its values.
+    let cost = |operands: &[&Operand<N>], byte_multiplier, base_cost| {
+        cost_in_value_size(stack, finalize_types, registers, operands, byte_multiplier, base_cost)
+    };
+
+    match command {
+        Command::Contains(command) => cost(&[command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST),
+        Command::Get(command) => cost(&[command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST),
+        Command::GetOrUse(command) => cost(&[command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST),
+        Command::Set(command) => cost(&[command.key(), command.value()], SET_PER_BYTE_COST, SET_BASE_COST),
+        _ => cost_of_command(stack, finalize_types, scope, command),
+    }
+}
+
+/// Returns the cost in microcredits of the value read from storage by the given command, once it has been executed.
+/// Note: `get` and `get.or_use` are charged for their key by `cost_of_finalize_command`, and for the value
+/// they load into their destination register here. For all other commands, this cost is zero.
+pub fn cost_of_finalize_read<N: Network>(
+    stack: &Stack<N>,
+    registers: &FinalizeRegisters<N>,
+    command: &Command<N>,
+) -> Result<u64> {
+    // Retrieve the destination register of the read.
+    let destination = match command {
+        Command::Get(command) => command.destination(),
+        Command::GetOrUse(command) => command.destination(),
+        _ => return Ok(0),
+    };
+    // Retrieve the finalize types.
+    let finalize_types = stack.get_finalize_types(registers.function_name())?;
+    // Measure the cost by the size of the value read.
+    let operand = Operand::Register(destination.clone());
+    cost_in_value_size(stack, finalize_types, registers, &[&operand], MAPPING_PER_BYTE_COST, 0)
+}
+
+/// A helper function to compute the following: base_cost + (byte_multiplier * size_of_operand_values).
+fn cost_in_value_size<N: Network>(
+    stack: &Stack<N>,
+    finalize_types: &FinalizeTypes<N>,
+    registers: &FinalizeRegisters<N>,
+    operands: &[&Operand<N>],
+    byte_multiplier: u64,
+    base_cost: u64,
+) -> Result<u64> {
+    // Compute the size of the operand values.
+    let size_of_values = operands.iter().try_fold(0u64, |acc, operand| {
+        // Determine the size of the operand value.
+        let value_type = finalize_types.get_type_from_operand(stack, operand)?;
+        let value_size = match (value_type, registers.load(stack, operand)?) {
+            (FinalizeType::Plaintext(plaintext_type), Value::Plaintext(plaintext)) => {
+                plaintext_value_size_in_bytes(stack, &plaintext_type, &plaintext)?
+            }
+            _ => bail!("Operand '{operand}' is not a valid plaintext in the finalize scope"),
+        };
+        // Safely add the size to the accumulator.
+        acc.checked_add(value_size).ok_or(anyhow!(
+            "Overflowed while computing the size of the operand '{operand}' in '{}/{}' (finalize)",
+            stack.program_id(),
+            registers.function_name()
+        ))
+    })?;
+    // Return the cost.
+    Ok(base_cost.saturating_add(byte_multiplier.saturating_mul(size_of_values)))
+}
+
+/// A helper function to determine the size of a plaintext value in bytes.
+/// Note: This never exceeds the size of its type, as strings are measured by their length.
+fn plaintext_value_size_in_bytes<N: Network>(
+    stack: &Stack<N>,
+    plaintext_type: &PlaintextType<N>,
+    plaintext: &Plaintext<N>,
+) -> Result<u64> {
+    match (plaintext_type, plaintext) {
+        (PlaintextType::Literal(_), Plaintext::Literal(literal, _)) => Ok(literal.size_in_bytes() as u64),
+        (PlaintextType::Optional(_), Plaintext::Optional(_, literal, _)) => {
+            Ok((literal.size_in_bytes() as u64).saturating_add(1))
+        }
+        (PlaintextType::Struct(struct_name), Plaintext::Struct(members, _)) => {
+            // Retrieve the struct from the stack.
+            let struct_ = stack.program().get_struct(struct_name)?;
+            // Retrieve the size of the struct name.
+            let size_of_name = struct_.name().to_bytes_le()?.len() as u64;
+            // Retrieve the size of all the members of the struct.
+            let size_of_members = struct_.members().iter().try_fold(0u64, |acc, (member_name, member_type)| {
+                // Retrieve the member value.
+                let Some(member) = members.get(member_name) else {
+                    bail!("Struct '{struct_name}' is missing the member '{member_name}'")
+                };
+                acc.checked_add(plaintext_value_size_in_bytes(stack, member_type, member)?).ok_or(anyhow!(
+                    "Overflowed while computing the size of the struct '{}/{struct_name}' - {member_type}",
+                    stack.program_id()
+                ))
+            })?;
+            // Return the size of the struct.
+            Ok(size_of_name.saturating_add(size_of_members))
+        }
+        (PlaintextType::Array(array_type), Plaintext::Array(elements, _)) => {
+            // Return the size of all the elements of the array.
+            elements.iter().try_fold(0u64, |acc, element| {
+                acc.checked_add(plaintext_value_size_in_bytes(stack, array_type.next_element_type(), element)?)
+                    .ok_or(anyhow!("Overflowed while computing the size of the array '{array_type}'"))
+            })
+        }
+        _ => bail!("Plaintext '{plaintext}' does not match the type '{plaintext_type}'"),
+    }
+}
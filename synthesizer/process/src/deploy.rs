@@ -15,7 +15,8 @@
 use super::*;
 
 impl<N: Network> Process<N> {
-    /// Deploys the given program ID, if it does not exist.
+    /// Deploys the given program ID.
+    /// If the program already exists, the deployment is an upgrade to the next edition of the program.
     #[inline]
     pub fn deploy<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
@@ -24,8 +25,11 @@ impl<N: Network> Process<N> {
     ) -> Result<Deployment<N>> {
         let timer = timer!("Process::deploy");
 
-        // Compute the stack.
-        let stack = Stack::new(self, program)?;
+        // Compute the stack, as a new program or as an upgrade of an existing program.
+        let stack = match self.contains_program(program.id()) {
+            true => Stack::upgrade(self, program)?,
+            false => Stack::new(self, program)?,
+        };
         lap!(timer, "Compute the stack");
 
         // Return the deployment.
@@ -39,14 +43,45 @@ impl<N: Network> Process<N> {
 
     /// Adds the newly-deployed program.
     /// This method assumes the given deployment **is valid**.
+    ///
+    /// Editions of a program may be loaded in any order, and the latest loaded edition is used for new calls.
     #[inline]
     pub fn load_deployment(&mut self, deployment: &Deployment<N>) -> Result<()> {
         let timer = timer!("Process::load_deployment");
 
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // Ensure the edition of the program does not already exist in the process.
+        ensure!(
+            !self.contains_program_edition(program_id, deployment.edition()),
+            "Program '{program_id}' (edition {}) already exists",
+            deployment.edition()
+        );
+
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = match self.contains_program(program_id) || deployment.edition() != N::EDITION {
+            true => {
+                Stack::check_program_is_well_formed(deployment.program())?;
+                Stack::initialize(self, deployment.program(), deployment.edition())?
+            }
+            false => Stack::new(self, deployment.program())?,
+        };
         lap!(timer, "Compute the stack");
 
+        // Ensure the edition is compatible with its adjacent editions.
+        // Note: As editions may be loaded in any order, each pair of adjacent editions is checked once both are loaded.
+        if deployment.edition() > N::EDITION {
+            if let Ok(previous) = self.get_stack_edition(program_id, deployment.edition() - 1) {
+                previous.check_upgrade_is_compatible(deployment.program())?;
+            }
+        }
+        if let Some(next) = deployment.edition().checked_add(1) {
+            if let Ok(next) = self.get_stack_edition(program_id, next) {
+                stack.check_upgrade_is_compatible(next.program())?;
+            }
+        }
+        lap!(timer, "Check the upgrade compatibility");
+
         // Insert the verifying keys.
         for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
             stack.insert_verifying_key(function_name, verifying_key.clone())?;
@@ -60,4 +95,24 @@ impl<N: Network> Process<N> {
 
         Ok(())
     }
+
+    /// Computes the stack for the given deployment, as either a new program or an upgrade of an existing program.
+    #[inline]
+    pub(crate) fn compute_deployment_stack(&self, deployment: &Deployment<N>) -> Result<Stack<N>> {
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // Compute the stack, as a new program or as an upgrade of an existing program.
+        let stack = match self.contains_program(program_id) {
+            true => Stack::upgrade(self, deployment.program())?,
+            false => Stack::new(self, deployment.program())?,
+        };
+        // Ensure the edition of the deployment matches the edition of the stack.
+        ensure!(
+            deployment.edition() == stack.edition(),
+            "Expected edition {} for program '{program_id}', found edition {}",
+            stack.edition(),
+            deployment.edition()
+        );
+        Ok(stack)
+    }
 }
@@ -0,0 +1,195 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The estimated cost of a single transition, in microcredits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TransitionCost<N: Network> {
+    /// The program ID of the transition.
+    program_id: ProgramID<N>,
+    /// The function name of the transition.
+    function_name: Identifier<N>,
+    /// The storage cost of the transition.
+    storage_cost: u64,
+    /// The cost of the commands in the finalize scope of the transition,
+    /// excluding the futures it awaits (which are attributed to their own transitions).
+    finalize_cost: u64,
+}
+
+impl<N: Network> TransitionCost<N> {
+    /// Returns the program ID of the transition.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name of the transition.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the storage cost of the transition.
+    pub const fn storage_cost(&self) -> u64 {
+        self.storage_cost
+    }
+
+    /// Returns the finalize cost of the transition, excluding the futures it awaits.
+    pub const fn finalize_cost(&self) -> u64 {
+        self.finalize_cost
+    }
+
+    /// Returns the total cost of the transition.
+    pub const fn total_cost(&self) -> u64 {
+        self.storage_cost.saturating_add(self.finalize_cost)
+    }
+}
+
+/// The estimated cost of an execution, in microcredits, broken down per transition.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionCostEstimate<N: Network> {
+    /// The estimated costs of the transitions, in execution order (i.e. the root transition is last).
+    transitions: Vec<TransitionCost<N>>,
+    /// The storage cost of the execution, including an upper bound on the size of the proof.
+    storage_cost: u64,
+    /// The finalize cost of the execution.
+    finalize_cost: u64,
+}
+
+impl<N: Network> ExecutionCostEstimate<N> {
+    /// Returns the estimated costs of the transitions, in execution order (i.e. the root transition is last).
+    pub fn transitions(&self) -> &[TransitionCost<N>] {
+        &self.transitions
+    }
+
+    /// Returns the storage cost of the execution.
+    ///
+    /// Note: The size of the proof is only known after proving, so this uses an upper bound on its size.
+    pub const fn storage_cost(&self) -> u64 {
+        self.storage_cost
+    }
+
+    /// Returns the finalize cost of the execution.
+    pub const fn finalize_cost(&self) -> u64 {
+        self.finalize_cost
+    }
+
+    /// Returns the total cost of the execution.
+    pub const fn total_cost(&self) -> u64 {
+        self.storage_cost.saturating_add(self.finalize_cost)
+    }
+}
+
+/// The estimated cost of a deployment, in microcredits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentCostEstimate {
+    /// The storage cost of the deployment.
+    storage_cost: u64,
+    /// The synthesis cost of the deployment.
+    synthesis_cost: u64,
+    /// The cost of the constructor of the program (if any).
+    constructor_cost: u64,
+    /// The namespace cost of the deployment.
+    namespace_cost: u64,
+}
+
+impl DeploymentCostEstimate {
+    /// Returns the storage cost of the deployment.
+    pub const fn storage_cost(&self) -> u64 {
+        self.storage_cost
+    }
+
+    /// Returns the synthesis cost of the deployment.
+    pub const fn synthesis_cost(&self) -> u64 {
+        self.synthesis_cost
+    }
+
+    /// Returns the cost of the constructor of the program (if any).
+    pub const fn constructor_cost(&self) -> u64 {
+        self.constructor_cost
+    }
+
+    /// Returns the namespace cost of the deployment.
+    pub const fn namespace_cost(&self) -> u64 {
+        self.namespace_cost
+    }
+
+    /// Returns the total cost of the deployment.
+    pub const fn total_cost(&self) -> u64 {
+        self.storage_cost
+            .saturating_add(self.synthesis_cost)
+            .saturating_add(self.constructor_cost)
+            .saturating_add(self.namespace_cost)
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Estimates the cost of executing the given authorization, **without** generating any proofs.
+    ///
+    /// The estimate includes every transition in the authorization, including nested calls into imported programs.
+    /// The storage cost uses an upper bound on the size of the proof, which is only known after proving.
+    pub fn estimate_execution_cost(&self, authorization: &Authorization<N>) -> Result<ExecutionCostEstimate<N>> {
+        let timer = timer!("Process::estimate_execution_cost");
+
+        // Retrieve the authorized transitions.
+        let transitions = authorization.transitions();
+        ensure!(!transitions.is_empty(), "The authorization does not contain any transitions");
+
+        // Estimate the cost of each transition.
+        let transition_costs = transitions
+            .values()
+            .map(|transition| {
+                // Retrieve the stack.
+                let stack = self.get_stack(transition.program_id())?;
+                // Compute the storage cost of the transition.
+                let storage_cost = u64::try_from(transition.to_bytes_le()?.len())?;
+                // Compute the finalize cost of the transition, excluding the futures it awaits.
+                let finalize_cost = cost_in_microcredits_without_futures(stack, transition.function_name())?;
+                Ok(TransitionCost {
+                    program_id: *transition.program_id(),
+                    function_name: *transition.function_name(),
+                    storage_cost,
+                    finalize_cost,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Estimate the cost of each transition");
+
+        // Determine the number of instances in the proof, which has one instance for each transition,
+        // and one instance for the inclusion of each input record.
+        let num_records = transitions
+            .values()
+            .flat_map(|transition| transition.inputs())
+            .filter(|input| matches!(input, Input::Record(..)))
+            .count();
+        let num_instances = transitions.len().saturating_add(num_records);
+        // Compute an upper bound on the size of the proof.
+        let proof_size = u64::try_from(Proof::<N>::max_size_in_bytes(num_instances))?;
+
+        // Construct the execution, without a proof.
+        let execution = Execution::from(transitions.into_values(), Default::default(), None)?;
+        // Compute the cost of the execution, and add the upper bound on the size of the proof.
+        let (_, (storage_cost, finalize_cost)) = execution_cost(self, &execution)?;
+        let storage_cost = storage_cost.saturating_add(proof_size);
+        finish!(timer, "Estimate the cost of the execution");
+
+        Ok(ExecutionCostEstimate { transitions: transition_costs, storage_cost, finalize_cost })
+    }
+
+    /// Estimates the cost of publishing the given deployment, **without** proving the fee.
+    pub fn estimate_deployment_cost(&self, deployment: &Deployment<N>) -> Result<DeploymentCostEstimate> {
+        // Compute the cost of the deployment.
+        let (_, (storage_cost, synthesis_cost, constructor_cost, namespace_cost)) = deployment_cost(self, deployment)?;
+        Ok(DeploymentCostEstimate { storage_cost, synthesis_cost, constructor_cost, namespace_cost })
+    }
+}
@@ -28,8 +28,10 @@ impl<N: Network> Process<N> {
 
         // Retrieve the stack.
         let stack = self.get_stack(request.program_id())?;
+        // Initialize the call stack.
+        let call_stack = CallStack::evaluate(authorization, self.dynamic_stacks(stack))?;
         // Evaluate the function.
-        let response = stack.evaluate_function::<A>(CallStack::evaluate(authorization)?, None);
+        let response = stack.evaluate_function::<A>(call_stack, None);
         lap!(timer, "Evaluate the function");
 
         finish!(timer);
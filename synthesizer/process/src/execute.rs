@@ -38,12 +38,12 @@ impl<N: Network> Process<N> {
         let root_tvk = None;
         // Initialize the trace.
         let trace = Arc::new(RwLock::new(Trace::new()));
+        // Retrieve the stack.
+        let stack = self.get_stack(request.program_id())?;
         // Initialize the call stack.
-        let call_stack = CallStack::execute(authorization, trace.clone())?;
+        let call_stack = CallStack::execute(authorization, trace.clone(), self.dynamic_stacks(stack))?;
         lap!(timer, "Initialize call stack");
 
-        // Retrieve the stack.
-        let stack = self.get_stack(request.program_id())?;
         // Execute the circuit.
         let response = stack.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?;
         lap!(timer, "Execute the function");
@@ -13,14 +13,19 @@
 // limitations under the License.
 
 use super::*;
-use console::program::{FinalizeType, Future, Register};
-use synthesizer_program::{Await, FinalizeRegistersState, Operand};
+use console::{
+    account::Address,
+    program::{FinalizeType, Future, Register},
+};
+use ledger_block::Output;
+use synthesizer_program::{Await, CommandTrait, Constructor, Event, FinalizeRegistersState, Operand};
 use utilities::handle_halting;
 
 use std::collections::HashSet;
 
 impl<N: Network> Process<N> {
     /// Finalizes the deployment and fee.
+    /// If this is the first edition of the program, its constructor (if any) is run with the given deployer address.
     /// This method assumes the given deployment **is valid**.
     /// This method should **only** be called by `VM::finalize()`.
     #[inline]
@@ -30,13 +35,17 @@ impl<N: Network> Process<N> {
         store: &FinalizeStore<N, P>,
         deployment: &Deployment<N>,
         fee: &Fee<N>,
+        deployer: &Address<N>,
     ) -> Result<(Stack<N>, Vec<FinalizeOperation<N>>)> {
         let timer = timer!("Process::finalize_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.compute_deployment_stack(deployment)?;
         lap!(timer, "Compute the stack");
 
+        // Compute the budget for the constructor, in microcredits.
+        let budget = constructor_budget(self, deployment, fee)?;
+
         // Insert the verifying keys.
         for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
             stack.insert_verifying_key(function_name, verifying_key.clone())?;
@@ -58,21 +67,40 @@ impl<N: Network> Process<N> {
 
             /* Finalize the deployment. */
 
-            // Retrieve the program ID.
-            let program_id = deployment.program_id();
-            // Iterate over the mappings.
-            for mapping in deployment.program().mappings().values() {
-                // Initialize the mapping.
-                finalize_operations.push(store.initialize_mapping(*program_id, *mapping.name())?);
+            // If the deployment is the first edition of the program, initialize the mappings.
+            // Note: An upgrade keeps the same mappings, so the existing mapping state carries over.
+            if !self.contains_program(deployment.program_id()) {
+                // Retrieve the program ID.
+                let program_id = deployment.program_id();
+                // Iterate over the mappings.
+                for mapping in deployment.program().mappings().values() {
+                    // Initialize the mapping.
+                    finalize_operations.push(store.initialize_mapping(*program_id, *mapping.name())?);
+                }
+                lap!(timer, "Initialize the program mappings");
+
+                // If the program has a constructor, run it with the deployer address.
+                // Note: The constructor only runs once, as an upgrade keeps the constructor unchanged.
+                if let Some(constructor) = deployment.program().constructor() {
+                    finalize_operations.extend(finalize_constructor(
+                        state,
+                        store,
+                        &stack,
+                        constructor,
+                        deployer,
+                        *fee.transition_id(),
+                        budget,
+                    )?);
+                }
             }
-            finish!(timer, "Initialize the program mappings");
+            finish!(timer, "Finalize the program constructor");
 
             // Return the stack and finalize operations.
             Ok((stack, finalize_operations))
         })
     }
 
-    /// Finalizes the execution and fee.
+    /// Finalizes the execution and fee, returning the finalize operations and the emitted events.
     /// This method assumes the given execution **is valid**.
     /// This method should **only** be called by `VM::finalize()`.
     #[inline]
@@ -82,7 +110,7 @@ impl<N: Network> Process<N> {
         store: &FinalizeStore<N, P>,
         execution: &Execution<N>,
         fee: Option<&Fee<N>>,
-    ) -> Result<Vec<FinalizeOperation<N>>> {
+    ) -> Result<(Vec<FinalizeOperation<N>>, Vec<Event<N>>)> {
         let timer = timer!("Program::finalize_execution");
 
         // Ensure the execution contains transitions.
@@ -94,22 +122,32 @@ impl<N: Network> Process<N> {
         // Retrieve the stack.
         let stack = self.get_stack(transition.program_id())?;
         // Ensure the number of calls matches the number of transitions.
-        let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
-        ensure!(
-            number_of_calls == execution.len(),
-            "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
-            execution.len()
-        );
+        Self::check_number_of_calls(stack, transition.function_name(), execution.len())?;
         lap!(timer, "Verify the number of transitions");
 
         // Construct the call graph.
-        let call_graph = self.construct_call_graph(execution)?;
+        let mut call_graph = self.construct_call_graph(execution)?;
+        // If the function contains a dynamic call, retain only the child transitions that output a future.
+        // Note: The callee of a dynamic call only returns plaintext values, and is never awaited in finalize.
+        if stack.contains_dynamic_call(transition.function_name())? {
+            for children in call_graph.values_mut() {
+                children.retain(|child| {
+                    execution.get_transition(child).map_or(false, |child| {
+                        child.outputs().iter().any(|output| matches!(output, Output::Future(..)))
+                    })
+                });
+            }
+        }
+
+        // Compute the budget for the finalize, in microcredits.
+        let budget = finalize_budget(execution, fee)?;
 
         atomic_batch_scope!(store, {
             // Finalize the root transition.
             // Note that this will result in all the remaining transitions being finalized, since the number
             // of calls matches the number of transitions.
-            let mut finalize_operations = finalize_transition(state, store, stack, transition, call_graph)?;
+            let (mut finalize_operations, events) =
+                finalize_transition(state, store, stack, transition, call_graph, budget)?;
 
             /* Finalize the fee. */
 
@@ -122,8 +160,8 @@ impl<N: Network> Process<N> {
             }
 
             finish!(timer);
-            // Return the finalize operations.
-            Ok(finalize_operations)
+            // Return the finalize operations and the events.
+            Ok((finalize_operations, events))
         })
     }
 
@@ -164,22 +202,26 @@ fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
     call_graph.insert(*fee.transition_id(), Vec::new());
 
     // Finalize the transition.
-    match finalize_transition(state, store, stack, fee, call_graph) {
+    // Note: The fee transition itself is not metered against a budget.
+    match finalize_transition(state, store, stack, fee, call_graph, u64::MAX) {
         // If the evaluation succeeds, return the finalize operations.
-        Ok(finalize_operations) => Ok(finalize_operations),
+        // Note: The fee programs do not emit events.
+        Ok((finalize_operations, _)) => Ok(finalize_operations),
         // If the evaluation fails, bail and return the error.
         Err(error) => bail!("'finalize' failed on '{}/{}' - {error}", fee.program_id(), fee.function_name()),
     }
 }
 
-/// Finalizes the given transition.
+/// Finalizes the given transition, returning the finalize operations and the emitted events.
+/// The cost of every executed command is charged against the given budget (in microcredits).
 fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
     state: FinalizeGlobalState,
     store: &FinalizeStore<N, P>,
     stack: &Stack<N>,
     transition: &Transition<N>,
     call_graph: HashMap<N::TransitionID, Vec<N::TransitionID>>,
-) -> Result<Vec<FinalizeOperation<N>>> {
+    budget: u64,
+) -> Result<(Vec<FinalizeOperation<N>>, Vec<Event<N>>)> {
     // Retrieve the program ID.
     let program_id = transition.program_id();
     // Retrieve the function name.
@@ -192,7 +234,7 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
     // If the last output of the transition is a future, retrieve and finalize it. Otherwise, there are no operations to finalize.
     let future = match transition.outputs().last().and_then(|output| output.future()) {
         Some(future) => future,
-        _ => return Ok(Vec::new()),
+        _ => return Ok((Vec::new(), Vec::new())),
     };
 
     // Check that the program ID and function name of the transition match those in the future.
@@ -203,6 +245,10 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
 
     // Initialize a list for finalize operations.
     let mut finalize_operations = Vec::new();
+    // Initialize a list for the emitted events.
+    let mut events = Vec::new();
+    // Initialize a counter for the microcredits consumed by the executed commands.
+    let mut consumed = 0u64;
 
     // Initialize a stack of active finalize states.
     let mut states = Vec::new();
@@ -220,10 +266,23 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
         mut awaited,
     }) = states.pop()
     {
+        // Retrieve the instruction tracer, if one is attached.
+        let tracer = stack.tracer();
+
         // Evaluate the commands.
         while counter < finalize.commands().len() {
             // Retrieve the command.
             let command = &finalize.commands()[counter];
+
+            // Charge the cost of the command, and ensure it does not exceed the budget.
+            charge(&mut consumed, cost_of_finalize_command(stack, &registers, command)?, budget, command)?;
+
+            // If tracing, record the command and its operands.
+            let step = tracer.as_ref().map(|tracer| {
+                let scope = (TracedScope::Finalize, finalize.name());
+                (tracer, tracer.begin(stack, &registers, scope, counter, command.opcode(), &command.operands()))
+            });
+
             // Finalize the command.
             match &command {
                 Command::BranchEq(branch_eq) => {
@@ -295,6 +354,11 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                     // Add the awaited register to the tracked set.
                     awaited.insert(await_.register().clone());
 
+                    // If tracing, record the completion of the command.
+                    if let Some((tracer, position)) = step {
+                        tracer.end(position, stack, &registers, vec![], None);
+                    }
+
                     // Aggregate the caller state.
                     let caller_state = FinalizeState { counter, finalize, registers, stack, call_counter, awaited };
 
@@ -305,6 +369,32 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
 
                     continue 'outer;
                 }
+                Command::Emit(emit) => {
+                    let result = handle_halting!(panic::AssertUnwindSafe(|| emit.finalize(stack, &registers)));
+                    match result {
+                        // If the evaluation succeeds, add the event to the list.
+                        Ok(Ok(event)) => events.push(event),
+                        // If the evaluation fails, bail and return the error.
+                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        // If the evaluation fails, bail and return the error.
+                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                    }
+                    counter += 1;
+                }
+                Command::Instruction(Instruction::Repeat(repeat)) => {
+                    let result = handle_halting!(panic::AssertUnwindSafe(|| {
+                        RepeatTrait::finalize(repeat, stack, &mut registers)
+                    }));
+                    match result {
+                        // If the evaluation succeeds, continue.
+                        Ok(Ok(())) => {}
+                        // If the evaluation fails, bail and return the error.
+                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        // If the evaluation fails, bail and return the error.
+                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                    }
+                    counter += 1;
+                }
                 _ => {
                     let result =
                         handle_halting!(panic::AssertUnwindSafe(|| { command.finalize(stack, store, &mut registers) }));
@@ -321,6 +411,14 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                     counter += 1;
                 }
             };
+
+            // Charge the cost of the value read by the command (if any), and ensure it does not exceed the budget.
+            charge(&mut consumed, cost_of_finalize_read(stack, &registers, command)?, budget, command)?;
+
+            // If tracing, record the destinations of the command.
+            if let Some((tracer, position)) = step {
+                tracer.end(position, stack, &registers, command.destinations(), None);
+            }
         }
         // Check that all future registers have been awaited.
         let mut unawaited = Vec::new();
@@ -336,6 +434,97 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
         );
     }
 
+    // Return the finalize operations and the events.
+    Ok((finalize_operations, events))
+}
+
+/// Finalizes the given constructor, with the given deployer address as its input (if declared),
+/// returning the finalize operations.
+/// The cost of every executed command is charged against the given budget (in microcredits).
+fn finalize_constructor<N: Network, P: FinalizeStorage<N>>(
+    state: FinalizeGlobalState,
+    store: &FinalizeStore<N, P>,
+    stack: &Stack<N>,
+    constructor: &Constructor<N>,
+    deployer: &Address<N>,
+    transition_id: N::TransitionID,
+    budget: u64,
+) -> Result<Vec<FinalizeOperation<N>>> {
+    // Retrieve the constructor logic.
+    let finalize = constructor.finalize_logic();
+
+    #[cfg(debug_assertions)]
+    println!("Finalizing constructor for {}...", stack.program_id());
+
+    // Initialize the registers.
+    let mut registers = FinalizeRegisters::new(
+        state,
+        transition_id,
+        *finalize.name(),
+        stack.get_finalize_types(finalize.name())?.clone(),
+    );
+    // Store the deployer address, if the constructor declares it as an input.
+    if let Some(input) = finalize.inputs().first() {
+        registers.store(stack, input.register(), Value::from(Literal::Address(*deployer)))?;
+    }
+
+    // Initialize a list for finalize operations.
+    let mut finalize_operations = Vec::new();
+    // Initialize a counter for the microcredits consumed by the executed commands.
+    let mut consumed = 0u64;
+    // Initialize a counter for the index of the commands.
+    let mut counter = 0;
+    // Retrieve the instruction tracer, if one is attached.
+    let tracer = stack.tracer();
+
+    // Evaluate the commands.
+    while counter < finalize.commands().len() {
+        // Retrieve the command.
+        let command = &finalize.commands()[counter];
+
+        // Charge the cost of the command, and ensure it does not exceed the budget.
+        charge(&mut consumed, cost_of_finalize_command(stack, &registers, command)?, budget, command)?;
+
+        // If tracing, record the command and its operands.
+        let step = tracer.as_ref().map(|tracer| {
+            let scope = (TracedScope::Finalize, finalize.name());
+            (tracer, tracer.begin(stack, &registers, scope, counter, command.opcode(), &command.operands()))
+        });
+
+        // Finalize the command, returning the index of the next command and the finalize operation (if any).
+        let result = handle_halting!(panic::AssertUnwindSafe(|| -> Result<(usize, Option<FinalizeOperation<N>>)> {
+            match &command {
+                Command::BranchEq(branch_eq) => Ok((branch_to(counter, branch_eq, finalize, stack, &registers)?, None)),
+                Command::BranchNeq(branch_neq) => {
+                    Ok((branch_to(counter, branch_neq, finalize, stack, &registers)?, None))
+                }
+                Command::Instruction(Instruction::Repeat(repeat)) => {
+                    RepeatTrait::finalize(repeat, stack, &mut registers).map(|()| (counter + 1, None))
+                }
+                // Note: The stack ensures a constructor does not contain these commands. This is an additional check.
+                Command::Await(..) | Command::Emit(..) => bail!("A constructor cannot await futures or emit events"),
+                _ => command.finalize(stack, store, &mut registers).map(|operation| (counter + 1, operation)),
+            }
+        }));
+        match result {
+            // If the evaluation succeeds, store the finalize operation (if any) and advance the counter.
+            Ok(Ok((next_counter, finalize_operation))) => {
+                finalize_operations.extend(finalize_operation);
+                counter = next_counter;
+                // Charge the cost of the value read by the command (if any).
+                charge(&mut consumed, cost_of_finalize_read(stack, &registers, command)?, budget, command)?;
+                // If tracing, record the destinations of the command.
+                if let Some((tracer, position)) = step {
+                    tracer.end(position, stack, &registers, command.destinations(), None);
+                }
+            }
+            // If the evaluation fails, bail and return the error.
+            Ok(Err(error)) => bail!("'constructor' failed to evaluate command ({command}): {error}"),
+            // If the evaluation fails, bail and return the error.
+            Err(_) => bail!("'constructor' failed to evaluate command ({command})"),
+        }
+    }
+
     // Return the finalize operations.
     Ok(finalize_operations)
 }
@@ -356,6 +545,19 @@ struct FinalizeState<'a, N: Network> {
     awaited: HashSet<Register<N>>,
 }
 
+/// Charges the given cost to the consumed microcredits, and ensures the total does not exceed the given budget.
+fn charge<N: Network>(consumed: &mut u64, cost: u64, budget: u64, command: &Command<N>) -> Result<()> {
+    // Charge the cost.
+    *consumed = consumed.saturating_add(cost);
+    // Ensure the consumed microcredits do not exceed the budget.
+    ensure!(
+        *consumed <= budget,
+        "'finalize' exceeded the budget of {budget} microcredits (consumed {}) on command ({command})",
+        *consumed
+    );
+    Ok(())
+}
+
 // A helper function to initialize the finalize state.
 fn initialize_finalize_state<'a, N: Network>(
     state: FinalizeGlobalState,
@@ -516,8 +718,9 @@ function compute:
         // Compute the fee.
         let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
         // Finalize the deployment.
-        let (stack, _) =
-            process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+        let (stack, _) = process
+            .finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee, &rng.gen())
+            .unwrap();
         // Add the stack *manually* to the process.
         process.add_stack(stack);
 
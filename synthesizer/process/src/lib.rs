@@ -21,6 +21,9 @@
 mod cost;
 pub use cost::*;
 
+mod estimate;
+pub use estimate::*;
+
 mod stack;
 pub use stack::*;
 
@@ -45,7 +48,18 @@ mod tests;
 use console::{
     account::PrivateKey,
     network::prelude::*,
-    program::{compute_function_id, Identifier, Literal, Locator, Plaintext, ProgramID, Record, Response, Value},
+    program::{
+        compute_function_id,
+        Identifier,
+        Literal,
+        Locator,
+        Plaintext,
+        ProgramID,
+        Record,
+        Response,
+        Value,
+        ValueType,
+    },
     types::{Field, U16, U64},
 };
 use ledger_block::{Deployment, Execution, Fee, Input, Transition};
@@ -63,7 +77,7 @@ use synthesizer_program::{
     RegistersStore,
     StackProgram,
 };
-use synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
+use synthesizer_snark::{Proof, ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
@@ -77,8 +91,10 @@ use colored::Colorize;
 pub struct Process<N: Network> {
     /// The universal SRS.
     universal_srs: Arc<UniversalSRS<N>>,
-    /// The mapping of program IDs to stacks.
-    stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The mapping of program IDs to the stacks of their latest edition.
+    stacks: Arc<IndexMap<ProgramID<N>, Arc<Stack<N>>>>,
+    /// The mapping of `(program ID, edition)` to stacks, for every loaded edition.
+    editions: IndexMap<(ProgramID<N>, u16), Arc<Stack<N>>>,
 }
 
 impl<N: Network> Process<N> {
@@ -88,7 +104,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: Default::default(),
+            editions: IndexMap::new(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -131,8 +151,17 @@ impl<N: Network> Process<N> {
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
     pub fn add_stack(&mut self, stack: Stack<N>) {
-        // Add the stack to the process.
-        self.stacks.insert(*stack.program_id(), Arc::new(stack));
+        let stack = Arc::new(stack);
+        // Add the stack to the editions.
+        self.editions.insert((*stack.program_id(), stack.edition()), stack.clone());
+        // Add the stack to the process, if it is the latest edition of the program.
+        match self.stacks.get(stack.program_id()) {
+            Some(latest) if latest.edition() > stack.edition() => (),
+            // Note: The mapping is copied on write, so snapshots held by in-flight dynamic calls are unaffected.
+            _ => {
+                Arc::make_mut(&mut self.stacks).insert(*stack.program_id(), stack);
+            }
+        }
     }
 }
 
@@ -143,7 +172,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: Default::default(),
+            editions: IndexMap::new(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -176,7 +209,11 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: Default::default(),
+            editions: IndexMap::new(),
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -197,12 +234,24 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns the stacks used to resolve the dynamic calls of the given root stack.
+    #[inline]
+    pub fn dynamic_stacks(&self, root: &Arc<Stack<N>>) -> DynamicStacks<N> {
+        DynamicStacks::new(Some(root.clone()), self.stacks.clone())
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
         self.stacks.contains_key(program_id)
     }
 
+    /// Returns `true` if the process contains the given edition of the program with the given ID.
+    #[inline]
+    pub fn contains_program_edition(&self, program_id: &ProgramID<N>, edition: u16) -> bool {
+        self.editions.contains_key(&(*program_id, edition))
+    }
+
     /// Returns the stack for the given program ID.
     #[inline]
     pub fn get_stack(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<&Arc<Stack<N>>> {
@@ -216,6 +265,27 @@ impl<N: Network> Process<N> {
         Ok(stack)
     }
 
+    /// Returns the stack for the given program ID and edition.
+    #[inline]
+    pub fn get_stack_edition(&self, program_id: impl TryInto<ProgramID<N>>, edition: u16) -> Result<&Arc<Stack<N>>> {
+        // Prepare the program ID.
+        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
+        // Retrieve the stack.
+        self.editions
+            .get(&(program_id, edition))
+            .ok_or_else(|| anyhow!("Program '{program_id}' (edition {edition}) does not exist"))
+    }
+
+    /// Returns the stacks of all loaded editions of the given program ID, from the latest to the earliest edition.
+    #[inline]
+    pub fn get_stack_editions(&self, program_id: &ProgramID<N>) -> Vec<&Arc<Stack<N>>> {
+        // Collect the stacks for the program ID.
+        let mut stacks = self.editions.values().filter(|stack| stack.program_id() == program_id).collect::<Vec<_>>();
+        // Sort the stacks from the latest to the earliest edition.
+        stacks.sort_by_key(|stack| core::cmp::Reverse(stack.edition()));
+        stacks
+    }
+
     /// Returns the program for the given program ID.
     #[inline]
     pub fn get_program(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<&Program<N>> {
@@ -281,6 +351,14 @@ impl<N: Network> Process<N> {
         // Synthesize the proving and verifying key.
         self.get_stack(program_id)?.synthesize_key::<A, R>(function_name, rng)
     }
+
+    /// Attaches the given instruction tracer to every stack in the process, or detaches it if `None` is given.
+    ///
+    /// Note: Stacks that are added to the process afterwards do not inherit the tracer.
+    #[inline]
+    pub fn set_tracer(&self, tracer: Option<InstructionTracer<N>>) {
+        self.editions.values().for_each(|stack| stack.set_tracer(tracer.clone()));
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
@@ -22,6 +22,7 @@ impl<N: Network> Stack<N> {
         private_key: &PrivateKey<N>,
         function_name: impl TryInto<Identifier<N>>,
         inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        stacks: DynamicStacks<N>,
         rng: &mut R,
     ) -> Result<Authorization<N>> {
         let timer = timer!("Stack::authorize");
@@ -47,7 +48,7 @@ impl<N: Network> Stack<N> {
         // Initialize the authorization.
         let authorization = Authorization::new(request.clone());
         // Construct the call stack.
-        let call_stack = CallStack::Authorize(vec![request], *private_key, authorization.clone());
+        let call_stack = CallStack::Authorize(vec![request], *private_key, authorization.clone(), stacks);
         // Construct the authorization from the function.
         let _response = self.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?;
         finish!(timer, "Construct the authorization from the function");
@@ -227,7 +227,7 @@ impl<N: Network> CallTrait<N> for Call<N> {
 
                 match registers.call_stack() {
                     // If the circuit is in authorize or synthesize mode, then add any external calls to the stack.
-                    CallStack::Authorize(_, private_key, authorization)
+                    CallStack::Authorize(_, private_key, authorization, _)
                     | CallStack::Synthesize(_, private_key, authorization) => {
                         // Compute the request.
                         let request = Request::sign(
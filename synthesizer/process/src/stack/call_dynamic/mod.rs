@@ -0,0 +1,456 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{stack::Address, CallStack, Registers, RegistersCall, Stack, StackEvaluate, StackExecute};
+use aleo_std::prelude::{finish, lap, timer};
+use console::{
+    network::prelude::*,
+    program::{compute_function_id, Identifier, Literal, Plaintext, Request, Value},
+};
+use synthesizer_program::{
+    CallDynamic,
+    Function,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersSigner,
+    RegistersSignerCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+    StackMatches,
+    StackProgram,
+};
+
+use std::sync::Arc;
+
+pub trait CallDynamicTrait<N: Network> {
+    /// Evaluates the instruction.
+    fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut Registers<N, A>,
+    ) -> Result<()>;
+
+    /// Executes the instruction.
+    fn execute<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut (
+                 impl RegistersCall<N>
+                 + RegistersSigner<N>
+                 + RegistersSignerCircuit<N, A>
+                 + RegistersLoadCircuit<N, A>
+                 + RegistersStoreCircuit<N, A>
+             ),
+        rng: &mut R,
+    ) -> Result<()>;
+}
+
+impl<N: Network> CallDynamicTrait<N> for CallDynamic<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        let timer = timer!("CallDynamic::evaluate");
+
+        // Load the program address and function name of the callee.
+        let (program_address, function_name) =
+            to_callee(registers.load(stack, self.program())?, registers.load(stack, self.function_name())?)?;
+        // Load the operands values.
+        let inputs: Vec<_> = self.inputs().iter().map(|operand| registers.load(stack, operand)).try_collect()?;
+
+        // Retrieve the substack and function, and ensure the function matches the declared signature.
+        let (substack, function) = get_callee(&registers.call_stack(), self, &program_address, &function_name)?;
+        lap!(timer, "Retrieved the substack and function");
+
+        // Retrieve the next request (without popping it).
+        let request = registers.call_stack().peek()?;
+        // Ensure the request is for the callee.
+        ensure!(
+            request.program_id() == substack.program_id() && request.function_name() == function.name(),
+            "Expected a request for '{}/{}', found '{}/{}' in a 'call.dynamic' instruction.",
+            substack.program_id(),
+            function.name(),
+            request.program_id(),
+            request.function_name()
+        );
+        // Ensure the inputs match the original inputs.
+        ensure!(request.inputs() == inputs.as_slice(), "Inputs do not match in a 'call.dynamic' instruction.");
+
+        // Set the (console) caller.
+        let console_caller = Some(*stack.program_id());
+        // Evaluate the function.
+        let response = substack.evaluate_function::<A>(registers.call_stack(), console_caller)?;
+        lap!(timer, "Computed outputs");
+
+        // Assign the outputs to the destination registers.
+        for (output, register) in response.outputs().iter().zip_eq(&self.destinations()) {
+            // Assign the output to the register.
+            registers.store(stack, register, output.clone())?;
+        }
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut (
+                 impl RegistersCall<N>
+                 + RegistersSigner<N>
+                 + RegistersSignerCircuit<N, A>
+                 + RegistersLoadCircuit<N, A>
+                 + RegistersStoreCircuit<N, A>
+             ),
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("CallDynamic::execute");
+
+        // Load the program address of the callee.
+        let program_address = match registers.load_circuit(stack, self.program())? {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Address(address), _)) => address,
+            _ => bail!("Expected the program in a 'call.dynamic' instruction to be an address"),
+        };
+        // Load the function name of the callee.
+        let function_name = match registers.load_circuit(stack, self.function_name())? {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(field), _)) => field,
+            _ => bail!("Expected the function name in a 'call.dynamic' instruction to be a field"),
+        };
+        // Load the operands values.
+        let inputs: Vec<_> =
+            self.inputs().iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
+        lap!(timer, "Loaded the operands");
+
+        // Retrieve the number of inputs.
+        let num_inputs = inputs.len();
+
+        // If we are not handling the root request, retrieve the root request's tvk
+        let root_tvk = registers.root_tvk().ok();
+
+        // Retrieve the number of public variables in the circuit.
+        let num_public = A::num_public();
+
+        // Indicate that external calls are never a root request.
+        let is_root = false;
+
+        use circuit::Eject;
+        // Eject the existing circuit.
+        let r1cs = A::eject_r1cs_and_reset();
+        let (request, response) = {
+            // Eject the circuit program address, function name, and inputs.
+            let program = Value::Plaintext(Plaintext::from(Literal::Address(program_address.eject_value())));
+            let function = Value::Plaintext(Plaintext::from(Literal::Field(function_name.eject_value())));
+            let inputs = inputs.eject_value();
+
+            // Set the (console) caller.
+            let console_caller = Some(*stack.program_id());
+
+            match registers.call_stack() {
+                // If the circuit is in authorize mode, then add the dynamic call to the stack.
+                CallStack::Authorize(_, private_key, authorization, _) => {
+                    // Ensure the number of transitions remains within bounds, as dynamic calls are bounded at runtime.
+                    // Note that one transition is reserved for the fee.
+                    ensure!(
+                        authorization.len() + 1 < ledger_block::Transaction::<N>::MAX_TRANSITIONS,
+                        "Number of calls exceeds the maximum allowed number of transitions"
+                    );
+                    // Retrieve the substack and function, and ensure the function matches the declared signature.
+                    let (program_address, function_name) = to_callee(program, function)?;
+                    let (substack, function) =
+                        get_callee(&registers.call_stack(), self, &program_address, &function_name)?;
+
+                    // Compute the request.
+                    let request = Request::sign(
+                        &private_key,
+                        *substack.program_id(),
+                        *function.name(),
+                        inputs.iter(),
+                        &function.input_types(),
+                        root_tvk,
+                        is_root,
+                        rng,
+                    )?;
+
+                    // Retrieve the call stack.
+                    let mut call_stack = registers.call_stack();
+                    // Push the request onto the call stack.
+                    call_stack.push(request.clone())?;
+
+                    // Add the request to the authorization.
+                    authorization.push(request.clone());
+
+                    // Execute the request.
+                    let response = substack.execute_function::<A, R>(call_stack, console_caller, root_tvk, rng)?;
+
+                    // Return the request and response.
+                    (request, response)
+                }
+                CallStack::PackageRun(requests, private_key, ..) => {
+                    // Ensure the call depth remains within bounds, as dynamic calls are only bounded at runtime.
+                    ensure!(
+                        requests.len() + 1 < ledger_block::Transaction::<N>::MAX_TRANSITIONS,
+                        "Number of calls exceeds the maximum allowed number of transitions"
+                    );
+                    // Retrieve the substack and function, and ensure the function matches the declared signature.
+                    let (program_address, function_name) = to_callee(program, function)?;
+                    let (substack, function) =
+                        get_callee(&registers.call_stack(), self, &program_address, &function_name)?;
+
+                    // Compute the request.
+                    let request = Request::sign(
+                        &private_key,
+                        *substack.program_id(),
+                        *function.name(),
+                        inputs.iter(),
+                        &function.input_types(),
+                        root_tvk,
+                        is_root,
+                        rng,
+                    )?;
+
+                    // Retrieve the call stack.
+                    let mut call_stack = registers.call_stack();
+                    // Push the request onto the call stack.
+                    call_stack.push(request.clone())?;
+
+                    // Evaluate the request.
+                    let response = substack.execute_function::<A, _>(call_stack, console_caller, root_tvk, rng)?;
+
+                    // Return the request and response.
+                    (request, response)
+                }
+                // If the circuit is in synthesize or check deployment mode, then use a placeholder callee.
+                // Note: The callee is only known at execution time, so the circuit of the caller is constructed
+                // from the declared signature, and is independent of the callee.
+                CallStack::Synthesize(_, private_key, _) | CallStack::CheckDeployment(_, private_key, ..) => {
+                    // Construct the placeholder function name.
+                    let function_name = Identifier::from_str("dynamic")?;
+                    // Compute the request.
+                    let request = Request::sign(
+                        &private_key,
+                        *stack.program_id(),
+                        function_name,
+                        inputs.iter(),
+                        self.input_types(),
+                        root_tvk,
+                        is_root,
+                        rng,
+                    )?;
+
+                    // Compute the address.
+                    let address = Address::try_from(&private_key)?;
+                    // Sample dummy outputs.
+                    let outputs = self
+                        .return_types()
+                        .iter()
+                        .map(|return_type| stack.sample_value(&address, return_type, rng))
+                        .collect::<Result<Vec<_>>>()?;
+
+                    // Compute the response.
+                    let response = crate::Response::new(
+                        request.network_id(),
+                        stack.program_id(),
+                        &function_name,
+                        request.inputs().len(),
+                        request.tvk(),
+                        request.tcm(),
+                        outputs,
+                        self.return_types(),
+                        &vec![None; self.return_types().len()],
+                    )?;
+
+                    // Return the request and response.
+                    (request, response)
+                }
+                // If the circuit is in evaluate mode, then throw an error.
+                CallStack::Evaluate(..) => {
+                    bail!("Cannot 'execute' a function in 'evaluate' mode.")
+                }
+                // If the circuit is in execute mode, then evaluate and execute the instructions.
+                CallStack::Execute(authorization, ..) => {
+                    // Retrieve the substack and function, and ensure the function matches the declared signature.
+                    let (program_address, function_name) = to_callee(program, function)?;
+                    let (substack, function) =
+                        get_callee(&registers.call_stack(), self, &program_address, &function_name)?;
+
+                    // Retrieve the next request (without popping it).
+                    let request = authorization.peek_next()?;
+                    // Ensure the request is for the callee.
+                    ensure!(
+                        request.program_id() == substack.program_id() && request.function_name() == function.name(),
+                        "Expected a request for '{}/{}', found '{}/{}' in a 'call.dynamic' instruction.",
+                        substack.program_id(),
+                        function.name(),
+                        request.program_id(),
+                        request.function_name()
+                    );
+                    // Ensure the inputs match the original inputs.
+                    ensure!(
+                        request.inputs() == inputs.as_slice(),
+                        "Inputs do not match in a 'call.dynamic' instruction."
+                    );
+
+                    // Evaluate the function, and load the outputs.
+                    let console_response =
+                        substack.evaluate_function::<A>(registers.call_stack().replicate(), console_caller)?;
+                    // Execute the request.
+                    let response =
+                        substack.execute_function::<A, R>(registers.call_stack(), console_caller, root_tvk, rng)?;
+                    // Ensure the values are equal.
+                    if console_response.outputs() != response.outputs() {
+                        #[cfg(debug_assertions)]
+                        eprintln!("\n{:#?} != {:#?}\n", console_response.outputs(), response.outputs());
+                        bail!("Function '{}' outputs do not match in a 'call.dynamic' instruction.", function.name())
+                    }
+                    // Return the request and response.
+                    (request, response)
+                }
+            }
+        };
+        lap!(timer, "Computed the request and response");
+
+        // Compute the function ID of the callee.
+        let function_id = compute_function_id(request.network_id(), request.program_id(), request.function_name())?;
+
+        // Inject the existing circuit.
+        A::inject_r1cs(r1cs);
+
+        use circuit::Inject;
+
+        // Ensure the number of public variables remains the same.
+        ensure!(A::num_public() == num_public, "Forbidden: 'call.dynamic' injected excess public variables");
+
+        // Inject the program address (as its x-coordinate) of the callee as `Mode::Public`.
+        let candidate_program =
+            circuit::Field::<A>::new(circuit::Mode::Public, request.program_id().to_address()?.to_field()?);
+        // Ensure the program address matches the operand.
+        A::assert_eq(&candidate_program, circuit::Field::from(&program_address));
+        // Inject the function name (as a field element) of the callee as `Mode::Public`.
+        let candidate_function_name =
+            circuit::Field::<A>::new(circuit::Mode::Public, request.function_name().to_field()?);
+        // Ensure the function name matches the operand.
+        A::assert_eq(&candidate_function_name, &function_name);
+        // Inject the function ID of the callee as `Mode::Public`.
+        // Note: The verifier derives the program address, function name, and function ID from the callee transition.
+        let function_id = circuit::Field::<A>::new(circuit::Mode::Public, function_id);
+
+        // Inject the `signer` (from the request) as `Mode::Private`.
+        let signer = circuit::Address::new(circuit::Mode::Private, *request.signer());
+        // Inject the `sk_tag` (from the request) as `Mode::Private`.
+        let sk_tag = circuit::Field::new(circuit::Mode::Private, *request.sk_tag());
+        // Inject the `tvk` (from the request) as `Mode::Private`.
+        let tvk = circuit::Field::new(circuit::Mode::Private, *request.tvk());
+        // Inject the `tcm` (from the request) as `Mode::Public`.
+        let tcm = circuit::Field::new(circuit::Mode::Public, *request.tcm());
+        // Compute the transition commitment as `Hash(tvk)`.
+        let candidate_tcm = A::hash_psd2(&[tvk.clone()]);
+        // Ensure the transition commitment matches the computed transition commitment.
+        A::assert_eq(&tcm, &candidate_tcm);
+        // Inject the input IDs (from the request) as `Mode::Public`.
+        let input_ids = request
+            .input_ids()
+            .iter()
+            .map(|input_id| circuit::InputID::new(circuit::Mode::Public, *input_id))
+            .collect::<Vec<_>>();
+
+        // Ensure the candidate input IDs match their computed inputs.
+        let (check_input_ids, _) = circuit::Request::check_input_ids_with_function_id::<false>(
+            &function_id,
+            None,
+            &input_ids,
+            &inputs,
+            self.input_types(),
+            &signer,
+            &sk_tag,
+            &tvk,
+            &tcm,
+            None,
+        );
+        A::assert(check_input_ids);
+        lap!(timer, "Checked the input ids");
+
+        // Inject the outputs as `Mode::Private` (with the output IDs as `Mode::Public`).
+        let outputs = circuit::Response::process_outputs_from_callback_with_function_id(
+            &function_id,
+            None,
+            num_inputs,
+            &tvk,
+            &tcm,
+            response.outputs().to_vec(),
+            self.return_types(),
+        );
+        lap!(timer, "Checked the outputs");
+
+        // Assign the outputs to the destination registers.
+        for (output, register) in outputs.into_iter().zip_eq(&self.destinations()) {
+            // Assign the output to the register.
+            registers.store_circuit(stack, register, output)?;
+        }
+        lap!(timer, "Assigned the outputs to registers");
+
+        finish!(timer);
+
+        Ok(())
+    }
+}
+
+/// Returns the program address and function name of the callee, given the program and function name values.
+fn to_callee<N: Network>(program: Value<N>, function_name: Value<N>) -> Result<(Address<N>, Identifier<N>)> {
+    // Retrieve the program address.
+    let program_address = match program {
+        Value::Plaintext(Plaintext::Literal(Literal::Address(address), _)) => address,
+        _ => bail!("Expected the program in a 'call.dynamic' instruction to be an address"),
+    };
+    // Retrieve the function name.
+    let function_name = match function_name {
+        Value::Plaintext(Plaintext::Literal(Literal::Field(field), _)) => Identifier::from_field(&field)?,
+        _ => bail!("Expected the function name in a 'call.dynamic' instruction to be a field"),
+    };
+    Ok((program_address, function_name))
+}
+
+/// Returns the stack and function of the callee, ensuring the function matches the declared signature.
+fn get_callee<N: Network>(
+    call_stack: &CallStack<N>,
+    call: &CallDynamic<N>,
+    program_address: &Address<N>,
+    function_name: &Identifier<N>,
+) -> Result<(Arc<Stack<N>>, Function<N>)> {
+    // Retrieve the stack of the callee.
+    let substack = call_stack.get_dynamic_stack(program_address)?;
+    // Retrieve the function of the callee.
+    let function = substack.get_function(function_name)?;
+    let locator = format!("{}/{}", substack.program_id(), function.name());
+
+    // Ensure the dynamic call is not to 'credits.aleo/fee_private' or 'credits.aleo/fee_public'.
+    if locator == "credits.aleo/fee_private" || locator == "credits.aleo/fee_public" {
+        bail!("Cannot perform a dynamic call to 'credits.aleo/fee_private' or 'credits.aleo/fee_public'.")
+    }
+    // Ensure the input types match the declared input types.
+    ensure!(
+        function.input_types() == call.input_types(),
+        "Function '{locator}' does not match the input types declared in a 'call.dynamic' instruction"
+    );
+    // Ensure the output types match the declared return types.
+    ensure!(
+        function.output_types() == call.return_types(),
+        "Function '{locator}' does not match the return types declared in a 'call.dynamic' instruction"
+    );
+    Ok((substack, function))
+}
@@ -36,6 +36,12 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             bail!("Expected {} inputs, found {}", closure.inputs().len(), inputs.len())
         }
 
+        // Retrieve the instruction tracer, if the closure is being evaluated.
+        let tracer = match call_stack {
+            CallStack::Evaluate(..) => self.tracer(),
+            _ => None,
+        };
+
         // Initialize the registers.
         let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(closure.name())?.clone());
         // Set the transition signer.
@@ -54,11 +60,27 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Evaluate the instructions.
-        for instruction in closure.instructions() {
+        for (index, instruction) in closure.instructions().iter().enumerate() {
+            // If tracing, record the instruction and its operands.
+            let step = tracer.as_ref().map(|tracer| {
+                let scope = (TracedScope::Closure, closure.name());
+                (tracer, tracer.begin(self, &registers, scope, index, instruction.opcode(), instruction.operands()))
+            });
+            // Evaluate the instruction.
+            let result = match instruction {
+                // If the instruction is a `repeat` instruction, we need to handle it separately.
+                Instruction::Repeat(repeat) => RepeatTrait::evaluate(repeat, self, &mut registers),
+                // Otherwise, evaluate the instruction normally.
+                _ => instruction.evaluate(self, &mut registers),
+            };
             // If the evaluation fails, bail and return the error.
-            if let Err(error) = instruction.evaluate(self, &mut registers) {
+            if let Err(error) = result {
                 bail!("Failed to evaluate instruction ({instruction}): {error}");
             }
+            // If tracing, record the destinations of the instruction.
+            if let Some((tracer, position)) = step {
+                tracer.end(position, self, &registers, instruction.destinations(), None);
+            }
         }
         lap!(timer, "Evaluate the instructions");
 
@@ -82,6 +104,10 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a closure scope."),
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => bail!("Cannot retrieve the block timestamp from a closure scope."),
+                    // If the operand is the network ID, throw an error.
+                    Operand::NetworkID => bail!("Cannot retrieve the network ID from a closure scope."),
                 }
             })
             .collect();
@@ -103,17 +129,24 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
     ) -> Result<Response<N>> {
         let timer = timer!("Stack::evaluate_function");
 
+        // Retrieve the instruction tracer, if the function is being evaluated.
+        // Note: Functions that are evaluated while in the `Execute` mode are traced by `execute_function` instead.
+        let tracer = match call_stack {
+            CallStack::Evaluate(..) => self.tracer(),
+            _ => None,
+        };
+
         // Retrieve the next request, based on the call stack mode.
         let (request, call_stack) = match &call_stack {
-            CallStack::Evaluate(authorization) => (authorization.next()?, call_stack),
+            CallStack::Evaluate(authorization, _) => (authorization.next()?, call_stack),
             // If the evaluation is performed in the `Execute` mode, create a new `Evaluate` mode.
             // This is done to ensure that evaluation during execution is performed consistently.
-            CallStack::Execute(authorization, _) => {
+            CallStack::Execute(authorization, _, stacks) => {
                 // Note: We need to replicate the authorization, so that 'execute' can call 'authorization.next()?'.
                 // This way, the authorization remains unmodified in this 'evaluate' scope.
                 let authorization = authorization.replicate();
                 let request = authorization.next()?;
-                let call_stack = CallStack::Evaluate(authorization);
+                let call_stack = CallStack::Evaluate(authorization, stacks.clone());
                 (request, call_stack)
             }
             _ => bail!("Illegal operation: call stack must be `Evaluate` or `Execute` in `evaluate_function`."),
@@ -175,11 +208,20 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
 
         // Evaluate the instructions.
         // Note: We handle the `call` instruction separately, as it requires special handling.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            // If tracing, record the instruction and its operands.
+            let step = tracer.as_ref().map(|tracer| {
+                let scope = (TracedScope::Function, function.name());
+                (tracer, tracer.begin(self, &registers, scope, index, instruction.opcode(), instruction.operands()))
+            });
             // Evaluate the instruction.
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                // If the instruction is a `call.dynamic` instruction, we need to handle it separately.
+                Instruction::CallDynamic(call) => CallDynamicTrait::evaluate(call, self, &mut registers),
+                // If the instruction is a `repeat` instruction, we need to handle it separately.
+                Instruction::Repeat(repeat) => RepeatTrait::evaluate(repeat, self, &mut registers),
                 // Otherwise, evaluate the instruction normally.
                 _ => instruction.evaluate(self, &mut registers),
             };
@@ -187,6 +229,10 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             if let Err(error) = result {
                 bail!("Failed to evaluate instruction ({instruction}): {error}");
             }
+            // If tracing, record the destinations of the instruction.
+            if let Some((tracer, position)) = step {
+                tracer.end(position, self, &registers, instruction.destinations(), None);
+            }
         }
         lap!(timer, "Evaluate the instructions");
 
@@ -213,6 +259,10 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a function scope."),
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => bail!("Cannot retrieve the block timestamp from a function scope."),
+                    // If the operand is the network ID, throw an error.
+                    Operand::NetworkID => bail!("Cannot retrieve the network ID from a function scope."),
                 }
             })
             .collect::<Result<Vec<_>>>()?;
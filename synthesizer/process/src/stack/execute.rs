@@ -43,12 +43,26 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Retrieve the number of public variables in the circuit.
         let num_public = A::num_public();
 
+        use circuit::Eject;
+
+        // Retrieve the instruction tracer, if the closure is being executed.
+        let tracer = match call_stack {
+            CallStack::Execute(..) => self.tracer(),
+            _ => None,
+        };
+
         // Initialize the registers.
         let mut registers = Registers::new(call_stack, self.get_register_types(closure.name())?.clone());
+        // Set the transition signer.
+        registers.set_signer(signer.eject_value());
         // Set the transition signer, as a circuit.
         registers.set_signer_circuit(signer);
+        // Set the transition caller.
+        registers.set_caller(caller.eject_value());
         // Set the transition caller, as a circuit.
         registers.set_caller_circuit(caller);
+        // Set the transition view key.
+        registers.set_tvk(tvk.eject_value());
         // Set the transition view key, as a circuit.
         registers.set_tvk_circuit(tvk);
         lap!(timer, "Initialize the registers");
@@ -57,7 +71,6 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         closure.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
             // If the circuit is in execute mode, then store the console input.
             if let CallStack::Execute(..) = registers.call_stack() {
-                use circuit::Eject;
                 // Assign the console input to the register.
                 registers.store(self, register, input.eject_value())?;
             }
@@ -67,16 +80,40 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Execute the instructions.
-        for instruction in closure.instructions() {
+        for (index, instruction) in closure.instructions().iter().enumerate() {
+            // If tracing, record the instruction and its operands.
+            let step = tracer.as_ref().map(|tracer| {
+                let scope = (TracedScope::Closure, closure.name());
+                let position =
+                    tracer.begin(self, &registers, scope, index, instruction.opcode(), instruction.operands());
+                (tracer, position, A::num_constraints())
+            });
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
+                // Evaluate the instruction.
+                let result = match instruction {
+                    // If the instruction is a `repeat` instruction, we need to handle it separately.
+                    Instruction::Repeat(repeat) => RepeatTrait::evaluate(repeat, self, &mut registers),
+                    // Otherwise, evaluate the instruction normally.
+                    _ => instruction.evaluate(self, &mut registers),
+                };
                 // If the evaluation fails, bail and return the error.
-                if let Err(error) = instruction.evaluate(self, &mut registers) {
+                if let Err(error) = result {
                     bail!("Failed to evaluate instruction ({instruction}): {error}");
                 }
             }
             // Execute the instruction.
-            instruction.execute(self, &mut registers)?;
+            match instruction {
+                // If the instruction is a `repeat` instruction, we need to handle it separately.
+                Instruction::Repeat(repeat) => RepeatTrait::execute(repeat, self, &mut registers)?,
+                // Otherwise, execute the instruction normally.
+                _ => instruction.execute(self, &mut registers)?,
+            }
+            // If tracing, record the destinations of the instruction and the number of constraints it added.
+            if let Some((tracer, position, num_constraints)) = step {
+                let num_constraints = A::num_constraints().saturating_sub(num_constraints);
+                tracer.end(position, self, &registers, instruction.destinations(), Some(num_constraints));
+            }
         }
         lap!(timer, "Execute the instructions");
 
@@ -115,6 +152,14 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a closure scope")
                     }
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => {
+                        bail!("Illegal operation: cannot retrieve the block timestamp in a closure scope")
+                    }
+                    // If the operand is the network ID, throw an error.
+                    Operand::NetworkID => {
+                        bail!("Illegal operation: cannot retrieve the network ID in a closure scope")
+                    }
                 }
             })
             .collect();
@@ -200,6 +245,12 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         ensure!(console_request.verify(&input_types, console_is_root), "Request is invalid");
         lap!(timer, "Verify the console request");
 
+        // Retrieve the instruction tracer, if the function is being executed.
+        let tracer = match call_stack {
+            CallStack::Execute(..) => self.tracer(),
+            _ => None,
+        };
+
         // Initialize the registers.
         let mut registers = Registers::new(call_stack, self.get_register_types(function.name())?.clone());
 
@@ -275,13 +326,24 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         let mut contains_function_call = false;
 
         // Execute the instructions.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            // If tracing, record the instruction and its operands.
+            let step = tracer.as_ref().map(|tracer| {
+                let scope = (TracedScope::Function, function.name());
+                let position =
+                    tracer.begin(self, &registers, scope, index, instruction.opcode(), instruction.operands());
+                (tracer, position, A::num_constraints())
+            });
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // Evaluate the instruction.
                 let result = match instruction {
                     // If the instruction is a `call` instruction, we need to handle it separately.
                     Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                    // If the instruction is a `call.dynamic` instruction, we need to handle it separately.
+                    Instruction::CallDynamic(call) => CallDynamicTrait::evaluate(call, self, &mut registers),
+                    // If the instruction is a `repeat` instruction, we need to handle it separately.
+                    Instruction::Repeat(repeat) => RepeatTrait::evaluate(repeat, self, &mut registers),
                     // Otherwise, evaluate the instruction normally.
                     _ => instruction.evaluate(self, &mut registers),
                 };
@@ -295,6 +357,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::execute(call, self, &mut registers, rng),
+                // If the instruction is a `call.dynamic` instruction, we need to handle it separately.
+                Instruction::CallDynamic(call) => CallDynamicTrait::execute(call, self, &mut registers, rng),
+                // If the instruction is a `repeat` instruction, we need to handle it separately.
+                Instruction::Repeat(repeat) => RepeatTrait::execute(repeat, self, &mut registers),
                 // Otherwise, execute the instruction normally.
                 _ => instruction.execute(self, &mut registers),
             };
@@ -302,13 +368,23 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             if let Err(error) = result {
                 bail!("Failed to execute instruction ({instruction}): {error}");
             }
+            // If tracing, record the destinations of the instruction and the number of constraints it added.
+            if let Some((tracer, position, num_constraints)) = step {
+                let num_constraints = A::num_constraints().saturating_sub(num_constraints);
+                tracer.end(position, self, &registers, instruction.destinations(), Some(num_constraints));
+            }
 
             // If the instruction was a function call, then set the tracker to `true`.
-            if let Instruction::Call(call) = instruction {
+            match instruction {
                 // Check if the call is a function call.
-                if call.is_function_call(self)? {
-                    contains_function_call = true;
+                Instruction::Call(call) => {
+                    if call.is_function_call(self)? {
+                        contains_function_call = true;
+                    }
                 }
+                // A dynamic call is always a function call.
+                Instruction::CallDynamic(..) => contains_function_call = true,
+                _ => (),
             }
         }
         lap!(timer, "Execute the instructions");
@@ -343,6 +419,14 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a function scope")
                     }
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => {
+                        bail!("Illegal operation: cannot retrieve the block timestamp in a function scope")
+                    }
+                    // If the operand is the network ID, throw an error.
+                    Operand::NetworkID => {
+                        bail!("Illegal operation: cannot retrieve the network ID in a function scope")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -429,7 +513,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             }
         }
         // If the circuit is in `Authorize` mode, then save the transition.
-        if let CallStack::Authorize(_, _, authorization) = registers.call_stack() {
+        if let CallStack::Authorize(_, _, authorization, _) = registers.call_stack() {
             // Construct the transition.
             let transition = Transition::from(&console_request, &response, &output_types, &output_registers)?;
             // Add the transition to the authorization.
@@ -452,7 +536,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             lap!(timer, "Save the circuit assignment");
         }
         // If the circuit is in `Execute` mode, then execute the circuit into a transition.
-        else if let CallStack::Execute(_, ref trace) = registers.call_stack() {
+        else if let CallStack::Execute(_, ref trace, _) = registers.call_stack() {
             registers.ensure_console_and_circuit_registers_match()?;
 
             // Construct the transition.
@@ -479,7 +563,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             )?;
         }
         // If the circuit is in `PackageRun` mode, then save the assignment.
-        else if let CallStack::PackageRun(_, _, ref assignments) = registers.call_stack() {
+        else if let CallStack::PackageRun(_, _, ref assignments, _) = registers.call_stack() {
             // Construct the call metrics.
             let metrics = CallMetrics {
                 program_id: *self.program_id(),
@@ -41,6 +41,14 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
             Operand::BlockHeight => {
                 return Ok(Value::Plaintext(Plaintext::from(Literal::U32(U32::new(self.state.block_height())))));
             }
+            // If the operand is the block timestamp, load the block timestamp.
+            Operand::BlockTimestamp => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::I64(I64::new(self.state.block_timestamp())))));
+            }
+            // If the operand is the network ID, load the network ID.
+            Operand::NetworkID => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::U16(U16::new(N::ID)))));
+            }
         };
 
         // Retrieve the value.
@@ -51,7 +59,7 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
             // If the register is a locator, then return the plaintext value.
             Register::Locator(..) => value.clone(),
             // If the register is a register access, then load the specific plaintext value.
-            Register::Access(_, ref path) => value.find(path)?,
+            Register::Access(_, ref path) => value.find(&self.resolve_path(stack, path)?)?,
         };
 
         // Retrieve the type of the register.
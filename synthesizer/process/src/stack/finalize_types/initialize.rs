@@ -77,6 +77,48 @@ impl<N: Network> FinalizeTypes<N> {
     }
 }
 
+impl<N: Network> FinalizeTypes<N> {
+    /// Initializes a new instance of `FinalizeTypes` for the given closure, as repeated in a finalize scope.
+    /// Checks that the given closure is well-formed for the given stack, in a finalize scope.
+    #[inline]
+    pub(super) fn initialize_closure_types(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        closure: &Closure<N>,
+    ) -> Result<Self> {
+        // Initialize a map of registers to their types.
+        let mut finalize_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new() };
+
+        // Step 1. Check the inputs are well-formed.
+        for input in closure.inputs() {
+            // Ensure the input is a plaintext type.
+            let finalize_type = match input.register_type() {
+                RegisterType::Plaintext(plaintext_type) => FinalizeType::Plaintext(plaintext_type.clone()),
+                register_type => bail!("Closure '{}' has a '{register_type}' input in finalize", closure.name()),
+            };
+            // Check the input register type.
+            finalize_types.check_input(stack, input.register(), &finalize_type)?;
+        }
+
+        // Step 2. Check the instructions are well-formed.
+        for instruction in closure.instructions() {
+            // Check the instruction opcode, operands, and destinations.
+            finalize_types.check_instruction(stack, closure.name(), instruction)?;
+        }
+
+        // Step 3. Check the outputs are well-formed.
+        for output in closure.outputs() {
+            // Retrieve the output type.
+            let output_type = RegisterType::from(finalize_types.get_type_from_operand(stack, output.operand())?);
+            // Ensure the output type matches the declared type.
+            if &output_type != output.register_type() {
+                bail!("Output '{}' does not match the expected output type in finalize", output.operand())
+            }
+        }
+
+        Ok(finalize_types)
+    }
+}
+
 impl<N: Network> FinalizeTypes<N> {
     /// Inserts the given input register and type into the registers.
     /// Note: The given input register must be a `Register::Locator`.
@@ -138,7 +180,8 @@ impl<N: Network> FinalizeTypes<N> {
     ) -> Result<()> {
         // Ensure the register type is defined in the program.
         match finalize_type {
-            FinalizeType::Plaintext(PlaintextType::Literal(..)) => (),
+            FinalizeType::Plaintext(PlaintextType::Literal(..))
+            | FinalizeType::Plaintext(PlaintextType::Optional(..)) => {}
             FinalizeType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 RegisterTypes::check_struct(stack, struct_name)?
             }
@@ -169,6 +212,7 @@ impl<N: Network> FinalizeTypes<N> {
             Command::Instruction(instruction) => self.check_instruction(stack, finalize.name(), instruction)?,
             Command::Await(await_) => self.check_await(stack, await_)?,
             Command::Contains(contains) => self.check_contains(stack, contains)?,
+            Command::Emit(emit) => self.check_emit(stack, emit)?,
             Command::Get(get) => self.check_get(stack, get)?,
             Command::GetOrUse(get_or_use) => self.check_get_or_use(stack, get_or_use)?,
             Command::RandChaCha(rand_chacha) => self.check_rand_chacha(stack, finalize.name(), rand_chacha)?,
@@ -241,6 +285,18 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `emit` command is well-formed.
+    #[inline]
+    fn check_emit(&self, stack: &(impl StackMatches<N> + StackProgram<N>), emit: &Emit<N>) -> Result<()> {
+        // Ensure the payload is a plaintext.
+        match self.get_type_from_operand(stack, emit.operand())? {
+            // If the operand is a plaintext type, return success.
+            FinalizeType::Plaintext(..) => Ok(()),
+            // If the operand is a future, throw an error.
+            FinalizeType::Future(..) => bail!("A future cannot be emitted in an `emit` command"),
+        }
+    }
+
     /// Ensures the given `contains` command is well-formed.
     #[inline]
     fn check_contains(
@@ -436,7 +492,12 @@ impl<N: Network> FinalizeTypes<N> {
             FinalizeType::Future(..) => bail!("A default value cannot be a future"),
         };
         // Check that the value type in the mapping matches the default value type.
-        if mapping_value_type != &default_value_type {
+        // An optional default `T?` may be used for a mapping with value type `T`, in which case the result is `T?`.
+        let is_optional_default = match (mapping_value_type, &default_value_type) {
+            (PlaintextType::Literal(value_type), PlaintextType::Optional(default_type)) => value_type == default_type,
+            _ => false,
+        };
+        if mapping_value_type != &default_value_type && !is_optional_default {
             bail!(
                 "Default value type in `get.or_use` '{default_value_type}' does not match the value type in the mapping '{mapping_value_type}'."
             )
@@ -636,6 +697,9 @@ impl<N: Network> FinalizeTypes<N> {
             Opcode::Call => {
                 bail!("Instruction 'call' is not allowed in 'finalize'");
             }
+            Opcode::CallDynamic => {
+                bail!("Instruction 'call.dynamic' is not allowed in 'finalize'");
+            }
             Opcode::Cast(opcode) => match opcode {
                 "cast" => {
                     // Retrieve the cast operation.
@@ -657,6 +721,9 @@ impl<N: Network> FinalizeTypes<N> {
                         | CastType::Plaintext(PlaintextType::Literal(..)) => {
                             ensure!(instruction.operands().len() == 1, "Expected 1 operand.");
                         }
+                        CastType::Plaintext(PlaintextType::Optional(optional_type)) => {
+                            bail!("Casting to the optional type '{optional_type}?' is not supported.")
+                        }
                         CastType::Plaintext(PlaintextType::Struct(struct_name)) => {
                             // Ensure the struct name exists in the program.
                             if !stack.program().contains_struct(struct_name) {
@@ -670,8 +737,19 @@ impl<N: Network> FinalizeTypes<N> {
                         CastType::Plaintext(PlaintextType::Array(array_type)) => {
                             // Ensure that the array type is valid.
                             RegisterTypes::check_array(stack, array_type)?;
+                            // Determine if a string is cast into a byte array.
+                            let is_string_to_bytes = match instruction.operands() {
+                                [operand] => {
+                                    array_type.next_element_type() == &PlaintextType::Literal(LiteralType::U8)
+                                        && self.get_type_from_operand(stack, operand)?
+                                            == FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::String))
+                                }
+                                _ => false,
+                            };
                             // Ensure the operand types match the element type.
-                            self.matches_array(stack, instruction.operands(), array_type)?;
+                            if !is_string_to_bytes {
+                                self.matches_array(stack, instruction.operands(), array_type)?;
+                            }
                         }
                         CastType::Record(..) => {
                             bail!("Illegal operation: Cannot cast to a record.")
@@ -720,7 +798,99 @@ impl<N: Network> FinalizeTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
-            Opcode::Sign => {
+            Opcode::Merkle(opcode) => {
+                match opcode {
+                    "merkle.verify.bhp" => ensure!(
+                        matches!(instruction, Instruction::MerkleVerifyBHP(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "merkle.verify.psd" => ensure!(
+                        matches!(instruction, Instruction::MerkleVerifyPSD(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
+                // Ensure the instruction has one destination register.
+                ensure!(
+                    instruction.destinations().len() == 1,
+                    "Instruction '{instruction}' has multiple destinations."
+                );
+            }
+            Opcode::Option(opcode) => {
+                match opcode {
+                    "option.is_some" => ensure!(
+                        matches!(instruction, Instruction::OptionIsSome(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "option.none" => ensure!(
+                        matches!(instruction, Instruction::OptionNone(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "option.some" => ensure!(
+                        matches!(instruction, Instruction::OptionSome(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "option.unwrap" => ensure!(
+                        matches!(instruction, Instruction::OptionUnwrap(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
+                // Ensure the instruction has one destination register.
+                ensure!(
+                    instruction.destinations().len() == 1,
+                    "Instruction '{instruction}' has multiple destinations."
+                );
+            }
+            Opcode::Repeat => {
+                // Ensure the repeated closure is defined.
+                RegisterTypes::check_repeat_opcode(stack, finalize_name, instruction)?;
+                // Retrieve the repeated closure.
+                let closure = match instruction {
+                    Instruction::Repeat(repeat) => stack.program().get_closure(repeat.closure())?,
+                    _ => bail!("Instruction '{instruction}' is not a repeat operation."),
+                };
+                // Ensure the repeated closure is well-formed in a finalize scope.
+                Self::initialize_closure_types(stack, &closure)?;
+            }
+            Opcode::Serialize(opcode) => {
+                match opcode {
+                    "serialize.bits" => ensure!(
+                        matches!(instruction, Instruction::SerializeBits(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "serialize.bytes" => ensure!(
+                        matches!(instruction, Instruction::SerializeBytes(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "deserialize.bits" => ensure!(
+                        matches!(instruction, Instruction::DeserializeBits(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "deserialize.bytes" => ensure!(
+                        matches!(instruction, Instruction::DeserializeBytes(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
+                // Ensure the instruction has one destination register.
+                ensure!(
+                    instruction.destinations().len() == 1,
+                    "Instruction '{instruction}' has multiple destinations."
+                );
+            }
+            Opcode::Sign(opcode) => {
+                match opcode {
+                    "sign.verify" => ensure!(
+                        matches!(instruction, Instruction::SignVerify(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "ecdsa.verify" => ensure!(
+                        matches!(instruction, Instruction::ECDSAVerify(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
                 // Ensure the instruction has one destination register.
                 ensure!(
                     instruction.destinations().len() == 1,
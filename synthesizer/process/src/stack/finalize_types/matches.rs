@@ -96,6 +96,26 @@ impl<N: Network> FinalizeTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_height_type}' in the operand '{operand}'.",
                     )
                 }
+                // Ensure the block timestamp type (i64) matches the member type.
+                Operand::BlockTimestamp => {
+                    // Retrieve the block timestamp type.
+                    let block_timestamp_type = PlaintextType::Literal(LiteralType::I64);
+                    // Ensure the block timestamp type matches the member type.
+                    ensure!(
+                        &block_timestamp_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_timestamp_type}' in the operand '{operand}'.",
+                    )
+                }
+                // Ensure the network ID type (u16) matches the member type.
+                Operand::NetworkID => {
+                    // Retrieve the network ID type.
+                    let network_id_type = PlaintextType::Literal(LiteralType::U16);
+                    // Ensure the network ID type matches the member type.
+                    ensure!(
+                        &network_id_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{network_id_type}' in the operand '{operand}'.",
+                    )
+                }
             }
         }
         Ok(())
@@ -177,6 +197,28 @@ impl<N: Network> FinalizeTypes<N> {
                         array_type.next_element_type()
                     )
                 }
+                // Ensure the block timestamp type (i64) matches the member type.
+                Operand::BlockTimestamp => {
+                    // Retrieve the block timestamp type.
+                    let block_timestamp_type = PlaintextType::Literal(LiteralType::I64);
+                    // Ensure the block timestamp type matches the member type.
+                    ensure!(
+                        &block_timestamp_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{block_timestamp_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
+                // Ensure the network ID type (u16) matches the member type.
+                Operand::NetworkID => {
+                    // Retrieve the network ID type.
+                    let network_id_type = PlaintextType::Literal(LiteralType::U16);
+                    // Ensure the network ID type matches the member type.
+                    ensure!(
+                        &network_id_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{network_id_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
             }
         }
         Ok(())
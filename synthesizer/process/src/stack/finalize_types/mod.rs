@@ -37,8 +37,10 @@ use synthesizer_program::{
     Branch,
     CallOperator,
     CastType,
+    Closure,
     Command,
     Contains,
+    Emit,
     Finalize,
     Get,
     GetOrUse,
@@ -76,6 +78,13 @@ impl<N: Network> FinalizeTypes<N> {
         Self::initialize_finalize_types(stack, finalize)
     }
 
+    /// Initializes a new instance of `FinalizeTypes` for the given closure, as repeated in a finalize scope.
+    /// Checks that the given closure is well-formed for the given stack, in a finalize scope.
+    #[inline]
+    pub fn from_closure(stack: &(impl StackMatches<N> + StackProgram<N>), closure: &Closure<N>) -> Result<Self> {
+        Self::initialize_closure_types(stack, closure)
+    }
+
     /// Returns `true` if the given register exists.
     pub fn contains(&self, register: &Register<N>) -> bool {
         // Retrieve the register locator.
@@ -103,6 +112,8 @@ impl<N: Network> FinalizeTypes<N> {
             Operand::Signer => bail!("'self.signer' is not a valid operand in a finalize context."),
             Operand::Caller => bail!("'self.caller' is not a valid operand in a finalize context."),
             Operand::BlockHeight => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
+            Operand::BlockTimestamp => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::I64)),
+            Operand::NetworkID => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U16)),
         })
     }
 
@@ -152,6 +163,10 @@ impl<N: Network> FinalizeTypes<N> {
                 (FinalizeRefType::Plaintext(PlaintextType::Literal(..)), _) => {
                     bail!("'{register}' references a literal.")
                 }
+                // Ensure the plaintext type is not an optional, as the register references an access.
+                (FinalizeRefType::Plaintext(PlaintextType::Optional(..)), _) => {
+                    bail!("'{register}' references an optional.")
+                }
                 // Access the member on the path to output the register type.
                 (FinalizeRefType::Plaintext(PlaintextType::Struct(struct_name)), Access::Member(identifier)) => {
                     // Retrieve the member type from the struct and check that it exists.
@@ -171,6 +186,19 @@ impl<N: Network> FinalizeTypes<N> {
                         false => bail!("Index out of bounds"),
                     }
                 }
+                // Access the element on the path to output the register type.
+                // Note: The bounds of a register index are checked when the register is loaded.
+                (FinalizeRefType::Plaintext(PlaintextType::Array(array_type)), Access::Register(locator)) => {
+                    // Ensure the index register is a `u32`.
+                    match self.get_type(stack, &Register::Locator(*locator))? {
+                        // Retrieve the element type and update `finalize_type` for the next iteration.
+                        FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)) => {
+                            finalize_type = FinalizeRefType::Plaintext(array_type.next_element_type())
+                        }
+                        // Halts if the index is not a `u32`.
+                        index_type => bail!("Index 'r{locator}' for '{register}' must be a 'u32', found '{index_type}'"),
+                    }
+                }
                 // Access the input to the future to output the register type and check that it is in bounds.
                 (FinalizeRefType::Future(locator), Access::Index(index)) => {
                     // Retrieve the associated function.
@@ -198,9 +226,9 @@ impl<N: Network> FinalizeTypes<N> {
                         None => bail!("Index out of bounds"),
                     }
                 }
-                (FinalizeRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
+                (FinalizeRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..) | Access::Register(..))
                 | (FinalizeRefType::Plaintext(PlaintextType::Array(..)), Access::Member(..))
-                | (FinalizeRefType::Future(..), Access::Member(..)) => {
+                | (FinalizeRefType::Future(..), Access::Member(..) | Access::Register(..)) => {
                     bail!("Invalid access `{access}`")
                 }
             }
@@ -15,12 +15,13 @@
 use super::*;
 
 impl<N: Network> Stack<N> {
-    /// Initializes a new stack, given the process and program.
+    /// Initializes a new stack, given the process, program, and edition.
     #[inline]
-    pub(crate) fn initialize(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+    pub(crate) fn initialize(process: &Process<N>, program: &Program<N>, edition: u16) -> Result<Self> {
         // Construct the stack for the program.
         let mut stack = Self {
             program: program.clone(),
+            edition,
             external_stacks: Default::default(),
             register_types: Default::default(),
             finalize_types: Default::default(),
@@ -28,8 +29,10 @@ impl<N: Network> Stack<N> {
             proving_keys: Default::default(),
             verifying_keys: Default::default(),
             number_of_calls: Default::default(),
+            dynamic_calls: Default::default(),
             finalize_costs: Default::default(),
             program_depth: 0,
+            tracer: Default::default(),
         };
 
         // Add all the imports into the stack.
@@ -59,20 +62,36 @@ impl<N: Network> Stack<N> {
         for function in program.functions().values() {
             // Add the function to the stack.
             stack.insert_function(function)?;
-            // Determine the number of calls for the function.
+            // Determine the number of calls for the function, and whether it contains a dynamic call.
             let mut num_calls = 1;
+            let mut contains_dynamic_call = false;
             for instruction in function.instructions() {
-                if let Instruction::Call(call) = instruction {
-                    // Determine if this is a function call.
-                    if call.is_function_call(&stack)? {
-                        // Increment by the number of calls.
-                        num_calls += match call.operator() {
-                            CallOperator::Locator(locator) => stack
-                                .get_external_stack(locator.program_id())?
-                                .get_number_of_calls(locator.resource())?,
-                            CallOperator::Resource(resource) => stack.get_number_of_calls(resource)?,
-                        };
+                match instruction {
+                    Instruction::Call(call) => {
+                        // Determine if this is a function call.
+                        if call.is_function_call(&stack)? {
+                            // Increment by the number of calls.
+                            num_calls += match call.operator() {
+                                CallOperator::Locator(locator) => {
+                                    let external_stack = stack.get_external_stack(locator.program_id())?;
+                                    contains_dynamic_call |=
+                                        external_stack.contains_dynamic_call(locator.resource())?;
+                                    external_stack.get_number_of_calls(locator.resource())?
+                                }
+                                CallOperator::Resource(resource) => {
+                                    contains_dynamic_call |= stack.contains_dynamic_call(resource)?;
+                                    stack.get_number_of_calls(resource)?
+                                }
+                            };
+                        }
                     }
+                    // Note: The callee of a dynamic call is only known at execution time,
+                    // so it is counted as a single call, and the number of calls is a lower bound.
+                    Instruction::CallDynamic(..) => {
+                        num_calls += 1;
+                        contains_dynamic_call = true;
+                    }
+                    _ => (),
                 }
             }
             // Check that the number of calls does not exceed the maximum.
@@ -83,6 +102,8 @@ impl<N: Network> Stack<N> {
             );
             // Add the number of calls to the stack.
             stack.number_of_calls.insert(*function.name(), num_calls);
+            // Add the dynamic call indicator to the stack.
+            stack.dynamic_calls.insert(*function.name(), contains_dynamic_call);
 
             // Get the finalize cost.
             let finalize_cost = cost_in_microcredits(&stack, function.name())?;
@@ -96,6 +117,11 @@ impl<N: Network> Stack<N> {
             stack.finalize_costs.insert(*function.name(), finalize_cost);
         }
 
+        // Add the program constructor (if any) to the stack.
+        if let Some(constructor) = program.constructor() {
+            stack.insert_constructor(constructor)?;
+        }
+
         // Return the stack.
         Ok(stack)
     }
@@ -158,4 +184,28 @@ impl<N: Network> Stack<N> {
         // Return success.
         Ok(())
     }
+
+    /// Adds the finalize types of the given constructor to the stack.
+    #[inline]
+    fn insert_constructor(&mut self, constructor: &Constructor<N>) -> Result<()> {
+        // Retrieve the constructor logic.
+        let finalize = constructor.finalize_logic();
+        // Ensure the constructor name is not already added.
+        ensure!(!self.finalize_types.contains_key(finalize.name()), "Constructor already exists");
+
+        // Ensure the constructor does not await futures or emit events.
+        // Note: The constructor runs during the deployment, so there are no transitions to await or to emit from.
+        for command in finalize.commands() {
+            if matches!(command, Command::Await(..) | Command::Emit(..)) {
+                bail!("Forbidden operation: A constructor cannot invoke '{command}'")
+            }
+        }
+
+        // Compute the finalize types.
+        let finalize_types = FinalizeTypes::from_finalize(self, finalize)?;
+        // Add the constructor name and finalize types to the stack.
+        self.finalize_types.insert(*finalize.name(), finalize_types);
+        // Return success.
+        Ok(())
+    }
 }
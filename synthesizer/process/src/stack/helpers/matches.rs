@@ -200,6 +200,8 @@ impl<N: Network> Stack<N> {
                 Plaintext::Struct(..) => bail!("'{plaintext_type}' is invalid: expected literal, found struct"),
                 // If `plaintext` is an array, this is a mismatch.
                 Plaintext::Array(..) => bail!("'{plaintext_type}' is invalid: expected literal, found array"),
+                // If `plaintext` is an optional, this is a mismatch.
+                Plaintext::Optional(..) => bail!("'{plaintext_type}' is invalid: expected literal, found optional"),
             },
             PlaintextType::Struct(struct_name) => {
                 // Ensure the struct name is valid.
@@ -220,6 +222,7 @@ impl<N: Network> Stack<N> {
                     Plaintext::Literal(..) => bail!("'{struct_name}' is invalid: expected struct, found literal"),
                     Plaintext::Struct(members, ..) => members,
                     Plaintext::Array(..) => bail!("'{struct_name}' is invalid: expected struct, found array"),
+                    Plaintext::Optional(..) => bail!("'{struct_name}' is invalid: expected struct, found optional"),
                 };
 
                 let num_members = members.len();
@@ -265,6 +268,8 @@ impl<N: Network> Stack<N> {
                 Plaintext::Literal(..) => bail!("'{plaintext_type}' is invalid: expected array, found literal"),
                 // If `plaintext` is a struct, this is a mismatch.
                 Plaintext::Struct(..) => bail!("'{plaintext_type}' is invalid: expected array, found struct"),
+                // If `plaintext` is an optional, this is a mismatch.
+                Plaintext::Optional(..) => bail!("'{plaintext_type}' is invalid: expected array, found optional"),
                 // If `plaintext` is an array, it must match the array type.
                 Plaintext::Array(array, ..) => {
                     // Ensure the array length matches.
@@ -281,6 +286,17 @@ impl<N: Network> Stack<N> {
                     Ok(())
                 }
             },
+            PlaintextType::Optional(literal_type) => match plaintext {
+                // If `plaintext` is an optional, its literal must match the literal type.
+                Plaintext::Optional(_, literal, ..) => match literal.to_type() == *literal_type {
+                    true => Ok(()),
+                    false => bail!("'{plaintext_type}' is invalid: expected {literal_type}, found {literal}"),
+                },
+                // Otherwise, this is a mismatch.
+                Plaintext::Literal(..) | Plaintext::Struct(..) | Plaintext::Array(..) => {
+                    bail!("'{plaintext_type}' is invalid: expected optional, found '{plaintext}'")
+                }
+            },
         }
     }
 
@@ -148,6 +148,11 @@ impl<N: Network> Stack<N> {
 
                 Plaintext::Array(elements, Default::default())
             }
+            // Sample an optional.
+            PlaintextType::Optional(literal_type) => match bool::rand(rng) {
+                true => Plaintext::some(Literal::sample(*literal_type, rng))?,
+                false => Plaintext::none(*literal_type)?,
+            },
         };
         // Return the plaintext.
         Ok(plaintext)
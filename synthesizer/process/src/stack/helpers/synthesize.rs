@@ -27,6 +27,75 @@ impl<N: Network> Stack<N> {
             return Ok(());
         }
 
+        // Sample a request for the function, with a burner private key.
+        let (request, burner_private_key) = self.sample_burner_request(function_name, rng)?;
+        // The `root_tvk` is `None` when deploying an individual circuit.
+        let root_tvk = None;
+        // The caller is `None` when deploying an individual circuit.
+        let caller = None;
+
+        // Initialize the authorization.
+        let authorization = Authorization::new(request.clone());
+        // Initialize the call stack.
+        let call_stack = CallStack::Synthesize(vec![request], burner_private_key, authorization);
+        // Synthesize the circuit.
+        let _response = self.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?;
+
+        // Ensure the proving key exists.
+        ensure!(self.contains_proving_key(function_name), "Function '{function_name}' is missing a proving key.");
+        // Ensure the verifying key exists.
+        ensure!(self.contains_verifying_key(function_name), "Function '{function_name}' is missing a verifying key.");
+        Ok(())
+    }
+
+    /// Synthesizes and stores the `(proving_key, verifying_key)` for the given function name and assignment.
+    #[inline]
+    pub fn synthesize_from_assignment(
+        &self,
+        function_name: &Identifier<N>,
+        assignment: &circuit::Assignment<N::Field>,
+    ) -> Result<()> {
+        // If the proving and verifying key already exist, skip the synthesis for this function.
+        if self.contains_proving_key(function_name) && self.contains_verifying_key(function_name) {
+            return Ok(());
+        }
+
+        // Synthesize the proving and verifying key.
+        let (proving_key, verifying_key) = self.universal_srs.to_circuit_key(&function_name.to_string(), assignment)?;
+        // Insert the proving key.
+        self.insert_proving_key(function_name, proving_key)?;
+        // Insert the verifying key.
+        self.insert_verifying_key(function_name, verifying_key)
+    }
+
+    /// Synthesizes the circuit for the given function name, and returns its assignment.
+    /// The inputs are sampled, so the assignment is only meaningful for its structure and satisfiability.
+    #[inline]
+    pub fn synthesize_assignment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<circuit::Assignment<N::Field>> {
+        // Sample a request for the function, with a burner private key.
+        let (request, burner_private_key) = self.sample_burner_request(function_name, rng)?;
+        // Initialize the assignments.
+        let assignments = Assignments::<N>::default();
+        // Initialize the call stack.
+        let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone(), None);
+        // Synthesize the circuit.
+        // The caller and `root_tvk` are `None` when synthesizing an individual circuit.
+        let _response = self.execute_function::<A, R>(call_stack, None, None, rng)?;
+        // Return the assignment.
+        let assignment = assignments.read().last().map(|(assignment, _metrics)| assignment.clone());
+        assignment.ok_or_else(|| anyhow!("The assignment for function '{function_name}' is missing"))
+    }
+
+    /// Returns a request for the given function name with sampled inputs, signed by a burner private key.
+    fn sample_burner_request<R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<(Request<N>, PrivateKey<N>)> {
         // Retrieve the program ID.
         let program_id = self.program_id();
         // Retrieve the function input types.
@@ -55,9 +124,6 @@ impl<N: Network> Stack<N> {
         // The `root_tvk` is `None` when deploying an individual circuit.
         let root_tvk = None;
 
-        // The caller is `None` when deploying an individual circuit.
-        let caller = None;
-
         // Compute the request, with a burner private key.
         let request = Request::sign(
             &burner_private_key,
@@ -69,37 +135,6 @@ impl<N: Network> Stack<N> {
             is_root,
             rng,
         )?;
-        // Initialize the authorization.
-        let authorization = Authorization::new(request.clone());
-        // Initialize the call stack.
-        let call_stack = CallStack::Synthesize(vec![request], burner_private_key, authorization);
-        // Synthesize the circuit.
-        let _response = self.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?;
-
-        // Ensure the proving key exists.
-        ensure!(self.contains_proving_key(function_name), "Function '{function_name}' is missing a proving key.");
-        // Ensure the verifying key exists.
-        ensure!(self.contains_verifying_key(function_name), "Function '{function_name}' is missing a verifying key.");
-        Ok(())
-    }
-
-    /// Synthesizes and stores the `(proving_key, verifying_key)` for the given function name and assignment.
-    #[inline]
-    pub fn synthesize_from_assignment(
-        &self,
-        function_name: &Identifier<N>,
-        assignment: &circuit::Assignment<N::Field>,
-    ) -> Result<()> {
-        // If the proving and verifying key already exist, skip the synthesis for this function.
-        if self.contains_proving_key(function_name) && self.contains_verifying_key(function_name) {
-            return Ok(());
-        }
-
-        // Synthesize the proving and verifying key.
-        let (proving_key, verifying_key) = self.universal_srs.to_circuit_key(&function_name.to_string(), assignment)?;
-        // Insert the proving key.
-        self.insert_proving_key(function_name, proving_key)?;
-        // Insert the verifying key.
-        self.insert_verifying_key(function_name, verifying_key)
+        Ok((request, burner_private_key))
     }
 }
@@ -18,6 +18,9 @@ pub use authorization::*;
 mod call;
 pub use call::*;
 
+mod call_dynamic;
+pub use call_dynamic::*;
+
 mod finalize_registers;
 pub use finalize_registers::*;
 
@@ -30,11 +33,18 @@ pub use register_types::*;
 mod registers;
 pub use registers::*;
 
+mod repeat;
+pub use repeat::*;
+
+mod tracer;
+pub use tracer::*;
+
 mod authorize;
 mod deploy;
 mod evaluate;
 mod execute;
 mod helpers;
+mod upgrade;
 
 use crate::{cost_in_microcredits, traits::*, CallMetrics, Process, Trace};
 use console::{
@@ -64,7 +74,17 @@ use console::{
     types::{Field, Group},
 };
 use ledger_block::{Deployment, Transition};
-use synthesizer_program::{traits::*, CallOperator, Closure, Function, Instruction, Operand, Program};
+use synthesizer_program::{
+    traits::*,
+    CallOperator,
+    Closure,
+    Command,
+    Constructor,
+    Function,
+    Instruction,
+    Operand,
+    Program,
+};
 use synthesizer_snark::{Certificate, ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
@@ -79,23 +99,75 @@ pub type Assignments<N> = Arc<RwLock<Vec<(circuit::Assignment<<N as Environment>
 
 #[derive(Clone)]
 pub enum CallStack<N: Network> {
-    Authorize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
+    Authorize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>, DynamicStacks<N>),
     Synthesize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     CheckDeployment(Vec<Request<N>>, PrivateKey<N>, Assignments<N>, Option<u64>),
-    Evaluate(Authorization<N>),
-    Execute(Authorization<N>, Arc<RwLock<Trace<N>>>),
-    PackageRun(Vec<Request<N>>, PrivateKey<N>, Assignments<N>),
+    Evaluate(Authorization<N>, DynamicStacks<N>),
+    Execute(Authorization<N>, Arc<RwLock<Trace<N>>>, DynamicStacks<N>),
+    PackageRun(Vec<Request<N>>, PrivateKey<N>, Assignments<N>, DynamicStacks<N>),
 }
 
 impl<N: Network> CallStack<N> {
     /// Initializes a call stack as `Self::Evaluate`.
-    pub fn evaluate(authorization: Authorization<N>) -> Result<Self> {
-        Ok(CallStack::Evaluate(authorization))
+    pub fn evaluate(authorization: Authorization<N>, stacks: DynamicStacks<N>) -> Result<Self> {
+        Ok(CallStack::Evaluate(authorization, stacks))
     }
 
     /// Initializes a call stack as `Self::Execute`.
-    pub fn execute(authorization: Authorization<N>, trace: Arc<RwLock<Trace<N>>>) -> Result<Self> {
-        Ok(CallStack::Execute(authorization, trace))
+    pub fn execute(
+        authorization: Authorization<N>,
+        trace: Arc<RwLock<Trace<N>>>,
+        stacks: DynamicStacks<N>,
+    ) -> Result<Self> {
+        Ok(CallStack::Execute(authorization, trace, stacks))
+    }
+
+    /// Returns the stack of the program with the given program address, for a dynamic call.
+    pub fn get_dynamic_stack(&self, program_address: &Address<N>) -> Result<Arc<Stack<N>>> {
+        match self {
+            CallStack::Authorize(.., stacks)
+            | CallStack::Evaluate(_, stacks)
+            | CallStack::Execute(.., stacks)
+            | CallStack::PackageRun(.., stacks) => stacks.get(program_address),
+            CallStack::Synthesize(..) | CallStack::CheckDeployment(..) => {
+                bail!("Dynamic calls are not resolved when synthesizing a circuit")
+            }
+        }
+    }
+}
+
+/// The stacks that a dynamic call may resolve to, captured from the process when the call stack is initialized.
+#[derive(Clone)]
+pub struct DynamicStacks<N: Network> {
+    /// The stack of the root program, which takes precedence over the stacks of the process.
+    root: Option<Arc<Stack<N>>>,
+    /// The mapping of program IDs to the stacks of their latest edition.
+    stacks: Arc<IndexMap<ProgramID<N>, Arc<Stack<N>>>>,
+}
+
+impl<N: Network> Default for DynamicStacks<N> {
+    /// Initializes the dynamic stacks without any stacks.
+    fn default() -> Self {
+        Self::new(None, Default::default())
+    }
+}
+
+impl<N: Network> DynamicStacks<N> {
+    /// Initializes the dynamic stacks from the given root stack and the stacks of the process.
+    pub const fn new(root: Option<Arc<Stack<N>>>, stacks: Arc<IndexMap<ProgramID<N>, Arc<Stack<N>>>>) -> Self {
+        Self { root, stacks }
+    }
+
+    /// Returns the stack of the program with the given program address.
+    /// Note: This mirrors `Process::get_stack_with_root`, which resolves static calls during verification.
+    pub fn get(&self, program_address: &Address<N>) -> Result<Arc<Stack<N>>> {
+        // Check the root stack first, as it may be an edition that is not the latest in the process.
+        for stack in self.root.iter().chain(self.stacks.values()) {
+            if stack.program_id().to_address()? == *program_address {
+                return Ok(stack.clone());
+            }
+        }
+        bail!("Program with address '{program_address}' does not exist.")
     }
 }
 
@@ -103,8 +175,8 @@ impl<N: Network> CallStack<N> {
     /// Returns a new and independent replica of the call stack.
     pub fn replicate(&self) -> Self {
         match self {
-            CallStack::Authorize(requests, private_key, authorization) => {
-                CallStack::Authorize(requests.clone(), *private_key, authorization.replicate())
+            CallStack::Authorize(requests, private_key, authorization, stacks) => {
+                CallStack::Authorize(requests.clone(), *private_key, authorization.replicate(), stacks.clone())
             }
             CallStack::Synthesize(requests, private_key, authorization) => {
                 CallStack::Synthesize(requests.clone(), *private_key, authorization.replicate())
@@ -117,13 +189,18 @@ impl<N: Network> CallStack<N> {
                     *constraint_limit,
                 )
             }
-            CallStack::Evaluate(authorization) => CallStack::Evaluate(authorization.replicate()),
-            CallStack::Execute(authorization, trace) => {
-                CallStack::Execute(authorization.replicate(), Arc::new(RwLock::new(trace.read().clone())))
-            }
-            CallStack::PackageRun(requests, private_key, assignments) => {
-                CallStack::PackageRun(requests.clone(), *private_key, Arc::new(RwLock::new(assignments.read().clone())))
-            }
+            CallStack::Evaluate(authorization, stacks) => CallStack::Evaluate(authorization.replicate(), stacks.clone()),
+            CallStack::Execute(authorization, trace, stacks) => CallStack::Execute(
+                authorization.replicate(),
+                Arc::new(RwLock::new(trace.read().clone())),
+                stacks.clone(),
+            ),
+            CallStack::PackageRun(requests, private_key, assignments, stacks) => CallStack::PackageRun(
+                requests.clone(),
+                *private_key,
+                Arc::new(RwLock::new(assignments.read().clone())),
+                stacks.clone(),
+            ),
         }
     }
 
@@ -134,7 +211,7 @@ impl<N: Network> CallStack<N> {
             | CallStack::Synthesize(requests, ..)
             | CallStack::CheckDeployment(requests, ..)
             | CallStack::PackageRun(requests, ..) => requests.push(request),
-            CallStack::Evaluate(authorization) => authorization.push(request),
+            CallStack::Evaluate(authorization, _) => authorization.push(request),
             CallStack::Execute(authorization, ..) => authorization.push(request),
         }
         Ok(())
@@ -149,7 +226,7 @@ impl<N: Network> CallStack<N> {
             | CallStack::PackageRun(requests, ..) => {
                 requests.pop().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
-            CallStack::Evaluate(authorization) => authorization.next(),
+            CallStack::Evaluate(authorization, _) => authorization.next(),
             CallStack::Execute(authorization, ..) => authorization.next(),
         }
     }
@@ -163,7 +240,7 @@ impl<N: Network> CallStack<N> {
             | CallStack::PackageRun(requests, ..) => {
                 requests.last().cloned().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
-            CallStack::Evaluate(authorization) => authorization.peek_next(),
+            CallStack::Evaluate(authorization, _) => authorization.peek_next(),
             CallStack::Execute(authorization, ..) => authorization.peek_next(),
         }
     }
@@ -173,6 +250,8 @@ impl<N: Network> CallStack<N> {
 pub struct Stack<N: Network> {
     /// The program (record types, structs, functions).
     program: Program<N>,
+    /// The program edition.
+    edition: u16,
     /// The mapping of external stacks as `(program ID, stack)`.
     external_stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
     /// The mapping of closure and function names to their register types.
@@ -187,10 +266,14 @@ pub struct Stack<N: Network> {
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
     /// The mapping of function names to the number of calls.
     number_of_calls: IndexMap<Identifier<N>, usize>,
+    /// The mapping of function names to whether they (transitively) contain a dynamic call.
+    dynamic_calls: IndexMap<Identifier<N>, bool>,
     /// The mapping of function names to finalize cost.
     finalize_costs: IndexMap<Identifier<N>, u64>,
     /// The program depth.
     program_depth: usize,
+    /// The instruction tracer, if one is attached.
+    tracer: Arc<RwLock<Option<InstructionTracer<N>>>>,
 }
 
 impl<N: Network> Stack<N> {
@@ -201,6 +284,17 @@ impl<N: Network> Stack<N> {
         let program_id = program.id();
         // Ensure the program does not already exist in the process.
         ensure!(!process.contains_program(program_id), "Program '{program_id}' already exists");
+        // Ensure the program is well-formed.
+        Self::check_program_is_well_formed(program)?;
+        // Return the stack.
+        Stack::initialize(process, program, N::EDITION)
+    }
+
+    /// Ensures the given program contains functions, and serializes and deserializes correctly.
+    #[inline]
+    pub(crate) fn check_program_is_well_formed(program: &Program<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = program.id();
         // Ensure the program contains functions.
         ensure!(!program.functions().is_empty(), "No functions present in the deployment for program '{program_id}'");
 
@@ -214,8 +308,7 @@ impl<N: Network> Stack<N> {
         // Ensure the program deserializes from a string correctly.
         ensure!(program == &Program::from_str(&program_string)?, "Program string serialization failed");
 
-        // Return the stack.
-        Stack::initialize(process, program)
+        Ok(())
     }
 }
 
@@ -364,6 +457,22 @@ impl<N: Network> StackProgramTypes<N> for Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Returns the program edition.
+    #[inline]
+    pub const fn edition(&self) -> u16 {
+        self.edition
+    }
+
+    /// Returns `true` if the given function (transitively) contains a dynamic call.
+    /// In this case, the number of calls for the function is only a lower bound.
+    #[inline]
+    pub fn contains_dynamic_call(&self, function_name: &Identifier<N>) -> Result<bool> {
+        self.dynamic_calls
+            .get(function_name)
+            .copied()
+            .ok_or_else(|| anyhow!("Function '{function_name}' does not exist"))
+    }
+
     /// Returns `true` if the proving key for the given function name exists.
     #[inline]
     pub fn contains_proving_key(&self, function_name: &Identifier<N>) -> bool {
@@ -437,6 +546,18 @@ impl<N: Network> Stack<N> {
     pub fn remove_verifying_key(&self, function_name: &Identifier<N>) {
         self.verifying_keys.write().shift_remove(function_name);
     }
+
+    /// Returns the instruction tracer attached to the stack, if one is set.
+    #[inline]
+    pub fn tracer(&self) -> Option<InstructionTracer<N>> {
+        self.tracer.read().clone()
+    }
+
+    /// Attaches the given instruction tracer to the stack, or detaches the current one if `None` is given.
+    #[inline]
+    pub fn set_tracer(&self, tracer: Option<InstructionTracer<N>>) {
+        *self.tracer.write() = tracer;
+    }
 }
 
 impl<N: Network> Stack<N> {
@@ -458,6 +579,7 @@ impl<N: Network> Stack<N> {
 impl<N: Network> PartialEq for Stack<N> {
     fn eq(&self, other: &Self) -> bool {
         self.program == other.program
+            && self.edition == other.edition
             && self.external_stacks == other.external_stacks
             && self.register_types == other.register_types
             && self.finalize_types == other.finalize_types
@@ -37,6 +37,11 @@ impl<N: Network> RegisterTypes<N> {
             ensure!(instruction.opcode() != Opcode::Async, "An 'async' instruction is not allowed in closures");
             // Ensure the closure contains no call instructions.
             ensure!(instruction.opcode() != Opcode::Call, "A 'call' instruction is not allowed in closures");
+            // Ensure the closure contains no dynamic call instructions.
+            ensure!(
+                instruction.opcode() != Opcode::CallDynamic,
+                "A 'call.dynamic' instruction is not allowed in closures"
+            );
             // Check the instruction opcode, operands, and destinations.
             register_types.check_instruction(stack, closure.name(), instruction)?;
         }
@@ -107,6 +112,10 @@ impl<N: Network> RegisterTypes<N> {
                     // Ensure the `call` instruction precedes any `async` instruction.
                     ensure!(async_.is_none(), "The 'call' can only be invoked before an 'async' instruction")
                 }
+                Opcode::CallDynamic => {
+                    // Ensure the `call.dynamic` instruction precedes any `async` instruction.
+                    ensure!(async_.is_none(), "The 'call.dynamic' can only be invoked before an 'async' instruction")
+                }
                 _ => {}
             }
         }
@@ -264,7 +273,8 @@ impl<N: Network> RegisterTypes<N> {
     ) -> Result<()> {
         // Ensure the register type is defined in the program.
         match register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
+            RegisterType::Plaintext(PlaintextType::Literal(..))
+            | RegisterType::Plaintext(PlaintextType::Optional(..)) => (),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => Self::check_struct(stack, struct_name)?,
             RegisterType::Plaintext(PlaintextType::Array(array_type)) => Self::check_array(stack, array_type)?,
             RegisterType::Record(identifier) => {
@@ -316,7 +326,8 @@ impl<N: Network> RegisterTypes<N> {
 
         // Ensure the register type is defined in the program.
         match register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
+            RegisterType::Plaintext(PlaintextType::Literal(..))
+            | RegisterType::Plaintext(PlaintextType::Optional(..)) => (),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => Self::check_struct(stack, struct_name)?,
             RegisterType::Plaintext(PlaintextType::Array(array_type)) => Self::check_array(stack, array_type)?,
             RegisterType::Record(identifier) => {
@@ -497,6 +508,13 @@ impl<N: Network> RegisterTypes<N> {
                     }
                 }
             }
+            Opcode::CallDynamic => {
+                // Ensure the instruction is a dynamic call operation.
+                ensure!(
+                    matches!(instruction, Instruction::CallDynamic(..)),
+                    "Instruction '{instruction}' is not a dynamic call operation."
+                );
+            }
             Opcode::Cast(opcode) => match opcode {
                 "cast" => {
                     // Retrieve the cast operation.
@@ -518,6 +536,9 @@ impl<N: Network> RegisterTypes<N> {
                         | CastType::Plaintext(PlaintextType::Literal(..)) => {
                             ensure!(instruction.operands().len() == 1, "Expected 1 operand.");
                         }
+                        CastType::Plaintext(PlaintextType::Optional(optional_type)) => {
+                            bail!("Casting to the optional type '{optional_type}?' is not supported.")
+                        }
                         CastType::Plaintext(PlaintextType::Struct(struct_name)) => {
                             // Ensure the struct name exists in the program.
                             if !stack.program().contains_struct(struct_name) {
@@ -531,8 +552,19 @@ impl<N: Network> RegisterTypes<N> {
                         CastType::Plaintext(PlaintextType::Array(array_type)) => {
                             // Ensure that the array type is valid.
                             RegisterTypes::check_array(stack, array_type)?;
+                            // Determine if a string is cast into a byte array.
+                            let is_string_to_bytes = match instruction.operands() {
+                                [operand] => {
+                                    array_type.next_element_type() == &PlaintextType::Literal(LiteralType::U8)
+                                        && self.get_type_from_operand(stack, operand)?
+                                            == RegisterType::Plaintext(PlaintextType::Literal(LiteralType::String))
+                                }
+                                _ => false,
+                            };
                             // Ensure the operand types match the element type.
-                            self.matches_array(stack, instruction.operands(), array_type)?;
+                            if !is_string_to_bytes {
+                                self.matches_array(stack, instruction.operands(), array_type)?;
+                            }
                         }
                         CastType::Record(record_name) => {
                             // Ensure the record type is defined in the program.
@@ -588,7 +620,89 @@ impl<N: Network> RegisterTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
-            Opcode::Sign => {
+            Opcode::Merkle(opcode) => {
+                match opcode {
+                    "merkle.verify.bhp" => ensure!(
+                        matches!(instruction, Instruction::MerkleVerifyBHP(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "merkle.verify.psd" => ensure!(
+                        matches!(instruction, Instruction::MerkleVerifyPSD(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
+                // Ensure the instruction has one destination register.
+                ensure!(
+                    instruction.destinations().len() == 1,
+                    "Instruction '{instruction}' has multiple destinations."
+                );
+            }
+            Opcode::Option(opcode) => {
+                match opcode {
+                    "option.is_some" => ensure!(
+                        matches!(instruction, Instruction::OptionIsSome(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "option.none" => ensure!(
+                        matches!(instruction, Instruction::OptionNone(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "option.some" => ensure!(
+                        matches!(instruction, Instruction::OptionSome(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "option.unwrap" => ensure!(
+                        matches!(instruction, Instruction::OptionUnwrap(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
+                // Ensure the instruction has one destination register.
+                ensure!(
+                    instruction.destinations().len() == 1,
+                    "Instruction '{instruction}' has multiple destinations."
+                );
+            }
+            Opcode::Repeat => Self::check_repeat_opcode(stack, closure_or_function_name, instruction)?,
+            Opcode::Serialize(opcode) => {
+                match opcode {
+                    "serialize.bits" => ensure!(
+                        matches!(instruction, Instruction::SerializeBits(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "serialize.bytes" => ensure!(
+                        matches!(instruction, Instruction::SerializeBytes(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "deserialize.bits" => ensure!(
+                        matches!(instruction, Instruction::DeserializeBits(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "deserialize.bytes" => ensure!(
+                        matches!(instruction, Instruction::DeserializeBytes(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
+                // Ensure the instruction has one destination register.
+                ensure!(
+                    instruction.destinations().len() == 1,
+                    "Instruction '{instruction}' has multiple destinations."
+                );
+            }
+            Opcode::Sign(opcode) => {
+                match opcode {
+                    "sign.verify" => ensure!(
+                        matches!(instruction, Instruction::SignVerify(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "ecdsa.verify" => ensure!(
+                        matches!(instruction, Instruction::ECDSAVerify(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
                 // Ensure the instruction has one destination register.
                 ensure!(
                     instruction.destinations().len() == 1,
@@ -661,7 +775,7 @@ impl<N: Network> RegisterTypes<N> {
         // If the struct contains arrays, ensure their base element types are defined in the program.
         for member in struct_.members().values() {
             match member {
-                PlaintextType::Literal(..) => (),
+                PlaintextType::Literal(..) | PlaintextType::Optional(..) => (),
                 PlaintextType::Struct(struct_name) => Self::check_struct(stack, struct_name)?,
                 PlaintextType::Array(array_type) => Self::check_array(stack, array_type)?,
             }
@@ -718,6 +832,35 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
+    /// Ensures the opcode is a valid opcode and corresponds to the `repeat` instruction.
+    /// The repeated closure must be defined in the program, and if the `repeat` instruction is in a closure,
+    /// the repeated closure must be defined before it, which rules out recursion.
+    #[inline]
+    pub(crate) fn check_repeat_opcode(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        scope_name: &Identifier<N>,
+        instruction: &Instruction<N>,
+    ) -> Result<()> {
+        // Retrieve the repeat operation.
+        let repeat = match instruction {
+            Instruction::Repeat(repeat) => repeat,
+            _ => bail!("Instruction '{instruction}' is not a repeat operation."),
+        };
+        // Retrieve the closure name.
+        let closure_name = repeat.closure();
+        // Ensure the closure exists in the program.
+        let Some(index) = stack.program().closures().get_index_of(closure_name) else {
+            bail!("Closure '{closure_name}' is not defined in '{}'.", stack.program_id())
+        };
+        // If the scope is a closure, ensure the repeated closure is defined before it.
+        if let Some(scope_index) = stack.program().closures().get_index_of(scope_name) {
+            if index >= scope_index {
+                bail!("Cannot repeat '{closure_name}' in '{scope_name}', as it must be defined before '{scope_name}'.")
+            }
+        }
+        Ok(())
+    }
+
     /// Ensures the opcode is a valid opcode and corresponds to the `hash` instruction.
     #[inline]
     pub(crate) fn check_hash_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
@@ -739,6 +882,10 @@ impl<N: Network> RegisterTypes<N> {
                 matches!(instruction, Instruction::HashBHP1024(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "hash.blake2s" => ensure!(
+                matches!(instruction, Instruction::HashBlake2s(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             "hash.keccak256" => ensure!(
                 matches!(instruction, Instruction::HashKeccak256(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
@@ -771,6 +918,22 @@ impl<N: Network> RegisterTypes<N> {
                 matches!(instruction, Instruction::HashPSD8(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "hash.poseidon2_2" => ensure!(
+                matches!(instruction, Instruction::HashPoseidon2_2(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash.poseidon2_4" => ensure!(
+                matches!(instruction, Instruction::HashPoseidon2_4(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash.poseidon2_8" => ensure!(
+                matches!(instruction, Instruction::HashPoseidon2_8(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash.sha256" => ensure!(
+                matches!(instruction, Instruction::HashSha256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             "hash.sha3_256" => ensure!(
                 matches!(instruction, Instruction::HashSha3_256(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
@@ -98,6 +98,8 @@ impl<N: Network> RegisterTypes<N> {
                 RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address))
             }
             Operand::BlockHeight => bail!("'block.height' is not a valid operand in a non-finalize context."),
+            Operand::BlockTimestamp => bail!("'block.timestamp' is not a valid operand in a non-finalize context."),
+            Operand::NetworkID => bail!("'network.id' is not a valid operand in a non-finalize context."),
         })
     }
 
@@ -160,7 +162,7 @@ impl<N: Network> RegisterTypes<N> {
                     // Retrieve the path name.
                     let path_name = match access {
                         Access::Member(path_name) => path_name,
-                        Access::Index(_) => bail!("Attempted to index into a record"),
+                        Access::Index(_) | Access::Register(_) => bail!("Attempted to index into a record"),
                     };
                     // Retrieve the entry type from the record.
                     match stack.program().get_record(record_name)?.entries().get(path_name) {
@@ -184,7 +186,9 @@ impl<N: Network> RegisterTypes<N> {
                     // Retrieve the path name.
                     let path_name = match access {
                         Access::Member(path_name) => path_name,
-                        Access::Index(_) => bail!("Attempted to index into an external record"),
+                        Access::Index(_) | Access::Register(_) => {
+                            bail!("Attempted to index into an external record")
+                        }
                     };
                     // Retrieve the entry type from the external record.
                     match stack.get_external_record(locator)?.entries().get(path_name) {
@@ -205,6 +209,10 @@ impl<N: Network> RegisterTypes<N> {
                 (RegisterRefType::Plaintext(PlaintextType::Literal(..)), _) => {
                     bail!("'{register}' references a literal.")
                 }
+                // Ensure the plaintext type is not an optional, as the register references an access.
+                (RegisterRefType::Plaintext(PlaintextType::Optional(..)), _) => {
+                    bail!("'{register}' references an optional.")
+                }
                 // Traverse the path to output the register type.
                 (RegisterRefType::Plaintext(PlaintextType::Struct(struct_name)), Access::Member(identifier)) => {
                     // Retrieve the member type from the struct.
@@ -221,6 +229,17 @@ impl<N: Network> RegisterTypes<N> {
                         false => bail!("'{index}' is out of bounds for '{register}'"),
                     }
                 }
+                // Traverse the path to output the register type.
+                // Note: The bounds of a register index are checked when the register is loaded.
+                (RegisterRefType::Plaintext(PlaintextType::Array(array_type)), Access::Register(locator)) => {
+                    // Ensure the index register is a `u32`.
+                    match self.get_type(stack, &Register::Locator(*locator))? {
+                        RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32)) => {
+                            register_type = RegisterRefType::Plaintext(array_type.next_element_type())
+                        }
+                        index_type => bail!("Index 'r{locator}' for '{register}' must be a 'u32', found '{index_type}'"),
+                    }
+                }
                 // Access the input to the future to output the register type and check that it is in bounds.
                 (RegisterRefType::Future(locator), Access::Index(index)) => {
                     // Retrieve the associated function.
@@ -248,9 +267,9 @@ impl<N: Network> RegisterTypes<N> {
                         None => bail!("Index out of bounds"),
                     }
                 }
-                (RegisterRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
+                (RegisterRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..) | Access::Register(..))
                 | (RegisterRefType::Plaintext(PlaintextType::Array(..)), Access::Member(..))
-                | (RegisterRefType::Future(..), Access::Member(..)) => {
+                | (RegisterRefType::Future(..), Access::Member(..) | Access::Register(..)) => {
                     bail!("Invalid access `{access}`")
                 }
             }
@@ -38,6 +38,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
             Operand::Caller => return Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.caller()?)))),
             // If the operand is the block height, throw an error.
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
+            // If the operand is the block timestamp, throw an error.
+            Operand::BlockTimestamp => bail!("Cannot load the block timestamp in a non-finalize context"),
+            // If the operand is the network ID, throw an error.
+            Operand::NetworkID => bail!("Cannot load the network ID in a non-finalize context"),
         };
 
         // Retrieve the stack value.
@@ -50,17 +54,20 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
             Register::Locator(..) => stack_value.clone(),
             // If the register is a register access, then load the specific stack value.
             Register::Access(_, ref path) => {
+                // Resolve the register indices in the path.
+                let path = self.resolve_path(stack, path)?;
+
                 match stack_value {
                     // Retrieve the plaintext member from the path.
-                    Value::Plaintext(plaintext) => Value::Plaintext(plaintext.find(path)?),
+                    Value::Plaintext(plaintext) => Value::Plaintext(plaintext.find(&path)?),
                     // Retrieve the record entry from the path.
-                    Value::Record(record) => match record.find(path)? {
+                    Value::Record(record) => match record.find(&path)? {
                         Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
                             Value::Plaintext(plaintext)
                         }
                     },
                     // Retrieve the argument from the future.
-                    Value::Future(future) => future.find(path)?,
+                    Value::Future(future) => future.find(&path)?,
                 }
             }
         };
@@ -121,6 +128,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
             }
             // If the operand is the block height, throw an error.
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
+            // If the operand is the block timestamp, throw an error.
+            Operand::BlockTimestamp => bail!("Cannot load the block timestamp in a non-finalize context"),
+            // If the operand is the network ID, throw an error.
+            Operand::NetworkID => bail!("Cannot load the network ID in a non-finalize context"),
         };
 
         // Retrieve the circuit value.
@@ -133,8 +144,8 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
             Register::Locator(..) => circuit_value.clone(),
             // If the register is a register access, then load the specific stack value.
             Register::Access(_, ref path) => {
-                // Inject the path.
-                let path = path.iter().map(|access| circuit::Access::constant(*access)).collect::<Vec<_>>();
+                // Inject the path, resolving the register indices in the path.
+                let path = self.resolve_path_circuit(stack, path)?;
 
                 match circuit_value {
                     // Retrieve the plaintext member from the path.
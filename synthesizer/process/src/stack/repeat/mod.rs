@@ -0,0 +1,220 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{FinalizeRegisters, FinalizeTypes, Registers, RegistersCall, StackEvaluate, StackExecute};
+use aleo_std::prelude::{finish, lap, timer};
+use console::{
+    network::prelude::*,
+    program::{Literal, Plaintext, Value},
+    types::U32,
+};
+use synthesizer_program::{
+    FinalizeRegistersState,
+    Instruction,
+    Repeat,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersSigner,
+    RegistersSignerCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+    StackMatches,
+    StackProgram,
+};
+
+pub trait RepeatTrait<N: Network> {
+    /// Evaluates the instruction.
+    fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut Registers<N, A>,
+    ) -> Result<()>;
+
+    /// Executes the instruction.
+    fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut (
+                 impl RegistersCall<N>
+                 + RegistersSignerCircuit<N, A>
+                 + RegistersLoadCircuit<N, A>
+                 + RegistersStoreCircuit<N, A>
+             ),
+    ) -> Result<()>;
+
+    /// Finalizes the instruction.
+    fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut FinalizeRegisters<N>,
+    ) -> Result<()>;
+}
+
+impl<N: Network> RepeatTrait<N> for Repeat<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        let timer = timer!("Repeat::evaluate");
+
+        // Load the operands values.
+        let mut state: Vec<_> = self.operands().iter().map(|operand| registers.load(stack, operand)).try_collect()?;
+        // Retrieve the closure.
+        let closure = stack.program().get_closure(self.closure())?;
+        lap!(timer, "Retrieved the closure");
+
+        for iteration in 0..self.iterations() {
+            // Append the iteration to the inputs.
+            let inputs =
+                [state.as_slice(), &[Value::Plaintext(Plaintext::from(Literal::U32(U32::new(iteration))))]].concat();
+            // Evaluate the closure, and load the outputs.
+            let outputs = stack.evaluate_closure::<A>(
+                &closure,
+                &inputs,
+                registers.call_stack(),
+                registers.signer()?,
+                registers.caller()?,
+                registers.tvk()?,
+            )?;
+            // Carry the outputs over into the next iteration.
+            state.iter_mut().zip(outputs).for_each(|(value, output)| *value = output);
+        }
+        lap!(timer, "Evaluated the iterations");
+
+        // Assign the final state to the destination registers.
+        for (value, register) in state.into_iter().zip(&self.destinations()) {
+            // Assign the value to the register.
+            registers.store(stack, register, value)?;
+        }
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut (
+                 impl RegistersCall<N>
+                 + RegistersSignerCircuit<N, A>
+                 + RegistersLoadCircuit<N, A>
+                 + RegistersStoreCircuit<N, A>
+             ),
+    ) -> Result<()> {
+        let timer = timer!("Repeat::execute");
+
+        use circuit::Inject;
+
+        // Load the operands values.
+        let mut state: Vec<_> =
+            self.operands().iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
+        // Retrieve the closure.
+        let closure = stack.program().get_closure(self.closure())?;
+        lap!(timer, "Retrieved the closure");
+
+        // Unroll the iterations into the circuit.
+        for iteration in 0..self.iterations() {
+            // Inject the iteration as a constant, and append it to the inputs.
+            let iteration = circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::U32(
+                circuit::U32::new(circuit::Mode::Constant, U32::new(iteration)),
+            )));
+            let inputs = [state.as_slice(), &[iteration]].concat();
+            // Execute the closure, and load the outputs.
+            let outputs = stack.execute_closure(
+                &closure,
+                &inputs,
+                registers.call_stack(),
+                registers.signer_circuit()?,
+                registers.caller_circuit()?,
+                registers.tvk_circuit()?,
+            )?;
+            // Carry the outputs over into the next iteration.
+            state.iter_mut().zip(outputs).for_each(|(value, output)| *value = output);
+        }
+        lap!(timer, "Executed the iterations");
+
+        // Assign the final state to the destination registers.
+        for (value, register) in state.into_iter().zip(&self.destinations()) {
+            // Assign the value to the register.
+            registers.store_circuit(stack, register, value)?;
+        }
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut FinalizeRegisters<N>,
+    ) -> Result<()> {
+        // Load the operands values.
+        let mut state: Vec<_> = self.operands().iter().map(|operand| registers.load(stack, operand)).try_collect()?;
+        // Retrieve the closure.
+        let closure = stack.program().get_closure(self.closure())?;
+        // Retrieve the finalize types of the closure.
+        let finalize_types = FinalizeTypes::from_closure(stack, &closure)?;
+
+        for iteration in 0..self.iterations() {
+            // Initialize the registers for the iteration.
+            let mut closure_registers = FinalizeRegisters::new(
+                *registers.state(),
+                *registers.transition_id(),
+                *registers.function_name(),
+                finalize_types.clone(),
+            );
+            // Append the iteration to the inputs.
+            let iteration = Value::Plaintext(Plaintext::from(Literal::U32(U32::new(iteration))));
+            // Store the inputs.
+            closure.inputs().iter().zip_eq(state.iter().chain([&iteration])).try_for_each(|(input, value)| {
+                // Assign the input value to the register.
+                closure_registers.store(stack, input.register(), value.clone())
+            })?;
+            // Finalize the instructions.
+            for instruction in closure.instructions() {
+                // Finalize the instruction.
+                let result = match instruction {
+                    // If the instruction is a `repeat` instruction, we need to handle it separately.
+                    Instruction::Repeat(repeat) => RepeatTrait::finalize(repeat, stack, &mut closure_registers),
+                    // Otherwise, finalize the instruction normally.
+                    _ => instruction.finalize(stack, &mut closure_registers),
+                };
+                // If the finalize fails, bail and return the error.
+                if let Err(error) = result {
+                    bail!("Failed to finalize instruction ({instruction}) in '{}': {error}", closure.name());
+                }
+            }
+            // Load the outputs.
+            let outputs: Vec<_> =
+                closure.outputs().iter().map(|output| closure_registers.load(stack, output.operand())).try_collect()?;
+            // Carry the outputs over into the next iteration.
+            state.iter_mut().zip(outputs).for_each(|(value, output)| *value = output);
+        }
+
+        // Assign the final state to the destination registers.
+        for (value, register) in state.into_iter().zip(&self.destinations()) {
+            // Assign the value to the register.
+            registers.store(stack, register, value)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,345 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod serialize;
+mod string;
+
+use super::*;
+use console::program::Register;
+use synthesizer_program::Opcode;
+
+/// The scope in which a traced step was run.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TracedScope {
+    /// The step is an instruction in a closure.
+    Closure,
+    /// The step is an instruction in a function.
+    Function,
+    /// The step is a command in a finalize scope (or a constructor).
+    Finalize,
+}
+
+/// A single instruction or command, as recorded by an instruction tracer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TracedStep<N: Network> {
+    /// The program ID of the step.
+    program_id: ProgramID<N>,
+    /// The scope of the step.
+    scope: TracedScope,
+    /// The name of the closure, function, or finalize scope of the step.
+    resource: Identifier<N>,
+    /// The index of the instruction or command in its scope.
+    index: usize,
+    /// The opcode of the instruction or command.
+    opcode: Opcode,
+    /// The operands, and their values (if they could be loaded).
+    operands: Vec<(Operand<N>, Option<Value<N>>)>,
+    /// The destination registers, and their values (if they could be loaded).
+    /// This is `None` if the step did not complete.
+    destinations: Option<Vec<(Register<N>, Option<Value<N>>)>>,
+    /// The number of constraints added by the step, if the step was executed in a circuit.
+    num_constraints: Option<u64>,
+}
+
+impl<N: Network> TracedStep<N> {
+    /// Returns the program ID of the step.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the scope of the step.
+    pub const fn scope(&self) -> TracedScope {
+        self.scope
+    }
+
+    /// Returns the name of the closure, function, or finalize scope of the step.
+    pub const fn resource(&self) -> &Identifier<N> {
+        &self.resource
+    }
+
+    /// Returns the index of the instruction or command in its scope.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the opcode of the instruction or command.
+    pub const fn opcode(&self) -> Opcode {
+        self.opcode
+    }
+
+    /// Returns the operands, and their values (if they could be loaded).
+    pub fn operands(&self) -> &[(Operand<N>, Option<Value<N>>)] {
+        &self.operands
+    }
+
+    /// Returns the destination registers, and their values (if they could be loaded).
+    /// Returns `None` if the step did not complete.
+    pub fn destinations(&self) -> Option<&[(Register<N>, Option<Value<N>>)]> {
+        self.destinations.as_deref()
+    }
+
+    /// Returns the number of constraints added by the step, if the step was executed in a circuit.
+    pub const fn num_constraints(&self) -> Option<u64> {
+        self.num_constraints
+    }
+
+    /// Returns `true` if the step completed.
+    pub const fn is_complete(&self) -> bool {
+        self.destinations.is_some()
+    }
+}
+
+/// An opt-in tracer that records the instructions and commands run by the stacks it is attached to.
+///
+/// Clones of a tracer record into the same list of steps, so a single tracer may be attached to several
+/// stacks (see `Process::set_tracer`) to record nested calls across programs in the order they run.
+/// If a call fails, the last incomplete step is the instruction or command that failed.
+///
+/// Note: Instructions are recorded when a function is evaluated or executed (but not when it is
+/// authorized or synthesized), and commands are recorded when a finalize scope is run.
+#[derive(Clone)]
+pub struct InstructionTracer<N: Network> {
+    /// The recorded steps.
+    steps: Arc<RwLock<Vec<TracedStep<N>>>>,
+}
+
+impl<N: Network> Default for InstructionTracer<N> {
+    /// Initializes a new instruction tracer.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> InstructionTracer<N> {
+    /// Initializes a new instruction tracer.
+    pub fn new() -> Self {
+        Self { steps: Default::default() }
+    }
+
+    /// Returns the recorded steps.
+    pub fn steps(&self) -> Vec<TracedStep<N>> {
+        self.steps.read().clone()
+    }
+
+    /// Returns the number of recorded steps.
+    pub fn len(&self) -> usize {
+        self.steps.read().len()
+    }
+
+    /// Returns `true` if no steps have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.steps.read().is_empty()
+    }
+
+    /// Clears the recorded steps.
+    pub fn clear(&self) {
+        self.steps.write().clear()
+    }
+}
+
+impl<N: Network> InstructionTracer<N> {
+    /// Records the start of a step, loading the values of its operands from the given registers.
+    /// Returns the position of the step, which must be passed to `end` once the step completes.
+    pub(crate) fn begin(
+        &self,
+        stack: &Stack<N>,
+        registers: &impl RegistersLoad<N>,
+        (scope, resource): (TracedScope, &Identifier<N>),
+        index: usize,
+        opcode: Opcode,
+        operands: &[Operand<N>],
+    ) -> usize {
+        // Load the values of the operands.
+        let operands = operands.iter().map(|operand| (operand.clone(), registers.load(stack, operand).ok())).collect();
+        // Record the step.
+        let mut steps = self.steps.write();
+        steps.push(TracedStep {
+            program_id: *stack.program_id(),
+            scope,
+            resource: *resource,
+            index,
+            opcode,
+            operands,
+            destinations: None,
+            num_constraints: None,
+        });
+        steps.len() - 1
+    }
+
+    /// Records the completion of the step at the given position,
+    /// loading the values of its destination registers from the given registers.
+    pub(crate) fn end(
+        &self,
+        position: usize,
+        stack: &Stack<N>,
+        registers: &impl RegistersLoad<N>,
+        destinations: Vec<Register<N>>,
+        num_constraints: Option<u64>,
+    ) {
+        // Load the values of the destination registers.
+        let destinations = destinations
+            .into_iter()
+            .map(|register| {
+                let value = registers.load(stack, &Operand::Register(register.clone())).ok();
+                (register, value)
+            })
+            .collect();
+        // Update the step.
+        if let Some(step) = self.steps.write().get_mut(position) {
+            step.destinations = Some(destinations);
+            step.num_constraints = num_constraints;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+    use console::{account::PrivateKey, network::MainnetV0};
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_tracer_evaluate() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program tracer.aleo;
+
+function compute:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    mul r2 2u64 into r3;
+    output r3 as u64.private;",
+        )
+        .unwrap();
+
+        // Initialize the process, and attach a tracer.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&program).unwrap();
+        let tracer = InstructionTracer::new();
+        process.set_tracer(Some(tracer.clone()));
+
+        // Authorize and evaluate the function.
+        let private_key = PrivateKey::new(rng).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key, program.id(), "compute", ["3u64", "4u64"].into_iter(), rng)
+            .unwrap();
+        assert!(tracer.is_empty());
+        process.evaluate::<CurrentAleo>(authorization).unwrap();
+
+        // Check the recorded steps.
+        let steps = tracer.steps();
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0].scope(), TracedScope::Function);
+        assert_eq!(steps[0].index(), 0);
+        assert_eq!(*steps[0].opcode(), "add");
+        assert_eq!(steps[0].operands()[1].1, Some(Value::from_str("4u64").unwrap()));
+        assert_eq!(steps[1].index(), 1);
+        assert_eq!(steps[1].operands()[1].1, Some(Value::from_str("2u64").unwrap()));
+        let destinations = steps[1].destinations().unwrap();
+        assert_eq!(destinations[0].0, Register::Locator(3));
+        assert_eq!(destinations[0].1, Some(Value::from_str("14u64").unwrap()));
+        assert!(steps.iter().all(|step| step.num_constraints().is_none()));
+
+        // Detach the tracer, and ensure nothing else is recorded.
+        process.set_tracer(None);
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key, program.id(), "compute", ["3u64", "4u64"].into_iter(), rng)
+            .unwrap();
+        process.evaluate::<CurrentAleo>(authorization).unwrap();
+        assert_eq!(tracer.len(), 2);
+    }
+
+    #[test]
+    fn test_tracer_execute() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program tracer.aleo;
+
+function compute:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    output r2 as u64.private;",
+        )
+        .unwrap();
+
+        // Initialize the process, and attach a tracer.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&program).unwrap();
+        let tracer = InstructionTracer::new();
+        process.set_tracer(Some(tracer.clone()));
+
+        // Authorize and execute the function.
+        let private_key = PrivateKey::new(rng).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key, program.id(), "compute", ["3u64", "4u64"].into_iter(), rng)
+            .unwrap();
+        process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+
+        // Check that the step is recorded once, with the number of constraints it added.
+        let steps = tracer.steps();
+        assert_eq!(steps.len(), 1);
+        assert!(steps[0].num_constraints().unwrap() > 0);
+        assert_eq!(steps[0].destinations().unwrap()[0].1, Some(Value::from_str("7u64").unwrap()));
+    }
+
+    #[test]
+    fn test_tracer_failed_step() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program tracer.aleo;
+
+function compute:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    assert.eq r0 r1;
+    output r0 as u8.private;",
+        )
+        .unwrap();
+
+        // Initialize the process, and attach a tracer.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&program).unwrap();
+        let tracer = InstructionTracer::new();
+        process.set_tracer(Some(tracer.clone()));
+
+        // Authorize and evaluate the function, which fails the assertion.
+        let private_key = PrivateKey::new(rng).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key, program.id(), "compute", ["1u8", "2u8"].into_iter(), rng)
+            .unwrap();
+        assert!(process.evaluate::<CurrentAleo>(authorization).is_err());
+
+        // Check that the failed step is recorded with its operand values.
+        let steps = tracer.steps();
+        assert_eq!(steps.len(), 1);
+        assert!(!steps[0].is_complete());
+        assert_eq!(steps[0].operands()[0].1, Some(Value::from_str("1u8").unwrap()));
+        assert_eq!(steps[0].operands()[1].1, Some(Value::from_str("2u8").unwrap()));
+    }
+}
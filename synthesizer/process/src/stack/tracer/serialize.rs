@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl Serialize for TracedScope {
+    /// Serializes the traced scope into a string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Closure => serializer.serialize_str("closure"),
+            Self::Function => serializer.serialize_str("function"),
+            Self::Finalize => serializer.serialize_str("finalize"),
+        }
+    }
+}
+
+impl<N: Network> Serialize for TracedStep<N> {
+    /// Serializes the traced step into a JSON-compatible structure.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut step = serializer.serialize_struct("TracedStep", 8)?;
+        step.serialize_field("program_id", &self.program_id)?;
+        step.serialize_field("scope", &self.scope)?;
+        step.serialize_field("resource", &self.resource)?;
+        step.serialize_field("index", &self.index)?;
+        step.serialize_field("opcode", &self.opcode.to_string())?;
+        step.serialize_field(
+            "operands",
+            &self
+                .operands
+                .iter()
+                .map(|(operand, value)| TracedEntry { key: "operand", name: operand.to_string(), value })
+                .collect::<Vec<_>>(),
+        )?;
+        step.serialize_field(
+            "destinations",
+            &self.destinations.as_ref().map(|destinations| {
+                destinations
+                    .iter()
+                    .map(|(register, value)| TracedEntry { key: "register", name: register.to_string(), value })
+                    .collect::<Vec<_>>()
+            }),
+        )?;
+        step.serialize_field("num_constraints", &self.num_constraints)?;
+        step.end()
+    }
+}
+
+impl<N: Network> Serialize for InstructionTracer<N> {
+    /// Serializes the recorded steps into a JSON-compatible structure.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tracer = serializer.serialize_struct("InstructionTracer", 1)?;
+        tracer.serialize_field("steps", &*self.steps.read())?;
+        tracer.end()
+    }
+}
+
+/// A helper struct to serialize an operand or a destination register, along with its value.
+struct TracedEntry<'a, N: Network> {
+    /// The field name for the operand or register.
+    key: &'static str,
+    /// The operand or register.
+    name: String,
+    /// The value, if it could be loaded.
+    value: &'a Option<Value<N>>,
+}
+
+impl<N: Network> Serialize for TracedEntry<'_, N> {
+    /// Serializes the entry into a JSON-compatible structure.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut entry = serializer.serialize_struct("TracedEntry", 2)?;
+        entry.serialize_field(self.key, &self.name)?;
+        entry.serialize_field("value", self.value)?;
+        entry.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        // Construct a traced step.
+        let step = TracedStep::<CurrentNetwork> {
+            program_id: ProgramID::from_str("tracer.aleo")?,
+            scope: TracedScope::Finalize,
+            resource: Identifier::from_str("compute")?,
+            index: 1,
+            opcode: Opcode::Literal("add"),
+            operands: vec![
+                (Operand::from_str("r0")?, Some(Value::from_str("1u64")?)),
+                (Operand::from_str("r1")?, None),
+            ],
+            destinations: None,
+            num_constraints: None,
+        };
+
+        // Construct the tracer.
+        let tracer = InstructionTracer::new();
+        tracer.steps.write().push(step.clone());
+
+        // Check the serialized step.
+        let expected = concat!(
+            r#"{"program_id":"tracer.aleo","scope":"finalize","resource":"compute","index":1,"opcode":"add","#,
+            r#""operands":[{"operand":"r0","value":"1u64"},{"operand":"r1","value":null}],"#,
+            r#""destinations":null,"num_constraints":null}"#
+        );
+        assert_eq!(expected, serde_json::to_string(&step)?);
+        // Check the serialized tracer.
+        assert_eq!(format!(r#"{{"steps":[{expected}]}}"#), serde_json::to_string(&tracer)?);
+        assert_eq!(format!(r#"{{"steps":[{expected}]}}"#), tracer.to_string());
+
+        Ok(())
+    }
+}
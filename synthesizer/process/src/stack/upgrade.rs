@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Initializes a new stack for the next edition of an existing program, given the process and the program.
+    /// The program must already exist in the process, and must be a compatible upgrade of the latest edition.
+    #[inline]
+    pub fn upgrade(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Ensure the program is not 'credits.aleo'.
+        ensure!(program_id != &ProgramID::from_str("credits.aleo")?, "Program 'credits.aleo' cannot be upgraded");
+        // Retrieve the stack of the latest edition.
+        let previous = process.get_stack(program_id)?;
+        // Compute the next edition.
+        let Some(edition) = previous.edition().checked_add(1) else {
+            bail!("Program '{program_id}' has reached the maximum number of editions")
+        };
+        // Ensure the program is a compatible upgrade.
+        previous.check_upgrade_is_compatible(program)?;
+        // Ensure the program is well-formed.
+        Self::check_program_is_well_formed(program)?;
+        // Return the stack.
+        Stack::initialize(process, program, edition)
+    }
+
+    /// Checks that the given program is a compatible upgrade of the program in this stack.
+    ///
+    /// An upgrade is compatible if:
+    ///  - the program ID is unchanged,
+    ///  - the mappings are unchanged, so the existing mapping state carries over,
+    ///  - the existing structs and records are unchanged (new ones may be added),
+    ///  - the existing functions are kept, with unchanged input, output, and finalize input types,
+    ///  - the constructor is unchanged, as it only runs for the first edition.
+    ///
+    /// The imports, closures, and the bodies of functions and finalize blocks may change freely.
+    pub fn check_upgrade_is_compatible(&self, program: &Program<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = self.program.id();
+        // Ensure the program ID matches.
+        ensure!(program.id() == program_id, "Cannot upgrade '{program_id}' with program '{}'", program.id());
+
+        // Ensure the number of mappings is unchanged.
+        ensure!(
+            self.program.mappings().len() == program.mappings().len(),
+            "The upgrade of '{program_id}' must declare the same mappings"
+        );
+        // Ensure each mapping is unchanged.
+        for (name, mapping) in self.program.mappings() {
+            match program.mappings().get(name) {
+                Some(candidate) if candidate == mapping => (),
+                _ => bail!("The upgrade of '{program_id}' must keep mapping '{name}' unchanged"),
+            }
+        }
+
+        // Ensure the constructor is unchanged.
+        ensure!(
+            self.program.constructor() == program.constructor(),
+            "The upgrade of '{program_id}' must keep the constructor unchanged"
+        );
+
+        // Ensure each struct is unchanged.
+        for (name, struct_) in self.program.structs() {
+            match program.structs().get(name) {
+                Some(candidate) if candidate == struct_ => (),
+                _ => bail!("The upgrade of '{program_id}' must keep struct '{name}' unchanged"),
+            }
+        }
+
+        // Ensure each record is unchanged.
+        for (name, record) in self.program.records() {
+            match program.records().get(name) {
+                Some(candidate) if candidate == record => (),
+                _ => bail!("The upgrade of '{program_id}' must keep record '{name}' unchanged"),
+            }
+        }
+
+        // Ensure each function signature is unchanged.
+        for (name, function) in self.program.functions() {
+            // Retrieve the upgraded function.
+            let Ok(candidate) = program.get_function_ref(name) else {
+                bail!("The upgrade of '{program_id}' must keep function '{name}'")
+            };
+            // Ensure the input types are unchanged.
+            ensure!(
+                function.input_types() == candidate.input_types(),
+                "The upgrade of '{program_id}' must keep the input types of function '{name}'"
+            );
+            // Ensure the output types are unchanged.
+            ensure!(
+                function.output_types() == candidate.output_types(),
+                "The upgrade of '{program_id}' must keep the output types of function '{name}'"
+            );
+            // Ensure the finalize input types are unchanged.
+            ensure!(
+                function.finalize_logic().map(|finalize| finalize.input_types())
+                    == candidate.finalize_logic().map(|finalize| finalize.input_types()),
+                "The upgrade of '{program_id}' must keep the finalize input types of function '{name}'"
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -13,20 +13,26 @@
 // limitations under the License.
 
 use crate::{
+    cost_of_finalize_command,
+    cost_of_finalize_read,
+    deployment_cost,
+    execution_cost,
     traits::{StackEvaluate, StackExecute},
     CallStack,
+    FinalizeRegisters,
     Process,
     Stack,
+    StackProgramTypes,
     Trace,
 };
 use circuit::{network::AleoV0, Aleo};
 use console::{
     account::{Address, PrivateKey, ViewKey},
     network::{prelude::*, MainnetV0},
-    program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
-    types::{Field, U64},
+    program::{Identifier, Literal, Plaintext, ProgramID, Record, Register, Value},
+    types::{Field, U16, U64, U8},
 };
-use ledger_block::{Fee, Transaction};
+use ledger_block::{Deployment, Fee, Transaction};
 use ledger_query::Query;
 use ledger_store::{
     helpers::memory::{BlockMemory, FinalizeMemory},
@@ -35,7 +41,14 @@ use ledger_store::{
     FinalizeStorage,
     FinalizeStore,
 };
-use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Program, StackProgram};
+use synthesizer_program::{
+    FinalizeGlobalState,
+    FinalizeOperation,
+    FinalizeStoreTrait,
+    Program,
+    RegistersStore,
+    StackProgram,
+};
 use synthesizer_snark::UniversalSRS;
 
 use indexmap::IndexMap;
@@ -47,7 +60,7 @@ type CurrentAleo = AleoV0;
 
 /// Samples a new finalize state.
 pub fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-    FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+    FinalizeGlobalState::from(block_height as u64, block_height, 0, [0u8; 32])
 }
 
 /// Samples a valid fee for the given process, block store, and finalize store.
@@ -56,6 +69,17 @@ pub fn sample_fee<N: Network, A: Aleo<Network = N>, B: BlockStorage<N>, P: Final
     block_store: &BlockStore<N, B>,
     finalize_store: &FinalizeStore<N, P>,
     rng: &mut TestRng,
+) -> Fee<N> {
+    sample_fee_with_base_fee::<N, A, B, P>(process, block_store, finalize_store, 100, rng)
+}
+
+/// Samples a valid fee with the given base fee (in microcredits), for the given process, block store, and finalize store.
+pub fn sample_fee_with_base_fee<N: Network, A: Aleo<Network = N>, B: BlockStorage<N>, P: FinalizeStorage<N>>(
+    process: &Process<N>,
+    block_store: &BlockStore<N, B>,
+    finalize_store: &FinalizeStore<N, P>,
+    base_fee_in_microcredits: u64,
+    rng: &mut TestRng,
 ) -> Fee<N> {
     let program_id = ProgramID::from_str("credits.aleo").unwrap();
     let account_mapping = Identifier::from_str("account").unwrap();
@@ -70,12 +94,10 @@ pub fn sample_fee<N: Network, A: Aleo<Network = N>, B: BlockStorage<N>, P: Final
     // Construct the key.
     let key = Plaintext::from(Literal::Address(address));
     // Construct the public balance.
-    let value = Value::from(Literal::U64(U64::new(100)));
+    let value = Value::from(Literal::U64(U64::new(base_fee_in_microcredits)));
     // Update the public balance in finalize storage.
     finalize_store.update_key_value(program_id, account_mapping, key, value).unwrap();
 
-    // Sample a base fee in microcredits.
-    let base_fee_in_microcredits = 100;
     // Sample a priority fee in microcredits.
     let priority_fee_in_microcredits = 0;
     // Sample a dummy ID.
@@ -142,14 +164,15 @@ function foo:
     let expected = Value::Plaintext(Plaintext::<CurrentNetwork>::from_str("5field").unwrap());
 
     // Run the function.
-    let response =
-        stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization.replicate()).unwrap(), None).unwrap();
+    let call_stack = CallStack::evaluate(authorization.replicate(), process.dynamic_stacks(stack)).unwrap();
+    let response = stack.evaluate_function::<CurrentAleo>(call_stack, None).unwrap();
     let candidate = response.outputs();
     assert_eq!(1, candidate.len());
     assert_eq!(expected, candidate[0]);
 
     // Re-run to ensure state continues to work.
-    let response = stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap(), None).unwrap();
+    let call_stack = CallStack::evaluate(authorization, process.dynamic_stacks(stack)).unwrap();
+    let response = stack.evaluate_function::<CurrentAleo>(call_stack, None).unwrap();
     let candidate = response.outputs();
     assert_eq!(1, candidate.len());
     assert_eq!(expected, candidate[0]);
@@ -204,14 +227,15 @@ output r1 as field.private;",
     let stack = process.get_stack(program.id()).unwrap();
 
     // Compute the output value.
-    let response =
-        stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization.replicate()).unwrap(), None).unwrap();
+    let call_stack = CallStack::evaluate(authorization.replicate(), process.dynamic_stacks(stack)).unwrap();
+    let response = stack.evaluate_function::<CurrentAleo>(call_stack, None).unwrap();
     let candidate = response.outputs();
     assert_eq!(1, candidate.len());
     assert_eq!(expected, candidate[0]);
 
     // Re-run to ensure state continues to work.
-    let response = stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap(), None).unwrap();
+    let call_stack = CallStack::evaluate(authorization, process.dynamic_stacks(stack)).unwrap();
+    let response = stack.evaluate_function::<CurrentAleo>(call_stack, None).unwrap();
     let candidate = response.outputs();
     assert_eq!(1, candidate.len());
     assert_eq!(expected, candidate[0]);
@@ -269,14 +293,15 @@ output r1 as u64.private;",
     let stack = process.get_stack(program.id()).unwrap();
 
     // Compute the output value.
-    let response =
-        stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization.replicate()).unwrap(), None).unwrap();
+    let call_stack = CallStack::evaluate(authorization.replicate(), process.dynamic_stacks(stack)).unwrap();
+    let response = stack.evaluate_function::<CurrentAleo>(call_stack, None).unwrap();
     let candidate = response.outputs();
     assert_eq!(1, candidate.len());
     assert_eq!(expected, candidate[0]);
 
     // Re-run to ensure state continues to work.
-    let response = stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap(), None).unwrap();
+    let call_stack = CallStack::evaluate(authorization, process.dynamic_stacks(stack)).unwrap();
+    let response = stack.evaluate_function::<CurrentAleo>(call_stack, None).unwrap();
     let candidate = response.outputs();
     assert_eq!(1, candidate.len());
     assert_eq!(expected, candidate[0]);
@@ -359,8 +384,8 @@ output r4 as field.private;",
     let stack = process.get_stack(program.id()).unwrap();
 
     // Compute the output value.
-    let response =
-        stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization.replicate()).unwrap(), None).unwrap();
+    let call_stack = CallStack::evaluate(authorization.replicate(), process.dynamic_stacks(stack)).unwrap();
+    let response = stack.evaluate_function::<CurrentAleo>(call_stack, None).unwrap();
     let candidate = response.outputs();
     assert_eq!(3, candidate.len());
     assert_eq!(r2, candidate[0]);
@@ -368,7 +393,8 @@ output r4 as field.private;",
     assert_eq!(r4, candidate[2]);
 
     // Re-run to ensure state continues to work.
-    let response = stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap(), None).unwrap();
+    let call_stack = CallStack::evaluate(authorization, process.dynamic_stacks(stack)).unwrap();
+    let response = stack.evaluate_function::<CurrentAleo>(call_stack, None).unwrap();
     let candidate = response.outputs();
     assert_eq!(3, candidate.len());
     assert_eq!(r2, candidate[0]);
@@ -395,7 +421,7 @@ output r4 as field.private;",
 
     // Re-run to ensure state continues to work.
     let trace = Arc::new(RwLock::new(Trace::new()));
-    let call_stack = CallStack::execute(authorization, trace).unwrap();
+    let call_stack = CallStack::execute(authorization, trace, process.dynamic_stacks(stack)).unwrap();
     let response = stack.execute_function::<CurrentAleo, _>(call_stack, None, None, rng).unwrap();
     let candidate = response.outputs();
     assert_eq!(3, candidate.len());
@@ -464,14 +490,15 @@ output r1 as token.record;",
     let stack = process.get_stack(program.id()).unwrap();
 
     // Compute the output value.
-    let response =
-        stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization.replicate()).unwrap(), None).unwrap();
+    let call_stack = CallStack::evaluate(authorization.replicate(), process.dynamic_stacks(stack)).unwrap();
+    let response = stack.evaluate_function::<CurrentAleo>(call_stack, None).unwrap();
     let candidate = response.outputs();
     assert_eq!(1, candidate.len());
     assert_eq!(expected, candidate[0]);
 
     // Re-run to ensure state continues to work.
-    let response = stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap(), None).unwrap();
+    let call_stack = CallStack::evaluate(authorization, process.dynamic_stacks(stack)).unwrap();
+    let response = stack.evaluate_function::<CurrentAleo>(call_stack, None).unwrap();
     let candidate = response.outputs();
     assert_eq!(1, candidate.len());
     assert_eq!(expected, candidate[0]);
@@ -571,6 +598,54 @@ function hello_world:
     process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, &mut TestRng::default()).unwrap();
 }
 
+#[test]
+fn test_process_export_r1cs() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r#"program testing.aleo;
+
+function hello_world:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+"#,
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("hello_world").unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Synthesize the assignment of the function.
+    let stack = process.get_stack(program.id()).unwrap();
+    let assignment = stack.synthesize_assignment::<CurrentAleo, _>(&function_name, &mut TestRng::default()).unwrap();
+
+    // Export the constraint system and witness.
+    let r1cs = assignment.to_r1cs_file().unwrap();
+    let witness = assignment.to_r1cs_witness();
+    assert_eq!(r1cs.num_wires as usize, witness.values.len());
+    assert_eq!(r1cs.constraints.len() as u64, assignment.num_constraints());
+    assert!(r1cs.is_satisfied(&witness.values));
+
+    // Check that the binary formats round-trip.
+    let r1cs_bytes = r1cs.to_bytes_le().unwrap();
+    let witness_bytes = witness.to_bytes_le().unwrap();
+    assert_eq!(r1cs, circuit::R1CSFile::from_bytes_le(&r1cs_bytes).unwrap());
+    assert_eq!(witness, circuit::R1CSWitness::from_bytes_le(&witness_bytes).unwrap());
+
+    // Check that the JSON forms round-trip.
+    assert_eq!(r1cs, circuit::R1CSFile::from_json(&r1cs.to_json()).unwrap());
+    assert_eq!(witness, circuit::R1CSWitness::from_json(&witness.to_json()).unwrap());
+
+    // Check that a tampered witness is rejected.
+    let mut tampered = witness.values.clone();
+    let one = tampered[0];
+    *tampered.last_mut().unwrap() += one;
+    assert!(!r1cs.is_satisfied(&tampered));
+}
+
 #[test]
 fn test_process_multirecords() {
     // Initialize a new program.
@@ -1252,7 +1327,8 @@ finalize compute:
     // Compute the fee.
     let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
     // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    let (stack, _) =
+        process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee, &rng.gen()).unwrap();
     // Add the stack *manually* to the process.
     process.add_stack(stack);
 
@@ -1365,7 +1441,8 @@ finalize compute:
     // Compute the fee.
     let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
     // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    let (stack, _) =
+        process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee, &rng.gen()).unwrap();
     // Add the stack *manually* to the process.
     process.add_stack(stack);
 
@@ -1492,7 +1569,8 @@ finalize mint_public:
     // Compute the fee.
     let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
     // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    let (stack, _) =
+        process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee, &rng.gen()).unwrap();
     // Add the stack *manually* to the process.
     process.add_stack(stack);
 
@@ -1621,7 +1699,8 @@ finalize mint_public:
     // Compute the fee.
     let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
     // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    let (stack, _) =
+        process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee, &rng.gen()).unwrap();
     // Add the stack *manually* to the process.
     process.add_stack(stack);
 
@@ -1661,7 +1740,8 @@ finalize init:
     // Compute the fee.
     let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
     // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(sample_finalize_state(2), &finalize_store, &deployment, &fee).unwrap();
+    let (stack, _) =
+        process.finalize_deployment(sample_finalize_state(2), &finalize_store, &deployment, &fee, &rng.gen()).unwrap();
     // Add the stack *manually* to the process.
     process.add_stack(stack);
 
@@ -1716,6 +1796,112 @@ finalize init:
     assert_eq!(candidate, Value::from_str("100u64").unwrap());
 }
 
+#[test]
+fn test_process_estimate_execution_cost_with_nested_call() {
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program token.aleo;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async mint_public r0 r1 into r2;
+    output r2 as token.aleo/mint_public.future;
+
+finalize mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize another program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import token.aleo;
+
+program public_wallet.aleo;
+
+function init:
+    input r0 as address.public;
+    input r1 as u64.public;
+    call token.aleo/mint_public r0 r1 into r2;
+    async init r2 into r3;
+    output r3 as public_wallet.aleo/init.future;
+finalize init:
+    input r0 as token.aleo/mint_public.future;
+    await r0;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program0).unwrap();
+    process.add_program(&program1).unwrap();
+
+    // Initialize a burner private key.
+    let burner_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let burner_address = Address::try_from(&burner_private_key).unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str(&burner_address.to_string()).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("100u64").unwrap();
+
+    // Authorize the function call.
+    let function_name = Identifier::from_str("init").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&burner_private_key, program1.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+
+    // Estimate the cost of the execution.
+    let estimate = process.estimate_execution_cost(&authorization).unwrap();
+
+    // Check that the estimate includes the nested call, in execution order.
+    let transitions = estimate.transitions();
+    assert_eq!(transitions.len(), 2);
+    assert_eq!(transitions[0].program_id(), program0.id());
+    assert_eq!(transitions[0].function_name(), &Identifier::from_str("mint_public").unwrap());
+    assert_eq!(transitions[1].program_id(), program1.id());
+    assert_eq!(transitions[1].function_name(), &function_name);
+
+    // Check that the per-transition costs are consistent with the execution cost.
+    let stack = process.get_stack(program1.id()).unwrap();
+    let expected_finalize_cost = stack.get_finalize_cost(&function_name).unwrap();
+    assert!(transitions[0].finalize_cost() > 0);
+    assert_eq!(transitions.iter().map(|cost| cost.finalize_cost()).sum::<u64>(), expected_finalize_cost);
+    assert_eq!(estimate.finalize_cost(), expected_finalize_cost);
+    assert!(transitions.iter().map(|cost| cost.storage_cost()).sum::<u64>() <= estimate.storage_cost());
+    assert_eq!(estimate.total_cost(), estimate.storage_cost() + estimate.finalize_cost());
+
+    // Execute the request.
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+
+    // Ensure the estimate is an upper bound on the cost of the proven execution.
+    let (_, (storage_cost, finalize_cost)) = execution_cost(&process, &execution).unwrap();
+    assert!(estimate.storage_cost() >= storage_cost);
+    assert_eq!(estimate.finalize_cost(), finalize_cost);
+}
+
 #[test]
 fn test_process_execute_and_finalize_get_set() {
     // Initialize a new program.
@@ -1779,7 +1965,8 @@ finalize compute:
     // Compute the fee.
     let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
     // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    let (stack, _) =
+        process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee, &rng.gen()).unwrap();
     // Add the stack *manually* to the process.
     process.add_stack(stack);
 
@@ -2208,7 +2395,8 @@ finalize compute:
     // Compute the fee.
     let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
     // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    let (stack, _) =
+        process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee, &rng.gen()).unwrap();
     // Add the stack *manually* to the process.
     process.add_stack(stack);
 
@@ -2363,8 +2551,11 @@ fn test_process_deploy_credits_program() {
     let rng = &mut TestRng::default();
 
     // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
+    let empty_process = Process {
+        universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()),
+        stacks: Default::default(),
+        editions: IndexMap::new(),
+    };
 
     // Construct the process.
     let process = Process::load().unwrap();
@@ -2435,7 +2626,8 @@ function {function_name}:
     // Compute the fee.
     let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
     // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    let (stack, _) =
+        process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee, &rng.gen()).unwrap();
     // Add the stack *manually* to the process.
     process.add_stack(stack);
 
@@ -2638,6 +2830,1601 @@ fn test_program_exceeding_transaction_spend_limit() {
     assert!(result.is_err());
 
     // Attempt to initialize a `Stack` directly with the program, which should fail.
-    let result = Stack::initialize(&process, &program);
+    let result = Stack::initialize(&process, &program, <CurrentNetwork as Network>::EDITION);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_process_upgrade_compatibility() {
+    // Initialize the first edition of the program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program upgradable.aleo;
+
+struct point:
+    x as u32;
+    y as u32;
+
+mapping points:
+    key as u8.public;
+    value as point.public;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program);
+    let program_id = *program.id();
+    assert_eq!(process.get_stack(program_id).unwrap().edition(), <CurrentNetwork as Network>::EDITION);
+
+    // Ensure a new function and a new function body are compatible.
+    let upgrade = Program::<CurrentNetwork>::from_str(
+        r"
+program upgradable.aleo;
+
+struct point:
+    x as u32;
+    y as u32;
+
+mapping points:
+    key as u8.public;
+    value as point.public;
+
+function compute:
+    input r0 as u32.private;
+    mul r0 r0 into r1;
+    output r1 as u32.public;
+
+function negate:
+    input r0 as i32.private;
+    neg r0 into r1;
+    output r1 as i32.public;",
+    )
+    .unwrap();
+    let stack = Stack::upgrade(&process, &upgrade).unwrap();
+    assert_eq!(stack.edition(), <CurrentNetwork as Network>::EDITION + 1);
+
+    // Add the upgrade, and ensure both editions exist.
+    process.add_stack(stack);
+    assert_eq!(process.get_stack(program_id).unwrap().program(), &upgrade);
+    assert_eq!(process.get_stack_edition(program_id, 0).unwrap().program(), &program);
+    assert_eq!(process.get_stack_editions(&program_id).len(), 2);
+
+    // Ensure the incompatible upgrades are rejected.
+    for incompatible in [
+        // The mapping is removed.
+        r"
+program upgradable.aleo;
+
+struct point:
+    x as u32;
+    y as u32;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+        // The struct is changed.
+        r"
+program upgradable.aleo;
+
+struct point:
+    x as u64;
+    y as u64;
+
+mapping points:
+    key as u8.public;
+    value as point.public;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+        // The function signature is changed.
+        r"
+program upgradable.aleo;
+
+struct point:
+    x as u32;
+    y as u32;
+
+mapping points:
+    key as u8.public;
+    value as point.public;
+
+function compute:
+    input r0 as u32.public;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+    ] {
+        let incompatible = Program::<CurrentNetwork>::from_str(incompatible).unwrap();
+        assert!(Stack::upgrade(&process, &incompatible).is_err());
+    }
+
+    // Ensure 'credits.aleo' cannot be upgraded.
+    assert!(Stack::upgrade(&process, &Program::credits().unwrap()).is_err());
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Construct the deployment of the first edition, and of an incompatible second edition.
+    let incompatible = Program::<CurrentNetwork>::from_str(
+        r"
+program upgradable.aleo;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+    )
+    .unwrap();
+    let deployment = Process::load().unwrap().deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    let upgrade = Process::load().unwrap().deploy::<CurrentAleo, _>(&incompatible, rng).unwrap();
+    let upgrade = Deployment::new(1, upgrade.program().clone(), upgrade.verifying_keys().clone()).unwrap();
+
+    // Ensure the incompatible edition is rejected when loaded, in either order.
+    let mut process = Process::load().unwrap();
+    process.load_deployment(&deployment).unwrap();
+    assert!(process.load_deployment(&upgrade).is_err());
+    let mut process = Process::load().unwrap();
+    process.load_deployment(&upgrade).unwrap();
+    assert!(process.load_deployment(&deployment).is_err());
+}
+
+#[test]
+fn test_process_execute_and_finalize_repeat() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program looping.aleo;
+
+mapping totals:
+    key as address.public;
+    value as u64.public;
+
+// Adds the step and the iteration to the accumulator.
+closure accumulate:
+    input r0 as u64;
+    input r1 as u64;
+    input r2 as u32;
+    cast r2 into r3 as u64;
+    add r0 r1 into r4;
+    add r4 r3 into r5;
+    output r5 as u64;
+
+closure accumulate_twice:
+    input r0 as u64;
+    input r1 as u64;
+    input r2 as u32;
+    repeat 2u32 accumulate r0 r1 into r3;
+    output r3 as u64;
+
+function compute:
+    input r0 as u64.public;
+    repeat 4u32 accumulate 0u64 r0 into r1;
+    repeat 3u32 accumulate_twice r1 r0 into r2;
+    async compute self.caller r2 into r3;
+    output r1 as u64.public;
+    output r2 as u64.public;
+    output r3 as looping.aleo/compute.future;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use totals[r0] 0u64 into r2;
+    repeat 4u32 accumulate r2 r1 into r3;
+    set r3 into totals[r0];
+
+function bump:
+    input r0 as u64.public;
+    async bump r0 into r1;
+    output r1 as looping.aleo/bump.future;
+
+finalize bump:
+    input r0 as u64.public;
+    repeat 4u32 accumulate r0 r0 into r1;
+    repeat 3u32 accumulate_twice r1 r0 into r2;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("totals").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Check that the finalize cost is charged for every iteration: 4 * 1_500 + 3 * (2 * 1_500).
+    let stack = process.get_stack(program_id).unwrap();
+    assert_eq!(stack.get_finalize_cost(&Identifier::from_str("bump").unwrap()).unwrap(), 15_000);
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) =
+        process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee, &rng.gen()).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str("10u64").unwrap();
+
+    // Declare the expected output values.
+    // The first loop computes (10 + 0) + (10 + 1) + (10 + 2) + (10 + 3) = 46.
+    let r1 = Value::<CurrentNetwork>::from_str("46u64").unwrap();
+    // The nested loop adds 3 * ((10 + 0) + (10 + 1)) = 63.
+    let r2 = Value::<CurrentNetwork>::from_str("109u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(3, candidate.len());
+    assert_eq!(r1, candidate[0]);
+    assert_eq!(r2, candidate[1]);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(3, candidate.len());
+    assert_eq!(r1, candidate[0]);
+    assert_eq!(r2, candidate[1]);
+
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("looping", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Now, finalize the execution.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check that the total is now 4 * 109 + (0 + 1 + 2 + 3) = 442.
+    let candidate = finalize_store
+        .get_value_speculative(*program_id, mapping_name, &Plaintext::from(Literal::Address(caller)))
+        .unwrap()
+        .unwrap();
+    assert_eq!(candidate, Value::from_str("442u64").unwrap());
+}
+
+#[test]
+fn test_process_execute_and_finalize_register_index() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program indexing.aleo;
+
+struct entry:
+    amounts as [u64; 4u32];
+
+mapping picks:
+    key as address.public;
+    value as u64.public;
+
+function pick:
+    input r0 as [u64; 4u32].private;
+    input r1 as u32.private;
+    input r2 as [entry; 2u32].public;
+    input r3 as u32.public;
+    add r0[r1] r2[r3].amounts[r1] into r4;
+    async pick self.caller r0 r1 into r5;
+    output r4 as u64.private;
+    output r5 as indexing.aleo/pick.future;
+
+finalize pick:
+    input r0 as address.public;
+    input r1 as [u64; 4u32].public;
+    input r2 as u32.public;
+    set r1[r2] into picks[r0];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("picks").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("pick").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) =
+        process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee, &rng.gen()).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str("[1u64, 2u64, 3u64, 4u64]").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("2u32").unwrap();
+    let r2 = Value::<CurrentNetwork>::from_str(
+        "[{ amounts: [10u64, 20u64, 30u64, 40u64] }, { amounts: [50u64, 60u64, 70u64, 80u64] }]",
+    )
+    .unwrap();
+    let r3 = Value::<CurrentNetwork>::from_str("1u32").unwrap();
+
+    // Declare the expected output value, i.e. `r0[2] + r2[1].amounts[2]`.
+    let r4 = Value::<CurrentNetwork>::from_str("73u64").unwrap();
+
+    // Ensure an out-of-bounds index fails to evaluate.
+    let out_of_bounds = Value::<CurrentNetwork>::from_str("4u32").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program_id,
+            function_name,
+            [r0.clone(), out_of_bounds, r2.clone(), r3.clone()].iter(),
+            rng,
+        )
+        .unwrap();
+    assert!(process.evaluate::<CurrentAleo>(authorization).is_err());
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, [r0, r1, r2, r3].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(2, candidate.len());
+    assert_eq!(r4, candidate[0]);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(2, candidate.len());
+    assert_eq!(r4, candidate[0]);
+
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("indexing", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Now, finalize the execution.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check that the pick is now `r0[2]`.
+    let candidate = finalize_store
+        .get_value_speculative(*program_id, mapping_name, &Plaintext::from(Literal::Address(caller)))
+        .unwrap()
+        .unwrap();
+    assert_eq!(candidate, Value::from_str("3u64").unwrap());
+}
+
+#[test]
+fn test_process_register_index_must_be_u32() {
+    // Initialize a new program, that indexes with a `u8`.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program indexing.aleo;
+
+function pick:
+    input r0 as [u64; 4u32].private;
+    input r1 as u8.private;
+    add r0[r1] 1u64 into r2;
+    output r2 as u64.private;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Ensure the program is rejected.
+    let mut process = Process::load().unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_execute_and_finalize_block_timestamp_and_network_id() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program timing.aleo;
+
+mapping deadlines:
+    key as u16.public;
+    value as i64.public;
+
+function schedule:
+    input r0 as i64.public;
+    async schedule r0 into r1;
+    output r1 as timing.aleo/schedule.future;
+
+finalize schedule:
+    input r0 as i64.public;
+    add block.timestamp r0 into r1;
+    set r1 into deadlines[network.id];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("deadlines").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("schedule").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) =
+        process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee, &rng.gen()).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let r0 = Value::<CurrentNetwork>::from_str("3600i64").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, [r0].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Execute the request.
+    let (_response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("timing", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Now, finalize the execution, with a block timestamp.
+    let state = FinalizeGlobalState::from(2, 2, 1_700_000_000, [0u8; 32]);
+    process.finalize_execution(state, &finalize_store, &execution, None).unwrap();
+
+    // Check that the deadline is stored under the network ID.
+    let key = Plaintext::from(Literal::U16(U16::new(CurrentNetwork::ID)));
+    let candidate = finalize_store.get_value_speculative(*program_id, mapping_name, &key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("1700003600i64").unwrap());
+}
+
+#[test]
+fn test_process_block_timestamp_and_network_id_are_finalize_only() {
+    for (operand, type_) in [("block.timestamp", "i64"), ("network.id", "u16")] {
+        // Initialize a new program, that uses the operand in a function.
+        let program = format!(
+            r"
+program timing.aleo;
+
+function stamp:
+    input r0 as {type_}.private;
+    add {operand} r0 into r1;
+    output r1 as {type_}.private;
+"
+        );
+        let (string, program) = Program::<CurrentNetwork>::parse(&program).unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Ensure the program is rejected.
+        let mut process = Process::load().unwrap();
+        assert!(process.add_program(&program).is_err());
+    }
+}
+
+#[test]
+fn test_process_repeat_is_well_formed() {
+    /// Returns `true` if the given program can be added to a process.
+    fn is_well_formed(program: &str) -> bool {
+        let program = Program::<CurrentNetwork>::from_str(program).unwrap();
+        Process::<CurrentNetwork>::load().unwrap().add_program(&program).is_ok()
+    }
+
+    // A closure may repeat a closure that is defined before it.
+    assert!(is_well_formed(
+        r"
+program looping.aleo;
+closure twice:
+    input r0 as u64;
+    input r1 as u32;
+    add r0 r0 into r2;
+    output r2 as u64;
+closure pow2:
+    input r0 as u64;
+    input r1 as u32;
+    repeat 8u32 twice r0 into r2;
+    output r2 as u64;
+function compute:
+    input r0 as u64.private;
+    repeat 2u32 pow2 r0 into r1;
+    output r1 as u64.private;"
+    ));
+
+    // A closure may not repeat itself.
+    assert!(!is_well_formed(
+        r"
+program looping.aleo;
+closure twice:
+    input r0 as u64;
+    input r1 as u32;
+    repeat 2u32 twice r0 into r2;
+    output r2 as u64;
+function compute:
+    input r0 as u64.private;
+    repeat 2u32 twice r0 into r1;
+    output r1 as u64.private;"
+    ));
+
+    // A closure may not repeat a closure that is defined after it.
+    assert!(!is_well_formed(
+        r"
+program looping.aleo;
+closure pow2:
+    input r0 as u64;
+    input r1 as u32;
+    repeat 8u32 twice r0 into r2;
+    output r2 as u64;
+closure twice:
+    input r0 as u64;
+    input r1 as u32;
+    add r0 r0 into r2;
+    output r2 as u64;
+function compute:
+    input r0 as u64.private;
+    repeat 2u32 pow2 r0 into r1;
+    output r1 as u64.private;"
+    ));
+
+    // The closure outputs must match the loop-carried state.
+    assert!(!is_well_formed(
+        r"
+program looping.aleo;
+closure widen:
+    input r0 as u32;
+    input r1 as u32;
+    cast r0 into r2 as u64;
+    output r2 as u64;
+function compute:
+    input r0 as u32.private;
+    repeat 2u32 widen r0 into r1;
+    output r1 as u64.private;"
+    ));
+
+    // The closure must take the iteration as its last input.
+    assert!(!is_well_formed(
+        r"
+program looping.aleo;
+closure twice:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+function compute:
+    input r0 as u64.private;
+    repeat 2u32 twice r0 into r1;
+    output r1 as u64.private;"
+    ));
+
+    // A closure repeated in finalize may not use records.
+    assert!(!is_well_formed(
+        r"
+program looping.aleo;
+record token:
+    owner as address.private;
+    amount as u64.private;
+closure mint:
+    input r0 as u64;
+    input r1 as u32;
+    cast aleo1wfyyj2uvwuqw0c0dqa5x70wrawnlkkvuepn4y08xyaqfqqwweqys39jayw r0 into r2 as token.record;
+    output r0 as u64;
+function compute:
+    input r0 as u64.public;
+    async compute r0 into r1;
+    output r1 as looping.aleo/compute.future;
+finalize compute:
+    input r0 as u64.public;
+    repeat 2u32 mint r0 into r1;"
+    ));
+}
+
+#[test]
+fn test_process_evaluate_ecdsa_verify() {
+    use console::algorithms::ECDSA;
+
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program bridge.aleo;
+
+function verify:
+    input r0 as [[u8; 32u32]; 2u32].public;
+    input r1 as [[u8; 32u32]; 2u32].public;
+    input r2 as [u8; 32u32].public;
+    ecdsa.verify r0 r1 r2 into r3;
+    async verify r0 r1 r2 into r4;
+    output r3 as boolean.public;
+    output r4 as bridge.aleo/verify.future;
+
+finalize verify:
+    input r0 as [[u8; 32u32]; 2u32].public;
+    input r1 as [[u8; 32u32]; 2u32].public;
+    input r2 as [u8; 32u32].public;
+    ecdsa.verify r0 r1 r2 into r3;
+    assert.eq r3 true;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("verify").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the finalize cost includes the signature verification.
+    let stack = process.get_stack(program.id()).unwrap();
+    assert_eq!(stack.get_finalize_cost(&function_name).unwrap(), 250_500);
+
+    // Returns the given bytes as a value, split into rows of 32 bytes if there is more than one row.
+    let to_value = |bytes: &[u8]| {
+        let rows = bytes
+            .chunks(32)
+            .map(|row| format!("[{}]", row.iter().map(|byte| format!("{byte}u8")).collect::<Vec<_>>().join(", ")))
+            .collect::<Vec<_>>();
+        match rows.len() {
+            1 => Value::<CurrentNetwork>::from_str(&rows[0]).unwrap(),
+            _ => Value::<CurrentNetwork>::from_str(&format!("[{}]", rows.join(", "))).unwrap(),
+        }
+    };
+
+    // Sample a secp256k1 key, and sign a digest.
+    let secret_key = ECDSA::sample_secret_key(rng);
+    let public_key = ECDSA::to_public_key(&secret_key).unwrap();
+    let digest: [u8; 32] = Uniform::rand(rng);
+    let signature = ECDSA::sign(&secret_key, &digest, rng).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Check that the signature verifies for the digest, and not for a different digest.
+    let mut other_digest = digest;
+    other_digest[0] ^= 1;
+    for (digest, expected) in [(digest, true), (other_digest, false)] {
+        let inputs = [to_value(&signature), to_value(&public_key), to_value(&digest)];
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Compute the output value.
+        let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(2, candidate.len());
+        assert_eq!(Value::from_str(&format!("{expected}")).unwrap(), candidate[0]);
+    }
+}
+
+#[test]
+fn test_process_execute_and_finalize_emit() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program logger.aleo;
+
+struct pair:
+    first as u64;
+    second as u64;
+
+function log:
+    input r0 as u64.public;
+    async log r0 into r1;
+    output r1 as logger.aleo/log.future;
+
+finalize log:
+    input r0 as u64.public;
+    emit r0;
+    add r0 1u64 into r1;
+    cast r0 r1 into r2 as pair;
+    emit r2;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the function name.
+    let function_name = Identifier::from_str("log").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) =
+        process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee, &rng.gen()).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let r0 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, [r0].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Execute the request.
+    let (_response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("logger", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Now, finalize the execution.
+    let (finalize_operations, events) =
+        process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+    assert!(finalize_operations.is_empty());
+
+    // Check the events, in the order they were emitted.
+    assert_eq!(events.len(), 2);
+    for event in events.iter() {
+        assert_eq!(event.program_id(), program_id);
+        assert_eq!(event.function_name(), &function_name);
+    }
+    assert_eq!(events[0].payload(), &Plaintext::from_str("5u64").unwrap());
+    assert_eq!(events[1].payload(), &Plaintext::from_str("{ first: 5u64, second: 6u64 }").unwrap());
+}
+
+#[test]
+fn test_process_emit_rejects_future() {
+    // Initialize a new program, that emits a future.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+import credits.aleo;
+
+program logger.aleo;
+
+function log:
+    input r0 as address.public;
+    input r1 as u64.public;
+    call credits.aleo/transfer_public r0 r1 into r2;
+    async log r2 into r3;
+    output r3 as logger.aleo/log.future;
+
+finalize log:
+    input r0 as credits.aleo/transfer_public.future;
+    emit r0;
+    await r0;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Ensure the program is rejected.
+    let mut process = Process::load().unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_evaluate_merkle_verify() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program allowlist.aleo;
+
+function verify:
+    input r0 as field.public;
+    input r1 as u64.public;
+    input r2 as [field; 3u32].public;
+    merkle.verify.bhp r0 r1 r2 into r3;
+    merkle.verify.psd r0 r1 r2 into r4;
+    output r3 as boolean.public;
+    output r4 as boolean.public;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("verify").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Construct the allowlist leaves.
+    let leaves: Vec<Plaintext<CurrentNetwork>> =
+        (0..4u64).map(|i| Plaintext::from_str(&format!("{i}u64")).unwrap()).collect();
+    let bhp_leaves: Vec<_> = leaves.iter().map(|leaf| leaf.to_bits_le()).collect();
+    let psd_leaves: Vec<_> = leaves.iter().map(|leaf| leaf.to_fields().unwrap()).collect();
+    let bhp_tree = CurrentNetwork::merkle_tree_bhp::<2>(&bhp_leaves).unwrap();
+    let psd_tree = CurrentNetwork::merkle_tree_psd::<2>(&psd_leaves).unwrap();
+
+    // Returns the given leaf index and siblings as a path value, with the leaf index first.
+    let to_value = |leaf_index: u64, siblings: &[Field<CurrentNetwork>]| {
+        let fields = std::iter::once(Field::from_u64(leaf_index)).chain(siblings.iter().copied());
+        let fields = fields.map(|field| field.to_string()).collect::<Vec<_>>();
+        Value::<CurrentNetwork>::from_str(&format!("[{}]", fields.join(", "))).unwrap()
+    };
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Check that the BHP path verifies for its leaf, and the Poseidon path for its own, but not for a different leaf.
+    let bhp_path = bhp_tree.prove(2, &bhp_leaves[2]).unwrap();
+    let psd_path = psd_tree.prove(2, &psd_leaves[2]).unwrap();
+    let bhp_path = to_value(*bhp_path.leaf_index(), bhp_path.siblings());
+    let psd_path = to_value(*psd_path.leaf_index(), psd_path.siblings());
+    let bhp_root = Value::from(Literal::Field(*bhp_tree.root()));
+    let psd_root = Value::from(Literal::Field(*psd_tree.root()));
+    for (root, leaf, path, expected) in [
+        (&bhp_root, "2u64", &bhp_path, [true, false]),
+        (&bhp_root, "3u64", &bhp_path, [false, false]),
+        (&psd_root, "2u64", &psd_path, [false, true]),
+        (&psd_root, "3u64", &psd_path, [false, false]),
+    ] {
+        let inputs = [root.clone(), Value::from_str(leaf).unwrap(), path.clone()];
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Compute the output value.
+        let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(2, candidate.len());
+        assert_eq!(Value::from_str(&format!("{}", expected[0])).unwrap(), candidate[0]);
+        assert_eq!(Value::from_str(&format!("{}", expected[1])).unwrap(), candidate[1]);
+    }
+}
+
+#[test]
+fn test_process_execute_string_instructions() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program strings.aleo;
+
+function run:
+    input r0 as string.private;
+    input r1 as string.private;
+    concat r0 r1 into r2;
+    len r2 into r3;
+    slice r2 1u32 4u32 into r4;
+    cast r2 into r5 as [u8; 16u32];
+    cast r5 into r6 as string;
+    output r2 as string.private;
+    output r3 as u32.private;
+    output r4 as string.private;
+    output r5 as [u8; 16u32].private;
+    output r6 as string.private;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("run").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Declare the inputs and expected outputs.
+    let inputs = [Value::<CurrentNetwork>::from_str("\"hello\"").unwrap(), Value::from_str("\" world\"").unwrap()];
+    let bytes = "hello world".bytes().map(|byte| format!("{byte}u8")).chain((11..16).map(|_| "0u8".to_string()));
+    let expected = [
+        Value::from_str("\"hello world\"").unwrap(),
+        Value::from_str("11u32").unwrap(),
+        Value::from_str("\"ell\"").unwrap(),
+        Value::from_str(&format!("[{}]", bytes.collect::<Vec<_>>().join(", "))).unwrap(),
+        Value::from_str("\"hello world\"").unwrap(),
+    ];
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(expected, response.outputs());
+
+    // Execute the request.
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(expected, response.outputs());
+}
+
+#[test]
+fn test_process_string_casts_require_byte_arrays() {
+    for (body, is_valid) in [
+        ("cast r0 into r1 as [u8; 4u32];\n    output r1 as [u8; 4u32].private;", true),
+        ("cast r0 into r1 as [u16; 4u32];\n    output r1 as [u16; 4u32].private;", false),
+        ("cast r0 into r1 as [string; 1u32];\n    output r1 as [string; 1u32].private;", true),
+        ("cast r0 into r1 as [u8; 4u32];\n    cast r1 into r2 as field;\n    output r2 as field.private;", false),
+        (
+            "cast r0 into r1 as [u8; 4u32];\n    cast.lossy r1 into r2 as string;\n    output r2 as string.private;",
+            false,
+        ),
+    ] {
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            "program casts.aleo;\n\nfunction run:\n    input r0 as string.private;\n    {body}\n"
+        ))
+        .unwrap();
+        let mut process = Process::load().unwrap();
+        assert_eq!(is_valid, process.add_program(&program).is_ok(), "{body}");
+    }
+}
+
+#[test]
+fn test_process_finalize_command_cost_is_weighted_by_value_size() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program metered.aleo;
+
+mapping names:
+    key as u8.public;
+    value as string.public;
+
+function store:
+    input r0 as u8.public;
+    input r1 as string.public;
+    async store r0 r1 into r2;
+    output r2 as metered.aleo/store.future;
+
+finalize store:
+    input r0 as u8.public;
+    input r1 as string.public;
+    set r1 into names[r0];
+    get names[r0] into r2;
+    contains names[r0] into r3;
+    is.eq r2 r1 into r4;
+",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("store").unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Retrieve the stack.
+    let stack: &Stack<_> = process.get_stack(program.id()).unwrap();
+    // Retrieve the finalize logic.
+    let finalize = stack.get_function_ref(&function_name).unwrap().finalize_logic().unwrap();
+
+    // Initialize the finalize registers, with a short string.
+    let finalize_types = stack.get_finalize_types(&function_name).unwrap().clone();
+    let mut registers =
+        FinalizeRegisters::new(sample_finalize_state(1), Default::default(), function_name, finalize_types);
+    registers.store(stack, &Register::Locator(0), Value::from_str("1u8").unwrap()).unwrap();
+    registers.store(stack, &Register::Locator(1), Value::from_str("\"hello\"").unwrap()).unwrap();
+
+    // Compute the cost of each command.
+    let costs: Vec<_> =
+        finalize.commands().iter().map(|command| cost_of_finalize_command(stack, &registers, command).unwrap()).collect();
+    // The `set` is weighted by the key and the 5-byte string, and the reads by the key.
+    assert_eq!(costs, [10_000 + 100 * 6, 10_000 + 10, 10_000 + 10, 500]);
+
+    // Store the value read by the `get`, and ensure the read is weighted by the 5-byte string.
+    registers.store(stack, &Register::Locator(2), Value::from_str("\"hello\"").unwrap()).unwrap();
+    let read_costs: Vec<_> =
+        finalize.commands().iter().map(|command| cost_of_finalize_read(stack, &registers, command).unwrap()).collect();
+    assert_eq!(read_costs, [0, 10 * 5, 0, 0]);
+
+    // Initialize the finalize registers, with a string of the maximum size.
+    let string = Value::from_str(&format!("\"{}\"", "a".repeat(CurrentNetwork::MAX_STRING_BYTES as usize))).unwrap();
+    let finalize_types = stack.get_finalize_types(&function_name).unwrap().clone();
+    let mut registers =
+        FinalizeRegisters::new(sample_finalize_state(1), Default::default(), function_name, finalize_types);
+    registers.store(stack, &Register::Locator(0), Value::from_str("1u8").unwrap()).unwrap();
+    registers.store(stack, &Register::Locator(1), string.clone()).unwrap();
+    registers.store(stack, &Register::Locator(2), string).unwrap();
+
+    // Compute the metered cost of the commands, including the value read by the `get`.
+    let metered_cost = finalize
+        .commands()
+        .iter()
+        .map(|command| {
+            cost_of_finalize_command(stack, &registers, command).unwrap()
+                + cost_of_finalize_read(stack, &registers, command).unwrap()
+        })
+        .sum::<u64>();
+
+    // Ensure reading a large value exceeds the minimum finalize cost, so a fee paying only the minimum is rejected.
+    let minimum_cost = stack.get_finalize_cost(&function_name).unwrap();
+    assert!(metered_cost > minimum_cost, "{metered_cost} <= {minimum_cost}");
+}
+
+#[test]
+fn test_process_evaluate_option_instructions() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program options.aleo;
+
+function run:
+    input r0 as u64.private;
+    option.some r0 into r1;
+    option.none into r2 as u64?;
+    option.is_some r1 into r3;
+    option.is_some r2 into r4;
+    option.unwrap r1 into r5;
+    is.eq r1 r2 into r6;
+    output r1 as u64?.private;
+    output r2 as u64?.private;
+    output r3 as boolean.private;
+    output r4 as boolean.private;
+    output r5 as u64.private;
+    output r6 as boolean.private;
+
+function unwrap_none:
+    input r0 as u64?.private;
+    option.unwrap r0 into r1;
+    output r1 as u64.private;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Evaluate the `run` function.
+    let inputs = [Value::<CurrentNetwork>::from_str("5u64").unwrap()];
+    let function_name = Identifier::from_str("run").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+    let expected: Vec<Value<CurrentNetwork>> = ["some(5u64)", "none(u64)", "true", "false", "5u64", "false"]
+        .iter()
+        .map(|value| Value::from_str(value).unwrap())
+        .collect();
+    assert_eq!(expected, response.outputs());
+
+    // Ensure unwrapping an empty optional fails.
+    let inputs = [Value::<CurrentNetwork>::from_str("none(u64)").unwrap()];
+    let function_name = Identifier::from_str("unwrap_none").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    assert!(process.evaluate::<CurrentAleo>(authorization).is_err());
+}
+
+#[test]
+fn test_process_serialize_instructions() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program serialize.aleo;
+
+struct pair:
+    a as u8;
+    b as u16;
+
+function roundtrip:
+    input r0 as pair.private;
+    serialize.bytes r0 into r1 as [u8; 3u32];
+    deserialize.bytes r1 into r2 as pair;
+    serialize.bits r0.a into r3 as [boolean; 8u32];
+    deserialize.bits r3 into r4 as u8;
+    output r1 as [u8; 3u32].private;
+    output r2 as pair.private;
+    output r4 as u8.private;
+
+function to_boolean:
+    input r0 as [u8; 1u32].private;
+    deserialize.bytes r0 into r1 as boolean;
+    output r1 as boolean.private;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Evaluate and execute the `roundtrip` function.
+    let inputs = [Value::<CurrentNetwork>::from_str("{ a: 1u8, b: 515u16 }").unwrap()];
+    let function_name = Identifier::from_str("roundtrip").unwrap();
+    let expected: Vec<Value<CurrentNetwork>> = ["[1u8, 3u8, 2u8]", "{ a: 1u8, b: 515u16 }", "1u8"]
+        .iter()
+        .map(|value| Value::from_str(value).unwrap())
+        .collect();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(expected, response.outputs());
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(expected, response.outputs());
+
+    // Ensure deserializing requires the padding bits to be zero.
+    for (input, expected) in [("[1u8]", Some("true")), ("[0u8]", Some("false")), ("[3u8]", None)] {
+        let inputs = [Value::<CurrentNetwork>::from_str(input).unwrap()];
+        let function_name = Identifier::from_str("to_boolean").unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        match expected {
+            Some(expected) => {
+                let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+                assert_eq!(vec![Value::from_str(expected).unwrap()], response.outputs());
+            }
+            None => assert!(process.evaluate::<CurrentAleo>(authorization).is_err()),
+        }
+    }
+
+    // Ensure the sizes are checked when the program is added.
+    for instruction in [
+        "serialize.bits r0 into r1 as [boolean; 8u32];",
+        "serialize.bytes r0 into r1 as [u8; 3u32];",
+        "deserialize.bits r0 into r1 as u8;",
+    ] {
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            r"
+program serialize.aleo;
+
+function bad:
+    input r0 as u16.private;
+    {instruction}
+"
+        ))
+        .unwrap();
+        assert!(Process::<CurrentNetwork>::load().unwrap().add_program(&program).is_err());
+    }
+}
+
+#[test]
+fn test_process_get_or_use_optional_default() {
+    for (default, command, is_valid) in [
+        ("none(u64)", "option.unwrap r1 into r2;", true),
+        ("0u64", "add r1 1u64 into r2;", true),
+        ("none(u32)", "option.unwrap r1 into r2;", false),
+        ("none(u64)", "add r1 1u64 into r2;", false),
+    ] {
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            r"
+program lookups.aleo;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+function lookup:
+    input r0 as address.public;
+    async lookup r0 into r1;
+    output r1 as lookups.aleo/lookup.future;
+
+finalize lookup:
+    input r0 as address.public;
+    get.or_use balances[r0] {default} into r1;
+    {command}
+"
+        ))
+        .unwrap();
+        let mut process = Process::load().unwrap();
+        assert_eq!(is_valid, process.add_program(&program).is_ok(), "{default}: {command}");
+    }
+}
+
+#[test]
+fn test_process_execute_call_dynamic() {
+    // Initialize the callee programs, with the same signature.
+    let adder = Program::<CurrentNetwork>::from_str(
+        r"
+program adder.aleo;
+
+function compute:
+    input r0 as u64.public;
+    input r1 as u64.public;
+    add r0 r1 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+    let multiplier = Program::<CurrentNetwork>::from_str(
+        r"
+program multiplier.aleo;
+
+function compute:
+    input r0 as u64.public;
+    input r1 as u64.public;
+    mul r0 r1 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+    // Initialize a callee program, with a different signature.
+    let mismatch = Program::<CurrentNetwork>::from_str(
+        r"
+program mismatch.aleo;
+
+function compute:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+    // Initialize the router program, which does not import the callee programs.
+    let router = Program::<CurrentNetwork>::from_str(
+        r"
+program router.aleo;
+
+function route:
+    input r0 as address.public;
+    input r1 as field.public;
+    input r2 as u64.public;
+    input r3 as u64.public;
+    call.dynamic r0 r1 r2 r3 into r4 as (u64.public, u64.public) -> (u64.private);
+    output r4 as u64.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&adder).unwrap();
+    process.add_program(&multiplier).unwrap();
+    process.add_program(&mismatch).unwrap();
+    process.add_program(&router).unwrap();
+
+    // Ensure the number of calls is a lower bound for a function with a dynamic call.
+    let stack = process.get_stack(router.id()).unwrap();
+    let function_name = Identifier::from_str("route").unwrap();
+    assert_eq!(stack.get_number_of_calls(&function_name).unwrap(), 2);
+    assert!(stack.contains_dynamic_call(&function_name).unwrap());
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Check that the circuit key can be synthesized, independently of the callee.
+    process.synthesize_key::<CurrentAleo, _>(router.id(), &function_name, rng).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Prepare the inputs for a dynamic call to the given program.
+    let callee = Identifier::<CurrentNetwork>::from_str("compute").unwrap().to_field().unwrap();
+    let inputs = |program: &Program<CurrentNetwork>| {
+        [
+            Value::<CurrentNetwork>::from(Literal::Address(program.id().to_address().unwrap())),
+            Value::from(Literal::Field(callee)),
+            Value::from_str("5u64").unwrap(),
+            Value::from_str("7u64").unwrap(),
+        ]
+    };
+
+    for (program, expected) in [(&adder, "12u64"), (&multiplier, "35u64")] {
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, router.id(), function_name, inputs(program).iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 2);
+
+        // Compute the output value.
+        let expected = Value::from_str(expected).unwrap();
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(response.outputs(), [expected.clone()]);
+
+        // Execute the request.
+        let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs(), [expected]);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Prepare the trace.
+        trace.prepare(Query::from(block_store)).unwrap();
+        // Prove the execution.
+        let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+        assert_eq!(execution.len(), 2);
+
+        // Verify the execution.
+        process.verify_execution(&execution).unwrap();
+    }
+
+    // Ensure a dynamic call to a function with a different signature fails.
+    let result = process.authorize::<CurrentAleo, _>(
+        &caller_private_key,
+        router.id(),
+        function_name,
+        inputs(&mismatch).iter(),
+        rng,
+    );
+    assert!(result.is_err());
+
+    // Ensure a dynamic call to a program that does not exist fails.
+    let mut unknown = inputs(&adder);
+    unknown[0] = Value::from(Literal::Address(Address::try_from(&caller_private_key).unwrap()));
+    let result =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, router.id(), function_name, unknown.iter(), rng);
+    assert!(result.is_err());
+
+    // Initialize a callee program, which is only added to a clone of the process.
+    let subtractor = Program::<CurrentNetwork>::from_str(
+        r"
+program subtractor.aleo;
+
+function compute:
+    input r0 as u64.public;
+    input r1 as u64.public;
+    sub r1 r0 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+    let mut clone = process.clone();
+    clone.add_program(&subtractor).unwrap();
+
+    // Ensure a dynamic call resolves against the stacks of its own process.
+    let authorization = clone
+        .authorize::<CurrentAleo, _>(&caller_private_key, router.id(), function_name, inputs(&subtractor).iter(), rng)
+        .unwrap();
+    let response = clone.evaluate::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(response.outputs(), [Value::from_str("2u64").unwrap()]);
+    let result = process.authorize::<CurrentAleo, _>(
+        &caller_private_key,
+        router.id(),
+        function_name,
+        inputs(&subtractor).iter(),
+        rng,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_process_deploy_with_constructor() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program constructor_test.aleo;
+
+mapping admin:
+    key as u8.public;
+    value as address.public;
+
+mapping supply:
+    key as u8.public;
+    value as u64.public;
+
+constructor:
+    input r0 as address.public;
+    set r0 into admin[0u8];
+    set 1000000u64 into supply[0u8];
+
+function noop:
+    input r0 as u64.public;
+    output r0 as u64.public;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Initialize a new deployer account.
+    let deployer_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let deployer = Address::try_from(&deployer_private_key).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the deployment cost, and ensure it includes the constructor cost.
+    let (cost, (_, _, constructor_cost, _)) = deployment_cost(&process, &deployment).unwrap();
+    assert!(constructor_cost > 0);
+
+    // Ensure the deployment fails to finalize, if the base fee does not cover the constructor.
+    {
+        // Initialize a separate finalize store, as a failed finalize leaves its store mid-batch.
+        let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+        let fee =
+            sample_fee_with_base_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, cost - 1, rng);
+        let result =
+            process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee, &deployer);
+        assert!(result.is_err());
+        let admin = Identifier::from_str("admin").unwrap();
+        assert!(!finalize_store.contains_mapping_confirmed(program_id, &admin).unwrap());
+    }
+
+    // Compute the fee.
+    let fee = sample_fee_with_base_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, cost, rng);
+    // Finalize the deployment.
+    let (stack, operations) =
+        process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee, &deployer).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Ensure the constructor operations are recorded after the mapping initializations.
+    let num_operations = operations.len();
+    assert!(matches!(operations[num_operations - 4], FinalizeOperation::InitializeMapping(..)));
+    assert!(matches!(operations[num_operations - 3], FinalizeOperation::InitializeMapping(..)));
+    assert!(matches!(operations[num_operations - 2], FinalizeOperation::UpdateKeyValue(..)));
+    assert!(matches!(operations[num_operations - 1], FinalizeOperation::UpdateKeyValue(..)));
+
+    // Ensure the constructor initialized the mappings.
+    let key = Plaintext::from(Literal::U8(U8::new(0)));
+    let admin = finalize_store
+        .get_value_speculative(*program_id, Identifier::from_str("admin").unwrap(), &key)
+        .unwrap()
+        .unwrap();
+    assert_eq!(admin, Value::from(Literal::Address(deployer)));
+    let supply = finalize_store
+        .get_value_speculative(*program_id, Identifier::from_str("supply").unwrap(), &key)
+        .unwrap()
+        .unwrap();
+    assert_eq!(supply, Value::from(Literal::U64(U64::new(1000000))));
+
+    // Initialize a program whose constructor halts.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program constructor_halts.aleo;
+
+mapping admin:
+    key as u8.public;
+    value as address.public;
+
+constructor:
+    input r0 as address.public;
+    set r0 into admin[0u8];
+    assert.neq r0 r0;
+
+function noop:
+    input r0 as u64.public;
+    output r0 as u64.public;
+",
+    )
+    .unwrap();
+
+    // Ensure the deployment fails to finalize, and the mapping is not initialized.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    let (cost, _) = deployment_cost(&process, &deployment).unwrap();
+    let fee = sample_fee_with_base_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, cost, rng);
+    let result = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee, &deployer);
+    assert!(result.is_err());
+    assert!(!finalize_store.contains_mapping_confirmed(program.id(), &Identifier::from_str("admin").unwrap()).unwrap());
+
+    // Ensure a constructor cannot emit events.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program constructor_emits.aleo;
+
+constructor:
+    input r0 as address.public;
+    emit r0;
+
+function noop:
+    input r0 as u64.public;
+    output r0 as u64.public;
+",
+    )
+    .unwrap();
+    assert!(process.deploy::<CurrentAleo, _>(&program, rng).is_err());
+}
@@ -24,13 +24,9 @@ impl<N: Network> Process<N> {
     ) -> Result<()> {
         let timer = timer!("Process::verify_deployment");
 
-        // Retrieve the program ID.
-        let program_id = deployment.program().id();
-        // Ensure the program does not already exist in the process.
-        ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
-
         // Ensure the program is well-formed, by computing the stack.
-        let stack = Stack::new(self, deployment.program())?;
+        // Note: If the program already exists, this ensures the deployment is a compatible upgrade at the next edition.
+        let stack = self.compute_deployment_stack(deployment)?;
         lap!(timer, "Compute the stack");
 
         // Ensure the verifying keys are well-formed and the certificates are valid.
@@ -17,8 +17,23 @@ use super::*;
 impl<N: Network> Process<N> {
     /// Verifies the given execution is valid.
     /// Note: This does *not* check that the global state root exists in the ledger.
+    ///
+    /// An execution is bound to the latest edition of the program of its root transition,
+    /// so an execution constructed for an earlier edition is rejected once the program is upgraded.
     #[inline]
     pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
+        // Ensure the execution contains transitions.
+        ensure!(!execution.is_empty(), "There are no transitions in the execution");
+
+        // Retrieve the program ID of the root transition.
+        let program_id = *execution.peek()?.program_id();
+        // Verify the execution against the latest edition of the program.
+        self.verify_execution_with_root(execution, self.get_stack(program_id)?)
+    }
+
+    /// Verifies the given execution is valid, using the given stack for the program of the root transition.
+    #[inline]
+    fn verify_execution_with_root(&self, execution: &Execution<N>, root: &Arc<Stack<N>>) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
         // Ensure the execution contains transitions.
@@ -29,21 +44,16 @@ impl<N: Network> Process<N> {
             // Retrieve the transition (without popping it).
             let transition = execution.peek()?;
             // Retrieve the stack.
-            let stack = self.get_stack(transition.program_id())?;
+            let stack = self.get_stack_with_root(transition.program_id(), root)?;
             // Ensure the number of calls matches the number of transitions.
-            let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
-            ensure!(
-                number_of_calls == execution.len(),
-                "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
-                execution.len()
-            );
+            Self::check_number_of_calls(stack, transition.function_name(), execution.len())?;
             // Output the locator of the main function.
             Locator::new(*transition.program_id(), *transition.function_name()).to_string()
         };
         lap!(timer, "Verify the number of transitions");
 
         // Construct the call graph of the execution.
-        let call_graph = self.construct_call_graph(execution)?;
+        let call_graph = self.construct_call_graph_with_root(execution, root)?;
         // Construct the reverse call graph of the execution.
         // Note: This is a mapping of the child transition ID to the parent transition ID.
         let reverse_call_graph = Self::reverse_call_graph(&call_graph);
@@ -102,7 +112,7 @@ impl<N: Network> Process<N> {
             lap!(timer, "Verify the outputs");
 
             // Retrieve the stack.
-            let stack = self.get_stack(transition.program_id())?;
+            let stack = self.get_stack_with_root(transition.program_id(), root)?;
             // Retrieve the function from the stack.
             let function = stack.get_function(transition.function_name())?;
 
@@ -111,7 +121,8 @@ impl<N: Network> Process<N> {
             let parent = reverse_call_graph.get(transition.id()).and_then(|tid| execution.get_program_id(tid));
 
             // Construct the verifier inputs for the transition.
-            let inputs = self.to_transition_verifier_inputs(transition, parent, &call_graph, &mut transition_map)?;
+            let inputs =
+                self.to_transition_verifier_inputs(stack, transition, parent, &call_graph, &mut transition_map)?;
             lap!(timer, "Constructed the verifier inputs for a transition of {}", function.name());
 
             // Save the verifying key and its inputs.
@@ -155,9 +166,36 @@ impl<N: Network> Process<N> {
 }
 
 impl<N: Network> Process<N> {
+    /// Ensures the number of transitions in an execution matches the number of calls for the given function.
+    /// If the function contains a dynamic call, the number of calls is only a lower bound,
+    /// and the call structure is instead checked when constructing the call graph.
+    pub(crate) fn check_number_of_calls(
+        stack: &Stack<N>,
+        function_name: &Identifier<N>,
+        num_transitions: usize,
+    ) -> Result<()> {
+        // Retrieve the number of calls.
+        let number_of_calls = stack.get_number_of_calls(function_name)?;
+        match stack.contains_dynamic_call(function_name)? {
+            true => ensure!(
+                number_of_calls <= num_transitions && num_transitions < ledger_block::Transaction::<N>::MAX_TRANSITIONS,
+                "The number of transitions in the execution is incorrect. Expected at least {}, but found {}",
+                number_of_calls,
+                num_transitions
+            ),
+            false => ensure!(
+                number_of_calls == num_transitions,
+                "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
+                num_transitions
+            ),
+        }
+        Ok(())
+    }
+
     /// Returns the public inputs to verify the proof for the given transition.
     fn to_transition_verifier_inputs(
         &self,
+        stack: &Stack<N>,
         transition: &Transition<N>,
         parent: Option<&ProgramID<N>>,
         call_graph: &HashMap<N::TransitionID, Vec<N::TransitionID>>,
@@ -183,11 +221,42 @@ impl<N: Network> Process<N> {
         // [Inputs] Extend the verifier inputs with the public inputs for 'self.caller'.
         inputs.extend([*is_root, *parent_x, *parent_y]);
 
+        // Determine which of the function calls are dynamic calls, in the order they are called.
+        let mut is_dynamic_calls = Vec::new();
+        for instruction in stack.get_function_ref(transition.function_name())?.instructions() {
+            match instruction {
+                Instruction::Call(call) => {
+                    if call.is_function_call(stack)? {
+                        is_dynamic_calls.push(false);
+                    }
+                }
+                Instruction::CallDynamic(..) => is_dynamic_calls.push(true),
+                _ => (),
+            }
+        }
+        // Retrieve the child transition IDs.
+        let children = call_graph.get(transition.id()).unwrap();
+        ensure!(children.len() == is_dynamic_calls.len(), "The number of function calls is incorrect");
+
         // If there are function calls, append their inputs and outputs.
-        for transition_id in call_graph.get(transition.id()).unwrap() {
+        for (transition_id, is_dynamic_call) in children.iter().zip_eq(is_dynamic_calls) {
             // Note: This unwrap is safe, as we are processing transitions in post-order,
             // which implies that all child transition IDs have been added to `transition_map`.
             let transition: &&Transition<N> = transition_map.get(transition_id).unwrap();
+            // If the function call is a dynamic call, then append the callee program address and function.
+            if is_dynamic_call {
+                // Compute the function ID of the callee.
+                let network_id = U16::new(N::ID);
+                let function_id =
+                    compute_function_id(&network_id, transition.program_id(), transition.function_name())?;
+                // [Inputs] Extend the verifier inputs with the program address (as its x-coordinate),
+                // the function name (as a field element), and the function ID of the dynamic call.
+                inputs.extend([
+                    *transition.program_id().to_address()?.to_field()?,
+                    *transition.function_name().to_field()?,
+                    *function_id,
+                ]);
+            }
             // [Inputs] Extend the verifier inputs with the transition commitment of the external call.
             inputs.extend([**transition.tcm()]);
             // [Inputs] Extend the verifier inputs with the input IDs of the external call.
@@ -231,21 +300,54 @@ impl<N: Network> Process<N> {
     pub fn construct_call_graph(
         &self,
         execution: &Execution<N>,
+    ) -> Result<HashMap<N::TransitionID, Vec<N::TransitionID>>> {
+        // Retrieve the stack of the root transition.
+        let root = self.get_stack(execution.peek()?.program_id())?;
+        // Construct the call graph.
+        self.construct_call_graph_with_root(execution, root)
+    }
+
+    /// A helper function to construct a call graph from an execution,
+    /// using the given stack for the program of the root transition.
+    fn construct_call_graph_with_root(
+        &self,
+        execution: &Execution<N>,
+        root: &Arc<Stack<N>>,
     ) -> Result<HashMap<N::TransitionID, Vec<N::TransitionID>>> {
         // Metadata for each transition the execution.
+        // Note: For the callee of a dynamic call, the program ID and function name are only known from the execution,
+        // and the transition must instead match the declared signature of the `call.dynamic` instruction.
         struct TransitionMetadata<N: Network> {
             uid: usize,
-            pid: ProgramID<N>,
-            fname: Identifier<N>,
+            pid: Option<ProgramID<N>>,
+            fname: Option<Identifier<N>>,
             tid: Option<N::TransitionID>,
             children: Option<Vec<usize>>,
+            signature: Option<(Vec<ValueType<N>>, Vec<ValueType<N>>)>,
         }
 
         impl<N: Network> TransitionMetadata<N> {
             fn new(counter: &mut usize, pid: ProgramID<N>, fname: Identifier<N>, tid: Option<N::TransitionID>) -> Self {
                 let uid = *counter;
                 *counter += 1;
-                Self { uid, pid, fname, tid, children: None }
+                Self { uid, pid: Some(pid), fname: Some(fname), tid, children: None, signature: None }
+            }
+
+            fn new_dynamic(
+                counter: &mut usize,
+                input_types: Vec<ValueType<N>>,
+                output_types: Vec<ValueType<N>>,
+            ) -> Self {
+                let uid = *counter;
+                *counter += 1;
+                Self {
+                    uid,
+                    pid: None,
+                    fname: None,
+                    tid: None,
+                    children: None,
+                    signature: Some((input_types, output_types)),
+                }
             }
 
             /// Returns 'true' if the subgraph starting from this transition has been fully-indexed.
@@ -305,9 +407,27 @@ impl<N: Network> Process<N> {
                     ));
                 }
                 // If the stack is not empty, then add the current transition ID to the entry.
-                Some(head) => match head.pid == *transition.program_id() && head.fname == *transition.function_name() {
-                    true => head.tid = Some(*transition.id()),
-                    false => bail!("Invalid traversal - unexpected transition in the execution"),
+                Some(head) => match &head.signature {
+                    // If the entry is the callee of a dynamic call, then ensure the transition matches the signature.
+                    Some((input_types, output_types)) => {
+                        // Retrieve the function of the transition.
+                        let stack = self.get_stack_with_root(transition.program_id(), root)?;
+                        let function = stack.get_function_ref(transition.function_name())?;
+                        // Ensure the function matches the declared signature.
+                        ensure!(
+                            function.input_types() == *input_types && function.output_types() == *output_types,
+                            "Invalid traversal - transition does not match the signature of the dynamic call"
+                        );
+                        head.pid = Some(*transition.program_id());
+                        head.fname = Some(*transition.function_name());
+                        head.tid = Some(*transition.id());
+                    }
+                    None => match head.pid == Some(*transition.program_id())
+                        && head.fname == Some(*transition.function_name())
+                    {
+                        true => head.tid = Some(*transition.id()),
+                        false => bail!("Invalid traversal - unexpected transition in the execution"),
+                    },
                 },
             }
 
@@ -319,24 +439,40 @@ impl<N: Network> Process<N> {
                 // Note this unwrap is safe, for the same reason as above.
                 update_call_graph(traversal_stack.pop().unwrap(), &mut call_graph, &mut uid_to_tid)?;
             } else {
+                // Retrieve the program ID and function name.
+                let (top_pid, top_fname) = match (top.pid, top.fname) {
+                    (Some(pid), Some(fname)) => (pid, fname),
+                    _ => bail!("Invalid traversal - transition metadata is missing the program ID or function name"),
+                };
                 // Retrieve the stack.
-                let stack = self.get_stack(top.pid)?;
+                let stack = self.get_stack_with_root(&top_pid, root)?;
                 // Retrieve the function from the stack.
-                let function = stack.get_function(&top.fname)?;
+                let function = stack.get_function(&top_fname)?;
                 // Collect the children of the current transition.
                 let mut children = Vec::new();
                 for instruction in function.instructions() {
-                    if let Instruction::Call(call) = instruction {
-                        let (pid, fname) = match call.operator() {
-                            synthesizer_program::CallOperator::Locator(locator) => {
-                                (locator.program_id(), locator.resource())
+                    match instruction {
+                        Instruction::Call(call) => {
+                            let (pid, fname) = match call.operator() {
+                                synthesizer_program::CallOperator::Locator(locator) => {
+                                    (locator.program_id(), locator.resource())
+                                }
+                                synthesizer_program::CallOperator::Resource(fname) => (&top_pid, fname),
+                            };
+                            // Add the child to the traversal stack, only if it is a call to a transition.
+                            if self.get_stack_with_root(pid, root)?.get_function(fname).is_ok() {
+                                children.push(TransitionMetadata::new(&mut counter, *pid, *fname, None));
                             }
-                            synthesizer_program::CallOperator::Resource(fname) => (&top.pid, fname),
-                        };
-                        // Add the child to the traversal stack, only if it is a call to a transition.
-                        if self.get_stack(pid)?.get_function(fname).is_ok() {
-                            children.push(TransitionMetadata::new(&mut counter, *pid, *fname, None));
                         }
+                        // Add the callee of a dynamic call to the traversal stack, with its declared signature.
+                        Instruction::CallDynamic(call) => {
+                            children.push(TransitionMetadata::new_dynamic(
+                                &mut counter,
+                                call.input_types().to_vec(),
+                                call.return_types().to_vec(),
+                            ));
+                        }
+                        _ => (),
                     }
                 }
 
@@ -370,6 +506,18 @@ impl<N: Network> Process<N> {
         Ok(call_graph)
     }
 
+    /// Returns the given root stack if it is for the given program ID, and otherwise the latest stack for the program ID.
+    fn get_stack_with_root<'a>(
+        &'a self,
+        program_id: &ProgramID<N>,
+        root: &'a Arc<Stack<N>>,
+    ) -> Result<&'a Arc<Stack<N>>> {
+        match root.program_id() == program_id {
+            true => Ok(root),
+            false => self.get_stack(program_id),
+        }
+    }
+
     /// A helper function to reverse the call graph.
     ///
     /// The call graph is a mapping of parent transition IDs to child transition IDs,
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, Command: CommandTrait<N>> FromBytes for ConstructorCore<N, Command> {
+    /// Reads the constructor from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the finalize logic.
+        let finalize = FinalizeCore::read_le(&mut reader)?;
+        // Return the constructor.
+        Self::new(finalize).map_err(error)
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> ToBytes for ConstructorCore<N, Command> {
+    /// Writes the constructor to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the finalize logic.
+        self.finalize.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Constructor;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_constructor_bytes() -> Result<()> {
+        let constructor_string = r"
+constructor:
+    input r0 as address.public;
+    set r0 into admin[0u8];
+    set 1000u64 into supply[r0];";
+
+        let expected = Constructor::<CurrentNetwork>::from_str(constructor_string)?;
+        let expected_bytes = expected.to_bytes_le()?;
+
+        let candidate = Constructor::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        Ok(())
+    }
+}
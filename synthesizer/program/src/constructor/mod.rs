@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+
+use crate::{finalize::FinalizeCore, traits::CommandTrait};
+use console::{
+    network::prelude::*,
+    program::{FinalizeType, Identifier, LiteralType, PlaintextType},
+};
+
+/// A constructor is a finalize scope that is executed exactly once, when the program is first deployed.
+/// It may declare a single `address.public` input, which is assigned the address of the deployer.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ConstructorCore<N: Network, Command: CommandTrait<N>> {
+    /// The finalize logic of the constructor.
+    finalize: FinalizeCore<N, Command>,
+}
+
+impl<N: Network, Command: CommandTrait<N>> ConstructorCore<N, Command> {
+    /// Initializes a new constructor from the given finalize logic.
+    ///
+    /// # Errors
+    /// This method will halt if the finalize logic is not named `constructor`.
+    /// This method will halt if the finalize logic has more than one input.
+    /// This method will halt if the input is not of type `address.public`.
+    pub fn new(finalize: FinalizeCore<N, Command>) -> Result<Self> {
+        // Ensure the finalize logic is named `constructor`.
+        ensure!(*finalize.name() == Self::name()?, "The constructor scope must be named '{}'", Self::type_name());
+        // Ensure there is at most one input.
+        ensure!(finalize.inputs().len() <= 1, "A constructor can only take the deployer address as input");
+        // Ensure the input (if any) is the deployer address.
+        if let Some(input) = finalize.inputs().first() {
+            let expected = FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Address));
+            ensure!(
+                input.finalize_type() == &expected,
+                "The constructor input '{}' must be of type 'address.public'",
+                input.register()
+            );
+        }
+        Ok(Self { finalize })
+    }
+
+    /// Returns the name of the constructor scope.
+    pub fn name() -> Result<Identifier<N>> {
+        Identifier::from_str(Self::type_name())
+    }
+
+    /// Returns the finalize logic of the constructor.
+    pub const fn finalize_logic(&self) -> &FinalizeCore<N, Command> {
+        &self.finalize
+    }
+
+    /// Returns the constructor commands.
+    pub fn commands(&self) -> &[Command] {
+        self.finalize.commands()
+    }
+
+    /// Returns `true` if the constructor takes the deployer address as input.
+    pub fn has_deployer_input(&self) -> bool {
+        !self.finalize.inputs().is_empty()
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> TypeName for ConstructorCore<N, Command> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "constructor"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Constructor, Finalize};
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_constructor_new() {
+        // Ensure a constructor without inputs is valid.
+        let finalize = Finalize::<CurrentNetwork>::from_str("finalize constructor:\n    add 1u8 1u8 into r0;").unwrap();
+        assert!(Constructor::new(finalize).is_ok());
+
+        // Ensure a constructor with the deployer address is valid.
+        let finalize = Finalize::<CurrentNetwork>::from_str(
+            "finalize constructor:\n    input r0 as address.public;\n    is.eq r0 r0 into r1;",
+        )
+        .unwrap();
+        assert!(Constructor::new(finalize).is_ok());
+
+        // Ensure a constructor with a different name is invalid.
+        let finalize = Finalize::<CurrentNetwork>::from_str("finalize foo:\n    add 1u8 1u8 into r0;").unwrap();
+        assert!(Constructor::new(finalize).is_err());
+
+        // Ensure a constructor with a non-address input is invalid.
+        let finalize = Finalize::<CurrentNetwork>::from_str(
+            "finalize constructor:\n    input r0 as field.public;\n    add r0 r0 into r1;",
+        )
+        .unwrap();
+        assert!(Constructor::new(finalize).is_err());
+
+        // Ensure a constructor with more than one input is invalid.
+        let finalize = Finalize::<CurrentNetwork>::from_str(
+            r"
+finalize constructor:
+    input r0 as address.public;
+    input r1 as address.public;
+    is.eq r0 r1 into r2;",
+        )
+        .unwrap();
+        assert!(Constructor::new(finalize).is_err());
+    }
+}
@@ -31,6 +31,15 @@ impl<N: Network, Command: CommandTrait<N>> Parser for FinalizeCore<N, Command> {
         // Parse the colon ':' keyword from the string.
         let (string, _) = tag(":")(string)?;
 
+        // Parse the inputs and commands from the string.
+        Self::parse_body(string, name)
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
+    /// Parses the inputs and commands of a finalize scope with the given name.
+    /// Note: The string is expected to begin right after the header of the scope.
+    pub(crate) fn parse_body(string: &str, name: Identifier<N>) -> ParserResult<Self> {
         // Parse the inputs from the string.
         let (string, inputs) = many0(Input::parse)(string)?;
         // Parse the commands from the string.
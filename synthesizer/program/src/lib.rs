@@ -20,10 +20,14 @@ pub type Program<N> = crate::ProgramCore<N, Instruction<N>, Command<N>>;
 pub type Function<N> = crate::FunctionCore<N, Instruction<N>, Command<N>>;
 pub type Finalize<N> = crate::FinalizeCore<N, Command<N>>;
 pub type Closure<N> = crate::ClosureCore<N, Instruction<N>>;
+pub type Constructor<N> = crate::ConstructorCore<N, Command<N>>;
 
 mod closure;
 pub use closure::*;
 
+mod constructor;
+pub use constructor::*;
+
 pub mod finalize;
 pub use finalize::*;
 
@@ -101,6 +105,8 @@ enum ProgramDefinition {
     Closure,
     /// A program function.
     Function,
+    /// A program constructor.
+    Constructor,
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -121,6 +127,8 @@ pub struct ProgramCore<N: Network, Instruction: InstructionTrait<N>, Command: Co
     closures: IndexMap<Identifier<N>, ClosureCore<N, Instruction>>,
     /// A map of the declared functions for the program.
     functions: IndexMap<Identifier<N>, FunctionCore<N, Instruction, Command>>,
+    /// The optional constructor for the program.
+    constructor: Option<ConstructorCore<N, Command>>,
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -139,6 +147,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
             records: IndexMap::new(),
             closures: IndexMap::new(),
             functions: IndexMap::new(),
+            constructor: None,
         })
     }
 
@@ -183,6 +192,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         &self.functions
     }
 
+    /// Returns the constructor of the program, if one is defined.
+    pub const fn constructor(&self) -> Option<&ConstructorCore<N, Command>> {
+        self.constructor.as_ref()
+    }
+
     /// Returns `true` if the program contains an import with the given program ID.
     pub fn contains_import(&self, id: &ProgramID<N>) -> bool {
         self.imports.contains_key(id)
@@ -379,7 +393,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
             ensure!(!Self::is_reserved_keyword(identifier), "'{identifier}' is a reserved keyword.");
             // Ensure the member type is already defined in the program.
             match plaintext_type {
-                PlaintextType::Literal(_) => continue,
+                PlaintextType::Literal(_) | PlaintextType::Optional(_) => continue,
                 PlaintextType::Struct(member_identifier) => {
                     // Ensure the member struct name exists in the program.
                     if !self.structs.contains_key(member_identifier) {
@@ -434,7 +448,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
             ensure!(!Self::is_reserved_keyword(identifier), "'{identifier}' is a reserved keyword.");
             // Ensure the member type is already defined in the program.
             match entry_type.plaintext_type() {
-                PlaintextType::Literal(_) => continue,
+                PlaintextType::Literal(_) | PlaintextType::Optional(_) => continue,
                 PlaintextType::Struct(identifier) => {
                     if !self.structs.contains_key(identifier) {
                         bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
@@ -552,6 +566,27 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         }
         Ok(())
     }
+
+    /// Adds the constructor to the program.
+    ///
+    /// # Errors
+    /// This method will halt if a constructor was previously added.
+    #[inline]
+    fn add_constructor(&mut self, constructor: ConstructorCore<N, Command>) -> Result<()> {
+        // Retrieve the constructor name.
+        let constructor_name = ConstructorCore::<N, Command>::name()?;
+
+        // Ensure the program does not already have a constructor.
+        ensure!(self.constructor.is_none(), "Program '{}' already has a constructor.", self.id);
+
+        // Add the constructor name to the identifiers.
+        if self.identifiers.insert(constructor_name, ProgramDefinition::Constructor).is_some() {
+            bail!("'{constructor_name}' already exists in the program.")
+        }
+        // Add the constructor to the program.
+        self.constructor = Some(constructor);
+        Ok(())
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -606,6 +641,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         "value",
         "async",
         "finalize",
+        "constructor",
         // Reserved (catch all)
         "global",
         "block",
@@ -753,6 +789,56 @@ function compute:
         Ok(())
     }
 
+    #[test]
+    fn test_program_constructor() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+mapping admin:
+    key as u8.public;
+    value as address.public;
+
+constructor:
+    input r0 as address.public;
+    set r0 into admin[0u8];
+
+function noop:
+    input r0 as u8.public;
+    output r0 as u8.public;",
+        )?;
+        // Ensure the constructor was added.
+        let constructor = program.constructor().unwrap();
+        assert!(constructor.has_deployer_input());
+        assert_eq!(1, constructor.commands().len());
+
+        // Ensure the program round-trips through its string and byte representations.
+        assert_eq!(program, Program::from_str(&program.to_string())?);
+        assert_eq!(program, Program::from_bytes_le(&program.to_bytes_le()?)?);
+
+        // Ensure a program cannot define more than one constructor.
+        let result = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+mapping admin:
+    key as u8.public;
+    value as address.public;
+
+constructor:
+    input r0 as address.public;
+    set r0 into admin[0u8];
+
+constructor:
+    input r0 as address.public;
+    set r0 into admin[1u8];",
+        );
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_import() -> Result<()> {
         // Initialize a new program.
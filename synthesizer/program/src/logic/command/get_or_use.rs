@@ -20,7 +20,7 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{Register, Value},
+    program::{Plaintext, Register, Value},
 };
 
 /// A get command that uses the provided default in case of failure, e.g. `get.or_use accounts[r0] r1 into r2;`.
@@ -114,20 +114,27 @@ impl<N: Network> GetOrUse<N> {
         };
 
         // Ensure the mapping exists in storage.
-        if !store.contains_mapping_confirmed(&program_id, &mapping_name)? {
+        if !store.contains_mapping_speculative(&program_id, &mapping_name)? {
             bail!("Mapping '{program_id}/{mapping_name}' does not exist in storage");
         }
 
         // Load the operand as a plaintext.
         let key = registers.load_plaintext(stack, &self.key)?;
 
+        // Load the default value as a plaintext.
+        let default = registers.load_plaintext(stack, &self.default)?;
+
         // Retrieve the value from storage as a literal.
         let value = match store.get_value_speculative(program_id, mapping_name, &key)? {
+            // If the default value is an optional, then wrap the stored literal in `some`.
+            Some(Value::Plaintext(Plaintext::Literal(literal, _))) if matches!(default, Plaintext::Optional(..)) => {
+                Value::Plaintext(Plaintext::some(literal)?)
+            }
             Some(Value::Plaintext(plaintext)) => Value::Plaintext(plaintext),
             Some(Value::Record(..)) => bail!("Cannot 'get.or_use' a 'record'"),
             Some(Value::Future(..)) => bail!("Cannot 'get.or_use' a 'future'"),
             // If a key does not exist, then use the default value.
-            None => Value::Plaintext(registers.load_plaintext(stack, &self.default)?),
+            None => Value::Plaintext(default),
         };
 
         // Assign the value to the destination register.
@@ -21,6 +21,9 @@ pub use branch::*;
 mod contains;
 pub use contains::*;
 
+mod emit;
+pub use emit::*;
+
 mod get;
 pub use get::*;
 
@@ -53,6 +56,8 @@ use crate::{
     FinalizeOperation,
     FinalizeRegistersState,
     Instruction,
+    Opcode,
+    Operand,
 };
 use console::{
     network::prelude::*,
@@ -84,6 +89,8 @@ pub enum Command<N: Network> {
     BranchNeq(BranchNeq<N>),
     /// Indicates a position to which the program can branch to.
     Position(Position<N>),
+    /// Emits the `operand` as an event.
+    Emit(Emit<N>),
 }
 
 impl<N: Network> CommandTrait<N> for Command<N> {
@@ -101,7 +108,8 @@ impl<N: Network> CommandTrait<N> for Command<N> {
             | Command::BranchNeq(_)
             | Command::Position(_)
             | Command::Remove(_)
-            | Command::Set(_) => vec![],
+            | Command::Set(_)
+            | Command::Emit(_) => vec![],
         }
     }
 
@@ -129,7 +137,7 @@ impl<N: Network> CommandTrait<N> for Command<N> {
     /// Returns `true` if the command is a call instruction.
     #[inline]
     fn is_call(&self) -> bool {
-        matches!(self, Command::Instruction(Instruction::Call(_)))
+        matches!(self, Command::Instruction(Instruction::Call(_)) | Command::Instruction(Instruction::CallDynamic(_)))
     }
 
     /// Returns `true` if the command is a cast to record instruction.
@@ -145,6 +153,44 @@ impl<N: Network> CommandTrait<N> for Command<N> {
 }
 
 impl<N: Network> Command<N> {
+    /// Returns the opcode of the command.
+    #[inline]
+    pub const fn opcode(&self) -> Opcode {
+        match self {
+            Command::Instruction(instruction) => instruction.opcode(),
+            Command::Await(_) => Await::<N>::opcode(),
+            Command::Contains(_) => Contains::<N>::opcode(),
+            Command::Get(_) => Get::<N>::opcode(),
+            Command::GetOrUse(_) => GetOrUse::<N>::opcode(),
+            Command::RandChaCha(_) => RandChaCha::<N>::opcode(),
+            Command::Remove(_) => Remove::<N>::opcode(),
+            Command::Set(_) => Set::<N>::opcode(),
+            Command::BranchEq(_) => BranchEq::<N>::opcode(),
+            Command::BranchNeq(_) => BranchNeq::<N>::opcode(),
+            Command::Position(_) => Position::<N>::opcode(),
+            Command::Emit(_) => Emit::<N>::opcode(),
+        }
+    }
+
+    /// Returns the operands of the command.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        match self {
+            Command::Instruction(instruction) => instruction.operands().to_vec(),
+            Command::Await(await_) => vec![Operand::Register(await_.register().clone())],
+            Command::Contains(contains) => contains.operands(),
+            Command::Get(get) => get.operands(),
+            Command::GetOrUse(get_or_use) => get_or_use.operands(),
+            Command::RandChaCha(rand_chacha) => rand_chacha.operands(),
+            Command::Remove(remove) => remove.operands(),
+            Command::Set(set) => set.operands(),
+            Command::BranchEq(branch_eq) => vec![branch_eq.first().clone(), branch_eq.second().clone()],
+            Command::BranchNeq(branch_neq) => vec![branch_neq.first().clone(), branch_neq.second().clone()],
+            Command::Position(_) => vec![],
+            Command::Emit(emit) => emit.operands(),
+        }
+    }
+
     /// Finalizes the command.
     #[inline]
     pub fn finalize(
@@ -176,6 +222,8 @@ impl<N: Network> Command<N> {
             }
             // Finalize the `position` command, and return no finalize operation.
             Command::Position(position) => position.finalize().map(|_| None),
+            // `emit` commands are processed by the caller of this method.
+            Command::Emit(_) => bail!("`emit` commands cannot be finalized directly."),
         }
     }
 }
@@ -208,8 +256,10 @@ impl<N: Network> FromBytes for Command<N> {
             9 => Ok(Self::BranchNeq(BranchNeq::read_le(&mut reader)?)),
             // Read the `position` command.
             10 => Ok(Self::Position(Position::read_le(&mut reader)?)),
+            // Read the `emit` command.
+            11 => Ok(Self::Emit(Emit::read_le(&mut reader)?)),
             // Invalid variant.
-            11.. => Err(error(format!("Invalid command variant: {variant}"))),
+            12.. => Err(error(format!("Invalid command variant: {variant}"))),
         }
     }
 }
@@ -284,6 +334,12 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the position command.
                 position.write_le(&mut writer)
             }
+            Self::Emit(emit) => {
+                // Write the variant.
+                11u8.write_le(&mut writer)?;
+                // Write the `emit` command.
+                emit.write_le(&mut writer)
+            }
         }
     }
 }
@@ -297,6 +353,7 @@ impl<N: Network> Parser for Command<N> {
         alt((
             map(Await::parse, |await_| Self::Await(await_)),
             map(Contains::parse, |contains| Self::Contains(contains)),
+            map(Emit::parse, |emit| Self::Emit(emit)),
             map(GetOrUse::parse, |get_or_use| Self::GetOrUse(get_or_use)),
             map(Get::parse, |get| Self::Get(get)),
             map(RandChaCha::parse, |rand_chacha| Self::RandChaCha(rand_chacha)),
@@ -350,6 +407,7 @@ impl<N: Network> Display for Command<N> {
             Self::BranchEq(branch_eq) => Display::fmt(branch_eq, f),
             Self::BranchNeq(branch_neq) => Display::fmt(branch_neq, f),
             Self::Position(position) => Display::fmt(position, f),
+            Self::Emit(emit) => Display::fmt(emit, f),
         }
     }
 }
@@ -389,6 +447,12 @@ mod tests {
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
 
+        // Emit
+        let expected = "emit r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
         // Get
         let expected = "get object[r0] into r1;";
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
@@ -466,6 +530,12 @@ mod tests {
         assert_eq!(Command::Contains(Contains::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
 
+        // Emit
+        let expected = "emit r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Emit(Emit::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
         // Get
         let expected = "get object[r0] into r1;";
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
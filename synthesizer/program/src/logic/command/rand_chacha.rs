@@ -21,7 +21,7 @@ use crate::{
 use console::{
     network::prelude::*,
     program::{Literal, LiteralType, Plaintext, Register, Value},
-    types::{Address, Boolean, Field, Group, Scalar, I128, I16, I32, I64, I8, U128, U16, U32, U64, U8},
+    types::{Address, Boolean, Field, Group, Scalar, I128, I16, I256, I32, I64, I8, U128, U16, U256, U32, U64, U8},
 };
 
 use rand::SeedableRng;
@@ -121,11 +121,13 @@ impl<N: Network> RandChaCha<N> {
             LiteralType::I32 => Literal::I32(I32::rand(&mut rng)),
             LiteralType::I64 => Literal::I64(I64::rand(&mut rng)),
             LiteralType::I128 => Literal::I128(I128::rand(&mut rng)),
+            LiteralType::I256 => Literal::I256(I256::rand(&mut rng)),
             LiteralType::U8 => Literal::U8(U8::rand(&mut rng)),
             LiteralType::U16 => Literal::U16(U16::rand(&mut rng)),
             LiteralType::U32 => Literal::U32(U32::rand(&mut rng)),
             LiteralType::U64 => Literal::U64(U64::rand(&mut rng)),
             LiteralType::U128 => Literal::U128(U128::rand(&mut rng)),
+            LiteralType::U256 => Literal::U256(U256::rand(&mut rng)),
             LiteralType::Scalar => Literal::Scalar(Scalar::rand(&mut rng)),
             LiteralType::Signature => bail!("Cannot 'rand.chacha' into a 'signature'"),
             LiteralType::String => bail!("Cannot 'rand.chacha' into a 'string'"),
@@ -303,11 +305,13 @@ mod tests {
             LiteralType::I32,
             LiteralType::I64,
             LiteralType::I128,
+            LiteralType::I256,
             LiteralType::U8,
             LiteralType::U16,
             LiteralType::U32,
             LiteralType::U64,
             LiteralType::U128,
+            LiteralType::U256,
             LiteralType::Scalar,
         ]
     }
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod bytes;
+mod serialize;
+mod string;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID},
+};
+
+/// An event emitted by the `emit` command in a finalize scope.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Event<N: Network> {
+    /// The program ID of the emitting finalize scope.
+    program_id: ProgramID<N>,
+    /// The function name of the emitting finalize scope.
+    function_name: Identifier<N>,
+    /// The payload of the event.
+    payload: Plaintext<N>,
+}
+
+impl<N: Network> Event<N> {
+    /// Initializes a new event.
+    pub const fn new(program_id: ProgramID<N>, function_name: Identifier<N>, payload: Plaintext<N>) -> Self {
+        Self { program_id, function_name, payload }
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the payload.
+    pub const fn payload(&self) -> &Plaintext<N> {
+        &self.payload
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Samples a list of events.
+    pub(crate) fn sample_events() -> Vec<Event<CurrentNetwork>> {
+        let program_id = ProgramID::from_str("auction.aleo").unwrap();
+        let function_name = Identifier::from_str("bid").unwrap();
+
+        vec![
+            Event::new(program_id, function_name, Plaintext::from_str("5u64").unwrap()),
+            Event::new(program_id, function_name, Plaintext::from_str("{ bid: 1u32, amount: 5u64 }").unwrap()),
+            Event::new(program_id, function_name, Plaintext::from_str("[1u8, 2u8, 3u8]").unwrap()),
+        ]
+    }
+}
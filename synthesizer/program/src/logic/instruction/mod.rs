@@ -83,6 +83,8 @@ pub enum Instruction<N: Network> {
     Async(Async<N>),
     /// Calls a closure or function on the operands.
     Call(Call<N>),
+    /// Calls a function of a program given by an operand, against a declared signature.
+    CallDynamic(CallDynamic<N>),
     /// Casts the operands into the declared type.
     Cast(Cast<N>),
     /// Casts the operands into the declared type, with lossy truncation if applicable.
@@ -99,12 +101,20 @@ pub enum Instruction<N: Network> {
     CommitPED64(CommitPED64<N>),
     /// Performs a Pedersen commitment on up to a 128-bit input.
     CommitPED128(CommitPED128<N>),
+    /// Concatenates `first` and `second`, storing the outcome in `destination`.
+    Concat(Concat<N>),
+    /// Deserializes the little-endian bits in `first` into the destination type, storing the outcome in `destination`.
+    DeserializeBits(DeserializeBits<N>),
+    /// Deserializes the little-endian bits packed in the bytes of `first`, storing the outcome in `destination`.
+    DeserializeBytes(DeserializeBytes<N>),
     /// Divides `first` by `second`, storing the outcome in `destination`.
     Div(Div<N>),
     /// Divides `first` by `second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
     DivWrapped(DivWrapped<N>),
     /// Doubles `first`, storing the outcome in `destination`.
     Double(Double<N>),
+    /// Computes whether the secp256k1 ECDSA `signature` is valid for the given `public_key` and message `digest`.
+    ECDSAVerify(ECDSAVerify<N>),
     /// Computes whether `first` is greater than `second` as a boolean, storing the outcome in `destination`.
     GreaterThan(GreaterThan<N>),
     /// Computes whether `first` is greater than or equal to `second` as a boolean, storing the outcome in `destination`.
@@ -117,6 +127,8 @@ pub enum Instruction<N: Network> {
     HashBHP768(HashBHP768<N>),
     /// Performs a BHP hash on inputs of 1024-bit chunks.
     HashBHP1024(HashBHP1024<N>),
+    /// Performs a BLAKE2s hash, outputting 256 bits.
+    HashBlake2s(HashBlake2s<N>),
     /// Performs a Keccak hash, outputting 256 bits.
     HashKeccak256(HashKeccak256<N>),
     /// Performs a Keccak hash, outputting 384 bits.
@@ -133,6 +145,14 @@ pub enum Instruction<N: Network> {
     HashPSD4(HashPSD4<N>),
     /// Performs a Poseidon hash with an input rate of 8.
     HashPSD8(HashPSD8<N>),
+    /// Performs a Poseidon2 hash with an input rate of 2.
+    HashPoseidon2_2(HashPoseidon2_2<N>),
+    /// Performs a Poseidon2 hash with an input rate of 4.
+    HashPoseidon2_4(HashPoseidon2_4<N>),
+    /// Performs a Poseidon2 hash with an input rate of 8.
+    HashPoseidon2_8(HashPoseidon2_8<N>),
+    /// Performs a SHA-2 hash, outputting 256 bits.
+    HashSha256(HashSha256<N>),
     /// Performs a SHA-3 hash, outputting 256 bits.
     HashSha3_256(HashSha3_256<N>),
     /// Performs a SHA-3 hash, outputting 384 bits.
@@ -151,10 +171,16 @@ pub enum Instruction<N: Network> {
     IsEq(IsEq<N>),
     /// Computes whether `first` does **not** equals `second` as a boolean, storing the outcome in `destination`.
     IsNeq(IsNeq<N>),
+    /// Computes the length of `first` in bytes, storing the outcome in `destination`.
+    Len(Len<N>),
     /// Computes whether `first` is less than `second` as a boolean, storing the outcome in `destination`.
     LessThan(LessThan<N>),
     /// Computes whether `first` is less than or equal to `second` as a boolean, storing the outcome in `destination`.
     LessThanOrEqual(LessThanOrEqual<N>),
+    /// Computes whether the Merkle `path` is valid for the `root` and `leaf` in a BHP Merkle tree, storing the outcome in `destination`.
+    MerkleVerifyBHP(MerkleVerifyBHP<N>),
+    /// Computes whether the Merkle `path` is valid for the `root` and `leaf` in a Poseidon Merkle tree, storing the outcome in `destination`.
+    MerkleVerifyPSD(MerkleVerifyPSD<N>),
     /// Computes `first` mod `second`, storing the outcome in `destination`.
     Modulo(Modulo<N>),
     /// Multiplies `first` with `second`, storing the outcome in `destination`.
@@ -169,6 +195,14 @@ pub enum Instruction<N: Network> {
     Nor(Nor<N>),
     /// Flips each bit in the representation of `first`, storing the outcome in `destination`.
     Not(Not<N>),
+    /// Computes whether the optional `first` holds a value, storing the outcome in `destination`.
+    OptionIsSome(OptionIsSome<N>),
+    /// Constructs an empty optional of the given literal type, storing the outcome in `destination`.
+    OptionNone(OptionNone<N>),
+    /// Wraps the literal `first` into a present optional, storing the outcome in `destination`.
+    OptionSome(OptionSome<N>),
+    /// Unwraps the literal held by the optional `first`, storing the outcome in `destination`.
+    OptionUnwrap(OptionUnwrap<N>),
     /// Performs a bitwise `or` on `first` and `second`, storing the outcome in `destination`.
     Or(Or<N>),
     /// Raises `first` to the power of `second`, storing the outcome in `destination`.
@@ -179,6 +213,12 @@ pub enum Instruction<N: Network> {
     Rem(Rem<N>),
     /// Divides `first` by `second`, wrapping around at the boundary of the type, storing the remainder in `destination`.
     RemWrapped(RemWrapped<N>),
+    /// Calls a closure for a fixed number of iterations, threading its outputs into its next inputs.
+    Repeat(Repeat<N>),
+    /// Serializes `first` into its little-endian bits, storing the outcome in `destination`.
+    SerializeBits(SerializeBits<N>),
+    /// Serializes `first` into its little-endian bits packed into bytes, storing the outcome in `destination`.
+    SerializeBytes(SerializeBytes<N>),
     /// Shifts `first` left by `second` bits, storing the outcome in `destination`.
     Shl(Shl<N>),
     /// Shifts `first` left by `second` bits, wrapping around at the boundary of the type, storing the outcome in `destination`.
@@ -189,6 +229,8 @@ pub enum Instruction<N: Network> {
     ShrWrapped(ShrWrapped<N>),
     /// Computes whether `signature` is valid for the given `address` and `message`.
     SignVerify(SignVerify<N>),
+    /// Selects the bytes of `first` from index `second` (inclusive) to index `third` (exclusive), storing the outcome in `destination`.
+    Slice(Slice<N>),
     /// Squares 'first', storing the outcome in `destination`.
     Square(Square<N>),
     /// Compute the square root of 'first', storing the outcome in `destination`.
@@ -241,6 +283,7 @@ macro_rules! instruction {
             AssertNeq,
             Async,
             Call,
+            CallDynamic,
             Cast,
             CastLossy,
             CommitBHP256,
@@ -249,15 +292,20 @@ macro_rules! instruction {
             CommitBHP1024,
             CommitPED64,
             CommitPED128,
+            Concat,
+            DeserializeBits,
+            DeserializeBytes,
             Div,
             DivWrapped,
             Double,
+            ECDSAVerify,
             GreaterThan,
             GreaterThanOrEqual,
             HashBHP256,
             HashBHP512,
             HashBHP768,
             HashBHP1024,
+            HashBlake2s,
             HashKeccak256,
             HashKeccak384,
             HashKeccak512,
@@ -266,6 +314,10 @@ macro_rules! instruction {
             HashPSD2,
             HashPSD4,
             HashPSD8,
+            HashPoseidon2_2,
+            HashPoseidon2_4,
+            HashPoseidon2_8,
+            HashSha256,
             HashSha3_256,
             HashSha3_384,
             HashSha3_512,
@@ -275,8 +327,11 @@ macro_rules! instruction {
             Inv,
             IsEq,
             IsNeq,
+            Len,
             LessThan,
             LessThanOrEqual,
+            MerkleVerifyBHP,
+            MerkleVerifyPSD,
             Modulo,
             Mul,
             MulWrapped,
@@ -284,16 +339,24 @@ macro_rules! instruction {
             Neg,
             Nor,
             Not,
+            OptionIsSome,
+            OptionNone,
+            OptionSome,
+            OptionUnwrap,
             Or,
             Pow,
             PowWrapped,
             Rem,
             RemWrapped,
+            Repeat,
+            SerializeBits,
+            SerializeBytes,
             Shl,
             ShlWrapped,
             Shr,
             ShrWrapped,
             SignVerify,
+            Slice,
             Square,
             SquareRoot,
             Sub,
@@ -469,7 +532,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            89,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
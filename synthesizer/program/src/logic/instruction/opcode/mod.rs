@@ -23,6 +23,8 @@ pub enum Opcode {
     Async,
     /// The opcode is for a call operation (i.e. `call`).
     Call,
+    /// The opcode is for a dynamic call operation (i.e. `call.dynamic`).
+    CallDynamic,
     /// The opcode is for a cast operation (i.e. `cast`).
     Cast(&'static str),
     /// The opcode is for a finalize command (i.e. `increment`).
@@ -35,8 +37,16 @@ pub enum Opcode {
     Is(&'static str),
     /// The opcode is for a literal operation (i.e. `add`).
     Literal(&'static str),
+    /// The opcode is for Merkle path verification (i.e. `merkle.verify.bhp`).
+    Merkle(&'static str),
+    /// The opcode is for an optional operation (i.e. `option.some`).
+    Option(&'static str),
+    /// The opcode is for a repeat operation (i.e. `repeat`).
+    Repeat,
+    /// The opcode is for a serialization operation (i.e. `serialize.bits`).
+    Serialize(&'static str),
     /// The opcode is for signature verification (i.e. `sign.verify`).
-    Sign,
+    Sign(&'static str),
 }
 
 impl Deref for Opcode {
@@ -48,13 +58,18 @@ impl Deref for Opcode {
             Opcode::Assert(opcode) => opcode,
             Opcode::Async => &"async",
             Opcode::Call => &"call",
+            Opcode::CallDynamic => &"call.dynamic",
             Opcode::Cast(opcode) => opcode,
             Opcode::Command(opcode) => opcode,
             Opcode::Commit(opcode) => opcode,
             Opcode::Hash(opcode) => opcode,
             Opcode::Is(opcode) => opcode,
             Opcode::Literal(opcode) => opcode,
-            Opcode::Sign => &"sign.verify",
+            Opcode::Merkle(opcode) => opcode,
+            Opcode::Option(opcode) => opcode,
+            Opcode::Repeat => &"repeat",
+            Opcode::Serialize(opcode) => opcode,
+            Opcode::Sign(opcode) => opcode,
         }
     }
 }
@@ -73,13 +88,18 @@ impl Display for Opcode {
             Self::Assert(opcode) => write!(f, "{opcode}"),
             Self::Async => write!(f, "{}", self.deref()),
             Self::Call => write!(f, "{}", self.deref()),
+            Self::CallDynamic => write!(f, "{}", self.deref()),
             Self::Cast(opcode) => write!(f, "{opcode}"),
             Self::Command(opcode) => write!(f, "{opcode}"),
             Self::Commit(opcode) => write!(f, "{opcode}"),
             Self::Hash(opcode) => write!(f, "{opcode}"),
             Self::Is(opcode) => write!(f, "{opcode}"),
             Self::Literal(opcode) => write!(f, "{opcode}"),
-            Self::Sign => write!(f, "{}", self.deref()),
+            Self::Merkle(opcode) => write!(f, "{opcode}"),
+            Self::Option(opcode) => write!(f, "{opcode}"),
+            Self::Repeat => write!(f, "{}", self.deref()),
+            Self::Serialize(opcode) => write!(f, "{opcode}"),
+            Self::Sign(opcode) => write!(f, "{opcode}"),
         }
     }
 }
@@ -0,0 +1,434 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{LiteralType, PlaintextType, Register, RegisterType, ValueType},
+};
+
+/// Calls a function whose program and name are loaded from the operands, against a declared signature.
+/// i.e. `call.dynamic r0 r1 r2 r3 into r4 as (address.public, u64.public) -> (u64.public);`
+///
+/// The first operand is the program address, and the second operand is the function name as a field element.
+/// The remaining operands are the inputs to the function, and must match the declared input types.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CallDynamic<N: Network> {
+    /// The operands, as `[program, function name, inputs..]`.
+    operands: Vec<Operand<N>>,
+    /// The destination registers.
+    destinations: Vec<Register<N>>,
+    /// The declared input types of the function.
+    input_types: Vec<ValueType<N>>,
+    /// The declared output types of the function.
+    return_types: Vec<ValueType<N>>,
+}
+
+impl<N: Network> CallDynamic<N> {
+    /// Initializes a new `call.dynamic` instruction.
+    pub fn new(
+        operands: Vec<Operand<N>>,
+        destinations: Vec<Register<N>>,
+        input_types: Vec<ValueType<N>>,
+        return_types: Vec<ValueType<N>>,
+    ) -> Result<Self> {
+        // Ensure the number of inputs is within the bounds.
+        ensure!(input_types.len() <= N::MAX_INPUTS, "The number of inputs must be <= {}", N::MAX_INPUTS);
+        // Ensure the number of outputs is within the bounds.
+        ensure!(return_types.len() <= N::MAX_OUTPUTS, "The number of outputs must be <= {}", N::MAX_OUTPUTS);
+        // Ensure the operands consist of the program, the function name, and the inputs.
+        ensure!(
+            operands.len() == input_types.len() + 2,
+            "Expected {} operands for 'call.dynamic', found {}",
+            input_types.len() + 2,
+            operands.len()
+        );
+        // Ensure there is one destination register for each output.
+        ensure!(
+            destinations.len() == return_types.len(),
+            "Expected {} destinations for 'call.dynamic', found {}",
+            return_types.len(),
+            destinations.len()
+        );
+        // Ensure the declared types are plaintext types.
+        for value_type in input_types.iter().chain(&return_types) {
+            match value_type {
+                ValueType::Constant(..) | ValueType::Public(..) | ValueType::Private(..) => (),
+                _ => bail!("A dynamic call only supports plaintext inputs and outputs, found '{value_type}'"),
+            }
+        }
+        Ok(Self { operands, destinations, input_types, return_types })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::CallDynamic
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+
+    /// Returns the operand containing the program address.
+    #[inline]
+    pub fn program(&self) -> &Operand<N> {
+        &self.operands[0]
+    }
+
+    /// Returns the operand containing the function name.
+    #[inline]
+    pub fn function_name(&self) -> &Operand<N> {
+        &self.operands[1]
+    }
+
+    /// Returns the operands containing the function inputs.
+    #[inline]
+    pub fn inputs(&self) -> &[Operand<N>] {
+        &self.operands[2..]
+    }
+
+    /// Returns the destination registers.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        self.destinations.clone()
+    }
+
+    /// Returns the declared input types of the function.
+    #[inline]
+    pub fn input_types(&self) -> &[ValueType<N>] {
+        &self.input_types
+    }
+
+    /// Returns the declared output types of the function.
+    #[inline]
+    pub fn return_types(&self) -> &[ValueType<N>] {
+        &self.return_types
+    }
+}
+
+impl<N: Network> CallDynamic<N> {
+    /// Evaluates the instruction.
+    pub fn evaluate(&self, _stack: &impl StackProgram<N>, _registers: &mut impl RegistersLoad<N>) -> Result<()> {
+        bail!("Forbidden operation: Evaluate cannot invoke a 'call.dynamic' directly. Use 'call.dynamic' in 'Stack' instead.")
+    }
+
+    /// Executes the instruction.
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        _stack: &impl StackProgram<N>,
+        _registers: &mut impl RegistersLoadCircuit<N, A>,
+    ) -> Result<()> {
+        bail!("Forbidden operation: Execute cannot invoke a 'call.dynamic' directly. Use 'call.dynamic' in 'Stack' instead.")
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut impl RegistersLoad<N>,
+    ) -> Result<()> {
+        bail!("Forbidden operation: Finalize cannot invoke a 'call.dynamic' directly. Use 'call.dynamic' in 'Stack' instead.")
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types matches the number of operands.
+        if input_types.len() != self.operands.len() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {}",
+                Self::opcode(),
+                self.operands.len(),
+                input_types.len()
+            )
+        }
+        // Ensure the program is an address.
+        if input_types[0] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)) {
+            bail!("Instruction '{}' expects the program to be an 'address', found '{}'", Self::opcode(), input_types[0])
+        }
+        // Ensure the function name is a field element.
+        if input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field)) {
+            bail!(
+                "Instruction '{}' expects the function name to be a 'field', found '{}'",
+                Self::opcode(),
+                input_types[1]
+            )
+        }
+        // Ensure the inputs match the declared input types.
+        for (input_type, expected) in input_types[2..].iter().zip_eq(&self.input_types) {
+            let expected = RegisterType::from(expected.clone());
+            if *input_type != expected {
+                bail!("Instruction '{}' expects an input of type '{expected}', found '{input_type}'", Self::opcode())
+            }
+        }
+        // Return the declared output types.
+        Ok(self.return_types.iter().cloned().map(RegisterType::from).collect())
+    }
+}
+
+impl<N: Network> Parser for CallDynamic<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        /// Parses a destination register from the string.
+        fn parse_destination<N: Network>(string: &str) -> ParserResult<Register<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the destination from the string.
+            Register::parse(string)
+        }
+
+        /// Parses a parenthesized, comma-separated list of value types from the string.
+        fn parse_value_types<N: Network>(string: &str) -> ParserResult<Vec<ValueType<N>>> {
+            // Parse the "(" from the string.
+            let (string, _) = tag("(")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the value types from the string.
+            let (string, value_types) = separated_list0(
+                pair(pair(Sanitizer::parse_whitespaces, tag(",")), Sanitizer::parse_whitespaces),
+                ValueType::parse,
+            )(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the ")" from the string.
+            let (string, _) = tag(")")(string)?;
+            Ok((string, value_types))
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = many1(complete(parse_operand))(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Optionally parse the "into" from the string.
+        let (string, destinations) = match opt(tag("into"))(string)? {
+            // If the "into" was not parsed, return the string and an empty vector of destinations.
+            (string, None) => (string, vec![]),
+            // If the "into" was parsed, parse the destinations from the string.
+            (string, Some(_)) => {
+                // Parse the destinations from the string.
+                let (string, destinations) = many1(complete(parse_destination))(string)?;
+                // Parse the whitespace from the string.
+                let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                // Return the string and the destinations.
+                (string, destinations)
+            }
+        };
+
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the input types from the string.
+        let (string, input_types) = parse_value_types(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "->" from the string.
+        let (string, _) = tag("->")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the output types from the string.
+        let (string, return_types) = parse_value_types(string)?;
+
+        // Construct the instruction.
+        match Self::new(operands, destinations, input_types, return_types) {
+            Ok(call) => Ok((string, call)),
+            Err(e) => map_res(fail, |_: ParserResult<Self>| Err(error(e.to_string())))(string),
+        }
+    }
+}
+
+impl<N: Network> FromStr for CallDynamic<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for CallDynamic<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for CallDynamic<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the operation.
+        write!(f, "{}", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+        if !self.destinations.is_empty() {
+            write!(f, " into")?;
+            self.destinations.iter().try_for_each(|destination| write!(f, " {destination}"))?;
+        }
+        write!(f, " as ({}) -> ({})", self.input_types.iter().join(", "), self.return_types.iter().join(", "))
+    }
+}
+
+impl<N: Network> FromBytes for CallDynamic<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of operands.
+        let num_operands = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of operands is within the bounds.
+        if num_operands > N::MAX_INPUTS + 2 {
+            return Err(error(format!("The number of operands must be <= {}", N::MAX_INPUTS + 2)));
+        }
+        // Read the operands.
+        let operands = (0..num_operands).map(|_| Operand::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
+        // Read the number of destination registers.
+        let num_destinations = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of destinations is within the bounds.
+        if num_destinations > N::MAX_OUTPUTS {
+            return Err(error(format!("The number of destinations must be <= {}", N::MAX_OUTPUTS)));
+        }
+        // Read the destination registers.
+        let destinations =
+            (0..num_destinations).map(|_| Register::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
+        // Read the number of input types.
+        let num_input_types = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of input types is within the bounds.
+        if num_input_types > N::MAX_INPUTS {
+            return Err(error(format!("The number of inputs must be <= {}", N::MAX_INPUTS)));
+        }
+        // Read the input types.
+        let input_types =
+            (0..num_input_types).map(|_| ValueType::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
+        // Read the number of output types.
+        let num_return_types = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of output types is within the bounds.
+        if num_return_types > N::MAX_OUTPUTS {
+            return Err(error(format!("The number of outputs must be <= {}", N::MAX_OUTPUTS)));
+        }
+        // Read the output types.
+        let return_types =
+            (0..num_return_types).map(|_| ValueType::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
+        // Return the operation.
+        Self::new(operands, destinations, input_types, return_types).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for CallDynamic<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the number of operands.
+        u8::try_from(self.operands.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the number of destination registers.
+        u8::try_from(self.destinations.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the destination registers.
+        self.destinations.iter().try_for_each(|destination| destination.write_le(&mut writer))?;
+        // Write the number of input types.
+        u8::try_from(self.input_types.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the input types.
+        self.input_types.iter().try_for_each(|input_type| input_type.write_le(&mut writer))?;
+        // Write the number of output types.
+        u8::try_from(self.return_types.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the output types.
+        self.return_types.iter().try_for_each(|return_type| return_type.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const TEST_CASES: &[&str] = &[
+        "call.dynamic r0 r1 as () -> ()",
+        "call.dynamic r0 r1 into r2 as () -> (u64.public)",
+        "call.dynamic token.aleo r0 r1 r2 into r3 as (address.public, u64.public) -> (u64.private)",
+        "call.dynamic r0 r1 r2 into r3 r4 as (field.constant) -> (boolean.public, [u8; 4u32].private)",
+    ];
+
+    #[test]
+    fn test_parse() {
+        let (string, call) = CallDynamic::<CurrentNetwork>::parse(
+            "call.dynamic r0 r1 r2 r3 into r4 as (u64.public, u64.private) -> (u64.public)",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(call.program(), &Operand::Register(Register::Locator(0)));
+        assert_eq!(call.function_name(), &Operand::Register(Register::Locator(1)));
+        assert_eq!(call.inputs(), [Operand::Register(Register::Locator(2)), Operand::Register(Register::Locator(3))]);
+        assert_eq!(call.destinations(), vec![Register::Locator(4)]);
+        assert_eq!(call.input_types().len(), 2);
+        assert_eq!(call.return_types().len(), 1);
+
+        // Ensure the number of inputs must match the declared input types.
+        assert!(CallDynamic::<CurrentNetwork>::parse("call.dynamic r0 r1 r2 as () -> ()").is_err());
+        // Ensure the number of destinations must match the declared output types.
+        assert!(CallDynamic::<CurrentNetwork>::parse("call.dynamic r0 r1 into r2 as () -> ()").is_err());
+        // Ensure records are not supported.
+        assert!(CallDynamic::<CurrentNetwork>::parse("call.dynamic r0 r1 r2 as (token.record) -> ()").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        for expected in TEST_CASES {
+            assert_eq!(CallDynamic::<CurrentNetwork>::from_str(expected).unwrap().to_string(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_bytes() {
+        for case in TEST_CASES {
+            let expected = CallDynamic::<CurrentNetwork>::from_str(case).unwrap();
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, CallDynamic::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
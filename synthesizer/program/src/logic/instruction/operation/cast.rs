@@ -45,7 +45,7 @@ use console::{
         Value,
         ValueType,
     },
-    types::Field,
+    types::{Field, StringType},
 };
 
 use indexmap::IndexMap;
@@ -242,6 +242,9 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                         1 => literal.cast_lossy(*literal_type)?,
                         2.. => unreachable!("Invalid cast variant"),
                     },
+                    Value::Plaintext(Plaintext::Array(elements, ..)) if VARIANT == CastVariant::Cast as u8 => {
+                        Self::cast_bytes_to_string(*literal_type, elements)?
+                    }
                     _ => bail!("Casting to a literal requires a literal"),
                 };
                 registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(value)))
@@ -252,6 +255,9 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
                 self.cast_to_array(stack, registers, array_type, inputs)
             }
+            CastType::Plaintext(PlaintextType::Optional(optional_type)) => {
+                bail!("Illegal operation: Cannot cast to the optional type '{optional_type}?'.")
+            }
             CastType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -387,6 +393,21 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                         1 => literal.cast_lossy(*literal_type)?,
                         2.. => unreachable!("Invalid cast variant"),
                     },
+                    circuit::Value::Plaintext(circuit::Plaintext::Array(elements, ..))
+                        if VARIANT == CastVariant::Cast as u8 =>
+                    {
+                        // Ensure the bytes are a valid string.
+                        Self::cast_bytes_to_string(*literal_type, &elements.eject_value())?;
+                        // Retrieve the bytes from the array.
+                        let bytes: Vec<_> = elements
+                            .iter()
+                            .map(|element| match element {
+                                circuit::Plaintext::Literal(circuit::Literal::U8(byte), ..) => Ok(byte.clone()),
+                                _ => bail!("Casting an array to a string requires an array of 'u8' elements"),
+                            })
+                            .try_collect()?;
+                        circuit::Literal::String(circuit::StringType::from_padded_bytes(&bytes))
+                    }
                     _ => bail!("Casting to a literal requires a literal"),
                 };
                 registers.store_circuit(
@@ -448,6 +469,23 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(struct_))
             }
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
+                // If the operand is a string, cast it into a byte array, padded with trailing zeros.
+                if let [circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::String(string), ..))] =
+                    inputs.as_slice()
+                {
+                    if Self::is_byte_array(array_type) {
+                        // Ensure the string fits within the array.
+                        string.eject_value().to_padded_bytes(**array_type.length() as usize)?;
+                        // Construct the array.
+                        let bytes = string.to_padded_bytes(**array_type.length() as usize);
+                        let elements =
+                            bytes.into_iter().map(|byte| circuit::Plaintext::from(circuit::Literal::U8(byte)));
+                        let array = circuit::Plaintext::Array(elements.collect(), Default::default());
+                        // Store the array.
+                        return registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array));
+                    }
+                }
+
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_ARRAY_ELEMENTS {
                     bail!("Casting to an array requires at least {} operand(s)", N::MIN_ARRAY_ELEMENTS)
@@ -492,6 +530,9 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 // Store the array.
                 registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array))
             }
+            CastType::Plaintext(PlaintextType::Optional(optional_type)) => {
+                bail!("Illegal operation: Cannot cast to the optional type '{optional_type}?'.")
+            }
             CastType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -624,6 +665,9 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                         1 => literal.cast_lossy(*literal_type)?,
                         2.. => unreachable!("Invalid cast variant"),
                     },
+                    Value::Plaintext(Plaintext::Array(elements, ..)) if VARIANT == CastVariant::Cast as u8 => {
+                        Self::cast_bytes_to_string(*literal_type, elements)?
+                    }
                     _ => bail!("Casting to a literal requires a literal"),
                 };
                 registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(value)))
@@ -634,6 +678,9 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
                 self.cast_to_array(stack, registers, array_type, inputs)
             }
+            CastType::Plaintext(PlaintextType::Optional(optional_type)) => {
+                bail!("Illegal operation: Cannot cast to the optional type '{optional_type}?'.")
+            }
             CastType::Record(_record_name) => {
                 bail!("Illegal operation: Cannot cast to a record in a finalize block.")
             }
@@ -677,8 +724,17 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                     input_types[0]
                 );
             }
-            CastType::Plaintext(PlaintextType::Literal(..)) => {
+            CastType::Plaintext(PlaintextType::Literal(literal_type)) => {
                 ensure!(input_types.len() == 1, "Casting to a literal requires exactly 1 operand");
+                // If the operand is an array, ensure it is a byte array cast into a string.
+                if let RegisterType::Plaintext(PlaintextType::Array(array_type)) = &input_types[0] {
+                    ensure!(
+                        VARIANT == CastVariant::Cast as u8
+                            && *literal_type == LiteralType::String
+                            && Self::is_byte_array(array_type),
+                        "Casting an array to a literal is only supported for casting '[u8; N]' to 'string'"
+                    );
+                }
             }
             CastType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Retrieve the struct and ensure it is defined in the program.
@@ -726,6 +782,12 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                     }
                 }
             }
+            CastType::Plaintext(PlaintextType::Array(array_type))
+                if Self::is_byte_array(array_type)
+                    && input_types == [RegisterType::Plaintext(PlaintextType::Literal(LiteralType::String))] =>
+            {
+                // Casting a string into a byte array is valid for any array length.
+            }
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
                 // Ensure the input types length is at least the minimum.
                 if input_types.len() < N::MIN_ARRAY_ELEMENTS {
@@ -775,6 +837,9 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                     }
                 }
             }
+            CastType::Plaintext(PlaintextType::Optional(optional_type)) => {
+                bail!("Illegal operation: Cannot cast to the optional type '{optional_type}?'.")
+            }
             CastType::Record(record_name) => {
                 // Retrieve the record type and ensure is defined in the program.
                 let record = stack.program().get_record(record_name)?;
@@ -909,6 +974,18 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
         array_type: &ArrayType<N>,
         inputs: Vec<Value<N>>,
     ) -> Result<()> {
+        // If the operand is a string, cast it into a byte array, padded with trailing zeros.
+        if let [Value::Plaintext(Plaintext::Literal(Literal::String(string), ..))] = inputs.as_slice() {
+            if Self::is_byte_array(array_type) {
+                // Construct the array.
+                let bytes = string.to_padded_bytes(**array_type.length() as usize)?;
+                let elements = bytes.into_iter().map(|byte| Plaintext::from(Literal::U8(byte)));
+                let array = Plaintext::Array(elements.collect(), Default::default());
+                // Store the array.
+                return registers.store(stack, &self.destination, Value::Plaintext(array));
+            }
+        }
+
         // Ensure that there is at least one operand.
         if inputs.len() < N::MIN_ARRAY_ELEMENTS {
             bail!("Casting to an array requires at least {} operand", N::MIN_ARRAY_ELEMENTS)
@@ -949,6 +1026,26 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
         // Store the array.
         registers.store(stack, &self.destination, Value::Plaintext(array))
     }
+
+    /// A helper method to handle casting a byte array into a string, stripping any trailing zeros.
+    fn cast_bytes_to_string(literal_type: LiteralType, elements: &[Plaintext<N>]) -> Result<Literal<N>> {
+        ensure!(literal_type == LiteralType::String, "Casting an array to a literal is only supported for 'string'");
+        // Retrieve the bytes from the array.
+        let bytes: Vec<_> = elements
+            .iter()
+            .map(|element| match element {
+                Plaintext::Literal(Literal::U8(byte), ..) => Ok(*byte),
+                _ => bail!("Casting an array to a string requires an array of 'u8' elements"),
+            })
+            .try_collect()?;
+        // Construct the string.
+        Ok(Literal::String(StringType::from_padded_bytes(&bytes)?))
+    }
+
+    /// Returns `true` if the given array type is a byte array (i.e. `[u8; N]`).
+    fn is_byte_array(array_type: &ArrayType<N>) -> bool {
+        array_type.next_element_type() == &PlaintextType::Literal(LiteralType::U8)
+    }
 }
 
 impl<N: Network, const VARIANT: u8> Parser for CastOperation<N, VARIANT> {
@@ -987,7 +1084,8 @@ impl<N: Network, const VARIANT: u8> Parser for CastOperation<N, VARIANT> {
         let max_operands = match cast_type {
             CastType::GroupXCoordinate
             | CastType::GroupYCoordinate
-            | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
+            | CastType::Plaintext(PlaintextType::Literal(_))
+            | CastType::Plaintext(PlaintextType::Optional(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
@@ -1035,7 +1133,8 @@ impl<N: Network, const VARIANT: u8> Display for CastOperation<N, VARIANT> {
         let max_operands = match self.cast_type {
             CastType::GroupYCoordinate
             | CastType::GroupXCoordinate
-            | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
+            | CastType::Plaintext(PlaintextType::Literal(_))
+            | CastType::Plaintext(PlaintextType::Optional(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
@@ -1080,7 +1179,8 @@ impl<N: Network, const VARIANT: u8> FromBytes for CastOperation<N, VARIANT> {
         let max_operands = match cast_type {
             CastType::GroupYCoordinate
             | CastType::GroupXCoordinate
-            | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
+            | CastType::Plaintext(PlaintextType::Literal(_))
+            | CastType::Plaintext(PlaintextType::Optional(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
@@ -1101,7 +1201,8 @@ impl<N: Network, const VARIANT: u8> ToBytes for CastOperation<N, VARIANT> {
         let max_operands = match self.cast_type {
             CastType::GroupYCoordinate
             | CastType::GroupXCoordinate
-            | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
+            | CastType::Plaintext(PlaintextType::Literal(_))
+            | CastType::Plaintext(PlaintextType::Optional(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
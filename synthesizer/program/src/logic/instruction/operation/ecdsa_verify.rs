@@ -0,0 +1,350 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    algorithms::ECDSA,
+    network::prelude::*,
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value, U32},
+    types::Boolean,
+};
+
+/// Computes whether the secp256k1 ECDSA `signature` is valid for the given `public_key` and message `digest`.
+///
+/// The signature is a `[[u8; 32u32]; 2u32]` array of the big-endian `r` and `s` scalars,
+/// the public key is a `[[u8; 32u32]; 2u32]` array of the big-endian `x` and `y` coordinates,
+/// and the digest is a `[u8; 32u32]` array (e.g. the Keccak-256 hash of the message).
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ECDSAVerify<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> ECDSAVerify<N> {
+    /// Initializes a new `ecdsa.verify` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Sign("ecdsa.verify")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly three operands.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> ECDSAVerify<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature = to_bytes(&registers.load(stack, &self.operands[0])?, ECDSA::SIGNATURE_SIZE_IN_BYTES)?;
+        let public_key = to_bytes(&registers.load(stack, &self.operands[1])?, ECDSA::PUBLIC_KEY_SIZE_IN_BYTES)?;
+        let digest = to_bytes(&registers.load(stack, &self.operands[2])?, ECDSA::DIGEST_SIZE_IN_BYTES)?;
+
+        // Verify the signature.
+        // Note that these `try_into` calls are safe, as `to_bytes` checks the number of bytes.
+        let output = ECDSA::verify(
+            &public_key.try_into().map_err(|_| anyhow!("Invalid ECDSA public key"))?,
+            &digest.try_into().map_err(|_| anyhow!("Invalid ECDSA digest"))?,
+            &signature.try_into().map_err(|_| anyhow!("Invalid ECDSA signature"))?,
+        );
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, Literal::Boolean(Boolean::new(output)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature =
+            to_circuit_bytes(&registers.load_circuit(stack, &self.operands[0])?, ECDSA::SIGNATURE_SIZE_IN_BYTES)?;
+        let public_key =
+            to_circuit_bytes(&registers.load_circuit(stack, &self.operands[1])?, ECDSA::PUBLIC_KEY_SIZE_IN_BYTES)?;
+        let digest = to_circuit_bytes(&registers.load_circuit(stack, &self.operands[2])?, ECDSA::DIGEST_SIZE_IN_BYTES)?;
+
+        // Verify the signature.
+        let output = circuit::ECDSA::verify(&public_key, &digest, &signature);
+
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::Boolean(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Initialize the expected input types.
+        let bytes = |dimensions: Vec<u32>| -> Result<RegisterType<N>> {
+            let dimensions = dimensions.into_iter().map(U32::new).collect();
+            let array_type = ArrayType::new(PlaintextType::Literal(LiteralType::U8), dimensions)?;
+            Ok(RegisterType::Plaintext(PlaintextType::Array(array_type)))
+        };
+        let pair_of_bytes = bytes(vec![2, 32])?;
+        let digest_bytes = bytes(vec![32])?;
+
+        // Ensure the first operand is a signature.
+        if input_types[0] != pair_of_bytes {
+            bail!(
+                "Instruction '{}' expects the first input to be a '{pair_of_bytes}' signature. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            )
+        }
+
+        // Ensure the second operand is a public key.
+        if input_types[1] != pair_of_bytes {
+            bail!(
+                "Instruction '{}' expects the second input to be a '{pair_of_bytes}' public key. Found input of type '{}'",
+                Self::opcode(),
+                input_types[1]
+            )
+        }
+
+        // Ensure the third operand is a digest.
+        if input_types[2] != digest_bytes {
+            bail!(
+                "Instruction '{}' expects the third input to be a '{digest_bytes}' digest. Found input of type '{}'",
+                Self::opcode(),
+                input_types[2]
+            )
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+/// Returns the bytes of the given (possibly nested) `u8` array, in order.
+fn to_bytes<N: Network>(value: &Value<N>, num_bytes: usize) -> Result<Vec<u8>> {
+    fn flatten<N: Network>(plaintext: &Plaintext<N>, bytes: &mut Vec<u8>) -> Result<()> {
+        match plaintext {
+            Plaintext::Literal(Literal::U8(byte), ..) => bytes.push(**byte),
+            Plaintext::Array(elements, ..) => elements.iter().try_for_each(|element| flatten(element, bytes))?,
+            _ => bail!("Expected a 'u8' array, found '{plaintext}'"),
+        }
+        Ok(())
+    }
+
+    let mut bytes = Vec::with_capacity(num_bytes);
+    match value {
+        Value::Plaintext(plaintext) => flatten(plaintext, &mut bytes)?,
+        _ => bail!("Expected a 'u8' array, found '{value}'"),
+    }
+    ensure!(bytes.len() == num_bytes, "Expected {num_bytes} bytes, found {}", bytes.len());
+    Ok(bytes)
+}
+
+/// Returns the bytes of the given (possibly nested) `u8` array circuit, in order.
+fn to_circuit_bytes<A: circuit::Aleo>(value: &circuit::Value<A>, num_bytes: usize) -> Result<Vec<circuit::U8<A>>> {
+    fn flatten<A: circuit::Aleo>(plaintext: &circuit::Plaintext<A>, bytes: &mut Vec<circuit::U8<A>>) -> Result<()> {
+        match plaintext {
+            circuit::Plaintext::Literal(circuit::Literal::U8(byte), ..) => bytes.push(byte.clone()),
+            circuit::Plaintext::Array(elements, ..) => {
+                elements.iter().try_for_each(|element| flatten(element, bytes))?
+            }
+            _ => bail!("Expected a 'u8' array"),
+        }
+        Ok(())
+    }
+
+    let mut bytes = Vec::with_capacity(num_bytes);
+    match value {
+        circuit::Value::Plaintext(plaintext) => flatten(plaintext, &mut bytes)?,
+        _ => bail!("Expected a 'u8' array"),
+    }
+    ensure!(bytes.len() == num_bytes, "Expected {num_bytes} bytes, found {}", bytes.len());
+    Ok(bytes)
+}
+
+impl<N: Network> Parser for ECDSAVerify<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third], destination }))
+    }
+}
+
+impl<N: Network> FromStr for ECDSAVerify<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for ECDSAVerify<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ECDSAVerify<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for ECDSAVerify<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for ECDSAVerify<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, is) = ECDSAVerify::<CurrentNetwork>::parse("ecdsa.verify r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(is.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(is.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(is.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(is.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(is.destination, Register::Locator(3), "The destination register is incorrect");
+    }
+}
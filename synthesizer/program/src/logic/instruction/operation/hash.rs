@@ -31,6 +31,9 @@ pub type HashBHP768<N> = HashInstruction<N, { Hasher::HashBHP768 as u8 }>;
 /// BHP1024 is a collision-resistant hash function that processes inputs in 1024-bit chunks.
 pub type HashBHP1024<N> = HashInstruction<N, { Hasher::HashBHP1024 as u8 }>;
 
+/// BLAKE2s is a cryptographic hash function that outputs a 256-bit digest.
+pub type HashBlake2s<N> = HashInstruction<N, { Hasher::HashBlake2s as u8 }>;
+
 /// Keccak256 is a cryptographic hash function that outputs a 256-bit digest.
 pub type HashKeccak256<N> = HashInstruction<N, { Hasher::HashKeccak256 as u8 }>;
 /// Keccak384 is a cryptographic hash function that outputs a 384-bit digest.
@@ -50,6 +53,16 @@ pub type HashPSD4<N> = HashInstruction<N, { Hasher::HashPSD4 as u8 }>;
 /// Poseidon8 is a cryptographic hash function that processes inputs in 8-field chunks.
 pub type HashPSD8<N> = HashInstruction<N, { Hasher::HashPSD8 as u8 }>;
 
+/// Poseidon2_2 is a Poseidon2-permutation hash function that processes inputs in 2-field chunks.
+pub type HashPoseidon2_2<N> = HashInstruction<N, { Hasher::HashPoseidon2_2 as u8 }>;
+/// Poseidon2_4 is a Poseidon2-permutation hash function that processes inputs in 4-field chunks.
+pub type HashPoseidon2_4<N> = HashInstruction<N, { Hasher::HashPoseidon2_4 as u8 }>;
+/// Poseidon2_8 is a Poseidon2-permutation hash function that processes inputs in 8-field chunks.
+pub type HashPoseidon2_8<N> = HashInstruction<N, { Hasher::HashPoseidon2_8 as u8 }>;
+
+/// SHA-256 is a cryptographic hash function that outputs a 256-bit digest.
+pub type HashSha256<N> = HashInstruction<N, { Hasher::HashSha256 as u8 }>;
+
 /// SHA3-256 is a cryptographic hash function that outputs a 256-bit digest.
 pub type HashSha3_256<N> = HashInstruction<N, { Hasher::HashSha3_256 as u8 }>;
 /// SHA3-384 is a cryptographic hash function that outputs a 384-bit digest.
@@ -83,6 +96,11 @@ enum Hasher {
     HashManyPSD2,
     HashManyPSD4,
     HashManyPSD8,
+    HashBlake2s,
+    HashSha256,
+    HashPoseidon2_2,
+    HashPoseidon2_4,
+    HashPoseidon2_8,
 }
 
 /// Returns the expected number of operands given the variant.
@@ -111,6 +129,7 @@ fn is_valid_destination_type<N: Network>(destination_type: &PlaintextType<N>) ->
             | PlaintextType::Literal(LiteralType::String)
             | PlaintextType::Struct(..)
             | PlaintextType::Array(..)
+            | PlaintextType::Optional(..)
     )
 }
 
@@ -165,7 +184,12 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             15 => Opcode::Hash("hash_many.psd2"),
             16 => Opcode::Hash("hash_many.psd4"),
             17 => Opcode::Hash("hash_many.psd8"),
-            18.. => panic!("Invalid 'hash' instruction opcode"),
+            18 => Opcode::Hash("hash.blake2s"),
+            19 => Opcode::Hash("hash.sha256"),
+            20 => Opcode::Hash("hash.poseidon2_2"),
+            21 => Opcode::Hash("hash.poseidon2_4"),
+            22 => Opcode::Hash("hash.poseidon2_8"),
+            23.. => panic!("Invalid 'hash' instruction opcode"),
         }
     }
 
@@ -251,15 +275,35 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (15, _) => bail!("'hash_many.psd2' is not yet implemented"),
             (16, _) => bail!("'hash_many.psd4' is not yet implemented"),
             (17, _) => bail!("'hash_many.psd8' is not yet implemented"),
-            (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
+            (18, PlaintextType::Literal(..)) => {
+                Literal::Group(N::hash_to_group_bhp256(&N::hash_blake2s(&input.to_bits_le())?)?)
+            }
+            (19, PlaintextType::Literal(..)) => {
+                Literal::Group(N::hash_to_group_bhp256(&N::hash_sha256(&input.to_bits_le())?)?)
+            }
+            (20, PlaintextType::Literal(LiteralType::Address)) | (20, PlaintextType::Literal(LiteralType::Group)) => {
+                Literal::Group(N::hash_to_group_poseidon2_2(&input.to_fields()?)?)
+            }
+            (20, PlaintextType::Literal(..)) => Literal::Field(N::hash_poseidon2_2(&input.to_fields()?)?),
+            (21, PlaintextType::Literal(LiteralType::Address)) | (21, PlaintextType::Literal(LiteralType::Group)) => {
+                Literal::Group(N::hash_to_group_poseidon2_4(&input.to_fields()?)?)
+            }
+            (21, PlaintextType::Literal(..)) => Literal::Field(N::hash_poseidon2_4(&input.to_fields()?)?),
+            (22, PlaintextType::Literal(LiteralType::Address)) | (22, PlaintextType::Literal(LiteralType::Group)) => {
+                Literal::Group(N::hash_to_group_poseidon2_8(&input.to_fields()?)?)
+            }
+            (22, PlaintextType::Literal(..)) => Literal::Field(N::hash_poseidon2_8(&input.to_fields()?)?),
+            (23.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
+            (_, PlaintextType::Optional(..)) => bail!("Cannot hash into an optional"),
         };
         // Cast the output to the destination type.
         let output = match self.destination_type {
             PlaintextType::Literal(literal_type) => output.cast_lossy(literal_type)?,
             PlaintextType::Struct(..) => bail!("Cannot hash into a struct"),
             PlaintextType::Array(..) => bail!("Cannot hash into an array (yet)"),
+            PlaintextType::Optional(..) => bail!("Cannot hash into an optional"),
         };
         // Store the output.
         registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
@@ -322,15 +366,35 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (15, _) => bail!("'hash_many.psd2' is not yet implemented"),
             (16, _) => bail!("'hash_many.psd4' is not yet implemented"),
             (17, _) => bail!("'hash_many.psd8' is not yet implemented"),
-            (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
+            (18, PlaintextType::Literal(..)) => {
+                circuit::Literal::Group(A::hash_to_group_bhp256(&A::hash_blake2s(&input.to_bits_le())))
+            }
+            (19, PlaintextType::Literal(..)) => {
+                circuit::Literal::Group(A::hash_to_group_bhp256(&A::hash_sha256(&input.to_bits_le())))
+            }
+            (20, PlaintextType::Literal(LiteralType::Address)) | (20, PlaintextType::Literal(LiteralType::Group)) => {
+                circuit::Literal::Group(A::hash_to_group_poseidon2_2(&input.to_fields()))
+            }
+            (20, PlaintextType::Literal(..)) => circuit::Literal::Field(A::hash_poseidon2_2(&input.to_fields())),
+            (21, PlaintextType::Literal(LiteralType::Address)) | (21, PlaintextType::Literal(LiteralType::Group)) => {
+                circuit::Literal::Group(A::hash_to_group_poseidon2_4(&input.to_fields()))
+            }
+            (21, PlaintextType::Literal(..)) => circuit::Literal::Field(A::hash_poseidon2_4(&input.to_fields())),
+            (22, PlaintextType::Literal(LiteralType::Address)) | (22, PlaintextType::Literal(LiteralType::Group)) => {
+                circuit::Literal::Group(A::hash_to_group_poseidon2_8(&input.to_fields()))
+            }
+            (22, PlaintextType::Literal(..)) => circuit::Literal::Field(A::hash_poseidon2_8(&input.to_fields())),
+            (23.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
+            (_, PlaintextType::Optional(..)) => bail!("Cannot hash into an optional"),
         };
         // Cast the output to the destination type.
         let output = match self.destination_type {
             PlaintextType::Literal(literal_type) => output.cast_lossy(literal_type)?,
             PlaintextType::Struct(..) => bail!("Cannot hash into a struct"),
             PlaintextType::Array(..) => bail!("Cannot hash into an array (yet)"),
+            PlaintextType::Optional(..) => bail!("Cannot hash into an optional"),
         };
         // Convert the output to a stack value.
         let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
@@ -365,9 +429,9 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
         match VARIANT {
-            0..=14 => Ok(vec![RegisterType::Plaintext(self.destination_type.clone())]),
+            0..=14 | 18..=22 => Ok(vec![RegisterType::Plaintext(self.destination_type.clone())]),
             15..=17 => bail!("'hash_many' is not yet implemented"),
-            18.. => bail!("Invalid 'hash' variant: {VARIANT}"),
+            23.. => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
     }
 }
@@ -512,11 +576,13 @@ mod tests {
             PlaintextType::Literal(LiteralType::I32),
             PlaintextType::Literal(LiteralType::I64),
             PlaintextType::Literal(LiteralType::I128),
+            PlaintextType::Literal(LiteralType::I256),
             PlaintextType::Literal(LiteralType::U8),
             PlaintextType::Literal(LiteralType::U16),
             PlaintextType::Literal(LiteralType::U32),
             PlaintextType::Literal(LiteralType::U64),
             PlaintextType::Literal(LiteralType::U128),
+            PlaintextType::Literal(LiteralType::U256),
             PlaintextType::Literal(LiteralType::Scalar),
         ]
     }
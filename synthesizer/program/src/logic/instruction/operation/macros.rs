@@ -349,11 +349,13 @@ mod tests {
                 console::program::Literal::I32(console::types::I32::rand($rng)),
                 console::program::Literal::I64(console::types::I64::rand($rng)),
                 console::program::Literal::I128(console::types::I128::rand($rng)),
+                console::program::Literal::I256(console::types::I256::rand($rng)),
                 console::program::Literal::U8(console::types::U8::rand($rng)),
                 console::program::Literal::U16(console::types::U16::rand($rng)),
                 console::program::Literal::U32(console::types::U32::rand($rng)),
                 console::program::Literal::U64(console::types::U64::rand($rng)),
                 console::program::Literal::U128(console::types::U128::rand($rng)),
+                console::program::Literal::U256(console::types::U256::rand($rng)),
                 console::program::Literal::Scalar(console::types::Scalar::rand($rng)),
                 console::program::Literal::sample(console::program::LiteralType::Signature, $rng),
                 console::program::Literal::String(console::types::StringType::rand($rng)),
@@ -714,6 +716,7 @@ mod tests {
                             (I16, I16) => { sample_value!(I128, I128) };
                             (I32, I32) => { sample_value!(I128, I128) };
                             (I64, I64) => { sample_value!(I128, I128) };
+                            (I256, I256) => { sample_value!(I128, I128) };
                             (I128, I128) => {
                                 match i {
                                     0 => ($input_a::zero(), $input_b::zero()),
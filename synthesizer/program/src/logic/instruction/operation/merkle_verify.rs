@@ -0,0 +1,449 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    collections::merkle_tree::MerklePath,
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{Boolean, Field, U64},
+};
+
+/// Verifies a Merkle path for a BHP Merkle tree, with a BHP1024 leaf hasher and a BHP512 path hasher.
+pub type MerkleVerifyBHP<N> = MerkleVerify<N, { MerkleVariant::MerkleVerifyBHP as u8 }>;
+/// Verifies a Merkle path for a Poseidon Merkle tree, with a Poseidon4 leaf hasher and a Poseidon2 path hasher.
+pub type MerkleVerifyPSD<N> = MerkleVerify<N, { MerkleVariant::MerkleVerifyPSD as u8 }>;
+
+enum MerkleVariant {
+    MerkleVerifyBHP,
+    MerkleVerifyPSD,
+}
+
+/// The maximum depth of a Merkle tree that can be verified by a `merkle.verify` instruction.
+pub const MAX_MERKLE_DEPTH: u8 = 32;
+
+/// Invokes the given function with the given (runtime) Merkle tree depth as a const generic.
+macro_rules! match_depth {
+    ($depth:expr, $function:ident $args:tt) => {
+        match_depth!($depth, $function $args, [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+        ])
+    };
+    ($depth:expr, $function:ident $args:tt, [$($d:literal),+]) => {
+        match $depth {
+            $($d => $function::<_, $d> $args,)+
+            depth => bail!("Merkle tree depth {depth} is not supported (maximum depth is {MAX_MERKLE_DEPTH})"),
+        }
+    };
+}
+
+/// Computes whether the Merkle `path` is valid for the given `root` and `leaf`, storing the outcome in `destination`.
+///
+/// The root is a `field`, and the leaf is any plaintext value.
+/// The path is a `[field; N]` array, where the first element is the leaf index,
+/// and the remaining `N - 1` elements are the sibling hashes from the leaf to the root.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MerkleVerify<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> MerkleVerify<N, VARIANT> {
+    /// Initializes a new `merkle.verify` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Merkle("merkle.verify.bhp"),
+            1 => Opcode::Merkle("merkle.verify.psd"),
+            2.. => panic!("Invalid 'merkle.verify' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly three operands.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> MerkleVerify<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the root.
+        let root = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(Plaintext::Literal(Literal::Field(root), ..)) => root,
+            _ => bail!("Instruction '{}' expects the root to be a field", Self::opcode()),
+        };
+        // Retrieve the leaf.
+        let leaf = registers.load(stack, &self.operands[1])?;
+        // Retrieve the path.
+        let path = match registers.load(stack, &self.operands[2])? {
+            Value::Plaintext(Plaintext::Array(elements, ..)) => elements
+                .iter()
+                .map(|element| match element {
+                    Plaintext::Literal(Literal::Field(field), ..) => Ok(*field),
+                    _ => bail!("Instruction '{}' expects the path to be a field array", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Instruction '{}' expects the path to be a field array", Self::opcode()),
+        };
+
+        // Split the path into the leaf index and the siblings.
+        let Some((leaf_index, siblings)) = path.split_first() else {
+            bail!("Instruction '{}' expects a non-empty path", Self::opcode())
+        };
+        // Note: This call will ensure the leaf index is a 64-bit integer.
+        let leaf_index = U64::from_bits_le(&leaf_index.to_bits_le())?;
+        // Determine the depth of the Merkle tree.
+        let depth = u8::try_from(siblings.len())?;
+
+        // Verify the Merkle path.
+        let output = match VARIANT {
+            0 => match_depth!(depth, verify_bhp(&root, &leaf.to_bits_le(), leaf_index, siblings))?,
+            1 => match_depth!(depth, verify_psd(&root, &leaf.to_fields()?, leaf_index, siblings))?,
+            2.. => bail!("Invalid 'merkle.verify' variant: {VARIANT}"),
+        };
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, Literal::Boolean(Boolean::new(output)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::traits::{FromBits, ToBits, ToFields, ToLowerBits};
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the root.
+        let root = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(root), ..)) => root,
+            _ => bail!("Instruction '{}' expects the root to be a field", Self::opcode()),
+        };
+        // Retrieve the leaf.
+        let leaf = registers.load_circuit(stack, &self.operands[1])?;
+        // Retrieve the path.
+        let path = match registers.load_circuit(stack, &self.operands[2])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Array(elements, ..)) => elements
+                .iter()
+                .map(|element| match element {
+                    circuit::Plaintext::Literal(circuit::Literal::Field(field), ..) => Ok(field.clone()),
+                    _ => bail!("Instruction '{}' expects the path to be a field array", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Instruction '{}' expects the path to be a field array", Self::opcode()),
+        };
+
+        // Split the path into the leaf index and the siblings.
+        let Some((leaf_index, siblings)) = path.split_first() else {
+            bail!("Instruction '{}' expects a non-empty path", Self::opcode())
+        };
+        // Note: This call will enforce the leaf index is a 64-bit integer.
+        let leaf_index = circuit::U64::from_bits_le(&leaf_index.to_lower_bits_le(64));
+        // Determine the depth of the Merkle tree.
+        let depth = u8::try_from(siblings.len())?;
+
+        // Verify the Merkle path.
+        let output = match VARIANT {
+            0 => match_depth!(depth, verify_bhp_circuit(&root, &leaf.to_bits_le(), leaf_index, siblings)),
+            1 => match_depth!(depth, verify_psd_circuit(&root, &leaf.to_fields(), leaf_index, siblings)),
+            2.. => bail!("Invalid 'merkle.verify' variant: {VARIANT}"),
+        };
+
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::Boolean(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the first operand is a field.
+        if input_types[0] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field)) {
+            bail!(
+                "Instruction '{}' expects the first input to be a 'field' root. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            )
+        }
+
+        // Ensure the second operand is a plaintext.
+        if !matches!(input_types[1], RegisterType::Plaintext(..)) {
+            bail!(
+                "Instruction '{}' expects the second input to be a plaintext leaf. Found input of type '{}'",
+                Self::opcode(),
+                input_types[1]
+            )
+        }
+
+        // Ensure the third operand is a field array, with a leaf index and between 1 and `MAX_MERKLE_DEPTH` siblings.
+        match &input_types[2] {
+            RegisterType::Plaintext(PlaintextType::Array(array_type))
+                if array_type.next_element_type() == &PlaintextType::Literal(LiteralType::Field)
+                    && (2..=MAX_MERKLE_DEPTH as u32 + 1).contains(&**array_type.length()) => {}
+            _ => bail!(
+                "Instruction '{}' expects the third input to be a '[field; N]' path, where 2 <= N <= {}. Found input of type '{}'",
+                Self::opcode(),
+                MAX_MERKLE_DEPTH as u32 + 1,
+                input_types[2]
+            ),
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+/// Returns `true` if the given Merkle path is valid for the given root and leaf, in a BHP Merkle tree.
+#[allow(clippy::ptr_arg)]
+fn verify_bhp<N: Network, const DEPTH: u8>(
+    root: &Field<N>,
+    leaf: &Vec<bool>,
+    leaf_index: U64<N>,
+    siblings: &[Field<N>],
+) -> Result<bool> {
+    // Note: This call will ensure the leaf index is within the tree depth.
+    let path = MerklePath::<N, DEPTH>::try_from((leaf_index, siblings.to_vec()))?;
+    Ok(N::verify_merkle_path_bhp(&path, root, leaf))
+}
+
+/// Returns `true` if the given Merkle path is valid for the given root and leaf, in a Poseidon Merkle tree.
+#[allow(clippy::ptr_arg)]
+fn verify_psd<N: Network, const DEPTH: u8>(
+    root: &Field<N>,
+    leaf: &Vec<Field<N>>,
+    leaf_index: U64<N>,
+    siblings: &[Field<N>],
+) -> Result<bool> {
+    // Note: This call will ensure the leaf index is within the tree depth.
+    let path = MerklePath::<N, DEPTH>::try_from((leaf_index, siblings.to_vec()))?;
+    Ok(N::verify_merkle_path_psd(&path, root, leaf))
+}
+
+/// Returns `true` if the given Merkle path is valid for the given root and leaf, in a BHP Merkle tree circuit.
+#[allow(clippy::ptr_arg)]
+fn verify_bhp_circuit<A: circuit::Aleo, const DEPTH: u8>(
+    root: &circuit::Field<A>,
+    leaf: &Vec<circuit::Boolean<A>>,
+    leaf_index: circuit::U64<A>,
+    siblings: &[circuit::Field<A>],
+) -> circuit::Boolean<A> {
+    let path = circuit::collections::merkle_tree::MerklePath::<A, DEPTH>::from((leaf_index, siblings.to_vec()));
+    A::verify_merkle_path_bhp(&path, root, leaf)
+}
+
+/// Returns `true` if the given Merkle path is valid for the given root and leaf, in a Poseidon Merkle tree circuit.
+#[allow(clippy::ptr_arg)]
+fn verify_psd_circuit<A: circuit::Aleo, const DEPTH: u8>(
+    root: &circuit::Field<A>,
+    leaf: &Vec<circuit::Field<A>>,
+    leaf_index: circuit::U64<A>,
+    siblings: &[circuit::Field<A>],
+) -> circuit::Boolean<A> {
+    let path = circuit::collections::merkle_tree::MerklePath::<A, DEPTH>::from((leaf_index, siblings.to_vec()));
+    A::verify_merkle_path_psd(&path, root, leaf)
+}
+
+impl<N: Network, const VARIANT: u8> Parser for MerkleVerify<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third], destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for MerkleVerify<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for MerkleVerify<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for MerkleVerify<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for MerkleVerify<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for MerkleVerify<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        for opcode in ["merkle.verify.bhp", "merkle.verify.psd"] {
+            let string = format!("{opcode} r0 r1 r2 into r3");
+            let operands = match opcode {
+                "merkle.verify.bhp" => {
+                    let (string, is) = MerkleVerifyBHP::<CurrentNetwork>::parse(&string).unwrap();
+                    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+                    assert_eq!(is.destination, Register::Locator(3), "The destination register is incorrect");
+                    is.operands
+                }
+                _ => {
+                    let (string, is) = MerkleVerifyPSD::<CurrentNetwork>::parse(&string).unwrap();
+                    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+                    assert_eq!(is.destination, Register::Locator(3), "The destination register is incorrect");
+                    is.operands
+                }
+            };
+            assert_eq!(operands.len(), 3, "The number of operands is incorrect");
+            assert_eq!(operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+            assert_eq!(operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+            assert_eq!(operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        }
+    }
+}
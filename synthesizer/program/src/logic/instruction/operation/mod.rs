@@ -21,12 +21,18 @@ pub use async_::*;
 mod call;
 pub use call::*;
 
+mod call_dynamic;
+pub use call_dynamic::*;
+
 mod cast;
 pub use cast::*;
 
 mod commit;
 pub use commit::*;
 
+mod ecdsa_verify;
+pub use ecdsa_verify::*;
+
 mod hash;
 pub use hash::*;
 
@@ -38,9 +44,24 @@ pub use literals::*;
 
 mod macros;
 
+mod merkle_verify;
+pub use merkle_verify::*;
+
+mod option;
+pub use option::*;
+
+mod repeat;
+pub use repeat::*;
+
+mod serialize;
+pub use serialize::*;
+
 mod sign_verify;
 pub use sign_verify::*;
 
+mod string;
+pub use string::*;
+
 use crate::Opcode;
 use console::network::prelude::*;
 
@@ -73,6 +94,7 @@ crate::operation!(
         I32 => I32 ("ensure overflows halt"),
         I64 => I64 ("ensure overflows halt"),
         I128 => I128 ("ensure overflows halt"),
+        I256 => I256 ("ensure overflows halt"),
     }
 );
 
@@ -86,6 +108,7 @@ crate::operation!(
         I32 => I32,
         I64 => I64,
         I128 => I128,
+        I256 => I256,
     }
 );
 
@@ -101,11 +124,13 @@ crate::operation!(
         (I32, I32) => I32 ("ensure overflows halt"),
         (I64, I64) => I64 ("ensure overflows halt"),
         (I128, I128) => I128 ("ensure overflows halt"),
+        (I256, I256) => I256 ("ensure overflows halt"),
         (U8, U8) => U8 ("ensure overflows halt"),
         (U16, U16) => U16 ("ensure overflows halt"),
         (U32, U32) => U32 ("ensure overflows halt"),
         (U64, U64) => U64 ("ensure overflows halt"),
         (U128, U128) => U128 ("ensure overflows halt"),
+        (U256, U256) => U256 ("ensure overflows halt"),
         (Scalar, Scalar) => Scalar,
     }
 );
@@ -120,11 +145,13 @@ crate::operation!(
         (I32, I32) => I32,
         (I64, I64) => I64,
         (I128, I128) => I128,
+        (I256, I256) => I256,
         (U8, U8) => U8,
         (U16, U16) => U16,
         (U32, U32) => U32,
         (U64, U64) => U64,
         (U128, U128) => U128,
+        (U256, U256) => U256,
     }
 );
 
@@ -139,14 +166,19 @@ crate::operation!(
         (I32, I32) => I32,
         (I64, I64) => I64,
         (I128, I128) => I128,
+        (I256, I256) => I256,
         (U8, U8) => U8,
         (U16, U16) => U16,
         (U32, U32) => U32,
         (U64, U64) => U64,
         (U128, U128) => U128,
+        (U256, U256) => U256,
     }
 );
 
+/// Concatenates `first` and `second`, storing the outcome in `destination`.
+pub type Concat<N> = BinaryLiteral<N, ConcatOperation<N>>;
+
 /// Divides `first` by `second`, storing the outcome in `destination`.
 pub type Div<N> = BinaryLiteral<N, DivOperation<N>>;
 
@@ -158,11 +190,13 @@ crate::operation!(
         (I32, I32) => I32 ("ensure overflows halt", "ensure divide by zero halts"),
         (I64, I64) => I64 ("ensure overflows halt", "ensure divide by zero halts"),
         (I128, I128) => I128 ("ensure overflows halt", "ensure divide by zero halts"),
+        (I256, I256) => I256 ("ensure overflows halt", "ensure divide by zero halts"),
         (U8, U8) => U8 ("ensure divide by zero halts"),
         (U16, U16) => U16 ("ensure divide by zero halts"),
         (U32, U32) => U32 ("ensure divide by zero halts"),
         (U64, U64) => U64 ("ensure divide by zero halts"),
         (U128, U128) => U128 ("ensure divide by zero halts"),
+        (U256, U256) => U256 ("ensure divide by zero halts"),
         // (Scalar, Scalar) => Scalar,
     }
 );
@@ -177,11 +211,13 @@ crate::operation!(
         (I32, I32) => I32 ("ensure divide by zero halts"),
         (I64, I64) => I64 ("ensure divide by zero halts"),
         (I128, I128) => I128 ("ensure divide by zero halts"),
+        (I256, I256) => I256 ("ensure divide by zero halts"),
         (U8, U8) => U8 ("ensure divide by zero halts"),
         (U16, U16) => U16 ("ensure divide by zero halts"),
         (U32, U32) => U32 ("ensure divide by zero halts"),
         (U64, U64) => U64 ("ensure divide by zero halts"),
         (U128, U128) => U128 ("ensure divide by zero halts"),
+        (U256, U256) => U256 ("ensure divide by zero halts"),
     }
 );
 
@@ -207,11 +243,13 @@ crate::operation!(
         (I32, I32) => Boolean,
         (I64, I64) => Boolean,
         (I128, I128) => Boolean,
+        (I256, I256) => Boolean,
         (U8, U8) => Boolean,
         (U16, U16) => Boolean,
         (U32, U32) => Boolean,
         (U64, U64) => Boolean,
         (U128, U128) => Boolean,
+        (U256, U256) => Boolean,
         (Scalar, Scalar) => Boolean,
     }
 );
@@ -228,11 +266,13 @@ crate::operation!(
         (I32, I32) => Boolean,
         (I64, I64) => Boolean,
         (I128, I128) => Boolean,
+        (I256, I256) => Boolean,
         (U8, U8) => Boolean,
         (U16, U16) => Boolean,
         (U32, U32) => Boolean,
         (U64, U64) => Boolean,
         (U128, U128) => Boolean,
+        (U256, U256) => Boolean,
         (Scalar, Scalar) => Boolean,
     }
 );
@@ -246,6 +286,9 @@ crate::operation!(
     }
 );
 
+/// Computes the length of `first` in bytes, storing the outcome in `destination`.
+pub type Len<N> = UnaryLiteral<N, LenOperation<N>>;
+
 /// Computes whether `first` is less than `second` as a boolean, storing the outcome in `destination`.
 pub type LessThan<N> = BinaryLiteral<N, LessThanOperation<N>>;
 
@@ -258,11 +301,13 @@ crate::operation!(
         (I32, I32) => Boolean,
         (I64, I64) => Boolean,
         (I128, I128) => Boolean,
+        (I256, I256) => Boolean,
         (U8, U8) => Boolean,
         (U16, U16) => Boolean,
         (U32, U32) => Boolean,
         (U64, U64) => Boolean,
         (U128, U128) => Boolean,
+        (U256, U256) => Boolean,
         (Scalar, Scalar) => Boolean,
     }
 );
@@ -279,11 +324,13 @@ crate::operation!(
         (I32, I32) => Boolean,
         (I64, I64) => Boolean,
         (I128, I128) => Boolean,
+        (I256, I256) => Boolean,
         (U8, U8) => Boolean,
         (U16, U16) => Boolean,
         (U32, U32) => Boolean,
         (U64, U64) => Boolean,
         (U128, U128) => Boolean,
+        (U256, U256) => Boolean,
         (Scalar, Scalar) => Boolean,
     }
 );
@@ -298,6 +345,7 @@ crate::operation!(
         (U32, U32) => U32("ensure divide by zero halts"),
         (U64, U64) => U64("ensure divide by zero halts"),
         (U128, U128) => U128("ensure divide by zero halts"),
+        (U256, U256) => U256("ensure divide by zero halts"),
     }
 );
 
@@ -314,11 +362,13 @@ crate::operation!(
         (I32, I32) => I32 ("ensure overflows halt"),
         (I64, I64) => I64 ("ensure overflows halt"),
         (I128, I128) => I128 ("ensure overflows halt"),
+        (I256, I256) => I256 ("ensure overflows halt"),
         (U8, U8) => U8 ("ensure overflows halt"),
         (U16, U16) => U16 ("ensure overflows halt"),
         (U32, U32) => U32 ("ensure overflows halt"),
         (U64, U64) => U64 ("ensure overflows halt"),
         (U128, U128) => U128 ("ensure overflows halt"),
+        (U256, U256) => U256 ("ensure overflows halt"),
         // (Scalar, Scalar) => Scalar,
     }
 );
@@ -333,11 +383,13 @@ crate::operation!(
         (I32, I32) => I32,
         (I64, I64) => I64,
         (I128, I128) => I128,
+        (I256, I256) => I256,
         (U8, U8) => U8,
         (U16, U16) => U16,
         (U32, U32) => U32,
         (U64, U64) => U64,
         (U128, U128) => U128,
+        (U256, U256) => U256,
     }
 );
 
@@ -362,6 +414,7 @@ crate::operation!(
         I32 => I32 ("ensure overflows halt"),
         I64 => I64 ("ensure overflows halt"),
         I128 => I128 ("ensure overflows halt"),
+        I256 => I256 ("ensure overflows halt"),
     }
 );
 
@@ -385,11 +438,13 @@ crate::operation!(
         I32 => I32,
         I64 => I64,
         I128 => I128,
+        I256 => I256,
         U8 => U8,
         U16 => U16,
         U32 => U32,
         U64 => U64,
         U128 => U128,
+        U256 => U256,
     }
 );
 
@@ -404,11 +459,13 @@ crate::operation!(
         (I32, I32) => I32,
         (I64, I64) => I64,
         (I128, I128) => I128,
+        (I256, I256) => I256,
         (U8, U8) => U8,
         (U16, U16) => U16,
         (U32, U32) => U32,
         (U64, U64) => U64,
         (U128, U128) => U128,
+        (U256, U256) => U256,
     }
 );
 
@@ -431,8 +488,11 @@ crate::operation!(
         (I64, U16) => I64 ("ensure exponentiation overflows halt"),
         (I64, U32) => I64 ("ensure exponentiation overflows halt"),
         (I128, U8) => I128 ("ensure exponentiation overflows halt"),
+        (I256, U8) => I256 ("ensure exponentiation overflows halt"),
         (I128, U16) => I128 ("ensure exponentiation overflows halt"),
+        (I256, U16) => I256 ("ensure exponentiation overflows halt"),
         (I128, U32) => I128 ("ensure exponentiation overflows halt"),
+        (I256, U32) => I256 ("ensure exponentiation overflows halt"),
         (U8, U8) => U8 ("ensure exponentiation overflows halt"),
         (U8, U16) => U8 ("ensure exponentiation overflows halt"),
         (U8, U32) => U8 ("ensure exponentiation overflows halt"),
@@ -446,8 +506,11 @@ crate::operation!(
         (U64, U16) => U64 ("ensure exponentiation overflows halt"),
         (U64, U32) => U64 ("ensure exponentiation overflows halt"),
         (U128, U8) => U128 ("ensure exponentiation overflows halt"),
+        (U256, U8) => U256 ("ensure exponentiation overflows halt"),
         (U128, U16) => U128 ("ensure exponentiation overflows halt"),
+        (U256, U16) => U256 ("ensure exponentiation overflows halt"),
         (U128, U32) => U128 ("ensure exponentiation overflows halt"),
+        (U256, U32) => U256 ("ensure exponentiation overflows halt"),
     }
 );
 
@@ -469,8 +532,11 @@ crate::operation!(
         (I64, U16) => I64,
         (I64, U32) => I64,
         (I128, U8) => I128,
+        (I256, U8) => I256,
         (I128, U16) => I128,
+        (I256, U16) => I256,
         (I128, U32) => I128,
+        (I256, U32) => I256,
         (U8, U8) => U8,
         (U8, U16) => U8,
         (U8, U32) => U8,
@@ -484,8 +550,11 @@ crate::operation!(
         (U64, U16) => U64,
         (U64, U32) => U64,
         (U128, U8) => U128,
+        (U256, U8) => U256,
         (U128, U16) => U128,
+        (U256, U16) => U256,
         (U128, U32) => U128,
+        (U256, U32) => U256,
     }
 );
 
@@ -499,11 +568,13 @@ crate::operation!(
         (I32, I32) => I32 ("ensure overflows halt", "ensure divide by zero halts"),
         (I64, I64) => I64 ("ensure overflows halt", "ensure divide by zero halts"),
         (I128, I128) => I128 ("ensure overflows halt", "ensure divide by zero halts"),
+        (I256, I256) => I256 ("ensure overflows halt", "ensure divide by zero halts"),
         (U8, U8) => U8 ("ensure divide by zero halts"),
         (U16, U16) => U16 ("ensure divide by zero halts"),
         (U32, U32) => U32 ("ensure divide by zero halts"),
         (U64, U64) => U64 ("ensure divide by zero halts"),
         (U128, U128) => U128 ("ensure divide by zero halts"),
+        (U256, U256) => U256 ("ensure divide by zero halts"),
     }
 );
 
@@ -517,11 +588,13 @@ crate::operation!(
         (I32, I32) => I32 ("ensure divide by zero halts"),
         (I64, I64) => I64 ("ensure divide by zero halts"),
         (I128, I128) => I128 ("ensure divide by zero halts"),
+        (I256, I256) => I256 ("ensure divide by zero halts"),
         (U8, U8) => U8 ("ensure divide by zero halts"),
         (U16, U16) => U16 ("ensure divide by zero halts"),
         (U32, U32) => U32 ("ensure divide by zero halts"),
         (U64, U64) => U64 ("ensure divide by zero halts"),
         (U128, U128) => U128 ("ensure divide by zero halts"),
+        (U256, U256) => U256 ("ensure divide by zero halts"),
     }
 );
 
@@ -543,8 +616,11 @@ crate::operation!(
         (I64, U16) => I64 ("ensure shifting past boundary halts"),
         (I64, U32) => I64 ("ensure shifting past boundary halts"),
         (I128, U8) => I128 ("ensure shifting past boundary halts"),
+        (I256, U8) => I256 ("ensure shifting past boundary halts"),
         (I128, U16) => I128 ("ensure shifting past boundary halts"),
+        (I256, U16) => I256 ("ensure shifting past boundary halts"),
         (I128, U32) => I128 ("ensure shifting past boundary halts"),
+        (I256, U32) => I256 ("ensure shifting past boundary halts"),
         (U8, U8) => U8 ("ensure shifting past boundary halts"),
         (U8, U16) => U8 ("ensure shifting past boundary halts"),
         (U8, U32) => U8 ("ensure shifting past boundary halts"),
@@ -558,8 +634,11 @@ crate::operation!(
         (U64, U16) => U64 ("ensure shifting past boundary halts"),
         (U64, U32) => U64 ("ensure shifting past boundary halts"),
         (U128, U8) => U128 ("ensure shifting past boundary halts"),
+        (U256, U8) => U256 ("ensure shifting past boundary halts"),
         (U128, U16) => U128 ("ensure shifting past boundary halts"),
+        (U256, U16) => U256 ("ensure shifting past boundary halts"),
         (U128, U32) => U128 ("ensure shifting past boundary halts"),
+        (U256, U32) => U256 ("ensure shifting past boundary halts"),
     }
 );
 
@@ -581,8 +660,11 @@ crate::operation!(
         (I64, U16) => I64,
         (I64, U32) => I64,
         (I128, U8) => I128,
+        (I256, U8) => I256,
         (I128, U16) => I128,
+        (I256, U16) => I256,
         (I128, U32) => I128,
+        (I256, U32) => I256,
         (U8, U8) => U8,
         (U8, U16) => U8,
         (U8, U32) => U8,
@@ -596,8 +678,11 @@ crate::operation!(
         (U64, U16) => U64,
         (U64, U32) => U64,
         (U128, U8) => U128,
+        (U256, U8) => U256,
         (U128, U16) => U128,
+        (U256, U16) => U256,
         (U128, U32) => U128,
+        (U256, U32) => U256,
     }
 );
 
@@ -619,8 +704,11 @@ crate::operation!(
         (I64, U16) => I64 ("ensure shifting past boundary halts"),
         (I64, U32) => I64 ("ensure shifting past boundary halts"),
         (I128, U8) => I128 ("ensure shifting past boundary halts"),
+        (I256, U8) => I256 ("ensure shifting past boundary halts"),
         (I128, U16) => I128 ("ensure shifting past boundary halts"),
+        (I256, U16) => I256 ("ensure shifting past boundary halts"),
         (I128, U32) => I128 ("ensure shifting past boundary halts"),
+        (I256, U32) => I256 ("ensure shifting past boundary halts"),
         (U8, U8) => U8 ("ensure shifting past boundary halts"),
         (U8, U16) => U8 ("ensure shifting past boundary halts"),
         (U8, U32) => U8 ("ensure shifting past boundary halts"),
@@ -634,8 +722,11 @@ crate::operation!(
         (U64, U16) => U64 ("ensure shifting past boundary halts"),
         (U64, U32) => U64 ("ensure shifting past boundary halts"),
         (U128, U8) => U128 ("ensure shifting past boundary halts"),
+        (U256, U8) => U256 ("ensure shifting past boundary halts"),
         (U128, U16) => U128 ("ensure shifting past boundary halts"),
+        (U256, U16) => U256 ("ensure shifting past boundary halts"),
         (U128, U32) => U128 ("ensure shifting past boundary halts"),
+        (U256, U32) => U256 ("ensure shifting past boundary halts"),
     }
 );
 
@@ -657,8 +748,11 @@ crate::operation!(
         (I64, U16) => I64,
         (I64, U32) => I64,
         (I128, U8) => I128,
+        (I256, U8) => I256,
         (I128, U16) => I128,
+        (I256, U16) => I256,
         (I128, U32) => I128,
+        (I256, U32) => I256,
         (U8, U8) => U8,
         (U8, U16) => U8,
         (U8, U32) => U8,
@@ -672,11 +766,17 @@ crate::operation!(
         (U64, U16) => U64,
         (U64, U32) => U64,
         (U128, U8) => U128,
+        (U256, U8) => U256,
         (U128, U16) => U128,
+        (U256, U16) => U256,
         (U128, U32) => U128,
+        (U256, U32) => U256,
     }
 );
 
+/// Selects the bytes of `first` from index `second` (inclusive) to index `third` (exclusive), storing the outcome in `destination`.
+pub type Slice<N> = TernaryLiteral<N, SliceOperation<N>>;
+
 /// Squares `first`, storing the outcome in `destination`.
 pub type Square<N> = UnaryLiteral<N, SquareOperation<N>>;
 
@@ -707,11 +807,13 @@ crate::operation!(
         (I32, I32) => I32 ("ensure overflows halt"),
         (I64, I64) => I64 ("ensure overflows halt"),
         (I128, I128) => I128 ("ensure overflows halt"),
+        (I256, I256) => I256 ("ensure overflows halt"),
         (U8, U8) => U8 ("ensure overflows halt"),
         (U16, U16) => U16 ("ensure overflows halt"),
         (U32, U32) => U32 ("ensure overflows halt"),
         (U64, U64) => U64 ("ensure overflows halt"),
         (U128, U128) => U128 ("ensure overflows halt"),
+        (U256, U256) => U256 ("ensure overflows halt"),
         // (Scalar, Scalar) => Scalar,
     }
 );
@@ -726,11 +828,13 @@ crate::operation!(
         (I32, I32) => I32,
         (I64, I64) => I64,
         (I128, I128) => I128,
+        (I256, I256) => I256,
         (U8, U8) => U8,
         (U16, U16) => U16,
         (U32, U32) => U32,
         (U64, U64) => U64,
         (U128, U128) => U128,
+        (U256, U256) => U256,
     }
 );
 
@@ -748,11 +852,13 @@ crate::operation!(
         (Boolean, I32, I32) => I32,
         (Boolean, I64, I64) => I64,
         (Boolean, I128, I128) => I128,
+        (Boolean, I256, I256) => I256,
         (Boolean, U8, U8) => U8,
         (Boolean, U16, U16) => U16,
         (Boolean, U32, U32) => U32,
         (Boolean, U64, U64) => U64,
         (Boolean, U128, U128) => U128,
+        (Boolean, U256, U256) => U256,
         (Boolean, Scalar, Scalar) => Scalar,
         (Boolean, Signature, Signature) => Signature,
         // (Boolean, StringType, StringType) => StringType,
@@ -770,10 +876,12 @@ crate::operation!(
         (I32, I32) => I32,
         (I64, I64) => I64,
         (I128, I128) => I128,
+        (I256, I256) => I256,
         (U8, U8) => U8,
         (U16, U16) => U16,
         (U32, U32) => U32,
         (U64, U64) => U64,
         (U128, U128) => U128,
+        (U256, U256) => U256,
     }
 );
@@ -0,0 +1,493 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+};
+
+/// Wraps the literal `first` into a present optional, storing the outcome in `destination`.
+pub type OptionSome<N> = OptionInstruction<N, { Variant::Some as u8 }>;
+/// Computes whether the optional `first` holds a value, storing the outcome in `destination`.
+pub type OptionIsSome<N> = OptionInstruction<N, { Variant::IsSome as u8 }>;
+/// Unwraps the literal held by the optional `first`, storing the outcome in `destination`.
+pub type OptionUnwrap<N> = OptionInstruction<N, { Variant::Unwrap as u8 }>;
+
+enum Variant {
+    Some,
+    IsSome,
+    Unwrap,
+}
+
+/// Constructs, tests, or unwraps an optional, and stores the outcome in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct OptionInstruction<N: Network, const VARIANT: u8> {
+    /// The operand.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> OptionInstruction<N, VARIANT> {
+    /// Initializes a new `option` instruction.
+    #[inline]
+    pub fn new(operand: Operand<N>, destination: Register<N>) -> Result<Self> {
+        Ok(Self { operands: vec![operand], destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Option("option.some"),
+            1 => Opcode::Option("option.is_some"),
+            2 => Opcode::Option("option.unwrap"),
+            _ => panic!("Invalid 'option' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly one input.
+        debug_assert!(self.operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> OptionInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the input.
+        let input = registers.load_plaintext(stack, &self.operands[0])?;
+
+        // Compute the output.
+        let output = match (VARIANT, input) {
+            (0, Plaintext::Literal(literal, ..)) => Plaintext::some(literal)?,
+            (1, Plaintext::Optional(is_some, ..)) => Plaintext::from(Literal::Boolean(is_some)),
+            (2, Plaintext::Optional(is_some, literal, ..)) => match *is_some {
+                true => Plaintext::from(literal),
+                false => bail!("'{}' failed: the optional of type '{}?' is empty", Self::opcode(), literal.to_type()),
+            },
+            (0, _) => bail!("Instruction '{}' expects a literal operand", Self::opcode()),
+            (1 | 2, _) => bail!("Instruction '{}' expects an optional operand", Self::opcode()),
+            _ => bail!("Invalid 'option' variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::Inject;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the input.
+        let input = registers.load_plaintext_circuit(stack, &self.operands[0])?;
+
+        // Compute the output.
+        let output = match (VARIANT, input) {
+            (0, circuit::Plaintext::Literal(literal, ..)) => {
+                ensure!(!matches!(literal, circuit::Literal::Signature(..)), "An optional cannot contain a signature");
+                circuit::Plaintext::Optional(circuit::Boolean::constant(true), literal, Default::default())
+            }
+            (1, circuit::Plaintext::Optional(is_some, ..)) => circuit::Plaintext::from(circuit::Literal::Boolean(is_some)),
+            (2, circuit::Plaintext::Optional(is_some, literal, ..)) => {
+                // Ensure the optional holds a value.
+                A::assert(is_some);
+                circuit::Plaintext::from(literal)
+            }
+            (0, _) => bail!("Instruction '{}' expects a literal operand", Self::opcode()),
+            (1 | 2, _) => bail!("Instruction '{}' expects an optional operand", Self::opcode()),
+            _ => bail!("Invalid 'option' variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 input, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        let output_type = match (VARIANT, &input_types[0]) {
+            (0, RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Signature))) => {
+                bail!("Instruction '{}' cannot wrap a signature", Self::opcode())
+            }
+            (0, RegisterType::Plaintext(PlaintextType::Literal(literal_type))) => PlaintextType::Optional(*literal_type),
+            (1, RegisterType::Plaintext(PlaintextType::Optional(..))) => PlaintextType::Literal(LiteralType::Boolean),
+            (2, RegisterType::Plaintext(PlaintextType::Optional(literal_type))) => PlaintextType::Literal(*literal_type),
+            (0, input_type) => bail!("Instruction '{}' expects a literal, found '{input_type}'", Self::opcode()),
+            (1 | 2, input_type) => bail!("Instruction '{}' expects an optional, found '{input_type}'", Self::opcode()),
+            _ => bail!("Invalid 'option' variant: {VARIANT}"),
+        };
+        Ok(vec![RegisterType::Plaintext(output_type)])
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for OptionInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the operand from the string.
+        let (string, operand) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![operand], destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for OptionInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for OptionInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for OptionInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {} into {}", Self::opcode(), self.operands[0], self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for OptionInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operand.
+        let operand = Operand::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands: vec![operand], destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for OptionInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
+        }
+        // Write the operand.
+        self.operands[0].write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+/// Constructs an empty optional of the given literal type, storing the outcome in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct OptionNone<N: Network> {
+    /// The destination register.
+    destination: Register<N>,
+    /// The literal type of the optional.
+    literal_type: LiteralType,
+}
+
+impl<N: Network> OptionNone<N> {
+    /// Initializes a new `option.none` instruction.
+    #[inline]
+    pub fn new(destination: Register<N>, literal_type: LiteralType) -> Result<Self> {
+        // Ensure the literal type can be held by an optional.
+        ensure!(literal_type != LiteralType::Signature, "An optional cannot contain a signature");
+        // Return the instruction.
+        Ok(Self { destination, literal_type })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Option("option.none")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &[]
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the literal type of the optional.
+    #[inline]
+    pub const fn literal_type(&self) -> LiteralType {
+        self.literal_type
+    }
+}
+
+impl<N: Network> OptionNone<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::none(self.literal_type)?))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::Inject;
+
+        // Construct the empty optional as a constant.
+        let output = circuit::Plaintext::constant(Plaintext::none(self.literal_type)?);
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if !input_types.is_empty() {
+            bail!("Instruction '{}' expects 0 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the literal type can be held by an optional.
+        ensure!(self.literal_type != LiteralType::Signature, "An optional cannot contain a signature");
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Optional(self.literal_type))])
+    }
+}
+
+impl<N: Network> Parser for OptionNone<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the optional type from the string.
+        let (string, literal_type) = map_res(PlaintextType::<N>::parse, |plaintext_type| match plaintext_type {
+            PlaintextType::Optional(literal_type) => Ok(literal_type),
+            _ => Err(error("Instruction 'option.none' expects an optional type")),
+        })(string)?;
+
+        Ok((string, Self { destination, literal_type }))
+    }
+}
+
+impl<N: Network> FromStr for OptionNone<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for OptionNone<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for OptionNone<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} into {} as {}?", Self::opcode(), self.destination, self.literal_type)
+    }
+}
+
+impl<N: Network> FromBytes for OptionNone<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Read the literal type.
+        let literal_type = LiteralType::read_le(&mut reader)?;
+
+        // Return the operation.
+        Self::new(destination, literal_type).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for OptionNone<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the destination register.
+        self.destination.write_le(&mut writer)?;
+        // Write the literal type.
+        self.literal_type.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, option) = OptionSome::<CurrentNetwork>::parse("option.some r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(option.operands, vec![Operand::Register(Register::Locator(0))], "The operand is incorrect");
+        assert_eq!(option.destination, Register::Locator(1), "The destination register is incorrect");
+
+        let (string, option) = OptionIsSome::<CurrentNetwork>::parse("option.is_some r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(option.operands, vec![Operand::Register(Register::Locator(0))], "The operand is incorrect");
+
+        let (string, option) = OptionUnwrap::<CurrentNetwork>::parse("option.unwrap r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(option.operands, vec![Operand::Register(Register::Locator(0))], "The operand is incorrect");
+
+        let (string, option) = OptionNone::<CurrentNetwork>::parse("option.none into r1 as u64?").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(option.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(option.literal_type, LiteralType::U64, "The literal type is incorrect");
+
+        // Ensure the destination type must be an optional.
+        assert!(OptionNone::<CurrentNetwork>::parse("option.none into r1 as u64").is_err());
+        assert!(OptionNone::<CurrentNetwork>::parse("option.none into r1 as signature?").is_err());
+    }
+}
@@ -0,0 +1,409 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Identifier, LiteralType, PlaintextType, Register, RegisterType},
+    types::U32,
+};
+
+/// The maximum number of iterations that can be performed by a `repeat` instruction.
+pub const MAX_REPEAT_ITERATIONS: u32 = 256;
+
+/// Repeatedly calls a closure for a fixed number of iterations, i.e. `repeat 4u32 accumulate r0 r1 into r2;`
+///
+/// The closure receives the operands, followed by the current iteration as a `u32` (starting from `0u32`).
+/// The leading operands are the loop-carried state: after each iteration, the outputs of the closure
+/// replace the leading operands, and after the final iteration, the outputs are stored in the destinations.
+/// The remaining operands are passed unchanged to every iteration.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Repeat<N: Network> {
+    /// The number of iterations.
+    iterations: U32<N>,
+    /// The closure name.
+    closure: Identifier<N>,
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination registers.
+    destinations: Vec<Register<N>>,
+}
+
+impl<N: Network> Repeat<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Repeat
+    }
+
+    /// Returns the number of iterations.
+    #[inline]
+    pub fn iterations(&self) -> u32 {
+        *self.iterations
+    }
+
+    /// Returns the closure name.
+    #[inline]
+    pub const fn closure(&self) -> &Identifier<N> {
+        &self.closure
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+
+    /// Returns the destination registers.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        self.destinations.clone()
+    }
+}
+
+impl<N: Network> Repeat<N> {
+    /// Evaluates the instruction.
+    pub fn evaluate(&self, _stack: &impl StackProgram<N>, _registers: &mut impl RegistersLoad<N>) -> Result<()> {
+        bail!("Forbidden operation: Evaluate cannot invoke a 'repeat' directly. Use 'repeat' in 'Stack' instead.")
+    }
+
+    /// Executes the instruction.
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        _stack: &impl StackProgram<N>,
+        _registers: &mut impl RegistersLoadCircuit<N, A>,
+    ) -> Result<()> {
+        bail!("Forbidden operation: Execute cannot invoke a 'repeat' directly. Use 'repeat' in 'Stack' instead.")
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut impl RegistersLoad<N>,
+    ) -> Result<()> {
+        bail!("Forbidden operation: Finalize cannot invoke a 'repeat' directly. Use 'repeat' in 'Stack' instead.")
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of iterations is within the bounds.
+        if self.iterations() == 0 || self.iterations() > MAX_REPEAT_ITERATIONS {
+            bail!("The number of iterations in 'repeat' must be between 1 and {MAX_REPEAT_ITERATIONS}")
+        }
+        // Ensure the number of operands matches the number of input types.
+        if self.operands.len() != input_types.len() {
+            bail!("Expected {} input types, found {}", self.operands.len(), input_types.len())
+        }
+        // Ensure the loop-carried state is a prefix of the operands.
+        if self.destinations.len() > self.operands.len() {
+            bail!("Expected at most {} destinations, found {}", self.operands.len(), self.destinations.len())
+        }
+
+        // Retrieve the closure.
+        let closure = stack.program().get_closure(&self.closure)?;
+        // Ensure the number of inputs matches the number of operands, plus the iteration.
+        if closure.inputs().len() != self.operands.len() + 1 {
+            bail!("Expected {} inputs, found {}", closure.inputs().len(), self.operands.len() + 1)
+        }
+        // Ensure the number of outputs matches the number of destinations.
+        if closure.outputs().len() != self.destinations.len() {
+            bail!("Expected {} outputs, found {}", closure.outputs().len(), self.destinations.len())
+        }
+        // Ensure the operand types match the input types of the closure.
+        for (input, input_type) in closure
+            .inputs()
+            .iter()
+            .zip_eq(input_types.iter().chain([&RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32))]))
+        {
+            if input.register_type() != input_type {
+                bail!("Closure '{}' expects '{}', found '{input_type}'", self.closure, input.register_type())
+            }
+        }
+        // Ensure the output types match the input types of the loop-carried state.
+        for (output, input) in closure.outputs().iter().zip(closure.inputs()) {
+            if output.register_type() != input.register_type() {
+                bail!(
+                    "Closure '{}' must output '{}' to repeat, found '{}'",
+                    self.closure,
+                    input.register_type(),
+                    output.register_type()
+                )
+            }
+        }
+        // Return the output register types.
+        Ok(closure.outputs().iter().map(|output| output.register_type()).cloned().collect())
+    }
+}
+
+impl<N: Network> Parser for Repeat<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        /// Parses a destination register from the string.
+        fn parse_destination<N: Network>(string: &str) -> ParserResult<Register<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the destination from the string.
+            Register::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the number of iterations from the string.
+        let (string, iterations) = map_res(U32::parse, |iterations: U32<N>| {
+            // Ensure the number of iterations is within the bounds.
+            match *iterations > 0 && *iterations <= MAX_REPEAT_ITERATIONS {
+                true => Ok(iterations),
+                false => Err(error("Failed to parse 'repeat' opcode: invalid number of iterations")),
+            }
+        })(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the name of the closure from the string.
+        let (string, closure) = Identifier::parse(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = map_res(many0(complete(parse_operand)), |operands: Vec<Operand<N>>| {
+            // Ensure the number of operands is within the bounds.
+            match operands.len() < N::MAX_OPERANDS {
+                true => Ok(operands),
+                false => Err(error("Failed to parse 'repeat' opcode: too many operands")),
+            }
+        })(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Optionally parse the "into" from the string.
+        let (string, destinations) = match opt(tag("into"))(string)? {
+            // If the "into" was not parsed, return the string and an empty vector of destinations.
+            (string, None) => (string, vec![]),
+            // If the "into" was parsed, parse the destinations from the string.
+            (string, Some(_)) => {
+                // Parse the whitespace from the string.
+                let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                // Parse the destinations from the string.
+                let (string, destinations) =
+                    map_res(many1(complete(parse_destination)), |destinations: Vec<Register<N>>| {
+                        // Ensure the number of destinations is within the bounds.
+                        match destinations.len() <= operands.len() {
+                            true => Ok(destinations),
+                            false => Err(error("Failed to parse 'repeat' opcode: too many destinations")),
+                        }
+                    })(string)?;
+                // Return the string and the destinations.
+                (string, destinations)
+            }
+        };
+
+        Ok((string, Self { iterations, closure, operands, destinations }))
+    }
+}
+
+impl<N: Network> FromStr for Repeat<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Repeat<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Repeat<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() >= N::MAX_OPERANDS {
+            return Err(fmt::Error);
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.len() > self.operands.len() {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {} {}", Self::opcode(), self.iterations, self.closure)?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+        if !self.destinations.is_empty() {
+            write!(f, " into")?;
+            self.destinations.iter().try_for_each(|destination| write!(f, " {destination}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for Repeat<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of iterations.
+        let iterations = U32::read_le(&mut reader)?;
+        // Ensure the number of iterations is within the bounds.
+        if *iterations == 0 || *iterations > MAX_REPEAT_ITERATIONS {
+            return Err(error(format!("The number of iterations must be between 1 and {MAX_REPEAT_ITERATIONS}")));
+        }
+
+        // Read the closure name.
+        let closure = Identifier::read_le(&mut reader)?;
+
+        // Read the number of operands.
+        let num_operands = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of operands is within the bounds.
+        if num_operands >= N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be < {}", N::MAX_OPERANDS)));
+        }
+
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(num_operands);
+        // Read the operands.
+        for _ in 0..num_operands {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+
+        // Read the number of destination registers.
+        let num_destinations = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of destinations is within the bounds.
+        if num_destinations > num_operands {
+            return Err(error(format!("The number of destinations must be <= {num_operands}")));
+        }
+
+        // Initialize the vector for the destinations.
+        let mut destinations = Vec::with_capacity(num_destinations);
+        // Read the destination registers.
+        for _ in 0..num_destinations {
+            destinations.push(Register::read_le(&mut reader)?);
+        }
+
+        // Return the operation.
+        Ok(Self { iterations, closure, operands, destinations })
+    }
+}
+
+impl<N: Network> ToBytes for Repeat<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() >= N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be < {}", N::MAX_OPERANDS)));
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.len() > self.operands.len() {
+            return Err(error(format!("The number of destinations must be <= {}", self.operands.len())));
+        }
+
+        // Write the number of iterations.
+        self.iterations.write_le(&mut writer)?;
+        // Write the closure name.
+        self.closure.write_le(&mut writer)?;
+        // Write the number of operands.
+        u8::try_from(self.operands.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the number of destination register.
+        u8::try_from(self.destinations.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the destination registers.
+        self.destinations.iter().try_for_each(|destination| destination.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::Literal};
+
+    type CurrentNetwork = MainnetV0;
+
+    const TEST_CASES: &[&str] = &[
+        "repeat 1u32 foo",
+        "repeat 4u32 foo r0",
+        "repeat 4u32 foo r0.owner",
+        "repeat 8u32 foo r0 r1",
+        "repeat 8u32 foo r0 into r1",
+        "repeat 16u32 foo r0 r1 into r2",
+        "repeat 16u32 foo r0 r1 into r2 r3",
+        "repeat 256u32 foo r0 r1 r2 into r3 r4 r5",
+    ];
+
+    #[test]
+    fn test_parse() {
+        let (string, repeat) = Repeat::<CurrentNetwork>::parse("repeat 4u32 sum r0 0u64 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(repeat.iterations(), 4);
+        assert_eq!(repeat.closure(), &Identifier::from_str("sum").unwrap());
+        assert_eq!(repeat.operands(), &[
+            Operand::Register(Register::Locator(0)),
+            Operand::Literal(Literal::from_str("0u64").unwrap())
+        ]);
+        assert_eq!(repeat.destinations(), vec![Register::Locator(1)]);
+
+        // Ensure the number of iterations is bounded.
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 0u32 sum r0 into r1").is_err());
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 257u32 sum r0 into r1").is_err());
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 4u64 sum r0 into r1").is_err());
+        assert!(Repeat::<CurrentNetwork>::parse("repeat r0 sum r0 into r1").is_err());
+        // Ensure the destinations are bounded by the loop-carried state.
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 4u32 sum r0 into r1 r2").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        for expected in TEST_CASES {
+            assert_eq!(Repeat::<CurrentNetwork>::from_str(expected).unwrap().to_string(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_bytes() {
+        for case in TEST_CASES {
+            let expected = Repeat::<CurrentNetwork>::from_str(case).unwrap();
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, Repeat::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
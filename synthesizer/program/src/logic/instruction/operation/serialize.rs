@@ -0,0 +1,698 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{Boolean, U8},
+};
+
+use indexmap::IndexMap;
+
+/// Serializes `first` into its little-endian bits, storing the outcome in `destination`.
+pub type SerializeBits<N> = SerializeInstruction<N, { Variant::SerializeBits as u8 }>;
+/// Serializes `first` into its little-endian bits packed into bytes, storing the outcome in `destination`.
+pub type SerializeBytes<N> = SerializeInstruction<N, { Variant::SerializeBytes as u8 }>;
+/// Deserializes the little-endian bits in `first` into the destination type, storing the outcome in `destination`.
+pub type DeserializeBits<N> = SerializeInstruction<N, { Variant::DeserializeBits as u8 }>;
+/// Deserializes the little-endian bits packed in the bytes of `first`, storing the outcome in `destination`.
+pub type DeserializeBytes<N> = SerializeInstruction<N, { Variant::DeserializeBytes as u8 }>;
+
+enum Variant {
+    SerializeBits,
+    SerializeBytes,
+    DeserializeBits,
+    DeserializeBytes,
+}
+
+/// Serializes a plaintext into a `[boolean; N]` or `[u8; N]` array, or deserializes such an array into a plaintext.
+///
+/// The encoding is the concatenation of the `to_bits_le` encodings of the literals in the plaintext,
+/// visiting struct members in declaration order and array elements in index order.
+/// When packed into bytes, each byte holds 8 consecutive bits, and the final byte is padded with zeros.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SerializeInstruction<N: Network, const VARIANT: u8> {
+    /// The operand.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+    /// The destination register type.
+    destination_type: PlaintextType<N>,
+}
+
+impl<N: Network, const VARIANT: u8> SerializeInstruction<N, VARIANT> {
+    /// Initializes a new `serialize` instruction.
+    #[inline]
+    pub fn new(operand: Operand<N>, destination: Register<N>, destination_type: PlaintextType<N>) -> Result<Self> {
+        // Ensure the destination type is valid.
+        Self::check_destination_type(&destination_type)?;
+        // Return the instruction.
+        Ok(Self { operands: vec![operand], destination, destination_type })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Serialize("serialize.bits"),
+            1 => Opcode::Serialize("serialize.bytes"),
+            2 => Opcode::Serialize("deserialize.bits"),
+            3 => Opcode::Serialize("deserialize.bytes"),
+            _ => panic!("Invalid 'serialize' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly one input.
+        debug_assert!(self.operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the destination register type.
+    #[inline]
+    pub const fn destination_type(&self) -> &PlaintextType<N> {
+        &self.destination_type
+    }
+}
+
+impl<N: Network, const VARIANT: u8> SerializeInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the input.
+        let input = registers.load_plaintext(stack, &self.operands[0])?;
+
+        // Compute the output.
+        let output = match VARIANT {
+            0 | 1 => {
+                // Serialize the input into bits.
+                let mut bits = Vec::new();
+                Self::write_bits(&input, &mut bits)?;
+                // Ensure the number of bits matches the destination type.
+                let num_elements = Self::num_elements(bits.len());
+                ensure!(
+                    num_elements == self.num_destination_elements()?,
+                    "Instruction '{}' serialized '{input}' into {num_elements} elements, expected '{}'",
+                    Self::opcode(),
+                    self.destination_type
+                );
+                // Construct the array elements.
+                let elements = match VARIANT {
+                    0 => bits.into_iter().map(|bit| Plaintext::from(Literal::Boolean(Boolean::new(bit)))).collect(),
+                    _ => {
+                        // Pad the bits with zeros, up to the next byte.
+                        bits.resize(num_elements * 8, false);
+                        bits.chunks(8)
+                            .map(|byte| Ok(Plaintext::from(Literal::U8(U8::from_bits_le(byte)?))))
+                            .collect::<Result<Vec<_>>>()?
+                    }
+                };
+                Plaintext::Array(elements, Default::default())
+            }
+            2 | 3 => {
+                // Retrieve the array elements.
+                let elements = match input {
+                    Plaintext::Array(elements, ..) => elements,
+                    _ => bail!("Instruction '{}' expects an array operand", Self::opcode()),
+                };
+                // Unpack the elements into bits.
+                let mut bits = Vec::with_capacity(elements.len() * 8);
+                for element in elements.iter() {
+                    match (VARIANT, element) {
+                        (2, Plaintext::Literal(Literal::Boolean(bit), ..)) => bits.push(**bit),
+                        (3, Plaintext::Literal(Literal::U8(byte), ..)) => byte.write_bits_le(&mut bits),
+                        _ => bail!("Instruction '{}' found an invalid array element '{element}'", Self::opcode()),
+                    }
+                }
+                // Ensure the number of elements matches the destination type.
+                let num_bits = Self::size_in_bits(stack, &self.destination_type)?;
+                ensure!(
+                    elements.len() == Self::num_elements(num_bits),
+                    "Instruction '{}' expects {} elements to deserialize '{}', found {}",
+                    Self::opcode(),
+                    Self::num_elements(num_bits),
+                    self.destination_type,
+                    elements.len()
+                );
+                // Ensure the padding bits are zero.
+                ensure!(bits[num_bits..].iter().all(|bit| !bit), "'{}' found nonzero padding bits", Self::opcode());
+                // Deserialize the plaintext from the bits.
+                Self::read_bits(stack, &self.destination_type, &mut &bits[..num_bits])?
+            }
+            _ => bail!("Invalid 'serialize' variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::{
+            traits::{FromBits, ToBits},
+            Inject,
+        };
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the input.
+        let input = registers.load_plaintext_circuit(stack, &self.operands[0])?;
+
+        // Compute the output.
+        let output = match VARIANT {
+            0 | 1 => {
+                // Serialize the input into bits.
+                let mut bits = Vec::new();
+                Self::write_bits_circuit(&input, &mut bits)?;
+                // Ensure the number of bits matches the destination type.
+                let num_elements = Self::num_elements(bits.len());
+                ensure!(
+                    num_elements == self.num_destination_elements()?,
+                    "Instruction '{}' serialized the operand into {num_elements} elements, expected '{}'",
+                    Self::opcode(),
+                    self.destination_type
+                );
+                // Construct the array elements.
+                let elements = match VARIANT {
+                    0 => bits.into_iter().map(|bit| circuit::Plaintext::from(circuit::Literal::Boolean(bit))).collect(),
+                    _ => {
+                        // Pad the bits with zeros, up to the next byte.
+                        bits.resize(num_elements * 8, circuit::Boolean::constant(false));
+                        bits.chunks(8)
+                            .map(|byte| circuit::Plaintext::from(circuit::Literal::U8(circuit::U8::from_bits_le(byte))))
+                            .collect()
+                    }
+                };
+                circuit::Plaintext::Array(elements, Default::default())
+            }
+            2 | 3 => {
+                // Retrieve the array elements.
+                let elements = match input {
+                    circuit::Plaintext::Array(elements, ..) => elements,
+                    _ => bail!("Instruction '{}' expects an array operand", Self::opcode()),
+                };
+                // Unpack the elements into bits.
+                let mut bits = Vec::with_capacity(elements.len() * 8);
+                for element in elements.iter() {
+                    match (VARIANT, element) {
+                        (2, circuit::Plaintext::Literal(circuit::Literal::Boolean(bit), ..)) => bits.push(bit.clone()),
+                        (3, circuit::Plaintext::Literal(circuit::Literal::U8(byte), ..)) => {
+                            byte.write_bits_le(&mut bits)
+                        }
+                        _ => bail!("Instruction '{}' found an invalid array element", Self::opcode()),
+                    }
+                }
+                // Ensure the number of elements matches the destination type.
+                let num_bits = Self::size_in_bits(stack, &self.destination_type)?;
+                ensure!(
+                    elements.len() == Self::num_elements(num_bits),
+                    "Instruction '{}' expects {} elements to deserialize '{}', found {}",
+                    Self::opcode(),
+                    Self::num_elements(num_bits),
+                    self.destination_type,
+                    elements.len()
+                );
+                // Ensure the padding bits are zero.
+                for bit in &bits[num_bits..] {
+                    A::assert(!bit);
+                }
+                // Deserialize the plaintext from the bits.
+                Self::read_bits_circuit(stack, &self.destination_type, &mut &bits[..num_bits])?
+            }
+            _ => bail!("Invalid 'serialize' variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 input, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+        // Ensure the destination type is valid.
+        Self::check_destination_type(&self.destination_type)?;
+
+        // Retrieve the plaintext input type.
+        let input_type = match &input_types[0] {
+            RegisterType::Plaintext(plaintext_type) => plaintext_type,
+            input_type => bail!("Instruction '{}' expects a plaintext, found '{input_type}'", Self::opcode()),
+        };
+
+        match VARIANT {
+            0 | 1 => {
+                // Ensure the size of the input type matches the destination type.
+                let num_elements = Self::num_elements(Self::size_in_bits(stack, input_type)?);
+                ensure!(
+                    num_elements == self.num_destination_elements()?,
+                    "Instruction '{}' serializes '{input_type}' into {num_elements} elements, expected '{}'",
+                    Self::opcode(),
+                    self.destination_type
+                );
+            }
+            2 | 3 => {
+                // Ensure the input type is an array of the expected element type.
+                let expected_element_type = PlaintextType::Literal(Self::element_type());
+                let num_elements = match input_type {
+                    PlaintextType::Array(array_type) if array_type.next_element_type() == &expected_element_type => {
+                        **array_type.length() as usize
+                    }
+                    _ => bail!(
+                        "Instruction '{}' expects an array of '{expected_element_type}', found '{input_type}'",
+                        Self::opcode()
+                    ),
+                };
+                // Ensure the size of the destination type matches the input type.
+                let expected_num_elements = Self::num_elements(Self::size_in_bits(stack, &self.destination_type)?);
+                ensure!(
+                    num_elements == expected_num_elements,
+                    "Instruction '{}' expects {expected_num_elements} elements for '{}', found '{input_type}'",
+                    Self::opcode(),
+                    self.destination_type
+                );
+            }
+            _ => bail!("Invalid 'serialize' variant: {VARIANT}"),
+        }
+
+        Ok(vec![RegisterType::Plaintext(self.destination_type.clone())])
+    }
+}
+
+impl<N: Network, const VARIANT: u8> SerializeInstruction<N, VARIANT> {
+    /// Returns the literal type of the array elements.
+    const fn element_type() -> LiteralType {
+        match VARIANT {
+            0 | 2 => LiteralType::Boolean,
+            _ => LiteralType::U8,
+        }
+    }
+
+    /// Returns the number of array elements needed to hold the given number of bits.
+    const fn num_elements(num_bits: usize) -> usize {
+        match VARIANT {
+            0 | 2 => num_bits,
+            _ => (num_bits + 7) / 8,
+        }
+    }
+
+    /// Returns the number of elements in the destination array.
+    fn num_destination_elements(&self) -> Result<usize> {
+        match &self.destination_type {
+            PlaintextType::Array(array_type) => Ok(**array_type.length() as usize),
+            _ => bail!("Instruction '{}' expects an array destination type", Self::opcode()),
+        }
+    }
+
+    /// Ensures the destination type is valid for the instruction.
+    fn check_destination_type(destination_type: &PlaintextType<N>) -> Result<()> {
+        match VARIANT {
+            0 | 1 => {
+                // Ensure the destination type is an array of the expected element type.
+                let expected_element_type = PlaintextType::Literal(Self::element_type());
+                match destination_type {
+                    PlaintextType::Array(array_type) if array_type.next_element_type() == &expected_element_type => {
+                        Ok(())
+                    }
+                    _ => bail!(
+                        "Instruction '{}' expects an array of '{expected_element_type}', found '{destination_type}'",
+                        Self::opcode()
+                    ),
+                }
+            }
+            2 | 3 => match destination_type {
+                PlaintextType::Literal(LiteralType::String) => {
+                    bail!("Instruction '{}' cannot deserialize a string", Self::opcode())
+                }
+                PlaintextType::Optional(..) => bail!("Instruction '{}' cannot deserialize an optional", Self::opcode()),
+                _ => Ok(()),
+            },
+            _ => bail!("Invalid 'serialize' variant: {VARIANT}"),
+        }
+    }
+
+    /// Returns the number of bits in the serialization of the given plaintext type.
+    fn size_in_bits(stack: &impl StackProgram<N>, plaintext_type: &PlaintextType<N>) -> Result<usize> {
+        match plaintext_type {
+            PlaintextType::Literal(LiteralType::String) => {
+                bail!("Instruction '{}' does not support strings, as they are not of fixed size", Self::opcode())
+            }
+            PlaintextType::Literal(literal_type) => Ok(literal_type.size_in_bits::<N>() as usize),
+            PlaintextType::Struct(struct_name) => {
+                stack.program().get_struct(struct_name)?.members().values().try_fold(0usize, |acc, member_type| {
+                    Ok(acc.saturating_add(Self::size_in_bits(stack, member_type)?))
+                })
+            }
+            PlaintextType::Array(array_type) => Ok(Self::size_in_bits(stack, array_type.next_element_type())?
+                .saturating_mul(**array_type.length() as usize)),
+            PlaintextType::Optional(..) => bail!("Instruction '{}' does not support optionals", Self::opcode()),
+        }
+    }
+
+    /// Writes the little-endian bits of the given plaintext.
+    fn write_bits(plaintext: &Plaintext<N>, bits: &mut Vec<bool>) -> Result<()> {
+        match plaintext {
+            Plaintext::Literal(Literal::String(..), ..) => {
+                bail!("Instruction '{}' does not support strings, as they are not of fixed size", Self::opcode())
+            }
+            Plaintext::Literal(literal, ..) => literal.write_bits_le(bits),
+            Plaintext::Struct(members, ..) => {
+                for member in members.values() {
+                    Self::write_bits(member, bits)?;
+                }
+            }
+            Plaintext::Array(elements, ..) => {
+                for element in elements.iter() {
+                    Self::write_bits(element, bits)?;
+                }
+            }
+            Plaintext::Optional(..) => bail!("Instruction '{}' does not support optionals", Self::opcode()),
+        }
+        Ok(())
+    }
+
+    /// Reads a plaintext of the given type from the front of the little-endian bits.
+    fn read_bits(
+        stack: &impl StackProgram<N>,
+        plaintext_type: &PlaintextType<N>,
+        bits: &mut &[bool],
+    ) -> Result<Plaintext<N>> {
+        match plaintext_type {
+            PlaintextType::Literal(literal_type) => {
+                // Split off the bits of the literal.
+                let num_bits = Self::size_in_bits(stack, plaintext_type)?;
+                ensure!(bits.len() >= num_bits, "Instruction '{}' ran out of bits", Self::opcode());
+                let (literal_bits, remaining) = bits.split_at(num_bits);
+                *bits = remaining;
+                // Construct the literal.
+                Ok(Plaintext::from(Literal::from_bits_le(literal_type.type_id(), literal_bits)?))
+            }
+            PlaintextType::Struct(struct_name) => {
+                // Read the members, in declaration order.
+                let mut members = IndexMap::new();
+                for (member_name, member_type) in stack.program().get_struct(struct_name)?.members() {
+                    members.insert(*member_name, Self::read_bits(stack, member_type, bits)?);
+                }
+                Ok(Plaintext::Struct(members, Default::default()))
+            }
+            PlaintextType::Array(array_type) => {
+                // Read the elements, in index order.
+                let elements = (0..**array_type.length())
+                    .map(|_| Self::read_bits(stack, array_type.next_element_type(), bits))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Plaintext::Array(elements, Default::default()))
+            }
+            PlaintextType::Optional(..) => bail!("Instruction '{}' does not support optionals", Self::opcode()),
+        }
+    }
+
+    /// Writes the little-endian bits of the given plaintext circuit.
+    fn write_bits_circuit<A: circuit::Aleo<Network = N>>(
+        plaintext: &circuit::Plaintext<A>,
+        bits: &mut Vec<circuit::Boolean<A>>,
+    ) -> Result<()> {
+        use circuit::traits::ToBits;
+
+        match plaintext {
+            circuit::Plaintext::Literal(circuit::Literal::String(..), ..) => {
+                bail!("Instruction '{}' does not support strings, as they are not of fixed size", Self::opcode())
+            }
+            circuit::Plaintext::Literal(literal, ..) => literal.write_bits_le(bits),
+            circuit::Plaintext::Struct(members, ..) => {
+                for member in members.values() {
+                    Self::write_bits_circuit(member, bits)?;
+                }
+            }
+            circuit::Plaintext::Array(elements, ..) => {
+                for element in elements.iter() {
+                    Self::write_bits_circuit(element, bits)?;
+                }
+            }
+            circuit::Plaintext::Optional(..) => bail!("Instruction '{}' does not support optionals", Self::opcode()),
+        }
+        Ok(())
+    }
+
+    /// Reads a plaintext circuit of the given type from the front of the little-endian bits.
+    fn read_bits_circuit<A: circuit::Aleo<Network = N>>(
+        stack: &impl StackProgram<N>,
+        plaintext_type: &PlaintextType<N>,
+        bits: &mut &[circuit::Boolean<A>],
+    ) -> Result<circuit::Plaintext<A>> {
+        use circuit::Inject;
+
+        match plaintext_type {
+            PlaintextType::Literal(literal_type) => {
+                // Split off the bits of the literal.
+                let num_bits = Self::size_in_bits(stack, plaintext_type)?;
+                ensure!(bits.len() >= num_bits, "Instruction '{}' ran out of bits", Self::opcode());
+                let (literal_bits, remaining) = bits.split_at(num_bits);
+                *bits = remaining;
+                // Construct the literal.
+                let variant = circuit::U8::constant(U8::new(literal_type.type_id()));
+                Ok(circuit::Plaintext::from(circuit::Literal::from_bits_le(&variant, literal_bits)))
+            }
+            PlaintextType::Struct(struct_name) => {
+                // Read the members, in declaration order.
+                let mut members = IndexMap::new();
+                for (member_name, member_type) in stack.program().get_struct(struct_name)?.members() {
+                    members.insert(
+                        circuit::Identifier::constant(*member_name),
+                        Self::read_bits_circuit(stack, member_type, bits)?,
+                    );
+                }
+                Ok(circuit::Plaintext::Struct(members, Default::default()))
+            }
+            PlaintextType::Array(array_type) => {
+                // Read the elements, in index order.
+                let elements = (0..**array_type.length())
+                    .map(|_| Self::read_bits_circuit(stack, array_type.next_element_type(), bits))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(circuit::Plaintext::Array(elements, Default::default()))
+            }
+            PlaintextType::Optional(..) => bail!("Instruction '{}' does not support optionals", Self::opcode()),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for SerializeInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the operand from the string.
+        let (string, operand) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register type from the string.
+        let (string, destination_type) = map_res(PlaintextType::parse, |destination_type| {
+            Self::check_destination_type(&destination_type).map(|_| destination_type)
+        })(string)?;
+
+        Ok((string, Self { operands: vec![operand], destination, destination_type }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for SerializeInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for SerializeInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for SerializeInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {} into {} as {}", Self::opcode(), self.operands[0], self.destination, self.destination_type)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for SerializeInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operand.
+        let operand = Operand::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Read the destination register type.
+        let destination_type = PlaintextType::read_le(&mut reader)?;
+
+        // Return the operation.
+        Self::new(operand, destination, destination_type).map_err(error)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for SerializeInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
+        }
+        // Write the operand.
+        self.operands[0].write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)?;
+        // Write the destination register type.
+        self.destination_type.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, serialize) =
+            SerializeBits::<CurrentNetwork>::parse("serialize.bits r0 into r1 as [boolean; 8u32]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(serialize.operands, vec![Operand::Register(Register::Locator(0))], "The operand is incorrect");
+        assert_eq!(serialize.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(
+            serialize.destination_type,
+            PlaintextType::from_str("[boolean; 8u32]").unwrap(),
+            "The destination type is incorrect"
+        );
+
+        let (string, serialize) =
+            SerializeBytes::<CurrentNetwork>::parse("serialize.bytes r0 into r1 as [u8; 32u32]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(serialize.destination_type, PlaintextType::from_str("[u8; 32u32]").unwrap());
+
+        let (string, deserialize) =
+            DeserializeBits::<CurrentNetwork>::parse("deserialize.bits r0 into r1 as u8").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(deserialize.destination_type, PlaintextType::Literal(LiteralType::U8));
+
+        let (string, deserialize) =
+            DeserializeBytes::<CurrentNetwork>::parse("deserialize.bytes r0 into r1 as token").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(deserialize.destination_type, PlaintextType::from_str("token").unwrap());
+
+        // Ensure the serialized destination type must be an array of the expected element type.
+        assert!(SerializeBits::<CurrentNetwork>::parse("serialize.bits r0 into r1 as [u8; 8u32]").is_err());
+        assert!(SerializeBytes::<CurrentNetwork>::parse("serialize.bytes r0 into r1 as [boolean; 8u32]").is_err());
+        assert!(SerializeBytes::<CurrentNetwork>::parse("serialize.bytes r0 into r1 as u8").is_err());
+        // Ensure the deserialized destination type cannot be a string or an optional.
+        assert!(DeserializeBits::<CurrentNetwork>::parse("deserialize.bits r0 into r1 as string").is_err());
+        assert!(DeserializeBytes::<CurrentNetwork>::parse("deserialize.bytes r0 into r1 as u8?").is_err());
+    }
+
+    #[test]
+    fn test_bytes() {
+        for instruction in ["serialize.bytes r0 into r1 as [u8; 4u32]", "serialize.bytes r2 into r3 as [u8; 32u32]"] {
+            let expected = SerializeBytes::<CurrentNetwork>::from_str(instruction).unwrap();
+            let candidate = SerializeBytes::<CurrentNetwork>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+            assert_eq!(expected, candidate);
+            assert_eq!(instruction, candidate.to_string());
+        }
+        for instruction in ["deserialize.bits r0 into r1 as u16", "deserialize.bits r2 into r3 as [boolean; 4u32]"] {
+            let expected = DeserializeBits::<CurrentNetwork>::from_str(instruction).unwrap();
+            let candidate = DeserializeBits::<CurrentNetwork>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+            assert_eq!(expected, candidate);
+            assert_eq!(instruction, candidate.to_string());
+        }
+    }
+}
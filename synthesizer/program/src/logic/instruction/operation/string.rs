@@ -0,0 +1,226 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{Opcode, Operation};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType},
+};
+
+use core::marker::PhantomData;
+
+/// The implementation of the `concat` operation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ConcatOperation<N: Network>(PhantomData<N>);
+
+impl<N: Network> Operation<N, Literal<N>, LiteralType, 2> for ConcatOperation<N> {
+    /// The opcode of the operation.
+    const OPCODE: Opcode = Opcode::Literal("concat");
+
+    /// Returns the result of evaluating the operation on the given inputs.
+    #[inline]
+    fn evaluate(inputs: &[Literal<N>; 2]) -> Result<Literal<N>> {
+        match inputs {
+            [Literal::String(first), Literal::String(second)] => Ok(Literal::String(first.concat(second))),
+            _ => bail!("Invalid operands for the '{}' instruction", Self::OPCODE),
+        }
+    }
+
+    /// Returns the result of executing the operation on the given circuit inputs.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(inputs: &[circuit::Literal<A>; 2]) -> Result<circuit::Literal<A>> {
+        match inputs {
+            [circuit::Literal::String(first), circuit::Literal::String(second)] => {
+                Ok(circuit::Literal::String(first.concat(second)))
+            }
+            _ => bail!("Invalid operands for the '{}' instruction", Self::OPCODE),
+        }
+    }
+
+    /// Returns the output type from the given input types.
+    #[inline]
+    fn output_type(inputs: &[LiteralType; 2]) -> Result<LiteralType> {
+        match inputs {
+            [LiteralType::String, LiteralType::String] => Ok(LiteralType::String),
+            _ => bail!("Invalid operand types for the '{}' instruction", Self::OPCODE),
+        }
+    }
+}
+
+/// The implementation of the `len` operation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct LenOperation<N: Network>(PhantomData<N>);
+
+impl<N: Network> Operation<N, Literal<N>, LiteralType, 1> for LenOperation<N> {
+    /// The opcode of the operation.
+    const OPCODE: Opcode = Opcode::Literal("len");
+
+    /// Returns the result of evaluating the operation on the given inputs.
+    #[inline]
+    fn evaluate(inputs: &[Literal<N>; 1]) -> Result<Literal<N>> {
+        match inputs {
+            [Literal::String(first)] => Ok(Literal::U32(first.length())),
+            _ => bail!("Invalid operand for the '{}' instruction", Self::OPCODE),
+        }
+    }
+
+    /// Returns the result of executing the operation on the given circuit inputs.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(inputs: &[circuit::Literal<A>; 1]) -> Result<circuit::Literal<A>> {
+        match inputs {
+            [circuit::Literal::String(first)] => Ok(circuit::Literal::U32(first.length())),
+            _ => bail!("Invalid operand for the '{}' instruction", Self::OPCODE),
+        }
+    }
+
+    /// Returns the output type from the given input types.
+    #[inline]
+    fn output_type(inputs: &[LiteralType; 1]) -> Result<LiteralType> {
+        match inputs {
+            [LiteralType::String] => Ok(LiteralType::U32),
+            _ => bail!("Invalid operand type for the '{}' instruction", Self::OPCODE),
+        }
+    }
+}
+
+/// The implementation of the `slice` operation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SliceOperation<N: Network>(PhantomData<N>);
+
+impl<N: Network> Operation<N, Literal<N>, LiteralType, 3> for SliceOperation<N> {
+    /// The opcode of the operation.
+    const OPCODE: Opcode = Opcode::Literal("slice");
+
+    /// Returns the result of evaluating the operation on the given inputs.
+    #[inline]
+    fn evaluate(inputs: &[Literal<N>; 3]) -> Result<Literal<N>> {
+        match inputs {
+            [Literal::String(first), Literal::U32(second), Literal::U32(third)] => {
+                Ok(Literal::String(first.slice(second, third)))
+            }
+            _ => bail!("Invalid operands for the '{}' instruction", Self::OPCODE),
+        }
+    }
+
+    /// Returns the result of executing the operation on the given circuit inputs.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(inputs: &[circuit::Literal<A>; 3]) -> Result<circuit::Literal<A>> {
+        match inputs {
+            [circuit::Literal::String(first), circuit::Literal::U32(second), circuit::Literal::U32(third)] => {
+                Ok(circuit::Literal::String(first.slice(second, third)))
+            }
+            _ => bail!("Invalid operands for the '{}' instruction", Self::OPCODE),
+        }
+    }
+
+    /// Returns the output type from the given input types.
+    #[inline]
+    fn output_type(inputs: &[LiteralType; 3]) -> Result<LiteralType> {
+        match inputs {
+            [LiteralType::String, LiteralType::U32, LiteralType::U32] => Ok(LiteralType::String),
+            _ => bail!("Invalid operand types for the '{}' instruction", Self::OPCODE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::{Eject, Inject, Mode};
+    use console::types::{StringType, U32};
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    fn string(value: &str) -> Literal<CurrentNetwork> {
+        Literal::String(StringType::new(value))
+    }
+
+    fn u32(value: u32) -> Literal<CurrentNetwork> {
+        Literal::U32(U32::new(value))
+    }
+
+    /// Returns the circuit literals for the given console literals, in the given mode.
+    fn inject<const NUM_OPERANDS: usize>(
+        mode: Mode,
+        inputs: &[Literal<CurrentNetwork>; NUM_OPERANDS],
+    ) -> [circuit::Literal<CurrentAleo>; NUM_OPERANDS] {
+        inputs.clone().map(|input| circuit::Literal::new(mode, input))
+    }
+
+    #[test]
+    fn test_concat() -> Result<()> {
+        let inputs = [string("hello "), string("world")];
+        assert_eq!(string("hello world"), ConcatOperation::evaluate(&inputs)?);
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let candidate = ConcatOperation::execute::<CurrentAleo>(&inject(mode, &inputs))?;
+            assert_eq!(string("hello world"), candidate.eject_value());
+            assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+            <CurrentAleo as circuit::Environment>::reset();
+        }
+
+        assert_eq!(LiteralType::String, ConcatOperation::<CurrentNetwork>::output_type(&[LiteralType::String; 2])?);
+        assert!(ConcatOperation::<CurrentNetwork>::output_type(&[LiteralType::String, LiteralType::U8]).is_err());
+        assert!(ConcatOperation::evaluate(&[string("hello"), u32(1)]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_len() -> Result<()> {
+        let inputs = [string("héllo")];
+        assert_eq!(u32(6), LenOperation::evaluate(&inputs)?);
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let candidate = LenOperation::execute::<CurrentAleo>(&inject(mode, &inputs))?;
+            assert_eq!(u32(6), candidate.eject_value());
+            assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+            <CurrentAleo as circuit::Environment>::reset();
+        }
+
+        assert_eq!(LiteralType::U32, LenOperation::<CurrentNetwork>::output_type(&[LiteralType::String])?);
+        assert!(LenOperation::<CurrentNetwork>::output_type(&[LiteralType::U32]).is_err());
+        assert!(LenOperation::evaluate(&[u32(1)]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_slice() -> Result<()> {
+        let inputs = [string("hello world"), u32(3), u32(8)];
+        assert_eq!(string("lo wo"), SliceOperation::evaluate(&inputs)?);
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let candidate = SliceOperation::execute::<CurrentAleo>(&inject(mode, &inputs))?;
+            assert_eq!(string("lo wo"), candidate.eject_value());
+            assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+            <CurrentAleo as circuit::Environment>::reset();
+        }
+
+        let types = [LiteralType::String, LiteralType::U32, LiteralType::U32];
+        assert_eq!(LiteralType::String, SliceOperation::<CurrentNetwork>::output_type(&types)?);
+        assert!(SliceOperation::<CurrentNetwork>::output_type(&[LiteralType::String; 3]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_slice_out_of_bounds() {
+        let inputs = [string("hello"), u32(3), u32(6)];
+
+        // Ensure the evaluation halts.
+        assert!(std::panic::catch_unwind(|| SliceOperation::evaluate(&inputs)).is_err());
+        // Ensure the execution is not satisfied.
+        let _candidate = SliceOperation::execute::<CurrentAleo>(&inject(Mode::Private, &inputs)).unwrap();
+        assert!(!<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+}
@@ -97,4 +97,124 @@ mod tests {
         assert_eq!(instruction, candidate.to_string());
         Ok(())
     }
+
+    #[test]
+    fn test_parse_ecdsa_verify() -> Result<()> {
+        let instruction = "ecdsa.verify r0 r1 r2 into r3;";
+        let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+        assert_eq!("", remainder);
+        assert!(matches!(candidate, Instruction::ECDSAVerify(..)));
+        assert_eq!(instruction, candidate.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_merkle_verify() -> Result<()> {
+        let instruction = "merkle.verify.bhp r0 r1 r2 into r3;";
+        let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+        assert_eq!("", remainder);
+        assert!(matches!(candidate, Instruction::MerkleVerifyBHP(..)));
+        assert_eq!(instruction, candidate.to_string());
+
+        let instruction = "merkle.verify.psd r0 r1 r2 into r3;";
+        let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+        assert_eq!("", remainder);
+        assert!(matches!(candidate, Instruction::MerkleVerifyPSD(..)));
+        assert_eq!(instruction, candidate.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_string_instructions() -> Result<()> {
+        let instruction = "concat r0 r1 into r2;";
+        let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+        assert_eq!("", remainder);
+        assert!(matches!(candidate, Instruction::Concat(..)));
+        assert_eq!(instruction, candidate.to_string());
+
+        let instruction = "len r0 into r1;";
+        let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+        assert_eq!("", remainder);
+        assert!(matches!(candidate, Instruction::Len(..)));
+        assert_eq!(instruction, candidate.to_string());
+
+        let instruction = "slice r0 1u32 r1 into r2;";
+        let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+        assert_eq!("", remainder);
+        assert!(matches!(candidate, Instruction::Slice(..)));
+        assert_eq!(instruction, candidate.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_repeat() -> Result<()> {
+        let instruction = "repeat 4u32 sum r0 0u64 into r1;";
+        let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+        assert_eq!("", remainder);
+        assert!(matches!(candidate, Instruction::Repeat(..)));
+        assert_eq!(instruction, candidate.to_string());
+
+        // Ensure the number of iterations is a compile-time bound.
+        assert!(Instruction::<CurrentNetwork>::parse("repeat r0 sum r1 into r2;").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_option_instructions() -> Result<()> {
+        let instruction = "option.some r0 into r1;";
+        let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+        assert_eq!("", remainder);
+        assert!(matches!(candidate, Instruction::OptionSome(..)));
+        assert_eq!(instruction, candidate.to_string());
+
+        let instruction = "option.none into r1 as u64?;";
+        let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+        assert_eq!("", remainder);
+        assert!(matches!(candidate, Instruction::OptionNone(..)));
+        assert_eq!(instruction, candidate.to_string());
+
+        let instruction = "option.is_some r0 into r1;";
+        let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+        assert_eq!("", remainder);
+        assert!(matches!(candidate, Instruction::OptionIsSome(..)));
+        assert_eq!(instruction, candidate.to_string());
+
+        let instruction = "option.unwrap r0 into r1;";
+        let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+        assert_eq!("", remainder);
+        assert!(matches!(candidate, Instruction::OptionUnwrap(..)));
+        assert_eq!(instruction, candidate.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_serialize_instructions() -> Result<()> {
+        let instruction = "serialize.bits r0 into r1 as [boolean; 16u32];";
+        let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+        assert_eq!("", remainder);
+        assert!(matches!(candidate, Instruction::SerializeBits(..)));
+        assert_eq!(instruction, candidate.to_string());
+
+        let instruction = "serialize.bytes r0 into r1 as [u8; 2u32];";
+        let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+        assert_eq!("", remainder);
+        assert!(matches!(candidate, Instruction::SerializeBytes(..)));
+        assert_eq!(instruction, candidate.to_string());
+
+        let instruction = "deserialize.bits r0 into r1 as u16;";
+        let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+        assert_eq!("", remainder);
+        assert!(matches!(candidate, Instruction::DeserializeBits(..)));
+        assert_eq!(instruction, candidate.to_string());
+
+        let instruction = "deserialize.bytes r0 into r1 as u16;";
+        let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+        assert_eq!("", remainder);
+        assert!(matches!(candidate, Instruction::DeserializeBytes(..)));
+        assert_eq!(instruction, candidate.to_string());
+
+        // Ensure the serialized destination type must match the opcode.
+        assert!(Instruction::<CurrentNetwork>::parse("serialize.bits r0 into r1 as [u8; 2u32];").is_err());
+        Ok(())
+    }
 }
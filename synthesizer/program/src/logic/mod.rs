@@ -15,6 +15,9 @@
 pub mod command;
 pub use command::*;
 
+mod event;
+pub use event::*;
+
 mod finalize_global_state;
 pub use finalize_global_state::*;
 
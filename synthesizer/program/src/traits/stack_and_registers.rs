@@ -20,6 +20,7 @@ use console::{
     network::Network,
     prelude::{bail, Result},
     program::{
+        Access,
         Future,
         Identifier,
         Literal,
@@ -197,6 +198,7 @@ pub trait RegistersLoad<N: Network> {
             Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal),
             Value::Plaintext(Plaintext::Struct(..))
             | Value::Plaintext(Plaintext::Array(..))
+            | Value::Plaintext(Plaintext::Optional(..))
             | Value::Record(..)
             | Value::Future(..) => {
                 bail!("Operand must be a literal")
@@ -221,6 +223,30 @@ pub trait RegistersLoad<N: Network> {
             Value::Record(..) | Value::Future(..) => bail!("Operand must be a plaintext"),
         }
     }
+
+    /// Returns the given path, with each register index replaced by the `u32` index in that register.
+    ///
+    /// # Errors
+    /// This method should halt if a register index is not a `u32`.
+    /// This method should halt if the register locator is not found.
+    #[inline]
+    fn resolve_path(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        path: &[Access<N>],
+    ) -> Result<Vec<Access<N>>> {
+        path.iter()
+            .map(|access| match access {
+                Access::Register(locator) => {
+                    match self.load_literal(stack, &Operand::Register(Register::Locator(*locator)))? {
+                        Literal::U32(index) => Ok(Access::Index(index)),
+                        literal => bail!("Expected a 'u32' index in 'r{locator}', found '{literal}'"),
+                    }
+                }
+                Access::Member(..) | Access::Index(..) => Ok(*access),
+            })
+            .collect()
+    }
 }
 
 pub trait RegistersLoadCircuit<N: Network, A: circuit::Aleo<Network = N>> {
@@ -251,6 +277,7 @@ pub trait RegistersLoadCircuit<N: Network, A: circuit::Aleo<Network = N>> {
             circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => Ok(literal),
             circuit::Value::Plaintext(circuit::Plaintext::Struct(..))
             | circuit::Value::Plaintext(circuit::Plaintext::Array(..))
+            | circuit::Value::Plaintext(circuit::Plaintext::Optional(..))
             | circuit::Value::Record(..)
             | circuit::Value::Future(..) => bail!("Operand must be a literal"),
         }
@@ -273,6 +300,33 @@ pub trait RegistersLoadCircuit<N: Network, A: circuit::Aleo<Network = N>> {
             circuit::Value::Record(..) | circuit::Value::Future(..) => bail!("Operand must be a plaintext"),
         }
     }
+
+    /// Returns the given path as a circuit, with each register index replaced by the `u32` index in that register.
+    /// Note: The indices loaded from registers may be non-constant.
+    ///
+    /// # Errors
+    /// This method should halt if a register index is not a `u32`.
+    /// This method should halt if the register locator is not found.
+    #[inline]
+    fn resolve_path_circuit(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        path: &[Access<N>],
+    ) -> Result<Vec<circuit::Access<A>>> {
+        use circuit::Inject;
+
+        path.iter()
+            .map(|access| match access {
+                Access::Register(locator) => {
+                    match self.load_literal_circuit(stack, &Operand::Register(Register::Locator(*locator)))? {
+                        circuit::Literal::U32(index) => Ok(circuit::Access::Index(index)),
+                        literal => bail!("Expected a 'u32' index in 'r{locator}', found '{literal}'"),
+                    }
+                }
+                Access::Member(..) | Access::Index(..) => Ok(circuit::Access::constant(*access)),
+            })
+            .collect()
+    }
 }
 
 pub trait RegistersStore<N: Network> {
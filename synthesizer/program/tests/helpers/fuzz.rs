@@ -0,0 +1,463 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A differential fuzzing harness, which checks that the console path (`evaluate`) and the circuit path (`execute`)
+//! of an instruction agree on every combination of opcode and literal types.
+//!
+//! Each combination is type-checked, compiled into a one-instruction program, and run through `Stack` on sampled
+//! operands. The two paths agree if both fail, or if both succeed with the same output. On disagreement, the operands
+//! are shrunk towards simpler values before the mismatch is reported.
+
+use crate::helpers::sample::sample_registers;
+
+use circuit::{AleoV0, Eject, Mode};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, LiteralType, PlaintextType, RegisterType, Value},
+    types::{integers::Integer, Address, Boolean, Field, Group, Scalar, StringType},
+};
+use snarkvm_synthesizer_program::{
+    Instruction,
+    InstructionTrait,
+    Opcode,
+    Operand,
+    Program,
+    RegistersLoad,
+    RegistersLoadCircuit,
+};
+use synthesizer_process::{Process, Stack};
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+/// The default number of sampled operands for each case.
+const DEFAULT_ITERATIONS: usize = 4;
+/// The maximum number of successful shrinking steps for a mismatch.
+const MAX_SHRINK_STEPS: usize = 256;
+
+/// All literal types.
+const LITERAL_TYPES: [LiteralType; 19] = [
+    LiteralType::Address,
+    LiteralType::Boolean,
+    LiteralType::Field,
+    LiteralType::Group,
+    LiteralType::I8,
+    LiteralType::I16,
+    LiteralType::I32,
+    LiteralType::I64,
+    LiteralType::I128,
+    LiteralType::I256,
+    LiteralType::U8,
+    LiteralType::U16,
+    LiteralType::U32,
+    LiteralType::U64,
+    LiteralType::U128,
+    LiteralType::U256,
+    LiteralType::Scalar,
+    LiteralType::Signature,
+    LiteralType::String,
+];
+
+/// The outcome of running an instruction on one path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// The instruction succeeded, with its output (if it has a destination).
+    Pass(Option<Value<CurrentNetwork>>),
+    /// The instruction returned an error, halted, or (in the circuit) was not satisfied.
+    Fail,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Pass(Some(output)) => write!(f, "pass with '{output}'"),
+            Self::Pass(None) => write!(f, "pass"),
+            Self::Fail => write!(f, "fail"),
+        }
+    }
+}
+
+/// An instruction on a fixed combination of literal types, compiled into a program.
+pub struct Case {
+    /// The stack of the program, whose function `run` contains the instruction.
+    stack: Stack<CurrentNetwork>,
+    /// The instruction.
+    instruction: Instruction<CurrentNetwork>,
+    /// The types of the operands.
+    input_types: Vec<LiteralType>,
+}
+
+impl Case {
+    /// Runs the console path of the instruction on the given operands.
+    pub fn evaluate(&self, inputs: &[Literal<CurrentNetwork>]) -> Outcome {
+        let values = inputs.iter().map(|input| (input, None)).collect::<Vec<_>>();
+        let result = catch_unwind(AssertUnwindSafe(|| -> Result<_> {
+            let mut registers = sample_registers(&self.stack, &function_name(), &values)?;
+            self.instruction.evaluate(&self.stack, &mut registers)?;
+            self.destination().map(|destination| registers.load(&self.stack, &destination)).transpose()
+        }));
+        match result {
+            Ok(Ok(output)) => Outcome::Pass(output),
+            _ => Outcome::Fail,
+        }
+    }
+
+    /// Runs the circuit path of the instruction on the given operands and modes.
+    pub fn execute(&self, inputs: &[Literal<CurrentNetwork>], modes: &[Mode]) -> Outcome {
+        <CurrentAleo as circuit::Environment>::reset();
+        let values = inputs.iter().zip_eq(modes).map(|(input, mode)| (input, Some(*mode))).collect::<Vec<_>>();
+        let result = catch_unwind(AssertUnwindSafe(|| -> Result<_> {
+            let mut registers = sample_registers(&self.stack, &function_name(), &values)?;
+            self.instruction.execute::<CurrentAleo>(&self.stack, &mut registers)?;
+            self.destination()
+                .map(|destination| Ok(registers.load_circuit(&self.stack, &destination)?.eject_value()))
+                .transpose()
+        }));
+        let is_satisfied = <CurrentAleo as circuit::Environment>::is_satisfied();
+        <CurrentAleo as circuit::Environment>::reset();
+        match result {
+            Ok(Ok(output)) if is_satisfied => Outcome::Pass(output),
+            _ => Outcome::Fail,
+        }
+    }
+
+    /// Returns the console and circuit outcomes, if they disagree on the given operands and modes.
+    pub fn check(&self, inputs: &[Literal<CurrentNetwork>], modes: &[Mode]) -> Option<(Outcome, Outcome)> {
+        let console = self.evaluate(inputs);
+        let circuit = self.execute(inputs, modes);
+        (console != circuit).then_some((console, circuit))
+    }
+
+    /// Returns the destination operand of the instruction, if it has one.
+    fn destination(&self) -> Option<Operand<CurrentNetwork>> {
+        self.instruction.destinations().into_iter().next().map(Operand::Register)
+    }
+}
+
+/// A disagreement between the console and circuit paths of an instruction.
+#[derive(Clone, Debug)]
+pub struct Mismatch {
+    /// The instruction.
+    pub instruction: String,
+    /// The (shrunk) operands.
+    pub inputs: Vec<Literal<CurrentNetwork>>,
+    /// The modes of the operands in the circuit.
+    pub modes: Vec<Mode>,
+    /// The outcome of the console path.
+    pub console: Outcome,
+    /// The outcome of the circuit path.
+    pub circuit: Outcome,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let inputs = self.inputs.iter().zip_eq(&self.modes).map(|(input, mode)| format!("{input}.{mode}"));
+        write!(
+            f,
+            "'{}' on [{}]: console {}, circuit {}",
+            self.instruction,
+            inputs.collect::<Vec<_>>().join(", "),
+            self.console,
+            self.circuit
+        )
+    }
+}
+
+/// The differential fuzzer.
+pub struct Fuzzer {
+    /// The process, used to construct the stack of each case.
+    process: Process<CurrentNetwork>,
+    /// The number of sampled operands for each case.
+    iterations: usize,
+    /// If set, only opcodes containing this string are fuzzed.
+    filter: Option<String>,
+}
+
+impl Fuzzer {
+    /// Initializes a new fuzzer.
+    /// The number of iterations and an opcode filter may be set with `FUZZ_ITERATIONS` and `FUZZ_FILTER`.
+    pub fn new() -> Result<Self> {
+        let iterations = match std::env::var("FUZZ_ITERATIONS") {
+            Ok(iterations) => iterations.parse()?,
+            Err(_) => DEFAULT_ITERATIONS,
+        };
+        let filter = std::env::var("FUZZ_FILTER").ok();
+        Ok(Self { process: Process::load()?, iterations, filter })
+    }
+
+    /// Returns `true` if the given opcode passes the opcode filter.
+    pub fn is_selected(&self, opcode: Opcode) -> bool {
+        self.filter.as_ref().map_or(true, |filter| opcode.to_string().contains(filter.as_str()))
+    }
+
+    /// Returns the cases for the given opcode, one for each combination of literal types that type-checks.
+    pub fn cases(&self, opcode: Opcode) -> Result<Vec<Case>> {
+        // Retrieve a stack to type-check against, as the candidates only use literal types.
+        let credits = self.process.get_stack("credits.aleo")?;
+
+        let mut cases = Vec::new();
+        for (instruction, input_types) in candidates(opcode) {
+            // Skip the candidate if it does not parse, or does not type-check.
+            let Ok(instruction) = Instruction::<CurrentNetwork>::from_str(&instruction) else { continue };
+            let register_types = input_types
+                .iter()
+                .map(|literal_type| RegisterType::Plaintext(PlaintextType::Literal(*literal_type)))
+                .collect::<Vec<_>>();
+            if instruction.output_types(&**credits, &register_types).is_err() {
+                continue;
+            }
+            // Compile the instruction into a program.
+            let inputs = input_types
+                .iter()
+                .enumerate()
+                .map(|(i, literal_type)| format!("input r{i} as {literal_type}.private;"));
+            let program = Program::from_str(&format!(
+                "program fuzz.aleo;\n\nfunction {}:\n    {}\n    {instruction}\n",
+                function_name(),
+                inputs.collect::<Vec<_>>().join("\n    ")
+            ))?;
+            let stack = Stack::new(&self.process, &program)?;
+            cases.push(Case { stack, instruction, input_types });
+        }
+        Ok(cases)
+    }
+
+    /// Fuzzes the given opcode, and returns the number of cases and the mismatches found (at most one per case).
+    pub fn fuzz<R: Rng + CryptoRng>(&self, opcode: Opcode, rng: &mut R) -> Result<(usize, Vec<Mismatch>)> {
+        let cases = self.cases(opcode)?;
+        let mut mismatches = Vec::new();
+        for case in &cases {
+            for _ in 0..self.iterations {
+                let inputs =
+                    case.input_types.iter().map(|literal_type| sample_literal(*literal_type, rng)).collect::<Vec<_>>();
+                let modes = case.input_types.iter().map(|_| sample_mode(rng)).collect::<Vec<_>>();
+                if case.check(&inputs, &modes).is_some() {
+                    // Shrink the operands, while the paths still disagree.
+                    let inputs = shrink(inputs, shrink_literal, |inputs| case.check(inputs, &modes).is_some());
+                    let (console, circuit) = case.check(&inputs, &modes).expect("The shrunk operands must disagree");
+                    let instruction = case.instruction.to_string();
+                    mismatches.push(Mismatch { instruction, inputs, modes, console, circuit });
+                    break;
+                }
+            }
+        }
+        Ok((cases.len(), mismatches))
+    }
+}
+
+/// Returns the name of the function under test.
+fn function_name() -> Identifier<CurrentNetwork> {
+    Identifier::from_str("run").expect("The function name must be valid")
+}
+
+/// Returns the candidate instructions for the given opcode, with the types of their operands.
+/// Opcodes that do not operate on literals alone are not fuzzed, and have no candidates.
+fn candidates(opcode: Opcode) -> Vec<(String, Vec<LiteralType>)> {
+    /// Returns every combination of `num_operands` literal types.
+    fn combinations(num_operands: usize) -> Vec<Vec<LiteralType>> {
+        (0..num_operands).fold(vec![vec![]], |combinations, _| {
+            combinations
+                .into_iter()
+                .flat_map(|types| {
+                    LITERAL_TYPES.iter().map(move |literal_type| [types.clone(), vec![*literal_type]].concat())
+                })
+                .collect()
+        })
+    }
+    /// Returns the operands `r0 .. r{num_operands - 1}`.
+    fn operands(num_operands: usize) -> String {
+        (0..num_operands).map(|i| format!("r{i}")).collect::<Vec<_>>().join(" ")
+    }
+
+    match opcode {
+        // The operations on literals, with one to three operands and a destination.
+        Opcode::Literal(..) | Opcode::Is(..) => {
+            // Determine the number of operands of the opcode.
+            let Some(num_operands) = (1..=3).find(|num_operands| {
+                let operands = operands(*num_operands);
+                Instruction::<CurrentNetwork>::from_str(&format!("{opcode} {operands} into r{num_operands};")).is_ok()
+            }) else {
+                return vec![];
+            };
+            let instruction = format!("{opcode} {} into r{num_operands};", operands(num_operands));
+            combinations(num_operands).into_iter().map(|types| (instruction.clone(), types)).collect()
+        }
+        // The assertions, with two operands and no destination.
+        Opcode::Assert(..) => combinations(2).into_iter().map(|types| (format!("{opcode} r0 r1;"), types)).collect(),
+        // The casts, hashes, and commitments, with a literal destination type.
+        Opcode::Cast(..) | Opcode::Hash(..) | Opcode::Commit(..) => {
+            let num_operands = if matches!(opcode, Opcode::Commit(..)) { 2 } else { 1 };
+            let operands = operands(num_operands);
+            combinations(num_operands)
+                .into_iter()
+                .flat_map(|types| {
+                    let operands = operands.clone();
+                    LITERAL_TYPES.iter().map(move |destination_type| {
+                        (format!("{opcode} {operands} into r{num_operands} as {destination_type};"), types.clone())
+                    })
+                })
+                .collect()
+        }
+        // The remaining opcodes operate on composite values, or on the program state.
+        Opcode::Async
+        | Opcode::Call
+        | Opcode::CallDynamic
+        | Opcode::Command(..)
+        | Opcode::Merkle(..)
+        | Opcode::Option(..)
+        | Opcode::Repeat
+        | Opcode::Serialize(..)
+        | Opcode::Sign(..) => vec![],
+    }
+}
+
+/// Samples a mode for an operand in the circuit.
+fn sample_mode<R: Rng + CryptoRng>(rng: &mut R) -> Mode {
+    match rng.gen_range(0..3) {
+        0 => Mode::Constant,
+        1 => Mode::Public,
+        _ => Mode::Private,
+    }
+}
+
+/// Samples a literal of the given type, biased towards edge cases.
+fn sample_literal<R: Rng + CryptoRng>(literal_type: LiteralType, rng: &mut R) -> Literal<CurrentNetwork> {
+    let edge_cases = edge_literals(literal_type);
+    match edge_cases.is_empty() || rng.gen_bool(0.75) {
+        true => Literal::sample(literal_type, rng),
+        false => edge_cases[rng.gen_range(0..edge_cases.len())].clone(),
+    }
+}
+
+/// Returns the edge cases for the given literal type.
+fn edge_literals(literal_type: LiteralType) -> Vec<Literal<CurrentNetwork>> {
+    /// Returns zero, one, and the bounds of an integer type, and negative one if it is signed.
+    fn edge_integers<I: IntegerType>() -> Vec<Integer<CurrentNetwork, I>> {
+        let mut integers = vec![Integer::zero(), Integer::one(), Integer::MAX, Integer::MIN];
+        if I::is_signed() {
+            integers.push(Integer::zero() - Integer::one());
+        }
+        integers
+    }
+
+    match literal_type {
+        LiteralType::Address => vec![Literal::Address(Address::zero())],
+        LiteralType::Boolean => vec![Literal::Boolean(Boolean::new(false)), Literal::Boolean(Boolean::new(true))],
+        LiteralType::Field => [Field::zero(), Field::one(), -Field::one()].map(Literal::Field).to_vec(),
+        LiteralType::Group => vec![Literal::Group(Group::zero()), Literal::Group(Group::generator())],
+        LiteralType::I8 => edge_integers().into_iter().map(Literal::I8).collect(),
+        LiteralType::I16 => edge_integers().into_iter().map(Literal::I16).collect(),
+        LiteralType::I32 => edge_integers().into_iter().map(Literal::I32).collect(),
+        LiteralType::I64 => edge_integers().into_iter().map(Literal::I64).collect(),
+        LiteralType::I128 => edge_integers().into_iter().map(Literal::I128).collect(),
+        LiteralType::I256 => edge_integers().into_iter().map(Literal::I256).collect(),
+        LiteralType::U8 => edge_integers().into_iter().map(Literal::U8).collect(),
+        LiteralType::U16 => edge_integers().into_iter().map(Literal::U16).collect(),
+        LiteralType::U32 => edge_integers().into_iter().map(Literal::U32).collect(),
+        LiteralType::U64 => edge_integers().into_iter().map(Literal::U64).collect(),
+        LiteralType::U128 => edge_integers().into_iter().map(Literal::U128).collect(),
+        LiteralType::U256 => edge_integers().into_iter().map(Literal::U256).collect(),
+        LiteralType::Scalar => vec![Literal::Scalar(Scalar::zero()), Literal::Scalar(Scalar::one())],
+        LiteralType::Signature => vec![],
+        LiteralType::String => vec![Literal::String(StringType::new(""))],
+    }
+}
+
+/// Returns simpler candidates for the given literal, of the same type.
+fn shrink_literal(literal: &Literal<CurrentNetwork>) -> Vec<Literal<CurrentNetwork>> {
+    /// Returns zero, one, and half of the given integer, if they are simpler.
+    fn shrink_integer<I: IntegerType>(integer: &Integer<CurrentNetwork, I>) -> Vec<Integer<CurrentNetwork, I>> {
+        match integer.is_zero() {
+            true => vec![],
+            false => {
+                let half = *integer / (Integer::one() + Integer::one());
+                let mut integers = Vec::with_capacity(3);
+                for candidate in [Integer::zero(), Integer::one(), half] {
+                    if candidate != *integer && !integers.contains(&candidate) {
+                        integers.push(candidate);
+                    }
+                }
+                integers
+            }
+        }
+    }
+
+    match literal {
+        Literal::Address(..) | Literal::Signature(..) => vec![],
+        Literal::Boolean(boolean) => match **boolean {
+            true => vec![Literal::Boolean(Boolean::new(false))],
+            false => vec![],
+        },
+        Literal::Field(field) => {
+            [Field::zero(), Field::one()].into_iter().filter(|c| c != field).map(Literal::Field).collect()
+        }
+        Literal::Group(group) => match group.is_zero() {
+            true => vec![],
+            false => vec![Literal::Group(Group::zero())],
+        },
+        Literal::I8(integer) => shrink_integer(integer).into_iter().map(Literal::I8).collect(),
+        Literal::I16(integer) => shrink_integer(integer).into_iter().map(Literal::I16).collect(),
+        Literal::I32(integer) => shrink_integer(integer).into_iter().map(Literal::I32).collect(),
+        Literal::I64(integer) => shrink_integer(integer).into_iter().map(Literal::I64).collect(),
+        Literal::I128(integer) => shrink_integer(integer).into_iter().map(Literal::I128).collect(),
+        Literal::I256(integer) => shrink_integer(integer).into_iter().map(Literal::I256).collect(),
+        Literal::U8(integer) => shrink_integer(integer).into_iter().map(Literal::U8).collect(),
+        Literal::U16(integer) => shrink_integer(integer).into_iter().map(Literal::U16).collect(),
+        Literal::U32(integer) => shrink_integer(integer).into_iter().map(Literal::U32).collect(),
+        Literal::U64(integer) => shrink_integer(integer).into_iter().map(Literal::U64).collect(),
+        Literal::U128(integer) => shrink_integer(integer).into_iter().map(Literal::U128).collect(),
+        Literal::U256(integer) => shrink_integer(integer).into_iter().map(Literal::U256).collect(),
+        Literal::Scalar(scalar) => {
+            [Scalar::zero(), Scalar::one()].into_iter().filter(|c| c != scalar).map(Literal::Scalar).collect()
+        }
+        Literal::String(string) => {
+            let num_chars = string.chars().count();
+            match num_chars {
+                0 => vec![],
+                _ => {
+                    let half = string.chars().take(num_chars / 2).collect::<String>();
+                    vec![Literal::String(StringType::new(&half))]
+                }
+            }
+        }
+    }
+}
+
+/// Shrinks the given failing inputs, by greedily replacing one input at a time with a simpler candidate,
+/// for as long as the inputs still fail.
+pub fn shrink<T: Clone>(
+    mut inputs: Vec<T>,
+    candidates: impl Fn(&T) -> Vec<T>,
+    mut is_failing: impl FnMut(&[T]) -> bool,
+) -> Vec<T> {
+    let mut num_steps = 0;
+    'shrink: while num_steps < MAX_SHRINK_STEPS {
+        for index in 0..inputs.len() {
+            for candidate in candidates(&inputs[index]) {
+                let mut shrunk = inputs.clone();
+                shrunk[index] = candidate;
+                if is_failing(&shrunk) {
+                    inputs = shrunk;
+                    num_steps += 1;
+                    continue 'shrink;
+                }
+            }
+        }
+        break;
+    }
+    inputs
+}
@@ -22,7 +22,15 @@ use snarkvm_synthesizer_program::{
     traits::{RegistersStore, RegistersStoreCircuit},
     FinalizeGlobalState,
 };
-use synthesizer_process::{Authorization, CallStack, FinalizeRegisters, Registers, Stack, StackProgramTypes};
+use synthesizer_process::{
+    Authorization,
+    CallStack,
+    DynamicStacks,
+    FinalizeRegisters,
+    Registers,
+    Stack,
+    StackProgramTypes,
+};
 
 type CurrentNetwork = MainnetV0;
 type CurrentAleo = AleoV0;
@@ -35,7 +43,7 @@ pub fn sample_registers(
 ) -> Result<Registers<CurrentNetwork, CurrentAleo>> {
     // Initialize the registers.
     let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
-        CallStack::evaluate(Authorization::try_from((vec![], vec![]))?)?,
+        CallStack::evaluate(Authorization::try_from((vec![], vec![]))?, DynamicStacks::default())?,
         stack.get_register_types(function_name)?.clone(),
     );
 
@@ -68,7 +76,7 @@ pub fn sample_finalize_registers(
 ) -> Result<FinalizeRegisters<CurrentNetwork>> {
     // Initialize the registers.
     let mut finalize_registers = FinalizeRegisters::<CurrentNetwork>::new(
-        FinalizeGlobalState::from(1, 1, [0; 32]),
+        FinalizeGlobalState::from(1, 1, 0, [0; 32]),
         <CurrentNetwork as Network>::TransitionID::default(),
         *function_name,
         stack.get_finalize_types(function_name)?.clone(),
@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::fuzz::{shrink, Fuzzer};
+
+use console::{network::MainnetV0, prelude::*};
+use snarkvm_synthesizer_program::{Instruction, Opcode};
+
+type CurrentNetwork = MainnetV0;
+
+/// Fuzzes every selected opcode, and checks that the console and circuit paths agree.
+fn check_opcodes(select: impl Fn(&Opcode) -> bool) {
+    let fuzzer = Fuzzer::new().unwrap();
+    let mut rng = TestRng::default();
+
+    let mut mismatches = Vec::new();
+    for opcode in Instruction::<CurrentNetwork>::OPCODES.iter().filter(|opcode| select(opcode)) {
+        if !fuzzer.is_selected(*opcode) {
+            continue;
+        }
+        let (num_cases, found) = fuzzer.fuzz(*opcode, &mut rng).unwrap();
+        println!("Fuzzed '{opcode}' on {num_cases} type combinations, with {} mismatches", found.len());
+        assert!(num_cases > 0, "Found no valid type combinations for '{opcode}'");
+        mismatches.extend(found);
+    }
+    assert!(mismatches.is_empty(), "Found {} mismatches:\n{}", mismatches.len(), mismatches.iter().join("\n"));
+}
+
+#[test]
+fn test_fuzz_literal_opcodes() {
+    check_opcodes(|opcode| matches!(opcode, Opcode::Literal(..)));
+}
+
+#[test]
+fn test_fuzz_is_and_assert_opcodes() {
+    check_opcodes(|opcode| matches!(opcode, Opcode::Is(..) | Opcode::Assert(..)));
+}
+
+#[test]
+fn test_fuzz_cast_opcodes() {
+    check_opcodes(|opcode| matches!(opcode, Opcode::Cast(..)));
+}
+
+#[test]
+fn test_fuzz_hash_opcodes() {
+    // Note: `hash_many` is not yet implemented, so it is not fuzzed.
+    check_opcodes(|opcode| matches!(opcode, Opcode::Hash(hash) if !hash.starts_with("hash_many")));
+}
+
+#[test]
+fn test_fuzz_commit_opcodes() {
+    check_opcodes(|opcode| matches!(opcode, Opcode::Commit(..)));
+}
+
+#[test]
+fn test_shrink() {
+    let candidates = |x: &u64| if *x == 0 { vec![] } else { vec![0, x / 2, x - 1] };
+
+    // Ensure the inputs are shrunk to the smallest failing inputs.
+    let shrunk = shrink(vec![1000, 7, 500], candidates, |inputs| inputs[0] >= 10 && inputs[2] >= 3);
+    assert_eq!(shrunk, vec![10, 0, 3]);
+
+    // Ensure passing candidates are not taken.
+    let shrunk = shrink(vec![5, 5], candidates, |inputs| inputs[0] + inputs[1] == 10);
+    assert_eq!(shrunk, vec![5, 5]);
+}
@@ -27,6 +27,7 @@ use snarkvm_synthesizer_program::{
     HashBHP256,
     HashBHP512,
     HashBHP768,
+    HashBlake2s,
     HashInstruction,
     HashKeccak256,
     HashKeccak384,
@@ -36,6 +37,10 @@ use snarkvm_synthesizer_program::{
     HashPSD2,
     HashPSD4,
     HashPSD8,
+    HashPoseidon2_2,
+    HashPoseidon2_4,
+    HashPoseidon2_8,
+    HashSha256,
     HashSha3_256,
     HashSha3_384,
     HashSha3_512,
@@ -63,11 +68,13 @@ fn valid_destination_types<N: Network>() -> &'static [PlaintextType<N>] {
         PlaintextType::Literal(LiteralType::I32),
         PlaintextType::Literal(LiteralType::I64),
         PlaintextType::Literal(LiteralType::I128),
+        PlaintextType::Literal(LiteralType::I256),
         PlaintextType::Literal(LiteralType::U8),
         PlaintextType::Literal(LiteralType::U16),
         PlaintextType::Literal(LiteralType::U32),
         PlaintextType::Literal(LiteralType::U64),
         PlaintextType::Literal(LiteralType::U128),
+        PlaintextType::Literal(LiteralType::U256),
         PlaintextType::Literal(LiteralType::Scalar),
     ]
 }
@@ -233,6 +240,8 @@ test_hash!(hash_bhp512, HashBHP512, ITERATIONS);
 test_hash!(hash_bhp768, HashBHP768, ITERATIONS);
 test_hash!(hash_bhp1024, HashBHP1024, ITERATIONS);
 
+test_hash!(hash_blake2s, HashBlake2s, 5);
+
 test_hash!(hash_keccak256, HashKeccak256, 5);
 test_hash!(hash_keccak384, HashKeccak384, 5);
 test_hash!(hash_keccak512, HashKeccak512, 5);
@@ -241,6 +250,12 @@ test_hash!(hash_psd2, HashPSD2, ITERATIONS);
 test_hash!(hash_psd4, HashPSD4, ITERATIONS);
 test_hash!(hash_psd8, HashPSD8, ITERATIONS);
 
+test_hash!(hash_poseidon2_2, HashPoseidon2_2, ITERATIONS);
+test_hash!(hash_poseidon2_4, HashPoseidon2_4, ITERATIONS);
+test_hash!(hash_poseidon2_8, HashPoseidon2_8, ITERATIONS);
+
+test_hash!(hash_sha256, HashSha256, 5);
+
 test_hash!(hash_sha3_256, HashSha3_256, 5);
 test_hash!(hash_sha3_384, HashSha3_384, 5);
 test_hash!(hash_sha3_512, HashSha3_512, 5);
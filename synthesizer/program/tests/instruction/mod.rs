@@ -14,5 +14,6 @@
 
 mod assert;
 mod commit;
+mod fuzz;
 mod hash;
 mod is;
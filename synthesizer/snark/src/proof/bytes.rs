@@ -52,6 +52,8 @@ mod tests {
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Proof::read_le(&expected_bytes[..])?);
+        // Ensure the proof is within the size bound for a single instance.
+        assert!(expected_bytes.len() <= Proof::<console::network::MainnetV0>::max_size_in_bytes(1));
 
         Ok(())
     }
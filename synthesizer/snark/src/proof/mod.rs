@@ -29,6 +29,12 @@ impl<N: Network> Proof<N> {
     pub const fn new(proof: varuna::Proof<N::PairingCurve>) -> Self {
         Self { proof }
     }
+
+    /// Returns an upper bound on the number of bytes in a proof for the given number of instances.
+    pub fn max_size_in_bytes(num_instances: usize) -> usize {
+        // Account for the version, followed by the proof.
+        1usize.saturating_add(varuna::Proof::<N::PairingCurve>::max_serialized_size(num_instances))
+    }
 }
 
 impl<N: Network> Deref for Proof<N> {
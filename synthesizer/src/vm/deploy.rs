@@ -30,6 +30,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
     ) -> Result<Transaction<N>> {
+        // Ensure an upgrade is deployed by the owner of the program.
+        if let Some(owner) = self.transaction_store().deployment_store().get_owner(program.id())? {
+            ensure!(
+                owner.address() == Address::try_from(private_key)?,
+                "Only the owner of '{}' may upgrade the program",
+                program.id()
+            );
+        }
         // Compute the deployment.
         let deployment = self.deploy_raw(program, rng)?;
         // Ensure the transaction is not empty.
@@ -40,7 +48,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let owner = ProgramOwner::new(private_key, deployment_id, rng)?;
 
         // Compute the minimum deployment cost.
-        let (minimum_deployment_cost, _) = deployment_cost(&deployment)?;
+        let (minimum_deployment_cost, _) = deployment_cost(&self.process().read(), &deployment)?;
         // Authorize the fee.
         let fee_authorization = match fee_record {
             Some(record) => self.authorize_fee_private(
@@ -65,6 +73,18 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Return the deploy transaction.
         Transaction::from_deployment(owner, deployment, fee)
     }
+
+    /// Returns the estimated cost of deploying the given program, **without** proving the fee.
+    pub fn estimate_deployment_cost<R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        rng: &mut R,
+    ) -> Result<DeploymentCostEstimate> {
+        // Compute the deployment.
+        let deployment = self.deploy_raw(program, rng)?;
+        // Estimate the cost of the deployment.
+        self.process().read().estimate_deployment_cost(&deployment)
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -101,9 +101,33 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
     ) -> Result<Fee<N>> {
-        debug_assert!(authorization.is_fee_private() || authorization.is_fee_public(), "Expected a fee authorization");
+        ensure!(authorization.is_fee_private() || authorization.is_fee_public(), "Expected a fee authorization");
         self.execute_fee_authorization_raw(authorization, query, rng)
     }
+
+    /// Returns the estimated cost of executing the given program function, **without** generating any proofs.
+    ///
+    /// Note: The estimated storage cost uses an upper bound on the size of the proof.
+    pub fn estimate_execution_cost<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        (program_id, function_name): (impl TryInto<ProgramID<N>>, impl TryInto<Identifier<N>>),
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        rng: &mut R,
+    ) -> Result<ExecutionCostEstimate<N>> {
+        // Compute the authorization.
+        let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
+        // Estimate the cost of the execution.
+        self.estimate_authorization_cost(&authorization)
+    }
+
+    /// Returns the estimated cost of executing the given authorization, **without** generating any proofs.
+    ///
+    /// Note: The estimated storage cost uses an upper bound on the size of the proof.
+    pub fn estimate_authorization_cost(&self, authorization: &Authorization<N>) -> Result<ExecutionCostEstimate<N>> {
+        ensure!(!authorization.is_fee_private() && !authorization.is_fee_public(), "Expected an execution");
+        self.process().read().estimate_execution_cost(authorization)
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -315,6 +339,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_estimate_execution_cost() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Prepare the inputs.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ];
+
+        // Estimate the cost of the execution.
+        let estimate = vm
+            .estimate_execution_cost(
+                &caller_private_key,
+                ("credits.aleo", "transfer_public"),
+                inputs.clone().into_iter(),
+                rng,
+            )
+            .unwrap();
+        assert_eq!(estimate.transitions().len(), 1);
+        assert_eq!(estimate.total_cost(), estimate.storage_cost() + estimate.finalize_cost());
+
+        // Execute.
+        let transaction = vm
+            .execute(&caller_private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+            .unwrap();
+
+        // Check the estimate against the cost of the proven execution.
+        let execution = transaction.execution().unwrap();
+        let (_, (storage_cost, finalize_cost)) = execution_cost(&vm.process().read(), execution).unwrap();
+        assert_eq!(estimate.finalize_cost(), finalize_cost);
+        assert!(estimate.storage_cost() >= storage_cost);
+    }
+
     #[test]
     fn test_join_transaction_size() {
         let rng = &mut TestRng::default();
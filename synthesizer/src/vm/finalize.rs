@@ -315,7 +315,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         // Define the closure for processing a rejected deployment.
                         let process_rejected_deployment =
                             |fee: &Fee<N>,
-                             deployment: Deployment<N>|
+                             deployment: Deployment<N>,
+                             reason: &str|
                              -> Result<Result<ConfirmedTransaction<N>, String>> {
                                 process
                                     .finalize_fee(state, store, fee)
@@ -323,7 +324,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                                         Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize))
                                     })
                                     .map(|(fee_tx, finalize)| {
-                                        let rejected = Rejected::new_deployment(*program_owner, deployment);
+                                        let rejected =
+                                            Rejected::new_deployment(*program_owner, deployment).with_reason(reason);
                                         ConfirmedTransaction::rejected_deploy(counter, fee_tx, rejected, finalize)
                                             .map_err(|e| e.to_string())
                                     })
@@ -332,7 +334,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         // Check if the program has already been deployed in this block.
                         match deployments.contains(deployment.program_id()) {
                             // If the program has already been deployed, construct the rejected deploy transaction.
-                            true => match process_rejected_deployment(fee, *deployment.clone()) {
+                            true => match process_rejected_deployment(
+                                fee,
+                                *deployment.clone(),
+                                "The program has already been deployed in this block",
+                            ) {
                                 Ok(result) => result,
                                 Err(error) => {
                                     // Note: On failure, skip this transaction, and continue speculation.
@@ -345,7 +351,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                                 }
                             },
                             // If the program has not yet been deployed, attempt to deploy it.
-                            false => match process.finalize_deployment(state, store, deployment, fee) {
+                            false => match process.finalize_deployment(
+                                state,
+                                store,
+                                deployment,
+                                fee,
+                                &program_owner.address(),
+                            ) {
                                 // Construct the accepted deploy transaction.
                                 Ok((_, finalize)) => {
                                     // Add the program id to the list of deployments.
@@ -354,18 +366,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                                         .map_err(|e| e.to_string())
                                 }
                                 // Construct the rejected deploy transaction.
-                                Err(_error) => match process_rejected_deployment(fee, *deployment.clone()) {
-                                    Ok(result) => result,
-                                    Err(error) => {
-                                        // Note: On failure, skip this transaction, and continue speculation.
-                                        #[cfg(debug_assertions)]
-                                        eprintln!("Failed to finalize the fee in a rejected deploy - {error}");
-                                        // Store the aborted transaction.
-                                        aborted.push((transaction.clone(), error.to_string()));
-                                        // Continue to the next transaction.
-                                        continue 'outer;
+                                Err(reason) => {
+                                    match process_rejected_deployment(fee, *deployment.clone(), &reason.to_string()) {
+                                        Ok(result) => result,
+                                        Err(error) => {
+                                            // Note: On failure, skip this transaction, and continue speculation.
+                                            #[cfg(debug_assertions)]
+                                            eprintln!("Failed to finalize the fee in a rejected deploy - {error}");
+                                            // Store the aborted transaction.
+                                            aborted.push((transaction.clone(), error.to_string()));
+                                            // Continue to the next transaction.
+                                            continue 'outer;
+                                        }
                                     }
-                                },
+                                }
                             },
                         }
                     }
@@ -374,20 +388,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     Transaction::Execute(_, execution, fee) => {
                         match process.finalize_execution(state, store, execution, fee.as_ref()) {
                             // Construct the accepted execute transaction.
-                            Ok(finalize) => {
-                                ConfirmedTransaction::accepted_execute(counter, transaction.clone(), finalize)
+                            Ok((finalize, events)) => {
+                                ConfirmedTransaction::accepted_execute(counter, transaction.clone(), finalize, events)
                                     .map_err(|e| e.to_string())
                             }
                             // Construct the rejected execute transaction.
-                            Err(_error) => match fee {
+                            Err(reason) => match fee {
                                 // Finalize the fee, to ensure it is valid.
                                 Some(fee) => {
                                     match process.finalize_fee(state, store, fee).and_then(|finalize| {
                                         Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize))
                                     }) {
                                         Ok((fee_tx, finalize)) => {
-                                            // Construct the rejected execution.
-                                            let rejected = Rejected::new_execution(execution.clone());
+                                            // Construct the rejected execution, with the reason for the rejection.
+                                            let rejected = Rejected::new_execution(execution.clone())
+                                                .with_reason(&reason.to_string());
                                             // Construct the rejected execute transaction.
                                             ConfirmedTransaction::rejected_execute(counter, fee_tx, rejected, finalize)
                                                 .map_err(|e| e.to_string())
@@ -576,14 +591,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
                 let outcome: Result<(), String> = match transaction {
                     ConfirmedTransaction::AcceptedDeploy(_, transaction, finalize) => {
-                        // Extract the deployment and fee from the transaction.
-                        let (deployment, fee) = match transaction {
-                            Transaction::Deploy(_, _, deployment, fee) => (deployment, fee),
+                        // Extract the program owner, deployment, and fee from the transaction.
+                        let (program_owner, deployment, fee) = match transaction {
+                            Transaction::Deploy(_, program_owner, deployment, fee) => (program_owner, deployment, fee),
                             // Note: This will abort the entire atomic batch.
                             _ => return Err("Expected deploy transaction".to_string()),
                         };
                         // The finalize operation here involves appending the 'stack', and adding the program to the finalize tree.
-                        match process.finalize_deployment(state, store, deployment, fee) {
+                        match process.finalize_deployment(state, store, deployment, fee, &program_owner.address()) {
                             // Ensure the finalize operations match the expected.
                             Ok((stack, finalize_operations)) => match finalize == &finalize_operations {
                                 // Store the stack.
@@ -602,7 +617,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         };
                         Ok(())
                     }
-                    ConfirmedTransaction::AcceptedExecute(_, transaction, finalize) => {
+                    ConfirmedTransaction::AcceptedExecute(_, transaction, finalize, events) => {
                         // Extract the execution and fee from the transaction.
                         let (execution, fee) = match transaction {
                             Transaction::Execute(_, execution, fee) => (execution, fee),
@@ -613,13 +628,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         // and update the respective leaves of the finalize tree.
                         match process.finalize_execution(state, store, execution, fee.as_ref()) {
                             // Ensure the finalize operations match the expected.
-                            Ok(finalize_operations) => {
+                            Ok((finalize_operations, emitted_events)) => {
                                 if finalize != &finalize_operations {
                                     // Note: This will abort the entire atomic batch.
                                     return Err(format!(
                                         "Mismatch in finalize operations for an accepted execute - (found: {finalize_operations:?}, expected: {finalize:?})"
                                     ));
                                 }
+                                // Ensure the events match the expected.
+                                if events != &emitted_events {
+                                    // Note: This will abort the entire atomic batch.
+                                    return Err(format!(
+                                        "Mismatch in events for an accepted execute - (found: {emitted_events:?}, expected: {events:?})"
+                                    ));
+                                }
                             }
                             // Note: This will abort the entire atomic batch.
                             Err(error) => {
@@ -1288,18 +1310,21 @@ finalize transfer_public:
         create_execution(vm, caller_private_key, program_id, "transfer_public", inputs, unspent_records, rng)
     }
 
-    /// A helper method to construct the rejected transaction format for `atomic_finalize`.
+    /// A helper method to construct the rejected transaction format for `atomic_finalize`,
+    /// with the finalize operations and the reason of the given confirmed transaction.
     fn reject(
         index: u32,
         transaction: &Transaction<CurrentNetwork>,
-        finalize: &[FinalizeOperation<CurrentNetwork>],
+        confirmed: &ConfirmedTransaction<CurrentNetwork>,
     ) -> ConfirmedTransaction<CurrentNetwork> {
+        // Ensure the reason for the rejection is recorded.
+        let reason = confirmed.to_rejected().and_then(|rejected| rejected.reason()).unwrap();
         match transaction {
             Transaction::Execute(_, execution, fee) => ConfirmedTransaction::RejectedExecute(
                 index,
                 Transaction::from_fee(fee.clone().unwrap()).unwrap(),
-                Rejected::new_execution(execution.clone()),
-                finalize.to_vec(),
+                Rejected::new_execution(execution.clone()).with_reason(reason),
+                confirmed.finalize_operations().to_vec(),
             ),
             _ => panic!("only reject execution transactions"),
         }
@@ -1574,10 +1599,7 @@ finalize transfer_public:
             assert!(confirmed_transactions[1].is_rejected());
 
             assert_eq!(confirmed_transactions[0].transaction(), &transfer_20);
-            assert_eq!(
-                confirmed_transactions[1],
-                reject(1, &transfer_10, confirmed_transactions[1].finalize_operations())
-            );
+            assert_eq!(confirmed_transactions[1], reject(1, &transfer_10, &confirmed_transactions[1]));
         }
 
         // Starting Balance = 20
@@ -1601,10 +1623,7 @@ finalize transfer_public:
 
             assert_eq!(confirmed_transactions[0].transaction(), &mint_20);
             assert_eq!(confirmed_transactions[1].transaction(), &transfer_30);
-            assert_eq!(
-                confirmed_transactions[2],
-                reject(2, &transfer_20, confirmed_transactions[2].finalize_operations())
-            );
+            assert_eq!(confirmed_transactions[2], reject(2, &transfer_20, &confirmed_transactions[2]));
             assert_eq!(confirmed_transactions[3].transaction(), &transfer_10);
         }
     }
@@ -1695,15 +1714,18 @@ function ped_hash:
             assert_eq!(confirmed_transactions.len(), 1);
             assert!(transaction.is_execute());
             if let Transaction::Execute(_, execution, fee) = transaction {
+                let confirmed_transaction = confirmed_transactions.iter().next().unwrap();
+                // Ensure the reason for the rejection is recorded.
+                let reason = confirmed_transaction.to_rejected().and_then(|rejected| rejected.reason()).unwrap();
+
                 let fee_transaction = Transaction::from_fee(fee.unwrap()).unwrap();
                 let expected_confirmed_transaction = ConfirmedTransaction::RejectedExecute(
                     0,
                     fee_transaction,
-                    Rejected::new_execution(execution),
+                    Rejected::new_execution(execution).with_reason(reason),
                     vec![],
                 );
 
-                let confirmed_transaction = confirmed_transactions.iter().next().unwrap();
                 assert_eq!(confirmed_transaction, &expected_confirmed_transaction);
             }
         }
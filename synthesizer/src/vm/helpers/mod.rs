@@ -19,3 +19,6 @@ mod macros;
 
 mod rewards;
 pub use rewards::*;
+
+mod simulation;
+pub use simulation::*;
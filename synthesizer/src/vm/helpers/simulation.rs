@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, program::Value};
+use synthesizer_program::FinalizeOperation;
+
+/// The outcome of simulating an execution against the current state, without proving.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Simulation<N: Network> {
+    /// The outputs of the top-level function.
+    outputs: Vec<Value<N>>,
+    /// The finalize operations that would be applied, if the transaction were included in the next block.
+    finalize_operations: Vec<FinalizeOperation<N>>,
+    /// The estimated minimum fee in microcredits, as `(total cost, (storage cost, finalize cost))`.
+    estimated_fee: (u64, (u64, u64)),
+    /// The reason the execution would be rejected in finalize, if any.
+    rejection_reason: Option<String>,
+}
+
+impl<N: Network> Simulation<N> {
+    /// Initializes a new simulation outcome.
+    pub const fn new(
+        outputs: Vec<Value<N>>,
+        finalize_operations: Vec<FinalizeOperation<N>>,
+        estimated_fee: (u64, (u64, u64)),
+        rejection_reason: Option<String>,
+    ) -> Self {
+        Self { outputs, finalize_operations, estimated_fee, rejection_reason }
+    }
+
+    /// Returns the outputs of the top-level function.
+    pub fn outputs(&self) -> &[Value<N>] {
+        &self.outputs
+    }
+
+    /// Returns the finalize operations that would be applied.
+    /// If the execution is rejected, these are the finalize operations of the fee (if any).
+    pub fn finalize_operations(&self) -> &[FinalizeOperation<N>] {
+        &self.finalize_operations
+    }
+
+    /// Returns the estimated minimum fee in microcredits, as `(total cost, (storage cost, finalize cost))`.
+    ///
+    /// Note: The storage cost does not include the size of the proof, which is only known after proving.
+    pub const fn estimated_fee(&self) -> (u64, (u64, u64)) {
+        self.estimated_fee
+    }
+
+    /// Returns the reason the execution would be rejected in finalize, if any.
+    pub fn rejection_reason(&self) -> Option<&str> {
+        self.rejection_reason.as_deref()
+    }
+
+    /// Returns `true` if the execution would be accepted in finalize.
+    pub const fn is_accepted(&self) -> bool {
+        self.rejection_reason.is_none()
+    }
+}
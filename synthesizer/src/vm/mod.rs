@@ -19,6 +19,7 @@ mod authorize;
 mod deploy;
 mod execute;
 mod finalize;
+mod simulate;
 mod verify;
 
 use crate::{cast_mut_ref, cast_ref, process};
@@ -55,7 +56,15 @@ use ledger_store::{
     TransactionStore,
     TransitionStore,
 };
-use synthesizer_process::{deployment_cost, execution_cost, Authorization, Process, Trace};
+use synthesizer_process::{
+    deployment_cost,
+    execution_cost,
+    Authorization,
+    DeploymentCostEstimate,
+    ExecutionCostEstimate,
+    Process,
+    Trace,
+};
 use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait, Program};
 
 use aleo_std::prelude::{finish, lap, timer};
@@ -114,8 +123,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let program = deployment.program();
             let program_id = program.id();
 
-            // Return early if the program is already loaded.
-            if process.contains_program(program_id) {
+            // Return early if the edition of the program is already loaded.
+            if process.contains_program_edition(program_id, deployment.edition()) {
                 return Ok(vec![]);
             }
 
@@ -168,8 +177,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 .collect::<Result<Vec<_>>>()?;
 
             for (program_id, deployment) in deployments.iter().flatten() {
-                // Load the deployment if it does not exist in the process yet.
-                if !process.contains_program(program_id) {
+                // Load the deployment if its edition does not exist in the process yet.
+                if !process.contains_program_edition(program_id, deployment.edition()) {
                     process.load_deployment(deployment)?;
                 }
             }
@@ -353,6 +362,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
@@ -426,7 +436,7 @@ pub(crate) mod test_helpers {
 
     /// Samples a new finalize state.
     pub(crate) fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-        FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+        FinalizeGlobalState::from(block_height as u64, block_height, 0, [0u8; 32])
     }
 
     pub(crate) fn sample_vm() -> VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>> {
@@ -1203,6 +1213,30 @@ function do:
         assert!(vm.check_transaction(&deployment, None, rng).is_err());
     }
 
+    #[test]
+    fn test_estimate_deployment_cost() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        // Update the VM.
+        vm.add_next_block(&sample_genesis_block(rng)).unwrap();
+
+        // Estimate the cost of the deployment.
+        let estimate = vm.estimate_deployment_cost(&sample_program(), rng).unwrap();
+        assert_eq!(estimate.constructor_cost(), 0);
+
+        // Check the estimate against the cost of the deployment transaction.
+        let transaction = sample_deployment_transaction(rng);
+        let deployment = transaction.deployment().unwrap();
+        let (cost, (storage_cost, synthesis_cost, _, namespace_cost)) =
+            deployment_cost(&vm.process().read(), deployment).unwrap();
+        assert_eq!(estimate.storage_cost(), storage_cost);
+        assert_eq!(estimate.synthesis_cost(), synthesis_cost);
+        assert_eq!(estimate.namespace_cost(), namespace_cost);
+        assert_eq!(estimate.total_cost(), cost);
+    }
+
     #[test]
     fn test_deployment_synthesis_overreport() {
         let rng = &mut TestRng::default();
@@ -1418,4 +1452,116 @@ finalize do:
         // Verify.
         vm.check_transaction(&transaction, None, rng).unwrap();
     }
+
+    #[test]
+    fn test_program_upgrade_preserves_mapping_state() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm_with_genesis_block(rng);
+
+        // Define the program, where `bump` increments the counter by the given amount.
+        let program = |amount: &str| {
+            Program::from_str(&format!(
+                r"
+program upgradable.aleo;
+
+mapping counter:
+    key as u8.public;
+    value as u64.public;
+
+function bump:
+    async bump into r0;
+    output r0 as upgradable.aleo/bump.future;
+
+finalize bump:
+    get.or_use counter[0u8] 0u64 into r0;
+    add r0 {amount} into r1;
+    set r1 into counter[0u8];"
+            ))
+            .unwrap()
+        };
+        // Define a helper to execute `bump` in a new block.
+        let bump = |rng: &mut TestRng| {
+            let execution = vm
+                .execute(
+                    &private_key,
+                    ("upgradable.aleo", "bump"),
+                    Vec::<Value<_>>::new().into_iter(),
+                    None,
+                    0,
+                    None,
+                    rng,
+                )
+                .unwrap();
+            vm.add_next_block(&sample_next_block(&vm, &private_key, &[execution], rng).unwrap()).unwrap();
+        };
+        // Define a helper to retrieve the counter.
+        let counter = || {
+            vm.finalize_store()
+                .get_value_confirmed(
+                    ProgramID::from_str("upgradable.aleo").unwrap(),
+                    Identifier::from_str("counter").unwrap(),
+                    &Plaintext::from_str("0u8").unwrap(),
+                )
+                .unwrap()
+                .unwrap()
+        };
+
+        // Deploy the first edition.
+        let deployment = vm.deploy(&private_key, &program("1u64"), None, 0, None, rng).unwrap();
+        vm.check_transaction(&deployment, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+        bump(rng);
+        assert_eq!(counter(), Value::from_str("1u64").unwrap());
+
+        // Deploy the second edition.
+        let deployment = vm.deploy(&private_key, &program("2u64"), None, 0, None, rng).unwrap();
+        let Transaction::Deploy(_, _, ref upgrade, _) = deployment else { panic!("Expected a deployment") };
+        assert_eq!(upgrade.edition(), 1);
+        vm.check_transaction(&deployment, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+
+        // Ensure both editions are loaded, and the latest edition is used.
+        let program_id = ProgramID::from_str("upgradable.aleo").unwrap();
+        assert_eq!(vm.process().read().get_stack(program_id).unwrap().edition(), 1);
+        assert!(vm.process().read().contains_program_edition(&program_id, 0));
+
+        // Ensure the mapping state carries over to the upgraded program.
+        bump(rng);
+        assert_eq!(counter(), Value::from_str("3u64").unwrap());
+
+        // Ensure an upgrade that changes a mapping is rejected.
+        let incompatible = Program::from_str(
+            r"
+program upgradable.aleo;
+
+mapping counter:
+    key as u8.public;
+    value as u32.public;
+
+function bump:
+    async bump into r0;
+    output r0 as upgradable.aleo/bump.future;
+
+finalize bump:
+    set 0u32 into counter[0u8];",
+        )
+        .unwrap();
+        assert!(vm.deploy(&private_key, &incompatible, None, 0, None, rng).is_err());
+
+        // Ensure an account other than the owner cannot upgrade the program.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        assert!(vm.deploy(&other_private_key, &program("3u64"), None, 0, None, rng).is_err());
+
+        // Ensure an upgrade signed by an account other than the owner is rejected, even if the owner pays the fee.
+        let transaction = vm.deploy(&private_key, &program("3u64"), None, 0, None, rng).unwrap();
+        let Transaction::Deploy(_, _, upgrade, fee) = transaction else { panic!("Expected a deployment") };
+        let owner = ProgramOwner::new(&other_private_key, upgrade.to_deployment_id().unwrap(), rng).unwrap();
+        let transaction = Transaction::from_deployment(owner, *upgrade, fee).unwrap();
+        assert!(vm.check_transaction(&transaction, None, rng).is_err());
+    }
 }
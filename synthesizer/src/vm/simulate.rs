@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Simulates the given execute authorization (and optional fee authorization) against the current state,
+    /// **without** generating any proofs.
+    ///
+    /// This method evaluates the function to obtain its outputs, and then runs finalize (and the fee, if given)
+    /// over a speculative view of the finalize store, which is discarded upon completion.
+    /// Committed state is never modified.
+    ///
+    /// Returns the outputs, the finalize operations, the estimated fee, and the rejection reason (if any).
+    /// If the execution would be rejected, the finalize operations are those of the fee (if given).
+    pub fn simulate(
+        &self,
+        state: FinalizeGlobalState,
+        authorization: &Authorization<N>,
+        fee_authorization: Option<&Authorization<N>>,
+    ) -> Result<Simulation<N>> {
+        let timer = timer!("VM::simulate");
+
+        // Evaluate the function, to obtain its outputs.
+        let outputs = {
+            macro_rules! logic {
+                ($process:expr, $network:path, $aleo:path) => {{
+                    // Evaluate the function on a replica of the authorization.
+                    let authorization = cast_ref!(&authorization as Authorization<$network>).replicate();
+                    let response = $process.evaluate::<$aleo>(authorization)?;
+                    // Prepare the outputs.
+                    Ok(cast_ref!((response.outputs().to_vec()) as Vec<Value<N>>).clone())
+                }};
+            }
+            let result: Result<Vec<Value<N>>> = process!(self, logic);
+            result?
+        };
+        lap!(timer, "Evaluate the function");
+
+        // Retrieve the current state root.
+        let state_root = self.block_store().current_state_root();
+        // Construct the execution from the authorized transitions, without a proof.
+        let execution = Execution::from(authorization.transitions().into_values(), state_root, None)?;
+        // Construct the fee from the authorized fee transition, without a proof.
+        let fee = match fee_authorization {
+            Some(fee_authorization) => match fee_authorization.transitions().into_values().next() {
+                Some(transition) => Some(Fee::from(transition, state_root, None)?),
+                None => bail!("The fee authorization does not contain a transition"),
+            },
+            None => None,
+        };
+
+        // Ensure the transitions do not spend an input that has already been spent.
+        for transition in execution.transitions().chain(fee.as_deref()) {
+            for input_id in transition.input_ids() {
+                ensure!(!self.transition_store().contains_input_id(input_id)?, "Input '{input_id}' is already spent");
+            }
+        }
+
+        // Estimate the fee.
+        let estimated_fee = execution_cost(&self.process().read(), &execution)?;
+        lap!(timer, "Estimate the fee");
+
+        // Acquire the atomic lock, which is needed to ensure this function is not called concurrently
+        // with other `atomic_finalize!` macro calls, which will cause a `bail!` to be triggered erroneously.
+        // Note: This lock must be held for the entire scope of the call to `atomic_finalize!`.
+        let _atomic_lock = self.atomic_lock.lock();
+
+        // Perform a **dry-run** of finalize, which aborts the atomic batch upon completion.
+        let (finalize_operations, rejection_reason) = atomic_finalize!(self.finalize_store(), FinalizeMode::DryRun, {
+            // Retrieve the finalize store.
+            let store = self.finalize_store();
+            // Acquire the read lock on the process.
+            let process = self.process.read();
+
+            match process.finalize_execution(state, store, &execution, fee.as_ref()) {
+                // If finalize succeeds, the execution is accepted.
+                Ok((finalize_operations, _)) => Ok((finalize_operations, None)),
+                // If finalize fails, the execution is rejected, and only the fee is finalized.
+                Err(error) => {
+                    let finalize_operations = match &fee {
+                        Some(fee) => process.finalize_fee(state, store, fee).map_err(|e| e.to_string())?,
+                        None => Vec::new(),
+                    };
+                    Ok((finalize_operations, Some(error.to_string())))
+                }
+            }
+        })?;
+
+        finish!(timer, "Finished dry-run of finalize");
+
+        Ok(Simulation::new(outputs, finalize_operations, estimated_fee, rejection_reason))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::{
+        sample_finalize_state,
+        sample_genesis_private_key,
+        sample_vm_with_genesis_block,
+        CurrentNetwork,
+    };
+
+    #[test]
+    fn test_simulate_transfer_public() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = sample_vm_with_genesis_block(rng);
+        // Initialize the caller.
+        let private_key = sample_genesis_private_key(rng);
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Retrieve the balance of the caller.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from(Literal::Address(address));
+        let balance = vm.finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap();
+
+        // Authorize a transfer to the caller.
+        let inputs =
+            [Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let authorization =
+            vm.authorize(&private_key, "credits.aleo", "transfer_public", inputs.iter().cloned(), rng).unwrap();
+
+        // Simulate the execution.
+        let simulation = vm.simulate(sample_finalize_state(1), &authorization, None).unwrap();
+        assert!(simulation.is_accepted());
+        assert_eq!(simulation.outputs().len(), 1);
+        assert_eq!(simulation.finalize_operations().len(), 2);
+        assert!(simulation.estimated_fee().0 > 0);
+
+        // Ensure the simulation is repeatable, and did not modify committed state.
+        assert_eq!(vm.simulate(sample_finalize_state(1), &authorization, None).unwrap(), simulation);
+        assert_eq!(vm.finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap(), balance);
+    }
+
+    #[test]
+    fn test_simulate_rejected_execution() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = sample_vm_with_genesis_block(rng);
+        // Initialize a caller without a public balance.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Authorize a transfer to the caller.
+        let inputs =
+            [Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let authorization =
+            vm.authorize(&private_key, "credits.aleo", "transfer_public", inputs.iter().cloned(), rng).unwrap();
+
+        // Simulate the execution, which is rejected in finalize.
+        let simulation = vm.simulate(sample_finalize_state(1), &authorization, None).unwrap();
+        assert!(!simulation.is_accepted());
+        assert!(simulation.rejection_reason().unwrap().contains("finalize"));
+        assert!(simulation.finalize_operations().is_empty());
+        assert_eq!(simulation.outputs().len(), 1);
+    }
+}
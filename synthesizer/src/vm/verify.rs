@@ -107,18 +107,33 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 };
                 // Verify the signature corresponds to the transaction ID.
                 ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
+                // Retrieve the latest edition of the program, if it is already deployed.
+                let latest_edition =
+                    self.transaction_store().deployment_store().get_edition(deployment.program_id())?;
+                // Determine the expected edition.
+                // Note: A new program is deployed at the network edition, while an upgrade is the next edition.
+                let expected_edition = match latest_edition {
+                    Some(edition) => match edition.checked_add(1) {
+                        Some(edition) => edition,
+                        None => bail!("Program ID '{}' cannot be upgraded further", deployment.program_id()),
+                    },
+                    None => N::EDITION,
+                };
                 // Ensure the edition is correct.
-                if deployment.edition() != N::EDITION {
-                    bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
-                }
-                // Ensure the program ID does not already exist in the store.
-                if self.transaction_store().contains_program_id(deployment.program_id())? {
-                    bail!("Program ID '{}' is already deployed", deployment.program_id())
+                if deployment.edition() != expected_edition {
+                    bail!("Invalid deployment transaction '{id}' - expected edition {expected_edition}")
                 }
-                // Ensure the program does not already exist in the process.
-                if self.contains_program(deployment.program_id()) {
+                // Ensure a new program does not already exist in the process.
+                if latest_edition.is_none() && self.contains_program(deployment.program_id()) {
                     bail!("Program ID '{}' already exists", deployment.program_id());
                 }
+                // Ensure an upgrade is signed by the owner of the program.
+                if latest_edition.is_some() {
+                    match self.transaction_store().deployment_store().get_owner(deployment.program_id())? {
+                        Some(previous) if previous.address() == owner.address() => (),
+                        _ => bail!("Invalid deployment transaction '{id}' - only the owner may upgrade the program"),
+                    }
+                }
                 // Verify the deployment if it has not been verified before.
                 if !is_partially_verified {
                     self.check_deployment_internal(deployment, rng)?;
@@ -161,7 +176,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Failed to compute the Merkle root for deployment transaction '{id}'")
                 };
                 // Compute the minimum deployment cost.
-                let (cost, _) = deployment_cost(deployment)?;
+                let (cost, _) = deployment_cost(&self.process().read(), deployment)?;
                 // Ensure the fee is sufficient to cover the cost.
                 if *fee.base_amount()? < cost {
                     bail!("Transaction '{id}' has an insufficient base fee (deployment) - requires {cost} microcredits")
@@ -273,7 +273,7 @@ fn run_test(test: &ProgramTest) -> serde_yaml::Mapping {
                     result.insert(
                         serde_yaml::Value::String("speculate".to_string()),
                         serde_yaml::Value::String(match transactions.iter().next().unwrap() {
-                            ConfirmedTransaction::AcceptedExecute(_, _, _) => "the execution was accepted".to_string(),
+                            ConfirmedTransaction::AcceptedExecute(..) => "the execution was accepted".to_string(),
                             ConfirmedTransaction::RejectedExecute(_, _, _, _) => {
                                 "the execution was rejected".to_string()
                             }
@@ -522,6 +522,7 @@ fn construct_finalize_global_state<C: ConsensusStorage<CurrentNetwork>>(
     FinalizeGlobalState::new::<CurrentNetwork>(
         next_round,
         next_height,
+        CurrentNetwork::GENESIS_TIMESTAMP + 1,
         latest_cumulative_weight,
         0u128,
         latest_block.hash(),
@@ -51,7 +51,8 @@ impl<N: Network> Package<N> {
         // Initialize the assignments.
         let assignments = Assignments::<N>::default();
         // Initialize the call stack.
-        let call_stack = CallStack::PackageRun(vec![request], *private_key, assignments.clone());
+        let call_stack =
+            CallStack::PackageRun(vec![request], *private_key, assignments.clone(), process.dynamic_stacks(stack));
         // Synthesize the circuit.
         let response = stack.execute_function::<A, R>(call_stack, None, None, rng)?;
         // Retrieve the call metrics.